@@ -11,8 +11,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "proto"
     };
 
-    let settings = AxumConnectGenSettings::from_directory_recursive(proto_dir)
+    let mut settings = AxumConnectGenSettings::from_directory_recursive(proto_dir)
         .expect("failed to glob proto files");
+
+    // Vorgegebenes protoc (System/vendored) statt Download von GitHub,
+    // z.B. für Offline- und CI-Builds: PROTOC=/usr/bin/protoc cargo build
+    println!("cargo:rerun-if-env-changed=PROTOC");
+    if std::env::var_os("PROTOC").is_some_and(|p| !p.is_empty()) {
+        settings.protoc_version = None;
+    }
+
     axum_connect_codegen(settings)?;
     Ok(())
 }
@@ -0,0 +1,109 @@
+//! Fehler des Clients
+
+use reqwest::StatusCode;
+use serde_json::Value;
+
+/// Ergebnis eines API-Aufrufs
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Fehler beim Aufruf der API
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Verbindung, Timeout oder TLS
+    #[error("transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+
+    /// Antwort mit Fehlerstatus
+    #[error("{status} {code}: {message}")]
+    Api {
+        status: StatusCode,
+        /// Fehlercode aus dem Body (z. B. `UNAUTHORIZED`, `realm_not_found`)
+        code: String,
+        message: String,
+    },
+
+    /// Erfolgreiche Antwort, deren Body nicht zum erwarteten Typ passt
+    #[error("unexpected response body: {0}")]
+    Decode(#[from] serde_json::Error),
+
+    /// Basis-URL nicht verwendbar
+    #[error("invalid base url: {0}")]
+    BaseUrl(String),
+}
+
+impl Error {
+    /// Baut den Fehler aus Status und Body einer Fehlerantwort
+    ///
+    /// Versteht beide Formate der API: `{"error": {"code", "message"}}` aus
+    /// Authentifizierung und Zugriffskontrolle sowie `{"error": "<code>",
+    /// "message": "..."}` der Handler.
+    pub(crate) fn from_response(status: StatusCode, body: &[u8]) -> Self {
+        let value: Value = serde_json::from_slice(body).unwrap_or(Value::Null);
+        let (code, message) = match &value["error"] {
+            Value::Object(details) => (
+                details.get("code").and_then(Value::as_str),
+                details.get("message").and_then(Value::as_str),
+            ),
+            Value::String(code) => (Some(code.as_str()), value["message"].as_str()),
+            _ => (None, None),
+        };
+        let reason = status.canonical_reason().unwrap_or("error");
+        Self::Api {
+            status,
+            code: code.map_or_else(|| reason.to_lowercase().replace(' ', "_"), str::to_string),
+            message: message
+                .map(str::to_string)
+                .unwrap_or_else(|| String::from_utf8_lossy(body).into_owned()),
+        }
+    }
+
+    /// HTTP-Status, falls der Server geantwortet hat
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Api { status, .. } => Some(*status),
+            Self::Transport(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Fehlercode der API (nur [`Error::Api`])
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Self::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_formats() {
+        let nested = Error::from_response(
+            StatusCode::UNAUTHORIZED,
+            br#"{"error":{"code":"UNAUTHORIZED","message":"Missing bearer token"}}"#,
+        );
+        assert_eq!(nested.status(), Some(StatusCode::UNAUTHORIZED));
+        assert_eq!(nested.code(), Some("UNAUTHORIZED"));
+        assert_eq!(
+            nested.to_string(),
+            "401 Unauthorized UNAUTHORIZED: Missing bearer token"
+        );
+
+        let flat = Error::from_response(
+            StatusCode::NOT_FOUND,
+            br#"{"error":"realm_not_found","message":"realm x"}"#,
+        );
+        assert_eq!(flat.code(), Some("realm_not_found"));
+
+        let empty = Error::from_response(StatusCode::NOT_FOUND, b"");
+        assert_eq!(empty.code(), Some("not_found"));
+        let text = Error::from_response(StatusCode::TOO_MANY_REQUESTS, b"slow down");
+        assert!(matches!(
+            text,
+            Error::Api { ref code, ref message, .. } if code == "too_many_requests" && message == "slow down"
+        ));
+    }
+}
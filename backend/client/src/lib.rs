@@ -0,0 +1,526 @@
+//! Erynoa Client – typisierter Rust-Client für die REST-API
+//!
+//! Geschrieben gegen dieselben Request-/Response-Typen wie die Handler in
+//! `erynoa_api::api::v1` (Spezifikation: `GET /api/v1/openapi.json`). Ändert
+//! sich ein Handler-Typ, bricht der Client beim Kompilieren statt zur Laufzeit.
+//!
+//! Endpunkte, die Snapshots aus `core` liefern, geben [`serde_json::Value`]
+//! zurück – wie in der Spezifikation. Listen liefern eine [`Page`]; die
+//! nächste Seite holt man mit `cursor = page.next_cursor`. Für Routen ohne eigene Methode gibt es
+//! [`Client::get`], [`Client::post`] und [`Client::delete`].
+//!
+//! ```no_run
+//! # async fn demo() -> erynoa_client::Result<()> {
+//! let client = erynoa_client::Client::new("http://127.0.0.1:3000")?;
+//! let health = client.health().await?;
+//! assert_eq!(health.status, "healthy");
+//!
+//! let authed = client.with_token("<access_token>");
+//! let me = authed.me().await?;
+//! println!("{}", me.did);
+//! # Ok(())
+//! # }
+//! ```
+
+mod error;
+
+pub use error::{Error, Result};
+
+use std::time::Duration;
+
+use reqwest::{Method, RequestBuilder, StatusCode, Url};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+pub use erynoa_api::api::v1::audit_handlers::{AuditListQuery, AuditListResponse};
+pub use erynoa_api::api::v1::auth::{
+    ChallengeResponse, MeResponse, PasskeyRegistrationRequest, PasskeyRegistrationResponse,
+    PasskeyVerificationRequest, PasskeyVerificationResponse, SessionRefreshRequest,
+    SessionRevokeRequest, SessionRevokeResponse, TokenPair,
+};
+pub use erynoa_api::api::v1::config_handlers::EffectiveConfigResponse;
+pub use erynoa_api::api::v1::ledger_handlers::{
+    LedgerBalancesResponse, LedgerInvariantsResponse, LedgerJournalQuery, LedgerSupplyResponse,
+};
+pub use erynoa_api::api::v1::listing_handlers::{
+    ContentListQuery, IdentityListItem, IdentityListQuery, TrustDirection, TrustEdgesQuery,
+};
+pub use erynoa_api::api::v1::pagination::{Page, SortOrder};
+pub use erynoa_api::api::v1::production_handlers::{
+    ControllerCheckBody, ControllerCheckResponse, CrossingValidateBody, CrossingValidateResponse,
+    GovernanceProposalBody, GovernanceVoteBody, IdentityRootResponse, IntentParseBody,
+    IntentRequestBody, RealmCreateBody, RealmListQuery, RealmMembersBody, TrustGetResponse,
+    TrustUpdateBody, TrustUpdateResponse,
+};
+pub use erynoa_api::api::v1::rest_handlers::{
+    HealthResponse, InfoResponse, ReadyResponse, StatusResponse,
+};
+pub use erynoa_api::api::v1::saga_handlers::{SagaAbortBody, SagaExecuteBody, SagaSignalBody};
+pub use erynoa_api::api::v1::state_handlers::{
+    EventsCheckpointResponse, EventsQuery, HealthStateDetailResponse, HealthStateResponse,
+    SnapshotQuery, StateModeResponse, StateModeSetBody,
+};
+pub use erynoa_api::config::{ChangeStatus, ConfigChange, ConfigReload, ReloadOutcome};
+
+/// Präfix aller REST-Routen
+const API_PREFIX: [&str; 2] = ["api", "v1"];
+
+/// Builder für [`Client`]
+#[derive(Debug)]
+pub struct ClientBuilder {
+    base_url: String,
+    token: Option<String>,
+    timeout: Option<Duration>,
+    http: Option<reqwest::Client>,
+}
+
+impl ClientBuilder {
+    /// Session-Token (`access_token` aus Login oder Refresh)
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Timeout pro Request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Eigener `reqwest::Client` (Proxy, Zertifikate, Connection-Pool)
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    pub fn build(self) -> Result<Client> {
+        let base_url = Url::parse(&self.base_url)
+            .map_err(|e| Error::BaseUrl(format!("{}: {e}", self.base_url)))?;
+        if base_url.cannot_be_a_base() {
+            return Err(Error::BaseUrl(self.base_url));
+        }
+        let http = match self.http {
+            Some(http) => http,
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                builder.build()?
+            }
+        };
+        Ok(Client {
+            http,
+            base_url,
+            token: self.token,
+        })
+    }
+}
+
+/// Client für einen Erynoa-Knoten
+///
+/// Günstig zu klonen (teilt den Connection-Pool).
+#[derive(Debug, Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: Url,
+    token: Option<String>,
+}
+
+impl Client {
+    /// Client ohne Session für `base_url` (z. B. `http://127.0.0.1:3000`)
+    pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        Self::builder(base_url).build()
+    }
+
+    pub fn builder(base_url: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            base_url: base_url.into(),
+            token: None,
+            timeout: None,
+            http: None,
+        }
+    }
+
+    /// Kopie mit Session-Token (gleicher Connection-Pool)
+    pub fn with_token(&self, token: impl Into<String>) -> Self {
+        Self {
+            token: Some(token.into()),
+            ..self.clone()
+        }
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    // ------------------------------------------------------------------------
+    // Generische Aufrufe
+    // ------------------------------------------------------------------------
+
+    /// URL aus Pfadsegmenten unter `/api/v1` (Segmente werden kodiert)
+    pub fn url(&self, segments: &[&str]) -> Url {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("base url checked in build()")
+            .pop_if_empty()
+            .extend(API_PREFIX)
+            .extend(segments);
+        url
+    }
+
+    fn request(&self, method: Method, segments: &[&str]) -> RequestBuilder {
+        let request = self.http.request(method, self.url(segments));
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(request: RequestBuilder) -> Result<T> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.bytes().await?;
+        if !status.is_success() {
+            return Err(Error::from_response(status, &body));
+        }
+        let body: &[u8] = if status == StatusCode::NO_CONTENT || body.is_empty() {
+            b"null"
+        } else {
+            &body
+        };
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    /// GET `/api/v1/<segments>` mit optionaler Query
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        segments: &[&str],
+        query: &(impl Serialize + ?Sized),
+    ) -> Result<T> {
+        Self::send(self.request(Method::GET, segments).query(query)).await
+    }
+
+    /// POST `/api/v1/<segments>` mit JSON-Body
+    pub async fn post<T: DeserializeOwned>(
+        &self,
+        segments: &[&str],
+        body: &(impl Serialize + ?Sized),
+    ) -> Result<T> {
+        Self::send(self.request(Method::POST, segments).json(body)).await
+    }
+
+    /// DELETE `/api/v1/<segments>`
+    pub async fn delete(&self, segments: &[&str]) -> Result<()> {
+        Self::send::<Value>(self.request(Method::DELETE, segments))
+            .await
+            .map(drop)
+    }
+
+    // ------------------------------------------------------------------------
+    // System
+    // ------------------------------------------------------------------------
+
+    pub async fn health(&self) -> Result<HealthResponse> {
+        self.get(&["health"], &()).await
+    }
+
+    pub async fn ready(&self) -> Result<ReadyResponse> {
+        self.get(&["ready"], &()).await
+    }
+
+    pub async fn info(&self) -> Result<InfoResponse> {
+        self.get(&["info"], &()).await
+    }
+
+    pub async fn status(&self) -> Result<StatusResponse> {
+        self.get(&["status"], &()).await
+    }
+
+    /// OpenAPI-Spezifikation des Knotens
+    pub async fn openapi(&self) -> Result<Value> {
+        self.get(&["openapi.json"], &()).await
+    }
+
+    // ------------------------------------------------------------------------
+    // Auth
+    // ------------------------------------------------------------------------
+
+    pub async fn auth_challenge(&self) -> Result<ChallengeResponse> {
+        self.get(&["auth", "challenge"], &()).await
+    }
+
+    pub async fn register_passkey(
+        &self,
+        request: &PasskeyRegistrationRequest,
+    ) -> Result<PasskeyRegistrationResponse> {
+        self.post(&["auth", "passkey", "register"], request).await
+    }
+
+    /// Login per Passkey-Assertion; Session-Tokens in `session`
+    pub async fn verify_passkey(
+        &self,
+        request: &PasskeyVerificationRequest,
+    ) -> Result<PasskeyVerificationResponse> {
+        self.post(&["auth", "passkey", "verify"], request).await
+    }
+
+    pub async fn refresh_session(&self, refresh_token: &str) -> Result<TokenPair> {
+        let request = SessionRefreshRequest {
+            refresh_token: refresh_token.to_string(),
+        };
+        self.post(&["auth", "session", "refresh"], &request).await
+    }
+
+    pub async fn revoke_session(
+        &self,
+        request: &SessionRevokeRequest,
+    ) -> Result<SessionRevokeResponse> {
+        self.post(&["auth", "session", "revoke"], request).await
+    }
+
+    pub async fn me(&self) -> Result<MeResponse> {
+        self.get(&["me"], &()).await
+    }
+
+    // ------------------------------------------------------------------------
+    // State, Health, Events
+    // ------------------------------------------------------------------------
+
+    /// `UnifiedSnapshot` (optional gefiltert)
+    pub async fn state_snapshot(&self, query: &SnapshotQuery) -> Result<Value> {
+        self.get(&["state", "snapshot"], query).await
+    }
+
+    pub async fn state_mode(&self) -> Result<StateModeResponse> {
+        self.get(&["state", "mode"], &()).await
+    }
+
+    pub async fn set_state_mode(&self, mode: &str) -> Result<StateModeResponse> {
+        let body = StateModeSetBody {
+            mode: mode.to_string(),
+        };
+        self.post(&["state", "mode"], &body).await
+    }
+
+    pub async fn reset_state_mode(&self) -> Result<StateModeResponse> {
+        self.post(&["state", "mode", "reset"], &()).await
+    }
+
+    pub async fn state_warnings(&self) -> Result<Vec<String>> {
+        self.get(&["state", "warnings"], &()).await
+    }
+
+    pub async fn health_state(&self) -> Result<HealthStateResponse> {
+        self.get(&["health", "state"], &()).await
+    }
+
+    pub async fn health_state_detail(&self) -> Result<HealthStateDetailResponse> {
+        self.get(&["health", "state", "detail"], &()).await
+    }
+
+    /// `WrappedStateEvent`s aus dem Event-Log
+    pub async fn events(&self, query: &EventsQuery) -> Result<Page<Value>> {
+        self.get(&["events"], query).await
+    }
+
+    pub async fn event(&self, sequence: u64) -> Result<Value> {
+        self.get(&["events", &sequence.to_string()], &()).await
+    }
+
+    pub async fn events_checkpoints(&self) -> Result<EventsCheckpointResponse> {
+        self.get(&["events", "checkpoints"], &()).await
+    }
+
+    // ------------------------------------------------------------------------
+    // Trust, Identity, Realms, Crossing, Controller
+    // ------------------------------------------------------------------------
+
+    pub async fn trust(&self, did: &str) -> Result<TrustGetResponse> {
+        self.get(&["trust", did], &()).await
+    }
+
+    pub async fn update_trust(&self, body: &TrustUpdateBody) -> Result<TrustUpdateResponse> {
+        self.post(&["trust", "update"], body).await
+    }
+
+    pub async fn identity_root(&self) -> Result<IdentityRootResponse> {
+        self.get(&["identity", "root"], &()).await
+    }
+
+    /// Trust-Kanten (`StoredTrust`) einer DID
+    pub async fn trust_edges(&self, did: &str, query: &TrustEdgesQuery) -> Result<Page<Value>> {
+        self.get(&["trust", did, "edges"], query).await
+    }
+
+    pub async fn identity(&self, did: &str) -> Result<Value> {
+        self.get(&["identity", did], &()).await
+    }
+
+    pub async fn identities(&self, query: &IdentityListQuery) -> Result<Page<IdentityListItem>> {
+        self.get(&["identity"], query).await
+    }
+
+    /// `ContentMetadata` gespeicherter Inhalte
+    pub async fn content(&self, query: &ContentListQuery) -> Result<Page<Value>> {
+        self.get(&["content"], query).await
+    }
+
+    /// Realms mit `RealmSpecificSnapshot`-Feldern
+    pub async fn realms(&self, query: &RealmListQuery) -> Result<Page<Value>> {
+        self.get(&["realms"], query).await
+    }
+
+    pub async fn realm(&self, realm_id: &str) -> Result<Value> {
+        self.get(&["realms", realm_id], &()).await
+    }
+
+    pub async fn create_realm(&self, body: &RealmCreateBody) -> Result<Value> {
+        self.post(&["realms"], body).await
+    }
+
+    pub async fn realm_members(&self, realm_id: &str, body: &RealmMembersBody) -> Result<Value> {
+        self.post(&["realms", realm_id, "members"], body).await
+    }
+
+    pub async fn validate_crossing(
+        &self,
+        body: &CrossingValidateBody,
+    ) -> Result<CrossingValidateResponse> {
+        self.post(&["crossing", "validate"], body).await
+    }
+
+    pub async fn controller_check(
+        &self,
+        body: &ControllerCheckBody,
+    ) -> Result<ControllerCheckResponse> {
+        self.post(&["controller", "check"], body).await
+    }
+
+    // ------------------------------------------------------------------------
+    // Governance, Intent, Saga
+    // ------------------------------------------------------------------------
+
+    pub async fn create_proposal(&self, body: &GovernanceProposalBody) -> Result<Value> {
+        self.post(&["governance", "proposals"], body).await
+    }
+
+    pub async fn vote(&self, proposal_id: &str, body: &GovernanceVoteBody) -> Result<Value> {
+        self.post(&["governance", "proposals", proposal_id, "vote"], body)
+            .await
+    }
+
+    pub async fn parse_intent(&self, body: &IntentParseBody) -> Result<Value> {
+        self.post(&["intent", "parse"], body).await
+    }
+
+    pub async fn simulate_intent(&self, body: &IntentRequestBody) -> Result<Value> {
+        self.post(&["intent", "simulate"], body).await
+    }
+
+    /// Startet eine Saga; mit `wait` kommt der Endzustand zurück
+    pub async fn execute_saga(&self, body: &SagaExecuteBody) -> Result<Value> {
+        self.post(&["saga", "execute"], body).await
+    }
+
+    pub async fn saga(&self, saga_id: &str) -> Result<Value> {
+        self.get(&["saga", saga_id], &()).await
+    }
+
+    pub async fn sagas(&self) -> Result<Vec<Value>> {
+        self.get(&["saga", "list"], &()).await
+    }
+
+    pub async fn cancel_saga(&self, saga_id: &str, body: &SagaAbortBody) -> Result<Value> {
+        self.post(&["saga", saga_id, "cancel"], body).await
+    }
+
+    pub async fn signal_saga(&self, body: &SagaSignalBody) -> Result<Value> {
+        self.post(&["saga", "signal"], body).await
+    }
+
+    // ------------------------------------------------------------------------
+    // Ledger, Audit
+    // ------------------------------------------------------------------------
+
+    pub async fn balances(&self, did: &str) -> Result<LedgerBalancesResponse> {
+        self.get(&["ledger", "balances", did], &()).await
+    }
+
+    pub async fn supply(&self, asset: &str) -> Result<LedgerSupplyResponse> {
+        self.get(&["ledger", "supply", asset], &()).await
+    }
+
+    /// Journal-Einträge, neueste zuerst
+    pub async fn journal(&self, query: &LedgerJournalQuery) -> Result<Vec<Value>> {
+        self.get(&["ledger", "journal"], query).await
+    }
+
+    pub async fn ledger_invariants(&self) -> Result<LedgerInvariantsResponse> {
+        self.get(&["ledger", "invariants"], &()).await
+    }
+
+    pub async fn audit(&self, query: &AuditListQuery) -> Result<AuditListResponse> {
+        self.get(&["audit"], query).await
+    }
+
+    pub async fn audit_verify(&self) -> Result<Value> {
+        self.get(&["audit", "verify"], &()).await
+    }
+
+    // ------------------------------------------------------------------------
+    // Konfiguration (nur Admin-Listener)
+    // ------------------------------------------------------------------------
+
+    pub async fn config(&self) -> Result<EffectiveConfigResponse> {
+        self.get(&["config"], &()).await
+    }
+
+    /// Diff des letzten Reloads (404 ohne Reload seit dem Start)
+    pub async fn config_diff(&self) -> Result<ConfigReload> {
+        self.get(&["config", "diff"], &()).await
+    }
+
+    pub async fn reload_config(&self) -> Result<ConfigReload> {
+        self.post(&["config", "reload"], &()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_encodes_segments() {
+        let client = Client::new("http://127.0.0.1:3000/").unwrap();
+        assert_eq!(
+            client.url(&["trust", "did:erynoa:self:abc"]).as_str(),
+            "http://127.0.0.1:3000/api/v1/trust/did:erynoa:self:abc"
+        );
+        assert_eq!(
+            client.url(&["state", "warnings", "a/b c"]).as_str(),
+            "http://127.0.0.1:3000/api/v1/state/warnings/a%2Fb%20c"
+        );
+
+        let proxied = Client::new("https://node.example/erynoa").unwrap();
+        assert_eq!(
+            proxied.url(&["health"]).as_str(),
+            "https://node.example/erynoa/api/v1/health"
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_bad_base_url() {
+        assert!(matches!(Client::new("not a url"), Err(Error::BaseUrl(_))));
+        assert!(matches!(
+            Client::new("mailto:ops@example.org"),
+            Err(Error::BaseUrl(_))
+        ));
+        let authed = Client::builder("http://localhost:3000")
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap()
+            .with_token("t");
+        assert_eq!(authed.token.as_deref(), Some("t"));
+    }
+}
@@ -0,0 +1,513 @@
+//! EnvironmentService – Environments als hierarchische Realms
+//!
+//! Ein Environment ist ein registriertes Realm. Die Hierarchie ergibt sich aus
+//! der Realm-ID: Kinder erhalten die ID `<parent>:<name>`. Realms ohne
+//! registrierten Präfix hängen direkt am Root-Realm.
+//!
+//! Name und Beschreibung werden nicht separat persistiert; der Name ist das
+//! letzte ID-Segment.
+
+use axum::extract::State;
+use axum_connect::prelude::*;
+
+use super::{api_error, timestamp};
+use crate::api::proto::{
+    BootstrapCheck, BootstrapMode, BootstrapState, BootstrapStatus, CreateEnvironmentRequest,
+    CreateEnvironmentResponse, EnvironmentNode, EnvironmentSummary, EnvironmentType,
+    EnvironmentTypeFilter, GetBootstrapStatusRequest, GetBootstrapStatusResponse,
+    GetEnvironmentInfoRequest, GetEnvironmentInfoResponse, GetEnvironmentTreeRequest,
+    GetEnvironmentTreeResponse, GovernanceType, JoinEnvironmentRequest, JoinEnvironmentResponse,
+    JoinStatus, LeaveEnvironmentRequest, LeaveEnvironmentResponse, ListEnvironmentsRequest,
+    ListEnvironmentsResponse, RealmId, SwitchEnvironmentRequest, SwitchEnvironmentResponse,
+    TrustVector6D as ProtoTrustVector,
+};
+use crate::api::v1::auth::AuthContext;
+use crate::core::{RealmObserver, RealmSpecificSnapshot, StateEvent};
+use crate::domain::{Capability, MembershipAction};
+use crate::error::ApiError;
+use crate::server::AppState;
+
+/// Standardtiefe für `GetEnvironmentTree`
+const DEFAULT_TREE_DEPTH: i32 = 8;
+
+// ============================================================================
+// Hierarchie
+// ============================================================================
+
+fn realm_ids(state: &AppState) -> Vec<String> {
+    let realms = state.unified_state.peer.realm.realms.read().unwrap();
+    let mut ids: Vec<String> = realms.keys().cloned().collect();
+    ids.sort();
+    ids
+}
+
+fn root_realm(state: &AppState) -> Option<String> {
+    state
+        .unified_state
+        .peer
+        .realm
+        .root_realm_id
+        .read()
+        .ok()
+        .and_then(|r| r.clone())
+}
+
+/// Nächster registrierter Präfix der ID, sonst das Root-Realm
+fn parent_of(id: &str, ids: &[String], root: Option<&str>) -> Option<String> {
+    if Some(id) == root {
+        return None;
+    }
+    let mut prefix = id;
+    while let Some((head, _)) = prefix.rsplit_once(':') {
+        if ids.iter().any(|i| i == head) {
+            return Some(head.to_string());
+        }
+        prefix = head;
+    }
+    root.map(str::to_string)
+}
+
+fn environment_type(id: &str, ids: &[String], root: Option<&str>) -> EnvironmentType {
+    match parent_of(id, ids, root) {
+        None if Some(id) == root => EnvironmentType::Root,
+        None => EnvironmentType::Virtual,
+        Some(parent) if Some(parent.as_str()) == root => EnvironmentType::Virtual,
+        Some(_) => EnvironmentType::SubVirtual,
+    }
+}
+
+fn name_of(id: &str) -> String {
+    id.rsplit(':').next().unwrap_or(id).to_string()
+}
+
+fn realm_id(id: &str) -> RealmId {
+    RealmId { id: id.to_string() }
+}
+
+fn required(environment: Option<RealmId>) -> RpcResult<String> {
+    environment.map(|r| r.id).ok_or_else(|| {
+        RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            "environment is required".to_string(),
+        )
+    })
+}
+
+fn snapshot(state: &AppState, id: &str) -> RpcResult<RealmSpecificSnapshot> {
+    state
+        .unified_state
+        .peer
+        .realm
+        .get_realm(id)
+        .ok_or_else(|| api_error(ApiError::NotFound(format!("environment {}", id))))
+}
+
+// ============================================================================
+// Mapping
+// ============================================================================
+
+fn governance(governance_type: &str) -> GovernanceType {
+    match governance_type.to_ascii_lowercase().as_str() {
+        "multisig" | "multi_sig" => GovernanceType::MultiSig,
+        "single" => GovernanceType::Single,
+        _ => GovernanceType::Dao,
+    }
+}
+
+fn governance_str(governance: GovernanceType) -> &'static str {
+    match governance {
+        GovernanceType::MultiSig => "multisig",
+        GovernanceType::Single => "single",
+        GovernanceType::Dao | GovernanceType::Unspecified => "quadratic",
+    }
+}
+
+fn min_trust(value: f32) -> ProtoTrustVector {
+    let v = value as f64;
+    ProtoTrustVector {
+        reliability: v,
+        integrity: v,
+        competence: v,
+        prestige: v,
+        vigilance: v,
+        omega: v,
+    }
+}
+
+fn matches_filter(filter: EnvironmentTypeFilter, ty: EnvironmentType) -> bool {
+    match filter {
+        EnvironmentTypeFilter::Unspecified | EnvironmentTypeFilter::All => true,
+        EnvironmentTypeFilter::Root => ty == EnvironmentType::Root,
+        EnvironmentTypeFilter::Virtual => ty == EnvironmentType::Virtual,
+        EnvironmentTypeFilter::SubVirtual => ty == EnvironmentType::SubVirtual,
+    }
+}
+
+fn check(name: &str, passed: bool, message: &str) -> BootstrapCheck {
+    BootstrapCheck {
+        name: name.to_string(),
+        passed,
+        message: (!passed).then(|| message.to_string()),
+    }
+}
+
+/// Bootstrap-Status der aufrufenden Identität in einem Realm
+fn bootstrap(state: &AppState, id: &str, auth: &AuthContext) -> BootstrapStatus {
+    let realms = state.unified_state.peer.realm.realms.read().unwrap();
+    let realm = realms.get(id);
+    let exists = realm.is_some();
+    let banned = realm.is_some_and(|r| r.is_banned_by_id(&auth.id));
+    let member = realm.is_some_and(|r| r.is_member_by_id(&auth.id));
+    let pending = realm.is_some_and(|r| {
+        r.pending_members_by_id
+            .read()
+            .map(|p| p.contains(&auth.id))
+            .unwrap_or(false)
+    });
+
+    let (state, progress, step) = if banned {
+        (BootstrapState::Revoked, 0, None)
+    } else if member {
+        (BootstrapState::Active, 100, None)
+    } else if pending {
+        (BootstrapState::Verifying, 50, Some("awaiting approval"))
+    } else {
+        (BootstrapState::Pending, 0, Some("join environment"))
+    };
+
+    BootstrapStatus {
+        mode: BootstrapMode::Short as i32,
+        state: state as i32,
+        progress_percent: progress,
+        current_step: step.map(str::to_string),
+        estimated_completion: None,
+        checks: vec![
+            check("realm_exists", exists, "Environment is not registered"),
+            check(
+                "not_banned",
+                !banned,
+                "Identity is banned from the environment",
+            ),
+            check("membership", member, "Identity is not a member"),
+        ],
+    }
+}
+
+fn log_membership(state: &AppState, id: &str, auth: &AuthContext, action: MembershipAction) {
+    state.unified_state.log_and_apply(
+        StateEvent::MembershipChange {
+            realm_id: id.to_string(),
+            identity_id: auth.did.clone(),
+            identity_universal_id: Some(auth.id),
+            action,
+            new_role: None,
+            initiated_by: Some(auth.did.clone()),
+            initiated_by_id: Some(auth.id),
+        },
+        vec![],
+    );
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn list_environments(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: ListEnvironmentsRequest,
+) -> RpcResult<ListEnvironmentsResponse> {
+    let filter = EnvironmentTypeFilter::try_from(req.type_filter)
+        .unwrap_or(EnvironmentTypeFilter::Unspecified);
+    let ids = realm_ids(&state);
+    let root = root_realm(&state);
+    let realms = state.unified_state.peer.realm.realms.read().unwrap();
+
+    let environments = ids
+        .iter()
+        .filter_map(|id| {
+            let realm = realms.get(id)?;
+            let ty = environment_type(id, &ids, root.as_deref());
+            matches_filter(filter, ty).then(|| EnvironmentSummary {
+                id: Some(realm_id(id)),
+                name: name_of(id),
+                r#type: ty as i32,
+                parent: parent_of(id, &ids, root.as_deref()).map(|p| realm_id(&p)),
+                member_count: realm.snapshot().member_count as i32,
+                joined: realm.is_member_by_id(&auth.id),
+            })
+        })
+        .collect();
+
+    Ok(ListEnvironmentsResponse { environments })
+}
+
+pub async fn get_environment_tree(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    req: GetEnvironmentTreeRequest,
+) -> RpcResult<GetEnvironmentTreeResponse> {
+    let ids = realm_ids(&state);
+    let root = root_realm(&state);
+    let start = match req.root.map(|r| r.id).or_else(|| root.clone()) {
+        Some(id) => id,
+        None => return Ok(GetEnvironmentTreeResponse { root: None }),
+    };
+    if !ids.contains(&start) {
+        return Err(api_error(ApiError::NotFound(format!(
+            "environment {}",
+            start
+        ))));
+    }
+
+    fn node(
+        state: &AppState,
+        id: &str,
+        ids: &[String],
+        root: Option<&str>,
+        depth: i32,
+    ) -> EnvironmentNode {
+        let children = if depth > 0 {
+            ids.iter()
+                .filter(|c| parent_of(c, ids, root).as_deref() == Some(id))
+                .map(|c| node(state, c, ids, root, depth - 1))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let axiom_count = state
+            .unified_state
+            .peer
+            .realm
+            .get_realm(id)
+            .map(|r| r.active_rules.len() as i32)
+            .unwrap_or(0);
+        EnvironmentNode {
+            id: Some(realm_id(id)),
+            name: name_of(id),
+            r#type: environment_type(id, ids, root) as i32,
+            children,
+            axiom_count,
+            has_cbdc: false,
+        }
+    }
+
+    let depth = req.max_depth.unwrap_or(DEFAULT_TREE_DEPTH).max(0);
+    Ok(GetEnvironmentTreeResponse {
+        root: Some(node(&state, &start, &ids, root.as_deref(), depth)),
+    })
+}
+
+pub async fn create_environment(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: CreateEnvironmentRequest,
+) -> RpcResult<CreateEnvironmentResponse> {
+    auth.require(&Capability::Write {
+        resource: "realms".to_string(),
+    })
+    .map_err(api_error)?;
+
+    let name = req.name.trim().to_ascii_lowercase();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            "name must consist of letters, digits, '-' or '_'".to_string(),
+        ));
+    }
+    if req.cbdc.is_some() || req.axioms_ecl.is_some() {
+        return Err(RpcError::new(
+            RpcErrorCode::Unimplemented,
+            "cbdc and axioms_ecl are not supported yet".to_string(),
+        ));
+    }
+
+    let parent = req.parent.map(|p| p.id).or_else(|| root_realm(&state));
+    let (id, parent_min_trust) = match &parent {
+        Some(parent) => {
+            auth.require_realm(parent).map_err(api_error)?;
+            let snapshot = snapshot(&state, parent)?;
+            (format!("{}:{}", parent, name), snapshot.min_trust)
+        }
+        None => (name, 0.0),
+    };
+    if state.unified_state.peer.realm.get_realm(&id).is_some() {
+        return Err(api_error(ApiError::Conflict(format!(
+            "environment {} already exists",
+            id
+        ))));
+    }
+
+    let governance =
+        GovernanceType::try_from(req.governance).unwrap_or(GovernanceType::Unspecified);
+    state.coordinator.integrator().on_realm_registered(
+        &id,
+        parent_min_trust,
+        governance_str(governance),
+    );
+    if let Some(realm) = state
+        .unified_state
+        .peer
+        .realm
+        .realms
+        .read()
+        .unwrap()
+        .get(&id)
+    {
+        realm.add_admin_by_id(auth.id, None);
+    }
+    // Per Governance ausgeführte Konfiguration eines früher registrierten Realms
+    if let Err(e) = state.governance.restore_realm_config(&id) {
+        tracing::warn!(realm = %id, error = %e, "Governance config restore failed");
+    }
+    log_membership(&state, &id, &auth, MembershipAction::Joined);
+
+    Ok(CreateEnvironmentResponse {
+        bootstrap: Some(bootstrap(&state, &id, &auth)),
+        id: Some(realm_id(&id)),
+        success: true,
+        error: None,
+    })
+}
+
+pub async fn join_environment(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: JoinEnvironmentRequest,
+) -> RpcResult<JoinEnvironmentResponse> {
+    let id = required(req.environment)?;
+    auth.require_realm(&id).map_err(api_error)?;
+    snapshot(&state, &id)?;
+
+    let (banned, member) = {
+        let realms = state.unified_state.peer.realm.realms.read().unwrap();
+        let realm = realms.get(&id);
+        (
+            realm.is_some_and(|r| r.is_banned_by_id(&auth.id)),
+            realm.is_some_and(|r| r.is_member_by_id(&auth.id)),
+        )
+    };
+    if banned {
+        return Ok(JoinEnvironmentResponse {
+            success: false,
+            status: JoinStatus::Rejected as i32,
+            error: Some("identity is banned from this environment".to_string()),
+        });
+    }
+    if !member {
+        log_membership(&state, &id, &auth, MembershipAction::Joined);
+    }
+
+    Ok(JoinEnvironmentResponse {
+        success: true,
+        status: JoinStatus::Approved as i32,
+        error: None,
+    })
+}
+
+pub async fn leave_environment(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: LeaveEnvironmentRequest,
+) -> RpcResult<LeaveEnvironmentResponse> {
+    let id = required(req.environment)?;
+    snapshot(&state, &id)?;
+
+    let member = state
+        .unified_state
+        .peer
+        .realm
+        .realms
+        .read()
+        .unwrap()
+        .get(&id)
+        .is_some_and(|r| r.is_member_by_id(&auth.id));
+    if !member {
+        return Ok(LeaveEnvironmentResponse {
+            success: false,
+            error: Some("not a member of this environment".to_string()),
+        });
+    }
+    log_membership(&state, &id, &auth, MembershipAction::Left);
+
+    Ok(LeaveEnvironmentResponse {
+        success: true,
+        error: None,
+    })
+}
+
+pub async fn get_environment_info(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    req: GetEnvironmentInfoRequest,
+) -> RpcResult<GetEnvironmentInfoResponse> {
+    let id = required(req.environment)?;
+    let realm = snapshot(&state, &id)?;
+    let ids = realm_ids(&state);
+    let root = root_realm(&state);
+
+    Ok(GetEnvironmentInfoResponse {
+        id: Some(realm_id(&id)),
+        name: name_of(&id),
+        r#type: environment_type(&id, &ids, root.as_deref()) as i32,
+        parent: parent_of(&id, &ids, root.as_deref()).map(|p| realm_id(&p)),
+        description: String::new(),
+        governance: governance(&realm.governance_type) as i32,
+        member_count: realm.member_count as i32,
+        axiom_count: realm.active_rules.len() as i32,
+        local_axioms: realm.active_rules,
+        cbdc: None,
+        min_trust_required: Some(min_trust(realm.min_trust)),
+        required_credentials: Vec::new(),
+        created_at: Some(timestamp(realm.created_at as i64)),
+    })
+}
+
+pub async fn switch_environment(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: SwitchEnvironmentRequest,
+) -> RpcResult<SwitchEnvironmentResponse> {
+    let id = required(req.environment)?;
+    snapshot(&state, &id)?;
+
+    let member = state
+        .unified_state
+        .peer
+        .realm
+        .realms
+        .read()
+        .unwrap()
+        .get(&id)
+        .is_some_and(|r| r.is_member_by_id(&auth.id));
+    if !member {
+        return Ok(SwitchEnvironmentResponse {
+            success: false,
+            active_environment: None,
+            error: Some("not a member of this environment".to_string()),
+        });
+    }
+
+    Ok(SwitchEnvironmentResponse {
+        success: true,
+        active_environment: Some(realm_id(&id)),
+        error: None,
+    })
+}
+
+pub async fn get_bootstrap_status(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: GetBootstrapStatusRequest,
+) -> RpcResult<GetBootstrapStatusResponse> {
+    let id = required(req.environment)?;
+    snapshot(&state, &id)?;
+
+    Ok(GetBootstrapStatusResponse {
+        status: Some(bootstrap(&state, &id, &auth)),
+    })
+}
@@ -0,0 +1,79 @@
+//! HealthService – Liveness und Readiness
+//!
+//! Connect-Pendant zu `/api/v1/health` und `/api/v1/ready`.
+
+use std::time::Instant;
+
+use axum::extract::State;
+use axum_connect::prelude::*;
+
+use crate::api::proto::{
+    check_response::ServingStatus, CheckRequest, CheckResponse, ReadyRequest, ReadyResponse,
+    ServiceStatus,
+};
+use crate::server::AppState;
+
+fn service_status(healthy: bool, message: &str, latency_ms: i64) -> Option<ServiceStatus> {
+    Some(ServiceStatus {
+        healthy,
+        message: message.to_string(),
+        latency_ms,
+    })
+}
+
+/// Liveness: der Prozess antwortet
+pub async fn check(_req: CheckRequest) -> RpcResult<CheckResponse> {
+    Ok(CheckResponse {
+        status: ServingStatus::Serving as i32,
+    })
+}
+
+/// Readiness: Keyspace, Content-Store und Session-Signatur sind nutzbar
+pub async fn ready(State(state): State<AppState>, _req: ReadyRequest) -> RpcResult<ReadyResponse> {
+    let start = Instant::now();
+    let database_ok = state.storage.ping().await.is_ok();
+    let database_latency = start.elapsed().as_millis() as i64;
+
+    let start = Instant::now();
+    let storage_ok = state.storage.content.is_healthy();
+    let storage_latency = start.elapsed().as_millis() as i64;
+
+    let start = Instant::now();
+    let auth_ok = state.storage.sessions.signing_key().is_ok();
+    let auth_latency = start.elapsed().as_millis() as i64;
+
+    let ready = database_ok && storage_ok && auth_ok;
+
+    Ok(ReadyResponse {
+        ready,
+        database: service_status(
+            database_ok,
+            if database_ok {
+                "decentralized"
+            } else {
+                "unavailable"
+            },
+            database_latency,
+        ),
+        // Kein externer Cache – alles liegt im Prozess
+        cache: service_status(true, "in-process", 0),
+        auth: service_status(
+            auth_ok,
+            if auth_ok {
+                "session keys loaded"
+            } else {
+                "signing key unavailable"
+            },
+            auth_latency,
+        ),
+        storage: service_status(
+            storage_ok,
+            if storage_ok {
+                "content store healthy"
+            } else {
+                "content store degraded"
+            },
+            storage_latency,
+        ),
+    })
+}
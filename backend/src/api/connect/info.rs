@@ -0,0 +1,36 @@
+//! InfoService – öffentliche Konfiguration für Clients
+
+use axum::extract::State;
+use axum_connect::prelude::*;
+
+use crate::api::proto::{AuthConfig, FeatureFlags, GetInfoRequest, GetInfoResponse, UrlConfig};
+use crate::config::VERSION;
+use crate::server::AppState;
+
+/// Version, Umgebung, Auth-Parameter, URLs und Feature-Flags
+pub async fn get_info(
+    State(state): State<AppState>,
+    _req: GetInfoRequest,
+) -> RpcResult<GetInfoResponse> {
+    let config = &state.config;
+    let app = &config.application;
+
+    Ok(GetInfoResponse {
+        version: VERSION.to_string(),
+        environment: app.environment.as_str().to_string(),
+        auth: Some(AuthConfig {
+            issuer: config.auth.issuer.clone(),
+            client_id: config.auth.audience.clone(),
+        }),
+        urls: Some(UrlConfig {
+            console: app.console_url.clone(),
+            platform: app.platform_url.clone(),
+            docs: app.docs_url.clone(),
+            api: app.api_url.clone(),
+        }),
+        features: Some(FeatureFlags {
+            registration: config.features.registration,
+            social_login: config.features.social_login,
+        }),
+    })
+}
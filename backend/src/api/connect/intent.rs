@@ -0,0 +1,553 @@
+//! IntentService – Intents einreichen, auflösen, simulieren und verfolgen
+//!
+//! Ziele werden in natürlicher Sprache für den Aufrufer geparst und im
+//! [`IntentStore`](crate::local::IntentStore) abgelegt. `ResolveIntent`
+//! komponiert die Saga und reicht sie beim Executor ein; danach folgt der
+//! Intent-Status der Saga.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use axum::extract::State;
+use axum_connect::pbjson_types::{Struct, Value};
+use axum_connect::prelude::*;
+
+use super::{api_error, internal, paginate, timestamp};
+use crate::api::proto::{
+    Budget as ProtoBudget, CancelIntentRequest, CancelIntentResponse, ChainType,
+    CostEstimate as ProtoCost, DependencyEdge, DependencyGraph, DependencyNode,
+    GetIntentStatusRequest, GetIntentStatusResponse, Intent as ProtoIntent, IntentState,
+    IntentSummary, ListIntentsRequest, ListIntentsResponse, RealmCrossing as ProtoCrossing,
+    RealmId as ProtoRealmId, ResolveIntentRequest, ResolveIntentResponse, SagaPlan, SagaStepPlan,
+    SimulateIntentRequest, SimulateIntentResponse, SimulationResult, SimulationStep,
+    SimulationWarning as ProtoWarning, SubmitIntentRequest, SubmitIntentResponse,
+    TrustDampeningMatrix as ProtoDampeningMatrix,
+};
+use crate::api::v1::auth::AuthContext;
+use crate::domain::{realm_id_from_name, Cost, Saga, SagaAction, SagaStatus, UniversalId};
+use crate::error::ApiError;
+use crate::local::{IntentPhase, IntentRecord, SagaPhase};
+use crate::peer::saga_executor::action_name;
+use crate::peer::saga_simulator::{BalanceChange, SimulationOutcome};
+use crate::peer::{IntentParser, SagaComposer};
+use crate::server::AppState;
+
+// ============================================================================
+// Mapping
+// ============================================================================
+
+fn cost_estimate(cost: &Cost, asset: &str) -> ProtoCost {
+    ProtoCost {
+        amount: cost.gas.to_string(),
+        asset: if asset.is_empty() { "gas" } else { asset }.to_string(),
+        fee_estimate: cost.mana as f64,
+        fee_asset: "mana".to_string(),
+    }
+}
+
+fn realm_ref(id: &UniversalId) -> Option<ProtoRealmId> {
+    Some(ProtoRealmId { id: id.to_hex() })
+}
+
+/// Geschätzte Dauer: `WaitFor` mit Timeout, sonst eine Sekunde pro Schritt
+fn estimated_duration(saga: &Saga) -> i32 {
+    saga.steps
+        .iter()
+        .map(|step| match &step.action {
+            SagaAction::WaitFor {
+                timeout_seconds, ..
+            } if *timeout_seconds > 0 => *timeout_seconds,
+            _ => 1,
+        })
+        .sum::<u64>()
+        .min(i32::MAX as u64) as i32
+}
+
+fn plan(state: &AppState, saga: &Saga, asset: &str) -> SagaPlan {
+    let matrix = state
+        .gateway
+        .as_ref()
+        .and_then(|g| g.dampening_matrix())
+        .map(|m| ProtoDampeningMatrix {
+            values: m.data.iter().flatten().map(|v| *v as f64).collect(),
+        });
+
+    SagaPlan {
+        steps: saga
+            .steps
+            .iter()
+            .map(|step| SagaStepPlan {
+                step_number: step.index as i32,
+                chain: ChainType::Erynoa as i32,
+                action: action_name(&step.action).to_string(),
+                description: step.description.clone(),
+                cost: Some(cost_estimate(&step.expected_cost, asset)),
+                requires_approval: false,
+            })
+            .collect(),
+        estimated_duration_seconds: estimated_duration(saga),
+        realm_crossings: saga
+            .steps
+            .iter()
+            .filter_map(|step| step.realm_crossing.as_ref())
+            .map(|crossing| ProtoCrossing {
+                from: realm_ref(&crossing.from),
+                to: realm_ref(&crossing.to),
+                matrix: matrix.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn planned_cost(saga: &Saga) -> Cost {
+    saga.steps
+        .iter()
+        .fold(Cost::ZERO, |acc, step| acc.seq(step.expected_cost))
+}
+
+fn dependency_graph(saga: &Saga) -> DependencyGraph {
+    let node_id = |idx: usize| format!("{}:{}", saga.id.to_hex(), idx);
+    DependencyGraph {
+        nodes: saga
+            .steps
+            .iter()
+            .map(|step| DependencyNode {
+                id: node_id(step.index),
+                action: action_name(&step.action).to_string(),
+                chain: ChainType::Erynoa as i32,
+            })
+            .collect(),
+        edges: saga
+            .steps
+            .iter()
+            .flat_map(|step| {
+                step.dependencies.iter().map(move |dep| DependencyEdge {
+                    from_id: node_id(*dep),
+                    to_id: node_id(step.index),
+                    dependency_type: "sequential".to_string(),
+                })
+            })
+            .collect(),
+    }
+}
+
+fn balances(changes: &[BalanceChange], after: bool) -> Struct {
+    changes
+        .iter()
+        .map(|c| {
+            let amount = if after { c.after } else { c.before };
+            (
+                format!("{}/{}", c.holder.to_hex(), c.asset_type),
+                Value::from(amount as f64),
+            )
+        })
+        .collect()
+}
+
+/// Intent-Status aus Phase und ggf. Saga-Zustand
+fn intent_state(state: &AppState, record: &IntentRecord) -> RpcResult<IntentState> {
+    Ok(match record.phase {
+        IntentPhase::Pending => IntentState::Pending,
+        IntentPhase::Cancelled => IntentState::Cancelled,
+        IntentPhase::Failed => IntentState::Failed,
+        IntentPhase::Resolved => {
+            let saga = match record.saga_id {
+                Some(id) => state.storage.sagas.get(&id).map_err(internal)?,
+                None => None,
+            };
+            match saga {
+                None => IntentState::Resolving,
+                Some(saga) => match saga.phase {
+                    SagaPhase::Submitted => IntentState::Resolved,
+                    SagaPhase::Running | SagaPhase::Compensating => IntentState::Executing,
+                    SagaPhase::Finished => match saga.saga.status {
+                        SagaStatus::Completed => IntentState::Completed,
+                        SagaStatus::Cancelled => IntentState::Cancelled,
+                        _ => IntentState::Failed,
+                    },
+                },
+            }
+        }
+    })
+}
+
+fn parse_intent_id(intent_id: &str) -> RpcResult<UniversalId> {
+    UniversalId::from_hex(intent_id).map_err(|_| {
+        RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!("invalid intent id: {}", intent_id),
+        )
+    })
+}
+
+/// Lädt einen Intent des Aufrufers
+fn load(state: &AppState, auth: &AuthContext, intent_id: &str) -> RpcResult<IntentRecord> {
+    let id = parse_intent_id(intent_id)?;
+    let record = state
+        .storage
+        .intents
+        .get(&id)
+        .map_err(internal)?
+        .ok_or_else(|| api_error(ApiError::NotFound(format!("intent {}", intent_id))))?;
+    if record.author != auth.did {
+        return Err(api_error(ApiError::Forbidden));
+    }
+    Ok(record)
+}
+
+fn compose(record: &IntentRecord) -> RpcResult<Saga> {
+    SagaComposer::default()
+        .compose(&record.intent)
+        .map_err(|e| api_error(e.into()))
+}
+
+fn save(state: &AppState, record: &mut IntentRecord) -> RpcResult<()> {
+    record.updated_at = chrono::Utc::now().timestamp();
+    state.storage.intents.put(record).map_err(internal)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Parst das Ziel für den Aufrufer und liefert einen geschätzten Plan
+pub async fn submit_intent(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: SubmitIntentRequest,
+) -> RpcResult<SubmitIntentResponse> {
+    if let Some(realm) = &req.target_realm {
+        auth.require_realm(&realm.id).map_err(api_error)?;
+    }
+
+    let started = Instant::now();
+    let metrics = &state.unified_state.peer.intent;
+    let parsed = IntentParser::default()
+        .with_alias_resolver(std::sync::Arc::new(state.storage.identities.clone()))
+        .parse_natural_for(auth.id, &req.goal);
+    let elapsed_us = started.elapsed().as_micros() as u64;
+    let mut intent = match parsed {
+        Ok(intent) => {
+            metrics.parsed(true, intent.goal.type_tag(), elapsed_us);
+            intent
+        }
+        Err(e) => {
+            metrics.parsed(false, "invalid", elapsed_us);
+            return Err(api_error(e.into()));
+        }
+    };
+
+    // Intent-IDs hängen nur von Quelle, Zieltyp und Realm ab – pro
+    // Einreichung eindeutig machen, damit auch die Saga-ID eindeutig ist
+    let nonce = uuid::Uuid::new_v4();
+    intent.id = UniversalId::new(
+        UniversalId::TAG_SAGA,
+        1,
+        &[intent.id.as_bytes().as_slice(), nonce.as_bytes()].concat(),
+    );
+    if let Some(realm) = &req.target_realm {
+        intent.context_realm = realm_id_from_name(&realm.id);
+    }
+    if let Some(timeout) = req.timeout_seconds.filter(|t| *t > 0) {
+        intent.timeout_seconds = timeout as u64;
+    }
+    let asset = req
+        .budget
+        .as_ref()
+        .map(|b| b.asset.clone())
+        .unwrap_or_default();
+    if let Some(budget) = &req.budget {
+        if !budget.asset.is_empty() {
+            intent.budget.payment_asset = budget.asset.clone();
+        }
+        if !budget.amount.is_empty() {
+            intent.budget.reserved_amount = budget.amount.parse().map_err(|_| {
+                RpcError::new(
+                    RpcErrorCode::InvalidArgument,
+                    format!("invalid budget amount: {}", budget.amount),
+                )
+            })?;
+        }
+    }
+
+    let mut record = IntentRecord::new(intent, req.goal.clone(), auth.did.clone());
+    record.target_realm = req.target_realm.map(|r| r.id);
+    record.metadata = req.metadata.into_iter().collect();
+    if let Some(slippage) = req.slippage_percent {
+        record
+            .metadata
+            .insert("slippage_percent".to_string(), slippage.to_string());
+    }
+
+    let saga = compose(&record)?;
+    state.storage.intents.put(&record).map_err(internal)?;
+
+    Ok(SubmitIntentResponse {
+        intent_id: record.id().to_hex(),
+        state: IntentState::Pending as i32,
+        estimated_plan: Some(plan(&state, &saga, &asset)),
+        estimated_cost: Some(cost_estimate(&planned_cost(&saga), &asset)),
+        required_approvals: Vec::new(),
+    })
+}
+
+/// Komponiert die Saga und reicht sie beim Executor ein (nicht gestartet)
+pub async fn resolve_intent(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: ResolveIntentRequest,
+) -> RpcResult<ResolveIntentResponse> {
+    let mut record = load(&state, &auth, &req.intent_id)?;
+    let asset = record.intent.budget.payment_asset.clone();
+
+    match record.phase {
+        IntentPhase::Pending => {}
+        IntentPhase::Resolved => {
+            let saga = match record.saga_id {
+                Some(id) => state.storage.sagas.get(&id).map_err(internal)?,
+                None => None,
+            };
+            if let Some(saga) = saga {
+                return Ok(ResolveIntentResponse {
+                    intent_id: req.intent_id,
+                    saga_id: saga.id().to_hex(),
+                    plan: Some(plan(&state, &saga.saga, &asset)),
+                    dependency_graph: Some(dependency_graph(&saga.saga)),
+                });
+            }
+        }
+        IntentPhase::Cancelled | IntentPhase::Failed => {
+            return Err(api_error(ApiError::InvalidState(format!(
+                "intent {} is {:?}",
+                req.intent_id, record.phase
+            ))));
+        }
+    }
+
+    let saga = match compose(&record) {
+        Ok(saga) => saga,
+        Err(e) => {
+            record.phase = IntentPhase::Failed;
+            record.error = Some(e.message.clone());
+            save(&state, &mut record)?;
+            return Err(e);
+        }
+    };
+    let submitted = state
+        .saga_executor
+        .submit(saga)
+        .map_err(|e| RpcError::new(RpcErrorCode::InvalidArgument, e.to_string()))?;
+
+    record.phase = IntentPhase::Resolved;
+    record.saga_id = Some(submitted.id());
+    save(&state, &mut record)?;
+
+    Ok(ResolveIntentResponse {
+        intent_id: req.intent_id,
+        saga_id: submitted.id().to_hex(),
+        plan: Some(plan(&state, &submitted.saga, &asset)),
+        dependency_graph: Some(dependency_graph(&submitted.saga)),
+    })
+}
+
+/// Dry-Run gegen den aktuellen Ledger- und Trust-Zustand
+pub async fn simulate_intent(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: SimulateIntentRequest,
+) -> RpcResult<SimulateIntentResponse> {
+    let record = load(&state, &auth, &req.intent_id)?;
+    let asset = record.intent.budget.payment_asset.clone();
+    let report = state
+        .saga_executor
+        .simulator()
+        .simulate_intent(&record.intent)
+        .map_err(|e| api_error(e.into()))?;
+
+    let steps = report
+        .steps
+        .iter()
+        .map(|step| SimulationStep {
+            step_number: step.index as i32,
+            action: step.action.to_string(),
+            result: match step.outcome {
+                SimulationOutcome::Success => SimulationResult::Success,
+                SimulationOutcome::Failure => SimulationResult::Failure,
+                SimulationOutcome::Uncertain => SimulationResult::Uncertain,
+                SimulationOutcome::Skipped => SimulationResult::Unspecified,
+            } as i32,
+            state_before: req.verbose.then(|| balances(&step.balance_changes, false)),
+            state_after: req.verbose.then(|| balances(&step.balance_changes, true)),
+        })
+        .collect();
+
+    let step_id = |step: Option<usize>| {
+        step.map(|i| format!("{}:{}", report.saga.id.to_hex(), i))
+            .unwrap_or_default()
+    };
+    let mut warnings: Vec<ProtoWarning> = report
+        .violations
+        .iter()
+        .map(|v| ProtoWarning {
+            code: v.constraint.to_string(),
+            message: v.message.clone(),
+            step_id: step_id(v.step),
+        })
+        .collect();
+    warnings.extend(report.warnings.iter().map(|w| ProtoWarning {
+        code: w.code.to_string(),
+        message: w.message.clone(),
+        step_id: step_id(w.step),
+    }));
+    if let Some(reason) = &report.failure_reason {
+        warnings.push(ProtoWarning {
+            code: "failure".to_string(),
+            message: reason.clone(),
+            step_id: step_id(report.failed_at),
+        });
+    }
+
+    let total = if req.estimate_fees {
+        &report.planned_cost
+    } else {
+        &report.total_cost
+    };
+    Ok(SimulateIntentResponse {
+        plan: Some(plan(&state, &report.saga, &asset)),
+        steps,
+        total_cost: Some(cost_estimate(total, &asset)),
+        would_succeed: report.would_succeed,
+        warnings,
+    })
+}
+
+pub async fn get_intent_status(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: GetIntentStatusRequest,
+) -> RpcResult<GetIntentStatusResponse> {
+    let record = load(&state, &auth, &req.intent_id)?;
+    let intent = &record.intent;
+
+    let constraints: HashMap<String, String> = intent
+        .constraints
+        .iter()
+        .map(|c| {
+            (
+                c.type_tag().to_string(),
+                serde_json::to_string(c).unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    Ok(GetIntentStatusResponse {
+        intent_id: req.intent_id,
+        state: intent_state(&state, &record)? as i32,
+        saga_id: record.saga_id.map(|id| id.to_hex()),
+        created_at: Some(timestamp(record.created_at)),
+        updated_at: Some(timestamp(record.updated_at)),
+        error_message: record.error.clone(),
+        intent: Some(ProtoIntent {
+            id: intent.id.to_hex(),
+            author: None,
+            goal: record.goal.clone(),
+            budget: Some(ProtoBudget {
+                amount: intent.budget.reserved_amount.to_string(),
+                asset: intent.budget.payment_asset.clone(),
+                chain: ChainType::Erynoa as i32,
+            }),
+            target_realm: Some(ProtoRealmId {
+                id: record
+                    .target_realm
+                    .clone()
+                    .unwrap_or_else(|| intent.context_realm.to_hex()),
+            }),
+            constraints,
+            created_at: Some(timestamp(record.created_at)),
+        }),
+    })
+}
+
+/// Intents des Aufrufers, älteste zuerst
+pub async fn list_intents(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: ListIntentsRequest,
+) -> RpcResult<ListIntentsResponse> {
+    let records = state
+        .storage
+        .intents
+        .list_by_author(&auth.did)
+        .map_err(internal)?;
+
+    let filter = req
+        .state_filter
+        .and_then(|f| IntentState::try_from(f).ok())
+        .filter(|f| *f != IntentState::Unspecified);
+    let mut summaries = Vec::with_capacity(records.len());
+    for record in &records {
+        let state = intent_state(&state, record)?;
+        if filter.is_some_and(|f| f != state) {
+            continue;
+        }
+        summaries.push(IntentSummary {
+            intent_id: record.id().to_hex(),
+            goal: record.goal.clone(),
+            state: state as i32,
+            created_at: Some(timestamp(record.created_at)),
+        });
+    }
+    let total_count = summaries.len() as i32;
+
+    let (intents, next_cursor) = paginate(
+        summaries,
+        |s| s.intent_id.clone(),
+        req.cursor.as_deref(),
+        req.limit,
+    );
+    Ok(ListIntentsResponse {
+        intents,
+        next_cursor,
+        total_count,
+    })
+}
+
+/// Bricht einen Intent ab, solange seine Saga noch nicht gestartet ist
+pub async fn cancel_intent(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: CancelIntentRequest,
+) -> RpcResult<CancelIntentResponse> {
+    let mut record = load(&state, &auth, &req.intent_id)?;
+    let current = intent_state(&state, &record)?;
+
+    match current {
+        IntentState::Pending => {}
+        IntentState::Resolved => {
+            if let Some(saga_id) = record.saga_id {
+                state
+                    .saga_executor
+                    .cancel(&saga_id, req.reason.clone())
+                    .await
+                    .map_err(|e| RpcError::new(RpcErrorCode::FailedPrecondition, e.to_string()))?;
+            }
+        }
+        other => {
+            return Ok(CancelIntentResponse {
+                success: false,
+                error: Some(format!(
+                    "intent cannot be cancelled in state {}",
+                    other.as_str_name()
+                )),
+            });
+        }
+    }
+
+    record.phase = IntentPhase::Cancelled;
+    record.error = req.reason;
+    save(&state, &mut record)?;
+    Ok(CancelIntentResponse {
+        success: true,
+        error: None,
+    })
+}
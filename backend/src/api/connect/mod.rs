@@ -0,0 +1,209 @@
+//! Connect-RPC Services
+//!
+//! Die in `proto/erynoa/v1` definierten Services, erreichbar unter
+//! `/api/v1/connect/erynoa.v1.<Service>/<Method>` (Connect-Protokoll, JSON und
+//! Binary). Parallel zu den REST-Endpoints auf demselben [`AppState`].
+//!
+//! Authentifizierung wie bei REST über `Authorization: Bearer <token>`:
+//! [`AuthContext`] ist auch als Connect-Extractor nutzbar, Fehler werden auf
+//! Connect-Codes (`unauthenticated`, `permission_denied`, …) abgebildet.
+
+mod environment;
+mod health;
+mod info;
+mod intent;
+mod peer;
+mod saga;
+mod storage;
+mod user;
+
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::Router;
+use axum_connect::pbjson_types::Timestamp;
+use axum_connect::prelude::*;
+use axum_connect::prost::Message;
+
+use super::proto::{
+    EnvironmentService, HealthService, InfoService, IntentService, PeerService, SagaService,
+    StorageService, UserService,
+};
+use super::v1::auth::AuthContext;
+use crate::error::ApiError;
+use crate::server::AppState;
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 500;
+
+/// Router mit allen Connect-Services
+pub fn connect_router() -> Router<AppState> {
+    Router::new()
+        // HealthService / InfoService
+        .rpc(HealthService::check(health::check))
+        .rpc(HealthService::ready(health::ready))
+        .rpc(InfoService::get_info(info::get_info))
+        // UserService
+        .rpc(UserService::list(user::list))
+        .rpc(UserService::get(user::get))
+        .rpc(UserService::get_current(user::get_current))
+        .rpc(UserService::create(user::create))
+        .rpc(UserService::update(user::update))
+        .rpc(UserService::delete(user::delete))
+        // StorageService
+        .rpc(StorageService::upload(storage::upload))
+        .rpc(StorageService::list(storage::list))
+        .rpc(StorageService::delete(storage::delete))
+        .rpc(StorageService::head(storage::head))
+        .rpc(StorageService::get_presigned_upload_url(
+            storage::get_presigned_upload_url,
+        ))
+        .rpc(StorageService::get_presigned_download_url(
+            storage::get_presigned_download_url,
+        ))
+        .rpc(StorageService::list_buckets(storage::list_buckets))
+        .rpc(StorageService::create_bucket(storage::create_bucket))
+        .rpc(StorageService::delete_bucket(storage::delete_bucket))
+        // PeerService
+        .rpc(PeerService::get_status(peer::get_status))
+        .rpc(PeerService::get_info(peer::get_info))
+        .rpc(PeerService::list_derived_keys(peer::list_derived_keys))
+        .rpc(PeerService::derive_key(peer::derive_key))
+        .rpc(PeerService::evaluate_gateway(peer::evaluate_gateway))
+        .rpc(PeerService::start_peer(peer::start_peer))
+        .rpc(PeerService::stop_peer(peer::stop_peer))
+        // IntentService
+        .rpc(IntentService::submit_intent(intent::submit_intent))
+        .rpc(IntentService::resolve_intent(intent::resolve_intent))
+        .rpc(IntentService::simulate_intent(intent::simulate_intent))
+        .rpc(IntentService::get_intent_status(intent::get_intent_status))
+        .rpc(IntentService::list_intents(intent::list_intents))
+        .rpc(IntentService::cancel_intent(intent::cancel_intent))
+        // SagaService
+        .rpc(SagaService::list_sagas(saga::list_sagas))
+        .rpc(SagaService::get_saga_status(saga::get_saga_status))
+        .rpc(SagaService::execute_saga(saga::execute_saga))
+        .rpc(SagaService::cancel_saga(saga::cancel_saga))
+        .rpc(SagaService::rollback_saga(saga::rollback_saga))
+        .rpc(SagaService::get_saga_history(saga::get_saga_history))
+        .rpc(SagaService::stream_saga_updates(saga::stream_saga_updates))
+        // EnvironmentService
+        .rpc(EnvironmentService::list_environments(
+            environment::list_environments,
+        ))
+        .rpc(EnvironmentService::get_environment_tree(
+            environment::get_environment_tree,
+        ))
+        .rpc(EnvironmentService::create_environment(
+            environment::create_environment,
+        ))
+        .rpc(EnvironmentService::join_environment(
+            environment::join_environment,
+        ))
+        .rpc(EnvironmentService::leave_environment(
+            environment::leave_environment,
+        ))
+        .rpc(EnvironmentService::get_environment_info(
+            environment::get_environment_info,
+        ))
+        .rpc(EnvironmentService::switch_environment(
+            environment::switch_environment,
+        ))
+        .rpc(EnvironmentService::get_bootstrap_status(
+            environment::get_bootstrap_status,
+        ))
+}
+
+// ============================================================================
+// Gemeinsame Helfer
+// ============================================================================
+
+/// Bildet einen [`ApiError`] auf den passenden Connect-Code ab
+///
+/// Interne Fehler werden wie bei REST nur geloggt, nicht exponiert.
+fn api_error(e: ApiError) -> RpcError {
+    let code = match &e {
+        ApiError::Unauthorized(_) | ApiError::InvalidToken(_) => RpcErrorCode::Unauthenticated,
+        ApiError::Forbidden | ApiError::Gateway(_) | ApiError::Protection(_) => {
+            RpcErrorCode::PermissionDenied
+        }
+        ApiError::Validation(_)
+        | ApiError::BadRequest(_)
+        | ApiError::IntentParse(_)
+        | ApiError::SagaComposition(_)
+        | ApiError::DIDError(_) => RpcErrorCode::InvalidArgument,
+        ApiError::NotFound(_) => RpcErrorCode::NotFound,
+        ApiError::Conflict(_) => RpcErrorCode::AlreadyExists,
+        ApiError::InvalidState(_)
+        | ApiError::Event(_)
+        | ApiError::Trust(_)
+        | ApiError::Consensus(_) => RpcErrorCode::FailedPrecondition,
+        ApiError::NotSupported(_) => RpcErrorCode::Unimplemented,
+        ApiError::RateLimited { .. } => RpcErrorCode::ResourceExhausted,
+        ApiError::ServiceUnavailable(_) => RpcErrorCode::Unavailable,
+        ApiError::Storage(_) | ApiError::Internal(_) => {
+            tracing::error!(error = ?e, "Internal error in Connect handler");
+            return RpcError::new(
+                RpcErrorCode::Internal,
+                "An internal error occurred. Please try again later.".to_string(),
+            );
+        }
+    };
+    RpcError::new(code, e.to_string())
+}
+
+/// Storage-Fehler (`anyhow`) als `internal`
+fn internal(e: anyhow::Error) -> RpcError {
+    api_error(ApiError::Internal(e))
+}
+
+/// Unix-Sekunden als `google.protobuf.Timestamp`
+fn timestamp(seconds: i64) -> Timestamp {
+    Timestamp { seconds, nanos: 0 }
+}
+
+/// Cursor-Paginierung über die ID des letzten Eintrags
+fn paginate<T>(
+    items: Vec<T>,
+    id_of: impl Fn(&T) -> String,
+    cursor: Option<&str>,
+    limit: Option<i32>,
+) -> (Vec<T>, Option<String>) {
+    let limit = limit
+        .filter(|l| *l > 0)
+        .map(|l| (l as usize).min(MAX_PAGE_SIZE))
+        .unwrap_or(DEFAULT_PAGE_SIZE);
+    let start = cursor
+        .and_then(|c| items.iter().position(|item| id_of(item) == c))
+        .map(|pos| pos + 1)
+        .unwrap_or(0);
+
+    let has_more = items.len() > start + limit;
+    let page: Vec<T> = items.into_iter().skip(start).take(limit).collect();
+    let next_cursor = if has_more {
+        page.last().map(&id_of)
+    } else {
+        None
+    };
+    (page, next_cursor)
+}
+
+impl RpcIntoError for ApiError {
+    fn rpc_into_error(self) -> RpcError {
+        api_error(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<M> RpcFromRequestParts<M, AppState> for AuthContext
+where
+    M: Message,
+{
+    type Rejection = ApiError;
+
+    async fn rpc_from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        <AuthContext as FromRequestParts<AppState>>::from_request_parts(parts, state).await
+    }
+}
@@ -0,0 +1,528 @@
+//! PeerService – Status, Schlüsselableitung und Gateway-Auswertung des Peers
+//!
+//! Wallets werden aus der Root-DID des Peers abgeleitet (`IdentityState`);
+//! unterstützt ist derzeit nur die Erynoa-Chain mit Ed25519.
+
+use axum::extract::State;
+use axum_connect::prelude::*;
+
+use super::saga::record_state;
+use super::{api_error, timestamp};
+use crate::api::proto::{
+    ActiveSagaSummary, Algorithm, ChainType, ConnectedChain, DeriveKeyRequest, DeriveKeyResponse,
+    DerivedKey, DerivedWallet, Did as ProtoDid, EvaluateGatewayRequest, EvaluateGatewayResponse,
+    GatewayStatus, ListDerivedKeysRequest, ListDerivedKeysResponse, PeerCapabilities, PeerConfig,
+    PeerServiceGetInfoRequest, PeerServiceGetInfoResponse, PeerServiceGetStatusRequest,
+    PeerServiceGetStatusResponse, PeerState as ProtoPeerState, PredicateResult, StartPeerRequest,
+    StartPeerResponse, StopPeerRequest, StopPeerResponse,
+    TrustDampeningMatrix as ProtoDampeningMatrix, TrustVector6D as ProtoTrustVector,
+};
+use crate::api::v1::auth::AuthContext;
+use crate::config::VERSION;
+use crate::core::identity_types::WalletAddress;
+use crate::core::StateEvent;
+use crate::domain::{
+    realm_id_from_name, Capability, DIDNamespace, TrustDampeningMatrix, TrustVector6D, DID,
+};
+use crate::error::ApiError;
+use crate::server::AppState;
+
+/// CAIP-2-artige Chain-ID für Erynoa-Wallets
+const ERYNOA_CHAIN_ID: &str = "erynoa:mainnet";
+/// Derivation-Zweck für Wallet-Schlüssel (`m/44'/erynoa'/0'/wallet/<n>`)
+const WALLET_PURPOSE: &str = "wallet";
+const INTENT_TYPES: &[&str] = &[
+    "transfer", "attest", "delegate", "query", "create", "complex",
+];
+
+// ============================================================================
+// Mapping
+// ============================================================================
+
+fn chain_type(chain_id: &str) -> ChainType {
+    match chain_id {
+        c if c.starts_with("erynoa") => ChainType::Erynoa,
+        "eip155:1" => ChainType::Ethereum,
+        "eip155:137" => ChainType::Polygon,
+        "eip155:42161" => ChainType::Arbitrum,
+        "eip155:10" => ChainType::Optimism,
+        c if c.starts_with("iota") => ChainType::Iota,
+        c if c.starts_with("shimmer") => ChainType::Shimmer,
+        _ => ChainType::Unspecified,
+    }
+}
+
+fn proto_did(did: &DID) -> ProtoDid {
+    ProtoDid {
+        namespace: did.namespace.to_string(),
+        unique_id: did.id.to_hex(),
+        created_at: Some(timestamp((did.created_at.wall_time() / 1_000_000) as i64)),
+    }
+}
+
+fn proto_trust(trust: &TrustVector6D) -> ProtoTrustVector {
+    ProtoTrustVector {
+        reliability: trust.r as f64,
+        integrity: trust.i as f64,
+        competence: trust.c as f64,
+        prestige: trust.p as f64,
+        vigilance: trust.v as f64,
+        omega: trust.omega as f64,
+    }
+}
+
+fn proto_matrix(matrix: &TrustDampeningMatrix) -> ProtoDampeningMatrix {
+    ProtoDampeningMatrix {
+        values: matrix.data.iter().flatten().map(|v| *v as f64).collect(),
+    }
+}
+
+fn wallets(state: &AppState) -> Vec<WalletAddress> {
+    let wallets = state.unified_state.identity.wallets.read().unwrap();
+    let mut all: Vec<WalletAddress> = wallets.values().flatten().cloned().collect();
+    all.sort_by_key(|w| w.created_at);
+    all
+}
+
+/// Öffentlicher Schlüssel einer Erynoa-Wallet (Adresse = abgeleitete DID)
+fn wallet_public_key(wallet: &WalletAddress) -> String {
+    DID::parse(&wallet.address)
+        .map(|did| did.public_key_hex())
+        .unwrap_or_default()
+}
+
+fn derived_key(wallet: &WalletAddress) -> DerivedKey {
+    DerivedKey {
+        chain: chain_type(&wallet.chain_id) as i32,
+        public_key: wallet_public_key(wallet),
+        address: wallet.address.clone(),
+        derivation_path: wallet.derivation_path.clone(),
+        algorithm: Algorithm::Ed25519 as i32,
+        derived_at: Some(timestamp((wallet.created_at / 1000) as i64)),
+    }
+}
+
+/// Parst `m/44'/erynoa'/0'/<purpose>/<index>`
+fn parse_path(path: &str) -> RpcResult<(String, u32)> {
+    let invalid = || {
+        RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!(
+                "invalid derivation path {} (expected m/44'/erynoa'/0'/<purpose>/<index>)",
+                path
+            ),
+        )
+    };
+    let rest = path.strip_prefix("m/44'/erynoa'/0'/").ok_or_else(invalid)?;
+    let (purpose, index) = rest.split_once('/').ok_or_else(invalid)?;
+    if purpose.is_empty() || purpose.contains('/') {
+        return Err(invalid());
+    }
+    Ok((purpose.to_string(), index.parse().map_err(|_| invalid())?))
+}
+
+#[cfg(feature = "p2p")]
+fn running_peer_id(state: &AppState) -> Option<String> {
+    state
+        .p2p_handle
+        .as_ref()
+        .filter(|h| !h.command_tx.is_closed())
+        .map(|h| h.peer_id.clone())
+}
+
+#[cfg(not(feature = "p2p"))]
+fn running_peer_id(_state: &AppState) -> Option<String> {
+    None
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn get_status(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    _req: PeerServiceGetStatusRequest,
+) -> RpcResult<PeerServiceGetStatusResponse> {
+    let peer_id = running_peer_id(&state);
+    let peer_state = if peer_id.is_some() {
+        ProtoPeerState::Running
+    } else {
+        ProtoPeerState::Stopped
+    };
+
+    let did = state
+        .unified_state
+        .identity
+        .root_did
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(proto_did);
+
+    let now = chrono::Utc::now().timestamp();
+    let started_at = state
+        .started_at
+        .map(|s| now - s.elapsed().as_secs() as i64)
+        .unwrap_or(now);
+
+    let records = state
+        .saga_executor
+        .list()
+        .map_err(|e| api_error(ApiError::Internal(anyhow::anyhow!(e.to_string()))))?;
+    let last_activity = records
+        .iter()
+        .map(|r| r.updated_at)
+        .max()
+        .unwrap_or(started_at)
+        .max(started_at);
+    let active_sagas = records
+        .iter()
+        .filter(|r| !r.is_finished())
+        .map(|r| ActiveSagaSummary {
+            saga_id: r.id().to_hex(),
+            state: record_state(r) as i32,
+            current_step: r.saga.current_step_index().unwrap_or(r.saga.steps.len()) as i32,
+            total_steps: r.saga.steps.len() as i32,
+        })
+        .collect();
+
+    let storage_ok = state.storage.ping().await.is_ok();
+
+    let realm_count = state.unified_state.peer.realm.realms.read().unwrap().len();
+    let gateway_snapshot = state.unified_state.peer.gateway.snapshot();
+
+    Ok(PeerServiceGetStatusResponse {
+        peer_id: peer_id.unwrap_or_default(),
+        did,
+        state: peer_state as i32,
+        connected_chains: vec![ConnectedChain {
+            chain: ChainType::Erynoa as i32,
+            endpoint: state.config.application.api_url.clone(),
+            healthy: storage_ok,
+            block_height: state.unified_state.event_log.snapshot().sequence as i64,
+            last_sync: Some(timestamp(now)),
+        }],
+        wallets: wallets(&state)
+            .iter()
+            .map(|w| DerivedWallet {
+                chain: chain_type(&w.chain_id) as i32,
+                address: w.address.clone(),
+                derivation_path: w.derivation_path.clone(),
+                algorithm: Algorithm::Ed25519 as i32,
+            })
+            .collect(),
+        gateway_status: Some(GatewayStatus {
+            active: state.gateway.is_some(),
+            registered_realms: realm_count.max(gateway_snapshot.registered_realms) as i32,
+            pending_crossings: state.unified_state.peer.realm.snapshot().active_crossings as i32,
+        }),
+        active_sagas,
+        started_at: Some(timestamp(started_at)),
+        last_activity: Some(timestamp(last_activity)),
+    })
+}
+
+pub async fn get_info(
+    State(state): State<AppState>,
+    _req: PeerServiceGetInfoRequest,
+) -> RpcResult<PeerServiceGetInfoResponse> {
+    Ok(PeerServiceGetInfoResponse {
+        version: VERSION.to_string(),
+        supported_chains: vec![ChainType::Erynoa as i32],
+        supported_algorithms: vec![Algorithm::Ed25519 as i32],
+        capabilities: Some(PeerCapabilities {
+            composer: true,
+            gateway: state.gateway.is_some(),
+            key_vault: false,
+            htlc_support: false,
+            streaming: true,
+            supported_intent_types: INTENT_TYPES.iter().map(|t| t.to_string()).collect(),
+        }),
+        config: Some(PeerConfig {
+            max_concurrent_sagas: 0,
+            default_timeout_seconds: 24 * 3600,
+            default_slippage_percent: 0.0,
+            default_environment: state
+                .unified_state
+                .peer
+                .realm
+                .root_realm_id
+                .read()
+                .unwrap()
+                .clone()
+                .unwrap_or_default(),
+        }),
+    })
+}
+
+pub async fn list_derived_keys(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    _req: ListDerivedKeysRequest,
+) -> RpcResult<ListDerivedKeysResponse> {
+    Ok(ListDerivedKeysResponse {
+        keys: wallets(&state).iter().map(derived_key).collect(),
+    })
+}
+
+/// Leitet eine Erynoa-Wallet aus der Root-DID ab (`execute:derive_key`)
+///
+/// Ohne Pfad wird der nächste freie Index unter `wallet` verwendet.
+pub async fn derive_key(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: DeriveKeyRequest,
+) -> RpcResult<DeriveKeyResponse> {
+    auth.require(&Capability::Execute {
+        action: "derive_key".to_string(),
+    })
+    .map_err(api_error)?;
+
+    match ChainType::try_from(req.chain).unwrap_or(ChainType::Unspecified) {
+        ChainType::Erynoa => {}
+        ChainType::Unspecified => {
+            return Err(RpcError::new(
+                RpcErrorCode::InvalidArgument,
+                "chain is required".to_string(),
+            ))
+        }
+        other => {
+            return Err(RpcError::new(
+                RpcErrorCode::Unimplemented,
+                format!(
+                    "key derivation for {} is not supported",
+                    other.as_str_name()
+                ),
+            ))
+        }
+    }
+    match req.algorithm.map(Algorithm::try_from) {
+        None | Some(Ok(Algorithm::Unspecified)) | Some(Ok(Algorithm::Ed25519)) => {}
+        _ => {
+            return Err(RpcError::new(
+                RpcErrorCode::Unimplemented,
+                "only Ed25519 keys are supported".to_string(),
+            ))
+        }
+    }
+
+    let identity = &state.unified_state.identity;
+    let root = identity.root_did.read().unwrap().clone().ok_or_else(|| {
+        RpcError::new(
+            RpcErrorCode::FailedPrecondition,
+            "peer identity is not bootstrapped".to_string(),
+        )
+    })?;
+
+    let existing = identity.get_wallets_for_chain(ERYNOA_CHAIN_ID);
+    let (purpose, index) = match req.path.as_deref().filter(|p| !p.is_empty()) {
+        Some(path) => parse_path(path)?,
+        None => (WALLET_PURPOSE.to_string(), existing.len() as u32),
+    };
+    let path = DID::derivation_path(&purpose, index);
+    if let Some(wallet) = existing.iter().find(|w| w.derivation_path == path) {
+        return Ok(DeriveKeyResponse {
+            key: Some(derived_key(wallet)),
+        });
+    }
+
+    let derived = DID::derive_custom(&root, DIDNamespace::Vault, &purpose, index);
+    let mut wallet = WalletAddress::new(ERYNOA_CHAIN_ID, derived.to_uri(), &path, root.id);
+    if existing.is_empty() {
+        wallet = wallet.as_primary();
+    }
+    identity
+        .add_wallet_address(wallet.clone())
+        .map_err(|e| RpcError::new(RpcErrorCode::FailedPrecondition, e.to_string()))?;
+    state.unified_state.log_and_apply(
+        StateEvent::WalletDerived {
+            did: root.id,
+            chain_id: wallet.chain_id.clone(),
+            address: wallet.address.clone(),
+            derivation_path: path,
+        },
+        vec![],
+    );
+
+    Ok(DeriveKeyResponse {
+        key: Some(derived_key(&wallet)),
+    })
+}
+
+/// Wertet ein Realm-Crossing über den GatewayGuard aus
+///
+/// Ohne `user` wird der Aufrufer geprüft.
+pub async fn evaluate_gateway(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: EvaluateGatewayRequest,
+) -> RpcResult<EvaluateGatewayResponse> {
+    let guard = state.gateway.as_ref().ok_or_else(|| {
+        api_error(ApiError::ServiceUnavailable(
+            "GatewayGuard not configured".to_string(),
+        ))
+    })?;
+
+    let did_uri = match &req.user {
+        Some(user) => format!("did:erynoa:{}:{}", user.namespace, user.unique_id),
+        None => auth.did.clone(),
+    };
+    let did = DID::parse(&did_uri)
+        .map_err(|e| RpcError::new(RpcErrorCode::InvalidArgument, e.to_string()))?;
+    let realm = |r: &Option<crate::api::proto::RealmId>, field: &str| {
+        r.as_ref()
+            .map(|r| realm_id_from_name(&r.id))
+            .ok_or_else(|| {
+                RpcError::new(
+                    RpcErrorCode::InvalidArgument,
+                    format!("{} is required", field),
+                )
+            })
+    };
+    let from = realm(&req.source_realm, "source_realm")?;
+    let to = realm(&req.target_realm, "target_realm")?;
+
+    let result = guard
+        .validate_crossing(&did, &from, &to)
+        .map_err(|e| RpcError::new(RpcErrorCode::NotFound, e.to_string()))?;
+
+    let failed = |prefix: &str| {
+        result
+            .violations
+            .iter()
+            .find(|v| v.starts_with(prefix))
+            .cloned()
+    };
+    let predicate = |name: &str, description: &str, prefix: &str| {
+        let failure_reason = failed(prefix);
+        PredicateResult {
+            name: name.to_string(),
+            satisfied: failure_reason.is_none(),
+            description: description.to_string(),
+            failure_reason,
+        }
+    };
+    let mut predicates = vec![
+        predicate(
+            "min_trust",
+            "Trust norm meets the realm minimum",
+            "Insufficient trust",
+        ),
+        predicate(
+            "required_credentials",
+            "All credentials required by the realm are present",
+            "Missing credential",
+        ),
+        predicate(
+            "ecl_entry_policy",
+            "ECL entry policy allows the crossing",
+            "ECL policy",
+        ),
+    ];
+    if !req.verbose {
+        predicates.retain(|p| !p.satisfied);
+    }
+
+    Ok(EvaluateGatewayResponse {
+        allowed: result.allowed,
+        predicates,
+        original_trust: Some(proto_trust(&result.original_trust)),
+        transformed_trust: Some(proto_trust(&result.dampened_trust)),
+        applied_matrix: guard.dampening_matrix().as_ref().map(proto_matrix),
+        denial_reason: result.violations.join("; "),
+    })
+}
+
+/// Der Swarm wird beim Serverstart gestartet; `StartPeer` meldet seinen Zustand
+pub async fn start_peer(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    _req: StartPeerRequest,
+) -> RpcResult<StartPeerResponse> {
+    auth.require(&Capability::All).map_err(api_error)?;
+    Ok(match running_peer_id(&state) {
+        Some(peer_id) => StartPeerResponse {
+            success: true,
+            peer_id,
+            error: None,
+        },
+        None => StartPeerResponse {
+            success: false,
+            peer_id: String::new(),
+            error: Some(
+                "P2P networking is not running; enable features.p2p_enabled and restart"
+                    .to_string(),
+            ),
+        },
+    })
+}
+
+/// Stoppt den Swarm; mit `force` werden laufende Sagas abgebrochen
+pub async fn stop_peer(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: StopPeerRequest,
+) -> RpcResult<StopPeerResponse> {
+    auth.require(&Capability::All).map_err(api_error)?;
+
+    let mut cancelled_sagas = 0;
+    if req.force {
+        let records = state
+            .saga_executor
+            .list()
+            .map_err(|e| api_error(ApiError::Internal(anyhow::anyhow!(e.to_string()))))?;
+        for record in records.iter().filter(|r| !r.is_finished()) {
+            if state
+                .saga_executor
+                .cancel(&record.id(), Some("peer stopped".to_string()))
+                .await
+                .is_ok()
+            {
+                cancelled_sagas += 1;
+            }
+        }
+    }
+
+    Ok(stop_swarm(&state, cancelled_sagas).await)
+}
+
+#[cfg(feature = "p2p")]
+async fn stop_swarm(state: &AppState, cancelled_sagas: i32) -> StopPeerResponse {
+    let Some(handle) = state
+        .p2p_handle
+        .as_ref()
+        .filter(|h| !h.command_tx.is_closed())
+    else {
+        return StopPeerResponse {
+            success: false,
+            cancelled_sagas,
+            error: Some("P2P networking is not running".to_string()),
+        };
+    };
+    match handle
+        .command_tx
+        .send(crate::peer::p2p::SwarmCommand::Stop)
+        .await
+    {
+        Ok(()) => StopPeerResponse {
+            success: true,
+            cancelled_sagas,
+            error: None,
+        },
+        Err(e) => StopPeerResponse {
+            success: false,
+            cancelled_sagas,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+async fn stop_swarm(_state: &AppState, cancelled_sagas: i32) -> StopPeerResponse {
+    StopPeerResponse {
+        success: false,
+        cancelled_sagas,
+        error: Some("P2P networking is not compiled in (feature `p2p`)".to_string()),
+    }
+}
@@ -0,0 +1,467 @@
+//! SagaService – Saga-Status, Ausführung, Abbruch und Live-Updates
+//!
+//! Dünne Abbildung von [`SagaExecutor`](crate::peer::SagaExecutor) und
+//! [`SagaRecord`] auf die Proto-Typen aus `peer.proto`. Ausführen, Abbrechen,
+//! Rollback und Live-Updates prüfen dieselben Rechte wie die REST-Endpoints
+//! ([`authorize_saga`]).
+
+use axum::extract::State;
+use axum_connect::futures::stream::{self, Stream, StreamExt};
+use axum_connect::pbjson_types::{Struct, Value};
+use axum_connect::prelude::*;
+use tokio::sync::broadcast::error::RecvError;
+
+use super::{api_error, paginate, timestamp};
+use crate::api::proto::{
+    CancelSagaRequest, CancelSagaResponse, ChainType, CompensationResult, CostEstimate,
+    ExecuteSagaRequest, ExecuteSagaResponse, GetSagaHistoryRequest, GetSagaHistoryResponse,
+    GetSagaStatusRequest, GetSagaStatusResponse, HtlcStatus, ListSagasRequest, ListSagasResponse,
+    RollbackSagaRequest, RollbackSagaResponse, SagaHistoryEntry, SagaState, SagaStateFilter,
+    SagaStep, SagaStepState, SagaStepUpdate, SagaSummary, StreamSagaUpdatesRequest,
+    StreamSagaUpdatesResponse,
+};
+use crate::api::v1::auth::AuthContext;
+use crate::api::v1::saga_handlers::authorize_saga;
+use crate::domain::{SagaAction, SagaStatus, StepResult, StepStatus, UniversalId};
+use crate::local::{LedgerAccount, LockStatus, SagaPhase, SagaRecord};
+use crate::peer::saga_executor::{action_name, SagaExecutorError, SagaUpdate};
+use crate::server::AppState;
+
+// ============================================================================
+// Mapping
+// ============================================================================
+
+fn rpc_error(e: SagaExecutorError) -> RpcError {
+    let code = match &e {
+        SagaExecutorError::NotFound(_) => RpcErrorCode::NotFound,
+        SagaExecutorError::AlreadyRunning(_) => RpcErrorCode::Aborted,
+        SagaExecutorError::AlreadyFinished(_) => RpcErrorCode::FailedPrecondition,
+        SagaExecutorError::InvalidSaga(_) => RpcErrorCode::InvalidArgument,
+        SagaExecutorError::Storage(_) => RpcErrorCode::Internal,
+    };
+    RpcError::new(code, e.to_string())
+}
+
+fn parse_saga_id(saga_id: &str) -> RpcResult<UniversalId> {
+    UniversalId::from_hex(saga_id).map_err(|_| {
+        RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!("invalid saga id: {}", saga_id),
+        )
+    })
+}
+
+fn load(state: &AppState, saga_id: &str) -> RpcResult<SagaRecord> {
+    let id = parse_saga_id(saga_id)?;
+    state
+        .saga_executor
+        .get(&id)
+        .map_err(rpc_error)?
+        .ok_or_else(|| rpc_error(SagaExecutorError::NotFound(id.to_hex())))
+}
+
+/// Lädt eine Saga, die der Aufrufer steuern darf
+fn load_authorized(state: &AppState, auth: &AuthContext, saga_id: &str) -> RpcResult<SagaRecord> {
+    let record = load(state, saga_id)?;
+    authorize_saga(state, auth, &record).map_err(api_error)?;
+    Ok(record)
+}
+
+fn step_id(record: &SagaRecord, idx: usize) -> String {
+    format!("{}:{}", record.id().to_hex(), idx)
+}
+
+fn is_waiting(record: &SagaRecord) -> bool {
+    record
+        .saga
+        .steps
+        .iter()
+        .any(|s| s.status == StepStatus::Running && matches!(s.action, SagaAction::WaitFor { .. }))
+}
+
+fn saga_state(phase: SagaPhase, status: &SagaStatus, waiting: bool) -> SagaState {
+    match phase {
+        SagaPhase::Submitted => SagaState::Pending,
+        SagaPhase::Running if waiting => SagaState::WaitingConfirmation,
+        SagaPhase::Running => SagaState::Executing,
+        SagaPhase::Compensating => SagaState::RollingBack,
+        SagaPhase::Finished => match status {
+            SagaStatus::Completed => SagaState::Completed,
+            SagaStatus::Compensated => SagaState::RolledBack,
+            SagaStatus::Cancelled => SagaState::Cancelled,
+            SagaStatus::Failed { .. } | SagaStatus::TimedOut => SagaState::Failed,
+            SagaStatus::Pending | SagaStatus::InProgress { .. } => SagaState::Unspecified,
+        },
+    }
+}
+
+pub(super) fn record_state(record: &SagaRecord) -> SagaState {
+    saga_state(record.phase, &record.saga.status, is_waiting(record))
+}
+
+fn step_state(status: StepStatus, action: Option<&SagaAction>) -> SagaStepState {
+    match status {
+        StepStatus::Pending | StepStatus::Skipped => SagaStepState::Pending,
+        StepStatus::Running if matches!(action, Some(SagaAction::WaitFor { .. })) => {
+            SagaStepState::Waiting
+        }
+        StepStatus::Running => SagaStepState::Executing,
+        StepStatus::Completed => SagaStepState::Finalized,
+        StepStatus::Failed => SagaStepState::Failed,
+        StepStatus::Compensated => SagaStepState::Compensated,
+    }
+}
+
+fn summary(record: &SagaRecord) -> SagaSummary {
+    SagaSummary {
+        saga_id: record.id().to_hex(),
+        intent_id: record.saga.intent_id.to_hex(),
+        state: record_state(record) as i32,
+        current_step: record
+            .saga
+            .current_step_index()
+            .unwrap_or(record.saga.steps.len()) as i32,
+        total_steps: record.saga.steps.len() as i32,
+        created_at: Some(timestamp(record.created_at)),
+    }
+}
+
+fn compensations(record: &SagaRecord) -> Vec<CompensationResult> {
+    record
+        .compensations
+        .iter()
+        .map(|c| CompensationResult {
+            step_id: step_id(record, c.step_index),
+            success: c.success,
+            tx_hash: c.journal_seq.map(|seq| seq.to_string()),
+            error: c.error.clone(),
+        })
+        .collect()
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// ListSagas – Sagas mit optionalem Zustandsfilter
+pub async fn list_sagas(
+    State(state): State<AppState>,
+    req: ListSagasRequest,
+) -> RpcResult<ListSagasResponse> {
+    let filter = req
+        .state_filter
+        .and_then(|f| SagaStateFilter::try_from(f).ok())
+        .unwrap_or(SagaStateFilter::Unspecified);
+
+    let records: Vec<SagaRecord> = state
+        .saga_executor
+        .list()
+        .map_err(rpc_error)?
+        .into_iter()
+        .filter(|r| match filter {
+            SagaStateFilter::Unspecified => true,
+            SagaStateFilter::Pending => r.phase == SagaPhase::Submitted,
+            SagaStateFilter::Active => r.is_in_flight(),
+            SagaStateFilter::Failed => {
+                r.is_finished()
+                    && matches!(
+                        r.saga.status,
+                        SagaStatus::Failed { .. } | SagaStatus::TimedOut | SagaStatus::Compensated
+                    )
+            }
+            SagaStateFilter::Completed => r.is_finished() && r.saga.is_completed(),
+        })
+        .collect();
+
+    let total_count = records.len() as i32;
+    let (page, next_cursor) = paginate(
+        records,
+        |r| r.id().to_hex(),
+        req.cursor.as_deref(),
+        req.limit,
+    );
+
+    Ok(ListSagasResponse {
+        sagas: page.iter().map(summary).collect(),
+        next_cursor,
+        total_count,
+    })
+}
+
+/// GetSagaStatus – Detailstatus inkl. Schritte und Escrow-Lock
+pub async fn get_saga_status(
+    State(state): State<AppState>,
+    req: GetSagaStatusRequest,
+) -> RpcResult<GetSagaStatusResponse> {
+    let record = load(&state, &req.saga_id)?;
+
+    let steps = record
+        .saga
+        .steps
+        .iter()
+        .map(|step| SagaStep {
+            step_number: step.index as i32 + 1,
+            step_id: step_id(&record, step.index),
+            chain: ChainType::Erynoa as i32,
+            action: if req.verbose {
+                step.description.clone()
+            } else {
+                action_name(&step.action).to_string()
+            },
+            state: step_state(step.status, Some(&step.action)) as i32,
+            tx_hash: record
+                .journal_seqs
+                .get(&step.index)
+                .map(|seq| seq.to_string()),
+            event_id: None,
+            started_at: record
+                .step_started_at
+                .get(&step.index)
+                .map(|t| timestamp(*t)),
+            completed_at: record
+                .step_completed_at
+                .get(&step.index)
+                .map(|t| timestamp(*t)),
+            error: match &step.result {
+                Some(StepResult::Failed { error, .. }) => Some(error.clone()),
+                _ => None,
+            },
+            // Ledger-Buchungen haben keine externen Proofs
+            proof: None,
+        })
+        .collect();
+
+    // Letzter Escrow-Lock der Saga als HTLC-Status
+    let htlc_status = match record.lock_ids.values().next_back() {
+        Some(lock_id) => {
+            let lock = state
+                .storage
+                .ledger
+                .lock_info(lock_id)
+                .map_err(|e| RpcError::new(RpcErrorCode::Internal, e.to_string()))?;
+            lock.map(|lock| HtlcStatus {
+                active: lock.status == LockStatus::Locked,
+                timeout_remaining_seconds: 0,
+                lock_hash: lock_id.to_hex(),
+                secret: None,
+            })
+        }
+        None => None,
+    };
+
+    let compensatable_steps: Vec<String> = record
+        .saga
+        .steps
+        .iter()
+        .filter(|s| s.status == StepStatus::Completed && s.compensation.is_some())
+        .map(|s| step_id(&record, s.index))
+        .collect();
+    let rollback_available = !compensatable_steps.is_empty()
+        && (record.is_in_flight()
+            || matches!(
+                record.saga.status,
+                SagaStatus::Completed | SagaStatus::Failed { .. }
+            ));
+
+    Ok(GetSagaStatusResponse {
+        saga_id: record.id().to_hex(),
+        intent_id: record.saga.intent_id.to_hex(),
+        state: record_state(&record) as i32,
+        steps,
+        htlc_status,
+        rollback_available,
+        compensatable_steps,
+        created_at: Some(timestamp(record.created_at)),
+        updated_at: Some(timestamp(record.updated_at)),
+        error_message: record.error.clone(),
+    })
+}
+
+/// ExecuteSaga – Startet eine eingereichte Saga im Hintergrund
+pub async fn execute_saga(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: ExecuteSagaRequest,
+) -> RpcResult<ExecuteSagaResponse> {
+    let id = load_authorized(&state, &auth, &req.saga_id)?.id();
+    let (started, error) = match state.saga_executor.spawn(id) {
+        Ok(()) => (true, None),
+        Err(SagaExecutorError::NotFound(id)) => {
+            return Err(rpc_error(SagaExecutorError::NotFound(id)))
+        }
+        Err(e) => (false, Some(e.to_string())),
+    };
+    let record = load(&state, &req.saga_id)?;
+
+    Ok(ExecuteSagaResponse {
+        started,
+        state: record_state(&record) as i32,
+        error,
+    })
+}
+
+/// CancelSaga – Abbruch mit Kompensation
+pub async fn cancel_saga(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: CancelSagaRequest,
+) -> RpcResult<CancelSagaResponse> {
+    let id = load_authorized(&state, &auth, &req.saga_id)?.id();
+    let record = state
+        .saga_executor
+        .cancel(&id, req.reason)
+        .await
+        .map_err(rpc_error)?;
+
+    Ok(CancelSagaResponse {
+        success: record.saga.status == SagaStatus::Cancelled,
+        final_state: record_state(&record) as i32,
+        compensations: compensations(&record),
+        error: record.error.clone(),
+    })
+}
+
+/// RollbackSaga – Erzwingt die Kompensation, liefert Salden des Executors
+pub async fn rollback_saga(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: RollbackSagaRequest,
+) -> RpcResult<RollbackSagaResponse> {
+    let id = load_authorized(&state, &auth, &req.saga_id)?.id();
+    let record = state
+        .saga_executor
+        .rollback(&id, req.reason)
+        .await
+        .map_err(rpc_error)?;
+
+    let balances = state
+        .storage
+        .ledger
+        .balances(&LedgerAccount::Holder(record.saga.executor))
+        .map_err(|e| RpcError::new(RpcErrorCode::Internal, e.to_string()))?;
+    let final_balances: Struct = balances
+        .into_iter()
+        .map(|(asset, amount)| (asset, Value::from(amount as f64)))
+        .collect();
+
+    Ok(RollbackSagaResponse {
+        success: record.saga.status == SagaStatus::Compensated,
+        compensations: compensations(&record),
+        final_balances: Some(final_balances),
+        error: record.error.clone(),
+    })
+}
+
+/// GetSagaHistory – Abgeschlossene Sagas
+pub async fn get_saga_history(
+    State(state): State<AppState>,
+    req: GetSagaHistoryRequest,
+) -> RpcResult<GetSagaHistoryResponse> {
+    let state_filter = req
+        .state_filter
+        .and_then(|f| SagaState::try_from(f).ok())
+        .filter(|f| *f != SagaState::Unspecified);
+    let since = req.since.map(|t| t.seconds);
+
+    let records: Vec<SagaRecord> = state
+        .saga_executor
+        .list()
+        .map_err(rpc_error)?
+        .into_iter()
+        .filter(|r| r.is_finished())
+        .filter(|r| state_filter.is_none_or(|f| record_state(r) == f))
+        .filter(|r| since.is_none_or(|s| r.finished_at.unwrap_or(r.updated_at) >= s))
+        .collect();
+
+    let total_count = records.len() as i32;
+    let (page, next_cursor) = paginate(
+        records,
+        |r| r.id().to_hex(),
+        req.cursor.as_deref(),
+        req.limit,
+    );
+
+    let entries = page
+        .iter()
+        .map(|r| SagaHistoryEntry {
+            saga_id: r.id().to_hex(),
+            intent_id: r.saga.intent_id.to_hex(),
+            goal: r
+                .saga
+                .steps
+                .iter()
+                .map(|s| s.description.as_str())
+                .collect::<Vec<_>>()
+                .join(" → "),
+            final_state: record_state(r) as i32,
+            step_count: r.saga.steps.len() as i32,
+            created_at: Some(timestamp(r.created_at)),
+            completed_at: r.finished_at.map(timestamp),
+            actual_cost: Some(CostEstimate {
+                amount: r.saga.consumed_budget.mana.to_string(),
+                asset: "mana".to_string(),
+                fee_estimate: r.saga.consumed_budget.gas as f64,
+                fee_asset: "gas".to_string(),
+            }),
+        })
+        .collect();
+
+    Ok(GetSagaHistoryResponse {
+        entries,
+        next_cursor,
+        total_count,
+    })
+}
+
+fn update_response(update: &SagaUpdate) -> StreamSagaUpdatesResponse {
+    StreamSagaUpdatesResponse {
+        saga_id: update.saga_id.to_hex(),
+        state: saga_state(update.phase, &update.status, false) as i32,
+        step_update: update.step.as_ref().map(|step| SagaStepUpdate {
+            step_number: step.index as i32 + 1,
+            step_id: format!("{}:{}", update.saga_id.to_hex(), step.index),
+            state: step_state(step.status, None) as i32,
+            tx_hash: step.journal_seq.map(|seq| seq.to_string()),
+            message: step.message.clone(),
+        }),
+        timestamp: Some(timestamp(update.timestamp)),
+    }
+}
+
+/// StreamSagaUpdates – Aktueller Zustand, danach Live-Updates bis zum Endzustand
+pub async fn stream_saga_updates(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: StreamSagaUpdatesRequest,
+) -> impl Stream<Item = RpcResult<StreamSagaUpdatesResponse>> {
+    // Erst abonnieren, dann lesen: kein Update geht zwischen beiden verloren
+    let receiver = state.saga_executor.subscribe();
+    let (first, rest) = match load_authorized(&state, &auth, &req.saga_id) {
+        Ok(record) => {
+            let initial = StreamSagaUpdatesResponse {
+                saga_id: record.id().to_hex(),
+                state: record_state(&record) as i32,
+                step_update: None,
+                timestamp: Some(timestamp(record.updated_at)),
+            };
+            let rest = (!record.is_finished()).then(|| (record.id(), receiver));
+            (Ok(initial), rest)
+        }
+        Err(e) => (Err(e), None),
+    };
+
+    stream::once(async move { first }).chain(stream::unfold(rest, |rest| async move {
+        let (saga_id, mut receiver) = rest?;
+        loop {
+            match receiver.recv().await {
+                Ok(update) if update.saga_id == saga_id => {
+                    let done = update.phase == SagaPhase::Finished;
+                    let next = (!done).then_some((saga_id, receiver));
+                    return Some((Ok(update_response(&update)), next));
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }))
+}
@@ -0,0 +1,310 @@
+//! StorageService – Objekte im Content Store (CAS)
+//!
+//! Der Key eines Objekts ist seine CID (und damit auch das ETag). Buckets
+//! sind Tags `bucket:<name>` am Content; `default` existiert immer. Ein
+//! Objekt wird erst gelöscht, wenn es in keinem Bucket mehr liegt.
+
+use axum::extract::State;
+use axum_connect::prelude::*;
+
+use super::{api_error, internal, timestamp};
+use crate::api::proto::{
+    CreateBucketRequest, CreateBucketResponse, DeleteBucketRequest, DeleteBucketResponse,
+    GetPresignedDownloadUrlRequest, GetPresignedDownloadUrlResponse, GetPresignedUploadUrlRequest,
+    GetPresignedUploadUrlResponse, ListBucketsRequest, ListBucketsResponse, ObjectInfo,
+    StorageServiceDeleteRequest, StorageServiceDeleteResponse, StorageServiceHeadRequest,
+    StorageServiceHeadResponse, StorageServiceListRequest, StorageServiceListResponse,
+    UploadRequest, UploadResponse,
+};
+use crate::api::v1::auth::AuthContext;
+use crate::domain::{Capability, DID};
+use crate::error::ApiError;
+use crate::local::{ContentId, ContentMetadata};
+use crate::server::AppState;
+
+const DEFAULT_BUCKET: &str = "default";
+const BUCKET_TAG_PREFIX: &str = "bucket:";
+const DEFAULT_MAX_KEYS: usize = 1000;
+
+// ============================================================================
+// Helfer
+// ============================================================================
+
+fn bucket_tag(bucket: &str) -> String {
+    format!("{}{}", BUCKET_TAG_PREFIX, bucket)
+}
+
+fn object_url(bucket: &str, cid: &ContentId) -> String {
+    format!("erynoa://content/{}/{}", bucket, cid)
+}
+
+/// Bucket-Namen: 3–63 Zeichen, Kleinbuchstaben, Ziffern, `-` und `.`
+fn validate_bucket_name(name: &str) -> RpcResult<()> {
+    let valid = (3..=63).contains(&name.len())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.')
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric());
+    if valid {
+        Ok(())
+    } else {
+        Err(RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!("invalid bucket name: {}", name),
+        ))
+    }
+}
+
+/// Löst den Bucket einer Anfrage auf und prüft, dass er existiert
+fn resolve_bucket(state: &AppState, bucket: Option<&str>) -> RpcResult<String> {
+    let bucket = bucket.filter(|b| !b.is_empty()).unwrap_or(DEFAULT_BUCKET);
+    if bucket != DEFAULT_BUCKET
+        && !state
+            .storage
+            .content
+            .bucket_exists(bucket)
+            .map_err(internal)?
+    {
+        return Err(api_error(ApiError::NotFound(format!("bucket {}", bucket))));
+    }
+    Ok(bucket.to_string())
+}
+
+/// Metadaten eines Objekts, sofern es im Bucket liegt
+fn object(state: &AppState, bucket: &str, key: &str) -> RpcResult<Option<ContentMetadata>> {
+    let cid = ContentId::from_hash(key.to_string());
+    let metadata = state.storage.content.get_metadata(&cid).map_err(internal)?;
+    Ok(metadata.filter(|m| m.tags.contains(&bucket_tag(bucket))))
+}
+
+fn presigned_unsupported() -> RpcError {
+    RpcError::new(
+        RpcErrorCode::Unimplemented,
+        "presigned URLs are not supported by the content store; use Upload with a bearer token"
+            .to_string(),
+    )
+}
+
+// ============================================================================
+// Objekte
+// ============================================================================
+
+/// Speichert ein Objekt im Bucket (`write:storage`)
+pub async fn upload(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: UploadRequest,
+) -> RpcResult<UploadResponse> {
+    auth.require(&Capability::Write {
+        resource: "storage".to_string(),
+    })
+    .map_err(api_error)?;
+
+    let max = state.config.storage.max_content_size;
+    if req.file.len() as u64 > max {
+        return Err(RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!("file exceeds maximum content size of {} bytes", max),
+        ));
+    }
+    let bucket = resolve_bucket(&state, req.bucket.as_deref())?;
+    let content_type = if req.content_type.is_empty() {
+        "application/octet-stream"
+    } else {
+        req.content_type.as_str()
+    };
+
+    let content = &state.storage.content;
+    let creator = auth.did.parse::<DID>().ok();
+    let tags = vec![bucket_tag(&bucket)];
+    let cid = content
+        .put(req.file, content_type, creator, tags.clone())
+        .map_err(internal)?;
+    // Bei Dedup liegt der Content schon vor – Bucket-Tag trotzdem setzen
+    content.add_tags(&cid, &tags).map_err(internal)?;
+
+    tracing::debug!(cid = %cid, bucket = %bucket, filename = %req.filename, "Object uploaded");
+    Ok(UploadResponse {
+        url: object_url(&bucket, &cid),
+        key: cid.to_string(),
+        etag: cid.to_string(),
+        bucket,
+    })
+}
+
+/// Objekte eines Buckets, optional per Key-Präfix gefiltert
+pub async fn list(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    req: StorageServiceListRequest,
+) -> RpcResult<StorageServiceListResponse> {
+    let bucket = resolve_bucket(&state, req.bucket.as_deref())?;
+    let max_keys = if req.max_keys > 0 {
+        req.max_keys as usize
+    } else {
+        DEFAULT_MAX_KEYS
+    };
+    let prefix = req.prefix.unwrap_or_default();
+
+    let content = &state.storage.content;
+    let mut objects = Vec::new();
+    for cid in content.get_by_tag(&bucket_tag(&bucket)).map_err(internal)? {
+        if !cid.as_str().starts_with(&prefix) {
+            continue;
+        }
+        // Index kann veraltete CIDs enthalten (Delete bereinigt ihn nicht)
+        if let Some(metadata) = content.get_metadata(&cid).map_err(internal)? {
+            objects.push(ObjectInfo {
+                key: cid.to_string(),
+                size: metadata.size as i64,
+                content_type: Some(metadata.content_type),
+                last_modified: Some(timestamp(metadata.created_at)),
+            });
+        }
+        if objects.len() >= max_keys {
+            break;
+        }
+    }
+
+    Ok(StorageServiceListResponse {
+        count: objects.len() as i32,
+        objects,
+    })
+}
+
+/// Entfernt ein Objekt aus dem Bucket (nur durch den Ersteller)
+///
+/// Der Content selbst wird gelöscht, sobald er in keinem Bucket mehr liegt.
+pub async fn delete(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: StorageServiceDeleteRequest,
+) -> RpcResult<StorageServiceDeleteResponse> {
+    let bucket = resolve_bucket(&state, req.bucket.as_deref())?;
+    let metadata = object(&state, &bucket, &req.key)?
+        .ok_or_else(|| api_error(ApiError::NotFound(format!("object {}", req.key))))?;
+
+    let is_creator = metadata
+        .created_by
+        .as_ref()
+        .is_some_and(|did| did.to_string() == auth.did);
+    if !is_creator && !auth.has(&Capability::All) {
+        return Err(api_error(ApiError::Forbidden));
+    }
+
+    let content = &state.storage.content;
+    content
+        .remove_tag(&metadata.cid, &bucket_tag(&bucket))
+        .map_err(internal)?;
+    let still_referenced = metadata
+        .tags
+        .iter()
+        .any(|t| t.starts_with(BUCKET_TAG_PREFIX) && *t != bucket_tag(&bucket));
+    if !still_referenced {
+        content.delete(&metadata.cid).map_err(internal)?;
+    }
+
+    Ok(StorageServiceDeleteResponse {})
+}
+
+pub async fn head(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    req: StorageServiceHeadRequest,
+) -> RpcResult<StorageServiceHeadResponse> {
+    let bucket = resolve_bucket(&state, req.bucket.as_deref())?;
+    Ok(StorageServiceHeadResponse {
+        exists: object(&state, &bucket, &req.key)?.is_some(),
+    })
+}
+
+pub async fn get_presigned_upload_url(
+    _auth: AuthContext,
+    _req: GetPresignedUploadUrlRequest,
+) -> RpcResult<GetPresignedUploadUrlResponse> {
+    Err(presigned_unsupported())
+}
+
+pub async fn get_presigned_download_url(
+    _auth: AuthContext,
+    _req: GetPresignedDownloadUrlRequest,
+) -> RpcResult<GetPresignedDownloadUrlResponse> {
+    Err(presigned_unsupported())
+}
+
+// ============================================================================
+// Buckets
+// ============================================================================
+
+pub async fn list_buckets(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    _req: ListBucketsRequest,
+) -> RpcResult<ListBucketsResponse> {
+    let mut buckets = state.storage.content.list_buckets().map_err(internal)?;
+    buckets.insert(0, DEFAULT_BUCKET.to_string());
+    Ok(ListBucketsResponse { buckets })
+}
+
+/// Legt einen Bucket an (`write:storage`)
+pub async fn create_bucket(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: CreateBucketRequest,
+) -> RpcResult<CreateBucketResponse> {
+    auth.require(&Capability::Write {
+        resource: "storage".to_string(),
+    })
+    .map_err(api_error)?;
+    validate_bucket_name(&req.name)?;
+
+    let created = req.name != DEFAULT_BUCKET
+        && state
+            .storage
+            .content
+            .create_bucket(&req.name)
+            .map_err(internal)?;
+    if !created {
+        return Err(api_error(ApiError::Conflict(format!(
+            "bucket {} already exists",
+            req.name
+        ))));
+    }
+
+    Ok(CreateBucketResponse {
+        message: format!("bucket {} created", req.name),
+        name: req.name,
+    })
+}
+
+/// Entfernt einen leeren Bucket (`write:storage`)
+pub async fn delete_bucket(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: DeleteBucketRequest,
+) -> RpcResult<DeleteBucketResponse> {
+    auth.require(&Capability::Write {
+        resource: "storage".to_string(),
+    })
+    .map_err(api_error)?;
+    if req.name == DEFAULT_BUCKET {
+        return Err(RpcError::new(
+            RpcErrorCode::FailedPrecondition,
+            "the default bucket cannot be deleted".to_string(),
+        ));
+    }
+    let bucket = resolve_bucket(&state, Some(&req.name))?;
+
+    let content = &state.storage.content;
+    let mut objects = content.get_by_tag(&bucket_tag(&bucket)).map_err(internal)?;
+    objects.retain(|cid| content.exists(cid).unwrap_or(false));
+    if !objects.is_empty() {
+        return Err(RpcError::new(
+            RpcErrorCode::FailedPrecondition,
+            format!("bucket {} is not empty ({} objects)", bucket, objects.len()),
+        ));
+    }
+
+    content.delete_bucket(&bucket).map_err(internal)?;
+    Ok(DeleteBucketResponse {})
+}
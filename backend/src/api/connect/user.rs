@@ -0,0 +1,308 @@
+//! UserService – Benutzerprofile auf Basis der Passkey-Identitäten
+//!
+//! Ein User ist eine per Passkey registrierte DID. E-Mail, Name und Rolle
+//! liegen als Metadaten an der [`StoredIdentity`]; die ID ist die DID-URI.
+
+use axum::extract::State;
+use axum_connect::prelude::*;
+
+use super::{api_error, internal, paginate, timestamp};
+use crate::api::proto::{
+    CreateRequest, CreateResponse, DeleteRequest, DeleteResponse, GetCurrentRequest,
+    GetCurrentResponse, GetRequest, GetResponse, ListRequest, ListResponse, UpdateRequest,
+    UpdateResponse, User,
+};
+use crate::api::v1::auth::{AuthContext, StoredPasskeyCredential};
+use crate::domain::{Capability, DID};
+use crate::error::ApiError;
+use crate::local::StoredIdentity;
+use crate::server::AppState;
+
+const DEFAULT_ROLE: &str = "member";
+const ADMIN_ROLE: &str = "admin";
+const ROLES: &[&str] = &[DEFAULT_ROLE, ADMIN_ROLE];
+
+// ============================================================================
+// Mapping
+// ============================================================================
+
+fn parse_did(did: &str) -> RpcResult<DID> {
+    did.parse::<DID>().map_err(|e| {
+        RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!("invalid user id {}: {}", did, e),
+        )
+    })
+}
+
+fn meta<'a>(identity: Option<&'a StoredIdentity>, key: &str) -> Option<&'a str> {
+    identity
+        .and_then(|i| i.metadata.get(key))
+        .map(String::as_str)
+}
+
+fn to_user(credential: &StoredPasskeyCredential, identity: Option<&StoredIdentity>) -> User {
+    let updated_at = meta(identity, "updated_at")
+        .and_then(|t| t.parse().ok())
+        .unwrap_or(credential.created_at);
+
+    User {
+        id: credential.did.clone(),
+        email: meta(identity, "email").unwrap_or_default().to_string(),
+        name: meta(identity, "name")
+            .map(str::to_string)
+            .or_else(|| credential.display_name.clone())
+            .unwrap_or_default(),
+        role: meta(identity, "role").unwrap_or(DEFAULT_ROLE).to_string(),
+        created_at: Some(timestamp(credential.created_at)),
+        updated_at: Some(timestamp(updated_at)),
+    }
+}
+
+/// Lädt Credential und Identität eines Users
+fn load(
+    state: &AppState,
+    id: &str,
+) -> RpcResult<(StoredPasskeyCredential, Option<StoredIdentity>)> {
+    let credential = state
+        .storage
+        .identities
+        .get_passkey_credential_by_did(id)
+        .map_err(internal)?
+        .ok_or_else(|| api_error(ApiError::NotFound(format!("user {}", id))))?;
+    let identity = state
+        .storage
+        .identities
+        .get(&parse_did(id)?)
+        .map_err(internal)?;
+    Ok((credential, identity))
+}
+
+/// Admin = Capability `*` oder Profilrolle `admin`
+fn is_admin(state: &AppState, auth: &AuthContext) -> bool {
+    if auth.has(&Capability::All) {
+        return true;
+    }
+    auth.did
+        .parse::<DID>()
+        .ok()
+        .and_then(|did| state.storage.identities.get(&did).ok().flatten())
+        .is_some_and(|identity| meta(Some(&identity), "role") == Some(ADMIN_ROLE))
+}
+
+fn validate_email(email: &str) -> RpcResult<()> {
+    let valid = email
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!("invalid email: {}", email),
+        ))
+    }
+}
+
+fn validate_role(role: &str) -> RpcResult<()> {
+    if ROLES.contains(&role) {
+        Ok(())
+    } else {
+        Err(RpcError::new(
+            RpcErrorCode::InvalidArgument,
+            format!("unknown role: {} (expected one of {:?})", role, ROLES),
+        ))
+    }
+}
+
+/// Schreibt Profilfelder; die Identität wird beim ersten Schreiben aus dem
+/// Passkey übernommen
+fn set_fields(
+    state: &AppState,
+    credential: &StoredPasskeyCredential,
+    fields: &[(&str, &str)],
+) -> RpcResult<()> {
+    let identities = &state.storage.identities;
+    let did = parse_did(&credential.did)?;
+    if identities.get(&did).map_err(internal)?.is_none() {
+        identities
+            .import_identity(did.clone(), &credential.public_key_hex)
+            .map_err(internal)?;
+    }
+    for (key, value) in fields {
+        identities
+            .set_metadata(&did, key, value)
+            .map_err(internal)?;
+    }
+    let now = chrono::Utc::now().timestamp().to_string();
+    identities
+        .set_metadata(&did, "updated_at", &now)
+        .map_err(internal)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Alle registrierten User, sortiert nach Registrierung
+pub async fn list(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    req: ListRequest,
+) -> RpcResult<ListResponse> {
+    let identities = &state.storage.identities;
+    let mut credentials = identities.list_passkey_credentials().map_err(internal)?;
+    credentials.sort_by(|a, b| (a.created_at, &a.did).cmp(&(b.created_at, &b.did)));
+    let total_count = credentials.len() as i32;
+
+    let cursor = Some(req.page_token.as_str()).filter(|t| !t.is_empty());
+    let (page, next) = paginate(credentials, |c| c.did.clone(), cursor, Some(req.page_size));
+
+    let users = page
+        .iter()
+        .map(|credential| {
+            let identity = credential
+                .did
+                .parse::<DID>()
+                .ok()
+                .and_then(|did| identities.get(&did).ok().flatten());
+            to_user(credential, identity.as_ref())
+        })
+        .collect();
+
+    Ok(ListResponse {
+        users,
+        next_page_token: next.unwrap_or_default(),
+        total_count,
+    })
+}
+
+pub async fn get(
+    State(state): State<AppState>,
+    _auth: AuthContext,
+    req: GetRequest,
+) -> RpcResult<GetResponse> {
+    let (credential, identity) = load(&state, &req.id)?;
+    Ok(GetResponse {
+        user: Some(to_user(&credential, identity.as_ref())),
+    })
+}
+
+/// Der User der aktuellen Session
+pub async fn get_current(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    _req: GetCurrentRequest,
+) -> RpcResult<GetCurrentResponse> {
+    let (credential, identity) = load(&state, &auth.did)?;
+    Ok(GetCurrentResponse {
+        user: Some(to_user(&credential, identity.as_ref())),
+    })
+}
+
+/// Der User entsteht bei der Passkey-Registrierung; `Create` ergänzt
+/// E-Mail, Name und Rolle. Die Rolle `admin` vergibt nur ein Admin.
+pub async fn create(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: CreateRequest,
+) -> RpcResult<CreateResponse> {
+    let (credential, identity) = load(&state, &auth.did)?;
+    if meta(identity.as_ref(), "email").is_some() {
+        return Err(api_error(ApiError::Conflict(format!(
+            "profile for {} already exists",
+            auth.did
+        ))));
+    }
+
+    validate_email(&req.email)?;
+    let role = if req.role.is_empty() {
+        DEFAULT_ROLE
+    } else {
+        req.role.as_str()
+    };
+    validate_role(role)?;
+    if role != DEFAULT_ROLE && !is_admin(&state, &auth) {
+        return Err(api_error(ApiError::Forbidden));
+    }
+
+    let now = chrono::Utc::now().timestamp().to_string();
+    set_fields(
+        &state,
+        &credential,
+        &[
+            ("email", &req.email),
+            ("name", &req.name),
+            ("role", role),
+            ("profile_created_at", &now),
+        ],
+    )?;
+
+    let (credential, identity) = load(&state, &auth.did)?;
+    Ok(CreateResponse {
+        user: Some(to_user(&credential, identity.as_ref())),
+    })
+}
+
+/// Aktualisiert ein Profil (eigenes oder als Admin); Rollen nur als Admin
+pub async fn update(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: UpdateRequest,
+) -> RpcResult<UpdateResponse> {
+    let admin = is_admin(&state, &auth);
+    if req.id != auth.did && !admin {
+        return Err(api_error(ApiError::Forbidden));
+    }
+    let (credential, _) = load(&state, &req.id)?;
+
+    let mut fields: Vec<(&str, &str)> = Vec::new();
+    if let Some(email) = &req.email {
+        validate_email(email)?;
+        fields.push(("email", email));
+    }
+    if let Some(name) = &req.name {
+        fields.push(("name", name));
+    }
+    if let Some(role) = &req.role {
+        validate_role(role)?;
+        if !admin {
+            return Err(api_error(ApiError::Forbidden));
+        }
+        fields.push(("role", role));
+    }
+    if !fields.is_empty() {
+        set_fields(&state, &credential, &fields)?;
+    }
+
+    let (credential, identity) = load(&state, &req.id)?;
+    Ok(UpdateResponse {
+        user: Some(to_user(&credential, identity.as_ref())),
+    })
+}
+
+/// Entfernt Passkey und Identität und widerruft alle Sessions des Users
+pub async fn delete(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    req: DeleteRequest,
+) -> RpcResult<DeleteResponse> {
+    if req.id != auth.did && !is_admin(&state, &auth) {
+        return Err(api_error(ApiError::Forbidden));
+    }
+    let (credential, _) = load(&state, &req.id)?;
+
+    state
+        .storage
+        .identities
+        .delete_passkey_credential(&credential.credential_id)
+        .map_err(internal)?;
+    let revoked = state
+        .storage
+        .sessions
+        .revoke_all(&req.id, chrono::Utc::now().timestamp())
+        .map_err(internal)?;
+
+    tracing::info!(did = %req.id, revoked, "User deleted");
+    Ok(DeleteResponse {})
+}
@@ -0,0 +1,247 @@
+//! Zugriffskontrolle & Audit für privilegierte Routen-Gruppen
+//!
+//! Jede Gruppe (Modus-Steuerung, Debug, Trust-Updates, Audit, Konfiguration) wird mit einer
+//! [`AccessPolicy`] gemountet:
+//!
+//! - Routen mit `admin_listener_only` existieren nur auf dem Admin-Listener
+//!   (localhost); über den öffentlichen Listener antworten sie mit 404
+//! - ohne gültige Session 401, mit zu niedriger [`Role`] 403
+//! - jeder schreibende Aufruf mit gültiger Session – auch abgewiesene – landet
+//!   im hash-verketteten [`AuditLog`](crate::local::AuditLog), zugelassene mit
+//!   Zustand vorher/nachher; anonyme Aufrufe nur im Tracing, damit sie das
+//!   Log nicht unbegrenzt füllen
+//!
+//! Die aufgelöste Rolle steht Handlern als `Extension<Role>` zur Verfügung.
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{FromRequestParts, OriginalUri, Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{json, Value};
+
+use crate::api::v1::auth::{AuthContext, Role};
+use crate::domain::DID;
+use crate::error::ApiError;
+use crate::local::AuditRecord;
+use crate::server::AppState;
+
+/// Maximale Body-Größe privilegierter Requests
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Listener, über den ein Request angenommen wurde
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Listener {
+    /// Öffentlicher API-Port
+    Public,
+    /// Admin-Port (nur Loopback)
+    Admin,
+}
+
+impl Listener {
+    /// Name im Audit Log
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+/// Zugriffsregel einer Routen-Gruppe
+#[derive(Debug, Clone, Copy)]
+pub struct AccessPolicy {
+    /// Name der Gruppe im Audit Log
+    pub group: &'static str,
+    /// Mindestrolle
+    pub role: Role,
+    /// Nur über den Admin-Listener erreichbar
+    pub admin_listener_only: bool,
+    /// Zustands-Ausschnitt vor/nach dem Aufruf
+    pub probe: fn(&AppState, &Value) -> Value,
+}
+
+impl AccessPolicy {
+    /// Gruppe mit Mindestrolle, ohne Zustands-Probe
+    pub const fn new(group: &'static str, role: Role) -> Self {
+        Self {
+            group,
+            role,
+            admin_listener_only: false,
+            probe: no_probe,
+        }
+    }
+
+    /// Nur über den Admin-Listener erreichbar
+    pub const fn admin_listener_only(mut self) -> Self {
+        self.admin_listener_only = true;
+        self
+    }
+
+    /// Zustands-Probe für before/after im Audit Log
+    pub const fn with_probe(mut self, probe: fn(&AppState, &Value) -> Value) -> Self {
+        self.probe = probe;
+        self
+    }
+}
+
+/// State des Middleware-Layers: App-State plus Regel der Gruppe
+#[derive(Clone)]
+pub struct Guarded {
+    pub state: AppState,
+    pub policy: AccessPolicy,
+}
+
+impl Guarded {
+    pub fn new(state: AppState, policy: AccessPolicy) -> Self {
+        Self { state, policy }
+    }
+}
+
+fn no_probe(_state: &AppState, _body: &Value) -> Value {
+    Value::Null
+}
+
+/// System-Modus, Event-Sequenz, letzter Checkpoint und Warnungen
+pub fn probe_system(state: &AppState, _body: &Value) -> Value {
+    let events = state.unified_state.event_log_stats();
+    let warnings = state
+        .unified_state
+        .warnings
+        .read()
+        .map(|w| w.len())
+        .unwrap_or_default();
+    json!({
+        "system_mode": state.unified_state.system_mode(),
+        "event_sequence": events.sequence,
+        "last_checkpoint_sequence": events.last_checkpoint_sequence,
+        "warnings": warnings,
+    })
+}
+
+/// Trust der Entität aus `entity_id`
+pub fn probe_trust(state: &AppState, body: &Value) -> Value {
+    let entity = body.get("entity_id").and_then(Value::as_str);
+    let trust = entity
+        .and_then(|did| DID::parse(did).ok())
+        .and_then(|did| state.unified_state.core.trust.get_trust(&did.id));
+    json!({ "entity_id": entity, "trust": trust })
+}
+
+/// Generation der wirksamen Konfiguration und Ergebnis des letzten Reloads
+pub fn probe_config(state: &AppState, _body: &Value) -> Value {
+    let last = state.live_config.last_reload();
+    json!({
+        "generation": state.live_config.generation(),
+        "last_outcome": last.map(|r| r.outcome),
+    })
+}
+
+/// Middleware für privilegierte Routen-Gruppen (via `route_layer`)
+pub async fn access_middleware(
+    State(guarded): State<Guarded>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Guarded { state, policy } = guarded;
+    let listener = request
+        .extensions()
+        .get::<Listener>()
+        .copied()
+        .unwrap_or(Listener::Public);
+    let (mut parts, body) = request.into_parts();
+    let method = parts.method.clone();
+    let path = parts
+        .extensions
+        .get::<OriginalUri>()
+        .map(|uri| uri.0.to_string())
+        .unwrap_or_else(|| parts.uri.to_string());
+    let audited = !matches!(method, Method::GET | Method::HEAD | Method::OPTIONS);
+
+    let bytes = match to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
+    };
+    let payload: Value = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+
+    let mut record = AuditRecord {
+        actor: None,
+        role: None,
+        group: policy.group.to_string(),
+        listener: listener.as_str().to_string(),
+        method: method.to_string(),
+        path,
+        request: payload,
+        status: 0,
+        before: None,
+        after: None,
+    };
+
+    if policy.admin_listener_only && listener != Listener::Admin {
+        // Nur zur Zuordnung im Audit Log; die Antwort bleibt 404
+        record.actor = AuthContext::from_request_parts(&mut parts, &state)
+            .await
+            .ok()
+            .map(|auth| auth.did);
+        let response = ApiError::NotFound("route not found".to_string()).into_response();
+        return finish(&state, audited, record, response);
+    }
+
+    let auth = match AuthContext::from_request_parts(&mut parts, &state).await {
+        Ok(auth) => auth,
+        Err(err) => return finish(&state, audited, record, err.into_response()),
+    };
+    let role = auth.role(&state, None);
+    record.actor = Some(auth.did.clone());
+    record.role = Some(role.to_string());
+
+    if role < policy.role {
+        tracing::warn!(
+            did = %auth.did,
+            role = %role,
+            required = %policy.role,
+            group = policy.group,
+            "privileged call denied"
+        );
+        return finish(&state, audited, record, ApiError::Forbidden.into_response());
+    }
+
+    parts.extensions.insert(role);
+    if audited {
+        record.before = Some((policy.probe)(&state, &record.request));
+    }
+    let response = next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await;
+    if audited {
+        record.after = Some((policy.probe)(&state, &record.request));
+    }
+    finish(&state, audited, record, response)
+}
+
+/// Schreibt den Audit-Eintrag (falls nötig) und reicht die Antwort durch
+///
+/// Ohne Akteur (keine gültige Session) wird nicht geschrieben.
+fn finish(
+    state: &AppState,
+    audited: bool,
+    mut record: AuditRecord,
+    response: Response,
+) -> Response {
+    if audited && record.actor.is_none() {
+        tracing::debug!(
+            group = %record.group,
+            path = %record.path,
+            status = response.status().as_u16(),
+            "anonymous privileged call not audited"
+        );
+    } else if audited {
+        record.status = response.status().as_u16();
+        if let Err(err) = state.storage.audit.append(record) {
+            tracing::error!(error = %err, "failed to append audit log entry");
+        }
+    }
+    response
+}
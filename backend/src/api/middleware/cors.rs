@@ -6,7 +6,7 @@
 use crate::server::AppState;
 use axum::http::{HeaderValue, Method};
 use std::time::Duration;
-use tower_http::cors::CorsLayer;
+use tower_http::cors::{AllowOrigin, CorsLayer};
 
 /// Erstellt CORS-Layer basierend auf Environment
 ///
@@ -17,14 +17,19 @@ use tower_http::cors::CorsLayer;
 /// - X-Grpc-Web
 /// - X-User-Agent
 /// - Authorization (für authentifizierte Requests)
+///
+/// In Production wird `application.console_url` pro Request aus der wirksamen
+/// Konfiguration gelesen und folgt damit dem Hot Reload.
 pub fn build_cors(state: &AppState) -> CorsLayer {
     // In Development: sehr permissiv (erlaubt alle Headers inkl. Connect-RPC)
     // In Production: explizite Header-Liste
     // Note: very_permissive() erlaubt bereits alle Headers, was für Connect-RPC ausreicht
     if state.config.application.environment.is_production() {
-        let origin = state.config.application.console_url.clone();
+        let live = state.live_config.clone();
         CorsLayer::new()
-            .allow_origin(origin.as_str().parse::<HeaderValue>().unwrap())
+            .allow_origin(AllowOrigin::predicate(move |origin: &HeaderValue, _| {
+                origin.as_bytes() == live.current().application.console_url.as_bytes()
+            }))
             .allow_methods([
                 Method::GET,
                 Method::POST,
@@ -0,0 +1,324 @@
+//! Idempotency-Keys für schreibende Requests
+//!
+//! Trägt ein POST/PUT/PATCH/DELETE den Header `Idempotency-Key`, gilt:
+//!
+//! - die erste erfolgreiche Antwort wird pro Aufrufer (DID der Session) und
+//!   Key im [`IdempotencyStore`](crate::local::IdempotencyStore) gespeichert
+//!   und bei jeder Wiederholung innerhalb der TTL unverändert ausgeliefert
+//!   (Header `Idempotent-Replayed: true`)
+//! - gleichzeitige Wiederholungen warten über die [`InFlightMap`] auf den
+//!   ersten Request statt selbst Events zu erzeugen
+//! - derselbe Key mit anderer Methode, anderem Pfad oder Body → 422
+//!
+//! Fehlerantworten werden nicht gespeichert: Sie haben keinen Zustand
+//! verändert, eine Wiederholung führt den Request erneut aus. Ebenso
+//! Antworten ohne bekannte Länge (Streams, SSE) oder über
+//! `MAX_RESPONSE_BYTES`: Sie werden unverändert durchgereicht.
+//!
+//! Ausgenommen sind Requests ohne gültige Session (kein Aufrufer, dem der Key
+//! zugeordnet werden könnte), alles unter `/api/v1/auth/` (Antworten enthalten
+//! Tokens) und Connect-RPCs unter `/api/v1/connect/` (Server-Streams).
+//!
+//! [`InFlightMap`]: crate::core::InFlightMap
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    body::{to_bytes, Body, HttpBody},
+    extract::{OriginalUri, Request, State},
+    http::{header::CONTENT_TYPE, HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+use crate::api::constants::API_VERSION;
+use crate::api::v1::auth::{context::bearer_token, AuthContext};
+use crate::local::IdempotencyRecord;
+use crate::server::AppState;
+
+/// Request-Header mit dem Key des Clients
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+/// Antwort-Header bei ausgelieferter gespeicherter Antwort
+pub const IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// Maximale Body-Größe von Requests mit Idempotency-Key
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// Größere Antworten werden nicht gespeichert
+const MAX_RESPONSE_BYTES: usize = 1024 * 1024;
+/// Maximale Länge eines Keys
+const MAX_KEY_LEN: usize = 255;
+
+type InFlightResult = Option<Arc<IdempotencyRecord>>;
+
+/// Middleware für die gesamte API (via `layer`)
+pub async fn idempotency_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let settings = &state.config.idempotency;
+    let mutating = matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    );
+    if !settings.enabled || !mutating {
+        return next.run(request).await;
+    }
+    let Some(key) = request.headers().get(&IDEMPOTENCY_KEY) else {
+        return next.run(request).await;
+    };
+    let path = request
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|uri| uri.0.to_string())
+        .unwrap_or_else(|| request.uri().to_string());
+    if is_excluded_path(&path) {
+        return next.run(request).await;
+    }
+    let key = match key.to_str() {
+        Ok(key) if valid_key(key) => key.to_string(),
+        _ => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "invalid_idempotency_key",
+                format!("Idempotency-Key must be 1-{MAX_KEY_LEN} visible ASCII characters"),
+            )
+        }
+    };
+
+    // Pro Aufrufer: gleiche Keys verschiedener DIDs kollidieren nicht
+    let Ok(caller) = bearer_token(request.headers())
+        .and_then(|token| AuthContext::from_token(&state, token))
+        .map(|auth| auth.did)
+    else {
+        return next.run(request).await;
+    };
+    let scoped = format!("{caller}:{key}");
+
+    let (parts, body) = request.into_parts();
+    let bytes = match to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
+    };
+    let fingerprint = IdempotencyRecord::fingerprint(parts.method.as_str(), &path, &bytes);
+
+    // Erster Request führt aus; Wiederholungen warten. Schlägt der erste fehl
+    // (nichts gespeichert), darf einer der Wartenden selbst ausführen. 409 nur,
+    // wenn bis zum Ablauf von `wait_secs` noch ein Request in-flight ist.
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(settings.wait_secs);
+    loop {
+        let now = chrono::Utc::now().timestamp();
+        match state.storage.idempotency.get(&scoped, now) {
+            Ok(Some(record)) => return replay(&record, &fingerprint),
+            Ok(None) => {}
+            Err(e) => return storage_error(e),
+        }
+
+        let Some(waiter) = state.idempotency.try_register(&scoped) else {
+            let mut guard = InFlight {
+                state: &state,
+                key: &scoped,
+                result: None,
+            };
+            // Der vorige Besitzer kann zwischen Lookup und Registrierung fertig
+            // geworden sein
+            match state.storage.idempotency.get(&scoped, now) {
+                Ok(Some(record)) => {
+                    let response = replay(&record, &fingerprint);
+                    guard.result = Some(Arc::new(record));
+                    return response;
+                }
+                Ok(None) => {}
+                Err(e) => return storage_error(e),
+            }
+            let request = Request::from_parts(parts, Body::from(bytes));
+            return execute(guard, next, request, fingerprint).await;
+        };
+        match tokio::time::timeout_at(deadline, waiter).await {
+            Ok(Ok(Some(record))) => return replay(&record, &fingerprint),
+            // Erster Request ohne gespeicherte Antwort beendet: erneut prüfen
+            Ok(Ok(None)) | Ok(Err(_)) => continue,
+            Err(_) => {
+                return error(
+                    StatusCode::CONFLICT,
+                    "idempotency_key_in_use",
+                    "a request with this Idempotency-Key is still in progress".to_string(),
+                )
+            }
+        }
+    }
+}
+
+/// Hält den Key in-flight; Wartende werden auch bei Abbruch benachrichtigt
+struct InFlight<'a> {
+    state: &'a AppState,
+    key: &'a str,
+    result: InFlightResult,
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.state
+            .idempotency
+            .complete_load(self.key, self.result.take());
+    }
+}
+
+/// Führt den Request aus und speichert eine erfolgreiche Antwort
+async fn execute(
+    mut guard: InFlight<'_>,
+    next: Next,
+    request: Request,
+    fingerprint: String,
+) -> Response {
+    let response = next.run(request).await;
+    if !response.status().is_success() || !bounded(&response) {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_RESPONSE_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => return storage_error(e),
+    };
+    let Ok(text) = std::str::from_utf8(&bytes) else {
+        // Nur Text-Antworten werden gespeichert
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let record = IdempotencyRecord {
+        key: guard.key.to_string(),
+        fingerprint,
+        status: parts.status.as_u16(),
+        content_type: parts
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        body: text.to_string(),
+        created_at: now,
+        expires_at: now + guard.state.config.idempotency.ttl_secs as i64,
+    };
+    match guard.state.storage.idempotency.put(&record) {
+        Ok(()) => guard.result = Some(Arc::new(record)),
+        Err(e) => tracing::error!(error = %e, "failed to store idempotent response"),
+    }
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Gespeicherte Antwort ausliefern (oder 422 bei abweichendem Request)
+fn replay(record: &IdempotencyRecord, fingerprint: &str) -> Response {
+    if record.fingerprint != fingerprint {
+        return error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "idempotency_key_reused",
+            "Idempotency-Key was already used for a different request".to_string(),
+        );
+    }
+    let status = StatusCode::from_u16(record.status).unwrap_or(StatusCode::OK);
+    let mut response = (status, record.body.clone()).into_response();
+    let headers = response.headers_mut();
+    match record
+        .content_type
+        .as_deref()
+        .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+        Some(content_type) => headers.insert(CONTENT_TYPE, content_type),
+        None => headers.remove(CONTENT_TYPE),
+    };
+    headers.insert(IDEMPOTENT_REPLAYED, HeaderValue::from_static("true"));
+    response
+}
+
+fn valid_key(key: &str) -> bool {
+    (1..=MAX_KEY_LEN).contains(&key.len()) && key.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Auth-Endpunkte (Tokens) und Connect-RPCs (Server-Streams) werden nie gespeichert
+fn is_excluded_path(path: &str) -> bool {
+    path.strip_prefix(API_VERSION)
+        .is_some_and(|rest| rest.starts_with("/auth/") || rest.starts_with("/connect/"))
+}
+
+/// Nur Antworten mit bekannter Länge bis `MAX_RESPONSE_BYTES` werden gepuffert
+fn bounded(response: &Response) -> bool {
+    response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|len| len <= MAX_RESPONSE_BYTES as u64)
+}
+
+fn error(status: StatusCode, code: &str, message: String) -> Response {
+    (status, Json(json!({ "error": code, "message": message }))).into_response()
+}
+
+fn storage_error(e: impl std::fmt::Display) -> Response {
+    error(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "storage_error",
+        e.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(body: &str) -> IdempotencyRecord {
+        IdempotencyRecord {
+            key: "did:erynoa:self:k:k".into(),
+            fingerprint: "f".into(),
+            status: 201,
+            content_type: Some("application/json".into()),
+            body: body.into(),
+            created_at: 0,
+            expires_at: 10,
+        }
+    }
+
+    #[test]
+    fn test_valid_key() {
+        assert!(valid_key("3f2c1a6e-8b1d-4c55-9d0e-7a4b2f9c1e00"));
+        assert!(!valid_key(""));
+        assert!(!valid_key("with space"));
+        assert!(!valid_key(&"k".repeat(MAX_KEY_LEN + 1)));
+    }
+
+    #[test]
+    fn test_is_excluded_path() {
+        assert!(is_excluded_path("/api/v1/auth/session/refresh"));
+        assert!(is_excluded_path("/api/v1/auth/passkey/verify?x=1"));
+        assert!(is_excluded_path(
+            "/api/v1/connect/erynoa.v1.SagaService/StreamSagaUpdates"
+        ));
+        assert!(!is_excluded_path("/api/v1/realms"));
+        assert!(!is_excluded_path("/api/v1/authority"));
+    }
+
+    #[test]
+    fn test_only_bounded_responses_are_buffered() {
+        assert!(bounded(&"ok".into_response()));
+        let large = vec![b'x'; MAX_RESPONSE_BYTES + 1];
+        assert!(!bounded(&large.into_response()));
+        let stream = axum_connect::futures::stream::iter([Ok::<_, std::io::Error>("chunk")]);
+        assert!(!bounded(&Body::from_stream(stream).into_response()));
+    }
+
+    #[tokio::test]
+    async fn test_replay_restores_response() {
+        let response = replay(&record(r#"{"ok":true}"#), "f");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+        assert_eq!(response.headers()[IDEMPOTENT_REPLAYED], "true");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], br#"{"ok":true}"#);
+
+        let reused = replay(&record("{}"), "other");
+        assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
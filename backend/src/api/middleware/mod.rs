@@ -2,8 +2,18 @@
 //!
 //! Zentrale Middleware-Komponenten für Request-Verarbeitung
 
+mod access;
 mod cors;
+mod idempotency;
 mod logging;
+mod rate_limit;
+mod trace_context;
 
+pub use access::{
+    access_middleware, probe_config, probe_system, probe_trust, AccessPolicy, Guarded, Listener,
+};
 pub use cors::build_cors;
+pub use idempotency::idempotency_middleware;
 pub use logging::logging_middleware;
+pub use rate_limit::{rate_limit_middleware, RateLimiter};
+pub use trace_context::trace_context_middleware;
@@ -0,0 +1,466 @@
+//! Rate Limiting & Admission Control
+//!
+//! Limits pro Client – authentifizierte DID, ohne Session die IP-Adresse:
+//!
+//! - Request-Quota pro Minute, skaliert mit `BandwidthTier::from_trust`
+//! - Mana-Kosten pro Route über den `ManaManager` (Retry-After aus
+//!   `ManaAccount::time_to_regenerate`)
+//! - Lastabwurf bei degradiertem `SystemMode` (Circuit Breaker): im Modus
+//!   `Degraded` nur noch lesende Requests, bei `EmergencyShutdown` nur
+//!   Health-Checks und die Modus-Recovery
+//!
+//! Abweisungen landen über den `APIObserver` in den `APIState`-Metriken.
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, Request, State},
+    http::{header::RETRY_AFTER, HeaderValue, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::api::constants::API_VERSION;
+use crate::api::v1::auth::AuthContext;
+use crate::config::RateLimitSettings;
+use crate::core::SharedAPIObserver;
+use crate::domain::{SystemMode, TrustVector6D, DID};
+use crate::eclvm::{BandwidthTier, ManaConfig, ManaManager};
+use crate::error::ApiError;
+use crate::server::AppState;
+
+/// Länge des Quota-Fensters
+const WINDOW: Duration = Duration::from_secs(60);
+/// Ab dieser Anzahl Clients werden abgelaufene Fenster aufgeräumt
+const CLEANUP_THRESHOLD: usize = 10_000;
+
+/// Routen mit `expensive_cost` (relativ zu `API_VERSION`)
+const EXPENSIVE_ROUTES: &[&str] = &[
+    "/state/event",
+    "/trust/update",
+    "/intent",
+    "/saga",
+    "/ecl",
+    "/governance",
+];
+
+/// Präfixe lesender Connect-RPCs (alle Connect-Aufrufe sind POST)
+///
+/// Alle anderen RPCs gelten als schreibend: Ein neuer RPC wird im Zweifel
+/// wie ein Write abgerechnet und im Modus `Degraded` abgewiesen.
+const READ_ONLY_RPCS: &[&str] = &[
+    "Get", "List", "Head", "Check", "Ready", "Simulate", "Evaluate", "Stream",
+];
+
+/// Quota-Fenster eines Clients
+#[derive(Debug)]
+struct Window {
+    started: Instant,
+    count: u64,
+}
+
+/// Rate Limiter mit Request-Quota und Mana-Konto pro Client
+pub struct RateLimiter {
+    config: RwLock<RateLimitSettings>,
+    mana: ManaManager,
+    windows: RwLock<HashMap<String, Window>>,
+    observer: Option<SharedAPIObserver>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitSettings) -> Self {
+        Self {
+            config: RwLock::new(config),
+            mana: ManaManager::new(ManaConfig::default()),
+            windows: RwLock::new(HashMap::new()),
+            observer: None,
+        }
+    }
+
+    /// Meldet Buckets, Resets und Abweisungen an einen Observer (APIState)
+    pub fn with_observer(mut self, observer: SharedAPIObserver) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Mana-Konten mit eigener Konfiguration statt `ManaConfig::default()`
+    pub fn with_mana_config(mut self, mana: ManaConfig) -> Self {
+        self.mana = ManaManager::new(mana);
+        self
+    }
+
+    pub fn config(&self) -> RateLimitSettings {
+        self.config.read().unwrap().clone()
+    }
+
+    /// Quota, Routenkosten und Mana-Konfiguration zur Laufzeit austauschen
+    ///
+    /// Laufende Quota-Fenster und Mana-Guthaben bleiben erhalten.
+    pub fn reconfigure(&self, config: RateLimitSettings, mana: ManaConfig) {
+        *self.config.write().unwrap() = config;
+        self.mana.set_config(mana);
+    }
+
+    /// Request-Quota pro Minute für einen Trust-Vektor
+    pub fn quota(&self, trust: &TrustVector6D) -> u64 {
+        let tier = BandwidthTier::from_trust(trust.r as f64);
+        self.config.read().unwrap().requests_per_minute * tier.request_multiplier()
+    }
+
+    /// Mana-Kosten einer Route; `None` für Routen ohne Limit (Health-Checks)
+    pub fn route_cost(&self, method: &Method, path: &str) -> Option<u64> {
+        let route = path.strip_prefix(API_VERSION).unwrap_or(path);
+        if is_health_route(route) {
+            return None;
+        }
+
+        let config = self.config.read().unwrap();
+        let cost = if !is_write(method, route) {
+            config.read_cost
+        } else if EXPENSIVE_ROUTES.iter().any(|r| route.starts_with(r)) {
+            config.expensive_cost
+        } else {
+            config.write_cost
+        };
+        Some(cost)
+    }
+
+    /// Lässt einen Request zu oder liefert die Wartezeit bis zum nächsten Versuch
+    ///
+    /// Zuerst wird die Request-Quota des Fensters geprüft, danach Mana
+    /// abgebucht. Ein abgewiesener Request verbraucht kein Mana.
+    pub fn admit(&self, client: &str, trust: &TrustVector6D, cost: u64) -> Result<(), Duration> {
+        let quota = self.quota(trust);
+        {
+            let mut windows = self.windows.write().unwrap();
+            if windows.len() > CLEANUP_THRESHOLD {
+                windows.retain(|_, w| w.started.elapsed() < WINDOW);
+                self.mana.cleanup_inactive(WINDOW * 10);
+            }
+
+            let window = windows.entry(client.to_string()).or_insert_with(|| {
+                if let Some(observer) = &self.observer {
+                    let requests_per_minute = self.config.read().unwrap().requests_per_minute;
+                    let multiplier = quota as f64 / requests_per_minute.max(1) as f64;
+                    observer.on_rate_limit_bucket_created(
+                        client,
+                        client,
+                        quota,
+                        WINDOW.as_secs(),
+                        multiplier,
+                    );
+                }
+                Window {
+                    started: Instant::now(),
+                    count: 0,
+                }
+            });
+            if window.started.elapsed() >= WINDOW {
+                window.started = Instant::now();
+                window.count = 0;
+                if let Some(observer) = &self.observer {
+                    observer.on_rate_limit_reset(client);
+                }
+            }
+            if window.count >= quota {
+                return Err(WINDOW.saturating_sub(window.started.elapsed()));
+            }
+            window.count += 1;
+        }
+
+        match self.mana.deduct(client, trust, cost) {
+            Ok(()) => Ok(()),
+            Err(ApiError::RateLimited { retry_after }) => Err(retry_after),
+            Err(_) => Ok(()),
+        }
+    }
+
+    /// Meldet eine Abweisung (429) an den Observer
+    fn rejected(&self, client: &str, path: &str, retry_after: Duration) {
+        if let Some(observer) = &self.observer {
+            observer.on_rate_limited(client, path, retry_secs(retry_after), None);
+        }
+    }
+}
+
+/// Ändert der Request Zustand? Connect-RPCs werden am Methodennamen erkannt.
+fn is_write(method: &Method, route: &str) -> bool {
+    match route.strip_prefix("/connect/") {
+        Some(rpc) => {
+            let name = rpc.rsplit('/').next().unwrap_or_default();
+            !READ_ONLY_RPCS.iter().any(|verb| name.starts_with(verb))
+        }
+        None => !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS),
+    }
+}
+
+fn is_health_route(route: &str) -> bool {
+    route == "/health"
+        || route.starts_with("/health/")
+        || route == "/ready"
+        || route.contains("erynoa.v1.HealthService/")
+}
+
+/// Admin-Recovery bleibt auch im Emergency-Shutdown erreichbar
+fn is_recovery_route(route: &str) -> bool {
+    route == "/state/mode" || route == "/state/mode/reset"
+}
+
+/// Aufgerundet auf volle Sekunden, mindestens 1 (Retry-After-Header)
+fn retry_secs(retry_after: Duration) -> u64 {
+    retry_after.as_secs_f64().ceil().max(1.0) as u64
+}
+
+/// Soll der Request im aktuellen Modus abgewiesen werden?
+fn sheds(mode: SystemMode, method: &Method, route: &str) -> bool {
+    if is_health_route(route) || is_recovery_route(route) {
+        return false;
+    }
+    match mode {
+        SystemMode::Normal => false,
+        SystemMode::Degraded => is_write(method, route),
+        SystemMode::EmergencyShutdown => true,
+    }
+}
+
+/// Client-Schlüssel und Trust: DID der Session, sonst IP mit Trust 0
+async fn identify(state: &AppState, request: Request) -> (Request, String, TrustVector6D) {
+    let (mut parts, body) = request.into_parts();
+    let auth = AuthContext::from_request_parts(&mut parts, state)
+        .await
+        .ok();
+    let request = Request::from_parts(parts, body);
+
+    if let Some(auth) = auth {
+        let trust = DID::parse(&auth.did)
+            .ok()
+            .and_then(|did| state.storage.trust.compute_reputation(&did).ok())
+            .unwrap_or_default();
+        return (request, auth.did, trust);
+    }
+
+    let ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    (request, format!("ip:{}", ip), TrustVector6D::ZERO)
+}
+
+/// Middleware: Lastabwurf, Request-Quota und Mana-Kosten pro Route
+pub async fn rate_limit_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let limiter = state.rate_limiter.clone();
+    if !limiter.config().enabled {
+        return next.run(request).await;
+    }
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let route = path.strip_prefix(API_VERSION).unwrap_or(&path);
+
+    let mode = state.unified_state.circuit_breaker.mode();
+    if sheds(mode, &method, route) {
+        state.unified_state.api.record_shed(None);
+        tracing::warn!(method = %method, path = %path, ?mode, "Request shed");
+        let mut response =
+            ApiError::ServiceUnavailable(format!("system mode {:?}", mode)).into_response();
+        response.headers_mut().insert(
+            RETRY_AFTER,
+            HeaderValue::from(limiter.config().shed_retry_after_secs),
+        );
+        return response;
+    }
+
+    let Some(cost) = limiter.route_cost(&method, &path) else {
+        return next.run(request).await;
+    };
+    let (request, client, trust) = identify(&state, request).await;
+    match limiter.admit(&client, &trust, cost) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            limiter.rejected(&client, &path, retry_after);
+            ApiError::RateLimited { retry_after }.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(requests_per_minute: u64) -> RateLimiter {
+        RateLimiter::new(RateLimitSettings {
+            requests_per_minute,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_quota_scales_with_trust_tier() {
+        let limiter = limiter(2);
+        assert_eq!(limiter.quota(&TrustVector6D::ZERO), 2);
+        assert_eq!(limiter.quota(&TrustVector6D::default()), 8);
+
+        for _ in 0..2 {
+            assert!(limiter.admit("ip:1", &TrustVector6D::ZERO, 1).is_ok());
+        }
+        let retry = limiter.admit("ip:1", &TrustVector6D::ZERO, 1).unwrap_err();
+        assert!(retry > Duration::ZERO && retry <= WINDOW);
+
+        // Andere Clients haben eigene Fenster
+        assert!(limiter.admit("ip:2", &TrustVector6D::ZERO, 1).is_ok());
+    }
+
+    #[test]
+    fn test_reconfigure_keeps_windows() {
+        let limiter = limiter(1);
+        assert!(limiter.admit("ip:1", &TrustVector6D::ZERO, 1).is_ok());
+        assert!(limiter.admit("ip:1", &TrustVector6D::ZERO, 1).is_err());
+
+        limiter.reconfigure(
+            RateLimitSettings {
+                requests_per_minute: 2,
+                read_cost: 5,
+                ..Default::default()
+            },
+            ManaConfig::default(),
+        );
+        assert_eq!(limiter.route_cost(&Method::GET, "/api/v1/info"), Some(5));
+        // Zweiter Request im selben Fenster, jetzt innerhalb der Quota
+        assert!(limiter.admit("ip:1", &TrustVector6D::ZERO, 1).is_ok());
+        assert!(limiter.admit("ip:1", &TrustVector6D::ZERO, 1).is_err());
+    }
+
+    #[test]
+    fn test_mana_exhaustion_returns_regeneration_time() {
+        let limiter = limiter(1_000);
+        // Trust 0: 10k Mana, 100 Mana/s Regeneration
+        assert!(limiter.admit("ip:1", &TrustVector6D::ZERO, 10_000).is_ok());
+        let retry = limiter
+            .admit("ip:1", &TrustVector6D::ZERO, 500)
+            .unwrap_err();
+        assert!(retry >= Duration::from_secs(4) && retry <= Duration::from_secs(5));
+        assert_eq!(retry_secs(retry), 5);
+    }
+
+    #[test]
+    fn test_route_costs_and_load_shedding() {
+        let limiter = limiter(120);
+        let cost = |method: Method, path: &str| limiter.route_cost(&method, path);
+
+        assert_eq!(cost(Method::GET, "/api/v1/health"), None);
+        assert_eq!(cost(Method::GET, "/api/v1/info"), Some(10));
+        assert_eq!(cost(Method::POST, "/api/v1/realms"), Some(100));
+        assert_eq!(cost(Method::POST, "/api/v1/state/event"), Some(1_000));
+        assert_eq!(
+            cost(
+                Method::POST,
+                "/api/v1/connect/erynoa.v1.UserService/GetCurrent"
+            ),
+            Some(10)
+        );
+        assert_eq!(
+            cost(
+                Method::POST,
+                "/api/v1/connect/erynoa.v1.IntentService/SubmitIntent"
+            ),
+            Some(100)
+        );
+
+        assert_eq!(
+            cost(
+                Method::POST,
+                "/api/v1/connect/erynoa.v1.SagaService/ExecuteSaga"
+            ),
+            Some(100)
+        );
+
+        assert!(!sheds(SystemMode::Degraded, &Method::GET, "/info"));
+        assert!(sheds(SystemMode::Degraded, &Method::POST, "/realms"));
+        assert!(sheds(SystemMode::EmergencyShutdown, &Method::GET, "/info"));
+        assert!(!sheds(
+            SystemMode::EmergencyShutdown,
+            &Method::POST,
+            "/state/mode/reset"
+        ));
+        assert!(!sheds(
+            SystemMode::EmergencyShutdown,
+            &Method::GET,
+            "/health"
+        ));
+    }
+
+    /// Jeder RPC aus `proto/` ist eindeutig als lesend oder schreibend eingestuft
+    #[test]
+    fn test_connect_rpcs_classified_from_proto() {
+        const READ: &[&str] = &[
+            "Check",
+            "Ready",
+            "GetInfo",
+            "GetStatus",
+            "ListDerivedKeys",
+            "EvaluateGateway",
+            "SimulateIntent",
+            "GetIntentStatus",
+            "ListIntents",
+            "ListSagas",
+            "GetSagaStatus",
+            "GetSagaHistory",
+            "StreamSagaUpdates",
+            "ListEnvironments",
+            "GetEnvironmentTree",
+            "GetEnvironmentInfo",
+            "GetBootstrapStatus",
+            "List",
+            "Head",
+            "GetPresignedUploadUrl",
+            "GetPresignedDownloadUrl",
+            "ListBuckets",
+            "Get",
+            "GetCurrent",
+        ];
+
+        let mut dirs = vec![std::path::PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/proto"
+        ))];
+        let mut rpcs = Vec::new();
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "proto") {
+                    let source = std::fs::read_to_string(&path).unwrap();
+                    let mut service = None;
+                    for line in source.lines().map(str::trim) {
+                        if let Some(rest) = line.strip_prefix("service ") {
+                            service = rest.split_whitespace().next().map(str::to_string);
+                        } else if let Some(rest) = line.strip_prefix("rpc ") {
+                            let name = rest.split('(').next().unwrap().trim().to_string();
+                            rpcs.push((service.clone().unwrap(), name));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(rpcs.iter().any(|(_, name)| name == "ExecuteSaga"));
+
+        for (service, name) in rpcs {
+            let route = format!("/connect/erynoa.v1.{service}/{name}");
+            let expected = !READ.contains(&name.as_str());
+            assert_eq!(is_write(&Method::POST, &route), expected, "{route}");
+            assert_eq!(
+                sheds(SystemMode::Degraded, &Method::POST, &route),
+                expected,
+                "{route}"
+            );
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Trace-Context Middleware
+//!
+//! Öffnet pro Request einen `http.request`-Span und übernimmt einen
+//! eingehenden W3C `traceparent` als Parent, damit Gateway-, ECLVM- und
+//! Saga-Spans im Trace des Aufrufers landen.
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use tracing::{field, Instrument};
+
+use crate::telemetry;
+
+/// Request-Span mit Remote-Parent aus dem `traceparent`-Header
+pub async fn trace_context_middleware(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+
+    let span = tracing::info_span!(
+        "http.request",
+        otel.name = %format!("{} {}", request.method(), route),
+        otel.kind = "server",
+        http.request.method = %request.method(),
+        http.route = %route,
+        http.response.status_code = field::Empty,
+    );
+    telemetry::set_parent_from_headers(&span, request.headers());
+
+    let response = next.run(request).instrument(span.clone()).await;
+    span.record("http.response.status_code", response.status().as_u16());
+    response
+}
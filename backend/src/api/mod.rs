@@ -2,12 +2,16 @@
 //!
 //! Feature-basierte API-Struktur mit Versionierung
 
+mod connect;
 mod constants;
 mod middleware;
+pub mod openapi;
+pub mod proto;
 mod routes;
 pub mod static_files;
 pub mod v1;
 
 pub use constants::API_VERSION;
-pub use routes::create_router;
+pub use middleware::RateLimiter;
+pub use routes::{create_admin_router, create_router};
 pub use static_files::{create_static_router, StaticConfig};
@@ -0,0 +1,296 @@
+//! OpenAPI 3.1 – maschinenlesbare Spezifikation der REST-API
+//!
+//! Erzeugt zur Laufzeit aus den `#[utoipa::path]`-Annotationen der Handler und
+//! den `ToSchema`/`IntoParams`-Typen der Handler-Module; ausgeliefert unter
+//! `GET /api/v1/openapi.json`. Der Rust-Client `erynoa-client` ist gegen
+//! dieselben Typen geschrieben.
+//!
+//! Antworten, die direkt Snapshots aus `core` oder Records aus `local`
+//! zurückgeben, sind als freies JSON-Objekt beschrieben; die Beschreibung der
+//! Antwort nennt den Typ. Geschützte Operationen tragen das Security-Schema
+//! `bearer` (Session-Token aus `/auth/passkey/verify`).
+
+use std::sync::OnceLock;
+
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::OpenApi as OpenApiSpec;
+use utoipa::{Modify, OpenApi, ToSchema};
+
+use super::v1::{
+    audit_handlers, auth::handlers as auth_handlers, config_handlers, debug_handlers,
+    ledger_handlers, listing_handlers, pagination, production_handlers, rest_handlers,
+    saga_handlers, state_handlers, subscriptions,
+};
+
+/// Fehler-Body der Handler-eigenen Fehler (`{"error": "<code>", "message": "..."}`)
+///
+/// Fehler aus Authentifizierung und Zugriffskontrolle verwenden dagegen
+/// [`ErrorResponse`](crate::error::ErrorResponse).
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ErrorBody {
+    /// Maschinenlesbarer Fehlercode (z. B. `realm_not_found`)
+    pub error: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Security-Schema `bearer` für Session-Tokens
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut OpenApiSpec) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(
+                Http::builder()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWS")
+                    .build(),
+            ),
+        );
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Erynoa API", description = "REST-API eines Erynoa-Knotens"),
+    modifiers(&BearerAuth),
+    components(schemas(ErrorBody, pagination::SortOrder, listing_handlers::TrustDirection)),
+    tags(
+        (name = "system", description = "Liveness, Readiness, Info"),
+        (name = "auth", description = "Passkey-Login und Sessions"),
+        (name = "state", description = "Snapshots, Metriken, Modus, Merkle-Sync"),
+        (name = "health", description = "Health-Scores"),
+        (name = "events", description = "State-Event-Log"),
+        (name = "debug", description = "Replay und Checkpoints (nur Admin-Listener)"),
+        (name = "crossing", description = "Realm-Crossings"),
+        (name = "trust", description = "Trust-Werte"),
+        (name = "identity", description = "Identitäten"),
+        (name = "realms", description = "Realms und Mitgliedschaften"),
+        (name = "content", description = "Content-Metadaten"),
+        (name = "ecl", description = "ECL-Handler pro Realm"),
+        (name = "governance", description = "Proposals und quadratisches Voting"),
+        (name = "controller", description = "Autorisierung"),
+        (name = "intent", description = "Intents parsen, simulieren, abfragen"),
+        (name = "saga", description = "Saga-Ausführung"),
+        (name = "ledger", description = "Salden, Supply, Escrow, Journal"),
+        (name = "audit", description = "Audit Log privilegierter Aufrufe"),
+        (name = "config", description = "Wirksame Konfiguration und Hot Reload (nur Admin-Listener)"),
+        (name = "subscriptions", description = "WebSocket-Subscriptions"),
+        (name = "meta", description = "Diese Spezifikation"),
+    ),
+    paths(
+        openapi_handler,
+        rest_handlers::health_handler,
+        rest_handlers::ready_handler,
+        rest_handlers::info_handler,
+        rest_handlers::status_handler,
+        auth_handlers::get_challenge,
+        auth_handlers::register_passkey,
+        auth_handlers::verify_passkey,
+        auth_handlers::refresh_session,
+        auth_handlers::revoke_session,
+        auth_handlers::me_handler,
+        state_handlers::state_snapshot_handler,
+        state_handlers::state_metrics_handler,
+        state_handlers::state_metrics_eclvm_handler,
+        state_handlers::state_metrics_health_handler,
+        state_handlers::state_warnings_list_handler,
+        state_handlers::state_warnings_clear_all_handler,
+        state_handlers::state_warnings_clear_by_key_handler,
+        state_handlers::state_mode_handler,
+        state_handlers::state_mode_set_handler,
+        state_handlers::state_mode_reset_handler,
+        state_handlers::state_circuit_breaker_handler,
+        state_handlers::state_event_apply_handler,
+        state_handlers::state_merkle_root_handler,
+        state_handlers::state_merkle_component_handler,
+        state_handlers::state_delta_handler,
+        state_handlers::state_proof_handler,
+        state_handlers::state_stream_handler,
+        state_handlers::state_component_handler,
+        state_handlers::health_state_handler,
+        state_handlers::health_state_detail_handler,
+        state_handlers::health_aggregate_handler,
+        state_handlers::events_list_handler,
+        state_handlers::event_by_sequence_handler,
+        state_handlers::events_log_snapshot_handler,
+        state_handlers::events_checkpoints_handler,
+        state_handlers::invariants_handler,
+        debug_handlers::debug_replay_handler,
+        debug_handlers::debug_replay_checkpoint_handler,
+        debug_handlers::debug_checkpoint_handler,
+        production_handlers::crossing_validate_handler,
+        production_handlers::crossing_stats_handler,
+        production_handlers::trust_get_handler,
+        production_handlers::trust_update_handler,
+        listing_handlers::trust_edges_handler,
+        production_handlers::identity_root_handler,
+        production_handlers::identity_get_handler,
+        listing_handlers::identity_list_handler,
+        listing_handlers::content_list_handler,
+        production_handlers::realms_list_handler,
+        production_handlers::realm_create_handler,
+        production_handlers::realm_get_handler,
+        production_handlers::realm_members_handler,
+        production_handlers::realm_ecl_handler,
+        production_handlers::ecl_run_handler,
+        production_handlers::ecl_handlers_list_handler,
+        production_handlers::ecl_handler_upload_handler,
+        production_handlers::ecl_handler_delete_handler,
+        production_handlers::ecl_api_handler,
+        production_handlers::ecl_ui_handler,
+        production_handlers::ecl_controller_handler,
+        production_handlers::governance_proposals_list_handler,
+        production_handlers::governance_proposals_create_handler,
+        production_handlers::governance_proposal_get_handler,
+        production_handlers::governance_proposals_vote_handler,
+        production_handlers::governance_proposal_finalize_handler,
+        production_handlers::controller_check_handler,
+        production_handlers::controller_permissions_handler,
+        production_handlers::intent_parse_handler,
+        production_handlers::intent_simulate_handler,
+        production_handlers::intent_query_handler,
+        production_handlers::saga_compose_handler,
+        production_handlers::saga_stats_handler,
+        saga_handlers::saga_execute_handler,
+        saga_handlers::saga_list_handler,
+        saga_handlers::saga_signal_handler,
+        saga_handlers::saga_get_handler,
+        saga_handlers::saga_cancel_handler,
+        saga_handlers::saga_rollback_handler,
+        ledger_handlers::ledger_balances_handler,
+        ledger_handlers::ledger_supply_handler,
+        ledger_handlers::ledger_lock_handler,
+        ledger_handlers::ledger_journal_handler,
+        ledger_handlers::ledger_invariants_handler,
+        audit_handlers::audit_list_handler,
+        audit_handlers::audit_verify_handler,
+        config_handlers::config_get_handler,
+        config_handlers::config_diff_handler,
+        config_handlers::config_reload_handler,
+        subscriptions::subscribe_handler,
+    )
+)]
+pub struct ApiDoc;
+
+/// Die Spezifikation (einmal erzeugt, danach geteilt)
+pub fn spec() -> &'static OpenApiSpec {
+    static SPEC: OnceLock<OpenApiSpec> = OnceLock::new();
+    SPEC.get_or_init(ApiDoc::openapi)
+}
+
+/// GET /api/v1/openapi.json – OpenAPI-Spezifikation dieser API
+#[utoipa::path(
+    get,
+    path = "/api/v1/openapi.json",
+    tag = "meta",
+    summary = "OpenAPI 3.1 specification of this API",
+    responses(
+        (status = 200, description = "OpenAPI-Dokument", body = Object)
+    )
+)]
+pub async fn openapi_handler() -> Json<&'static OpenApiSpec> {
+    Json(spec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::collections::HashSet;
+
+    fn operations(doc: &Value) -> Vec<(String, String, &Value)> {
+        let mut out = Vec::new();
+        for (path, item) in doc["paths"].as_object().unwrap() {
+            for (method, op) in item.as_object().unwrap() {
+                out.push((method.clone(), path.clone(), op));
+            }
+        }
+        out
+    }
+
+    fn refs<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(target)) = map.get("$ref") {
+                    out.push(target);
+                }
+                map.values().for_each(|v| refs(v, out));
+            }
+            Value::Array(items) => items.iter().for_each(|v| refs(v, out)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_spec_covers_routes() {
+        let doc = serde_json::to_value(spec()).unwrap();
+        assert_eq!(doc["openapi"], "3.1.0");
+        assert_eq!(doc["info"]["title"], "Erynoa API");
+
+        let ops = operations(&doc);
+        assert_eq!(ops.len(), 90);
+        let ids: HashSet<&str> = ops
+            .iter()
+            .map(|(_, _, op)| op["operationId"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids.len(), ops.len(), "operationIds müssen eindeutig sein");
+
+        let tags: HashSet<&str> = doc["tags"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap())
+            .collect();
+        for (method, path, op) in &ops {
+            assert!(path.starts_with("/api/v1/"), "{method} {path}");
+            assert!(op["summary"].is_string(), "{method} {path}: summary fehlt");
+            let tag = op["tags"][0].as_str().unwrap();
+            assert!(tags.contains(tag), "{method} {path}: unbekannter Tag {tag}");
+        }
+    }
+
+    #[test]
+    fn test_spec_security_and_refs() {
+        let doc = serde_json::to_value(spec()).unwrap();
+        assert_eq!(
+            doc["components"]["securitySchemes"]["bearer"]["scheme"],
+            "bearer"
+        );
+
+        let secured: Vec<String> = operations(&doc)
+            .into_iter()
+            .filter(|(_, _, op)| op["security"][0]["bearer"].is_array())
+            .map(|(method, path, _)| format!("{method} {path}"))
+            .collect();
+        for expected in [
+            "get /api/v1/me",
+            "post /api/v1/state/mode",
+            "post /api/v1/trust/update",
+            "get /api/v1/audit",
+            "post /api/v1/config/reload",
+        ] {
+            assert!(
+                secured.iter().any(|s| s == expected),
+                "{expected} ohne bearer"
+            );
+        }
+        assert!(!secured.iter().any(|s| s == "get /api/v1/health"));
+
+        let mut targets = Vec::new();
+        refs(&doc["paths"], &mut targets);
+        refs(&doc["components"], &mut targets);
+        assert!(targets.contains(&"#/components/schemas/TrustUpdateBody"));
+        for target in targets {
+            let name = target.strip_prefix("#/components/schemas/").unwrap();
+            assert!(
+                doc["components"]["schemas"][name].is_object(),
+                "{target} nicht definiert"
+            );
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Generierte Protobuf-Typen und Connect-Services (`proto/erynoa/v1/*.proto`)
+//!
+//! Wird von `build.rs` via axum-connect-build erzeugt.
+
+#![allow(clippy::all, clippy::pedantic, dead_code, missing_docs)]
+
+include!(concat!(env!("OUT_DIR"), "/erynoa.v1.rs"));
@@ -1,37 +1,86 @@
 //! API Routes
 //!
 //! REST-basierte API für Health-Checks, Info und WebAuthn
+//!
+//! Privilegierte Gruppen (Modus-Steuerung, Debug, Trust-Updates, Audit,
+//! Konfiguration) sind per [`AccessPolicy`] geschützt; Debug, `/config` und
+//! `/state/event` existieren nur auf dem Admin-Listener.
 
 use crate::server::AppState;
 use axum::{
-    middleware::from_fn,
+    middleware::{from_fn, from_fn_with_state},
     routing::{delete, get, post},
-    Router,
+    Extension, Router,
 };
-// TODO: Rate Limiting mit tower_governor 0.4 korrekt implementieren
-// use tower_governor::{governor::GovernorConfigBuilder, GovernorLayer};
 
+use super::connect::connect_router;
 use super::constants::API_VERSION;
-use super::middleware::{build_cors, logging_middleware};
+use super::middleware::{
+    access_middleware, build_cors, idempotency_middleware, logging_middleware, probe_config, probe_system, probe_trust,
+    rate_limit_middleware, trace_context_middleware, AccessPolicy, Guarded,
+    Listener,
+};
+use super::openapi;
+use super::v1::audit_handlers;
 use super::v1::auth::handlers as auth_handlers;
+use super::v1::auth::Role;
+use super::v1::config_handlers;
 use super::v1::debug_handlers;
+use super::v1::ledger_handlers;
+use super::v1::listing_handlers;
 use super::v1::production_handlers;
+use super::v1::saga_handlers;
 use super::v1::rest_handlers;
 use super::v1::state_handlers;
+use super::v1::subscriptions;
+
+/// Modus-Steuerung und Warnungen: nur Node-Betreiber
+const STATE_CONTROL: AccessPolicy =
+    AccessPolicy::new("state", Role::NodeOperator).with_probe(probe_system);
+
+/// Direktes Einspielen von Events: Node-Betreiber, nur Admin-Listener
+const STATE_EVENTS: AccessPolicy = AccessPolicy::new("state", Role::NodeOperator)
+    .admin_listener_only()
+    .with_probe(probe_system);
+
+/// Replay & Checkpoints: Node-Betreiber, nur Admin-Listener
+const DEBUG: AccessPolicy = AccessPolicy::new("debug", Role::NodeOperator)
+    .admin_listener_only()
+    .with_probe(probe_system);
 
-/// Erstellt den Haupt-Router mit REST-API
+/// Trust-Updates: nur Node-Betreiber (Trust ist global, `from_realm` nur Kontext)
+const TRUST_UPDATE: AccessPolicy =
+    AccessPolicy::new("trust", Role::NodeOperator).with_probe(probe_trust);
+
+/// Audit Log lesen: nur Node-Betreiber
+const AUDIT: AccessPolicy = AccessPolicy::new("audit", Role::NodeOperator);
+
+/// Konfiguration lesen und neu laden: Node-Betreiber, nur Admin-Listener
+const CONFIG: AccessPolicy = AccessPolicy::new("config", Role::NodeOperator)
+    .admin_listener_only()
+    .with_probe(probe_config);
+
+/// Erstellt den Haupt-Router mit REST-API (öffentlicher Listener)
 ///
 /// REST endpoints für Health-Checks und Info sind unter /api/v1/* verfügbar.
 /// Auth endpoints für Passkey/WebAuthn sind unter /api/v1/auth/* verfügbar.
 pub fn create_router(state: AppState) -> Router {
-    let cors = build_cors(&state);
+    build_router(state, Listener::Public)
+}
 
-    // ⚡ PRODUCTION HARDENING: Rate Limiting
-    // TODO: Rate Limiting mit tower_governor 0.4 korrekt implementieren
-    // Verhindert DoS-Attacken und Traffic-Floods
-    // - 50 Requests pro Sekunde pro IP (normale Nutzung)
-    // - Burst von 100 für kurzzeitige Spitzen erlaubt
-    // Temporarily disabled until correct API usage is determined
+/// Erstellt den Router des Admin-Listeners (localhost)
+///
+/// Gleiche Routen wie [`create_router`], zusätzlich die Gruppen mit
+/// `admin_listener_only`.
+pub fn create_admin_router(state: AppState) -> Router {
+    build_router(state, Listener::Admin)
+}
+
+fn build_router(state: AppState, listener: Listener) -> Router {
+    let cors = build_cors(&state);
+    let guard = |policy: AccessPolicy| {
+        from_fn_with_state(Guarded::new(state.clone(), policy), access_middleware)
+    };
 
     // REST routes for health checks and info
     // These are simple endpoints for load balancers, K8s probes, etc.
@@ -46,7 +95,9 @@ pub fn create_router(state: AppState) -> Router {
     let auth_routes = Router::new()
         .route("/challenge", get(auth_handlers::get_challenge))
         .route("/passkey/register", post(auth_handlers::register_passkey))
-        .route("/passkey/verify", post(auth_handlers::verify_passkey));
+        .route("/passkey/verify", post(auth_handlers::verify_passkey))
+        .route("/session/refresh", post(auth_handlers::refresh_session))
+        .route("/session/revoke", post(auth_handlers::revoke_session));
 
     // Phase 1 & 4 & 5: State – Snapshots, Metrics, Warnings, Mode, Circuit Breaker, Event, Merkle/Delta/Stream
     let state_routes = Router::new()
@@ -55,20 +106,28 @@ pub fn create_router(state: AppState) -> Router {
         .route("/metrics/eclvm", get(state_handlers::state_metrics_eclvm_handler))
         .route("/metrics/health", get(state_handlers::state_metrics_health_handler))
         .route("/warnings", get(state_handlers::state_warnings_list_handler))
-        .route("/warnings", delete(state_handlers::state_warnings_clear_all_handler))
-        .route("/warnings/:key", delete(state_handlers::state_warnings_clear_by_key_handler))
-        .route("/mode/reset", post(state_handlers::state_mode_reset_handler))
         .route("/mode", get(state_handlers::state_mode_handler))
-        .route("/mode", post(state_handlers::state_mode_set_handler))
         .route("/circuit_breaker", get(state_handlers::state_circuit_breaker_handler))
-        .route("/event", post(state_handlers::state_event_apply_handler))
         // Phase 5: Merkle & Delta Sync, State-Stream
         .route("/merkle/root", get(state_handlers::state_merkle_root_handler))
-        .route("/merkle/component/:component", get(state_handlers::state_merkle_component_handler))
+        .route("/merkle/component/{component}", get(state_handlers::state_merkle_component_handler))
         .route("/delta", get(state_handlers::state_delta_handler))
-        .route("/proof/:component", get(state_handlers::state_proof_handler))
+        .route("/proof/{component}", get(state_handlers::state_proof_handler))
         .route("/stream", get(state_handlers::state_stream_handler))
-        .route("/:component_name", get(state_handlers::state_component_handler));
+        .route("/{component_name}", get(state_handlers::state_component_handler))
+        .merge(
+            Router::new()
+                .route("/warnings", delete(state_handlers::state_warnings_clear_all_handler))
+                .route("/warnings/{key}", delete(state_handlers::state_warnings_clear_by_key_handler))
+                .route("/mode/reset", post(state_handlers::state_mode_reset_handler))
+                .route("/mode", post(state_handlers::state_mode_set_handler))
+                .route_layer(guard(STATE_CONTROL)),
+        )
+        .merge(
+            Router::new()
+                .route("/event", post(state_handlers::state_event_apply_handler))
+                .route_layer(guard(STATE_EVENTS)),
+        );
 
     let health_routes = Router::new()
         .route("/state", get(state_handlers::health_state_handler))
@@ -78,14 +137,15 @@ pub fn create_router(state: AppState) -> Router {
     let events_routes = Router::new()
         .route("/log/snapshot", get(state_handlers::events_log_snapshot_handler))
         .route("/checkpoints", get(state_handlers::events_checkpoints_handler))
-        .route("/:sequence", get(state_handlers::event_by_sequence_handler))
+        .route("/{sequence}", get(state_handlers::event_by_sequence_handler))
         .route("/", get(state_handlers::events_list_handler));
 
     // Phase 4: Debug – Replay, Checkpoint
     let debug_routes = Router::new()
         .route("/replay/checkpoint", post(debug_handlers::debug_replay_checkpoint_handler))
         .route("/replay", post(debug_handlers::debug_replay_handler))
-        .route("/checkpoint", post(debug_handlers::debug_checkpoint_handler));
+        .route("/checkpoint", post(debug_handlers::debug_checkpoint_handler))
+        .route_layer(guard(DEBUG));
 
     let invariants_routes = Router::new().route("/", get(state_handlers::invariants_handler));
 
@@ -95,29 +155,42 @@ pub fn create_router(state: AppState) -> Router {
         .route("/stats", get(production_handlers::crossing_stats_handler));
 
     let trust_routes = Router::new()
-        .route("/update", post(production_handlers::trust_update_handler))
-        .route("/:did", get(production_handlers::trust_get_handler));
+        .route("/{did}", get(production_handlers::trust_get_handler))
+        .route("/{did}/edges", get(listing_handlers::trust_edges_handler))
+        .merge(
+            Router::new()
+                .route("/update", post(production_handlers::trust_update_handler))
+                .route_layer(guard(TRUST_UPDATE)),
+        );
 
     let identity_routes = Router::new()
         .route("/root", get(production_handlers::identity_root_handler))
-        .route("/:did", get(production_handlers::identity_get_handler));
+        .route("/{did}", get(production_handlers::identity_get_handler))
+        .route("/", get(listing_handlers::identity_list_handler));
+
+    let content_routes = Router::new().route("/", get(listing_handlers::content_list_handler));
 
     let realms_routes = Router::new()
-        .route("/:realm_id/ecl", get(production_handlers::realm_ecl_handler))
-        .route("/:realm_id/members", post(production_handlers::realm_members_handler))
-        .route("/:realm_id", get(production_handlers::realm_get_handler))
+        .route("/{realm_id}/ecl", get(production_handlers::realm_ecl_handler))
+        .route("/{realm_id}/members", post(production_handlers::realm_members_handler))
+        .route("/{realm_id}", get(production_handlers::realm_get_handler))
         .route("/", get(production_handlers::realms_list_handler))
         .route("/", post(production_handlers::realm_create_handler));
 
     let ecl_routes = Router::new()
         .route("/run", post(production_handlers::ecl_run_handler))
-        .route("/api/:route_id", post(production_handlers::ecl_api_handler))
-        .route("/ui/:component_id", post(production_handlers::ecl_ui_handler))
-        .route("/controller/:key", post(production_handlers::ecl_controller_handler));
+        .route("/handlers", get(production_handlers::ecl_handlers_list_handler))
+        .route("/handlers", post(production_handlers::ecl_handler_upload_handler))
+        .route("/handlers/{realm_id}/{kind}/{key}", delete(production_handlers::ecl_handler_delete_handler))
+        .route("/api/{route_id}", post(production_handlers::ecl_api_handler))
+        .route("/ui/{component_id}", post(production_handlers::ecl_ui_handler))
+        .route("/controller/{key}", post(production_handlers::ecl_controller_handler));
 
     // Phase 3: Governance, Controller, Intent, Saga
     let governance_routes = Router::new()
-        .route("/proposals/:id/vote", post(production_handlers::governance_proposals_vote_handler))
+        .route("/proposals/{id}", get(production_handlers::governance_proposal_get_handler))
+        .route("/proposals/{id}/vote", post(production_handlers::governance_proposals_vote_handler))
+        .route("/proposals/{id}/finalize", post(production_handlers::governance_proposal_finalize_handler))
         .route("/proposals", get(production_handlers::governance_proposals_list_handler))
         .route("/proposals", post(production_handlers::governance_proposals_create_handler));
 
@@ -125,16 +198,49 @@ pub fn create_router(state: AppState) -> Router {
         .route("/check", post(production_handlers::controller_check_handler))
         .route("/permissions", get(production_handlers::controller_permissions_handler));
 
-    let intent_routes = Router::new().route("/parse", post(production_handlers::intent_parse_handler));
+    let intent_routes = Router::new()
+        .route("/parse", post(production_handlers::intent_parse_handler))
+        .route("/simulate", post(production_handlers::intent_simulate_handler))
+        .route("/query", post(production_handlers::intent_query_handler));
 
     let saga_routes = Router::new()
         .route("/compose", post(production_handlers::saga_compose_handler))
-        .route("/execute", post(production_handlers::saga_execute_handler))
-        .route("/stats", get(production_handlers::saga_stats_handler));
+        .route("/execute", post(saga_handlers::saga_execute_handler))
+        .route("/stats", get(production_handlers::saga_stats_handler))
+        .route("/list", get(saga_handlers::saga_list_handler))
+        .route("/signal", post(saga_handlers::saga_signal_handler))
+        .route("/{saga_id}", get(saga_handlers::saga_get_handler))
+        .route("/{saga_id}/cancel", post(saga_handlers::saga_cancel_handler))
+        .route("/{saga_id}/rollback", post(saga_handlers::saga_rollback_handler));
+
+    // Token-Ledger – Salden, Supply, Escrow, Journal (read-only)
+    let ledger_routes = Router::new()
+        .route("/balances/{did}", get(ledger_handlers::ledger_balances_handler))
+        .route("/supply/{asset}", get(ledger_handlers::ledger_supply_handler))
+        .route("/locks/{lock_id}", get(ledger_handlers::ledger_lock_handler))
+        .route("/journal", get(ledger_handlers::ledger_journal_handler))
+        .route("/invariants", get(ledger_handlers::ledger_invariants_handler));
+
+    // Audit Log privilegierter Aufrufe (read-only)
+    let audit_routes = Router::new()
+        .route("/verify", get(audit_handlers::audit_verify_handler))
+        .route("/", get(audit_handlers::audit_list_handler))
+        .route_layer(guard(AUDIT));
+
+    // Wirksame Konfiguration, Reload-Diff, manueller Reload
+    let config_routes = Router::new()
+        .route("/", get(config_handlers::config_get_handler))
+        .route("/diff", get(config_handlers::config_diff_handler))
+        .route("/reload", post(config_handlers::config_reload_handler))
+        .route_layer(guard(CONFIG));
 
     // API Router mit REST routes, Auth, State, Health, Events, Invariants, Phase 2, Phase 3
     let api = Router::new()
         .merge(rest_routes)
+        .route("/me", get(auth_handlers::me_handler))
+        // WebSocket-Subscriptions (Events, Trust, Saga, Deltas)
+        .route("/subscribe", get(subscriptions::subscribe_handler))
+        .route("/openapi.json", get(openapi::openapi_handler))
         .nest("/auth", auth_routes)
         .nest("/state", state_routes)
         .nest("/health", health_routes)
@@ -144,18 +250,30 @@ pub fn create_router(state: AppState) -> Router {
         .nest("/trust", trust_routes)
         .nest("/identity", identity_routes)
         .nest("/realms", realms_routes)
+        .nest("/content", content_routes)
         .nest("/ecl", ecl_routes)
         .nest("/governance", governance_routes)
         .nest("/controller", controller_routes)
         .nest("/intent", intent_routes)
         .nest("/saga", saga_routes)
-        .nest("/debug", debug_routes);
+        .nest("/ledger", ledger_routes)
+        .nest("/debug", debug_routes)
+        .nest("/audit", audit_routes)
+        .nest("/config", config_routes)
+        // Connect-RPC Services (erynoa.v1.*) parallel zu REST
+        .nest("/connect", connect_router());
 
     // Haupt-Router mit Middleware und State
     Router::new()
         .nest(API_VERSION, api)
+        // Idempotency-Key: Wiederholungen schreibender Requests liefern die gespeicherte Antwort
+        .layer(from_fn_with_state(state.clone(), idempotency_middleware))
+        // ⚡ Rate Limiting pro DID/IP (innerhalb CORS, damit 429 CORS-Header trägt)
+        .layer(from_fn_with_state(state.clone(), rate_limit_middleware))
         .layer(cors)
-        // .layer(rate_limit_layer)  // ⚡ Rate Limiting - TODO: Re-enable after fixing API usage
         .layer(from_fn(logging_middleware))
+        // Request-Span mit eingehendem W3C traceparent (äußerste Schicht)
+        .layer(from_fn(trace_context_middleware))
+        .layer(Extension(listener))
         .with_state(state)
 }
@@ -0,0 +1,101 @@
+//! Audit Log – Abfrage und Ketten-Prüfung
+//!
+//! Read-only Endpoints auf `storage.audit`. Einträge entstehen ausschließlich
+//! in der Zugriffskontrolle privilegierter Routen (`access_middleware`).
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::local::{AuditEntry, AuditVerification};
+use crate::server::AppState;
+
+// ============================================================================
+// Request / Response types
+// ============================================================================
+
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditListQuery {
+    /// Ab dieser Sequenz (inklusive)
+    pub from_seq: Option<u64>,
+    pub limit: Option<usize>,
+    /// Nur Aufrufe dieser DID
+    pub actor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditListResponse {
+    #[schema(value_type = Vec<Object>)]
+    pub entries: Vec<AuditEntry>,
+    pub total: u64,
+    pub head_hash: String,
+}
+
+fn storage_error(e: impl std::fmt::Display) -> axum::response::Response {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({ "error": "storage_error", "message": e.to_string() })),
+    )
+        .into_response()
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// GET /api/v1/audit – Einträge ab `from_seq`, optional gefiltert nach `actor`
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit",
+    tag = "audit",
+    summary = "Audit log entries",
+    params(AuditListQuery),
+    responses(
+        (status = 200, description = "Einträge und Kettenkopf", body = AuditListResponse),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn audit_list_handler(
+    State(state): State<AppState>,
+    Query(q): Query<AuditListQuery>,
+) -> impl IntoResponse {
+    let limit = q.limit.unwrap_or(100).min(1000);
+    let audit = &state.storage.audit;
+    match audit.range(q.from_seq.unwrap_or(0), limit, q.actor.as_deref()) {
+        Ok(entries) => Json(AuditListResponse {
+            entries,
+            total: audit.len(),
+            head_hash: audit.head_hash(),
+        })
+        .into_response(),
+        Err(e) => storage_error(e),
+    }
+}
+
+/// GET /api/v1/audit/verify – Prüft die Hash-Kette vollständig
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit/verify",
+    tag = "audit",
+    summary = "Verify the audit hash chain",
+    responses(
+        (status = 200, description = "AuditVerification", body = serde_json::Value),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn audit_verify_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.storage.audit.verify() {
+        Ok(verification) => Json::<AuditVerification>(verification).into_response(),
+        Err(e) => storage_error(e),
+    }
+}
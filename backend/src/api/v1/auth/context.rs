@@ -0,0 +1,112 @@
+//! Authenticated Request Context
+//!
+//! Axum extractor that validates the `Authorization: Bearer <token>` header
+//! against the [`SessionManager`](super::SessionManager) and exposes the
+//! caller's DID and granted capabilities to handlers.
+
+use axum::{
+    extract::FromRequestParts,
+    http::{header::AUTHORIZATION, request::Parts, HeaderMap},
+};
+
+use super::session::{SessionClaims, SessionError};
+use crate::domain::unified::identity::Capability;
+use crate::domain::UniversalId;
+use crate::error::ApiError;
+use crate::peer::intent_grammar::parse_did_uri;
+use crate::server::AppState;
+
+/// Authenticated caller of a request
+#[derive(Debug, Clone)]
+pub struct AuthContext {
+    /// Caller DID (URI)
+    pub did: String,
+    /// Caller identity
+    pub id: UniversalId,
+    /// Session ID
+    pub session_id: String,
+    /// Realm scope of the session (if any)
+    pub realm: Option<String>,
+    /// Granted capabilities
+    pub capabilities: Vec<Capability>,
+    /// Access token expiry (Unix seconds)
+    pub expires_at: i64,
+}
+
+impl AuthContext {
+    /// Builds the context from verified claims
+    pub fn from_claims(claims: SessionClaims) -> Result<Self, ApiError> {
+        let id = parse_did_uri(&claims.sub)
+            .map_err(|e| ApiError::InvalidToken(format!("invalid subject: {}", e)))?;
+        Ok(Self {
+            capabilities: claims.capabilities(),
+            did: claims.sub,
+            id,
+            session_id: claims.sid,
+            realm: claims.realm,
+            expires_at: claims.exp,
+        })
+    }
+
+    /// Verifies a raw access token (e.g. from a WebSocket query parameter)
+    pub fn from_token(state: &AppState, token: &str) -> Result<Self, ApiError> {
+        let claims = state.sessions.verify(token.trim()).map_err(|e| match e {
+            SessionError::Storage(e) => ApiError::Internal(e),
+            other => ApiError::InvalidToken(other.to_string()),
+        })?;
+        Self::from_claims(claims)
+    }
+
+    /// Does the session grant `capability`?
+    pub fn has(&self, capability: &Capability) -> bool {
+        self.capabilities.iter().any(|c| c.implies(capability))
+    }
+
+    /// Requires `capability`, otherwise 403
+    pub fn require(&self, capability: &Capability) -> Result<(), ApiError> {
+        if self.has(capability) {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden)
+        }
+    }
+
+    /// Requires that the session is not scoped to a different realm, otherwise 403
+    pub fn require_realm(&self, realm_id: &str) -> Result<(), ApiError> {
+        match &self.realm {
+            Some(scope) if scope != realm_id => Err(ApiError::Forbidden),
+            _ => Ok(()),
+        }
+    }
+
+    /// Requires that an optional body DID (legacy field) matches the caller, otherwise 403
+    pub fn require_self(&self, claimed: Option<&str>) -> Result<(), ApiError> {
+        match claimed {
+            Some(did) if did != self.did => Err(ApiError::Forbidden),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Extracts the token from an `Authorization: Bearer <token>` header
+pub fn bearer_token(headers: &HeaderMap) -> Result<&str, ApiError> {
+    let header = headers
+        .get(AUTHORIZATION)
+        .ok_or_else(|| ApiError::Unauthorized("missing bearer token".to_string()))?
+        .to_str()
+        .map_err(|_| ApiError::Unauthorized("invalid authorization header".to_string()))?;
+
+    header
+        .strip_prefix("Bearer ")
+        .or_else(|| header.strip_prefix("bearer "))
+        .ok_or_else(|| ApiError::Unauthorized("expected bearer token".to_string()))
+}
+
+impl FromRequestParts<AppState> for AuthContext {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, ApiError> {
+        let token = bearer_token(&parts.headers)?;
+        Self::from_token(state, token)
+    }
+}
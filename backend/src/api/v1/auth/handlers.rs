@@ -5,10 +5,17 @@
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use rand::RngCore;
 
+use super::context::AuthContext;
+use super::session::{SessionError, SessionRequest, TokenPair};
 use super::types::{
-    is_supported_algorithm, ChallengeResponse, PasskeyRegistrationRequest,
+    is_supported_algorithm, ChallengeResponse, MeResponse, PasskeyRegistrationRequest,
     PasskeyRegistrationResponse, PasskeyVerificationRequest, PasskeyVerificationResponse,
+    SessionRefreshRequest, SessionRevokeRequest, SessionRevokeResponse,
 };
+use super::webauthn::{check_sign_count, AssertionPolicy, WebAuthnError};
+use crate::domain::unified::passkey::{PasskeyPublicKey, PublicKeyError};
+use crate::error::{ApiError, Result as ApiResult};
+use crate::local::ChallengeRecord;
 use crate::server::AppState;
 
 /// Base64URL encode bytes
@@ -23,9 +30,6 @@ fn base64url_decode(s: &str) -> Result<Vec<u8>, base64::DecodeError> {
     URL_SAFE_NO_PAD.decode(s)
 }
 
-/// Challenge validity in seconds (5 minutes)
-const CHALLENGE_VALIDITY_SECS: i64 = 300;
-
 // ============================================================================
 // CHALLENGE ENDPOINT
 // ============================================================================
@@ -33,29 +37,45 @@ const CHALLENGE_VALIDITY_SECS: i64 = 300;
 /// GET /api/v1/auth/challenge
 ///
 /// Generates a new cryptographically secure challenge for WebAuthn operations.
-/// The challenge is a 32-byte random value, Base64URL encoded.
+/// The challenge is a 32-byte random value, Base64URL encoded. It is stored
+/// server-side and can be redeemed exactly once before it expires
+/// (`[auth] challenge_ttl_secs`).
 ///
 /// # Response
 /// ```json
 /// {
 ///     "challenge": "base64url-encoded-32-bytes",
+///     "challenge_id": "uuid",
 ///     "expires_at": 1706745600
 /// }
 /// ```
-pub async fn get_challenge() -> impl IntoResponse {
+#[utoipa::path(
+    get,
+    path = "/api/v1/auth/challenge",
+    tag = "auth",
+    summary = "Issue a single-use WebAuthn challenge",
+    responses(
+        (status = 200, description = "Challenge", body = ChallengeResponse)
+    )
+)]
+pub async fn get_challenge(State(state): State<AppState>) -> ApiResult<Json<ChallengeResponse>> {
     let mut challenge_bytes = [0u8; 32];
     rand::rngs::OsRng.fill_bytes(&mut challenge_bytes);
 
-    let challenge = base64url_encode(&challenge_bytes);
-    let expires_at = chrono::Utc::now().timestamp() + CHALLENGE_VALIDITY_SECS;
-
-    let response = ChallengeResponse {
-        challenge,
-        challenge_id: None, // Could add tracking if needed
-        expires_at: Some(expires_at),
+    let now = chrono::Utc::now().timestamp();
+    let record = ChallengeRecord {
+        id: uuid::Uuid::new_v4().to_string(),
+        challenge: base64url_encode(&challenge_bytes),
+        created_at: now,
+        expires_at: now + state.live_config.current().auth.challenge_ttl_secs as i64,
     };
+    state.storage.challenges.put(&record)?;
 
-    (StatusCode::OK, Json(response))
+    Ok(Json(ChallengeResponse {
+        challenge: record.challenge,
+        challenge_id: Some(record.id),
+        expires_at: Some(record.expires_at),
+    }))
 }
 
 // ============================================================================
@@ -67,6 +87,10 @@ pub async fn get_challenge() -> impl IntoResponse {
 /// Registers a new Passkey credential with the backend.
 /// This stores the public key for future signature verification.
 ///
+/// The DID must be `did:erynoa:<namespace>:<hex>` over the key's DID key
+/// material (Ed25519: the raw key, P-256: SHA-256 of the compressed point).
+/// A credential ID that is already bound to another DID is rejected.
+///
 /// # Request Body
 /// ```json
 /// {
@@ -86,6 +110,18 @@ pub async fn get_challenge() -> impl IntoResponse {
 ///     "did": "did:erynoa:self:abc123"
 /// }
 /// ```
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/passkey/register",
+    tag = "auth",
+    summary = "Register a passkey credential",
+    request_body = PasskeyRegistrationRequest,
+    responses(
+        (status = 200, description = "Registriert", body = PasskeyRegistrationResponse),
+        (status = 400, description = "Ungültiger Schlüssel, Algorithmus oder DID passt nicht zum Schlüssel", body = PasskeyRegistrationResponse),
+        (status = 409, description = "Credential-ID gehört zu einer anderen DID", body = PasskeyRegistrationResponse)
+    )
+)]
 pub async fn register_passkey(
     State(state): State<AppState>,
     Json(request): Json<PasskeyRegistrationRequest>,
@@ -117,35 +153,94 @@ pub async fn register_passkey(
         }
     };
 
-    // Validate Ed25519 key length (32 bytes)
-    if request.algorithm == super::types::cose::ED25519 && public_key_bytes.len() != 32 {
+    // Parse raw / COSE_Key / SEC1 / SPKI key for the algorithm
+    let public_key = match PasskeyPublicKey::parse(request.algorithm, &public_key_bytes) {
+        Ok(key) => key,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(PasskeyRegistrationResponse {
+                    success: false,
+                    did: None,
+                    error: Some(e.to_string()),
+                }),
+            );
+        }
+    };
+
+    // Normalized hex for storage (compatible with existing identity_store)
+    let public_key_hex = public_key.to_hex();
+
+    // Parse DID to validate format
+    let did = match request.did.parse::<crate::domain::DID>() {
+        Ok(did) => did,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(PasskeyRegistrationResponse {
+                    success: false,
+                    did: None,
+                    error: Some("Invalid DID format".to_string()),
+                }),
+            );
+        }
+    };
+
+    // The DID must be derived from the registered key, otherwise anyone could
+    // bind their key to someone else's DID
+    let expected_did = public_key.did_uri(did.namespace);
+    if request.did != expected_did {
         return (
             StatusCode::BAD_REQUEST,
             Json(PasskeyRegistrationResponse {
                 success: false,
                 did: None,
                 error: Some(format!(
-                    "Invalid Ed25519 public key length: {} (expected 32)",
-                    public_key_bytes.len()
+                    "DID does not match public key (expected {expected_did})"
                 )),
             }),
         );
     }
 
-    // Convert to hex for storage (compatible with existing identity_store)
-    let public_key_hex = hex::encode(&public_key_bytes);
-
-    // Parse DID to validate format
-    let did_result: Result<crate::domain::DID, _> = request.did.parse();
-    if did_result.is_err() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(PasskeyRegistrationResponse {
-                success: false,
-                did: None,
-                error: Some("Invalid DID format".to_string()),
-            }),
-        );
+    // A credential ID stays bound to its DID; re-registering it is a no-op so
+    // the stored signature counter is kept
+    match state
+        .storage
+        .identities
+        .get_passkey_credential(&request.credential_id)
+    {
+        Ok(Some(existing)) if existing.did == request.did => {
+            return (
+                StatusCode::OK,
+                Json(PasskeyRegistrationResponse {
+                    success: true,
+                    did: Some(request.did),
+                    error: None,
+                }),
+            );
+        }
+        Ok(Some(_)) => {
+            return (
+                StatusCode::CONFLICT,
+                Json(PasskeyRegistrationResponse {
+                    success: false,
+                    did: None,
+                    error: Some("Credential ID already registered".to_string()),
+                }),
+            );
+        }
+        Ok(None) => {}
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to look up passkey credential");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(PasskeyRegistrationResponse {
+                    success: false,
+                    did: None,
+                    error: Some("Failed to store credential".to_string()),
+                }),
+            );
+        }
     }
 
     // Store the passkey credential
@@ -155,7 +250,7 @@ pub async fn register_passkey(
         public_key_hex,
         algorithm: request.algorithm,
         did: request.did.clone(),
-        namespace: request.namespace,
+        namespace: did.namespace.to_string(),
         display_name: request.display_name,
         transports: request.transports,
         sign_count: 0,
@@ -215,6 +310,19 @@ pub async fn register_passkey(
 ///     "client_data_json": "base64url-client-data"
 /// }
 /// ```
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/passkey/verify",
+    tag = "auth",
+    summary = "Verify a passkey assertion and open a session",
+    request_body = PasskeyVerificationRequest,
+    responses(
+        (status = 200, description = "Angemeldet, Session-Tokens im Body", body = PasskeyVerificationResponse),
+        (status = 400, description = "Ungültige Assertion", body = PasskeyVerificationResponse),
+        (status = 401, description = "Signatur, Challenge oder Zähler ungültig", body = PasskeyVerificationResponse),
+        (status = 404, description = "Credential unbekannt", body = PasskeyVerificationResponse)
+    )
+)]
 pub async fn verify_passkey(
     State(state): State<AppState>,
     Json(request): Json<PasskeyVerificationRequest>,
@@ -233,6 +341,7 @@ pub async fn verify_passkey(
                     success: false,
                     did: None,
                     error: Some("Credential not found".to_string()),
+                    session: None,
                 }),
             );
         }
@@ -244,6 +353,7 @@ pub async fn verify_passkey(
                     success: false,
                     did: None,
                     error: Some("Internal error".to_string()),
+                    session: None,
                 }),
             );
         }
@@ -259,6 +369,7 @@ pub async fn verify_passkey(
                     success: false,
                     did: None,
                     error: Some("Invalid signature encoding".to_string()),
+                    session: None,
                 }),
             );
         }
@@ -274,6 +385,7 @@ pub async fn verify_passkey(
                     success: false,
                     did: None,
                     error: Some("Invalid authenticator data".to_string()),
+                    session: None,
                 }),
             );
         }
@@ -288,11 +400,26 @@ pub async fn verify_passkey(
                     success: false,
                     did: None,
                     error: Some("Invalid client data".to_string()),
+                    session: None,
                 }),
             );
         }
     };
 
+    // Validate ceremony type, origin, RP ID hash and UP/UV flags (live config)
+    let policy = AssertionPolicy::from(&state.live_config.current().auth);
+    let assertion = match policy.validate(&client_data_json, &auth_data) {
+        Ok(assertion) => assertion,
+        Err(e) => {
+            tracing::warn!(
+                credential_id = %request.credential_id,
+                error = %e,
+                "Passkey assertion rejected"
+            );
+            return verification_failure(StatusCode::UNAUTHORIZED, e.to_string());
+        }
+    };
+
     // Compute client data hash (SHA-256)
     use sha2::{Digest, Sha256};
     let client_data_hash = Sha256::digest(&client_data_json);
@@ -301,26 +428,61 @@ pub async fn verify_passkey(
     let mut signed_data = auth_data.clone();
     signed_data.extend_from_slice(&client_data_hash);
 
-    // Verify signature based on algorithm
-    let verification_result = match stored.algorithm {
-        super::types::cose::ED25519 => {
-            verify_ed25519_signature(&stored.public_key_hex, &signed_data, &signature_bytes)
-        }
-        _ => {
-            // For now, only Ed25519 is fully implemented
-            Err("Algorithm verification not implemented".to_string())
-        }
-    };
+    // Verify signature based on algorithm (Ed25519 or ES256)
+    let verification_result =
+        PasskeyPublicKey::from_stored(stored.algorithm, &stored.public_key_hex)
+            .and_then(|key| key.verify(&signed_data, &signature_bytes));
 
     match verification_result {
         Ok(true) => {
-            // Update last used timestamp
-            if let Err(e) = state
+            // Redeem the challenge (single-use, must not be expired)
+            let now = chrono::Utc::now().timestamp();
+            match state
                 .storage
-                .identities
-                .update_passkey_last_used(&request.credential_id)
+                .challenges
+                .consume(&assertion.client_data.challenge, now)
             {
-                tracing::warn!(error = %e, "Failed to update last used timestamp");
+                Ok(Some(_)) => {}
+                Ok(None) => {
+                    return verification_failure(
+                        StatusCode::UNAUTHORIZED,
+                        WebAuthnError::InvalidChallenge.to_string(),
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to redeem challenge");
+                    return verification_failure(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal error".to_string(),
+                    );
+                }
+            }
+
+            // Cloned authenticator detection; check and update the signature
+            // counter in one step so concurrent assertions cannot both pass
+            let sign_count = assertion.authenticator_data.sign_count;
+            match state.storage.identities.advance_passkey_sign_count(
+                &request.credential_id,
+                sign_count,
+                |stored_count| check_sign_count(stored_count, sign_count),
+            ) {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => {
+                    tracing::warn!(
+                        did = %stored.did,
+                        credential_id = %request.credential_id,
+                        error = %e,
+                        "Passkey sign counter regression"
+                    );
+                    return verification_failure(StatusCode::UNAUTHORIZED, e.to_string());
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to update signature counter");
+                    return verification_failure(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Internal error".to_string(),
+                    );
+                }
             }
 
             tracing::info!(
@@ -329,14 +491,46 @@ pub async fn verify_passkey(
                 "Passkey verification successful"
             );
 
-            (
-                StatusCode::OK,
-                Json(PasskeyVerificationResponse {
-                    success: true,
-                    did: Some(stored.did),
-                    error: None,
-                }),
-            )
+            // Start a session for the verified DID
+            let session_request = SessionRequest {
+                realm: request.realm_id,
+                capabilities: request.capabilities,
+            };
+            match state
+                .sessions
+                .issue(&stored.did, &request.credential_id, session_request)
+            {
+                Ok(tokens) => (
+                    StatusCode::OK,
+                    Json(PasskeyVerificationResponse {
+                        success: true,
+                        did: Some(stored.did),
+                        error: None,
+                        session: Some(tokens),
+                    }),
+                ),
+                Err(SessionError::Storage(e)) => {
+                    tracing::error!(error = %e, "Failed to store session");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(PasskeyVerificationResponse {
+                            success: false,
+                            did: None,
+                            error: Some("Failed to start session".to_string()),
+                            session: None,
+                        }),
+                    )
+                }
+                Err(e) => (
+                    StatusCode::BAD_REQUEST,
+                    Json(PasskeyVerificationResponse {
+                        success: false,
+                        did: None,
+                        error: Some(e.to_string()),
+                        session: None,
+                    }),
+                ),
+            }
         }
         Ok(false) => {
             tracing::warn!(
@@ -350,55 +544,144 @@ pub async fn verify_passkey(
                     success: false,
                     did: None,
                     error: Some("Invalid signature".to_string()),
+                    session: None,
                 }),
             )
         }
+        Err(PublicKeyError::InvalidSignature(reason)) => {
+            verification_failure(StatusCode::BAD_REQUEST, reason)
+        }
         Err(e) => {
             tracing::error!(error = %e, "Passkey verification error");
-
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(PasskeyVerificationResponse {
-                    success: false,
-                    did: None,
-                    error: Some(e),
-                }),
-            )
+            verification_failure(StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
         }
     }
 }
 
-/// Verify an Ed25519 signature
-fn verify_ed25519_signature(
-    public_key_hex: &str,
-    message: &[u8],
-    signature: &[u8],
-) -> Result<bool, String> {
-    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
-
-    // Decode public key from hex
-    let public_key_bytes =
-        hex::decode(public_key_hex).map_err(|e| format!("Invalid public key hex: {}", e))?;
-
-    // Create verifying key
-    let verifying_key = VerifyingKey::try_from(public_key_bytes.as_slice())
-        .map_err(|e| format!("Invalid Ed25519 public key: {}", e))?;
-
-    // Parse signature (64 bytes for Ed25519)
-    if signature.len() != 64 {
-        return Err(format!(
-            "Invalid signature length: {} (expected 64)",
-            signature.len()
-        ));
-    }
+/// Failed verification response
+fn verification_failure(
+    status: StatusCode,
+    error: String,
+) -> (StatusCode, Json<PasskeyVerificationResponse>) {
+    (
+        status,
+        Json(PasskeyVerificationResponse {
+            success: false,
+            did: None,
+            error: Some(error),
+            session: None,
+        }),
+    )
+}
 
-    let sig_bytes: [u8; 64] = signature
-        .try_into()
-        .map_err(|_| "Invalid signature length")?;
-    let sig = Signature::from_bytes(&sig_bytes);
+// ============================================================================
+// SESSION ENDPOINTS
+// ============================================================================
 
-    // Verify
-    Ok(verifying_key.verify(message, &sig).is_ok())
+/// POST /api/v1/auth/session/refresh
+///
+/// Exchanges a refresh token for a new token pair. The refresh token is
+/// rotated; presenting an old one again revokes the session.
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/session/refresh",
+    tag = "auth",
+    summary = "Exchange a refresh token for a new token pair",
+    request_body = SessionRefreshRequest,
+    responses(
+        (status = 200, description = "Neues Token-Paar", body = TokenPair),
+        (status = 401, description = "Refresh-Token ungültig oder widerrufen", body = crate::error::ErrorResponse)
+    )
+)]
+pub async fn refresh_session(
+    State(state): State<AppState>,
+    Json(request): Json<SessionRefreshRequest>,
+) -> ApiResult<Json<TokenPair>> {
+    state
+        .sessions
+        .refresh(&request.refresh_token)
+        .map(Json)
+        .map_err(session_error)
+}
+
+/// POST /api/v1/auth/session/revoke
+///
+/// Revokes the calling session, another session of the caller
+/// (`session_id`) or all sessions of the caller (`all: true`).
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/session/revoke",
+    tag = "auth",
+    summary = "Revoke sessions of the caller",
+    request_body = SessionRevokeRequest,
+    responses(
+        (status = 200, description = "Widerrufene Sessions", body = SessionRevokeResponse),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    request: Option<Json<SessionRevokeRequest>>,
+) -> ApiResult<Json<SessionRevokeResponse>> {
+    let request = request.map(|Json(r)| r).unwrap_or_default();
+
+    let revoked = if request.all {
+        state
+            .sessions
+            .revoke_all(&auth.did)
+            .map_err(session_error)?
+    } else {
+        let session_id = request
+            .session_id
+            .unwrap_or_else(|| auth.session_id.clone());
+        match state.sessions.session(&session_id).map_err(session_error)? {
+            Some(record) if record.did == auth.did => {
+                usize::from(state.sessions.revoke(&session_id).map_err(session_error)?)
+            }
+            Some(_) => return Err(ApiError::Forbidden),
+            None => return Err(ApiError::NotFound(format!("session {}", session_id))),
+        }
+    };
+
+    tracing::info!(did = %auth.did, revoked, "Sessions revoked");
+    Ok(Json(SessionRevokeResponse { revoked }))
+}
+
+/// GET /api/v1/me
+///
+/// Returns the authenticated caller of the request.
+#[utoipa::path(
+    get,
+    path = "/api/v1/me",
+    tag = "auth",
+    summary = "Authenticated caller",
+    responses(
+        (status = 200, description = "Aufrufer der Session", body = MeResponse),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn me_handler(auth: AuthContext) -> Json<MeResponse> {
+    Json(MeResponse {
+        capabilities: auth
+            .capabilities
+            .iter()
+            .map(|c| c.to_string_repr())
+            .collect(),
+        did: auth.did,
+        session_id: auth.session_id,
+        realm_id: auth.realm,
+        expires_at: auth.expires_at,
+    })
+}
+
+fn session_error(error: SessionError) -> ApiError {
+    match error {
+        SessionError::Storage(e) => ApiError::Internal(e),
+        other => ApiError::InvalidToken(other.to_string()),
+    }
 }
 
 // ============================================================================
@@ -416,4 +699,6 @@ pub fn create_auth_routes() -> Router<AppState> {
         .route("/challenge", get(get_challenge))
         .route("/passkey/register", post(register_passkey))
         .route("/passkey/verify", post(verify_passkey))
+        .route("/session/refresh", post(refresh_session))
+        .route("/session/revoke", post(revoke_session))
 }
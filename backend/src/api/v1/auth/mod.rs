@@ -1,10 +1,23 @@
 //! Passkey/WebAuthn Authentication Module
 //!
 //! Handles Challenge generation, Passkey registration, and verification.
-//! Supports Ed25519-based DIDs for compatibility with Erynoa's identity system.
+//! Supports Ed25519 and ES256 (P-256) passkeys; both are linked to Erynoa DIDs.
+//! Assertions are checked against server-side, single-use challenges and the
+//! relying party settings (see [`webauthn`]).
+//! A successful verification starts a session (signed access token + refresh
+//! token); [`AuthContext`] authenticates subsequent requests and [`Role`]
+//! classifies the caller for privileged route groups.
 
+pub mod context;
 pub mod handlers;
+pub mod roles;
+pub mod session;
 pub mod types;
+pub mod webauthn;
 
+pub use context::AuthContext;
 pub use handlers::*;
+pub use crate::domain::unified::passkey::{PasskeyPublicKey, PublicKeyError};
+pub use roles::Role;
+pub use session::{SessionClaims, SessionError, SessionManager, SessionRequest, TokenPair};
 pub use types::*;
@@ -0,0 +1,134 @@
+//! Role Model
+//!
+//! Maps an authenticated caller to one of three roles. Roles are derived per
+//! request from the caller DID, the session's capabilities and realm scope:
+//!
+//! | Role | Requirement |
+//! |------|-------------|
+//! | [`Role::NodeOperator`] | DID listed in `admin.operators` and session grants `execute:admin` |
+//! | [`Role::RealmAdmin`] | DID is admin of the targeted realm and session grants `execute:admin` |
+//! | [`Role::Member`] | any other valid session |
+//!
+//! Only an explicit `execute:admin` elevates; wildcards such as `execute:*` or
+//! `*` do not. Sessions requested without it never carry an elevated role,
+//! so operators can log in with least privilege for day-to-day use.
+
+use serde::{Deserialize, Serialize};
+
+use super::AuthContext;
+use crate::domain::unified::identity::Capability;
+use crate::server::AppState;
+
+/// Capability an elevated session must grant
+pub const ADMIN_ACTION: &str = "admin";
+
+/// Caller role, ordered by privilege
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Any authenticated identity
+    Member,
+    /// Administrator of the realm the request targets
+    RealmAdmin,
+    /// Operator of this node
+    NodeOperator,
+}
+
+impl Role {
+    /// Stable name (audit log, error messages)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Member => "member",
+            Self::RealmAdmin => "realm_admin",
+            Self::NodeOperator => "node_operator",
+        }
+    }
+
+    /// Resolves the role from already looked-up facts
+    ///
+    /// `realm_admin` is whether the caller administers the targeted realm.
+    pub fn resolve(auth: &AuthContext, operator: bool, realm_admin: bool) -> Self {
+        let admin = Capability::Execute {
+            action: ADMIN_ACTION.into(),
+        };
+        let elevated = auth.capabilities.contains(&admin);
+        match (elevated, operator, realm_admin) {
+            (true, true, _) => Self::NodeOperator,
+            (true, false, true) => Self::RealmAdmin,
+            _ => Self::Member,
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AuthContext {
+    /// Role of the caller, optionally for the realm the request targets
+    ///
+    /// Realm-scoped sessions cannot act as admin of a different realm.
+    pub fn role(&self, state: &AppState, realm_id: Option<&str>) -> Role {
+        let operator = state.config.admin.operators.iter().any(|d| d == &self.did);
+        let realm_admin = realm_id.is_some_and(|realm_id| {
+            self.require_realm(realm_id).is_ok()
+                && state
+                    .unified_state
+                    .peer
+                    .realm
+                    .realms
+                    .read()
+                    .is_ok_and(|realms| {
+                        realms
+                            .get(realm_id)
+                            .is_some_and(|realm| realm.is_admin_by_id(&self.id))
+                    })
+        });
+        Role::resolve(self, operator, realm_admin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::unified::identity::DID;
+
+    fn context(capabilities: &[&str]) -> AuthContext {
+        let did = DID::new_self(b"roles-test");
+        AuthContext {
+            did: did.to_uri(),
+            id: did.id,
+            session_id: "sid".into(),
+            realm: None,
+            capabilities: capabilities
+                .iter()
+                .map(|c| Capability::parse(c).unwrap())
+                .collect(),
+            expires_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_elevation_requires_admin_capability() {
+        let elevated = context(&["read:*", "execute:admin"]);
+        assert_eq!(Role::resolve(&elevated, true, true), Role::NodeOperator);
+        assert_eq!(Role::resolve(&elevated, false, true), Role::RealmAdmin);
+        assert_eq!(Role::resolve(&elevated, false, false), Role::Member);
+
+        let plain = context(&["read:*", "write:*"]);
+        assert_eq!(Role::resolve(&plain, true, true), Role::Member);
+
+        // Wildcards elevate nothing
+        let wildcard = context(&["*", "execute:*"]);
+        assert_eq!(Role::resolve(&wildcard, true, true), Role::Member);
+    }
+
+    #[test]
+    fn test_roles_are_ordered() {
+        assert!(Role::NodeOperator > Role::RealmAdmin);
+        assert!(Role::RealmAdmin > Role::Member);
+        assert_eq!(Role::RealmAdmin.to_string(), "realm_admin");
+    }
+}
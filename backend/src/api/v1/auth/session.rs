@@ -0,0 +1,531 @@
+//! Session Tokens
+//!
+//! Issues short-lived, EdDSA-signed access tokens (compact JWS) after a
+//! successful passkey verification, plus a rotating refresh token.
+//!
+//! - Access token: `{"alg":"EdDSA","typ":"JWT"}` header, claims see [`SessionClaims`]
+//! - Refresh token: `<session_id>.<secret>`, only the BLAKE3 hash is persisted
+//! - Revocation: every access token carries its `sid`; revoked sessions are
+//!   rejected even if the token itself has not expired yet
+//! - Refresh reuse: presenting an already rotated refresh secret revokes the
+//!   whole session
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+use super::roles::ADMIN_ACTION;
+use crate::config::AuthSettings;
+use crate::domain::unified::identity::Capability;
+use crate::local::{SessionRecord, SessionStore};
+
+/// Token type reported to clients
+pub const TOKEN_TYPE: &str = "Bearer";
+
+const JWS_HEADER: &str = r#"{"alg":"EdDSA","typ":"JWT"}"#;
+
+/// Session/token errors
+#[derive(Debug, Error)]
+pub enum SessionError {
+    #[error("malformed token: {0}")]
+    Malformed(String),
+
+    #[error("invalid token signature")]
+    InvalidSignature,
+
+    #[error("token expired")]
+    Expired,
+
+    #[error("token not valid for this audience or issuer")]
+    WrongAudience,
+
+    #[error("session revoked or unknown")]
+    Revoked,
+
+    #[error("refresh token reuse detected, session revoked")]
+    RefreshReuse,
+
+    #[error("capability not grantable: {0}")]
+    CapabilityNotGranted(String),
+
+    #[error("storage error: {0}")]
+    Storage(#[from] anyhow::Error),
+}
+
+/// Claims of an access token
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionClaims {
+    /// Issuer
+    pub iss: String,
+    /// Subject (DID URI)
+    pub sub: String,
+    /// Audience
+    pub aud: String,
+    /// Optional realm scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub realm: Option<String>,
+    /// Granted capabilities (`Capability::to_string_repr`)
+    pub caps: Vec<String>,
+    /// Session ID
+    pub sid: String,
+    /// Issued at (Unix seconds)
+    pub iat: i64,
+    /// Expires at (Unix seconds)
+    pub exp: i64,
+}
+
+impl SessionClaims {
+    /// Parsed capabilities (unparseable entries are skipped)
+    pub fn capabilities(&self) -> Vec<Capability> {
+        self.caps
+            .iter()
+            .filter_map(|c| Capability::parse(c).ok())
+            .collect()
+    }
+}
+
+/// Access + refresh token returned after login or refresh
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TokenPair {
+    /// Signed access token (compact JWS)
+    pub access_token: String,
+    /// Rotating refresh token
+    pub refresh_token: String,
+    /// Always `Bearer`
+    pub token_type: String,
+    /// Access token expiry (Unix seconds)
+    pub expires_at: i64,
+    /// Session/refresh expiry (Unix seconds)
+    pub refresh_expires_at: i64,
+    /// Session ID
+    pub session_id: String,
+}
+
+/// Session parameters requested at login
+#[derive(Debug, Clone, Default)]
+pub struct SessionRequest {
+    /// Restrict the session to a realm
+    pub realm: Option<String>,
+    /// Requested capabilities (defaults from config if empty)
+    pub capabilities: Vec<String>,
+}
+
+/// Issues, verifies, refreshes and revokes session tokens
+#[derive(Clone)]
+pub struct SessionManager {
+    store: SessionStore,
+    signing_key: SigningKey,
+    config: AuthSettings,
+}
+
+impl SessionManager {
+    /// Creates a manager; the signing key is loaded from (or created in) the store
+    pub fn new(store: SessionStore, config: AuthSettings) -> anyhow::Result<Self> {
+        let signing_key = store.signing_key()?;
+        Ok(Self {
+            store,
+            signing_key,
+            config,
+        })
+    }
+
+    /// Public key used to verify access tokens
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.signing_key.verifying_key()
+    }
+
+    /// Configured audience
+    pub fn audience(&self) -> &str {
+        &self.config.audience
+    }
+
+    /// Starts a new session for a verified DID
+    pub fn issue(
+        &self,
+        did: &str,
+        credential_id: &str,
+        request: SessionRequest,
+    ) -> Result<TokenPair, SessionError> {
+        let capabilities = self.grant(&request.capabilities)?;
+        let now = chrono::Utc::now().timestamp();
+        let secret = random_secret();
+
+        let record = SessionRecord {
+            id: uuid::Uuid::new_v4().simple().to_string(),
+            did: did.to_string(),
+            credential_id: credential_id.to_string(),
+            audience: self.config.audience.clone(),
+            realm: request.realm,
+            capabilities,
+            refresh_hash: hash_secret(&secret),
+            refresh_count: 0,
+            created_at: now,
+            expires_at: now + self.config.refresh_token_ttl_secs as i64,
+            revoked_at: None,
+        };
+        self.store.put(&record)?;
+
+        tracing::info!(did = %did, session_id = %record.id, "Session issued");
+        self.token_pair(&record, &secret, now)
+    }
+
+    /// Verifies an access token and returns its claims
+    pub fn verify(&self, token: &str) -> Result<SessionClaims, SessionError> {
+        let (signing_input, signature) = token
+            .rsplit_once('.')
+            .ok_or_else(|| SessionError::Malformed("expected three segments".into()))?;
+        let (header, payload) = signing_input
+            .split_once('.')
+            .filter(|(_, payload)| !payload.contains('.'))
+            .ok_or_else(|| SessionError::Malformed("expected three segments".into()))?;
+
+        let header = decode_segment(header)?;
+        if header != JWS_HEADER.as_bytes() {
+            return Err(SessionError::Malformed("unsupported header".into()));
+        }
+
+        let signature: [u8; 64] = decode_segment(signature)?
+            .try_into()
+            .map_err(|_| SessionError::Malformed("invalid signature length".into()))?;
+        self.verifying_key()
+            .verify(signing_input.as_bytes(), &Signature::from_bytes(&signature))
+            .map_err(|_| SessionError::InvalidSignature)?;
+
+        let claims: SessionClaims = serde_json::from_slice(&decode_segment(payload)?)
+            .map_err(|e| SessionError::Malformed(e.to_string()))?;
+
+        if claims.iss != self.config.issuer || claims.aud != self.config.audience {
+            return Err(SessionError::WrongAudience);
+        }
+        let now = chrono::Utc::now().timestamp();
+        if claims.exp <= now {
+            return Err(SessionError::Expired);
+        }
+        match self.store.get(&claims.sid)? {
+            Some(record) if record.is_active(now) && record.did == claims.sub => Ok(claims),
+            _ => Err(SessionError::Revoked),
+        }
+    }
+
+    /// Rotates the refresh token and issues a fresh access token
+    pub fn refresh(&self, refresh_token: &str) -> Result<TokenPair, SessionError> {
+        let (session_id, secret) = refresh_token
+            .split_once('.')
+            .ok_or_else(|| SessionError::Malformed("invalid refresh token".into()))?;
+
+        let now = chrono::Utc::now().timestamp();
+        let mut record = match self.store.get(session_id)? {
+            Some(record) if record.is_active(now) => record,
+            _ => return Err(SessionError::Revoked),
+        };
+
+        if !secret_matches(secret, &record.refresh_hash) {
+            return Err(self.reuse_detected(&record, now));
+        }
+
+        // Compare-and-swap: of two concurrent refreshes with the same secret
+        // exactly one wins, the other counts as reuse
+        let secret = random_secret();
+        let expected = std::mem::replace(&mut record.refresh_hash, hash_secret(&secret));
+        record.refresh_count += 1;
+        if !self.store.rotate_refresh(&expected, &record)? {
+            return Err(self.reuse_detected(&record, now));
+        }
+
+        self.token_pair(&record, &secret, now)
+    }
+
+    /// Revokes the session after a reused refresh secret
+    fn reuse_detected(&self, record: &SessionRecord, now: i64) -> SessionError {
+        if let Err(e) = self.store.revoke(&record.id, now) {
+            return e.into();
+        }
+        tracing::warn!(
+            did = %record.did,
+            session_id = %record.id,
+            "Refresh token reuse detected, session revoked"
+        );
+        SessionError::RefreshReuse
+    }
+
+    /// Loads a stored session
+    pub fn session(&self, session_id: &str) -> Result<Option<SessionRecord>, SessionError> {
+        Ok(self.store.get(session_id)?)
+    }
+
+    /// Revokes a session, returns `false` if unknown
+    pub fn revoke(&self, session_id: &str) -> Result<bool, SessionError> {
+        let now = chrono::Utc::now().timestamp();
+        Ok(self.store.revoke(session_id, now)?)
+    }
+
+    /// Revokes all active sessions of a DID
+    pub fn revoke_all(&self, did: &str) -> Result<usize, SessionError> {
+        let now = chrono::Utc::now().timestamp();
+        Ok(self.store.revoke_all(did, now)?)
+    }
+
+    /// Requested capabilities must be implied by the configured defaults
+    ///
+    /// `execute:admin` is the exception: it is granted only when requested
+    /// explicitly, the role check still requires an operator or realm admin DID.
+    fn grant(&self, requested: &[String]) -> Result<Vec<String>, SessionError> {
+        let defaults = self
+            .config
+            .default_capabilities
+            .iter()
+            .filter_map(|c| Capability::parse(c).ok())
+            .collect::<Vec<_>>();
+
+        if requested.is_empty() {
+            return Ok(defaults.iter().map(Capability::to_string_repr).collect());
+        }
+
+        let admin = Capability::Execute {
+            action: ADMIN_ACTION.into(),
+        };
+
+        requested
+            .iter()
+            .map(|raw| {
+                let capability = Capability::parse(raw)
+                    .map_err(|_| SessionError::CapabilityNotGranted(raw.clone()))?;
+                if capability == admin || defaults.iter().any(|d| d.implies(&capability)) {
+                    Ok(capability.to_string_repr())
+                } else {
+                    Err(SessionError::CapabilityNotGranted(raw.clone()))
+                }
+            })
+            .collect()
+    }
+
+    fn token_pair(
+        &self,
+        record: &SessionRecord,
+        secret: &str,
+        now: i64,
+    ) -> Result<TokenPair, SessionError> {
+        let exp = (now + self.config.access_token_ttl_secs as i64).min(record.expires_at);
+        let claims = SessionClaims {
+            iss: self.config.issuer.clone(),
+            sub: record.did.clone(),
+            aud: record.audience.clone(),
+            realm: record.realm.clone(),
+            caps: record.capabilities.clone(),
+            sid: record.id.clone(),
+            iat: now,
+            exp,
+        };
+
+        Ok(TokenPair {
+            access_token: self.sign(&claims)?,
+            refresh_token: format!("{}.{}", record.id, secret),
+            token_type: TOKEN_TYPE.to_string(),
+            expires_at: exp,
+            refresh_expires_at: record.expires_at,
+            session_id: record.id.clone(),
+        })
+    }
+
+    fn sign(&self, claims: &SessionClaims) -> Result<String, SessionError> {
+        let payload = serde_json::to_vec(claims).map_err(anyhow::Error::from)?;
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(JWS_HEADER),
+            URL_SAFE_NO_PAD.encode(payload)
+        );
+        let signature = self.signing_key.sign(signing_input.as_bytes());
+        Ok(format!(
+            "{}.{}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        ))
+    }
+}
+
+fn decode_segment(segment: &str) -> Result<Vec<u8>, SessionError> {
+    URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| SessionError::Malformed(e.to_string()))
+}
+
+fn random_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn hash_secret(secret: &str) -> String {
+    blake3::hash(secret.as_bytes()).to_hex().to_string()
+}
+
+/// Compares against a stored [`hash_secret`] in constant time
+/// (`blake3::Hash` equality does not short-circuit)
+fn secret_matches(secret: &str, stored_hash: &str) -> bool {
+    blake3::Hash::from_hex(stored_hash)
+        .is_ok_and(|stored| stored == blake3::hash(secret.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local::DecentralizedStorage;
+
+    const DID: &str =
+        "did:erynoa:self:abababababababababababababababababababababababababababababababab";
+
+    fn manager() -> (SessionManager, DecentralizedStorage) {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let manager =
+            SessionManager::new(storage.sessions.clone(), AuthSettings::default()).unwrap();
+        (manager, storage)
+    }
+
+    #[test]
+    fn test_issue_verify_and_revoke() {
+        let (manager, storage) = manager();
+        let pair = manager
+            .issue(
+                DID,
+                "cred-1",
+                SessionRequest {
+                    realm: Some("realm:test".into()),
+                    capabilities: vec!["write:trust".into()],
+                },
+            )
+            .unwrap();
+
+        let claims = manager.verify(&pair.access_token).unwrap();
+        assert_eq!(claims.sub, DID);
+        assert_eq!(claims.realm.as_deref(), Some("realm:test"));
+        assert_eq!(claims.caps, vec!["write:trust".to_string()]);
+
+        // Manipulierte Claims brechen die Signatur
+        let mut parts: Vec<&str> = pair.access_token.split('.').collect();
+        let forged = URL_SAFE_NO_PAD.encode(
+            serde_json::to_vec(&SessionClaims {
+                caps: vec!["*".into()],
+                ..claims.clone()
+            })
+            .unwrap(),
+        );
+        parts[1] = &forged;
+        assert!(matches!(
+            manager.verify(&parts.join(".")),
+            Err(SessionError::InvalidSignature)
+        ));
+
+        // Signaturschlüssel überlebt einen Neustart
+        let restarted =
+            SessionManager::new(storage.sessions.clone(), AuthSettings::default()).unwrap();
+        assert!(restarted.verify(&pair.access_token).is_ok());
+
+        assert!(manager.revoke(&pair.session_id).unwrap());
+        assert!(matches!(
+            manager.verify(&pair.access_token),
+            Err(SessionError::Revoked)
+        ));
+
+        // Nicht gewährbare Capability
+        assert!(matches!(
+            manager.issue(
+                DID,
+                "cred-1",
+                SessionRequest {
+                    realm: None,
+                    capabilities: vec!["*".into()],
+                }
+            ),
+            Err(SessionError::CapabilityNotGranted(_))
+        ));
+    }
+
+    #[test]
+    fn test_secret_matches_stored_hash() {
+        let stored = hash_secret("secret");
+        assert!(secret_matches("secret", &stored));
+        assert!(!secret_matches("secreT", &stored));
+        assert!(!secret_matches("secret", "not-a-hash"));
+    }
+
+    #[test]
+    fn test_admin_capability_only_on_explicit_request() {
+        let (manager, _storage) = manager();
+        let defaults = manager
+            .issue(DID, "cred-1", SessionRequest::default())
+            .unwrap();
+        let claims = manager.verify(&defaults.access_token).unwrap();
+        assert!(!claims.caps.iter().any(|c| c.starts_with("execute:admin")));
+
+        let request = |caps: &[&str]| SessionRequest {
+            realm: None,
+            capabilities: caps.iter().map(|c| c.to_string()).collect(),
+        };
+        let elevated = manager
+            .issue(DID, "cred-1", request(&["read:*", "execute:admin"]))
+            .unwrap();
+        let claims = manager.verify(&elevated.access_token).unwrap();
+        assert_eq!(claims.caps, vec!["read:*", "execute:admin"]);
+
+        assert!(matches!(
+            manager.issue(DID, "cred-1", request(&["execute:*"])),
+            Err(SessionError::CapabilityNotGranted(_))
+        ));
+    }
+
+    #[test]
+    fn test_refresh_rotation_and_reuse_detection() {
+        let (manager, _storage) = manager();
+        let first = manager
+            .issue(DID, "cred-1", SessionRequest::default())
+            .unwrap();
+
+        let second = manager.refresh(&first.refresh_token).unwrap();
+        assert_eq!(second.session_id, first.session_id);
+        assert_ne!(second.refresh_token, first.refresh_token);
+        assert!(manager.verify(&second.access_token).is_ok());
+
+        // Alter Refresh-Token erneut vorgelegt → Session widerrufen
+        assert!(matches!(
+            manager.refresh(&first.refresh_token),
+            Err(SessionError::RefreshReuse)
+        ));
+        assert!(matches!(
+            manager.refresh(&second.refresh_token),
+            Err(SessionError::Revoked)
+        ));
+        assert!(manager.verify(&second.access_token).is_err());
+    }
+
+    #[test]
+    fn test_concurrent_refresh_succeeds_once() {
+        let (manager, _storage) = manager();
+        let pair = manager
+            .issue(DID, "cred-1", SessionRequest::default())
+            .unwrap();
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let manager = manager.clone();
+                let token = pair.refresh_token.clone();
+                std::thread::spawn(move || manager.refresh(&token))
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        let rotated = results.iter().filter(|r| r.is_ok()).count();
+        assert_eq!(rotated, 1);
+        assert!(results
+            .iter()
+            .any(|r| matches!(r, Err(SessionError::RefreshReuse))));
+        // Reuse revokes the session, including the winning rotation
+        assert!(manager
+            .session(&pair.session_id)
+            .unwrap()
+            .unwrap()
+            .revoked_at
+            .is_some());
+    }
+}
@@ -3,9 +3,10 @@
 //! Request and Response types for WebAuthn/Passkey endpoints.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Challenge Response für WebAuthn Operations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ChallengeResponse {
     /// Base64URL encoded Challenge (32 random bytes)
     pub challenge: String,
@@ -18,7 +19,7 @@ pub struct ChallengeResponse {
 }
 
 /// Passkey Registration Request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PasskeyRegistrationRequest {
     /// Credential ID (Base64URL encoded)
     pub credential_id: String,
@@ -39,7 +40,7 @@ pub struct PasskeyRegistrationRequest {
 }
 
 /// Passkey Registration Response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PasskeyRegistrationResponse {
     /// Whether registration was successful
     pub success: bool,
@@ -52,7 +53,7 @@ pub struct PasskeyRegistrationResponse {
 }
 
 /// Passkey Verification Request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PasskeyVerificationRequest {
     /// Credential ID (Base64URL encoded)
     pub credential_id: String,
@@ -62,10 +63,16 @@ pub struct PasskeyVerificationRequest {
     pub authenticator_data: String,
     /// Client Data JSON (Base64URL encoded)
     pub client_data_json: String,
+    /// Optional realm scope for the issued session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub realm_id: Option<String>,
+    /// Requested capabilities (defaults from `[auth]` config if empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
 }
 
 /// Passkey Verification Response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PasskeyVerificationResponse {
     /// Whether verification was successful
     pub success: bool,
@@ -75,6 +82,50 @@ pub struct PasskeyVerificationResponse {
     /// Error message if failed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Issued session tokens (only on success)
+    #[serde(flatten)]
+    pub session: Option<super::session::TokenPair>,
+}
+
+/// Session Refresh Request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SessionRefreshRequest {
+    /// Refresh token from the last login/refresh
+    pub refresh_token: String,
+}
+
+/// Session Revoke Request
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct SessionRevokeRequest {
+    /// Session to revoke (defaults to the calling session)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Revoke all sessions of the caller
+    #[serde(default)]
+    pub all: bool,
+}
+
+/// Session Revoke Response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SessionRevokeResponse {
+    /// Number of revoked sessions
+    pub revoked: usize,
+}
+
+/// Authenticated caller (GET /api/v1/me)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MeResponse {
+    /// Caller DID
+    pub did: String,
+    /// Current session
+    pub session_id: String,
+    /// Realm scope of the session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realm_id: Option<String>,
+    /// Granted capabilities
+    pub capabilities: Vec<String>,
+    /// Access token expiry (Unix seconds)
+    pub expires_at: i64,
 }
 
 /// Stored Passkey Credential (for backend persistence)
@@ -105,27 +156,10 @@ pub struct StoredPasskeyCredential {
     pub last_used_at: Option<i64>,
 }
 
-/// COSE Algorithm Constants
-pub mod cose {
-    /// Ed25519 (EdDSA with Ed25519 curve)
-    pub const ED25519: i32 = -8;
-    /// ES256 (ECDSA with P-256 and SHA-256)
-    pub const ES256: i32 = -7;
-    /// RS256 (RSASSA-PKCS1-v1_5 with SHA-256)
-    pub const RS256: i32 = -257;
-}
+/// COSE algorithm constants
+pub use crate::domain::unified::passkey::{algorithm_name, cose};
 
 /// Validates that the algorithm is supported
 pub fn is_supported_algorithm(alg: i32) -> bool {
     matches!(alg, cose::ED25519 | cose::ES256)
 }
-
-/// Returns algorithm name for display
-pub fn algorithm_name(alg: i32) -> &'static str {
-    match alg {
-        cose::ED25519 => "Ed25519",
-        cose::ES256 => "ES256",
-        cose::RS256 => "RS256",
-        _ => "Unknown",
-    }
-}
@@ -0,0 +1,339 @@
+//! WebAuthn Assertion Validation
+//!
+//! Parses `clientDataJSON` and `authenticatorData` of an assertion and checks
+//! them against the relying party configuration (`[auth]` in `Settings`):
+//!
+//! - `type` must be `webauthn.get`
+//! - `origin` must be on the allow-list, `crossOrigin` only if allowed
+//! - `rpIdHash` must equal `SHA-256(rp_id)`
+//! - User Presence (UP) must be set, User Verification (UV) if required
+//! - the signature counter must increase (cloned authenticator detection)
+//!
+//! The challenge itself is checked against the [`ChallengeStore`](crate::local::ChallengeStore)
+//! by the handler, since it is single-use.
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::config::AuthSettings;
+
+/// Authenticator data flag: User Present
+pub const FLAG_UP: u8 = 0x01;
+/// Authenticator data flag: User Verified
+pub const FLAG_UV: u8 = 0x04;
+
+/// Expected `clientDataJSON.type` for assertions
+pub const ASSERTION_TYPE: &str = "webauthn.get";
+
+/// Assertion validation errors
+#[derive(Debug, Error, PartialEq)]
+pub enum WebAuthnError {
+    #[error("invalid clientDataJSON: {0}")]
+    InvalidClientData(String),
+
+    #[error("unexpected ceremony type: {0}")]
+    WrongType(String),
+
+    #[error("origin not allowed: {0}")]
+    OriginNotAllowed(String),
+
+    #[error("cross-origin assertion not allowed")]
+    CrossOriginNotAllowed,
+
+    #[error("authenticator data too short ({0} bytes)")]
+    InvalidAuthenticatorData(usize),
+
+    #[error("rpIdHash does not match relying party")]
+    RpIdMismatch,
+
+    #[error("user presence flag not set")]
+    UserNotPresent,
+
+    #[error("user verification required")]
+    UserNotVerified,
+
+    #[error("unknown, expired or already used challenge")]
+    InvalidChallenge,
+
+    #[error("signature counter did not increase ({stored} -> {received}), possible cloned authenticator")]
+    CounterRegression { stored: u32, received: u32 },
+}
+
+/// Parsed `clientDataJSON`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClientData {
+    /// Ceremony type (`webauthn.get` / `webauthn.create`)
+    #[serde(rename = "type")]
+    pub ceremony: String,
+    /// Challenge (Base64URL)
+    pub challenge: String,
+    /// Origin of the calling page
+    pub origin: String,
+    /// Cross-origin iframe?
+    #[serde(default, rename = "crossOrigin")]
+    pub cross_origin: bool,
+}
+
+impl ClientData {
+    /// Parses the raw JSON bytes
+    pub fn parse(bytes: &[u8]) -> Result<Self, WebAuthnError> {
+        serde_json::from_slice(bytes).map_err(|e| WebAuthnError::InvalidClientData(e.to_string()))
+    }
+}
+
+/// Parsed fixed part of `authenticatorData` (extensions are ignored)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatorData {
+    /// SHA-256 of the RP ID
+    pub rp_id_hash: [u8; 32],
+    /// Flags byte
+    pub flags: u8,
+    /// Signature counter
+    pub sign_count: u32,
+}
+
+impl AuthenticatorData {
+    /// Parses `rpIdHash (32) ‖ flags (1) ‖ signCount (4, big endian)`
+    pub fn parse(bytes: &[u8]) -> Result<Self, WebAuthnError> {
+        if bytes.len() < 37 {
+            return Err(WebAuthnError::InvalidAuthenticatorData(bytes.len()));
+        }
+        let mut rp_id_hash = [0u8; 32];
+        rp_id_hash.copy_from_slice(&bytes[..32]);
+        Ok(Self {
+            rp_id_hash,
+            flags: bytes[32],
+            sign_count: u32::from_be_bytes([bytes[33], bytes[34], bytes[35], bytes[36]]),
+        })
+    }
+
+    /// User Present
+    pub fn user_present(&self) -> bool {
+        self.flags & FLAG_UP != 0
+    }
+
+    /// User Verified
+    pub fn user_verified(&self) -> bool {
+        self.flags & FLAG_UV != 0
+    }
+}
+
+/// Relying party policy for assertions
+#[derive(Debug, Clone)]
+pub struct AssertionPolicy {
+    /// RP ID (e.g. `erynoa.io`)
+    pub rp_id: String,
+    /// Allowed `clientDataJSON.origin` values
+    pub allowed_origins: Vec<String>,
+    /// Require the UV flag
+    pub require_user_verification: bool,
+    /// Accept `crossOrigin: true` in the client data
+    pub allow_cross_origin: bool,
+}
+
+impl From<&AuthSettings> for AssertionPolicy {
+    fn from(settings: &AuthSettings) -> Self {
+        Self {
+            rp_id: settings.rp_id.clone(),
+            allowed_origins: settings.allowed_origins.clone(),
+            require_user_verification: settings.require_user_verification,
+            allow_cross_origin: settings.allow_cross_origin,
+        }
+    }
+}
+
+/// Successfully validated assertion (signature still to be checked)
+#[derive(Debug, Clone)]
+pub struct ValidatedAssertion {
+    /// Parsed client data
+    pub client_data: ClientData,
+    /// Parsed authenticator data
+    pub authenticator_data: AuthenticatorData,
+}
+
+impl AssertionPolicy {
+    /// Validates client data and authenticator data of an assertion
+    pub fn validate(
+        &self,
+        client_data_json: &[u8],
+        authenticator_data: &[u8],
+    ) -> Result<ValidatedAssertion, WebAuthnError> {
+        let client_data = ClientData::parse(client_data_json)?;
+        if client_data.ceremony != ASSERTION_TYPE {
+            return Err(WebAuthnError::WrongType(client_data.ceremony));
+        }
+        if !self
+            .allowed_origins
+            .iter()
+            .any(|o| o == &client_data.origin)
+        {
+            return Err(WebAuthnError::OriginNotAllowed(client_data.origin));
+        }
+        if client_data.cross_origin && !self.allow_cross_origin {
+            return Err(WebAuthnError::CrossOriginNotAllowed);
+        }
+
+        let authenticator_data = AuthenticatorData::parse(authenticator_data)?;
+        if authenticator_data.rp_id_hash[..] != Sha256::digest(self.rp_id.as_bytes())[..] {
+            return Err(WebAuthnError::RpIdMismatch);
+        }
+        if !authenticator_data.user_present() {
+            return Err(WebAuthnError::UserNotPresent);
+        }
+        if self.require_user_verification && !authenticator_data.user_verified() {
+            return Err(WebAuthnError::UserNotVerified);
+        }
+
+        Ok(ValidatedAssertion {
+            client_data,
+            authenticator_data,
+        })
+    }
+}
+
+/// Checks the signature counter against the stored value.
+///
+/// Authenticators without counter support always report `0`; the check is
+/// skipped only if both values are `0`.
+pub fn check_sign_count(stored: u32, received: u32) -> Result<(), WebAuthnError> {
+    if (stored == 0 && received == 0) || received > stored {
+        Ok(())
+    } else {
+        Err(WebAuthnError::CounterRegression { stored, received })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+    /// Aufgezeichnete Ed25519-Assertion (rp_id `localhost`, Origin `http://localhost:5173`)
+    const FIXTURE: &str =
+        include_str!("../../../../tests/fixtures/webauthn/ed25519_assertion.json");
+
+    #[derive(Deserialize)]
+    struct Fixture {
+        public_key: String,
+        challenge: String,
+        client_data_json: String,
+        authenticator_data: String,
+        signature: String,
+        sign_count: u32,
+    }
+
+    fn fixture() -> Fixture {
+        serde_json::from_str(FIXTURE).unwrap()
+    }
+
+    fn policy() -> AssertionPolicy {
+        AssertionPolicy {
+            rp_id: "localhost".into(),
+            allowed_origins: vec!["http://localhost:5173".into()],
+            require_user_verification: false,
+            allow_cross_origin: false,
+        }
+    }
+
+    fn decode(s: &str) -> Vec<u8> {
+        URL_SAFE_NO_PAD.decode(s).unwrap()
+    }
+
+    #[test]
+    fn test_recorded_assertion_validates() {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let f = fixture();
+        let client_data = decode(&f.client_data_json);
+        let auth_data = decode(&f.authenticator_data);
+
+        let validated = policy().validate(&client_data, &auth_data).unwrap();
+        assert_eq!(validated.client_data.challenge, f.challenge);
+        assert_eq!(validated.authenticator_data.sign_count, f.sign_count);
+        assert!(validated.authenticator_data.user_verified());
+
+        let mut signed = auth_data.clone();
+        signed.extend_from_slice(&Sha256::digest(&client_data));
+        let key = VerifyingKey::try_from(decode(&f.public_key).as_slice()).unwrap();
+        let signature = Signature::from_slice(&decode(&f.signature)).unwrap();
+        assert!(key.verify(&signed, &signature).is_ok());
+
+        // Gleiche Assertion gegen eine andere RP / Origin
+        let other_rp = AssertionPolicy {
+            rp_id: "erynoa.io".into(),
+            ..policy()
+        };
+        assert_eq!(
+            other_rp.validate(&client_data, &auth_data).unwrap_err(),
+            WebAuthnError::RpIdMismatch
+        );
+        let other_origin = AssertionPolicy {
+            allowed_origins: vec!["https://evil.example".into()],
+            ..policy()
+        };
+        assert!(matches!(
+            other_origin.validate(&client_data, &auth_data),
+            Err(WebAuthnError::OriginNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_flags_type_and_counter() {
+        let f = fixture();
+        let client_data = decode(&f.client_data_json);
+        let mut auth_data = decode(&f.authenticator_data);
+
+        // UP entfernt
+        auth_data[32] = 0;
+        assert_eq!(
+            policy().validate(&client_data, &auth_data).unwrap_err(),
+            WebAuthnError::UserNotPresent
+        );
+
+        // Nur UP, UV verlangt
+        auth_data[32] = FLAG_UP;
+        let strict = AssertionPolicy {
+            require_user_verification: true,
+            ..policy()
+        };
+        assert_eq!(
+            strict.validate(&client_data, &auth_data).unwrap_err(),
+            WebAuthnError::UserNotVerified
+        );
+
+        // Registrierungs-ClientData als Assertion
+        let create =
+            br#"{"type":"webauthn.create","challenge":"x","origin":"http://localhost:5173"}"#;
+        assert!(matches!(
+            policy().validate(create, &auth_data),
+            Err(WebAuthnError::WrongType(_))
+        ));
+
+        // Eingebettet in ein fremdes iframe
+        let cross = br#"{"type":"webauthn.get","challenge":"x","origin":"http://localhost:5173","crossOrigin":true}"#;
+        assert_eq!(
+            policy().validate(cross, &auth_data).unwrap_err(),
+            WebAuthnError::CrossOriginNotAllowed
+        );
+        let embedded = AssertionPolicy {
+            allow_cross_origin: true,
+            ..policy()
+        };
+        assert!(embedded.validate(cross, &auth_data).is_ok());
+        assert!(matches!(
+            policy().validate(&client_data, &auth_data[..20]),
+            Err(WebAuthnError::InvalidAuthenticatorData(20))
+        ));
+
+        assert!(check_sign_count(0, 0).is_ok());
+        assert!(check_sign_count(f.sign_count - 1, f.sign_count).is_ok());
+        assert_eq!(
+            check_sign_count(f.sign_count, f.sign_count).unwrap_err(),
+            WebAuthnError::CounterRegression {
+                stored: f.sign_count,
+                received: f.sign_count
+            }
+        );
+    }
+}
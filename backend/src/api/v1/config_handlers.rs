@@ -0,0 +1,112 @@
+//! Konfiguration – wirksame Werte, letzter Reload-Diff, manueller Reload
+//!
+//! Nur über den Admin-Listener. Die Werte stammen aus `state.live_config`;
+//! Regeln für Hot Reload siehe [`crate::config::reload`].
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::config::reload::{FIXED_PATHS, LIVE_PATHS};
+use crate::config::{ConfigReload, Settings};
+use crate::domain::unified::WorldFormulaConfig;
+use crate::server::AppState;
+
+// ============================================================================
+// Response types
+// ============================================================================
+
+/// Wirksame Konfiguration des Nodes
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EffectiveConfigResponse {
+    /// Anzahl übernommener Reloads seit dem Start
+    pub generation: u64,
+    /// Wirksame Settings (Abschnitte wie in `config/base.toml`)
+    #[schema(value_type = Object)]
+    pub settings: Settings,
+    /// Weltformel-Parameter seit dem Start (Änderungen werden abgelehnt)
+    #[schema(value_type = Object)]
+    pub world_formula: WorldFormulaConfig,
+    /// Pfade, die ohne Neustart übernommen werden
+    pub live_paths: Vec<String>,
+    /// Pfade, deren Änderung zur Laufzeit abgelehnt wird
+    pub fixed_paths: Vec<String>,
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// GET /api/v1/config – Wirksame Konfiguration
+#[utoipa::path(
+    get,
+    path = "/api/v1/config",
+    tag = "config",
+    summary = "Effective configuration (admin listener only)",
+    responses(
+        (status = 200, description = "Wirksame Konfiguration", body = EffectiveConfigResponse),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn config_get_handler(State(state): State<AppState>) -> Json<EffectiveConfigResponse> {
+    let live = &state.live_config;
+    Json(EffectiveConfigResponse {
+        generation: live.generation(),
+        settings: (*live.current()).clone(),
+        world_formula: (*live.world_formula()).clone(),
+        live_paths: LIVE_PATHS.iter().map(|p| p.to_string()).collect(),
+        fixed_paths: FIXED_PATHS.iter().map(|p| p.to_string()).collect(),
+    })
+}
+
+/// GET /api/v1/config/diff – Diff des letzten Reloads
+#[utoipa::path(
+    get,
+    path = "/api/v1/config/diff",
+    tag = "config",
+    summary = "Changes of the last config reload (admin listener only)",
+    responses(
+        (status = 200, description = "ConfigReload", body = Object),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse),
+        (status = 404, description = "Seit dem Start kein Reload", body = crate::api::openapi::ErrorBody)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn config_diff_handler(State(state): State<AppState>) -> Response {
+    match state.live_config.last_reload() {
+        Some(reload) => Json::<ConfigReload>(reload).into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "no_reload",
+                "message": "config has not been reloaded since startup"
+            })),
+        )
+            .into_response(),
+    }
+}
+
+/// POST /api/v1/config/reload – Config-Dateien sofort neu laden
+#[utoipa::path(
+    post,
+    path = "/api/v1/config/reload",
+    tag = "config",
+    summary = "Reload the config files now (admin listener only)",
+    responses(
+        (status = 200, description = "ConfigReload (auch bei `outcome = rejected`)", body = Object),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn config_reload_handler(State(state): State<AppState>) -> Json<ConfigReload> {
+    Json(state.reload_config())
+}
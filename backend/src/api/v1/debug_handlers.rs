@@ -9,6 +9,7 @@ use axum::{
     Json,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::core::WrappedStateEvent;
 use crate::server::AppState;
@@ -17,13 +18,13 @@ use crate::server::AppState;
 // Request / Response types
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ReplayBody {
     pub from_sequence: u64,
     pub to_sequence: u64,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ReplayResponse {
     pub events_replayed: u64,
     pub from_sequence: u64,
@@ -31,7 +32,7 @@ pub struct ReplayResponse {
     pub errors: Vec<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CheckpointResponse {
     pub checkpoint_id: String,
     pub sequence: u64,
@@ -43,6 +44,20 @@ pub struct CheckpointResponse {
 // ============================================================================
 
 /// POST /api/v1/debug/replay – Replay events from_sequence..to_sequence
+#[utoipa::path(
+    post,
+    path = "/api/v1/debug/replay",
+    tag = "debug",
+    summary = "Replay events in a sequence range (admin listener only)",
+    request_body = ReplayBody,
+    responses(
+        (status = 200, description = "Replay-Ergebnis", body = ReplayResponse),
+        (status = 400, description = "Ungültiger Bereich", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn debug_replay_handler(
     State(state): State<AppState>,
     Json(body): Json<ReplayBody>,
@@ -79,6 +94,18 @@ pub async fn debug_replay_handler(
 }
 
 /// POST /api/v1/debug/replay/checkpoint – Replay from last checkpoint to current sequence
+#[utoipa::path(
+    post,
+    path = "/api/v1/debug/replay/checkpoint",
+    tag = "debug",
+    summary = "Replay from the last checkpoint (admin listener only)",
+    responses(
+        (status = 200, description = "Replay-Ergebnis", body = ReplayResponse),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn debug_replay_checkpoint_handler(State(state): State<AppState>) -> impl IntoResponse {
     let snapshot = state.unified_state.event_log.snapshot();
     let last_checkpoint = snapshot.last_checkpoint_sequence;
@@ -116,6 +143,18 @@ pub async fn debug_replay_checkpoint_handler(State(state): State<AppState>) -> i
 // ============================================================================
 
 /// POST /api/v1/debug/checkpoint – Manually trigger checkpoint (state.create_checkpoint)
+#[utoipa::path(
+    post,
+    path = "/api/v1/debug/checkpoint",
+    tag = "debug",
+    summary = "Create a checkpoint (admin listener only)",
+    responses(
+        (status = 200, description = "Checkpoint", body = CheckpointResponse),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn debug_checkpoint_handler(State(state): State<AppState>) -> Json<CheckpointResponse> {
     let wrapped = state.unified_state.create_checkpoint();
     let checkpoint_id = format!("ckpt_{}", wrapped.sequence);
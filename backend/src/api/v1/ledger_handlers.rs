@@ -0,0 +1,212 @@
+//! Ledger – Salden, Supply, Escrow-Locks und Journal
+//!
+//! Read-only Endpoints auf `storage.ledger`. Buchungen entstehen ausschließlich
+//! aus finalisierten Events und Saga-Aktionen.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::UniversalId;
+use crate::local::{AssetSupply, JournalEntry, LedgerAccount};
+use crate::server::AppState;
+
+// ============================================================================
+// Request / Response types
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LedgerBalancesResponse {
+    pub did: String,
+    pub balances: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LedgerSupplyResponse {
+    pub asset_type: String,
+    #[serde(flatten)]
+    pub supply: AssetSupply,
+    pub circulating: u64,
+    pub issuer: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LedgerJournalQuery {
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LedgerInvariantsResponse {
+    pub ok: bool,
+    pub violations: Vec<String>,
+}
+
+fn storage_error(e: impl std::fmt::Display) -> axum::response::Response {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({ "error": "storage_error", "message": e.to_string() })),
+    )
+        .into_response()
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// GET /api/v1/ledger/balances/{did} – Alle Asset-Salden einer DID
+#[utoipa::path(
+    get,
+    path = "/api/v1/ledger/balances/{did}",
+    tag = "ledger",
+    summary = "Asset balances of a DID",
+    params(("did" = String, Path, description = "DID-URI")),
+    responses(
+        (status = 200, description = "Salden", body = LedgerBalancesResponse),
+        (status = 400, description = "Ungültige DID", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn ledger_balances_handler(
+    State(state): State<AppState>,
+    Path(did_str): Path<String>,
+) -> impl IntoResponse {
+    let Some(holder) = LedgerAccount::holder_id(&did_str) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": "invalid_did" })),
+        )
+            .into_response();
+    };
+
+    match state
+        .storage
+        .ledger
+        .balances(&LedgerAccount::Holder(holder))
+    {
+        Ok(balances) => Json(LedgerBalancesResponse {
+            did: did_str,
+            balances,
+        })
+        .into_response(),
+        Err(e) => storage_error(e),
+    }
+}
+
+/// GET /api/v1/ledger/supply/{asset} – Supply und Issuer eines Assets
+#[utoipa::path(
+    get,
+    path = "/api/v1/ledger/supply/{asset}",
+    tag = "ledger",
+    summary = "Supply and issuer of an asset",
+    params(("asset" = String, Path, description = "Asset-Typ")),
+    responses(
+        (status = 200, description = "Supply", body = LedgerSupplyResponse)
+    )
+)]
+pub async fn ledger_supply_handler(
+    State(state): State<AppState>,
+    Path(asset_type): Path<String>,
+) -> impl IntoResponse {
+    let ledger = &state.storage.ledger;
+    let (supply, asset) = match (ledger.supply(&asset_type), ledger.asset(&asset_type)) {
+        (Ok(supply), Ok(asset)) => (supply, asset),
+        (Err(e), _) | (_, Err(e)) => return storage_error(e),
+    };
+
+    Json(LedgerSupplyResponse {
+        circulating: supply.circulating(),
+        issuer: asset.map(|a| a.issuer.to_hex()),
+        asset_type,
+        supply,
+    })
+    .into_response()
+}
+
+/// GET /api/v1/ledger/locks/{lock_id} – Escrow-Lock inkl. offener Conditions
+#[utoipa::path(
+    get,
+    path = "/api/v1/ledger/locks/{lock_id}",
+    tag = "ledger",
+    summary = "Escrow lock with open conditions",
+    params(("lock_id" = String, Path, description = "Lock-ID (Hex)")),
+    responses(
+        (status = 200, description = "EscrowLock", body = serde_json::Value),
+        (status = 400, description = "Ungültige Lock-ID", body = crate::api::openapi::ErrorBody),
+        (status = 404, description = "Lock unbekannt", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn ledger_lock_handler(
+    State(state): State<AppState>,
+    Path(lock_id): Path<String>,
+) -> impl IntoResponse {
+    let Ok(id) = UniversalId::from_hex(&lock_id) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": "invalid_lock_id" })),
+        )
+            .into_response();
+    };
+
+    match state.storage.ledger.lock_info(&id) {
+        Ok(Some(lock)) => Json(serde_json::json!({
+            "pending_conditions": lock.pending_conditions(),
+            "lock": lock,
+        }))
+        .into_response(),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "lock_not_found" })),
+        )
+            .into_response(),
+        Err(e) => storage_error(e),
+    }
+}
+
+/// GET /api/v1/ledger/journal – Letzte Journal-Einträge (neueste zuerst)
+#[utoipa::path(
+    get,
+    path = "/api/v1/ledger/journal",
+    tag = "ledger",
+    summary = "Latest journal entries",
+    params(LedgerJournalQuery),
+    responses(
+        (status = 200, description = "JournalEntries (neueste zuerst)", body = Vec<serde_json::Value>)
+    )
+)]
+pub async fn ledger_journal_handler(
+    State(state): State<AppState>,
+    Query(q): Query<LedgerJournalQuery>,
+) -> impl IntoResponse {
+    let limit = q.limit.unwrap_or(100).min(1000);
+    match state.storage.ledger.journal(limit) {
+        Ok(entries) => Json::<Vec<JournalEntry>>(entries).into_response(),
+        Err(e) => storage_error(e),
+    }
+}
+
+/// GET /api/v1/ledger/invariants – Supply- und Double-Entry-Prüfung
+#[utoipa::path(
+    get,
+    path = "/api/v1/ledger/invariants",
+    tag = "ledger",
+    summary = "Supply and double-entry check",
+    responses(
+        (status = 200, description = "Prüfergebnis", body = LedgerInvariantsResponse)
+    )
+)]
+pub async fn ledger_invariants_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.storage.ledger.verify_invariants() {
+        Ok(violations) => Json(LedgerInvariantsResponse {
+            ok: violations.is_empty(),
+            violations: violations.iter().map(|v| v.to_string()).collect(),
+        })
+        .into_response(),
+        Err(e) => storage_error(e),
+    }
+}
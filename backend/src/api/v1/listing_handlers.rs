@@ -0,0 +1,327 @@
+//! Listen über die persistenten Stores
+//!
+//! Identitäten (`storage.identities`), Content-Metadaten (`storage.content`)
+//! und Trust-Kanten einer DID (`storage.trust`). Pagination, Sortierung und
+//! ETags wie in [`pagination`](super::pagination) beschrieben.
+
+use std::collections::BTreeMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::pagination::{numeric_key, page_response, PageRequest, SortOrder};
+use crate::domain::DID;
+use crate::local::{ContentId, ContentMetadata, ContentStore, StoredIdentity, StoredTrust};
+use crate::server::AppState;
+
+// ============================================================================
+// Request / Response types
+// ============================================================================
+
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IdentityListQuery {
+    /// Seitengröße (Default 100, max. 1000)
+    pub limit: Option<usize>,
+    /// `next_cursor` der vorigen Seite
+    pub cursor: Option<String>,
+    /// `did` (Default) oder `created_at`
+    pub sort: Option<String>,
+    pub order: Option<SortOrder>,
+    /// DID-Namespace (z.B. `self`, `guild`)
+    pub namespace: Option<String>,
+    /// Nur lokale (`true`) bzw. nur externe (`false`) Identitäten
+    pub local: Option<bool>,
+    /// Nur von dieser DID verbürgte Identitäten
+    pub voucher: Option<String>,
+}
+
+/// Eintrag in `GET /identity` (ohne Schlüsselmaterial außer dem Public Key)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IdentityListItem {
+    pub did: String,
+    /// Ed25519 Public Key (hex)
+    pub public_key: String,
+    /// Private Key liegt auf diesem Knoten
+    pub local: bool,
+    pub created_at: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voucher: Option<String>,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl From<StoredIdentity> for IdentityListItem {
+    fn from(identity: StoredIdentity) -> Self {
+        Self {
+            did: identity.did.to_string(),
+            public_key: identity.public_key,
+            local: identity.private_key.is_some(),
+            created_at: identity.created_at,
+            voucher: identity.voucher,
+            metadata: identity.metadata.into_iter().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ContentListQuery {
+    /// Seitengröße (Default 100, max. 1000)
+    pub limit: Option<usize>,
+    /// `next_cursor` der vorigen Seite
+    pub cursor: Option<String>,
+    /// `created_at` (Default), `size` oder `cid`
+    pub sort: Option<String>,
+    pub order: Option<SortOrder>,
+    /// Nur Inhalte dieses Erstellers (DID)
+    pub creator: Option<String>,
+    /// Nur Inhalte mit diesem Tag
+    pub tag: Option<String>,
+    /// MIME-Typ oder Präfix (z.B. `image/`)
+    pub content_type: Option<String>,
+}
+
+/// Richtung von Trust-Kanten aus Sicht der DID
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TrustDirection {
+    /// Wem die DID vertraut
+    #[default]
+    Outgoing,
+    /// Wer der DID vertraut
+    Incoming,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TrustEdgesQuery {
+    /// Seitengröße (Default 100, max. 1000)
+    pub limit: Option<usize>,
+    /// `next_cursor` der vorigen Seite
+    pub cursor: Option<String>,
+    /// `updated_at` (Default), `peer` oder `updates`
+    pub sort: Option<String>,
+    pub order: Option<SortOrder>,
+    pub direction: Option<TrustDirection>,
+}
+
+fn bad_request(code: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({ "error": code })),
+    )
+        .into_response()
+}
+
+/// Vollständige DID-URI (`did:erynoa:<namespace>:<id>`) wie in den Store-Indizes
+///
+/// Die Indizes sind nach der URI geschlüsselt; `DID::parse` leitet die ID
+/// neu ab und träfe den Eintrag nicht, dient hier also nur der Prüfung.
+fn did_uri(s: &str) -> Option<&str> {
+    let s = s.trim();
+    (s.starts_with("did:erynoa:") && DID::parse(s).is_ok()).then_some(s)
+}
+
+fn load_metadata(
+    content: &ContentStore,
+    cids: &[ContentId],
+) -> anyhow::Result<Vec<ContentMetadata>> {
+    cids.iter()
+        .filter_map(|cid| content.get_metadata(cid).transpose())
+        .collect()
+}
+
+fn storage_error(e: impl std::fmt::Display) -> Response {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({ "error": "storage_error", "message": e.to_string() })),
+    )
+        .into_response()
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// GET /api/v1/identity – Bekannte Identitäten
+#[utoipa::path(
+    get,
+    path = "/api/v1/identity",
+    tag = "identity",
+    summary = "Known identities",
+    params(IdentityListQuery),
+    responses(
+        (status = 200, description = "Page<IdentityListItem>; ETag", body = Object),
+        (status = 304, description = "If-None-Match passt"),
+        (status = 400, description = "Ungültiger Cursor oder Sortierfeld", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn identity_list_handler(
+    State(state): State<AppState>,
+    Query(q): Query<IdentityListQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let page = match PageRequest::new(
+        &["did", "created_at"],
+        q.sort.as_deref(),
+        q.order,
+        q.limit,
+        q.cursor.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return e.into_response(),
+    };
+    let identities = match state.storage.identities.list_all() {
+        Ok(identities) => identities,
+        Err(e) => return storage_error(e),
+    };
+
+    let namespace = q.namespace.map(|ns| ns.to_lowercase());
+    let items = identities
+        .into_iter()
+        .map(IdentityListItem::from)
+        .filter(|i| {
+            namespace
+                .as_deref()
+                .is_none_or(|ns| i.did.split(':').nth(2) == Some(ns))
+        })
+        .filter(|i| q.local.is_none_or(|local| i.local == local))
+        .filter(|i| {
+            q.voucher
+                .as_deref()
+                .is_none_or(|voucher| i.voucher.as_deref() == Some(voucher))
+        });
+    let page = page.paginate(items, |i| match page.sort {
+        "created_at" => numeric_key(i.created_at, &i.did),
+        _ => i.did.clone(),
+    });
+    page_response(&headers, &page)
+}
+
+/// GET /api/v1/content – Metadaten gespeicherter Inhalte
+#[utoipa::path(
+    get,
+    path = "/api/v1/content",
+    tag = "content",
+    summary = "Stored content metadata",
+    params(ContentListQuery),
+    responses(
+        (status = 200, description = "Page<ContentMetadata>; ETag", body = Object),
+        (status = 304, description = "If-None-Match passt"),
+        (status = 400, description = "Ungültige DID, Cursor oder Sortierfeld", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn content_list_handler(
+    State(state): State<AppState>,
+    Query(q): Query<ContentListQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let page = match PageRequest::new(
+        &["created_at", "size", "cid"],
+        q.sort.as_deref(),
+        q.order,
+        q.limit,
+        q.cursor.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return e.into_response(),
+    };
+    let content = &state.storage.content;
+
+    // Indizes nutzen, wenn ein Filter sie abdeckt
+    let metadata = match (&q.creator, &q.tag) {
+        (Some(creator), _) => {
+            let Some(creator) = did_uri(creator) else {
+                return bad_request("invalid_did");
+            };
+            content
+                .get_by_creator_uri(creator)
+                .and_then(|cids| load_metadata(content, &cids))
+        }
+        (None, Some(tag)) => content
+            .get_by_tag(tag)
+            .and_then(|cids| load_metadata(content, &cids)),
+        (None, None) => content.list_metadata(),
+    };
+    let metadata = match metadata {
+        Ok(metadata) => metadata,
+        Err(e) => return storage_error(e),
+    };
+
+    let items = metadata
+        .into_iter()
+        .filter(|m| q.tag.as_ref().is_none_or(|tag| m.tags.contains(tag)))
+        .filter(|m| {
+            q.content_type
+                .as_deref()
+                .is_none_or(|prefix| m.content_type.starts_with(prefix))
+        });
+    let page = page.paginate(items, |m| match page.sort {
+        "size" => numeric_key(m.size as i64, m.cid.as_str()),
+        "cid" => m.cid.to_string(),
+        _ => numeric_key(m.created_at, m.cid.as_str()),
+    });
+    page_response(&headers, &page)
+}
+
+/// GET /api/v1/trust/:did/edges – Ausgehende oder eingehende Trust-Kanten
+#[utoipa::path(
+    get,
+    path = "/api/v1/trust/{did}/edges",
+    tag = "trust",
+    summary = "Trust edges of a DID",
+    params(("did" = String, Path, description = "DID-URI"), TrustEdgesQuery),
+    responses(
+        (status = 200, description = "Page<StoredTrust>; ETag", body = Object),
+        (status = 304, description = "If-None-Match passt"),
+        (status = 400, description = "Ungültige DID, Cursor oder Sortierfeld", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn trust_edges_handler(
+    State(state): State<AppState>,
+    Path(did_str): Path<String>,
+    Query(q): Query<TrustEdgesQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let Some(did) = did_uri(&did_str) else {
+        return bad_request("invalid_did");
+    };
+    let page = match PageRequest::new(
+        &["updated_at", "peer", "updates"],
+        q.sort.as_deref(),
+        q.order,
+        q.limit,
+        q.cursor.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return e.into_response(),
+    };
+    let direction = q.direction.unwrap_or_default();
+    let edges = match direction {
+        TrustDirection::Outgoing => state.storage.trust.get_outgoing_by_uri(did),
+        TrustDirection::Incoming => state.storage.trust.get_incoming_by_uri(did),
+    };
+    let edges = match edges {
+        Ok(edges) => edges,
+        Err(e) => return storage_error(e),
+    };
+
+    // Gegenseite der Kante ist eindeutig pro DID und Richtung
+    let peer = |edge: &StoredTrust| match direction {
+        TrustDirection::Outgoing => edge.to.to_string(),
+        TrustDirection::Incoming => edge.from.to_string(),
+    };
+    let page = page.paginate(edges, |edge| match page.sort {
+        "peer" => peer(edge),
+        "updates" => numeric_key(edge.update_count as i64, &peer(edge)),
+        _ => numeric_key(edge.updated_at, &peer(edge)),
+    });
+    page_response(&headers, &page)
+}
@@ -8,12 +8,36 @@ pub mod rest_handlers;
 // Phase 1: Debug & Observability (state, health, invariants, events, metrics, warnings)
 pub mod state_handlers;
 
+// OpenMetrics-Exposition (aus UnifiedSnapshot und Store-Metriken generiert)
+pub mod openmetrics;
+
+// Cursor-Pagination, Filter und ETags für Listen-Endpoints
+pub mod pagination;
+
+// Listen über die persistenten Stores (Identitäten, Content, Trust-Kanten)
+pub mod listing_handlers;
+
 // Phase 2: Produktion Kern (crossing, trust, identity, realm, ecl stubs)
 pub mod production_handlers;
 
 // Phase 4: Ops & Recovery (replay, checkpoints)
 pub mod debug_handlers;
 
+// Token-Ledger (balances, supply, escrow locks, journal)
+pub mod ledger_handlers;
+
+// Audit Log privilegierter Aufrufe (Abfrage, Ketten-Prüfung)
+pub mod audit_handlers;
+
+// Konfiguration (wirksame Werte, Reload-Diff, manueller Reload)
+pub mod config_handlers;
+
+// Saga-Ausführung (execute, status, cancel, rollback, signal)
+pub mod saga_handlers;
+
+// WebSocket-Subscriptions (Realm-Events, Trust-Änderungen, Saga-Fortschritt)
+pub mod subscriptions;
+
 // Passkey/WebAuthn authentication module
 pub mod auth;
 pub use auth::StoredPasskeyCredential;
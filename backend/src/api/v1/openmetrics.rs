@@ -0,0 +1,542 @@
+//! OpenMetrics-Exposition für `UnifiedSnapshot` und Store-Metriken
+//!
+//! Die Metriken werden nicht von Hand gepflegt, sondern systematisch aus der
+//! Serde-Serialisierung der Snapshots erzeugt:
+//!
+//! - Zahlen werden zu Samples, Booleans zu `0`/`1`; Strings, Listen und `null`
+//!   werden übersprungen.
+//! - Der Metrikname ist der Feldpfad, z.B. `erynoa_p2p_swarm_connected_peers`.
+//! - Map-Felder (`realms`, `prices`, `*_by_<x>`, …) werden zu Labels statt zu
+//!   Namensbestandteilen, z.B. `erynoa_realms_trust_score{realm="…"}`.
+//! - Ganzzahlige Felder mit kumulativem Namen (`*_total`, `*_sent`,
+//!   `*_errors`, …) werden als Counter exportiert, alles andere als Gauge.
+//! - Latenz-Histogramme der Stores werden als OpenMetrics-Histogramme in
+//!   Sekunden exportiert.
+//!
+//! Neue Snapshot-Felder erscheinen damit automatisch im Scrape-Output.
+
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+use crate::core::UnifiedSnapshot;
+use crate::local::{LatencyHistogramSnapshot, StoreMetricsSnapshot};
+
+/// Content-Type der OpenMetrics-Textdarstellung
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Präfix aller exportierten Metriken
+const PREFIX: &str = "erynoa";
+
+/// Map-Felder, deren Schlüssel als Label exportiert werden
+const MAP_LABELS: &[(&str, &str)] = &[
+    ("realms", "realm"),
+    ("realm_ecl", "realm"),
+    ("per_realm_trust", "realm"),
+    ("prices", "layer"),
+    ("sub_did_counts", "namespace"),
+];
+
+/// Letzte Namenssegmente, die auf einen monoton steigenden Zähler hinweisen
+const COUNTER_WORDS: &[&str] = &[
+    "total",
+    "sent",
+    "received",
+    "failed",
+    "denied",
+    "executed",
+    "errors",
+    "created",
+    "processed",
+    "triggered",
+    "consumed",
+    "blocked",
+    "updates",
+    "changes",
+    "violations",
+    "resets",
+    "completed",
+    "rejected",
+    "aborts",
+    "shed",
+    "limited",
+    "dropped",
+    "reads",
+    "writes",
+    "deletes",
+    "hits",
+    "misses",
+];
+
+/// Felder der Store-Snapshots, die separat als Histogramm exportiert werden
+const HISTOGRAM_FIELDS: &[&str] = &["read_latency", "write_latency"];
+
+// ============================================================================
+// Families
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl MetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            MetricKind::Counter => "counter",
+            MetricKind::Gauge => "gauge",
+            MetricKind::Histogram => "histogram",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Sample {
+    suffix: &'static str,
+    labels: Vec<(String, String)>,
+    value: String,
+}
+
+#[derive(Debug)]
+struct Family {
+    kind: MetricKind,
+    help: String,
+    samples: Vec<Sample>,
+    seen: HashSet<String>,
+}
+
+/// Builder für eine OpenMetrics-Exposition
+///
+/// ```rust,ignore
+/// let body = OpenMetrics::new()
+///     .snapshot(&state.unified_state.snapshot())
+///     .stores(&storage.store_metrics())
+///     .render();
+/// ```
+#[derive(Debug, Default)]
+pub struct OpenMetrics {
+    families: BTreeMap<String, Family>,
+}
+
+impl OpenMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Alle numerischen Felder eines `UnifiedSnapshot` aufnehmen
+    pub fn snapshot(mut self, snapshot: &UnifiedSnapshot) -> Self {
+        if let Ok(value) = serde_json::to_value(snapshot) {
+            let mut path = Vec::new();
+            self.walk(&value, &mut path, &[], &[]);
+            self.collect_health_scores(&value);
+        }
+
+        self.push(
+            &metric_name(&["system_mode"]),
+            MetricKind::Gauge,
+            "system_mode (0 = Normal, 1 = Degraded, 2 = EmergencyShutdown)",
+            Vec::new(),
+            (snapshot.system_mode as u8).to_string(),
+        );
+        self.push(
+            &metric_name(&["warnings"]),
+            MetricKind::Gauge,
+            "warnings (Anzahl aktiver Warnungen)",
+            Vec::new(),
+            snapshot.warnings.len().to_string(),
+        );
+        self
+    }
+
+    /// Store-Metriken mit `store`-Label und Latenz-Histogrammen aufnehmen
+    pub fn stores(mut self, stores: &[(String, StoreMetricsSnapshot)]) -> Self {
+        for (store, metrics) in stores {
+            let labels = vec![("store".to_string(), store.clone())];
+
+            if let Ok(value) = serde_json::to_value(metrics) {
+                let mut path = vec!["store".to_string()];
+                self.walk(&value, &mut path, &labels, HISTOGRAM_FIELDS);
+            }
+
+            self.histogram(
+                &metric_name(&["storage", "read_latency_seconds"]),
+                "Lese-Latenz der lokalen Stores",
+                &labels,
+                &metrics.read_latency,
+            );
+            self.histogram(
+                &metric_name(&["storage", "write_latency_seconds"]),
+                "Schreib-Latenz der lokalen Stores",
+                &labels,
+                &metrics.write_latency,
+            );
+        }
+        self
+    }
+
+    /// OpenMetrics-Text erzeugen (inkl. abschließendem `# EOF`)
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (name, family) in &self.families {
+            let _ = writeln!(out, "# TYPE {} {}", name, family.kind.as_str());
+            let _ = writeln!(out, "# HELP {} {}", name, escape_help(&family.help));
+            for sample in &family.samples {
+                out.push_str(name);
+                out.push_str(sample.suffix);
+                if !sample.labels.is_empty() {
+                    out.push('{');
+                    for (i, (key, value)) in sample.labels.iter().enumerate() {
+                        if i > 0 {
+                            out.push(',');
+                        }
+                        let _ = write!(out, "{}=\"{}\"", key, escape_label(value));
+                    }
+                    out.push('}');
+                }
+                let _ = writeln!(out, " {}", sample.value);
+            }
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+
+    // ------------------------------------------------------------------------
+    // Intern
+    // ------------------------------------------------------------------------
+
+    fn walk(
+        &mut self,
+        value: &Value,
+        path: &mut Vec<String>,
+        labels: &[(String, String)],
+        skip: &[&str],
+    ) {
+        match value {
+            Value::Object(fields) => {
+                for (key, child) in fields {
+                    if path.len() == 1 && skip.contains(&key.as_str()) {
+                        continue;
+                    }
+                    path.push(key.clone());
+                    match (map_label(key), child) {
+                        (Some(label), Value::Object(entries)) => {
+                            for (entry, inner) in entries {
+                                let mut labels = labels.to_vec();
+                                labels.push((label.clone(), entry.clone()));
+                                self.walk(inner, path, &labels, &[]);
+                            }
+                        }
+                        _ => self.walk(child, path, labels, &[]),
+                    }
+                    path.pop();
+                }
+            }
+            Value::Number(number) => {
+                let field = path.last().map(String::as_str).unwrap_or_default();
+                let kind = if number.is_u64() && is_counter_name(field) {
+                    MetricKind::Counter
+                } else {
+                    MetricKind::Gauge
+                };
+                let segments: Vec<&str> = path.iter().map(String::as_str).collect();
+                self.push(
+                    &metric_name(&segments),
+                    kind,
+                    &segments.join("."),
+                    labels.to_vec(),
+                    format_number(number),
+                );
+            }
+            Value::Bool(flag) => {
+                let segments: Vec<&str> = path.iter().map(String::as_str).collect();
+                self.push(
+                    &metric_name(&segments),
+                    MetricKind::Gauge,
+                    &segments.join("."),
+                    labels.to_vec(),
+                    u8::from(*flag).to_string(),
+                );
+            }
+            Value::String(_) | Value::Array(_) | Value::Null => {}
+        }
+    }
+
+    /// `health_score` jeder Top-Level-Komponente als gemeinsame Familie
+    fn collect_health_scores(&mut self, snapshot: &Value) {
+        let Value::Object(components) = snapshot else {
+            return;
+        };
+        for (component, value) in components {
+            let Some(score) = value.get("health_score").and_then(Value::as_f64) else {
+                continue;
+            };
+            self.push(
+                &metric_name(&["component", "health_score"]),
+                MetricKind::Gauge,
+                "health_score je Komponente",
+                vec![("component".to_string(), component.clone())],
+                format_float(score),
+            );
+        }
+    }
+
+    fn histogram(
+        &mut self,
+        name: &str,
+        help: &str,
+        labels: &[(String, String)],
+        histogram: &LatencyHistogramSnapshot,
+    ) {
+        let mut cumulative = 0u64;
+        for (bound, count) in histogram.bounds_us.iter().zip(&histogram.counts) {
+            cumulative += count;
+            let mut labels = labels.to_vec();
+            labels.push(("le".to_string(), format_float(*bound as f64 / 1_000_000.0)));
+            self.push_suffixed(
+                name,
+                MetricKind::Histogram,
+                help,
+                "_bucket",
+                labels,
+                cumulative.to_string(),
+            );
+        }
+
+        let total = histogram.count();
+        let mut inf = labels.to_vec();
+        inf.push(("le".to_string(), "+Inf".to_string()));
+        self.push_suffixed(
+            name,
+            MetricKind::Histogram,
+            help,
+            "_bucket",
+            inf,
+            total.to_string(),
+        );
+        self.push_suffixed(
+            name,
+            MetricKind::Histogram,
+            help,
+            "_sum",
+            labels.to_vec(),
+            format_float(histogram.sum_us as f64 / 1_000_000.0),
+        );
+        self.push_suffixed(
+            name,
+            MetricKind::Histogram,
+            help,
+            "_count",
+            labels.to_vec(),
+            total.to_string(),
+        );
+    }
+
+    fn push(
+        &mut self,
+        name: &str,
+        kind: MetricKind,
+        help: &str,
+        labels: Vec<(String, String)>,
+        value: String,
+    ) {
+        match kind {
+            MetricKind::Counter => {
+                let family = name.strip_suffix("_total").unwrap_or(name);
+                self.push_suffixed(family, kind, help, "_total", labels, value);
+            }
+            _ => self.push_suffixed(name, kind, help, "", labels, value),
+        }
+    }
+
+    fn push_suffixed(
+        &mut self,
+        name: &str,
+        kind: MetricKind,
+        help: &str,
+        suffix: &'static str,
+        labels: Vec<(String, String)>,
+        value: String,
+    ) {
+        let family = self
+            .families
+            .entry(name.to_string())
+            .or_insert_with(|| Family {
+                kind,
+                help: help.to_string(),
+                samples: Vec::new(),
+                seen: HashSet::new(),
+            });
+
+        // Kollidierende Pfade (z.B. `a_b.c` und `a.b_c`) nur einmal exportieren
+        if family.kind != kind {
+            return;
+        }
+        let key = format!("{}{:?}", suffix, labels);
+        if !family.seen.insert(key) {
+            return;
+        }
+        family.samples.push(Sample {
+            suffix,
+            labels,
+            value,
+        });
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Label-Name für ein Map-Feld (`realms` → `realm`, `goals_by_type` → `type`)
+fn map_label(field: &str) -> Option<String> {
+    if let Some((_, label)) = MAP_LABELS.iter().find(|(name, _)| *name == field) {
+        return Some((*label).to_string());
+    }
+    field
+        .rsplit_once("_by_")
+        .map(|(_, label)| sanitize(label))
+        .filter(|label| !label.is_empty())
+}
+
+fn is_counter_name(field: &str) -> bool {
+    let last = field.rsplit('_').next().unwrap_or(field);
+    COUNTER_WORDS.contains(&last)
+}
+
+fn metric_name(segments: &[&str]) -> String {
+    let mut name = PREFIX.to_string();
+    for segment in segments {
+        name.push('_');
+        name.push_str(&sanitize(segment));
+    }
+    name
+}
+
+fn sanitize(raw: &str) -> String {
+    raw.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn format_number(number: &serde_json::Number) -> String {
+    match (number.as_u64(), number.as_i64(), number.as_f64()) {
+        (Some(value), _, _) => value.to_string(),
+        (_, Some(value), _) => value.to_string(),
+        (_, _, Some(value)) => format_float(value),
+        _ => "NaN".to_string(),
+    }
+}
+
+fn format_float(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_help(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UnifiedState;
+    use crate::local::StoreMetrics;
+
+    fn lines(body: &str) -> Vec<&str> {
+        body.lines().collect()
+    }
+
+    #[test]
+    fn test_snapshot_exposition_is_typed() {
+        let snapshot = UnifiedState::new().snapshot();
+        let body = OpenMetrics::new().snapshot(&snapshot).render();
+        let lines = lines(&body);
+
+        assert_eq!(lines.last(), Some(&"# EOF"));
+        assert!(lines.contains(&"# TYPE erynoa_health_score gauge"));
+        assert!(lines.contains(&"# TYPE erynoa_system_mode gauge"));
+        assert!(lines.contains(&"erynoa_system_mode 0"));
+        assert!(lines.contains(&"# TYPE erynoa_p2p_gossip_messages_sent counter"));
+        assert!(lines.contains(&"erynoa_p2p_gossip_messages_sent_total 0"));
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("erynoa_component_health_score{component=\"p2p\"}")));
+
+        // Jede Familie hat genau eine TYPE- und HELP-Zeile
+        let types = lines.iter().filter(|l| l.starts_with("# TYPE")).count();
+        let helps = lines.iter().filter(|l| l.starts_with("# HELP")).count();
+        assert_eq!(types, helps);
+        assert!(types > 100);
+    }
+
+    #[test]
+    fn test_map_fields_become_labels() {
+        assert_eq!(map_label("realms").as_deref(), Some("realm"));
+        assert_eq!(map_label("goals_by_type").as_deref(), Some("type"));
+        assert_eq!(map_label("connected_peers"), None);
+
+        let value = serde_json::json!({
+            "governance": { "policies_by_type": { "quorum": 3, "veto": 1 } }
+        });
+        let mut metrics = OpenMetrics::new();
+        metrics.walk(&value, &mut Vec::new(), &[], &[]);
+        let body = metrics.render();
+
+        assert!(body.contains("erynoa_governance_policies_by_type{type=\"quorum\"} 3\n"));
+        assert!(body.contains("erynoa_governance_policies_by_type{type=\"veto\"} 1\n"));
+    }
+
+    #[test]
+    fn test_store_latency_histogram() {
+        let store = StoreMetrics::new();
+        store.record_read(5, 1);
+        store.record_read(300, 1);
+        store.record_write(20, 1);
+
+        let body = OpenMetrics::new()
+            .stores(&[("events".to_string(), store.snapshot())])
+            .render();
+
+        assert!(body.contains("# TYPE erynoa_storage_read_latency_seconds histogram\n"));
+        assert!(body.contains(
+            "erynoa_storage_read_latency_seconds_bucket{store=\"events\",le=\"0.00001\"} 1\n"
+        ));
+        assert!(body.contains(
+            "erynoa_storage_read_latency_seconds_bucket{store=\"events\",le=\"+Inf\"} 2\n"
+        ));
+        assert!(body.contains("erynoa_storage_read_latency_seconds_count{store=\"events\"} 2\n"));
+        assert!(body.contains("erynoa_store_reads_total{store=\"events\"} 2\n"));
+        assert!(!body.contains("erynoa_store_read_latency"));
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+        assert_eq!(format_float(f64::INFINITY), "+Inf");
+        assert_eq!(sanitize("realm-1.x"), "realm_1_x");
+    }
+}
@@ -0,0 +1,322 @@
+//! Cursor-Pagination für Listen-Endpoints
+//!
+//! Alle Listen (`/events`, `/realms`, `/identity`, `/content`,
+//! `/trust/{did}/edges`) antworten mit einer [`Page`] und verstehen dieselben
+//! Query-Parameter:
+//!
+//! | Parameter | Bedeutung |
+//! |-----------|-----------|
+//! | `limit` | Seitengröße, Default 100, höchstens 1000 |
+//! | `cursor` | `next_cursor` der vorigen Seite |
+//! | `sort` | Sortierfeld (pro Endpoint, erstes ist Default) |
+//! | `order` | `asc` oder `desc` |
+//!
+//! Der Cursor ist opak (Base64url) und enthält Sortierfeld und Sortschlüssel
+//! des letzten gelieferten Elements; die nächste Seite beginnt direkt
+//! dahinter. Anders als bei Offsets verschieben neue Einträge zwischen zwei
+//! Abrufen keine Elemente auf die nächste Seite.
+//!
+//! Antworten tragen ein schwaches `ETag` über den Body. Stimmt es mit
+//! `If-None-Match` überein, antwortet der Endpoint mit `304 Not Modified`
+//! ohne Body.
+
+use axum::{
+    http::{
+        header::{CONTENT_TYPE, ETAG, IF_NONE_MATCH},
+        HeaderMap, HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
+    Json,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Seitengröße ohne `limit`
+pub const DEFAULT_LIMIT: usize = 100;
+/// Größte erlaubte Seitengröße
+pub const MAX_LIMIT: usize = 1000;
+
+/// Trenner zwischen Sortierfeld und Schlüssel im Cursor
+const CURSOR_SEPARATOR: char = '\n';
+
+/// Sortierrichtung
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Eine Seite einer Liste
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor der nächsten Seite; fehlt auf der letzten Seite
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Anzahl aller Elemente, die Filter erfüllen (zum Abfragezeitpunkt;
+    /// kann sich bis zur nächsten Seite ändern)
+    pub total_estimate: u64,
+}
+
+/// Ungültige Pagination-Parameter (400)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageError {
+    /// Cursor nicht dekodierbar oder zu anderem Sortierfeld
+    InvalidCursor,
+    /// Sortierfeld wird vom Endpoint nicht unterstützt
+    InvalidSort {
+        requested: String,
+        allowed: Vec<&'static str>,
+    },
+}
+
+impl IntoResponse for PageError {
+    fn into_response(self) -> Response {
+        let (code, message) = match self {
+            Self::InvalidCursor => (
+                "invalid_cursor",
+                "cursor is malformed or belongs to a different sort".to_string(),
+            ),
+            Self::InvalidSort { requested, allowed } => (
+                "invalid_sort",
+                format!(
+                    "cannot sort by '{requested}', expected one of: {}",
+                    allowed.join(", ")
+                ),
+            ),
+        };
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": code, "message": message })),
+        )
+            .into_response()
+    }
+}
+
+/// Geprüfte Pagination-Parameter einer Anfrage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Gewähltes Sortierfeld
+    pub sort: &'static str,
+    pub order: SortOrder,
+    pub limit: usize,
+    /// Sortschlüssel des letzten Elements der vorigen Seite
+    after: Option<String>,
+}
+
+impl PageRequest {
+    /// Prüft die Query-Parameter; `allowed[0]` ist das Default-Sortierfeld
+    pub fn new(
+        allowed: &[&'static str],
+        sort: Option<&str>,
+        order: Option<SortOrder>,
+        limit: Option<usize>,
+        cursor: Option<&str>,
+    ) -> Result<Self, PageError> {
+        let sort = match sort {
+            None => allowed[0],
+            Some(requested) => allowed
+                .iter()
+                .copied()
+                .find(|field| *field == requested)
+                .ok_or_else(|| PageError::InvalidSort {
+                    requested: requested.to_string(),
+                    allowed: allowed.to_vec(),
+                })?,
+        };
+        let after = cursor
+            .map(|cursor| decode_cursor(sort, cursor).ok_or(PageError::InvalidCursor))
+            .transpose()?;
+        Ok(Self {
+            sort,
+            order: order.unwrap_or_default(),
+            limit: limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+            after,
+        })
+    }
+
+    /// Sortiert, schneidet die Seite hinter dem Cursor aus und setzt den
+    /// nächsten Cursor. `key` muss pro Element eindeutig sein und
+    /// lexikographisch wie das Sortierfeld ordnen (siehe [`numeric_key`]).
+    pub fn paginate<T>(
+        &self,
+        items: impl IntoIterator<Item = T>,
+        key: impl Fn(&T) -> String,
+    ) -> Page<T> {
+        let mut keyed: Vec<(String, T)> =
+            items.into_iter().map(|item| (key(&item), item)).collect();
+        keyed.sort_unstable_by(|a, b| match self.order {
+            SortOrder::Asc => a.0.cmp(&b.0),
+            SortOrder::Desc => b.0.cmp(&a.0),
+        });
+        let total_estimate = keyed.len() as u64;
+
+        let start = self.after.as_ref().map_or(0, |after| {
+            keyed.partition_point(|(key, _)| match self.order {
+                SortOrder::Asc => key <= after,
+                SortOrder::Desc => key >= after,
+            })
+        });
+        let mut rest = keyed.into_iter().skip(start);
+        let page: Vec<(String, T)> = rest.by_ref().take(self.limit).collect();
+        let next_cursor = match (rest.next(), page.last()) {
+            (Some(_), Some((last, _))) => Some(encode_cursor(self.sort, last)),
+            _ => None,
+        };
+        Page {
+            items: page.into_iter().map(|(_, item)| item).collect(),
+            next_cursor,
+            total_estimate,
+        }
+    }
+}
+
+/// Sortschlüssel für Zahlen (nullgefüllt) mit eindeutigem Suffix
+pub fn numeric_key(value: i64, id: &str) -> String {
+    format!("{:020}{CURSOR_SEPARATOR}{id}", value.max(0))
+}
+
+fn encode_cursor(sort: &str, key: &str) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{sort}{CURSOR_SEPARATOR}{key}"))
+}
+
+fn decode_cursor(sort: &str, cursor: &str) -> Option<String> {
+    let raw = String::from_utf8(URL_SAFE_NO_PAD.decode(cursor).ok()?).ok()?;
+    let (field, key) = raw.split_once(CURSOR_SEPARATOR)?;
+    (field == sort).then(|| key.to_string())
+}
+
+/// JSON-Antwort mit `ETag`; `304` bei passendem `If-None-Match`
+///
+/// Das ETag wird über die kanonische Form (`serde_json::Value`, Objekte mit
+/// sortierten Schlüsseln) gebildet, damit `HashMap`-Felder es nicht
+/// zufällig ändern.
+pub fn page_response<T: Serialize>(headers: &HeaderMap, page: &Page<T>) -> Response {
+    let body =
+        match serde_json::to_value(page).and_then(|value| serde_json::to_vec(&value)) {
+            Ok(body) => body,
+            Err(e) => return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(
+                    serde_json::json!({ "error": "serialization_error", "message": e.to_string() }),
+                ),
+            )
+                .into_response(),
+        };
+    let etag = format!("W/\"{}\"", &blake3::hash(&body).to_hex()[..32]);
+    let etag_value = HeaderValue::from_str(&etag).expect("hex etag is a valid header value");
+    if etag_matches(headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(ETAG, etag_value)]).into_response();
+    }
+    (
+        [
+            (CONTENT_TYPE, HeaderValue::from_static("application/json")),
+            (ETAG, etag_value),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// Schwacher Vergleich nach RFC 9110 (`W/` wird ignoriert, `*` passt immer)
+fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let ours = opaque(etag);
+    headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|tag| tag.trim() == "*" || opaque(tag) == ours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(order: SortOrder, limit: usize, cursor: Option<&str>) -> PageRequest {
+        PageRequest::new(&["id", "size"], None, Some(order), Some(limit), cursor).unwrap()
+    }
+
+    fn walk(order: SortOrder) -> Vec<Vec<u32>> {
+        let items = [5u32, 1, 4, 2, 3];
+        let mut pages = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = request(order, 2, cursor.as_deref()).paginate(items, |n| n.to_string());
+            assert_eq!(page.total_estimate, 5);
+            pages.push(page.items);
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => return pages,
+            }
+        }
+    }
+
+    #[test]
+    fn test_paginate_walks_all_items() {
+        assert_eq!(walk(SortOrder::Asc), vec![vec![1, 2], vec![3, 4], vec![5]]);
+        assert_eq!(walk(SortOrder::Desc), vec![vec![5, 4], vec![3, 2], vec![1]]);
+
+        // Neue Elemente vor dem Cursor verschieben die Folgeseite nicht
+        let first = request(SortOrder::Asc, 2, None).paginate([1u32, 2, 3, 4], |n| n.to_string());
+        let cursor = first.next_cursor.unwrap();
+        let second = request(SortOrder::Asc, 2, Some(&cursor))
+            .paginate([0u32, 1, 2, 3, 4], |n| n.to_string());
+        assert_eq!(second.items, vec![3, 4]);
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(
+            PageRequest::new(&["id"], None, None, None, Some("%%%")),
+            Err(PageError::InvalidCursor)
+        );
+        // Cursor eines anderen Sortierfelds
+        let cursor = encode_cursor("size", "7");
+        assert_eq!(
+            PageRequest::new(&["id", "size"], None, None, None, Some(&cursor)),
+            Err(PageError::InvalidCursor)
+        );
+        assert!(PageRequest::new(&["id", "size"], Some("size"), None, None, Some(&cursor)).is_ok());
+        assert!(matches!(
+            PageRequest::new(&["id"], Some("name"), None, None, None),
+            Err(PageError::InvalidSort { .. })
+        ));
+        let clamped = PageRequest::new(&["id"], None, None, Some(100_000), None).unwrap();
+        assert_eq!(clamped.limit, MAX_LIMIT);
+    }
+
+    #[test]
+    fn test_numeric_key_orders_like_numbers() {
+        assert!(numeric_key(9, "b") < numeric_key(10, "a"));
+        assert!(numeric_key(10, "a") < numeric_key(10, "b"));
+    }
+
+    #[test]
+    fn test_etag_and_if_none_match() {
+        let page = request(SortOrder::Asc, 10, None).paginate([1u32, 2], |n| n.to_string());
+        let response = page_response(&HeaderMap::new(), &page);
+        assert_eq!(response.status(), StatusCode::OK);
+        let etag = response.headers()[ETAG].to_str().unwrap().to_string();
+        assert!(etag.starts_with("W/\""));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            IF_NONE_MATCH,
+            HeaderValue::from_str(&format!("\"other\", {}", etag.trim_start_matches("W/")))
+                .unwrap(),
+        );
+        assert_eq!(
+            page_response(&headers, &page).status(),
+            StatusCode::NOT_MODIFIED
+        );
+
+        headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"other\""));
+        assert_eq!(page_response(&headers, &page).status(), StatusCode::OK);
+    }
+}
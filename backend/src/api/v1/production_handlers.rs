@@ -1,36 +1,79 @@
 //! Phase 2 & 3: Produktion Kern – Crossing, ECL, Trust, Identity, Realm, Governance, Controller, Intent, Saga
 //!
-//! Endpoints für Crossing/validate, ECL (stubs), Trust, Identity, Realm (Phase 2);
-//! Governance (proposals, vote), Controller (check, permissions), Intent parse, Saga (compose, execute, stats) (Phase 3).
+//! Endpoints für Crossing/validate, ECL (Handler pro Realm), Trust, Identity, Realm (Phase 2);
+//! Governance (proposals, vote, finalize), Controller (check, permissions), Intent parse, Saga (compose, stats) (Phase 3).
+//! Saga-Ausführung liegt in `saga_handlers`.
 
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    response::IntoResponse,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::core::{
-    ControllerSnapshot, GatewaySnapshot, GovernanceSnapshot, RealmSnapshot, SagaComposerSnapshot,
-    StateEvent, TrustReason,
+    ControllerSnapshot, GatewaySnapshot, GovernanceSnapshot, RealmSpecificSnapshot,
+    SagaComposerSnapshot, StateEvent, TrustReason,
 };
+use crate::api::v1::pagination::{numeric_key, page_response, PageRequest, SortOrder};
+use crate::api::v1::auth::AuthContext;
 use crate::core::state::IdentitySnapshot;
-use crate::domain::{realm_id_from_name, DID};
+use crate::domain::unified::identity::Capability;
+use crate::domain::unified::VoteDirection;
+use crate::domain::{realm_id_from_name, Constraint, Goal, Intent, UniversalId, DID};
+use crate::eclvm::EntrypointKind;
+use crate::local::GovernanceAction;
+use crate::peer::ecl_service::{EclInvocation, EclServiceError, EclSource, EclUpload};
+use crate::peer::governance::GovernanceServiceError;
+use crate::peer::intent_grammar::parse_did_uri;
+use crate::peer::intent_parser::ParseError;
+use crate::peer::query::{QueryEngine, QueryError};
+use crate::peer::{IntentParser, NewProposal};
+use crate::error::ApiError;
+use crate::protection::quadratic::GovernanceError;
 use crate::server::AppState;
 
 // ============================================================================
 // Request / Response types
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RealmListQuery {
+    /// Seitengröße (Default 100, max. 1000)
+    pub limit: Option<usize>,
+    /// `next_cursor` der vorigen Seite
+    pub cursor: Option<String>,
+    /// `realm_id` (Default), `members`, `created_at` oder `events`
+    pub sort: Option<String>,
+    pub order: Option<SortOrder>,
+    /// Nur Realms mit diesem Governance-Typ
+    pub governance_type: Option<String>,
+    /// Nur Realms mit mindestens so vielen Mitgliedern
+    pub min_members: Option<usize>,
+}
+
+/// Eintrag in `GET /realms`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RealmListItem {
+    pub realm_id: String,
+    #[serde(flatten)]
+    pub snapshot: RealmSpecificSnapshot,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CrossingValidateBody {
     pub caller_did: String,
     pub from_realm: String,
     pub to_realm: String,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CrossingValidateResponse {
     pub allowed: bool,
     pub from_realm: String,
@@ -40,7 +83,7 @@ pub struct CrossingValidateResponse {
     pub violations: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TrustUpdateBody {
     pub entity_id: String,
     pub delta: f64,
@@ -49,64 +92,123 @@ pub struct TrustUpdateBody {
     pub from_realm: Option<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TrustGetResponse {
     pub did: String,
     pub trust: Option<f64>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TrustUpdateResponse {
     pub sequence: u64,
     pub component: String,
     pub event_id: String,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct IdentityRootResponse {
     pub root_did: Option<String>,
+    #[schema(value_type = Object)]
     pub snapshot: IdentitySnapshot,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RealmCreateBody {
     pub realm_id: String,
     #[serde(default)]
     pub name: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RealmMembersBody {
-    pub identity_id: String,
+    /// Betroffene Identity (Standard: Aufrufer; für invite/ban erforderlich)
+    #[serde(default)]
+    pub identity_id: Option<String>,
     pub action: String, // "join" | "leave" | "invite" etc.
 }
 
-#[derive(Serialize)]
-pub struct EclNotConfiguredResponse {
-    pub error: &'static str,
-    pub message: &'static str,
+/// POST /ecl/run – Quelltext oder Bytecode ad hoc im Realm ausführen
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EclRunBody {
+    pub realm_id: String,
+    /// `{"source": "..."}` oder `{"bytecode": [...]}`
+    #[serde(flatten)]
+    #[schema(value_type = Object)]
+    pub program: EclSource,
+    #[serde(default)]
+    pub gas_limit: Option<u64>,
+}
+
+/// POST /ecl/{api,ui,controller}/{key} – registrierten Handler ausführen
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EclInvokeBody {
+    pub realm_id: String,
+    #[serde(default)]
+    pub gas_limit: Option<u64>,
+}
+
+/// POST /ecl/handlers – Handler hochladen (nur Realm-Admins)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EclUploadBody {
+    pub realm_id: String,
+    pub kind: EntrypointKind,
+    pub key: String,
+    /// `{"source": "..."}` oder `{"bytecode": [...]}`
+    #[serde(flatten)]
+    #[schema(value_type = Object)]
+    pub program: EclSource,
+    #[serde(default)]
+    pub optimize: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EclHandlersQuery {
+    pub realm: String,
 }
 
 // Phase 3: Governance, Controller, Intent, Saga
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GovernanceProposalBody {
-    pub proposal_id: String,
     pub realm_id: String,
-    pub proposer_id: String,
-    pub proposal_type: String,
+    /// DID-URI des Antragstellers (optional, muss dem Aufrufer entsprechen)
+    #[serde(default)]
+    pub proposer_id: Option<String>,
+    pub title: String,
     #[serde(default)]
-    pub deadline_ms: Option<u128>,
+    pub description: String,
+    /// Bei Annahme auszuführende Aktion
+    #[schema(value_type = Object)]
+    pub action: GovernanceAction,
+    /// Abstimmungsdauer in Sekunden (Standard: 1 Woche)
+    #[serde(default)]
+    pub voting_period_secs: Option<u64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GovernanceVoteBody {
-    pub voter_id: String,
-    pub vote: bool,
+    /// DID-URI des Abstimmenden (optional, muss dem Aufrufer entsprechen)
+    #[serde(default)]
+    pub voter_id: Option<String>,
+    /// Kurzform: true = for, false = against
+    #[serde(default)]
+    pub vote: Option<bool>,
+    /// for | against | abstain (hat Vorrang vor `vote`)
+    #[serde(default)]
+    pub direction: Option<VoteDirection>,
+    /// Anzahl Stimmen (Κ21: kostet votes² Credits, Standard 1)
     #[serde(default)]
-    pub weight: Option<f64>,
+    pub votes: Option<u64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GovernanceListQuery {
+    #[serde(default)]
+    pub realm: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ControllerCheckBody {
     pub permission: String,
     pub resource: String,
@@ -114,39 +216,57 @@ pub struct ControllerCheckBody {
     pub realm_id: String,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ControllerCheckResponse {
     pub allowed: bool,
     pub reason: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct IntentParseBody {
     pub text: Option<String>,
     pub goal: Option<serde_json::Value>,
+    /// Quelle (DID-URI); ohne Angabe die Root-DID des Knotens, bei
+    /// `/intent/query` immer die DID der Session
+    pub source_did: Option<String>,
+    #[serde(default)]
+    #[schema(value_type = Vec<Object>)]
+    pub constraints: Vec<Constraint>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Body für `/intent/simulate` und `/intent/query`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IntentRequestBody {
+    /// Fertiger Intent; sonst wird wie bei `/intent/parse` geparst
+    #[schema(value_type = Option<Object>)]
+    pub intent: Option<Intent>,
+    #[serde(flatten)]
+    pub request: IntentParseBody,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SagaComposeBody {
     pub goal_type: Option<String>,
     pub constraints: Option<Vec<String>>,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct SagaExecuteBody {
-    pub saga_id: String,
-    pub step: Option<usize>,
-    pub total_steps: Option<usize>,
-    pub cross_realm: Option<bool>,
-    pub compensation_triggered: Option<bool>,
-    pub realms: Option<Vec<String>>,
-}
-
 // ============================================================================
 // Crossing & Gateway
 // ============================================================================
 
 /// POST /api/v1/crossing/validate – Validate realm crossing (GatewayGuard)
+#[utoipa::path(
+    post,
+    path = "/api/v1/crossing/validate",
+    tag = "crossing",
+    summary = "Validate a realm crossing",
+    request_body = CrossingValidateBody,
+    responses(
+        (status = 200, description = "Ergebnis der GatewayGuard-Prüfung", body = CrossingValidateResponse),
+        (status = 400, description = "Ungültige DID oder Prüfung fehlgeschlagen", body = crate::api::openapi::ErrorBody),
+        (status = 503, description = "GatewayGuard nicht konfiguriert", body = crate::api::openapi::ErrorBody)
+    )
+)]
 pub async fn crossing_validate_handler(
     State(state): State<AppState>,
     Json(body): Json<CrossingValidateBody>,
@@ -198,6 +318,15 @@ pub async fn crossing_validate_handler(
 }
 
 /// GET /api/v1/crossing/stats – GatewaySnapshot (crossings_total, allowed, denied, …)
+#[utoipa::path(
+    get,
+    path = "/api/v1/crossing/stats",
+    tag = "crossing",
+    summary = "Gateway statistics",
+    responses(
+        (status = 200, description = "GatewaySnapshot", body = serde_json::Value)
+    )
+)]
 pub async fn crossing_stats_handler(State(state): State<AppState>) -> Json<GatewaySnapshot> {
     let snapshot = state.unified_state.snapshot();
     Json(snapshot.peer.gateway)
@@ -208,6 +337,17 @@ pub async fn crossing_stats_handler(State(state): State<AppState>) -> Json<Gatew
 // ============================================================================
 
 /// GET /api/v1/trust/:did – Trust value or TrustEntry for DID
+#[utoipa::path(
+    get,
+    path = "/api/v1/trust/{did}",
+    tag = "trust",
+    summary = "Trust value of a DID",
+    params(("did" = String, Path, description = "DID-URI")),
+    responses(
+        (status = 200, description = "Trust (null, falls unbekannt)", body = TrustGetResponse),
+        (status = 400, description = "Ungültige DID", body = crate::api::openapi::ErrorBody)
+    )
+)]
 pub async fn trust_get_handler(
     State(state): State<AppState>,
     Path(did_str): Path<String>,
@@ -232,10 +372,35 @@ pub async fn trust_get_handler(
 }
 
 /// POST /api/v1/trust/update – Emit TrustUpdate event via log_and_apply
+#[utoipa::path(
+    post,
+    path = "/api/v1/trust/update",
+    tag = "trust",
+    summary = "Emit a TrustUpdate event",
+    request_body = TrustUpdateBody,
+    responses(
+        (status = 200, description = "Geloggtes Event", body = TrustUpdateResponse),
+        (status = 400, description = "Ungültige entity_id", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn trust_update_handler(
     State(state): State<AppState>,
+    auth: AuthContext,
     Json(body): Json<TrustUpdateBody>,
 ) -> impl IntoResponse {
+    if let Err(e) = auth.require(&Capability::Write {
+        resource: "trust".into(),
+    }) {
+        return e.into_response();
+    }
+    if let Some(realm) = &body.from_realm {
+        if let Err(e) = auth.require_realm(realm) {
+            return e.into_response();
+        }
+    }
     let did = match DID::parse(&body.entity_id) {
         Ok(d) => d,
         Err(_) => {
@@ -273,6 +438,15 @@ pub async fn trust_update_handler(
 // ============================================================================
 
 /// GET /api/v1/identity/root – Root DID and identity snapshot
+#[utoipa::path(
+    get,
+    path = "/api/v1/identity/root",
+    tag = "identity",
+    summary = "Root DID and identity snapshot",
+    responses(
+        (status = 200, description = "Root-DID", body = IdentityRootResponse)
+    )
+)]
 pub async fn identity_root_handler(State(state): State<AppState>) -> Json<IdentityRootResponse> {
     let snapshot = state.unified_state.snapshot();
     Json(IdentityRootResponse {
@@ -282,6 +456,16 @@ pub async fn identity_root_handler(State(state): State<AppState>) -> Json<Identi
 }
 
 /// GET /api/v1/identity/:did – Identity info for DID (from snapshot/state)
+#[utoipa::path(
+    get,
+    path = "/api/v1/identity/{did}",
+    tag = "identity",
+    summary = "Identity info for a DID",
+    params(("did" = String, Path, description = "DID-URI")),
+    responses(
+        (status = 200, description = "`{did, is_root, ...}`", body = serde_json::Value)
+    )
+)]
 pub async fn identity_get_handler(
     State(state): State<AppState>,
     Path(did_str): Path<String>,
@@ -309,13 +493,69 @@ pub async fn identity_get_handler(
 // Realm
 // ============================================================================
 
-/// GET /api/v1/realms – Realm list (from snapshot.peer.realm)
-pub async fn realms_list_handler(State(state): State<AppState>) -> Json<RealmSnapshot> {
-    let snapshot = state.unified_state.snapshot();
-    Json(snapshot.peer.realm)
+/// GET /api/v1/realms – Realms (aus snapshot.peer.realm), cursor-paginiert und gefiltert
+#[utoipa::path(
+    get,
+    path = "/api/v1/realms",
+    tag = "realms",
+    summary = "All realms",
+    params(RealmListQuery),
+    responses(
+        (status = 200, description = "Page<RealmListItem>; ETag", body = Object),
+        (status = 304, description = "If-None-Match passt"),
+        (status = 400, description = "Ungültiger Cursor oder Sortierfeld", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn realms_list_handler(
+    State(state): State<AppState>,
+    Query(q): Query<RealmListQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let page = match PageRequest::new(
+        &["realm_id", "members", "created_at", "events"],
+        q.sort.as_deref(),
+        q.order,
+        q.limit,
+        q.cursor.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return e.into_response(),
+    };
+    let realms = state
+        .unified_state
+        .snapshot()
+        .peer
+        .realm
+        .realms
+        .into_iter()
+        .map(|(realm_id, snapshot)| RealmListItem { realm_id, snapshot })
+        .filter(|r| {
+            q.governance_type
+                .as_ref()
+                .is_none_or(|g| r.snapshot.governance_type.eq_ignore_ascii_case(g))
+        })
+        .filter(|r| q.min_members.is_none_or(|min| r.snapshot.member_count >= min));
+    let page = page.paginate(realms, |r| match page.sort {
+        "members" => numeric_key(r.snapshot.member_count as i64, &r.realm_id),
+        "created_at" => numeric_key(r.snapshot.created_at as i64, &r.realm_id),
+        "events" => numeric_key(r.snapshot.events_total as i64, &r.realm_id),
+        _ => r.realm_id.clone(),
+    });
+    page_response(&headers, &page)
 }
 
 /// GET /api/v1/realms/:realm_id – Single realm + rules, members, ECL info
+#[utoipa::path(
+    get,
+    path = "/api/v1/realms/{realm_id}",
+    tag = "realms",
+    summary = "Single realm",
+    params(("realm_id" = String, Path, description = "Realm-ID")),
+    responses(
+        (status = 200, description = "`{realm_id, snapshot, total_realms}`", body = serde_json::Value),
+        (status = 404, description = "Realm unbekannt", body = crate::api::openapi::ErrorBody)
+    )
+)]
 pub async fn realm_get_handler(
     State(state): State<AppState>,
     Path(realm_id): Path<String>,
@@ -338,12 +578,32 @@ pub async fn realm_get_handler(
 }
 
 /// POST /api/v1/realms – Create realm (StateEvent::RealmLifecycle)
+#[utoipa::path(
+    post,
+    path = "/api/v1/realms",
+    tag = "realms",
+    summary = "Create a realm",
+    request_body = RealmCreateBody,
+    responses(
+        (status = 201, description = "`{realm_id, created_by, sequence, event_id}`", body = serde_json::Value),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn realm_create_handler(
     State(state): State<AppState>,
+    auth: AuthContext,
     Json(body): Json<RealmCreateBody>,
 ) -> impl IntoResponse {
     use crate::domain::RealmAction;
 
+    if let Err(e) = auth.require(&Capability::Write {
+        resource: "realms".into(),
+    }) {
+        return e.into_response();
+    }
+
     let event = StateEvent::RealmLifecycle {
         realm_id: body.realm_id.clone(),
         action: RealmAction::Created,
@@ -354,6 +614,7 @@ pub async fn realm_create_handler(
         StatusCode::CREATED,
         Json(serde_json::json!({
             "realm_id": body.realm_id,
+            "created_by": auth.did,
             "sequence": wrapped.sequence,
             "event_id": wrapped.id
         })),
@@ -362,13 +623,33 @@ pub async fn realm_create_handler(
 }
 
 /// POST /api/v1/realms/:realm_id/members – Membership change (StateEvent::MembershipChange)
+#[utoipa::path(
+    post,
+    path = "/api/v1/realms/{realm_id}/members",
+    tag = "realms",
+    summary = "Change realm membership",
+    params(("realm_id" = String, Path, description = "Realm-ID")),
+    request_body = RealmMembersBody,
+    responses(
+        (status = 200, description = "`{realm_id, identity_id, sequence, event_id}`", body = serde_json::Value),
+        (status = 400, description = "Unbekannte Aktion oder fehlende identity_id", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn realm_members_handler(
     State(state): State<AppState>,
+    auth: AuthContext,
     Path(realm_id): Path<String>,
     Json(body): Json<RealmMembersBody>,
 ) -> impl IntoResponse {
     use crate::domain::MembershipAction;
 
+    if let Err(e) = auth.require_realm(&realm_id) {
+        return e.into_response();
+    }
+
     let action = match body.action.to_lowercase().as_str() {
         "join" => MembershipAction::Joined,
         "leave" => MembershipAction::Left,
@@ -383,19 +664,52 @@ pub async fn realm_members_handler(
         }
     };
 
+    // join/leave nur für sich selbst, invite/ban nur durch Realm-Admins
+    let identity_id = match action {
+        MembershipAction::Joined | MembershipAction::Left => {
+            if let Err(e) = auth.require_self(body.identity_id.as_deref()) {
+                return e.into_response();
+            }
+            auth.did.clone()
+        }
+        _ => {
+            let is_admin = state
+                .unified_state
+                .peer
+                .realm
+                .realms
+                .read()
+                .is_ok_and(|realms| realms.get(&realm_id).is_some_and(|r| r.is_admin_by_id(&auth.id)));
+            if !is_admin {
+                return ApiError::Forbidden.into_response();
+            }
+            match body.identity_id {
+                Some(identity_id) => identity_id,
+                None => {
+                    return (
+                        StatusCode::BAD_REQUEST,
+                        Json(serde_json::json!({ "error": "identity_id_required", "action": body.action })),
+                    )
+                        .into_response();
+                }
+            }
+        }
+    };
+    let identity_universal_id = parse_did_uri(&identity_id).ok();
+
     let event = StateEvent::MembershipChange {
         realm_id: realm_id.clone(),
-        identity_id: body.identity_id.clone(),
-        identity_universal_id: None,
+        identity_id: identity_id.clone(),
+        identity_universal_id,
         action,
         new_role: None,
-        initiated_by: None,
-        initiated_by_id: None,
+        initiated_by: Some(auth.did.clone()),
+        initiated_by_id: Some(auth.id),
     };
     let wrapped = state.unified_state.log_and_apply(event, vec![]);
     Json(serde_json::json!({
         "realm_id": realm_id,
-        "identity_id": body.identity_id,
+        "identity_id": identity_id,
         "sequence": wrapped.sequence,
         "event_id": wrapped.id
     }))
@@ -403,6 +717,16 @@ pub async fn realm_members_handler(
 }
 
 /// GET /api/v1/realms/:realm_id/ecl – RealmECLSnapshot for realm
+#[utoipa::path(
+    get,
+    path = "/api/v1/realms/{realm_id}/ecl",
+    tag = "realms",
+    summary = "ECL statistics of a realm",
+    params(("realm_id" = String, Path, description = "Realm-ID")),
+    responses(
+        (status = 200, description = "RealmECLSnapshot", body = serde_json::Value)
+    )
+)]
 pub async fn realm_ecl_handler(
     State(state): State<AppState>,
     Path(realm_id): Path<String>,
@@ -424,117 +748,526 @@ pub async fn realm_ecl_handler(
 }
 
 // ============================================================================
-// ECL Entrypoints (stubs – return 501 until EclEntrypoints wired)
+// ECL Entrypoints (EclService: Handler pro Realm, Mana-Preflight)
 // ============================================================================
 
-/// POST /api/v1/ecl/run – Run policy (stub)
-pub async fn ecl_run_handler() -> impl IntoResponse {
-    (
-        StatusCode::NOT_IMPLEMENTED,
-        Json(EclNotConfiguredResponse {
-            error: "ecl_not_configured",
-            message: "ECL run_policy not wired; add EclEntrypoints to AppState to enable",
-        }),
-    )
+/// POST /api/v1/ecl/run – Run ECL source or bytecode ad hoc in a realm
+#[utoipa::path(
+    post,
+    path = "/api/v1/ecl/run",
+    tag = "ecl",
+    summary = "Run ECL source or bytecode in a realm",
+    request_body = EclRunBody,
+    responses(
+        (status = 200, description = "Ausführungsergebnis", body = serde_json::Value),
+        (status = 404, description = "Realm unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 422, description = "Kompilier- oder Laufzeitfehler", body = crate::api::openapi::ErrorBody),
+        (status = 429, description = "Nicht genug Mana", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn ecl_run_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Json(body): Json<EclRunBody>,
+) -> impl IntoResponse {
+    if let Err(e) = ecl_authorize(&auth, &body.realm_id) {
+        return e.into_response();
+    }
+    match state
+        .ecl
+        .run_source(&body.realm_id, &auth.id, body.program, body.gas_limit)
+    {
+        Ok(outcome) => Json(outcome).into_response(),
+        Err(e) => ecl_error_response(e),
+    }
 }
 
-/// POST /api/v1/ecl/api/:route_id – Run API handler (stub)
-pub async fn ecl_api_handler(Path(_route_id): Path<String>) -> impl IntoResponse {
-    (
-        StatusCode::NOT_IMPLEMENTED,
-        Json(EclNotConfiguredResponse {
-            error: "ecl_not_configured",
-            message: "ECL run_api not wired; add EclEntrypoints to AppState to enable",
-        }),
+/// POST /api/v1/ecl/api/{route_id} – Run API handler
+#[utoipa::path(
+    post,
+    path = "/api/v1/ecl/api/{route_id}",
+    tag = "ecl",
+    summary = "Run a registered API handler",
+    params(("route_id" = String, Path, description = "Route-ID")),
+    request_body = EclInvokeBody,
+    responses(
+        (status = 200, description = "Ausführungsergebnis", body = serde_json::Value),
+        (status = 404, description = "Realm oder Handler unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 422, description = "Laufzeitfehler", body = crate::api::openapi::ErrorBody),
+        (status = 429, description = "Nicht genug Mana", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn ecl_api_handler(
+    State(state): State<AppState>,
+    Path(route_id): Path<String>,
+    auth: AuthContext,
+    Json(body): Json<EclInvokeBody>,
+) -> impl IntoResponse {
+    ecl_invoke(&state, &auth, EntrypointKind::Api, route_id, body)
+}
+
+/// POST /api/v1/ecl/ui/{component_id} – Run UI handler (visibility / trust gate)
+#[utoipa::path(
+    post,
+    path = "/api/v1/ecl/ui/{component_id}",
+    tag = "ecl",
+    summary = "Run a registered UI handler",
+    params(("component_id" = String, Path, description = "Component-ID")),
+    request_body = EclInvokeBody,
+    responses(
+        (status = 200, description = "Ausführungsergebnis", body = serde_json::Value),
+        (status = 404, description = "Realm oder Handler unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 422, description = "Laufzeitfehler", body = crate::api::openapi::ErrorBody),
+        (status = 429, description = "Nicht genug Mana", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn ecl_ui_handler(
+    State(state): State<AppState>,
+    Path(component_id): Path<String>,
+    auth: AuthContext,
+    Json(body): Json<EclInvokeBody>,
+) -> impl IntoResponse {
+    ecl_invoke(&state, &auth, EntrypointKind::Ui, component_id, body)
+}
+
+/// POST /api/v1/ecl/controller/{permission_or_resource} – Run controller (AuthZ)
+#[utoipa::path(
+    post,
+    path = "/api/v1/ecl/controller/{key}",
+    tag = "ecl",
+    summary = "Run a registered controller",
+    params(("key" = String, Path, description = "Permission oder Resource")),
+    request_body = EclInvokeBody,
+    responses(
+        (status = 200, description = "Ausführungsergebnis", body = serde_json::Value),
+        (status = 404, description = "Realm oder Handler unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 422, description = "Laufzeitfehler", body = crate::api::openapi::ErrorBody),
+        (status = 429, description = "Nicht genug Mana", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn ecl_controller_handler(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+    auth: AuthContext,
+    Json(body): Json<EclInvokeBody>,
+) -> impl IntoResponse {
+    ecl_invoke(&state, &auth, EntrypointKind::Controller, key, body)
+}
+
+/// POST /api/v1/ecl/handlers – Upload handler (realm admins only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/ecl/handlers",
+    tag = "ecl",
+    summary = "Upload a handler (realm admins)",
+    request_body = EclUploadBody,
+    responses(
+        (status = 201, description = "Gespeicherter Handler", body = serde_json::Value),
+        (status = 400, description = "Ungültiger Handler", body = crate::api::openapi::ErrorBody),
+        (status = 422, description = "Kompilierfehler", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn ecl_handler_upload_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Json(body): Json<EclUploadBody>,
+) -> impl IntoResponse {
+    if let Err(e) = auth
+        .require(&Capability::Write {
+            resource: "ecl".into(),
+        })
+        .and_then(|_| auth.require_realm(&body.realm_id))
+    {
+        return e.into_response();
+    }
+    let upload = EclUpload {
+        realm: body.realm_id,
+        kind: body.kind,
+        key: body.key,
+        uploader: auth.id,
+        source: body.program,
+        optimize: body.optimize,
+    };
+    match state.ecl.upload(upload) {
+        Ok(record) => (StatusCode::CREATED, Json(serde_json::json!(record))).into_response(),
+        Err(e) => ecl_error_response(e),
+    }
+}
+
+/// GET /api/v1/ecl/handlers?realm= – Registered handlers of a realm
+#[utoipa::path(
+    get,
+    path = "/api/v1/ecl/handlers",
+    tag = "ecl",
+    summary = "Registered handlers of a realm",
+    params(EclHandlersQuery),
+    responses(
+        (status = 200, description = "`{realm, handlers}`", body = serde_json::Value),
+        (status = 404, description = "Realm unbekannt", body = crate::api::openapi::ErrorBody)
     )
+)]
+pub async fn ecl_handlers_list_handler(
+    State(state): State<AppState>,
+    Query(query): Query<EclHandlersQuery>,
+) -> impl IntoResponse {
+    match state.ecl.list(&query.realm) {
+        Ok(records) => Json(serde_json::json!({
+            "realm": query.realm,
+            "handlers": records,
+        }))
+        .into_response(),
+        Err(e) => ecl_error_response(e),
+    }
 }
 
-/// POST /api/v1/ecl/ui/:component_id – Run UI handler (stub)
-pub async fn ecl_ui_handler(Path(_component_id): Path<String>) -> impl IntoResponse {
-    (
-        StatusCode::NOT_IMPLEMENTED,
-        Json(EclNotConfiguredResponse {
-            error: "ecl_not_configured",
-            message: "ECL run_ui not wired; add EclEntrypoints to AppState to enable",
+/// DELETE /api/v1/ecl/handlers/{realm_id}/{kind}/{key} – Remove handler (realm admins only)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/ecl/handlers/{realm_id}/{kind}/{key}",
+    tag = "ecl",
+    summary = "Remove a handler (realm admins)",
+    params(("realm_id" = String, Path, description = "Realm-ID"), ("kind" = String, Path, description = "api, ui, datalogic, governance oder controller"), ("key" = String, Path, description = "Handler-Schlüssel")),
+    responses(
+        (status = 204, description = "Entfernt"),
+        (status = 400, description = "Unbekannte Art", body = crate::api::openapi::ErrorBody),
+        (status = 404, description = "Handler unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn ecl_handler_delete_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Path((realm_id, kind, key)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    let Some(kind) = EntrypointKind::parse(&kind) else {
+        return ecl_bad_request("invalid_kind", format!("unknown handler kind: {}", kind));
+    };
+    if let Err(e) = auth
+        .require(&Capability::Write {
+            resource: "ecl".into(),
+        })
+        .and_then(|_| auth.require_realm(&realm_id))
+    {
+        return e.into_response();
+    }
+    match state.ecl.remove(&realm_id, kind, &key, &auth.id) {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => ecl_error_response(EclServiceError::HandlerNotFound {
+            realm: realm_id,
+            kind: kind.as_str(),
+            key,
         }),
-    )
+        Err(e) => ecl_error_response(e),
+    }
 }
 
-/// POST /api/v1/ecl/controller/:permission_or_resource – Run controller (stub)
-pub async fn ecl_controller_handler(Path(_key): Path<String>) -> impl IntoResponse {
+fn ecl_invoke(
+    state: &AppState,
+    auth: &AuthContext,
+    kind: EntrypointKind,
+    key: String,
+    body: EclInvokeBody,
+) -> axum::response::Response {
+    if let Err(e) = ecl_authorize(auth, &body.realm_id) {
+        return e.into_response();
+    }
+    let invocation = EclInvocation {
+        realm: body.realm_id,
+        kind,
+        key,
+        caller: auth.id,
+        gas_limit: body.gas_limit,
+    };
+    match state.ecl.run(invocation) {
+        Ok(outcome) => Json(outcome).into_response(),
+        Err(e) => ecl_error_response(e),
+    }
+}
+
+/// Ausführen erfordert `execute:ecl` und eine zum Realm passende Session
+fn ecl_authorize(auth: &AuthContext, realm_id: &str) -> Result<(), ApiError> {
+    auth.require(&Capability::Execute {
+        action: "ecl".into(),
+    })?;
+    auth.require_realm(realm_id)
+}
+
+fn ecl_bad_request(code: &str, message: String) -> axum::response::Response {
     (
-        StatusCode::NOT_IMPLEMENTED,
-        Json(EclNotConfiguredResponse {
-            error: "ecl_not_configured",
-            message: "ECL run_controller not wired; add EclEntrypoints to AppState to enable",
-        }),
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({ "error": code, "message": message })),
     )
+        .into_response()
+}
+
+fn ecl_error_response(e: EclServiceError) -> axum::response::Response {
+    let (status, code) = match &e {
+        EclServiceError::UnknownRealm(_) => (StatusCode::NOT_FOUND, "unknown_realm"),
+        EclServiceError::HandlerNotFound { .. } => (StatusCode::NOT_FOUND, "handler_not_found"),
+        EclServiceError::NotAdmin { .. } => (StatusCode::FORBIDDEN, "not_realm_admin"),
+        EclServiceError::InvalidHandler(_) => (StatusCode::BAD_REQUEST, "invalid_handler"),
+        EclServiceError::Compile(_) => (StatusCode::UNPROCESSABLE_ENTITY, "compile_error"),
+        EclServiceError::Execution(_) => (StatusCode::UNPROCESSABLE_ENTITY, "execution_error"),
+        EclServiceError::Storage(_) => (StatusCode::INTERNAL_SERVER_ERROR, "storage_error"),
+        EclServiceError::InsufficientMana(retry_after) => {
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(axum::http::header::RETRY_AFTER, retry_after.as_secs().max(1).to_string())],
+                Json(serde_json::json!({ "error": "insufficient_mana", "message": e.to_string() })),
+            )
+                .into_response();
+        }
+    };
+    (status, Json(serde_json::json!({ "error": code, "message": e.to_string() }))).into_response()
 }
 
 // ============================================================================
 // Phase 3: Governance & Controller
 // ============================================================================
 
-/// POST /api/v1/governance/proposals – Create proposal (StateEvent::ProposalCreated)
+/// POST /api/v1/governance/proposals – Create proposal (EventPayload::Proposal)
+#[utoipa::path(
+    post,
+    path = "/api/v1/governance/proposals",
+    tag = "governance",
+    summary = "Create a proposal",
+    request_body = GovernanceProposalBody,
+    responses(
+        (status = 201, description = "Proposal", body = serde_json::Value),
+        (status = 400, description = "Ungültiges Proposal", body = crate::api::openapi::ErrorBody),
+        (status = 404, description = "Realm unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn governance_proposals_create_handler(
     State(state): State<AppState>,
+    auth: AuthContext,
     Json(body): Json<GovernanceProposalBody>,
 ) -> impl IntoResponse {
-    let proposal_id = body.proposal_id.clone();
-    let deadline_ms = body.deadline_ms.unwrap_or_else(|| {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| d.as_millis())
-            .unwrap_or(0)
-            + 86_400_000 // +24h default
-    });
-    let event = StateEvent::ProposalCreated {
-        proposal_id: body.proposal_id,
-        realm_id: body.realm_id,
-        proposer_id: body.proposer_id,
-        proposal_type: body.proposal_type,
-        deadline_ms,
+    if let Err(e) = governance_authorize(&auth, body.proposer_id.as_deref())
+        .and_then(|_| auth.require_realm(&body.realm_id))
+    {
+        return e.into_response();
+    }
+    let proposal = NewProposal {
+        realm: body.realm_id,
+        proposer: auth.id,
+        title: body.title,
+        description: body.description,
+        action: body.action,
+        voting_period_secs: body.voting_period_secs,
     };
-    let wrapped = state.unified_state.log_and_apply(event, vec![]);
-    (
-        StatusCode::CREATED,
-        Json(serde_json::json!({
-            "proposal_id": proposal_id,
-            "sequence": wrapped.sequence,
-            "event_id": wrapped.id
-        })),
-    )
-        .into_response()
+    match state.governance.propose(proposal) {
+        Ok(record) => (StatusCode::CREATED, Json(serde_json::json!(record))).into_response(),
+        Err(e) => governance_error_response(e),
+    }
 }
 
-/// POST /api/v1/governance/proposals/:id/vote – Cast vote (StateEvent::VoteCast)
+/// POST /api/v1/governance/proposals/:id/vote – Cast quadratic vote (EventPayload::Vote)
+#[utoipa::path(
+    post,
+    path = "/api/v1/governance/proposals/{id}/vote",
+    tag = "governance",
+    summary = "Cast a quadratic vote",
+    params(("id" = String, Path, description = "Proposal-ID")),
+    request_body = GovernanceVoteBody,
+    responses(
+        (status = 200, description = "`{proposal, remaining_credits}`", body = serde_json::Value),
+        (status = 404, description = "Proposal unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 409, description = "Bereits abgestimmt, geschlossen oder zu wenig Credits", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn governance_proposals_vote_handler(
     State(state): State<AppState>,
+    auth: AuthContext,
     Path(proposal_id): Path<String>,
     Json(body): Json<GovernanceVoteBody>,
 ) -> impl IntoResponse {
-    let weight = body.weight.unwrap_or(1.0);
-    let event = StateEvent::VoteCast {
-        proposal_id,
-        voter_id: body.voter_id,
-        vote: body.vote,
-        weight,
+    if let Err(e) = governance_authorize(&auth, body.voter_id.as_deref()) {
+        return e.into_response();
+    }
+    if let Some(scope) = &auth.realm {
+        match state.governance.get(&proposal_id) {
+            Ok(Some(record)) if &record.realm != scope => return ApiError::Forbidden.into_response(),
+            Err(e) => return governance_error_response(e),
+            _ => {}
+        }
+    }
+    let voter = auth.id;
+    let direction = match (body.direction, body.vote) {
+        (Some(direction), _) => direction,
+        (None, Some(true)) => VoteDirection::For,
+        (None, Some(false)) => VoteDirection::Against,
+        (None, None) => {
+            return governance_bad_request("direction_required", "direction or vote is required".into())
+        }
     };
-    let wrapped = state.unified_state.log_and_apply(event, vec![]);
-    Json(serde_json::json!({
-        "sequence": wrapped.sequence,
-        "event_id": wrapped.id
-    }))
-    .into_response()
+    let votes = body.votes.unwrap_or(1);
+    match state.governance.vote(&proposal_id, &voter, direction, votes) {
+        Ok(record) => {
+            let credits = state.governance.credits(&record.realm, &voter).unwrap_or(0);
+            Json(serde_json::json!({ "proposal": record, "remaining_credits": credits })).into_response()
+        }
+        Err(e) => governance_error_response(e),
+    }
 }
 
-/// GET /api/v1/governance/proposals – List proposals (GovernanceSnapshot)
-pub async fn governance_proposals_list_handler(State(state): State<AppState>) -> Json<GovernanceSnapshot> {
-    let snapshot = state.unified_state.snapshot();
-    Json(snapshot.governance)
+/// GET /api/v1/governance/proposals – List proposals (optional `?realm=`) plus GovernanceSnapshot
+#[utoipa::path(
+    get,
+    path = "/api/v1/governance/proposals",
+    tag = "governance",
+    summary = "Proposals and governance metrics",
+    params(GovernanceListQuery),
+    responses(
+        (status = 200, description = "`{proposals, metrics}`", body = serde_json::Value)
+    )
+)]
+pub async fn governance_proposals_list_handler(
+    State(state): State<AppState>,
+    Query(query): Query<GovernanceListQuery>,
+) -> impl IntoResponse {
+    match state.governance.list(query.realm.as_deref()) {
+        Ok(proposals) => {
+            let metrics: GovernanceSnapshot = state.unified_state.snapshot().governance;
+            Json(serde_json::json!({ "proposals": proposals, "metrics": metrics })).into_response()
+        }
+        Err(e) => governance_error_response(e),
+    }
+}
+
+/// GET /api/v1/governance/proposals/:id – Proposal with votes and tally
+#[utoipa::path(
+    get,
+    path = "/api/v1/governance/proposals/{id}",
+    tag = "governance",
+    summary = "Proposal with votes and tally",
+    params(("id" = String, Path, description = "Proposal-ID")),
+    responses(
+        (status = 200, description = "Proposal", body = serde_json::Value),
+        (status = 404, description = "Proposal unbekannt", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn governance_proposal_get_handler(
+    State(state): State<AppState>,
+    Path(proposal_id): Path<String>,
+) -> impl IntoResponse {
+    match state.governance.get(&proposal_id) {
+        Ok(Some(record)) => Json(serde_json::json!(record)).into_response(),
+        Ok(None) => governance_error_response(GovernanceServiceError::ProposalNotFound(proposal_id)),
+        Err(e) => governance_error_response(e),
+    }
+}
+
+/// POST /api/v1/governance/proposals/:id/finalize – Tally and execute after the deadline
+#[utoipa::path(
+    post,
+    path = "/api/v1/governance/proposals/{id}/finalize",
+    tag = "governance",
+    summary = "Tally and execute a proposal",
+    params(("id" = String, Path, description = "Proposal-ID")),
+    responses(
+        (status = 200, description = "Proposal", body = serde_json::Value),
+        (status = 404, description = "Proposal unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 409, description = "Abstimmung läuft noch", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn governance_proposal_finalize_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Path(proposal_id): Path<String>,
+) -> impl IntoResponse {
+    if let Err(e) = governance_authorize(&auth, None) {
+        return e.into_response();
+    }
+    if let Some(scope) = &auth.realm {
+        match state.governance.get(&proposal_id) {
+            Ok(Some(record)) if &record.realm != scope => return ApiError::Forbidden.into_response(),
+            Err(e) => return governance_error_response(e),
+            _ => {}
+        }
+    }
+    match state.governance.finalize(&proposal_id) {
+        Ok(record) => Json(serde_json::json!(record)).into_response(),
+        Err(e) => governance_error_response(e),
+    }
+}
+
+/// Antrag/Stimme erfordert `write:governance`; Body-DIDs müssen dem Aufrufer entsprechen
+fn governance_authorize(auth: &AuthContext, claimed: Option<&str>) -> Result<(), ApiError> {
+    auth.require(&Capability::Write {
+        resource: "governance".into(),
+    })?;
+    auth.require_self(claimed)
+}
+
+fn governance_bad_request(code: &str, message: String) -> axum::response::Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({ "error": code, "message": message })),
+    )
+        .into_response()
+}
+
+fn governance_error_response(e: GovernanceServiceError) -> axum::response::Response {
+    let (status, code) = match &e {
+        GovernanceServiceError::ProposalNotFound(_)
+        | GovernanceServiceError::Voting(GovernanceError::ProposalNotFound(_)) => {
+            (StatusCode::NOT_FOUND, "proposal_not_found")
+        }
+        GovernanceServiceError::UnknownRealm(_) => (StatusCode::NOT_FOUND, "unknown_realm"),
+        GovernanceServiceError::NotAMember { .. } => (StatusCode::FORBIDDEN, "not_a_member"),
+        GovernanceServiceError::InvalidProposal(_) => (StatusCode::BAD_REQUEST, "invalid_proposal"),
+        GovernanceServiceError::Voting(GovernanceError::InvalidVoteCount(_)) => {
+            (StatusCode::BAD_REQUEST, "invalid_vote_count")
+        }
+        GovernanceServiceError::Voting(GovernanceError::InsufficientCredits { .. }) => {
+            (StatusCode::CONFLICT, "insufficient_credits")
+        }
+        GovernanceServiceError::Voting(GovernanceError::AlreadyVoted) => (StatusCode::CONFLICT, "already_voted"),
+        GovernanceServiceError::Voting(GovernanceError::ProposalClosed) => (StatusCode::CONFLICT, "proposal_closed"),
+        GovernanceServiceError::VotingOpen(_) => (StatusCode::CONFLICT, "voting_open"),
+        GovernanceServiceError::Storage(_) => (StatusCode::INTERNAL_SERVER_ERROR, "storage_error"),
+    };
+    (status, Json(serde_json::json!({ "error": code, "message": e.to_string() }))).into_response()
 }
 
 /// POST /api/v1/controller/check – AuthZ check (permission, resource, caller_did, realm_id)
+#[utoipa::path(
+    post,
+    path = "/api/v1/controller/check",
+    tag = "controller",
+    summary = "Authorization check",
+    request_body = ControllerCheckBody,
+    responses(
+        (status = 200, description = "Ergebnis", body = ControllerCheckResponse)
+    )
+)]
 pub async fn controller_check_handler(
     State(state): State<AppState>,
     Json(_body): Json<ControllerCheckBody>,
@@ -551,6 +1284,15 @@ pub async fn controller_check_handler(
 }
 
 /// GET /api/v1/controller/permissions – Permissions for Realm/Caller (ControllerSnapshot)
+#[utoipa::path(
+    get,
+    path = "/api/v1/controller/permissions",
+    tag = "controller",
+    summary = "Controller statistics",
+    responses(
+        (status = 200, description = "ControllerSnapshot", body = serde_json::Value)
+    )
+)]
 pub async fn controller_permissions_handler(State(state): State<AppState>) -> Json<ControllerSnapshot> {
     let snapshot = state.unified_state.snapshot();
     Json(snapshot.controller)
@@ -560,19 +1302,248 @@ pub async fn controller_permissions_handler(State(state): State<AppState>) -> Js
 // Phase 3: Intent & Saga
 // ============================================================================
 
-/// POST /api/v1/intent/parse – Parse intent (stub: returns minimal parsed intent)
-pub async fn intent_parse_handler(Json(_body): Json<IntentParseBody>) -> impl IntoResponse {
-    Json(serde_json::json!({
-        "parsed": true,
-        "intent_id": "stub",
-        "goal_type": "unknown",
-        "estimated_steps": 0,
-        "message": "IntentParser not wired; stub response"
-    }))
-    .into_response()
+/// POST /api/v1/intent/parse – Intent aus Text (Intent-Grammatik) oder strukturiertem Goal parsen
+#[utoipa::path(
+    post,
+    path = "/api/v1/intent/parse",
+    tag = "intent",
+    summary = "Parse an intent from text or a structured goal",
+    request_body = IntentParseBody,
+    responses(
+        (status = 200, description = "`{parsed, intent_id, goal_type, intent}`", body = serde_json::Value),
+        (status = 400, description = "Parse-Fehler mit Span und Vorschlag", body = serde_json::Value)
+    )
+)]
+pub async fn intent_parse_handler(
+    State(state): State<AppState>,
+    Json(body): Json<IntentParseBody>,
+) -> impl IntoResponse {
+    match parse_intent_request(&state, body, None) {
+        Ok(intent) => Json(serde_json::json!({
+            "parsed": true,
+            "intent_id": intent.id.to_hex(),
+            "goal_type": intent.goal.type_tag(),
+            "intent": intent,
+        }))
+        .into_response(),
+        Err(rejection) => rejection.into_response(),
+    }
+}
+
+/// POST /api/v1/intent/simulate – Dry-Run: vorhergesagtes Ergebnis, Kosten und benötigter Trust
+///
+/// Akzeptiert dieselben Felder wie `/intent/parse` oder einen fertigen `intent`.
+/// Der Ledger wird nicht verändert.
+#[utoipa::path(
+    post,
+    path = "/api/v1/intent/simulate",
+    tag = "intent",
+    summary = "Dry-run an intent",
+    request_body = IntentRequestBody,
+    responses(
+        (status = 200, description = "`{simulated, intent_id, summary, report}`", body = serde_json::Value),
+        (status = 400, description = "Parse-Fehler", body = serde_json::Value),
+        (status = 422, description = "Nicht komponierbar", body = serde_json::Value)
+    )
+)]
+pub async fn intent_simulate_handler(
+    State(state): State<AppState>,
+    Json(body): Json<IntentRequestBody>,
+) -> impl IntoResponse {
+    let intent = match body.intent {
+        Some(intent) => intent,
+        None => match parse_intent_request(&state, body.request, None) {
+            Ok(intent) => intent,
+            Err(rejection) => return rejection.into_response(),
+        },
+    };
+
+    match state.saga_executor.simulator().simulate_intent(&intent) {
+        Ok(report) => Json(serde_json::json!({
+            "simulated": true,
+            "intent_id": intent.id.to_hex(),
+            "summary": report.summary(),
+            "report": report,
+        }))
+        .into_response(),
+        Err(e) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!({
+                "simulated": false,
+                "error": "composition_error",
+                "message": e.to_string(),
+            })),
+        )
+            .into_response(),
+    }
+}
+
+/// POST /api/v1/intent/query – Führt einen `Goal::Query`-Intent lesend aus
+///
+/// Akzeptiert dieselben Felder wie `/intent/simulate`; Gas und Mana werden
+/// aus dem Budget des Intents abgerechnet. Quelle des Intents ist stets der
+/// Aufrufer – Privacy-Regeln greifen für die DID der Session.
+#[utoipa::path(
+    post,
+    path = "/api/v1/intent/query",
+    tag = "intent",
+    summary = "Execute a query intent",
+    request_body = IntentRequestBody,
+    responses(
+        (status = 200, description = "`{executed, intent_id, query, result}`", body = serde_json::Value),
+        (status = 400, description = "Parse- oder Query-Fehler", body = serde_json::Value),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Privacy-Verletzung oder fremde Quelle", body = serde_json::Value),
+        (status = 422, description = "Ausführungsfehler", body = serde_json::Value)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn intent_query_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Json(body): Json<IntentRequestBody>,
+) -> impl IntoResponse {
+    let intent = match body.intent {
+        Some(intent) if intent.source != auth.id => return source_mismatch().into_response(),
+        Some(intent) => intent,
+        None => match parse_intent_request(&state, body.request, Some(auth.id)) {
+            Ok(intent) => intent,
+            Err(rejection) => return rejection.into_response(),
+        },
+    };
+
+    let engine = QueryEngine::new(state.storage.clone()).with_unified_state(state.unified_state.clone());
+    match engine.execute_intent(&intent) {
+        Ok((query, output)) => Json(serde_json::json!({
+            "executed": true,
+            "intent_id": intent.id.to_hex(),
+            "query": query,
+            "result": output,
+        }))
+        .into_response(),
+        Err(e) => {
+            let (status, code) = match &e {
+                QueryError::NotAQuery => (StatusCode::BAD_REQUEST, "not_a_query"),
+                QueryError::Syntax { .. } => (StatusCode::BAD_REQUEST, "query_syntax"),
+                QueryError::MissingFilter(_) | QueryError::Invalid(_) => (StatusCode::BAD_REQUEST, "invalid_query"),
+                QueryError::UnknownRealm(_) => (StatusCode::BAD_REQUEST, "unknown_realm"),
+                QueryError::Privacy(_) => (StatusCode::FORBIDDEN, "privacy_violation"),
+                QueryError::Execution(_) => (StatusCode::UNPROCESSABLE_ENTITY, "execution_error"),
+                QueryError::Storage(_) => (StatusCode::INTERNAL_SERVER_ERROR, "storage_error"),
+            };
+            let position = match &e {
+                QueryError::Syntax { position, .. } => Some(*position),
+                _ => None,
+            };
+            (
+                status,
+                Json(serde_json::json!({
+                    "executed": false,
+                    "error": code,
+                    "message": e.to_string(),
+                    "position": position,
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Intent-Quelle weicht vom Aufrufer ab
+fn source_mismatch() -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::FORBIDDEN,
+        Json(serde_json::json!({
+            "parsed": false,
+            "error": "source_did_mismatch",
+            "message": "intent source must be the caller's DID"
+        })),
+    )
+}
+
+/// Parst einen Intent-Request und erfasst Parser-Metriken
+///
+/// Mit `caller` ist die Quelle immer der Aufrufer; eine abweichende
+/// `source_did` wird mit 403 abgelehnt. Ohne `caller` gilt `source_did`,
+/// sonst die Root-DID. Fehler werden als Status + JSON zurückgegeben.
+fn parse_intent_request(
+    state: &AppState,
+    body: IntentParseBody,
+    caller: Option<UniversalId>,
+) -> Result<Intent, (StatusCode, Json<serde_json::Value>)> {
+    let started = Instant::now();
+    let metrics = &state.unified_state.peer.intent;
+
+    let source = match body.source_did.as_deref() {
+        Some(uri) => match parse_did_uri(uri) {
+            Ok(id) if caller.is_some_and(|caller| caller != id) => return Err(source_mismatch()),
+            Ok(id) => id,
+            Err(message) => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({ "parsed": false, "error": "invalid_source_did", "message": message })),
+                ))
+            }
+        },
+        None => match caller.or_else(|| state.unified_state.identity.root_did_id()) {
+            Some(id) => id,
+            None => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({ "parsed": false, "error": "source_did_required" })),
+                ))
+            }
+        },
+    };
+
+    let parser = IntentParser::default().with_alias_resolver(Arc::new(state.storage.identities.clone()));
+    let result = match (body.text, body.goal) {
+        (Some(text), _) => parser.parse_natural_for(source, &text),
+        (None, Some(goal)) => match serde_json::from_value::<Goal>(goal) {
+            Ok(goal) => parser
+                .parse_structured_for(source, goal, body.constraints)
+                .and_then(|intent| parser.validate(&intent).map(|_| intent)),
+            Err(e) => Err(ParseError::InvalidFormat(e.to_string())),
+        },
+        (None, None) => Err(ParseError::MissingField("text or goal".to_string())),
+    };
+
+    let elapsed_us = started.elapsed().as_micros() as u64;
+    match result {
+        Ok(intent) => {
+            metrics.parsed(true, intent.goal.type_tag(), elapsed_us);
+            Ok(intent)
+        }
+        Err(e) => {
+            metrics.parsed(false, "invalid", elapsed_us);
+            if matches!(e, ParseError::InvalidFormat(_) | ParseError::MissingField(_) | ParseError::InvalidConstraint(_)) {
+                metrics.validation_errors.fetch_add(1, Ordering::Relaxed);
+            }
+            Err((
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "parsed": false,
+                    "error": "parse_error",
+                    "message": e.to_string(),
+                    "span": e.span(),
+                    "suggestion": e.suggestion(),
+                })),
+            ))
+        }
+    }
 }
 
 /// POST /api/v1/saga/compose – Compose saga (stub: returns minimal composed saga)
+#[utoipa::path(
+    post,
+    path = "/api/v1/saga/compose",
+    tag = "saga",
+    summary = "Compose a saga (stub)",
+    request_body = SagaComposeBody,
+    responses(
+        (status = 200, description = "Komponierte Saga", body = serde_json::Value)
+    )
+)]
 pub async fn saga_compose_handler(Json(_body): Json<SagaComposeBody>) -> impl IntoResponse {
     Json(serde_json::json!({
         "composed": true,
@@ -585,35 +1556,16 @@ pub async fn saga_compose_handler(Json(_body): Json<SagaComposeBody>) -> impl In
     .into_response()
 }
 
-/// POST /api/v1/saga/execute – Execute saga step (StateEvent::SagaProgress)
-pub async fn saga_execute_handler(
-    State(state): State<AppState>,
-    Json(body): Json<SagaExecuteBody>,
-) -> impl IntoResponse {
-    let saga_id = body.saga_id.clone();
-    let step = body.step.unwrap_or(0);
-    let total_steps = body.total_steps.unwrap_or(1);
-    let cross_realm = body.cross_realm.unwrap_or(false);
-    let compensation_triggered = body.compensation_triggered.unwrap_or(false);
-    let realms = body.realms.unwrap_or_default();
-    let event = StateEvent::SagaProgress {
-        saga_id: body.saga_id,
-        step,
-        total_steps,
-        cross_realm,
-        compensation_triggered,
-        realms,
-    };
-    let wrapped = state.unified_state.log_and_apply(event, vec![]);
-    Json(serde_json::json!({
-        "saga_id": saga_id,
-        "sequence": wrapped.sequence,
-        "event_id": wrapped.id
-    }))
-    .into_response()
-}
-
 /// GET /api/v1/saga/stats – SagaComposerSnapshot
+#[utoipa::path(
+    get,
+    path = "/api/v1/saga/stats",
+    tag = "saga",
+    summary = "Saga composer statistics",
+    responses(
+        (status = 200, description = "SagaComposerSnapshot", body = serde_json::Value)
+    )
+)]
 pub async fn saga_stats_handler(State(state): State<AppState>) -> Json<SagaComposerSnapshot> {
     let snapshot = state.unified_state.snapshot();
     Json(snapshot.peer.saga)
@@ -5,7 +5,8 @@
 //! and simple HTTP clients that don't support Connect-RPC.
 
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use std::time::Instant;
 
 use crate::config::version::VERSION;
@@ -15,13 +16,13 @@ use crate::server::AppState;
 // Response Types
 // ============================================================================
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct HealthResponse {
-    pub status: &'static str,
-    pub version: &'static str,
+    pub status: String,
+    pub version: String,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ServiceStatusJson {
     pub healthy: bool,
     pub message: String,
@@ -29,33 +30,33 @@ pub struct ServiceStatusJson {
     pub latency_ms: Option<i64>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ReadyResponse {
-    pub status: &'static str,
+    pub status: String,
     pub services: ReadyServices,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ReadyServices {
     pub storage: ServiceStatusJson,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct InfoResponse {
-    pub version: &'static str,
+    pub version: String,
     pub environment: String,
-    pub auth_method: &'static str,
+    pub auth_method: String,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct StatusResponse {
     pub services: Vec<ServiceInfo>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ServiceInfo {
-    pub name: &'static str,
-    pub status: &'static str,
+    pub name: String,
+    pub status: String,
 }
 
 // ============================================================================
@@ -63,14 +64,33 @@ pub struct ServiceInfo {
 // ============================================================================
 
 /// GET /api/v1/health - Liveness probe
+#[utoipa::path(
+    get,
+    path = "/api/v1/health",
+    tag = "system",
+    summary = "Liveness probe",
+    responses(
+        (status = 200, description = "Prozess lebt", body = HealthResponse)
+    )
+)]
 pub async fn health_handler() -> Json<HealthResponse> {
     Json(HealthResponse {
-        status: "healthy",
-        version: VERSION,
+        status: "healthy".to_string(),
+        version: VERSION.to_string(),
     })
 }
 
 /// GET /api/v1/ready - Readiness probe
+#[utoipa::path(
+    get,
+    path = "/api/v1/ready",
+    tag = "system",
+    summary = "Readiness probe",
+    responses(
+        (status = 200, description = "Bereit", body = ReadyResponse),
+        (status = 503, description = "Storage nicht erreichbar", body = ReadyResponse)
+    )
+)]
 pub async fn ready_handler(State(state): State<AppState>) -> impl IntoResponse {
     // Storage check (dezentral Fjall)
     let storage_start = Instant::now();
@@ -82,7 +102,8 @@ pub async fn ready_handler(State(state): State<AppState>) -> impl IntoResponse {
             "ready"
         } else {
             "not_ready"
-        },
+        }
+        .to_string(),
         services: ReadyServices {
             storage: ServiceStatusJson {
                 healthy: storage_healthy,
@@ -104,27 +125,45 @@ pub async fn ready_handler(State(state): State<AppState>) -> impl IntoResponse {
 }
 
 /// GET /api/v1/info - Application info
+#[utoipa::path(
+    get,
+    path = "/api/v1/info",
+    tag = "system",
+    summary = "Application info",
+    responses(
+        (status = 200, description = "Version und Umgebung", body = InfoResponse)
+    )
+)]
 pub async fn info_handler(State(state): State<AppState>) -> Json<InfoResponse> {
     Json(InfoResponse {
-        version: VERSION,
+        version: VERSION.to_string(),
         environment: state.config.application.environment.as_str().to_string(),
-        auth_method: "DID-Auth",
+        auth_method: "DID-Auth".to_string(),
     })
 }
 
 /// GET /api/v1/status - Service status overview
+#[utoipa::path(
+    get,
+    path = "/api/v1/status",
+    tag = "system",
+    summary = "Service status overview",
+    responses(
+        (status = 200, description = "Status je Dienst", body = StatusResponse)
+    )
+)]
 pub async fn status_handler(State(state): State<AppState>) -> Json<StatusResponse> {
     let storage_ok = state.storage.ping().await.is_ok();
 
     Json(StatusResponse {
         services: vec![
             ServiceInfo {
-                name: "storage",
-                status: if storage_ok { "up" } else { "down" },
+                name: "storage".to_string(),
+                status: if storage_ok { "up" } else { "down" }.to_string(),
             },
             ServiceInfo {
-                name: "api",
-                status: "up",
+                name: "api".to_string(),
+                status: "up".to_string(),
             },
         ],
     })
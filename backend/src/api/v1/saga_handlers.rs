@@ -0,0 +1,355 @@
+//! Saga-Ausführung – Execute, Status, Cancel, Rollback, Signale
+//!
+//! Endpoints auf `state.saga_executor`. Zustand wird nach jedem Schritt in
+//! `storage.sagas` persistiert und nach einem Neustart fortgesetzt.
+//!
+//! Ledger-Schritte buchen im Namen des `executor` der Saga. Einreichen ist
+//! daher nur mit `executor` = Aufrufer erlaubt; Ausführen, Abbrechen,
+//! Rollback und Signale nur für den Executor selbst oder Node-Betreiber.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::api::v1::auth::{AuthContext, Role};
+use crate::domain::unified::identity::Capability;
+use crate::domain::{Saga, UniversalId};
+use crate::error::ApiError;
+use crate::local::SagaRecord;
+use crate::peer::saga_executor::SagaExecutorError;
+use crate::server::AppState;
+
+// ============================================================================
+// Request types
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SagaExecuteBody {
+    /// Bereits eingereichte Saga
+    pub saga_id: Option<String>,
+    /// Neue Saga (z.B. aus /saga/compose)
+    #[schema(value_type = Option<Object>)]
+    pub saga: Option<Saga>,
+    /// Auf den Endzustand warten statt im Hintergrund auszuführen
+    #[serde(default)]
+    pub wait: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct SagaAbortBody {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SagaSignalBody {
+    pub condition: String,
+    /// Nur für diese Saga
+    pub saga_id: Option<String>,
+}
+
+fn error_response(e: SagaExecutorError) -> axum::response::Response {
+    let (status, code) = match &e {
+        SagaExecutorError::NotFound(_) => (StatusCode::NOT_FOUND, "saga_not_found"),
+        SagaExecutorError::AlreadyRunning(_) => (StatusCode::CONFLICT, "saga_running"),
+        SagaExecutorError::AlreadyFinished(_) => (StatusCode::CONFLICT, "saga_finished"),
+        SagaExecutorError::InvalidSaga(_) => (StatusCode::BAD_REQUEST, "invalid_saga"),
+        SagaExecutorError::Storage(_) => (StatusCode::INTERNAL_SERVER_ERROR, "storage_error"),
+    };
+    (
+        status,
+        Json(serde_json::json!({ "error": code, "message": e.to_string() })),
+    )
+        .into_response()
+}
+
+fn parse_saga_id(saga_id: &str) -> Result<UniversalId, SagaExecutorError> {
+    UniversalId::from_hex(saga_id)
+        .map_err(|_| SagaExecutorError::InvalidSaga(format!("invalid saga id: {saga_id}")))
+}
+
+/// Einreichen erfordert `write:saga`; die Saga muss im Namen des Aufrufers laufen
+pub(crate) fn authorize_submit(auth: &AuthContext, saga: &Saga) -> Result<(), ApiError> {
+    auth.require(&Capability::Write {
+        resource: "saga".into(),
+    })?;
+    if saga.executor != auth.id {
+        return Err(ApiError::Forbidden);
+    }
+    Ok(())
+}
+
+/// Steuern erfordert `write:saga` und den Executor der Saga oder einen Node-Betreiber
+pub(crate) fn authorize_saga(
+    state: &AppState,
+    auth: &AuthContext,
+    record: &SagaRecord,
+) -> Result<(), ApiError> {
+    auth.require(&Capability::Write {
+        resource: "saga".into(),
+    })?;
+    if record.saga.executor == auth.id || auth.role(state, None) == Role::NodeOperator {
+        Ok(())
+    } else {
+        Err(ApiError::Forbidden)
+    }
+}
+
+/// Fehler beim Laden einer Saga mit Berechtigungsprüfung
+enum LoadError {
+    Saga(SagaExecutorError),
+    Auth(ApiError),
+}
+
+impl IntoResponse for LoadError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            LoadError::Saga(e) => error_response(e),
+            LoadError::Auth(e) => e.into_response(),
+        }
+    }
+}
+
+/// Lädt eine Saga und prüft [`authorize_saga`]
+fn load_authorized(
+    state: &AppState,
+    auth: &AuthContext,
+    saga_id: &str,
+) -> Result<UniversalId, LoadError> {
+    let saga_id = parse_saga_id(saga_id).map_err(LoadError::Saga)?;
+    let record = state
+        .saga_executor
+        .get(&saga_id)
+        .map_err(LoadError::Saga)?
+        .ok_or_else(|| LoadError::Saga(SagaExecutorError::NotFound(saga_id.to_hex())))?;
+    authorize_saga(state, auth, &record).map_err(LoadError::Auth)?;
+    Ok(saga_id)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// POST /api/v1/saga/execute – Saga einreichen und ausführen
+///
+/// Ohne `wait` läuft die Saga im Hintergrund (202 mit aktuellem Zustand).
+/// Eine neue Saga muss den Aufrufer als `executor` tragen.
+#[utoipa::path(
+    post,
+    path = "/api/v1/saga/execute",
+    tag = "saga",
+    summary = "Submit and execute a saga",
+    request_body = SagaExecuteBody,
+    responses(
+        (status = 200, description = "Endzustand (mit `wait`)", body = serde_json::Value),
+        (status = 202, description = "Läuft im Hintergrund", body = serde_json::Value),
+        (status = 400, description = "Ungültige Saga", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fremde Saga oder fehlende Capability", body = crate::error::ErrorResponse),
+        (status = 404, description = "Saga unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 409, description = "Läuft bereits oder beendet", body = crate::api::openapi::ErrorBody)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn saga_execute_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Json(body): Json<SagaExecuteBody>,
+) -> impl IntoResponse {
+    let executor = &state.saga_executor;
+    let saga_id = match (body.saga, body.saga_id) {
+        (Some(saga), _) => {
+            if let Err(e) = authorize_submit(&auth, &saga) {
+                return e.into_response();
+            }
+            // Bereits eingereichte Saga mit gleicher ID: Eigentümer prüfen
+            match executor.submit(saga) {
+                Ok(record) => match authorize_saga(&state, &auth, &record) {
+                    Ok(()) => record.id(),
+                    Err(e) => return e.into_response(),
+                },
+                Err(e) => return error_response(e),
+            }
+        }
+        (None, Some(id)) => match load_authorized(&state, &auth, &id) {
+            Ok(id) => id,
+            Err(e) => return e.into_response(),
+        },
+        (None, None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": "saga_or_saga_id_required" })),
+            )
+                .into_response()
+        }
+    };
+
+    if body.wait {
+        return match executor.execute(&saga_id).await {
+            Ok(record) => Json(record).into_response(),
+            Err(e) => error_response(e),
+        };
+    }
+
+    if let Err(e) = executor.spawn(saga_id) {
+        return error_response(e);
+    }
+    match executor.get(&saga_id) {
+        Ok(Some(record)) => (StatusCode::ACCEPTED, Json(record)).into_response(),
+        Ok(None) => error_response(SagaExecutorError::NotFound(saga_id.to_hex())),
+        Err(e) => error_response(e),
+    }
+}
+
+/// GET /api/v1/saga/list – Alle Sagas (neueste zuerst)
+#[utoipa::path(
+    get,
+    path = "/api/v1/saga/list",
+    tag = "saga",
+    summary = "All sagas, newest first",
+    responses(
+        (status = 200, description = "SagaRecords", body = Vec<serde_json::Value>)
+    )
+)]
+pub async fn saga_list_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.saga_executor.list() {
+        Ok(records) => Json(records).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// GET /api/v1/saga/{saga_id} – Persistierter Saga-Zustand
+#[utoipa::path(
+    get,
+    path = "/api/v1/saga/{saga_id}",
+    tag = "saga",
+    summary = "Persisted saga state",
+    params(("saga_id" = String, Path, description = "Saga-ID (Hex)")),
+    responses(
+        (status = 200, description = "SagaRecord", body = serde_json::Value),
+        (status = 400, description = "Ungültige Saga-ID", body = crate::api::openapi::ErrorBody),
+        (status = 404, description = "Saga unbekannt", body = crate::api::openapi::ErrorBody)
+    )
+)]
+pub async fn saga_get_handler(
+    State(state): State<AppState>,
+    Path(saga_id): Path<String>,
+) -> impl IntoResponse {
+    let saga_id = match parse_saga_id(&saga_id) {
+        Ok(id) => id,
+        Err(e) => return error_response(e),
+    };
+    match state.saga_executor.get(&saga_id) {
+        Ok(Some(record)) => Json(record).into_response(),
+        Ok(None) => error_response(SagaExecutorError::NotFound(saga_id.to_hex())),
+        Err(e) => error_response(e),
+    }
+}
+
+/// POST /api/v1/saga/{saga_id}/cancel – Abbrechen mit Kompensation
+#[utoipa::path(
+    post,
+    path = "/api/v1/saga/{saga_id}/cancel",
+    tag = "saga",
+    summary = "Cancel a saga with compensation",
+    params(("saga_id" = String, Path, description = "Saga-ID (Hex)")),
+    request_body = Option<SagaAbortBody>,
+    responses(
+        (status = 200, description = "SagaRecord", body = serde_json::Value),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fremde Saga oder fehlende Capability", body = crate::error::ErrorResponse),
+        (status = 404, description = "Saga unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 409, description = "Bereits beendet", body = crate::api::openapi::ErrorBody)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn saga_cancel_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Path(saga_id): Path<String>,
+    body: Option<Json<SagaAbortBody>>,
+) -> impl IntoResponse {
+    let saga_id = match load_authorized(&state, &auth, &saga_id) {
+        Ok(id) => id,
+        Err(e) => return e.into_response(),
+    };
+    let reason = body.and_then(|Json(b)| b.reason);
+    match state.saga_executor.cancel(&saga_id, reason).await {
+        Ok(record) => Json(record).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// POST /api/v1/saga/{saga_id}/rollback – Kompensation erzwingen
+#[utoipa::path(
+    post,
+    path = "/api/v1/saga/{saga_id}/rollback",
+    tag = "saga",
+    summary = "Force compensation",
+    params(("saga_id" = String, Path, description = "Saga-ID (Hex)")),
+    request_body = Option<SagaAbortBody>,
+    responses(
+        (status = 200, description = "SagaRecord", body = serde_json::Value),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fremde Saga oder fehlende Capability", body = crate::error::ErrorResponse),
+        (status = 404, description = "Saga unbekannt", body = crate::api::openapi::ErrorBody),
+        (status = 409, description = "Bereits beendet", body = crate::api::openapi::ErrorBody)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn saga_rollback_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Path(saga_id): Path<String>,
+    body: Option<Json<SagaAbortBody>>,
+) -> impl IntoResponse {
+    let saga_id = match load_authorized(&state, &auth, &saga_id) {
+        Ok(id) => id,
+        Err(e) => return e.into_response(),
+    };
+    let reason = body.and_then(|Json(b)| b.reason);
+    match state.saga_executor.rollback(&saga_id, reason).await {
+        Ok(record) => Json(record).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// POST /api/v1/saga/signal – `WaitFor`-Bedingung als erfüllt melden
+///
+/// Mit `saga_id` für den Executor der Saga; globale Bedingungen betreffen
+/// alle wartenden Sagas und sind Node-Betreibern vorbehalten.
+#[utoipa::path(
+    post,
+    path = "/api/v1/saga/signal",
+    tag = "saga",
+    summary = "Signal a WaitFor condition",
+    request_body = SagaSignalBody,
+    responses(
+        (status = 200, description = "`{signaled}`", body = serde_json::Value),
+        (status = 400, description = "Ungültige Saga-ID", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fremde Saga oder globale Bedingung ohne Betreiber-Rolle", body = crate::error::ErrorResponse),
+        (status = 404, description = "Saga unbekannt", body = crate::api::openapi::ErrorBody)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn saga_signal_handler(
+    State(state): State<AppState>,
+    auth: AuthContext,
+    Json(body): Json<SagaSignalBody>,
+) -> impl IntoResponse {
+    let condition = match body.saga_id {
+        Some(saga_id) => match load_authorized(&state, &auth, &saga_id) {
+            Ok(id) => format!("{}:{}", id.to_hex(), body.condition),
+            Err(e) => return e.into_response(),
+        },
+        None if auth.role(&state, None) == Role::NodeOperator => body.condition,
+        None => return ApiError::Forbidden.into_response(),
+    };
+    state.saga_executor.signal(condition.clone());
+    Json(serde_json::json!({ "signaled": condition })).into_response()
+}
@@ -4,12 +4,13 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::{sse::Event, IntoResponse, Sse},
+    http::{header, HeaderMap, StatusCode},
+    response::{sse::Event, IntoResponse, Response, Sse},
     Json,
 };
 use base64::Engine;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use std::collections::HashMap;
 use std::convert::Infallible;
 use tokio_stream::StreamExt;
@@ -19,13 +20,17 @@ use crate::core::{
     CircuitBreakerSnapshot, HealthReport, HealthStatus, InvariantResult, InvariantSeverity,
     StateComponent, StateEvent, SystemMode, UnifiedSnapshot, WrappedStateEvent,
 };
+use super::openmetrics::{self, OpenMetrics};
+use super::pagination::{numeric_key, page_response, PageRequest, SortOrder};
+use super::subscriptions::{EventMatcher, SubscriptionFilter};
 use crate::server::AppState;
 
 // ============================================================================
 // Query / Path types
 // ============================================================================
 
-#[derive(Debug, Default, serde::Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct SnapshotQuery {
     /// Komma-getrennte Komponenten (z.B. "core,eclvm"); leer = alle
     pub components: Option<String>,
@@ -33,39 +38,53 @@ pub struct SnapshotQuery {
     pub realm_id: Option<String>,
 }
 
-#[derive(Debug, Default, serde::Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct InvariantsQuery {
     /// Filter nach Severity: Warning, Error, Critical
     pub severity: Option<String>,
 }
 
-#[derive(Debug, Default, serde::Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct EventsQuery {
-    pub limit: Option<u32>,
+    /// Seitengröße (Default 100, max. 1000)
+    pub limit: Option<usize>,
+    /// `next_cursor` der vorigen Seite
+    pub cursor: Option<String>,
+    /// Sortierung nach Sequenz; Default `desc` (neueste zuerst)
+    pub order: Option<SortOrder>,
+    /// Nur Events mit größerer Sequenz
     pub since_sequence: Option<u64>,
     /// StateComponent-Name (z.B. "Trust", "ECLVM")
     pub component: Option<String>,
+    /// Nur Events mit diesem Realm-Kontext
     pub realm_id: Option<String>,
+    /// Nur Events, die diese DID enthalten
+    pub did: Option<String>,
+    /// Event-Typ (Variantenname, z.B. "TrustUpdate")
+    pub event_type: Option<String>,
 }
 
 // ============================================================================
 // Response types
 // ============================================================================
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct HealthStateResponse {
     pub score: f64,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct HealthStateDetailResponse {
     pub overall_score: f64,
+    #[schema(value_type = String, example = "Healthy")]
     pub status: HealthStatus,
     pub module_scores: HashMap<String, f64>,
     pub invariant_summary: InvariantSummary,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct InvariantSummary {
     pub passed: usize,
     pub failed: usize,
@@ -77,6 +96,16 @@ pub struct InvariantSummary {
 // ============================================================================
 
 /// GET /api/v1/state/snapshot – Full UnifiedSnapshot, optional filter by components/realm_id
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/snapshot",
+    tag = "state",
+    summary = "Full UnifiedSnapshot, optionally filtered by components",
+    params(SnapshotQuery),
+    responses(
+        (status = 200, description = "UnifiedSnapshot", body = serde_json::Value)
+    )
+)]
 pub async fn state_snapshot_handler(
     State(state): State<AppState>,
     Query(q): Query<SnapshotQuery>,
@@ -96,6 +125,17 @@ pub async fn state_snapshot_handler(
 }
 
 /// GET /api/v1/state/:component_name – Single component snapshot (e.g. core, eclvm)
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/{component_name}",
+    tag = "state",
+    summary = "Snapshot of a single component",
+    params(("component_name" = String, Path, description = "Komponente (z. B. core, eclvm)")),
+    responses(
+        (status = 200, description = "Komponenten-Snapshot", body = serde_json::Value),
+        (status = 404, description = "Unbekannte Komponente", body = serde_json::Value)
+    )
+)]
 pub async fn state_component_handler(
     State(state): State<AppState>,
     Path(component_name): Path<String>,
@@ -218,12 +258,30 @@ fn filter_snapshot_by_components(snapshot: &UnifiedSnapshot, components: &[&str]
 // ============================================================================
 
 /// GET /api/v1/health/state – Aggregated health score (state.calculate_health)
+#[utoipa::path(
+    get,
+    path = "/api/v1/health/state",
+    tag = "health",
+    summary = "Aggregated health score",
+    responses(
+        (status = 200, description = "Score", body = HealthStateResponse)
+    )
+)]
 pub async fn health_state_handler(State(state): State<AppState>) -> Json<HealthStateResponse> {
     let score = state.unified_state.snapshot().health_score;
     Json(HealthStateResponse { score })
 }
 
 /// GET /api/v1/health/state/detail – Detailed breakdown per layer
+#[utoipa::path(
+    get,
+    path = "/api/v1/health/state/detail",
+    tag = "health",
+    summary = "Health breakdown per module",
+    responses(
+        (status = 200, description = "Scores und Invarianten", body = HealthStateDetailResponse)
+    )
+)]
 pub async fn health_state_detail_handler(State(state): State<AppState>) -> Json<HealthStateDetailResponse> {
     let report = state.coordinator.aggregate_health();
     let mut by_severity: HashMap<String, usize> = HashMap::new();
@@ -249,11 +307,30 @@ pub async fn health_state_detail_handler(State(state): State<AppState>) -> Json<
 }
 
 /// GET /api/v1/health/aggregate – Full HealthReport
+#[utoipa::path(
+    get,
+    path = "/api/v1/health/aggregate",
+    tag = "health",
+    summary = "Full health report",
+    responses(
+        (status = 200, description = "HealthReport", body = serde_json::Value)
+    )
+)]
 pub async fn health_aggregate_handler(State(state): State<AppState>) -> Json<HealthReport> {
     Json(state.coordinator.aggregate_health())
 }
 
 /// GET /api/v1/invariants – List InvariantResult, optional severity filter
+#[utoipa::path(
+    get,
+    path = "/api/v1/invariants",
+    tag = "state",
+    summary = "Invariant check results",
+    params(InvariantsQuery),
+    responses(
+        (status = 200, description = "InvariantResults", body = Vec<serde_json::Value>)
+    )
+)]
 pub async fn invariants_handler(
     State(state): State<AppState>,
     Query(q): Query<InvariantsQuery>,
@@ -279,30 +356,66 @@ pub async fn invariants_handler(
 // Event Log (read-only)
 // ============================================================================
 
-/// GET /api/v1/events – List WrappedStateEvent with limit, since_sequence, component, realm_id
+/// GET /api/v1/events – Gepufferte WrappedStateEvents, cursor-paginiert und gefiltert
+#[utoipa::path(
+    get,
+    path = "/api/v1/events",
+    tag = "events",
+    summary = "Logged state events",
+    params(EventsQuery),
+    responses(
+        (status = 200, description = "Page<WrappedStateEvent>; ETag", body = Object),
+        (status = 304, description = "If-None-Match passt"),
+        (status = 400, description = "Ungültiger Cursor", body = crate::api::openapi::ErrorBody)
+    )
+)]
 pub async fn events_list_handler(
     State(state): State<AppState>,
     Query(q): Query<EventsQuery>,
-) -> Json<Vec<WrappedStateEvent>> {
-    let log = &state.unified_state.event_log;
-    let limit = q.limit.unwrap_or(100).min(1000);
-
-    let events = if let Some(since) = q.since_sequence {
-        log.events_since(since)
-    } else if let Some(ref comp) = q.component {
-        let comp_parsed = parse_component(comp);
-        log.events_for_component(comp_parsed)
-    } else {
-        let snap = log.snapshot();
-        let since = snap.sequence.saturating_sub(limit as u64);
-        log.events_since(since)
+    headers: HeaderMap,
+) -> Response {
+    let page = match PageRequest::new(
+        &["sequence"],
+        None,
+        Some(q.order.unwrap_or(SortOrder::Desc)),
+        q.limit,
+        q.cursor.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return e.into_response(),
     };
+    let matcher = EventMatcher::new(&SubscriptionFilter {
+        realm_id: q.realm_id,
+        did: q.did,
+        event_types: q.event_type.into_iter().collect(),
+        components: q.component.into_iter().collect(),
+        ..Default::default()
+    });
 
-    let out: Vec<WrappedStateEvent> = events.into_iter().take(limit as usize).collect();
-    Json(out)
+    let since = q.since_sequence;
+    let events = state
+        .unified_state
+        .event_log
+        .events_from(0, usize::MAX)
+        .0
+        .into_iter()
+        .filter(|e| since.is_none_or(|since| e.sequence > since))
+        .filter(|e| matcher.matches_event(e));
+    page_response(&headers, &page.paginate(events, |e| numeric_key(e.sequence as i64, "")))
 }
 
 /// GET /api/v1/events/:sequence – Single WrappedStateEvent by sequence
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{sequence}",
+    tag = "events",
+    summary = "Single event by sequence",
+    params(("sequence" = u64, Path, description = "Event-Sequenz")),
+    responses(
+        (status = 200, description = "WrappedStateEvent", body = serde_json::Value),
+        (status = 404, description = "Sequenz unbekannt oder nicht mehr gepuffert", body = serde_json::Value)
+    )
+)]
 pub async fn event_by_sequence_handler(
     State(state): State<AppState>,
     Path(sequence): Path<u64>,
@@ -328,13 +441,22 @@ pub async fn event_by_sequence_handler(
 }
 
 /// GET /api/v1/events/log/snapshot – EventLogSnapshot
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/log/snapshot",
+    tag = "events",
+    summary = "Event log statistics",
+    responses(
+        (status = 200, description = "EventLogSnapshot", body = serde_json::Value)
+    )
+)]
 pub async fn events_log_snapshot_handler(State(state): State<AppState>) -> impl IntoResponse {
     let snapshot = state.unified_state.event_log_stats();
     Json(snapshot)
 }
 
 /// GET /api/v1/events/checkpoints – Last checkpoint (sequence; id/state_hash from event log if available)
-#[derive(serde::Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct EventsCheckpointResponse {
     pub last_checkpoint_sequence: u64,
     pub current_sequence: u64,
@@ -344,6 +466,15 @@ pub struct EventsCheckpointResponse {
     pub state_hash_hex: Option<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/checkpoints",
+    tag = "events",
+    summary = "Last checkpoint",
+    responses(
+        (status = 200, description = "Checkpoint-Sequenzen", body = EventsCheckpointResponse)
+    )
+)]
 pub async fn events_checkpoints_handler(State(state): State<AppState>) -> Json<EventsCheckpointResponse> {
     let snapshot = state.unified_state.event_log_stats();
     Json(EventsCheckpointResponse {
@@ -354,7 +485,8 @@ pub async fn events_checkpoints_handler(State(state): State<AppState>) -> Json<E
     })
 }
 
-#[derive(Debug, Default, serde::Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct DeltaQuery {
     /// Merkle-Root (hex) – Deltas ab dem State mit diesem Root
     pub since_root: Option<String>,
@@ -362,13 +494,14 @@ pub struct DeltaQuery {
     pub since_sequence: Option<u64>,
 }
 
-#[derive(Debug, Default, serde::Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct StreamQuery {
     /// Komma-getrennte Komponenten (z.B. "trust,eclvm"); leer = alle
     pub components: Option<String>,
 }
 
-fn parse_component(s: &str) -> StateComponent {
+pub(crate) fn parse_component(s: &str) -> StateComponent {
     match s.to_lowercase().as_str() {
         "identity" => StateComponent::Identity,
         "trust" => StateComponent::Trust,
@@ -421,12 +554,32 @@ fn hex_to_merkle_hash(s: &str) -> Option<[u8; 32]> {
 // ============================================================================
 
 /// GET /api/v1/state/merkle/root – Merkle-Root des aktuellen State
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/merkle/root",
+    tag = "state",
+    summary = "Merkle root of the current state",
+    responses(
+        (status = 200, description = "`{root}` (Hex)", body = serde_json::Value)
+    )
+)]
 pub async fn state_merkle_root_handler(State(state): State<AppState>) -> impl IntoResponse {
     let root = state.unified_state.merkle_root();
     Json(serde_json::json!({ "root": merkle_hash_to_hex(&root) }))
 }
 
 /// GET /api/v1/state/merkle/component/:component – Merkle-Hash einer Komponente
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/merkle/component/{component}",
+    tag = "state",
+    summary = "Merkle hash of a component",
+    params(("component" = String, Path, description = "Komponente (z. B. trust, eclvm)")),
+    responses(
+        (status = 200, description = "`{component, hash}`", body = serde_json::Value),
+        (status = 404, description = "Kein Hash für die Komponente", body = serde_json::Value)
+    )
+)]
 pub async fn state_merkle_component_handler(
     State(state): State<AppState>,
     Path(component_name): Path<String>,
@@ -449,6 +602,16 @@ pub async fn state_merkle_component_handler(
 }
 
 /// GET /api/v1/state/delta?since_root=<hex>&since_sequence=<n> – Delta seit Root/Sequenz
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/delta",
+    tag = "state",
+    summary = "State deltas since a root or sequence",
+    params(DeltaQuery),
+    responses(
+        (status = 200, description = "`{root, deltas}`", body = serde_json::Value)
+    )
+)]
 pub async fn state_delta_handler(
     State(state): State<AppState>,
     Query(q): Query<DeltaQuery>,
@@ -483,6 +646,17 @@ pub async fn state_delta_handler(
 }
 
 /// GET /api/v1/state/proof/:component – State-Proof für eine Komponente (gegen Root verifizierbar)
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/proof/{component}",
+    tag = "state",
+    summary = "Merkle proof for a component",
+    params(("component" = String, Path, description = "Komponente (z. B. trust, eclvm)")),
+    responses(
+        (status = 200, description = "`{root, component, component_hash, proof_path}`", body = serde_json::Value),
+        (status = 404, description = "Kein Proof für die Komponente", body = serde_json::Value)
+    )
+)]
 pub async fn state_proof_handler(
     State(state): State<AppState>,
     Path(component_name): Path<String>,
@@ -513,6 +687,16 @@ pub async fn state_proof_handler(
 // ============================================================================
 
 /// GET /api/v1/state/stream – State-Delta-Subscription (SSE); optional ?components=trust,eclvm
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/stream",
+    tag = "state",
+    summary = "State delta stream (Server-Sent Events)",
+    params(StreamQuery),
+    responses(
+        (status = 200, description = "Ein `StateDelta` (JSON) pro Event", body = String, content_type = "text/event-stream")
+    )
+)]
 pub async fn state_stream_handler(
     State(state): State<AppState>,
     Query(q): Query<StreamQuery>,
@@ -543,24 +727,41 @@ pub async fn state_stream_handler(
 // Metrics
 // ============================================================================
 
-/// GET /api/v1/state/metrics – Key-value metrics for scraping
-pub async fn state_metrics_handler(State(state): State<AppState>) -> String {
-    let snapshot = state.unified_state.snapshot();
-    let mut out = String::new();
-    out.push_str(&format!("erynoa_health_score {}", snapshot.health_score));
-    out.push('\n');
-    out.push_str(&format!("erynoa_timestamp_ms {}", snapshot.timestamp_ms));
-    out.push('\n');
-    out.push_str(&format!("erynoa_uptime_secs {}", snapshot.uptime_secs));
-    out.push('\n');
-    out.push_str(&format!("erynoa_events_sequence {}", snapshot.event_log.sequence));
-    out.push('\n');
-    out.push_str(&format!("erynoa_events_buffer_size {}", snapshot.event_log.buffer_size));
-    out.push('\n');
-    out
+/// GET /api/v1/state/metrics – OpenMetrics-Exposition für Prometheus/Grafana
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/metrics",
+    tag = "state",
+    summary = "OpenMetrics exposition for Prometheus/Grafana",
+    responses(
+        (status = 200, description = "OpenMetrics-Text", body = String, content_type = "application/openmetrics-text; version=1.0.0; charset=utf-8")
+    )
+)]
+pub async fn state_metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let body = OpenMetrics::new()
+        .snapshot(&state.unified_state.snapshot())
+        .stores(&state.storage.store_metrics())
+        .render();
+    ([(header::CONTENT_TYPE, openmetrics::CONTENT_TYPE)], body)
+}
+
+/// Rendert die OpenMetrics-Exposition eines Snapshots (ohne Store-Metriken)
+///
+/// Wird auch vom Testnet-Node genutzt, der keinen vollständigen `AppState` hat.
+pub fn render_state_metrics(snapshot: &UnifiedSnapshot) -> String {
+    OpenMetrics::new().snapshot(snapshot).render()
 }
 
 /// GET /api/v1/state/metrics/eclvm – ECLVM-specific metrics
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/metrics/eclvm",
+    tag = "state",
+    summary = "ECLVM metrics",
+    responses(
+        (status = 200, description = "Metriken (Text)", body = String, content_type = "text/plain")
+    )
+)]
 pub async fn state_metrics_eclvm_handler(State(state): State<AppState>) -> String {
     let snapshot = state.unified_state.snapshot();
     let e = &snapshot.eclvm;
@@ -581,6 +782,15 @@ pub async fn state_metrics_eclvm_handler(State(state): State<AppState>) -> Strin
 }
 
 /// GET /api/v1/state/metrics/health – Health-related metrics
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/metrics/health",
+    tag = "state",
+    summary = "Health metrics",
+    responses(
+        (status = 200, description = "Metriken (Text)", body = String, content_type = "text/plain")
+    )
+)]
 pub async fn state_metrics_health_handler(State(state): State<AppState>) -> String {
     let report = state.coordinator.aggregate_health();
     let mut out = String::new();
@@ -613,12 +823,33 @@ pub async fn state_metrics_health_handler(State(state): State<AppState>) -> Stri
 // ============================================================================
 
 /// GET /api/v1/state/warnings – List active warnings
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/warnings",
+    tag = "state",
+    summary = "Active warnings",
+    responses(
+        (status = 200, description = "Warnungen", body = Vec<String>)
+    )
+)]
 pub async fn state_warnings_list_handler(State(state): State<AppState>) -> Json<Vec<String>> {
     let warnings = state.unified_state.snapshot().warnings.clone();
     Json(warnings)
 }
 
 /// DELETE /api/v1/state/warnings – Clear all warnings (prefix "" matches all)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/state/warnings",
+    tag = "state",
+    summary = "Clear all warnings",
+    responses(
+        (status = 204, description = "Gelöscht"),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn state_warnings_clear_all_handler(State(state): State<AppState>) -> impl IntoResponse {
     // clear_warning("") retains nothing because every string starts with ""
     state.unified_state.clear_warning("");
@@ -626,6 +857,19 @@ pub async fn state_warnings_clear_all_handler(State(state): State<AppState>) ->
 }
 
 /// DELETE /api/v1/state/warnings/:key – Clear warnings by prefix
+#[utoipa::path(
+    delete,
+    path = "/api/v1/state/warnings/{key}",
+    tag = "state",
+    summary = "Clear warnings by prefix",
+    params(("key" = String, Path, description = "Präfix")),
+    responses(
+        (status = 204, description = "Gelöscht"),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn state_warnings_clear_by_key_handler(
     State(state): State<AppState>,
     Path(key): Path<String>,
@@ -638,13 +882,22 @@ pub async fn state_warnings_clear_by_key_handler(
 // Phase 4: Circuit Breaker & System Mode
 // ============================================================================
 
-#[derive(serde::Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct StateModeResponse {
     pub mode: SystemMode,
     pub description: String,
 }
 
 /// GET /api/v1/state/mode – Current SystemMode + description
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/mode",
+    tag = "state",
+    summary = "Current system mode",
+    responses(
+        (status = 200, description = "SystemMode", body = StateModeResponse)
+    )
+)]
 pub async fn state_mode_handler(State(state): State<AppState>) -> Json<StateModeResponse> {
     let mode = state.unified_state.system_mode();
     Json(StateModeResponse {
@@ -653,12 +906,26 @@ pub async fn state_mode_handler(State(state): State<AppState>) -> Json<StateMode
     })
 }
 
-#[derive(serde::Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct StateModeSetBody {
     pub mode: String,
 }
 
 /// POST /api/v1/state/mode – Set SystemMode (Ops/Notfall)
+#[utoipa::path(
+    post,
+    path = "/api/v1/state/mode",
+    tag = "state",
+    summary = "Set system mode",
+    request_body = StateModeSetBody,
+    responses(
+        (status = 200, description = "Neuer Modus", body = StateModeResponse),
+        (status = 400, description = "Unbekannter Modus", body = crate::api::openapi::ErrorBody),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn state_mode_set_handler(
     State(state): State<AppState>,
     Json(body): Json<StateModeSetBody>,
@@ -687,6 +954,18 @@ pub async fn state_mode_set_handler(
 }
 
 /// POST /api/v1/state/mode/reset – Reset to Normal
+#[utoipa::path(
+    post,
+    path = "/api/v1/state/mode/reset",
+    tag = "state",
+    summary = "Reset circuit breaker to Normal",
+    responses(
+        (status = 200, description = "Modus nach Reset", body = StateModeResponse),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn state_mode_reset_handler(State(state): State<AppState>) -> Json<StateModeResponse> {
     state.unified_state.reset_circuit_breaker();
     let mode = state.unified_state.system_mode();
@@ -697,6 +976,15 @@ pub async fn state_mode_reset_handler(State(state): State<AppState>) -> Json<Sta
 }
 
 /// GET /api/v1/state/circuit_breaker – CircuitBreakerSnapshot
+#[utoipa::path(
+    get,
+    path = "/api/v1/state/circuit_breaker",
+    tag = "state",
+    summary = "Circuit breaker snapshot",
+    responses(
+        (status = 200, description = "CircuitBreakerSnapshot", body = serde_json::Value)
+    )
+)]
 pub async fn state_circuit_breaker_handler(State(state): State<AppState>) -> Json<CircuitBreakerSnapshot> {
     let snapshot = state.unified_state.circuit_breaker.snapshot();
     Json(snapshot)
@@ -706,14 +994,30 @@ pub async fn state_circuit_breaker_handler(State(state): State<AppState>) -> Jso
 // Phase 4: State Event (Mutation)
 // ============================================================================
 
-#[derive(serde::Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct StateEventApplyBody {
+    /// `StateEvent` als `{"<Variante>": {...}}`
+    #[schema(value_type = Object)]
     pub event: StateEvent,
     #[serde(default)]
     pub parent_ids: Option<Vec<String>>,
 }
 
 /// POST /api/v1/state/event – Apply StateEvent (log_and_apply). Admin/Debug.
+#[utoipa::path(
+    post,
+    path = "/api/v1/state/event",
+    tag = "state",
+    summary = "Apply a StateEvent (admin listener only)",
+    request_body = StateEventApplyBody,
+    responses(
+        (status = 200, description = "Geloggtes Event (WrappedStateEvent)", body = serde_json::Value),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability, Rolle oder falsches Realm", body = crate::error::ErrorResponse),
+        (status = 404, description = "Nicht über den öffentlichen Listener", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
 pub async fn state_event_apply_handler(
     State(state): State<AppState>,
     Json(body): Json<StateEventApplyBody>,
@@ -0,0 +1,409 @@
+//! Eine WebSocket-Verbindung mit ihren Subscriptions
+//!
+//! Der Verbindungs-Task liest Client-Nachrichten und wird vom
+//! `StateBroadcaster` (jedes Delta) und vom `SagaExecutor` geweckt. Ein
+//! separater Writer-Task leert die begrenzte Sende-Queue in den Socket.
+//!
+//! Backpressure: Event-Subscriptions lesen per Cursor aus dem
+//! `StateEventLog` und bleiben bei voller Queue einfach stehen; Saga- und
+//! Delta-Subscriptions verwerfen und melden danach `lagged`. Kann selbst
+//! eine Steuer-Nachricht nicht mehr eingereiht werden, wird die Verbindung
+//! geschlossen.
+
+use std::time::Duration;
+
+use axum::extract::ws::{Message, WebSocket};
+use axum_connect::futures::{SinkExt, StreamExt};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use super::protocol::{ClientMessage, EventMatcher, ServerMessage, SubscriptionFilter, Topic};
+use crate::api::v1::auth::{AuthContext, Role};
+use crate::api::v1::saga_handlers::authorize_saga;
+use crate::config::SubscriptionSettings;
+use crate::core::{StateDelta, WrappedStateEvent};
+use crate::domain::UniversalId;
+use crate::peer::saga_executor::SagaUpdate;
+use crate::server::AppState;
+
+/// Nachhol-Intervall für Event-Subscriptions, die an voller Queue hingen
+const RETRY_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Eine aktive Subscription
+struct Subscription {
+    id: String,
+    topic: Topic,
+    matcher: EventMatcher,
+    /// Nur [`Topic::Saga`]
+    saga_id: Option<UniversalId>,
+    /// Nächste Sequenz aus dem Event-Log (nur Event-Log-Topics)
+    next_sequence: u64,
+    /// Verworfene Push-Nachrichten seit dem letzten `lagged`
+    dropped: u64,
+}
+
+/// Zustand einer Verbindung
+struct Connection {
+    state: AppState,
+    auth: AuthContext,
+    operator: bool,
+    limits: SubscriptionSettings,
+    tx: mpsc::Sender<ServerMessage>,
+    subscriptions: Vec<Subscription>,
+    closed: bool,
+}
+
+/// Bedient die Verbindung bis Client-Close oder Überlastung
+pub(super) async fn serve(socket: WebSocket, state: AppState, auth: AuthContext) {
+    let limits = state.config.subscriptions.clone();
+    let (mut sink, mut stream) = socket.split();
+    let (tx, mut rx) = mpsc::channel::<ServerMessage>(limits.send_queue.max(1));
+
+    let writer = tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            let Ok(text) = serde_json::to_string(&message) else {
+                continue;
+            };
+            if sink.send(Message::Text(text.into())).await.is_err() {
+                return;
+            }
+        }
+        let _ = sink.close().await;
+    });
+
+    // Erst abonnieren, dann Cursor setzen: kein Event geht dazwischen verloren
+    let mut deltas = state.unified_state.subscribe_deltas();
+    let mut sagas = state.saga_executor.subscribe();
+    let mut retry = tokio::time::interval(RETRY_INTERVAL);
+    retry.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    let mut connection = Connection {
+        operator: auth.role(&state, None) == Role::NodeOperator,
+        state,
+        auth,
+        limits,
+        tx,
+        subscriptions: Vec::new(),
+        closed: false,
+    };
+
+    while !connection.closed {
+        tokio::select! {
+            incoming = stream.next() => match incoming {
+                Some(Ok(Message::Text(text))) => connection.on_text(text.as_str()),
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Ping/Pong beantwortet axum, Binärframes werden ignoriert
+                Some(Ok(_)) => {}
+            },
+            delta = deltas.recv() => match delta {
+                Ok(delta) => connection.on_delta(&delta),
+                Err(RecvError::Lagged(missed)) => connection.on_push_lagged(Topic::Deltas, missed),
+                Err(RecvError::Closed) => break,
+            },
+            update = sagas.recv() => match update {
+                Ok(update) => connection.on_saga(&update),
+                Err(RecvError::Lagged(missed)) => connection.on_push_lagged(Topic::Saga, missed),
+                Err(RecvError::Closed) => break,
+            },
+            _ = retry.tick() => connection.pump_events(),
+        }
+    }
+
+    // Sender fallen lassen, damit der Writer die Queue leert und schließt
+    drop(connection);
+    let _ = writer.await;
+}
+
+impl Connection {
+    fn on_text(&mut self, text: &str) {
+        let message = match serde_json::from_str::<ClientMessage>(text) {
+            Ok(message) => message,
+            Err(e) => {
+                self.control(ServerMessage::error(None, "invalid_message", e.to_string()));
+                return;
+            }
+        };
+        match message {
+            ClientMessage::Subscribe {
+                id,
+                topic,
+                filter,
+                since_sequence,
+            } => self.subscribe(id, topic, filter, since_sequence),
+            ClientMessage::Unsubscribe { id } => {
+                let before = self.subscriptions.len();
+                self.subscriptions.retain(|s| s.id != id);
+                if self.subscriptions.len() == before {
+                    self.control(ServerMessage::error(
+                        Some(&id),
+                        "unknown_subscription",
+                        "no subscription with this id",
+                    ));
+                } else {
+                    self.control(ServerMessage::Unsubscribed { id });
+                }
+            }
+            ClientMessage::Ping => self.control(ServerMessage::Pong),
+        }
+    }
+
+    fn subscribe(
+        &mut self,
+        id: String,
+        topic: Topic,
+        mut filter: SubscriptionFilter,
+        since_sequence: Option<u64>,
+    ) {
+        if self.subscriptions.iter().any(|s| s.id == id) {
+            self.control(ServerMessage::error(
+                Some(&id),
+                "duplicate_id",
+                "subscription id already in use",
+            ));
+            return;
+        }
+        if self.subscriptions.len() >= self.limits.max_subscriptions {
+            self.control(ServerMessage::error(
+                Some(&id),
+                "too_many_subscriptions",
+                format!(
+                    "at most {} subscriptions per connection",
+                    self.limits.max_subscriptions
+                ),
+            ));
+            return;
+        }
+        if let Some(realm_id) = &filter.realm_id {
+            if !self.can_see_realm(realm_id) {
+                self.control(ServerMessage::error(
+                    Some(&id),
+                    "forbidden",
+                    format!("not a member of realm {}", realm_id),
+                ));
+                return;
+            }
+        }
+
+        let saga_id = match topic {
+            Topic::Saga => match filter.saga_id.as_deref().map(UniversalId::from_hex) {
+                Some(Ok(saga_id)) => {
+                    if !self.can_follow_saga(&id, &saga_id) {
+                        return;
+                    }
+                    Some(saga_id)
+                }
+                Some(Err(_)) => {
+                    self.control(ServerMessage::error(
+                        Some(&id),
+                        "invalid_filter",
+                        "saga_id must be hex",
+                    ));
+                    return;
+                }
+                None => {
+                    self.control(ServerMessage::error(
+                        Some(&id),
+                        "invalid_filter",
+                        "topic saga requires filter.saga_id",
+                    ));
+                    return;
+                }
+            },
+            Topic::Trust => {
+                filter.did.get_or_insert_with(|| self.auth.did.clone());
+                filter.event_types = vec!["TrustUpdate".to_string()];
+                None
+            }
+            Topic::Events | Topic::Deltas => None,
+        };
+
+        let current = self.state.unified_state.event_log.snapshot().sequence;
+        let next_sequence = match since_sequence {
+            Some(seen) if topic.is_event_log() => seen.saturating_add(1).min(current),
+            _ => current,
+        };
+
+        self.control(ServerMessage::Subscribed {
+            id: id.clone(),
+            topic,
+            filter: filter.clone(),
+            next_sequence,
+        });
+        self.subscriptions.push(Subscription {
+            id,
+            topic,
+            matcher: EventMatcher::new(&filter),
+            saga_id,
+            next_sequence,
+            dropped: 0,
+        });
+        self.pump_events();
+    }
+
+    /// Wie bei REST und Connect: nur der Executor der Saga oder ein Node-Betreiber
+    fn can_follow_saga(&mut self, id: &str, saga_id: &UniversalId) -> bool {
+        let (code, message) = match self.state.saga_executor.get(saga_id) {
+            Ok(Some(record)) => match authorize_saga(&self.state, &self.auth, &record) {
+                Ok(()) => return true,
+                Err(_) => ("forbidden", "not allowed to follow this saga".to_string()),
+            },
+            Ok(None) => ("not_found", format!("saga {} not found", saga_id.to_hex())),
+            Err(e) => ("internal", e.to_string()),
+        };
+        self.control(ServerMessage::error(Some(id), code, message));
+        false
+    }
+
+    /// Node-Operatoren sehen alles, sonst Mitglieder und Admins des Realms
+    fn can_see_realm(&self, realm_id: &str) -> bool {
+        if self.operator {
+            return true;
+        }
+        if self.auth.require_realm(realm_id).is_err() {
+            return false;
+        }
+        self.state
+            .unified_state
+            .peer
+            .realm
+            .realms
+            .read()
+            .is_ok_and(|realms| {
+                realms.get(realm_id).is_some_and(|realm| {
+                    realm.is_member_by_id(&self.auth.id) || realm.is_admin_by_id(&self.auth.id)
+                })
+            })
+    }
+
+    fn visible(&self, event: &WrappedStateEvent) -> bool {
+        event
+            .realm_context()
+            .is_none_or(|realm_id| self.can_see_realm(&realm_id))
+    }
+
+    /// Steuer-Nachricht; ist die Queue voll, wird die Verbindung geschlossen
+    fn control(&mut self, message: ServerMessage) {
+        if let Err(e) = self.tx.try_send(message) {
+            if matches!(e, TrySendError::Full(_)) {
+                tracing::debug!(did = %self.auth.did, "subscription client too slow, closing");
+            }
+            self.closed = true;
+        }
+    }
+
+    /// Jedes Delta weckt die Event-Log-Subscriptions und geht an Delta-Subscriptions
+    fn on_delta(&mut self, delta: &StateDelta) {
+        for idx in 0..self.subscriptions.len() {
+            let sub = &self.subscriptions[idx];
+            if sub.topic != Topic::Deltas || !sub.matcher.matches_delta(delta) {
+                continue;
+            }
+            if let Some(realm_id) = &delta.realm_id {
+                if !self.can_see_realm(realm_id) {
+                    continue;
+                }
+            }
+            let message = ServerMessage::Delta {
+                id: sub.id.clone(),
+                delta: delta.clone(),
+            };
+            self.push(idx, message);
+        }
+        self.pump_events();
+    }
+
+    fn on_saga(&mut self, update: &SagaUpdate) {
+        for idx in 0..self.subscriptions.len() {
+            let sub = &self.subscriptions[idx];
+            if sub.topic != Topic::Saga || sub.saga_id != Some(update.saga_id) {
+                continue;
+            }
+            let message = ServerMessage::Saga {
+                id: sub.id.clone(),
+                update: update.clone(),
+            };
+            self.push(idx, message);
+        }
+    }
+
+    /// Der Broadcast-Kanal selbst ist übergelaufen
+    fn on_push_lagged(&mut self, topic: Topic, missed: u64) {
+        for sub in self.subscriptions.iter_mut().filter(|s| s.topic == topic) {
+            sub.dropped += missed;
+        }
+        if topic == Topic::Deltas {
+            self.pump_events();
+        }
+    }
+
+    /// Push-Nachricht; bei voller Queue verworfen und später als `lagged` gemeldet
+    fn push(&mut self, idx: usize, message: ServerMessage) {
+        let sub = &mut self.subscriptions[idx];
+        if sub.dropped > 0 {
+            let lagged = ServerMessage::Lagged {
+                id: sub.id.clone(),
+                missed: sub.dropped,
+            };
+            if self.tx.try_send(lagged).is_err() {
+                sub.dropped += 1;
+                return;
+            }
+            sub.dropped = 0;
+        }
+        if self.tx.try_send(message).is_err() {
+            sub.dropped += 1;
+        }
+    }
+
+    /// Liefert neue Events aus dem Event-Log, solange die Queue Platz hat
+    fn pump_events(&mut self) {
+        let state = self.state.clone();
+        let log = &state.unified_state.event_log;
+        let batch = self.limits.replay_batch.max(1);
+
+        for idx in 0..self.subscriptions.len() {
+            if !self.subscriptions[idx].topic.is_event_log() {
+                continue;
+            }
+            loop {
+                let from = self.subscriptions[idx].next_sequence;
+                let (events, oldest) = log.events_from(from, batch);
+
+                // Resume-Punkt ist bereits aus dem Ring-Buffer gefallen
+                if let Some(oldest) = oldest.filter(|oldest| *oldest > from) {
+                    let Ok(permit) = self.tx.try_reserve() else {
+                        return;
+                    };
+                    let sub = &mut self.subscriptions[idx];
+                    permit.send(ServerMessage::Lagged {
+                        id: sub.id.clone(),
+                        missed: oldest - from,
+                    });
+                    sub.next_sequence = oldest;
+                }
+
+                let fetched = events.len();
+                for event in events {
+                    let sub = &self.subscriptions[idx];
+                    if !sub.matcher.matches_event(&event) || !self.visible(&event) {
+                        self.subscriptions[idx].next_sequence = event.sequence + 1;
+                        continue;
+                    }
+                    let Ok(permit) = self.tx.try_reserve() else {
+                        // Queue voll: Cursor bleibt stehen, Nachholen beim nächsten Wake-up
+                        return;
+                    };
+                    let sequence = event.sequence;
+                    permit.send(ServerMessage::Event {
+                        id: sub.id.clone(),
+                        event,
+                    });
+                    self.subscriptions[idx].next_sequence = sequence + 1;
+                }
+                if fetched < batch {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! WebSocket-Subscriptions – `GET /api/v1/subscribe`
+//!
+//! Multiplexter Push-Kanal mit typisierten Subscriptions pro Verbindung:
+//!
+//! | Topic | Quelle | Filter | Resume |
+//! |-------|--------|--------|--------|
+//! | `events` | `StateEventLog` | `realm_id`, `did`, `event_types`, `components` | `since_sequence` |
+//! | `trust` | `TrustUpdate`-Events | `did` (Default: eigene DID), `realm_id` | `since_sequence` |
+//! | `saga` | `SagaExecutor`-Updates | `saga_id` (Pflicht) | – |
+//! | `deltas` | `StateBroadcaster` | `components`, `realm_id` | – |
+//!
+//! ## Authentifizierung
+//!
+//! Session-Token als `Authorization: Bearer …` oder – für Browser, die beim
+//! Upgrade keine Header setzen können – als Query `?access_token=…`. Die
+//! Session braucht `read:events`.
+//!
+//! ## Autorisierung
+//!
+//! Events mit Realm-Kontext erhalten nur Mitglieder und Admins des Realms
+//! (realm-gebundene Sessions nur ihr Realm), Node-Operatoren alle. Ein
+//! `realm_id`-Filter auf ein fremdes Realm wird mit `forbidden` abgelehnt.
+//!
+//! ## Beispiel
+//!
+//! ```text
+//! → {"type":"subscribe","id":"t","topic":"trust","since_sequence":41}
+//! ← {"type":"subscribed","id":"t","topic":"trust","filter":{…},"next_sequence":42}
+//! ← {"type":"event","id":"t","event":{"sequence":42,"event":{"TrustUpdate":{…}},…}}
+//! ```
+
+mod connection;
+pub mod protocol;
+
+pub use protocol::{ClientMessage, EventMatcher, ServerMessage, SubscriptionFilter, Topic};
+
+use axum::{
+    extract::{Query, State, WebSocketUpgrade},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::api::v1::auth::{context::bearer_token, AuthContext};
+use crate::domain::unified::identity::Capability;
+use crate::server::AppState;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SubscribeQuery {
+    /// Alternative zum Authorization-Header
+    pub access_token: Option<String>,
+}
+
+/// GET /api/v1/subscribe – WebSocket-Upgrade für Subscriptions
+#[utoipa::path(
+    get,
+    path = "/api/v1/subscribe",
+    tag = "subscriptions",
+    summary = "WebSocket subscriptions",
+    params(SubscribeQuery),
+    responses(
+        (status = 101, description = "WebSocket-Upgrade"),
+        (status = 401, description = "Keine oder ungültige Session", body = crate::error::ErrorResponse),
+        (status = 403, description = "Fehlende Capability `read:events`", body = crate::error::ErrorResponse)
+    ),
+    security(("bearer" = []))
+)]
+pub async fn subscribe_handler(
+    State(state): State<AppState>,
+    Query(q): Query<SubscribeQuery>,
+    headers: HeaderMap,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let auth = match q.access_token.as_deref() {
+        Some(token) => AuthContext::from_token(&state, token),
+        None => bearer_token(&headers).and_then(|token| AuthContext::from_token(&state, token)),
+    };
+    let auth = match auth.and_then(|auth| {
+        auth.require(&Capability::Read {
+            resource: "events".into(),
+        })
+        .map(|()| auth)
+    }) {
+        Ok(auth) => auth,
+        Err(e) => return e.into_response(),
+    };
+
+    ws.on_upgrade(move |socket| connection::serve(socket, state, auth))
+}
@@ -0,0 +1,298 @@
+//! Nachrichten und Filter des Subscription-Protokolls
+//!
+//! Alle Nachrichten sind JSON-Textframes mit Feld `type`. Client-Nachrichten
+//! siehe [`ClientMessage`], Server-Nachrichten siehe [`ServerMessage`].
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+
+use super::super::state_handlers::parse_component;
+use crate::core::{StateComponent, StateDelta, WrappedStateEvent};
+use crate::peer::saga_executor::SagaUpdate;
+
+/// Typ einer Subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Topic {
+    /// State-Events aus dem `StateEventLog` (mit Resume)
+    Events,
+    /// `TrustUpdate`-Events einer DID (Default: eigene DID, mit Resume)
+    Trust,
+    /// Fortschritt einer Saga (`SagaExecutor`-Updates)
+    Saga,
+    /// Rohe `StateDelta`s des `StateBroadcaster`
+    Deltas,
+}
+
+impl Topic {
+    /// Liest die Subscription aus dem Event-Log (Cursor statt Push)?
+    pub fn is_event_log(&self) -> bool {
+        matches!(self, Self::Events | Self::Trust)
+    }
+}
+
+/// Filter einer Subscription; nicht gesetzte Felder filtern nicht
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionFilter {
+    /// Nur Events/Deltas mit diesem Realm-Kontext
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realm_id: Option<String>,
+    /// Nur Events, die diese DID enthalten
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+    /// Event-Typen (Variantenname, z. B. `TrustUpdate`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub event_types: Vec<String>,
+    /// Komponenten (wie `/state/stream?components=`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+    /// Saga-ID (Hex), Pflicht für [`Topic::Saga`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saga_id: Option<String>,
+}
+
+/// Nachricht vom Client
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Neue Subscription unter der vom Client gewählten `id`
+    Subscribe {
+        id: String,
+        topic: Topic,
+        #[serde(default)]
+        filter: SubscriptionFilter,
+        /// Zuletzt gesehene Event-Sequenz; geliefert wird ab der nächsten
+        #[serde(default)]
+        since_sequence: Option<u64>,
+    },
+    /// Subscription beenden
+    Unsubscribe { id: String },
+    /// Lebenszeichen, Antwort `pong`
+    Ping,
+}
+
+/// Nachricht an den Client
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Subscription aktiv; Events ab `next_sequence` folgen
+    Subscribed {
+        id: String,
+        topic: Topic,
+        filter: SubscriptionFilter,
+        next_sequence: u64,
+    },
+    Unsubscribed {
+        id: String,
+    },
+    Event {
+        id: String,
+        event: WrappedStateEvent,
+    },
+    Saga {
+        id: String,
+        update: SagaUpdate,
+    },
+    Delta {
+        id: String,
+        delta: StateDelta,
+    },
+    /// Nachrichten gingen verloren (Puffer übergelaufen oder Client zu langsam)
+    Lagged {
+        id: String,
+        missed: u64,
+    },
+    Error {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        code: &'static str,
+        message: String,
+    },
+    Pong,
+}
+
+impl ServerMessage {
+    pub fn error(id: Option<&str>, code: &'static str, message: impl Into<String>) -> Self {
+        Self::Error {
+            id: id.map(str::to_string),
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Variantenname eines serialisierten `StateEvent` (`{"TrustUpdate": {...}}`)
+pub fn event_type(event: &Value) -> Option<&str> {
+    match event {
+        Value::Object(map) if map.len() == 1 => map.keys().next().map(String::as_str),
+        Value::String(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Kommt `needle` irgendwo als String-Wert vor?
+fn mentions(value: &Value, needle: &str) -> bool {
+    match value {
+        Value::String(s) => s == needle,
+        Value::Array(items) => items.iter().any(|v| mentions(v, needle)),
+        Value::Object(map) => map.values().any(|v| mentions(v, needle)),
+        _ => false,
+    }
+}
+
+/// Vorbereiteter Filter für Events und Deltas
+#[derive(Debug, Clone, Default)]
+pub struct EventMatcher {
+    realm_id: Option<String>,
+    did: Option<String>,
+    event_types: HashSet<String>,
+    components: HashSet<StateComponent>,
+}
+
+impl EventMatcher {
+    pub fn new(filter: &SubscriptionFilter) -> Self {
+        Self {
+            realm_id: filter.realm_id.clone(),
+            did: filter.did.clone(),
+            event_types: filter.event_types.iter().cloned().collect(),
+            components: filter
+                .components
+                .iter()
+                .map(|c| parse_component(c))
+                .collect(),
+        }
+    }
+
+    /// Passt das Event zum Filter?
+    pub fn matches_event(&self, event: &WrappedStateEvent) -> bool {
+        if !self.components.is_empty() && !self.components.contains(&event.component) {
+            return false;
+        }
+        if self.realm_id.is_some() && event.realm_context() != self.realm_id {
+            return false;
+        }
+        if self.event_types.is_empty() && self.did.is_none() {
+            return true;
+        }
+        let Ok(payload) = serde_json::to_value(&event.event) else {
+            return false;
+        };
+        if !self.event_types.is_empty()
+            && !event_type(&payload).is_some_and(|t| self.event_types.contains(t))
+        {
+            return false;
+        }
+        self.did.as_ref().is_none_or(|did| mentions(&payload, did))
+    }
+
+    /// Passt das Delta zum Filter? (Event-Typ und DID gelten nur für Events)
+    pub fn matches_delta(&self, delta: &StateDelta) -> bool {
+        (self.components.is_empty() || self.components.contains(&delta.component))
+            && (self.realm_id.is_none() || delta.realm_id == self.realm_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{DeltaType, StateEvent, TrustReason};
+
+    fn trust_update(entity_id: &str, from_realm: Option<&str>) -> WrappedStateEvent {
+        WrappedStateEvent::new(
+            StateEvent::TrustUpdate {
+                entity_id: entity_id.to_string(),
+                delta: 0.1,
+                reason: TrustReason::PositiveInteraction,
+                from_realm: from_realm.map(str::to_string),
+                triggered_events: 0,
+                new_trust: 0.6,
+            },
+            vec![],
+            7,
+        )
+    }
+
+    #[test]
+    fn test_parse_client_messages() {
+        let message: ClientMessage = serde_json::from_str(
+            r#"{"type":"subscribe","id":"t","topic":"trust","since_sequence":41,
+                "filter":{"did":"did:erynoa:self:abc"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            message,
+            ClientMessage::Subscribe {
+                id: "t".into(),
+                topic: Topic::Trust,
+                filter: SubscriptionFilter {
+                    did: Some("did:erynoa:self:abc".into()),
+                    ..Default::default()
+                },
+                since_sequence: Some(41),
+            }
+        );
+        let ping: ClientMessage = serde_json::from_str(r#"{"type":"ping"}"#).unwrap();
+        assert_eq!(ping, ClientMessage::Ping);
+        assert!(serde_json::from_str::<ClientMessage>(r#"{"type":"subscribe","id":"x"}"#).is_err());
+    }
+
+    #[test]
+    fn test_event_matcher() {
+        let event = trust_update("did:erynoa:self:abc", Some("realm-a"));
+
+        assert!(EventMatcher::default().matches_event(&event));
+        let by_type = EventMatcher::new(&SubscriptionFilter {
+            event_types: vec!["TrustUpdate".into()],
+            components: vec!["trust".into()],
+            ..Default::default()
+        });
+        assert!(by_type.matches_event(&event));
+        let other_type = EventMatcher::new(&SubscriptionFilter {
+            event_types: vec!["SagaProgress".into()],
+            ..Default::default()
+        });
+        assert!(!other_type.matches_event(&event));
+
+        let by_did = |did: &str| {
+            EventMatcher::new(&SubscriptionFilter {
+                did: Some(did.into()),
+                realm_id: Some("realm-a".into()),
+                ..Default::default()
+            })
+        };
+        assert!(by_did("did:erynoa:self:abc").matches_event(&event));
+        assert!(!by_did("did:erynoa:self:other").matches_event(&event));
+        assert!(!by_did("did:erynoa:self:abc")
+            .matches_event(&trust_update("did:erynoa:self:abc", None)));
+    }
+
+    #[test]
+    fn test_delta_matcher_and_messages() {
+        let delta =
+            StateDelta::new(StateComponent::Trust, DeltaType::Update, vec![]).with_realm("realm-a");
+        let matcher = EventMatcher::new(&SubscriptionFilter {
+            components: vec!["trust".into()],
+            realm_id: Some("realm-a".into()),
+            ..Default::default()
+        });
+        assert!(matcher.matches_delta(&delta));
+        assert!(!matcher.matches_delta(&StateDelta::new(
+            StateComponent::Trust,
+            DeltaType::Update,
+            vec![]
+        )));
+
+        let error =
+            serde_json::to_value(ServerMessage::error(Some("s1"), "forbidden", "no")).unwrap();
+        assert_eq!(
+            error,
+            serde_json::json!({ "type": "error", "id": "s1", "code": "forbidden", "message": "no" })
+        );
+        assert_eq!(
+            serde_json::to_value(ServerMessage::Pong).unwrap(),
+            serde_json::json!({ "type": "pong" })
+        );
+    }
+}
@@ -12,15 +12,33 @@
 //!     --mode relay
 //! ```
 //!
+//! ## Cluster-Modus
+//!
+//! Startet N Nodes lokal auf Loopback mit deterministischen Seeds:
+//!
+//! ```bash
+//! # In-Process, optional mit Szenario-Datei (JSON, siehe `peer::p2p::cluster`)
+//! cargo run --features p2p --bin erynoa-testnet-node -- \
+//!     --mode cluster --cluster-size 5 --cluster-relays 2 --seed 42 \
+//!     --scenario scenarios/partition.json
+//!
+//! # Als Child-Prozesse (Ports ab --p2p-port bzw. --api-port)
+//! cargo run --features p2p --bin erynoa-testnet-node -- \
+//!     --mode cluster --cluster-size 5 --cluster-processes true
+//! ```
+//!
 //! ## Environment-Variablen
 //!
 //! - `NODE_NAME`: Name des Nodes (Default: "node")
-//! - `NODE_MODE`: Modus (relay|client, Default: "relay")
+//! - `NODE_MODE`: Modus (relay|client|nat-client|high-privacy|cluster, Default: "relay")
 //! - `P2P_PORT`: libp2p Swarm Port (Default: 4001)
 //! - `API_PORT`: HTTP API Port (Default: 9000)
 //! - `BOOTSTRAP_PEERS`: Komma-separierte Multiaddrs
 //! - `P2P_ENABLE_MDNS`: mDNS aktivieren (Default: true)
 //! - `GENESIS_NODE`: Ob dieser Node der Genesis ist (Default: false)
+//! - `NODE_SEED` / `NODE_INDEX`: Deterministisches Keypair (Cluster-Modus)
+//! - `CLUSTER_SIZE` / `CLUSTER_RELAYS`: Cluster-Größe und Anzahl Relays
+//! - `CLUSTER_SCENARIO`: Pfad zu einer Szenario-Datei (JSON)
 
 use std::env;
 use std::net::SocketAddr;
@@ -41,6 +59,12 @@ struct Args {
     enable_mdns: bool,
     genesis_node: bool,
     data_dir: String,
+    seed: Option<u64>,
+    node_index: usize,
+    cluster_size: usize,
+    cluster_relays: usize,
+    cluster_processes: bool,
+    scenario: Option<String>,
 }
 
 impl Args {
@@ -89,6 +113,32 @@ impl Args {
             data_dir: Self::get_arg(&args, "--data-dir")
                 .or_else(|| env::var("APP_STORAGE__DATA_DIR").ok())
                 .unwrap_or_else(|| "./data".to_string()),
+
+            seed: Self::get_arg(&args, "--seed")
+                .or_else(|| env::var("NODE_SEED").ok())
+                .and_then(|s| s.parse().ok()),
+
+            node_index: Self::get_arg(&args, "--node-index")
+                .or_else(|| env::var("NODE_INDEX").ok())
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0),
+
+            cluster_size: Self::get_arg(&args, "--cluster-size")
+                .or_else(|| env::var("CLUSTER_SIZE").ok())
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(4),
+
+            cluster_relays: Self::get_arg(&args, "--cluster-relays")
+                .or_else(|| env::var("CLUSTER_RELAYS").ok())
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(1),
+
+            cluster_processes: Self::get_arg(&args, "--cluster-processes")
+                .map(|s| s.to_lowercase() == "true" || s == "1")
+                .unwrap_or(false),
+
+            scenario: Self::get_arg(&args, "--scenario")
+                .or_else(|| env::var("CLUSTER_SCENARIO").ok()),
         }
     }
 
@@ -122,6 +172,11 @@ async fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
+    #[cfg(feature = "p2p")]
+    if args.mode == "cluster" {
+        return run_cluster(&args).await;
+    }
+
     info!(
         node = %args.node_name,
         mode = %args.mode,
@@ -147,17 +202,30 @@ async fn main() -> anyhow::Result<()> {
     // P2P-Stack initialisieren
     #[cfg(feature = "p2p")]
     {
-        use erynoa_api::peer::p2p::{P2PConfig, TestnetEvent, TestnetSwarm};
+        use erynoa_api::peer::p2p::cluster::deterministic_keypair;
+        use erynoa_api::peer::p2p::{TestnetConfig, TestnetEvent, TestnetSwarm};
         use libp2p::identity::Keypair;
 
-        // Keypair generieren
-        let keypair = Keypair::generate_ed25519();
+        // Keypair generieren (deterministisch bei gesetztem Seed)
+        let keypair = match args.seed {
+            Some(seed) => deterministic_keypair(seed, args.node_index),
+            None => Keypair::generate_ed25519(),
+        };
         let peer_id = libp2p::PeerId::from(keypair.public());
 
         info!(peer_id = %peer_id, "🆔 Peer ID");
 
-        // P2P-Konfiguration erstellen
-        let mut config = P2PConfig::default();
+        // Testnet-Konfiguration aus Modus ableiten
+        let mut testnet_config = match args.mode.as_str() {
+            "client" => TestnetConfig::client(),
+            "nat-client" => TestnetConfig::nat_client(),
+            "high-privacy" => TestnetConfig::high_privacy(),
+            _ => TestnetConfig::relay(args.node_index),
+        };
+        // Start-Reihenfolge wird extern (Compose/Cluster) gesteuert
+        testnet_config.start_delay = std::time::Duration::ZERO;
+        testnet_config.rng_seed = args.seed;
+        let config = &mut testnet_config.p2p;
 
         // Listen-Adressen setzen
         config.listen_addresses = vec![
@@ -178,8 +246,11 @@ async fn main() -> anyhow::Result<()> {
             "⚙️  P2P configuration"
         );
 
-        // TestnetSwarm erstellen
-        let (mut swarm, event_rx) = TestnetSwarm::new(keypair, &config)?;
+        // TestnetSwarm erstellen und an den UnifiedState koppeln (P2P-Metriken)
+        let state = erynoa_api::core::create_unified_state();
+        let (swarm, event_rx) = TestnetSwarm::new(keypair, testnet_config)?;
+        let mut swarm = swarm.with_state(state.clone());
+        let commands = swarm.command_sender();
 
         info!(peer_id = %swarm.peer_id(), "✅ Testnet swarm created with full NAT-Traversal stack");
 
@@ -192,6 +263,7 @@ async fn main() -> anyhow::Result<()> {
                     TestnetEvent::PeerConnected {
                         peer_id,
                         is_inbound,
+                        ..
                     } => {
                         let peer_str = peer_id.to_string();
                         let mut peers = connected_peers_clone.write().await;
@@ -276,6 +348,17 @@ async fn main() -> anyhow::Result<()> {
                     TestnetEvent::ConnectionError { peer_id } => {
                         warn!(peer_id = ?peer_id, "❌ Connection error");
                     }
+                    TestnetEvent::Stats(stats) => {
+                        info!(
+                            peers = stats.connected_peers,
+                            routing_table = stats.routing_table_size,
+                            mesh = ?stats.mesh_peers,
+                            "📊 Testnet stats"
+                        );
+                    }
+                    other => {
+                        tracing::debug!(event = ?other, "Testnet event");
+                    }
                 }
             }
         });
@@ -296,6 +379,8 @@ async fn main() -> anyhow::Result<()> {
                 is_genesis,
                 peer_id_string,
                 connected_peers_api,
+                commands,
+                state,
             )
             .await
             {
@@ -306,9 +391,8 @@ async fn main() -> anyhow::Result<()> {
         info!(addr = %api_addr, "🌐 HTTP API server started");
 
         // Swarm starten
-        let config_clone = config.clone();
         let swarm_task = tokio::spawn(async move {
-            if let Err(e) = swarm.run(&config_clone).await {
+            if let Err(e) = swarm.run().await {
                 error!(error = %e, "Swarm error");
             }
         });
@@ -353,8 +437,11 @@ async fn start_api_server(
     is_genesis: bool,
     peer_id: String,
     connected_peers: ConnectedPeers,
+    commands: tokio::sync::mpsc::Sender<erynoa_api::peer::p2p::TestnetCommand>,
+    state: erynoa_api::core::SharedUnifiedState,
 ) -> anyhow::Result<()> {
     use axum::{routing::get, Json, Router};
+    use erynoa_api::peer::p2p::TestnetCommand;
     use std::time::Instant;
 
     let start_time = Instant::now();
@@ -368,13 +455,22 @@ async fn start_api_server(
                 let mode = mode.clone();
                 let peer_id = peer_id.clone();
                 let connected_peers = connected_peers.clone();
+                let commands = commands.clone();
                 move || {
                     let node_name = node_name.clone();
                     let mode = mode.clone();
                     let peer_id = peer_id.clone();
                     let connected_peers = connected_peers.clone();
+                    let commands = commands.clone();
                     async move {
                         let peers = connected_peers.read().await.clone();
+                        // Live-Status aus dem Swarm (Gossipsub, Kademlia, AutoNAT, Privacy)
+                        let (response, rx) = tokio::sync::oneshot::channel();
+                        let network =
+                            match commands.send(TestnetCommand::GetStatus { response }).await {
+                                Ok(()) => rx.await.ok(),
+                                Err(_) => None,
+                            };
                         let status = serde_json::json!({
                             "node_name": node_name,
                             "mode": mode,
@@ -384,6 +480,7 @@ async fn start_api_server(
                             "connected_peers": peers,
                             "uptime_secs": start_time.elapsed().as_secs(),
                             "version": env!("CARGO_PKG_VERSION"),
+                            "network": network,
                         });
                         Json(status)
                     }
@@ -405,6 +502,21 @@ async fn start_api_server(
                     }
                 }
             }),
+        )
+        .route(
+            "/api/v1/state/metrics",
+            get(move || {
+                let state = state.clone();
+                async move {
+                    (
+                        [(
+                            axum::http::header::CONTENT_TYPE,
+                            erynoa_api::api::v1::openmetrics::CONTENT_TYPE,
+                        )],
+                        erynoa_api::api::v1::state_handlers::render_state_metrics(&state.snapshot()),
+                    )
+                }
+            }),
         );
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
@@ -412,3 +524,124 @@ async fn start_api_server(
 
     Ok(())
 }
+
+/// Cluster-Modus: N Nodes lokal starten (in-process oder als Child-Prozesse)
+#[cfg(feature = "p2p")]
+async fn run_cluster(args: &Args) -> anyhow::Result<()> {
+    use erynoa_api::peer::p2p::{ClusterConfig, Scenario, TestnetCluster};
+
+    let seed = args.seed.unwrap_or(42);
+    let relays = args.cluster_relays.min(args.cluster_size);
+    let clients = args.cluster_size - relays;
+
+    info!(
+        size = args.cluster_size,
+        relays = relays,
+        seed = seed,
+        processes = args.cluster_processes,
+        "🧪 Starting testnet cluster"
+    );
+
+    if args.cluster_processes {
+        return run_cluster_processes(args, seed, relays).await;
+    }
+
+    let config = ClusterConfig::new(seed)
+        .with_base_port(args.p2p_port)
+        .with_relays(relays)
+        .with_clients(clients);
+    let mut cluster = TestnetCluster::launch(config).await?;
+
+    if let Some(path) = &args.scenario {
+        let scenario = Scenario::from_json(&std::fs::read_to_string(path)?)?;
+        let report = cluster.run_scenario(&scenario).await;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        cluster.shutdown().await;
+        if !report.passed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+    loop {
+        tokio::select! {
+            _ = signal::ctrl_c() => break,
+            _ = interval.tick() => {
+                for index in 0..cluster.len() {
+                    if let Ok(stats) = cluster.stats(index).await {
+                        info!(
+                            node = index,
+                            peers = stats.connected_peers,
+                            routing_table = stats.routing_table_size,
+                            mesh = ?stats.mesh_peers,
+                            "📊 Cluster node stats"
+                        );
+                    }
+                }
+                info!(converged = cluster.is_converged(), "🧪 Cluster convergence");
+            }
+        }
+    }
+
+    cluster.shutdown().await;
+    info!("👋 Cluster shut down");
+    Ok(())
+}
+
+/// Cluster als Child-Prozesse dieses Binaries starten
+///
+/// Node 0 ist Genesis; alle anderen bootstrappen gegen dessen vorab
+/// berechnete Adresse (deterministische Peer-ID aus Seed).
+#[cfg(feature = "p2p")]
+async fn run_cluster_processes(args: &Args, seed: u64, relays: usize) -> anyhow::Result<()> {
+    use erynoa_api::peer::p2p::cluster::deterministic_keypair;
+
+    let exe = env::current_exe()?;
+    let genesis_peer = libp2p::PeerId::from(deterministic_keypair(seed, 0).public());
+    let genesis_addr = format!("/ip4/127.0.0.1/tcp/{}/p2p/{}", args.p2p_port, genesis_peer);
+
+    let mut children = Vec::with_capacity(args.cluster_size);
+    for index in 0..args.cluster_size {
+        let (mode, name) = if index < relays {
+            ("relay", format!("relay{}", index))
+        } else {
+            ("client", format!("client{}", index - relays))
+        };
+        let mut command = tokio::process::Command::new(&exe);
+        command
+            .arg("--mode")
+            .arg(mode)
+            .arg("--node-name")
+            .arg(&name)
+            .arg("--node-index")
+            .arg(index.to_string())
+            .arg("--seed")
+            .arg(seed.to_string())
+            .arg("--p2p-port")
+            .arg((args.p2p_port + index as u16).to_string())
+            .arg("--api-port")
+            .arg((args.api_port + index as u16).to_string())
+            .arg("--enable-mdns")
+            .arg("false")
+            .arg("--data-dir")
+            .arg(format!("{}/{}", args.data_dir, name))
+            .kill_on_drop(true);
+        if index == 0 {
+            command.arg("--genesis-node").arg("true");
+        } else {
+            command.arg("--bootstrap-peers").arg(&genesis_addr);
+        }
+
+        let child = command.spawn()?;
+        info!(node = %name, pid = ?child.id(), "🧪 Spawned cluster node process");
+        children.push(child);
+    }
+
+    signal::ctrl_c().await?;
+    for child in &mut children {
+        let _ = child.kill().await;
+    }
+    info!("👋 Cluster processes stopped");
+    Ok(())
+}
@@ -8,17 +8,22 @@
 //! - **Storage**: Fjall (embedded KV-Store)
 //! - **Identität**: DID-basierte Auth (Ed25519)
 //! - **Cache**: In-Memory (kein externer Redis)
+//!
+//! Zur Laufzeit geänderte Dateien werden über [`reload`] neu geladen.
 
 pub mod constants;
+pub mod reload;
 pub mod version;
 
+pub use reload::{ChangeStatus, ConfigChange, ConfigReload, LiveConfig, ReloadError, ReloadOutcome};
 pub use version::{DESCRIPTION, NAME, VERSION};
 
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::convert::{TryFrom, TryInto};
+use std::path::Path;
 
 /// Hauptkonfiguration der Anwendung
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
     pub application: ApplicationSettings,
     /// Dezentraler Storage (Fjall)
@@ -30,9 +35,36 @@ pub struct Settings {
     /// P2P-Netzwerk-Konfiguration
     #[serde(default)]
     pub p2p: P2PSettings,
+    /// Session-Tokens nach Passkey-Login
+    #[serde(default)]
+    pub auth: AuthSettings,
+    /// Rate Limiting und Admission Control der API
+    #[serde(default)]
+    pub rate_limit: RateLimitSettings,
+    /// Logging und Tracing-Export
+    #[serde(default)]
+    pub telemetry: TelemetrySettings,
+    /// Admin-Listener und Node-Operatoren
+    #[serde(default)]
+    pub admin: AdminSettings,
+    /// WebSocket-Subscriptions (Limits pro Verbindung)
+    #[serde(default)]
+    pub subscriptions: SubscriptionSettings,
+    /// Idempotency-Keys schreibender Requests
+    #[serde(default)]
+    pub idempotency: IdempotencySettings,
+    /// Mana-Konten des Rate Limiters
+    #[serde(default)]
+    pub mana: ManaSettings,
+    /// Overrides der Weltformel-Parameter (Defaults aus `WorldFormulaConfig`)
+    #[serde(default)]
+    pub world_formula: WorldFormulaSettings,
+    /// Überwachung der Config-Dateien zur Laufzeit
+    #[serde(default)]
+    pub reload: ReloadSettings,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplicationSettings {
     pub host: String,
     pub port: u16,
@@ -48,7 +80,7 @@ pub struct ApplicationSettings {
 }
 
 /// Dezentraler Storage (Fjall embedded KV-Store)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageSettings {
     /// Datenverzeichnis für Fjall (Standard: ./data)
     #[serde(default = "default_data_dir")]
@@ -76,7 +108,7 @@ fn default_max_content_size() -> u64 {
 }
 
 /// Feature Flags für die Anwendung
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeatureFlags {
     /// Benutzer-Registrierung aktiviert
     #[serde(default = "default_true")]
@@ -104,7 +136,7 @@ impl Default for FeatureFlags {
 }
 
 /// P2P-Netzwerk-Konfiguration
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct P2PSettings {
     /// P2P-Port (Default: 4001)
     #[serde(default = "default_p2p_port")]
@@ -159,6 +191,498 @@ impl Default for P2PSettings {
     }
 }
 
+/// Session-Tokens (EdDSA-signiert) nach Passkey-Verifikation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthSettings {
+    /// `iss`-Claim der ausgestellten Tokens
+    #[serde(default = "default_auth_issuer")]
+    pub issuer: String,
+    /// `aud`-Claim (Standard-Audience der API)
+    #[serde(default = "default_auth_audience")]
+    pub audience: String,
+    /// Lebensdauer des Access-Tokens in Sekunden (Default: 15 min)
+    #[serde(default = "default_access_token_ttl")]
+    pub access_token_ttl_secs: u64,
+    /// Lebensdauer der Session/Refresh-Tokens in Sekunden (Default: 7 Tage)
+    #[serde(default = "default_refresh_token_ttl")]
+    pub refresh_token_ttl_secs: u64,
+    /// Capabilities, die ohne explizite Anfrage gewährt werden
+    #[serde(default = "default_auth_capabilities")]
+    pub default_capabilities: Vec<String>,
+    /// WebAuthn Relying-Party-ID (Hash muss im authenticatorData stehen)
+    #[serde(default = "default_rp_id")]
+    pub rp_id: String,
+    /// Erlaubte Origins im clientDataJSON
+    #[serde(default = "default_allowed_origins")]
+    pub allowed_origins: Vec<String>,
+    /// Gültigkeit einer Challenge in Sekunden (Default: 5 min)
+    #[serde(default = "default_challenge_ttl")]
+    pub challenge_ttl_secs: u64,
+    /// User-Verification-Flag (UV) erzwingen
+    #[serde(default = "default_false")]
+    pub require_user_verification: bool,
+    /// Assertions aus fremden iframes (`crossOrigin: true`) akzeptieren
+    #[serde(default = "default_false")]
+    pub allow_cross_origin: bool,
+}
+
+impl Default for AuthSettings {
+    fn default() -> Self {
+        Self {
+            issuer: default_auth_issuer(),
+            audience: default_auth_audience(),
+            access_token_ttl_secs: default_access_token_ttl(),
+            refresh_token_ttl_secs: default_refresh_token_ttl(),
+            default_capabilities: default_auth_capabilities(),
+            rp_id: default_rp_id(),
+            allowed_origins: default_allowed_origins(),
+            challenge_ttl_secs: default_challenge_ttl(),
+            require_user_verification: false,
+            allow_cross_origin: false,
+        }
+    }
+}
+
+/// Rate Limiting pro Client (DID, sonst IP)
+///
+/// Die Request-Quota gilt für den Tier `Newcomer` und wird mit dem
+/// `BandwidthTier` des Clients multipliziert. Zusätzlich kostet jede Route
+/// Mana (`ManaManager`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitSettings {
+    /// Rate Limiting aktiv
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Requests pro Minute für Tier `Newcomer` (Default: 120)
+    #[serde(default = "default_requests_per_minute")]
+    pub requests_per_minute: u64,
+    /// Mana-Kosten lesender Requests
+    #[serde(default = "default_read_cost")]
+    pub read_cost: u64,
+    /// Mana-Kosten schreibender Requests
+    #[serde(default = "default_write_cost")]
+    pub write_cost: u64,
+    /// Mana-Kosten teurer Routen (State-Events, Trust, Intents, Sagas, ECL)
+    #[serde(default = "default_expensive_cost")]
+    pub expensive_cost: u64,
+    /// Retry-After bei Lastabwurf im degradierten Modus (Sekunden)
+    #[serde(default = "default_shed_retry_after")]
+    pub shed_retry_after_secs: u64,
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            requests_per_minute: default_requests_per_minute(),
+            read_cost: default_read_cost(),
+            write_cost: default_write_cost(),
+            expensive_cost: default_expensive_cost(),
+            shed_retry_after_secs: default_shed_retry_after(),
+        }
+    }
+}
+
+/// Logging und OpenTelemetry-Export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetrySettings {
+    /// Log-Format auf stdout
+    #[serde(default)]
+    pub log_format: LogFormat,
+    /// OTLP/HTTP-Endpoint für Traces (z.B. `http://localhost:4318/v1/traces`);
+    /// nur mit Feature `otel` wirksam, leer = kein Export
+    #[serde(default)]
+    pub otlp_endpoint: Option<String>,
+    /// Anteil gesampelter Root-Traces (0.0 - 1.0); eingehende Trace-Contexts
+    /// übernehmen die Sampling-Entscheidung des Aufrufers
+    #[serde(default = "default_sample_ratio")]
+    pub sample_ratio: f64,
+}
+
+impl Default for TelemetrySettings {
+    fn default() -> Self {
+        Self {
+            log_format: LogFormat::default(),
+            otlp_endpoint: None,
+            sample_ratio: default_sample_ratio(),
+        }
+    }
+}
+
+/// Privilegierte Endpunkte (Rollen, Admin-Listener)
+///
+/// Node-Operatoren werden über ihre DID festgelegt. Routen, die nur über den
+/// Admin-Listener erreichbar sind (Replay, Checkpoints, Event-Injection),
+/// stehen ohne Admin-Listener nicht zur Verfügung.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminSettings {
+    /// Separaten Admin-Listener starten
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Bind-Adresse des Admin-Listeners (muss Loopback sein)
+    #[serde(default = "default_admin_host")]
+    pub host: String,
+    /// Port des Admin-Listeners (0 = zufällig)
+    #[serde(default = "default_admin_port")]
+    pub port: u16,
+    /// DIDs mit Rolle Node-Operator
+    #[serde(default)]
+    pub operators: Vec<String>,
+}
+
+impl Default for AdminSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            host: default_admin_host(),
+            port: default_admin_port(),
+            operators: Vec::new(),
+        }
+    }
+}
+
+impl AdminSettings {
+    /// Ist `host` eine Loopback-Adresse?
+    pub fn is_loopback(&self) -> bool {
+        self.host == "localhost"
+            || self
+                .host
+                .parse::<std::net::IpAddr>()
+                .is_ok_and(|ip| ip.is_loopback())
+    }
+}
+
+fn default_admin_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_admin_port() -> u16 {
+    3001
+}
+
+/// Limits des WebSocket-Endpunkts `/api/v1/subscribe`
+///
+/// Ist die Sende-Queue einer Verbindung voll, wird nicht weiter gelesen:
+/// Event-Subscriptions holen später aus dem Event-Log nach, Saga- und
+/// Delta-Subscriptions melden verworfene Nachrichten als `lagged`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionSettings {
+    /// Maximale Subscriptions pro Verbindung
+    #[serde(default = "default_max_subscriptions")]
+    pub max_subscriptions: usize,
+    /// Ausgehende Nachrichten, die pro Verbindung gepuffert werden
+    #[serde(default = "default_send_queue")]
+    pub send_queue: usize,
+    /// Maximale Events pro Nachhol-Durchlauf (Resume, Wake-up)
+    #[serde(default = "default_replay_batch")]
+    pub replay_batch: usize,
+}
+
+impl Default for SubscriptionSettings {
+    fn default() -> Self {
+        Self {
+            max_subscriptions: default_max_subscriptions(),
+            send_queue: default_send_queue(),
+            replay_batch: default_replay_batch(),
+        }
+    }
+}
+
+fn default_max_subscriptions() -> usize {
+    32
+}
+
+fn default_send_queue() -> usize {
+    256
+}
+
+fn default_replay_batch() -> usize {
+    100
+}
+
+/// Header `Idempotency-Key` für schreibende Requests
+///
+/// Erfolgreiche Antworten werden pro Aufrufer und Key für `ttl_secs`
+/// gespeichert und bei Wiederholung unverändert ausgeliefert. Gleichzeitige
+/// Wiederholungen warten bis zu `wait_secs` auf den ersten Request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdempotencySettings {
+    /// Header auswerten
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Aufbewahrung gespeicherter Antworten
+    #[serde(default = "default_idempotency_ttl_secs")]
+    pub ttl_secs: u64,
+    /// Wartezeit gleichzeitiger Wiederholungen, danach 409
+    #[serde(default = "default_idempotency_wait_secs")]
+    pub wait_secs: u64,
+}
+
+impl Default for IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl_secs: default_idempotency_ttl_secs(),
+            wait_secs: default_idempotency_wait_secs(),
+        }
+    }
+}
+
+fn default_idempotency_ttl_secs() -> u64 {
+    24 * 60 * 60
+}
+
+fn default_idempotency_wait_secs() -> u64 {
+    30
+}
+
+/// Mana-Konten pro Client (`ManaManager` des Rate Limiters)
+///
+/// `max_mana = base_allowance * (1 + trust.R * max_multiplier)`, regeneriert
+/// mit `base_regen_per_sec * (1 + trust.R * regen_trust_factor)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManaSettings {
+    /// Basis-Mana für alle Clients (auch Trust 0)
+    #[serde(default = "default_mana_base_allowance")]
+    pub base_allowance: u64,
+    /// Multiplikator bei Trust 1.0
+    #[serde(default = "default_mana_max_multiplier")]
+    pub max_multiplier: f64,
+    /// Regeneration pro Sekunde bei Trust 0
+    #[serde(default = "default_mana_base_regen")]
+    pub base_regen_per_sec: u64,
+    /// Beschleunigung der Regeneration bei Trust 1.0
+    #[serde(default = "default_mana_regen_trust_factor")]
+    pub regen_trust_factor: f64,
+}
+
+impl Default for ManaSettings {
+    fn default() -> Self {
+        Self {
+            base_allowance: default_mana_base_allowance(),
+            max_multiplier: default_mana_max_multiplier(),
+            base_regen_per_sec: default_mana_base_regen(),
+            regen_trust_factor: default_mana_regen_trust_factor(),
+        }
+    }
+}
+
+impl ManaSettings {
+    /// Konfiguration für den `ManaManager`
+    pub fn to_mana_config(&self) -> crate::eclvm::ManaConfig {
+        crate::eclvm::ManaConfig {
+            base_allowance: self.base_allowance,
+            max_multiplier: self.max_multiplier,
+            base_regen_per_sec: self.base_regen_per_sec,
+            regen_trust_factor: self.regen_trust_factor,
+            ..Default::default()
+        }
+    }
+}
+
+fn default_mana_base_allowance() -> u64 {
+    10_000
+}
+
+fn default_mana_max_multiplier() -> f64 {
+    100.0
+}
+
+fn default_mana_base_regen() -> u64 {
+    100
+}
+
+fn default_mana_regen_trust_factor() -> f64 {
+    10.0
+}
+
+/// Overrides einzelner Weltformel-Parameter
+///
+/// Nicht gesetzte Werte behalten die Defaults aus `WorldFormulaConfig`;
+/// [`WorldFormulaSettings::build`] validiert das Ergebnis.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorldFormulaSettings {
+    /// Lernrate positiver Trust-Updates (Κ4)
+    pub positive_delta: Option<f32>,
+    /// Negativity-Bias (Κ4)
+    pub negative_multiplier: Option<f32>,
+    /// Dämpfung pro Hop (Κ5)
+    pub hop_damping_factor: Option<f32>,
+    pub asymmetry_base: Option<f32>,
+    pub asymmetry_critical: Option<f32>,
+    /// Start-Trust neuer Identitäten
+    pub newcomer_trust: Option<f32>,
+    /// τ der Aktivität in Tagen (Κ15b)
+    pub activity_tau_days: Option<u32>,
+    pub activity_kappa: Option<u32>,
+    /// Temporaler Zerfall λ pro Tag
+    pub lambda_per_day: Option<f64>,
+    /// Gini-Schwelle der Anti-Calcification (Κ19)
+    pub gini_threshold: Option<f64>,
+    pub decay_rate: Option<f64>,
+    /// Penalty beim Realm-Crossing (Κ23)
+    pub realm_crossing_penalty: Option<f32>,
+}
+
+impl WorldFormulaSettings {
+    /// Defaults mit den gesetzten Overrides, validiert
+    pub fn build(
+        &self,
+    ) -> Result<crate::domain::unified::WorldFormulaConfig, crate::domain::unified::ConfigValidationError>
+    {
+        let mut builder = crate::domain::unified::WorldFormulaConfig::builder();
+        if let Some(v) = self.positive_delta {
+            builder = builder.positive_delta(v);
+        }
+        if let Some(v) = self.negative_multiplier {
+            builder = builder.negative_multiplier(v);
+        }
+        if let Some(v) = self.hop_damping_factor {
+            builder = builder.hop_damping_factor(v);
+        }
+        if let Some(v) = self.asymmetry_base {
+            builder = builder.asymmetry_base(v);
+        }
+        if let Some(v) = self.asymmetry_critical {
+            builder = builder.asymmetry_critical(v);
+        }
+        if let Some(v) = self.newcomer_trust {
+            builder = builder.newcomer_trust(v);
+        }
+        if let Some(v) = self.activity_tau_days {
+            builder = builder.activity_tau_days(v);
+        }
+        if let Some(v) = self.activity_kappa {
+            builder = builder.activity_kappa(v);
+        }
+        if let Some(v) = self.lambda_per_day {
+            builder = builder.lambda_per_day(v);
+        }
+        if let Some(v) = self.gini_threshold {
+            builder = builder.gini_threshold(v);
+        }
+        if let Some(v) = self.decay_rate {
+            builder = builder.decay_rate(v);
+        }
+        if let Some(v) = self.realm_crossing_penalty {
+            builder = builder.realm_crossing_penalty(v);
+        }
+        builder.build_validated()
+    }
+}
+
+/// Hot Reload der Config-Dateien
+///
+/// Geänderte Dateien in `config_dir` werden neu geladen und validiert; nur
+/// die in [`reload::LIVE_PATHS`] gelisteten Werte greifen ohne Neustart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReloadSettings {
+    /// Dateien periodisch auf Änderungen prüfen
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Verzeichnis mit `base.toml` und `<environment>.toml`
+    #[serde(default = "default_config_dir")]
+    pub config_dir: String,
+    /// Prüfintervall in Sekunden
+    #[serde(default = "default_reload_interval_secs")]
+    pub interval_secs: u64,
+}
+
+impl Default for ReloadSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            config_dir: default_config_dir(),
+            interval_secs: default_reload_interval_secs(),
+        }
+    }
+}
+
+fn default_config_dir() -> String {
+    "config".to_string()
+}
+
+fn default_reload_interval_secs() -> u64 {
+    5
+}
+
+/// Log-Format der fmt-Ausgabe
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Mehrzeilig, für Entwicklung
+    #[default]
+    Pretty,
+    /// Eine JSON-Zeile pro Event, für Log-Aggregation
+    Json,
+}
+
+fn default_sample_ratio() -> f64 {
+    1.0
+}
+
+fn default_requests_per_minute() -> u64 {
+    120
+}
+
+fn default_read_cost() -> u64 {
+    10
+}
+
+fn default_write_cost() -> u64 {
+    100
+}
+
+fn default_expensive_cost() -> u64 {
+    1_000
+}
+
+fn default_shed_retry_after() -> u64 {
+    30
+}
+
+fn default_auth_issuer() -> String {
+    "erynoa".to_string()
+}
+
+fn default_auth_audience() -> String {
+    "erynoa-api".to_string()
+}
+
+fn default_access_token_ttl() -> u64 {
+    900
+}
+
+fn default_refresh_token_ttl() -> u64 {
+    604_800
+}
+
+fn default_auth_capabilities() -> Vec<String> {
+    vec![
+        "read:*".to_string(),
+        "write:*".to_string(),
+        "execute:ecl".to_string(),
+        "execute:derive_key".to_string(),
+    ]
+}
+
+fn default_rp_id() -> String {
+    "localhost".to_string()
+}
+
+fn default_allowed_origins() -> Vec<String> {
+    vec![
+        "http://localhost:5173".to_string(),
+        "http://localhost:5174".to_string(),
+        "http://localhost:5175".to_string(),
+    ]
+}
+
+fn default_challenge_ttl() -> u64 {
+    300
+}
+
 fn default_p2p_port() -> u16 {
     4001
 }
@@ -230,7 +754,7 @@ where
 }
 
 /// Umgebungs-Typen
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Environment {
     Local,
@@ -275,15 +799,12 @@ impl Settings {
     /// Lädt die Konfiguration aus Environment und Config-Dateien
     pub fn load() -> Result<Self, config::ConfigError> {
         let base_path = std::env::current_dir().expect("Failed to get current directory");
-        let config_dir = base_path.join("config");
-
-        // Ermittle die aktuelle Umgebung
-        let environment: Environment = std::env::var("APP_ENVIRONMENT")
-            .unwrap_or_else(|_| "local".into())
-            .try_into()
-            .expect("Failed to parse APP_ENVIRONMENT");
+        Self::load_from(&base_path.join("config"))
+    }
 
-        let environment_filename = format!("{}.toml", environment.as_str());
+    /// Wie [`Settings::load`], mit den Config-Dateien aus `config_dir`
+    pub fn load_from(config_dir: &Path) -> Result<Self, config::ConfigError> {
+        let environment_filename = format!("{}.toml", Self::environment().as_str());
 
         let settings = config::Config::builder()
             // Basis-Konfiguration
@@ -301,4 +822,20 @@ impl Settings {
 
         settings.try_deserialize::<Settings>()
     }
+
+    /// Config-Dateien, die [`Settings::load_from`] liest (ggf. nicht vorhanden)
+    pub fn config_files(config_dir: &Path) -> Vec<std::path::PathBuf> {
+        vec![
+            config_dir.join("base.toml"),
+            config_dir.join(format!("{}.toml", Self::environment().as_str())),
+        ]
+    }
+
+    /// Aktuelle Umgebung aus `APP_ENVIRONMENT` (Default: local)
+    fn environment() -> Environment {
+        std::env::var("APP_ENVIRONMENT")
+            .unwrap_or_else(|_| "local".into())
+            .try_into()
+            .expect("Failed to parse APP_ENVIRONMENT")
+    }
 }
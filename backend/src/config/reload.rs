@@ -0,0 +1,492 @@
+//! Hot Reload der Konfiguration
+//!
+//! [`LiveConfig`] hält die wirksame Konfiguration des laufenden Nodes. Ein
+//! Reload validiert die neu geladenen [`Settings`] (Weltformel über den
+//! `WorldFormulaConfigBuilder`) und vergleicht sie pfadweise mit der
+//! wirksamen Konfiguration:
+//!
+//! - Pfade aus [`LIVE_PATHS`] werden sofort übernommen (`applied`)
+//! - Änderungen unter [`FIXED_PATHS`] werden abgelehnt (`rejected`)
+//! - alle anderen Änderungen greifen erst nach einem Neustart
+//!   (`restart_required`) und erscheinen bei jedem Reload erneut im Diff
+//! - ist die neue Konfiguration ungültig, wird nichts übernommen (`rejected`)
+//!
+//! `AppState::apply_config` gibt die Werte an die laufenden Subsysteme
+//! weiter: Rate Limiter, ECL-Service und `ProgrammableGateway` (über den
+//! `GatewayGuard`) übernehmen `mana`, das Trust-Gate `p2p.min_incoming_trust`.
+//! CORS und WebAuthn lesen ihre Werte pro Request.
+//!
+//! `world_formula` wird validiert, aber von keiner laufenden Komponente
+//! übernommen: `CalibrationEngine` und `ConsensusEngine` werden vom Node
+//! nicht betrieben. Eine geänderte Weltformel wird deshalb mit
+//! [`ReloadError::Fixed`] abgelehnt statt stillschweigend vorgemerkt. Die
+//! Konsens-Schwellen (`ConsensusConfig`) sind nicht Teil der [`Settings`].
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::Settings;
+use crate::domain::unified::{ConfigValidationError, WorldFormulaConfig};
+
+/// Pfade (und ihre Unterpfade), die ohne Neustart wirksam werden
+pub const LIVE_PATHS: &[&str] = &[
+    "application.console_url",
+    "auth.allowed_origins",
+    "auth.challenge_ttl_secs",
+    "auth.require_user_verification",
+    "auth.allow_cross_origin",
+    "p2p.min_incoming_trust",
+    "rate_limit",
+    "mana",
+];
+
+/// Pfade, deren Änderung zur Laufzeit abgelehnt wird (keine laufende
+/// Komponente übernimmt sie)
+pub const FIXED_PATHS: &[&str] = &["world_formula"];
+
+/// Wird `path` ohne Neustart wirksam?
+pub fn is_live(path: &str) -> bool {
+    matches_any(LIVE_PATHS, path)
+}
+
+/// Wird eine Änderung an `path` abgelehnt?
+pub fn is_fixed(path: &str) -> bool {
+    matches_any(FIXED_PATHS, path)
+}
+
+fn matches_any(paths: &[&str], path: &str) -> bool {
+    paths.iter().any(|prefix| {
+        path.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+/// Fehler beim Laden oder Validieren einer Konfiguration
+#[derive(Debug, thiserror::Error)]
+pub enum ReloadError {
+    #[error("config could not be loaded: {0}")]
+    Load(#[from] ::config::ConfigError),
+    #[error("invalid world_formula: {0}")]
+    WorldFormula(#[from] ConfigValidationError),
+    #[error("invalid {path}: {reason}")]
+    Invalid {
+        path: &'static str,
+        reason: &'static str,
+    },
+    #[error("{0} cannot be changed while the node is running")]
+    Fixed(String),
+}
+
+/// Wie wurde eine geänderte Einstellung behandelt?
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeStatus {
+    /// Wirksam seit diesem Reload
+    Applied,
+    /// Vorgemerkt, wirksam nach Neustart
+    RestartRequired,
+    /// Konfiguration ungültig, nichts übernommen
+    Rejected,
+}
+
+/// Geänderter Wert (Pfad in Punkt-Notation, z.B. `rate_limit.read_cost`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigChange {
+    pub path: String,
+    pub old: Value,
+    pub new: Value,
+    pub status: ChangeStatus,
+}
+
+/// Ergebnis eines Reloads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReloadOutcome {
+    /// Mindestens eine Änderung ist wirksam
+    Applied,
+    /// Nur Änderungen, die einen Neustart erfordern
+    Deferred,
+    /// Keine Abweichung zur wirksamen Konfiguration
+    Unchanged,
+    /// Laden oder Validierung fehlgeschlagen
+    Rejected,
+}
+
+/// Protokoll des letzten Reloads (`GET /api/v1/config/diff`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigReload {
+    /// Generation der wirksamen Konfiguration nach dem Reload
+    pub generation: u64,
+    /// Unix-Zeitstempel (Sekunden)
+    pub reloaded_at: i64,
+    pub outcome: ReloadOutcome,
+    pub changes: Vec<ConfigChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Prüft `settings` und liefert die daraus gebaute Weltformel-Konfiguration
+pub fn validate(settings: &Settings) -> Result<WorldFormulaConfig, ReloadError> {
+    let invalid = |path, reason| Err(ReloadError::Invalid { path, reason });
+
+    if !(0.0..=1.0).contains(&settings.p2p.min_incoming_trust) {
+        return invalid("p2p.min_incoming_trust", "must be within [0, 1]");
+    }
+    if settings.rate_limit.requests_per_minute == 0 {
+        return invalid("rate_limit.requests_per_minute", "must be positive");
+    }
+    if settings.mana.base_allowance == 0 {
+        return invalid("mana.base_allowance", "must be positive");
+    }
+    if !(0.0..).contains(&settings.mana.max_multiplier) {
+        return invalid("mana.max_multiplier", "must not be negative");
+    }
+    if !(0.0..).contains(&settings.mana.regen_trust_factor) {
+        return invalid("mana.regen_trust_factor", "must not be negative");
+    }
+    if !(0.0..=1.0).contains(&settings.telemetry.sample_ratio) {
+        return invalid("telemetry.sample_ratio", "must be within [0, 1]");
+    }
+    Ok(settings.world_formula.build()?)
+}
+
+/// Abweichende Pfade zwischen `old` und `new`, klassifiziert nach
+/// [`is_live`] und [`is_fixed`]
+pub fn diff(old: &Settings, new: &Settings) -> Vec<ConfigChange> {
+    let old = flatten(old);
+    let mut new = flatten(new);
+
+    let mut changes = Vec::new();
+    for (path, old_value) in old {
+        let new_value = new.remove(&path).unwrap_or(Value::Null);
+        if old_value != new_value {
+            changes.push(change(path, old_value, new_value));
+        }
+    }
+    for (path, new_value) in new {
+        changes.push(change(path, Value::Null, new_value));
+    }
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+fn change(path: String, old: Value, new: Value) -> ConfigChange {
+    let status = if is_live(&path) {
+        ChangeStatus::Applied
+    } else if is_fixed(&path) {
+        ChangeStatus::Rejected
+    } else {
+        ChangeStatus::RestartRequired
+    };
+    ConfigChange {
+        path,
+        old,
+        new,
+        status,
+    }
+}
+
+/// Blätter der JSON-Darstellung nach Pfad (Listen sind ein Blatt)
+fn flatten(settings: &Settings) -> BTreeMap<String, Value> {
+    fn walk(prefix: &str, value: Value, out: &mut BTreeMap<String, Value>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let path = if prefix.is_empty() {
+                        key
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    walk(&path, value, out);
+                }
+            }
+            leaf => {
+                out.insert(prefix.to_string(), leaf);
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    let value = serde_json::to_value(settings).expect("Settings serialisieren");
+    walk("", value, &mut out);
+    out
+}
+
+/// Übernimmt die Werte aus [`LIVE_PATHS`] von `candidate` in `effective`
+fn merge_live(effective: &mut Settings, candidate: &Settings) {
+    effective.application.console_url = candidate.application.console_url.clone();
+    effective.auth.allowed_origins = candidate.auth.allowed_origins.clone();
+    effective.auth.challenge_ttl_secs = candidate.auth.challenge_ttl_secs;
+    effective.auth.require_user_verification = candidate.auth.require_user_verification;
+    effective.auth.allow_cross_origin = candidate.auth.allow_cross_origin;
+    effective.p2p.min_incoming_trust = candidate.p2p.min_incoming_trust;
+    effective.rate_limit = candidate.rate_limit.clone();
+    effective.mana = candidate.mana.clone();
+}
+
+/// Wirksame Konfiguration des laufenden Nodes
+pub struct LiveConfig {
+    settings: RwLock<Arc<Settings>>,
+    world_formula: Arc<WorldFormulaConfig>,
+    generation: RwLock<u64>,
+    /// Serialisiert Reloads und hält das Protokoll des letzten
+    last_reload: Mutex<Option<ConfigReload>>,
+}
+
+impl LiveConfig {
+    /// Startkonfiguration (Generation 0)
+    ///
+    /// Ungültige Weltformel-Overrides fallen auf die Defaults zurück;
+    /// `Server::build` lehnt solche Konfigurationen vorher ab.
+    pub fn new(settings: Settings) -> Self {
+        let world_formula = settings.world_formula.build().unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Invalid world_formula, using defaults");
+            WorldFormulaConfig::default()
+        });
+        Self {
+            settings: RwLock::new(Arc::new(settings)),
+            world_formula: Arc::new(world_formula),
+            generation: RwLock::new(0),
+            last_reload: Mutex::new(None),
+        }
+    }
+
+    /// Aktuell wirksame Settings
+    pub fn current(&self) -> Arc<Settings> {
+        self.settings.read().unwrap().clone()
+    }
+
+    /// Weltformel-Parameter seit dem Start (Änderungen werden abgelehnt)
+    pub fn world_formula(&self) -> Arc<WorldFormulaConfig> {
+        self.world_formula.clone()
+    }
+
+    /// Anzahl übernommener Reloads seit dem Start
+    pub fn generation(&self) -> u64 {
+        *self.generation.read().unwrap()
+    }
+
+    /// Protokoll des letzten Reloads
+    pub fn last_reload(&self) -> Option<ConfigReload> {
+        self.last_reload.lock().unwrap().clone()
+    }
+
+    /// Validiert `candidate` und übernimmt die live änderbaren Werte
+    pub fn apply(&self, candidate: Settings) -> ConfigReload {
+        let mut last = self.last_reload.lock().unwrap();
+        let current = self.current();
+        let mut changes = diff(&current, &candidate);
+
+        let fixed = changes
+            .iter()
+            .find(|c| c.status == ChangeStatus::Rejected)
+            .map(|c| ReloadError::Fixed(c.path.clone()));
+        let validated = validate(&candidate).and_then(|_| fixed.map_or(Ok(()), Err));
+        let (outcome, error) = match validated {
+            Err(e) => {
+                for change in &mut changes {
+                    change.status = ChangeStatus::Rejected;
+                }
+                (ReloadOutcome::Rejected, Some(e.to_string()))
+            }
+            Ok(_) => {
+                if changes.iter().any(|c| c.status == ChangeStatus::Applied) {
+                    let mut next = (*current).clone();
+                    merge_live(&mut next, &candidate);
+                    *self.settings.write().unwrap() = Arc::new(next);
+                    *self.generation.write().unwrap() += 1;
+                    (ReloadOutcome::Applied, None)
+                } else if changes.is_empty() {
+                    (ReloadOutcome::Unchanged, None)
+                } else {
+                    (ReloadOutcome::Deferred, None)
+                }
+            }
+        };
+
+        let reload = self.record(outcome, changes, error);
+        *last = Some(reload.clone());
+        reload
+    }
+
+    /// Protokolliert einen Reload, dessen Konfiguration nicht geladen werden konnte
+    pub fn reject(&self, error: ReloadError) -> ConfigReload {
+        let mut last = self.last_reload.lock().unwrap();
+        let reload = self.record(ReloadOutcome::Rejected, Vec::new(), Some(error.to_string()));
+        *last = Some(reload.clone());
+        reload
+    }
+
+    fn record(
+        &self,
+        outcome: ReloadOutcome,
+        changes: Vec<ConfigChange>,
+        error: Option<String>,
+    ) -> ConfigReload {
+        ConfigReload {
+            generation: self.generation(),
+            reloaded_at: chrono::Utc::now().timestamp(),
+            outcome,
+            changes,
+            error,
+        }
+    }
+}
+
+/// Änderungszeitpunkte der Config-Dateien (fehlende Dateien als `None`)
+fn fingerprint(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| std::fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Prüft `files` alle `interval` und ruft `on_change` nach jeder Änderung
+pub fn watch_files(
+    files: Vec<PathBuf>,
+    interval: Duration,
+    on_change: impl Fn() + Send + 'static,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut seen = fingerprint(&files);
+        let mut ticker = tokio::time::interval(interval);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let current = fingerprint(&files);
+            if current != seen {
+                seen = current;
+                on_change();
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> Settings {
+        Settings::load().expect("config/base.toml")
+    }
+
+    #[test]
+    fn test_live_paths() {
+        assert!(is_live("rate_limit.read_cost"));
+        assert!(is_live("auth.allowed_origins"));
+        assert!(!is_live("world_formula.newcomer_trust"));
+        assert!(is_fixed("world_formula.newcomer_trust"));
+        assert!(!is_fixed("world_formulas"));
+        assert!(!is_live("auth.rp_id"));
+        assert!(!is_live("mana_extra"));
+        assert!(!is_live("application.port"));
+    }
+
+    #[test]
+    fn test_diff_classifies_changes() {
+        let old = settings();
+        let mut new = old.clone();
+        new.rate_limit.read_cost = 7;
+        new.application.port = 8080;
+        new.world_formula.newcomer_trust = Some(0.2);
+
+        let changes = diff(&old, &new);
+        let paths: Vec<_> = changes
+            .iter()
+            .map(|c| (c.path.as_str(), c.status))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("application.port", ChangeStatus::RestartRequired),
+                ("rate_limit.read_cost", ChangeStatus::Applied),
+                ("world_formula.newcomer_trust", ChangeStatus::Rejected),
+            ]
+        );
+        assert_eq!(changes[1].old, serde_json::json!(old.rate_limit.read_cost));
+        assert_eq!(changes[2].old, Value::Null);
+    }
+
+    #[test]
+    fn test_merge_live_covers_live_paths() {
+        let old = settings();
+        let mut new = old.clone();
+        new.application.console_url = "https://console.example".to_string();
+        new.auth.allowed_origins = vec!["https://console.example".to_string()];
+        new.auth.challenge_ttl_secs += 1;
+        new.auth.require_user_verification = !old.auth.require_user_verification;
+        new.auth.allow_cross_origin = !old.auth.allow_cross_origin;
+        new.p2p.min_incoming_trust = 0.5;
+        new.rate_limit.expensive_cost += 1;
+        new.mana.base_allowance += 1;
+
+        let mut effective = old.clone();
+        merge_live(&mut effective, &new);
+        assert!(diff(&effective, &new).is_empty());
+    }
+
+    #[test]
+    fn test_apply_keeps_restart_required_values() {
+        let live = LiveConfig::new(settings());
+        let mut candidate = settings();
+        candidate.rate_limit.requests_per_minute = 10;
+        candidate.admin.port = 4000;
+
+        let reload = live.apply(candidate.clone());
+        assert_eq!(reload.outcome, ReloadOutcome::Applied);
+        assert_eq!(reload.generation, 1);
+        assert_eq!(live.current().rate_limit.requests_per_minute, 10);
+        assert_ne!(live.current().admin.port, 4000);
+
+        // Vorgemerkte Änderung bleibt im Diff, bis neu gestartet wird
+        let reload = live.apply(candidate);
+        assert_eq!(reload.outcome, ReloadOutcome::Deferred);
+        assert_eq!(reload.generation, 1);
+        assert_eq!(reload.changes.len(), 1);
+        assert_eq!(reload.changes[0].path, "admin.port");
+    }
+
+    #[test]
+    fn test_apply_rejects_invalid_config() {
+        let live = LiveConfig::new(settings());
+        let mut candidate = settings();
+        candidate.rate_limit.read_cost = 1;
+        candidate.world_formula.positive_delta = Some(-1.0);
+
+        let reload = live.apply(candidate);
+        assert_eq!(reload.outcome, ReloadOutcome::Rejected);
+        assert!(reload.error.unwrap().starts_with("invalid world_formula"));
+        assert!(reload
+            .changes
+            .iter()
+            .all(|c| c.status == ChangeStatus::Rejected));
+        assert_eq!(live.generation(), 0);
+        assert_ne!(live.current().rate_limit.read_cost, 1);
+
+        // Gültige, aber nicht übernehmbare Weltformel: ganzer Reload abgelehnt
+        let mut candidate = settings();
+        candidate.rate_limit.read_cost = 1;
+        candidate.world_formula.newcomer_trust = Some(0.2);
+        let reload = live.apply(candidate);
+        assert_eq!(reload.outcome, ReloadOutcome::Rejected);
+        assert_eq!(
+            reload.error.as_deref(),
+            Some("world_formula.newcomer_trust cannot be changed while the node is running")
+        );
+        assert_eq!(live.generation(), 0);
+        assert_ne!(live.current().rate_limit.read_cost, 1);
+
+        let mut candidate = settings();
+        candidate.p2p.min_incoming_trust = 1.5;
+        let reload = live.apply(candidate);
+        assert_eq!(
+            reload.error.as_deref(),
+            Some("invalid p2p.min_incoming_trust: must be within [0, 1]")
+        );
+    }
+}
@@ -104,6 +104,7 @@ pub use state::{
     // Differential State Snapshots (Merkle)
     Hashable,
     IdentityViewData,
+    InFlightMap,
     IntentParserState,
     IntentParserSnapshot,
     KademliaState,
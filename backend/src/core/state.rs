@@ -761,7 +761,7 @@ pub struct CircuitBreakerSnapshot {
 // ============================================================================
 
 /// Grund für Trust-Änderung (für Audits und Debugging)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, utoipa::ToSchema)]
 pub enum TrustReason {
     /// Positive Interaktion (erfolgreiche Transaktion, gutes Verhalten)
     PositiveInteraction,
@@ -2110,6 +2110,27 @@ impl StateEventLog {
             .unwrap_or_default()
     }
 
+    /// Hole bis zu `limit` Events ab Sequenz `from_sequence` (inklusive)
+    ///
+    /// Für Resume-Cursor: liefert zusätzlich die älteste noch gepufferte
+    /// Sequenz, damit Aufrufer aus dem Ring-Buffer gefallene Events erkennen.
+    pub fn events_from(
+        &self,
+        from_sequence: u64,
+        limit: usize,
+    ) -> (Vec<WrappedStateEvent>, Option<u64>) {
+        self.buffer
+            .read()
+            .map(|b| {
+                let oldest = b.first().map(|e| e.sequence);
+                // Buffer ist nach Sequenz sortiert
+                let start = b.partition_point(|e| e.sequence < from_sequence);
+                let events = b[start..].iter().take(limit).cloned().collect();
+                (events, oldest)
+            })
+            .unwrap_or_default()
+    }
+
     /// Hole Events für Komponente
     pub fn events_for_component(&self, component: StateComponent) -> Vec<WrappedStateEvent> {
         self.buffer
@@ -7122,6 +7143,33 @@ pub struct IntentParserSnapshot {
 // REALM STATE LAYER - Per-Realm Isolation (Κ22-Κ24)
 // ============================================================================
 
+/// Konfigurationsänderung eines Realms
+///
+/// Payload von `StateEvent::RealmLifecycle` mit `RealmAction::ConfigChanged`
+/// (als JSON in `config`), z.B. aus angenommenen Governance-Proposals.
+/// Nur gesetzte Felder werden angewendet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RealmConfigChange {
+    /// Neuer Minimum-Trust
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_trust: Option<f32>,
+    /// Hinzuzufügende Regel-IDs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add_rules: Vec<String>,
+    /// Neuer Governance-Typ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub governance_type: Option<String>,
+    /// Zu deaktivierende ECL-Policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivate_policy: Option<String>,
+    /// Zu aktivierende ECL-Policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activate_policy: Option<String>,
+    /// Neue Quota-Limits
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quota_limits: Vec<(ResourceType, u64)>,
+}
+
 /// Per-Realm spezifischer State
 ///
 /// Jedes Realm hat seinen eigenen isolierten State mit:
@@ -7637,6 +7685,32 @@ impl RealmSpecificState {
         }
     }
 
+    /// Wendet eine Konfigurationsänderung an (`RealmAction::ConfigChanged`)
+    pub fn apply_config_change(&self, change: &RealmConfigChange) {
+        if let Some(min_trust) = change.min_trust {
+            if let Ok(mut current) = self.min_trust.write() {
+                *current = min_trust;
+            }
+        }
+        for rule_id in &change.add_rules {
+            self.add_rule(rule_id);
+        }
+        if let Some(governance_type) = &change.governance_type {
+            if let Ok(mut current) = self.governance_type.write() {
+                *current = governance_type.clone();
+            }
+        }
+        if let Some(policy) = &change.deactivate_policy {
+            self.deactivate_policy(policy);
+        }
+        if let Some(policy) = &change.activate_policy {
+            self.activate_policy(policy);
+        }
+        for (resource, limit) in &change.quota_limits {
+            self.set_quota_limit(*resource, *limit);
+        }
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // ISOLATION OPERATIONS
     // ─────────────────────────────────────────────────────────────────────────
@@ -8451,6 +8525,8 @@ pub struct PrivacyState {
     pub mixing_flushes: AtomicU64,
     /// Messages durch Mixing-Pool (v0.4.0)
     pub messages_mixed: AtomicU64,
+    /// Aktuell im Mixing-Pool gepufferte Messages
+    pub mixing_pool_size: AtomicUsize,
     /// Relay-Rotationen
     pub relay_rotations: AtomicU64,
     /// Trust-basierte Relay-Auswahl
@@ -8473,6 +8549,7 @@ impl PrivacyState {
             cover_traffic: AtomicU64::new(0),
             mixing_flushes: AtomicU64::new(0),
             messages_mixed: AtomicU64::new(0),
+            mixing_pool_size: AtomicUsize::new(0),
             relay_rotations: AtomicU64::new(0),
             trust_based_selections: AtomicU64::new(0),
             selection_failures: AtomicU64::new(0),
@@ -8492,6 +8569,7 @@ impl PrivacyState {
             cover_traffic: self.cover_traffic.load(Ordering::Relaxed),
             mixing_flushes: self.mixing_flushes.load(Ordering::Relaxed),
             messages_mixed: self.messages_mixed.load(Ordering::Relaxed),
+            mixing_pool_size: self.mixing_pool_size.load(Ordering::Relaxed),
             relay_rotations: self.relay_rotations.load(Ordering::Relaxed),
             trust_based_selections: self.trust_based_selections.load(Ordering::Relaxed),
             selection_failures: self.selection_failures.load(Ordering::Relaxed),
@@ -8518,6 +8596,7 @@ pub struct PrivacySnapshot {
     pub cover_traffic: u64,
     pub mixing_flushes: u64,
     pub messages_mixed: u64,
+    pub mixing_pool_size: usize,
     pub relay_rotations: u64,
     pub trust_based_selections: u64,
     pub selection_failures: u64,
@@ -8998,6 +9077,8 @@ pub struct APIState {
     pub requests_server_error: AtomicU64,
     /// Rate-Limited Requests (429)
     pub requests_rate_limited: AtomicU64,
+    /// Wegen degradiertem SystemMode abgewiesene Requests (503)
+    pub requests_shed: AtomicU64,
     /// Auth-Failed Requests (401/403)
     pub requests_auth_failed: AtomicU64,
 
@@ -9085,6 +9166,7 @@ impl APIState {
             requests_client_error: AtomicU64::new(0),
             requests_server_error: AtomicU64::new(0),
             requests_rate_limited: AtomicU64::new(0),
+            requests_shed: AtomicU64::new(0),
             requests_auth_failed: AtomicU64::new(0),
             avg_latency_us: RwLock::new(0.0),
             p95_latency_us: RwLock::new(0.0),
@@ -9168,6 +9250,12 @@ impl APIState {
         self.update_latency(latency_us);
     }
 
+    /// Request wegen Lastabwurf (degradierter SystemMode) abgewiesen
+    pub fn record_shed(&self, realm_id: Option<&str>) {
+        self.requests_shed.fetch_add(1, Ordering::Relaxed);
+        self.record_request(0, 503, 0, 0, realm_id);
+    }
+
     fn update_latency(&self, latency_us: u64) {
         if let Ok(mut history) = self.latency_history.write() {
             history.push(latency_us);
@@ -9244,6 +9332,7 @@ impl APIState {
             requests_client_error: self.requests_client_error.load(Ordering::Relaxed),
             requests_server_error: self.requests_server_error.load(Ordering::Relaxed),
             requests_rate_limited: self.requests_rate_limited.load(Ordering::Relaxed),
+            requests_shed: self.requests_shed.load(Ordering::Relaxed),
             requests_auth_failed: self.requests_auth_failed.load(Ordering::Relaxed),
             avg_latency_us: self.avg_latency_us.read().map(|v| *v).unwrap_or(0.0),
             p95_latency_us: self.p95_latency_us.read().map(|v| *v).unwrap_or(0.0),
@@ -9272,6 +9361,8 @@ pub struct APISnapshot {
     pub requests_client_error: u64,
     pub requests_server_error: u64,
     pub requests_rate_limited: u64,
+    #[serde(default)]
+    pub requests_shed: u64,
     pub requests_auth_failed: u64,
     pub avg_latency_us: f64,
     pub p95_latency_us: f64,
@@ -11439,11 +11530,28 @@ impl UnifiedState {
             // PEER + REALM EVENTS
             // ═══════════════════════════════════════════════════════════════════
             StateEvent::RealmLifecycle {
-                realm_id, action, ..
+                realm_id,
+                action,
+                config,
             } => match action {
                 RealmAction::Created => {
                     self.peer.realm.total_realms.fetch_add(1, Ordering::Relaxed);
                 }
+                RealmAction::ConfigChanged => {
+                    let change = config
+                        .as_deref()
+                        .and_then(|c| serde_json::from_str::<RealmConfigChange>(c).ok());
+                    if let Some(change) = change {
+                        if let Ok(realms) = self.peer.realm.realms.read() {
+                            if let Some(realm) = realms.get(realm_id) {
+                                realm.apply_config_change(&change);
+                            }
+                        }
+                        if let Some(policy) = &change.activate_policy {
+                            self.eclvm.register_policy_to_realm(realm_id, policy);
+                        }
+                    }
+                }
                 RealmAction::Destroyed => {
                     let _ = self.peer.realm.total_realms.fetch_update(
                         Ordering::SeqCst,
@@ -11454,17 +11562,14 @@ impl UnifiedState {
                 _ => {}
             },
 
-            StateEvent::MembershipChange {
-                realm_id, action, ..
-            } => match action {
-                MembershipAction::Joined => {
-                    self.peer.realm.identity_joined_realm(realm_id);
-                }
-                MembershipAction::Left | MembershipAction::Banned => {
-                    self.peer.realm.identity_left_realm(realm_id);
+            StateEvent::MembershipChange { realm_id, .. } => {
+                // Wie beim Replay: Member-Set per UniversalId, sonst nur Zähler
+                if let Ok(realms) = self.peer.realm.realms.read() {
+                    if let Some(realm) = realms.get(realm_id) {
+                        realm.apply_state_event(wrapped);
+                    }
                 }
-                _ => {}
-            },
+            }
 
             StateEvent::CrossingEvaluated { allowed, .. } => {
                 self.peer
@@ -13291,6 +13396,33 @@ mod tests {
         assert_eq!(snapshot.sequence, 5);
     }
 
+    #[test]
+    fn test_state_event_log_events_from() {
+        let log = StateEventLog::new_with_config(3, 100);
+        for i in 0..5 {
+            log.log(
+                StateEvent::EventProcessed {
+                    event_id: format!("evt_{}", i),
+                    depth: 1,
+                    parents_count: 0,
+                    triggers: vec![],
+                    validation_errors: false,
+                    processing_us: 100,
+                },
+                vec![],
+            );
+        }
+
+        // Ring-Buffer hält nur 2..=4
+        let (events, oldest) = log.events_from(0, 10);
+        assert_eq!(oldest, Some(2));
+        assert_eq!(events.iter().map(|e| e.sequence).collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        let (events, _) = log.events_from(3, 1);
+        assert_eq!(events.iter().map(|e| e.sequence).collect::<Vec<_>>(), vec![3]);
+        assert!(log.events_from(5, 10).0.is_empty());
+    }
+
     #[test]
     fn test_state_event_causality_tracking() {
         let log = StateEventLog::new();
@@ -17331,7 +17463,7 @@ mod tests_phase6_4 {
         );
 
         // Trust-Update sollte funktionieren
-        let result = handle.update_trust("did:test:bob", 0.1, TrustReason::Attestation);
+        let result = handle.update_trust("did:test:bob", 0.1, TrustReason::PositiveInteraction);
         assert!(matches!(result, MutationResult::Success));
 
         // Pending Event sollte vorhanden sein
@@ -17445,7 +17577,7 @@ mod tests_phase6_4 {
         );
 
         // Trust-Update
-        handle.update_trust("did:test:target", 0.05, TrustReason::PeerRecommendation);
+        handle.update_trust("did:test:target", 0.05, TrustReason::PositiveInteraction);
 
         // Commit
         let result = handle.commit();
@@ -17478,7 +17610,7 @@ mod tests_phase6_4 {
         );
 
         // Trust-Update
-        handle.update_trust("did:test:target", 0.1, TrustReason::DirectInteraction);
+        handle.update_trust("did:test:target", 0.1, TrustReason::PositiveInteraction);
 
         assert_eq!(handle.pending_events_count(), 1);
 
@@ -17515,7 +17647,7 @@ mod tests_phase6_4 {
 
             // Modifikation via Guard
             if let Some(h) = guard.handle() {
-                h.update_trust("did:test:target", 0.1, TrustReason::DirectInteraction);
+                h.update_trust("did:test:target", 0.1, TrustReason::PositiveInteraction);
             }
 
             // Guard wird dropped ohne commit → auto rollback
@@ -17567,7 +17699,7 @@ mod tests_phase6_4 {
         );
 
         // Erster Trust-Update verbraucht 100 Gas → sollte scheitern
-        let result = handle.update_trust("did:test:target", 0.1, TrustReason::DirectInteraction);
+        let result = handle.update_trust("did:test:target", 0.1, TrustReason::PositiveInteraction);
         assert!(matches!(
             result,
             MutationResult::BudgetExhausted(BudgetExhaustionReason::OutOfGas)
@@ -17690,7 +17822,7 @@ mod tests_phase6_4 {
         let eclvm_snapshot = state.eclvm.snapshot();
         assert_eq!(eclvm_snapshot.policies_executed, 5);
         assert_eq!(eclvm_snapshot.policies_passed, 3); // 0, 2, 4 = passed
-        assert_eq!(eclvm_snapshot.policies_failed, 2); // 1, 3 = failed
+        assert_eq!(eclvm_snapshot.policies_denied, 2); // 1, 3 = failed
 
         // Broadcaster sollte 5 Deltas gesendet haben
         let broadcaster_snapshot = state.broadcaster.snapshot();
@@ -17714,10 +17846,8 @@ mod tests_phase6_4 {
 
         let wrapped = state.log_and_apply(event, vec![]);
 
-        // Event sollte im Buffer sein
-        let events_since = state
-            .event_log
-            .events_since(wrapped.sequence.saturating_sub(1));
+        // Event sollte im Buffer sein (erstes Event hat Sequenz 0)
+        let (events_since, _) = state.event_log.events_from(wrapped.sequence, 10);
         assert!(!events_since.is_empty());
 
         // Das letzte Event sollte unser PolicyEvaluated sein
@@ -18344,55 +18474,64 @@ impl StorageServiceConfig {
 ///
 /// Verhindert, dass mehrere gleichzeitige Requests dasselbe Realm laden.
 /// Erste Request lädt, alle anderen warten auf das Ergebnis.
-type InFlightReceiver =
-    tokio::sync::oneshot::Receiver<Result<Arc<RealmSpecificState>, RealmLoadError>>;
-type InFlightSender = tokio::sync::oneshot::Sender<Result<Arc<RealmSpecificState>, RealmLoadError>>;
+type RealmLoadResult = Result<Arc<RealmSpecificState>, RealmLoadError>;
 
 /// In-Flight-Map für aktive Lade-Operationen
-#[derive(Debug, Default)]
-pub struct InFlightMap {
-    /// Realm-ID → Liste von wartenden Receivers
-    map: DashMap<String, Vec<InFlightSender>>,
+///
+/// Generisch über das Ergebnis `T`; neben Realm-Loads auch für
+/// Idempotency-Keys der API (gleichzeitige Wiederholungen warten auf die
+/// erste Ausführung).
+#[derive(Debug)]
+pub struct InFlightMap<T = RealmLoadResult> {
+    /// Key → Liste von wartenden Receivers (Eintrag vorhanden = in-flight)
+    map: DashMap<String, Vec<tokio::sync::oneshot::Sender<T>>>,
 }
 
 impl InFlightMap {
     /// Erstelle neue In-Flight-Map
     pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T> Default for InFlightMap<T> {
+    fn default() -> Self {
         Self {
             map: DashMap::new(),
         }
     }
+}
 
+impl<T: Clone> InFlightMap<T> {
     /// Prüfe ob Load bereits in-flight ist und registriere ggf. als Waiter
     ///
     /// # Returns
-    /// - `None`: Keine aktive Operation, Caller soll laden
+    /// - `None`: Keine aktive Operation, Caller soll laden (ist jetzt in-flight)
     /// - `Some(receiver)`: Aktive Operation, Caller wartet auf Ergebnis
-    pub fn try_register(&self, realm_id: &str) -> Option<InFlightReceiver> {
-        let mut entry = self.map.entry(realm_id.to_string()).or_default();
-        if entry.value().is_empty() {
-            // Erster Request - markiere als in-flight
-            None
-        } else {
-            // Bereits in-flight - registriere als Waiter
-            let (tx, rx) = tokio::sync::oneshot::channel();
-            entry.value_mut().push(tx);
-            Some(rx)
+    pub fn try_register(&self, key: &str) -> Option<tokio::sync::oneshot::Receiver<T>> {
+        match self.map.entry(key.to_string()) {
+            dashmap::mapref::entry::Entry::Vacant(entry) => {
+                // Erster Request - markiere als in-flight
+                entry.insert(Vec::new());
+                None
+            }
+            dashmap::mapref::entry::Entry::Occupied(mut entry) => {
+                // Bereits in-flight - registriere als Waiter
+                let (tx, rx) = tokio::sync::oneshot::channel();
+                entry.get_mut().push(tx);
+                Some(rx)
+            }
         }
     }
 
     /// Markiere Start einer Lade-Operation
-    pub fn start_load(&self, realm_id: &str) {
-        self.map.entry(realm_id.to_string()).or_default();
+    pub fn start_load(&self, key: &str) {
+        self.map.entry(key.to_string()).or_default();
     }
 
     /// Abschließen einer Lade-Operation und alle Waiter benachrichtigen
-    pub fn complete_load(
-        &self,
-        realm_id: &str,
-        result: Result<Arc<RealmSpecificState>, RealmLoadError>,
-    ) {
-        if let Some((_, waiters)) = self.map.remove(realm_id) {
+    pub fn complete_load(&self, key: &str, result: T) {
+        if let Some((_, waiters)) = self.map.remove(key) {
             for waiter in waiters {
                 // Ignoriere Fehler wenn Receiver dropped wurde
                 let _ = waiter.send(result.clone());
@@ -18400,6 +18539,11 @@ impl InFlightMap {
         }
     }
 
+    /// Ist für `key` eine Operation aktiv?
+    pub fn is_in_flight(&self, key: &str) -> bool {
+        self.map.contains_key(key)
+    }
+
     /// Anzahl aktiver In-Flight-Operationen
     pub fn active_count(&self) -> usize {
         self.map.len()
@@ -21267,9 +21411,25 @@ mod tests_phase7_1_storage_layer {
         let map = InFlightMap::new();
         // Erster Request sollte None zurückgeben (kein Waiter)
         let result = map.try_register("realm1");
-        // Nach try_register auf nicht-existierendem Entry wird ein leerer Vec angelegt
-        // aber da er leer ist, gibt try_register None zurück
+        // Nach try_register ist der Key in-flight (leere Waiter-Liste)
         assert!(result.is_none());
+        assert!(map.is_in_flight("realm1"));
+    }
+
+    #[test]
+    fn test_inflight_map_waiters_receive_result() {
+        let map: InFlightMap<u64> = InFlightMap::default();
+        assert!(map.try_register("key").is_none());
+        let mut first = map.try_register("key").expect("second caller waits");
+        let mut second = map.try_register("key").expect("third caller waits");
+        assert!(map.try_register("other").is_none());
+
+        map.complete_load("key", 42);
+        assert_eq!(first.try_recv(), Ok(42));
+        assert_eq!(second.try_recv(), Ok(42));
+        assert!(!map.is_in_flight("key"));
+        // Danach wieder frei
+        assert!(map.try_register("key").is_none());
     }
 
     // ─────────────────────────────────────────────────────────────────────────
@@ -12,7 +12,7 @@
 
 use super::state::{SharedUnifiedState, UnifiedSnapshot};
 use super::state_integration::StateIntegrator;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
@@ -407,7 +407,7 @@ impl StateCoordinator {
 // HEALTH REPORT
 // ============================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HealthStatus {
     Healthy,
     Degraded,
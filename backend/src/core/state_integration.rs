@@ -1329,6 +1329,9 @@ pub trait SwarmObserver: Send + Sync {
 
     /// Externe Adresse erkannt
     fn on_external_address(&self, address: &str);
+
+    /// Absolute Peer-Anzahl (periodischer Abgleich mit dem Swarm)
+    fn on_peer_count(&self, connected_peers: usize);
 }
 
 /// Gossipsub Observer
@@ -1353,6 +1356,9 @@ pub trait GossipObserver: Send + Sync {
 
     /// Duplicate message
     fn on_duplicate_message(&self, topic: &str);
+
+    /// Absoluter Mesh-Status (periodischer Abgleich mit Gossipsub)
+    fn on_mesh_status(&self, mesh_peers: usize, subscribed_topics: usize);
 }
 
 /// Kademlia DHT Observer
@@ -1389,6 +1395,9 @@ pub trait RelayObserver: Send + Sync {
 
     /// Bytes über Relay
     fn on_relay_bytes(&self, bytes: u64);
+
+    /// Absolute Anzahl aktiver Circuits (periodischer Abgleich)
+    fn on_circuits_active(&self, active: usize);
 }
 
 /// Privacy Layer Observer
@@ -1410,6 +1419,9 @@ pub trait PrivacyObserver: Send + Sync {
 
     /// Trust-basierte Auswahl
     fn on_trust_based_selection(&self, selected_peer: &str, trust: f64);
+
+    /// Absoluter Pool-Status (aktive Circuits, gepufferte Mixing-Messages)
+    fn on_pool_status(&self, circuits_active: usize, mixing_pool_size: usize);
 }
 
 // ============================================================================
@@ -3197,6 +3209,15 @@ impl SwarmObserver for StateIntegrator {
             }
         }
     }
+
+    fn on_peer_count(&self, connected_peers: usize) {
+        self.state
+            .p2p
+            .swarm
+            .connected_peers
+            .store(connected_peers, Ordering::Relaxed);
+        self.check_p2p_warnings();
+    }
 }
 
 // ============================================================================
@@ -3275,6 +3296,19 @@ impl GossipObserver for StateIntegrator {
             .duplicate_messages
             .fetch_add(1, Ordering::Relaxed);
     }
+
+    fn on_mesh_status(&self, mesh_peers: usize, subscribed_topics: usize) {
+        self.state
+            .p2p
+            .gossip
+            .mesh_peers
+            .store(mesh_peers, Ordering::Relaxed);
+        self.state
+            .p2p
+            .gossip
+            .subscribed_topics
+            .store(subscribed_topics, Ordering::Relaxed);
+    }
 }
 
 // ============================================================================
@@ -3398,6 +3432,14 @@ impl RelayObserver for StateIntegrator {
             .relay_bytes
             .fetch_add(bytes, Ordering::Relaxed);
     }
+
+    fn on_circuits_active(&self, active: usize) {
+        self.state
+            .p2p
+            .relay
+            .circuits_active
+            .store(active, Ordering::Relaxed);
+    }
 }
 
 // ============================================================================
@@ -3473,6 +3515,19 @@ impl PrivacyObserver for StateIntegrator {
             .trust_based_selections
             .fetch_add(1, Ordering::Relaxed);
     }
+
+    fn on_pool_status(&self, circuits_active: usize, mixing_pool_size: usize) {
+        self.state
+            .p2p
+            .privacy
+            .circuits_active
+            .store(circuits_active, Ordering::Relaxed);
+        self.state
+            .p2p
+            .privacy
+            .mixing_pool_size
+            .store(mixing_pool_size, Ordering::Relaxed);
+    }
 }
 
 // ============================================================================
@@ -5521,6 +5576,37 @@ mod tests {
         assert_eq!(snapshot.p2p.privacy.trust_based_selections, 1);
     }
 
+    #[test]
+    fn test_p2p_gauge_sync_overrides_counters() {
+        let state = create_unified_state();
+        let integrator = StateIntegrator::new(state.clone());
+
+        // Event-basierte Zähler driften (z.B. mehrere Verbindungen pro Peer)
+        integrator.on_peer_connected("peer-1", false, false);
+        integrator.on_peer_connected("peer-1", true, false);
+        integrator.on_peer_grafted("peer-1", "events/v1");
+        integrator.on_circuit_opened();
+        integrator.on_circuit_opened();
+
+        // Periodischer Abgleich setzt absolute Werte
+        integrator.on_peer_count(1);
+        integrator.on_mesh_status(4, 2);
+        integrator.on_routing_table_update(7);
+        integrator.on_circuits_active(1);
+        integrator.on_pool_status(3, 12);
+
+        let snapshot = state.snapshot();
+        assert_eq!(snapshot.p2p.swarm.connected_peers, 1);
+        assert_eq!(snapshot.p2p.swarm.inbound_connections, 1);
+        assert_eq!(snapshot.p2p.gossip.mesh_peers, 4);
+        assert_eq!(snapshot.p2p.gossip.subscribed_topics, 2);
+        assert_eq!(snapshot.p2p.kademlia.routing_table_size, 7);
+        assert_eq!(snapshot.p2p.relay.circuits_active, 1);
+        assert_eq!(snapshot.p2p.relay.circuits_served, 2);
+        assert_eq!(snapshot.p2p.privacy.circuits_active, 3);
+        assert_eq!(snapshot.p2p.privacy.mixing_pool_size, 12);
+    }
+
     #[test]
     fn test_warning_propagation() {
         let state = create_unified_state();
@@ -135,6 +135,7 @@ impl TrustEngine {
     }
 
     /// Κ4: Aktualisiere Trust basierend auf Event
+    #[tracing::instrument(name = "trust.process_event", skip_all, fields(author = %event.author))]
     pub fn process_event(&mut self, event: &Event) -> TrustResult<()> {
         self.initialize_trust(&event.author);
 
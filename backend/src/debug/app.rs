@@ -0,0 +1,462 @@
+//! Zustand des Debuggers und Tastatur-Steuerung
+//!
+//! Der Zustand wird ausschließlich über [`Update`]s aus den Hintergrund-Tasks
+//! verändert; Tastendrücke liefern [`Action`]s, die der Event-Loop gegen den
+//! Node ausführt. Snapshots und Events werden als `serde_json::Value` gehalten,
+//! damit der Debugger auch Nodes mit abweichender Version lesen kann.
+
+use std::collections::VecDeque;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Maximale Anzahl gehaltener Events im Event-Log
+pub const MAX_EVENTS: usize = 1000;
+/// Maximale Anzahl gehaltener Stream-Deltas
+pub const MAX_DELTAS: usize = 500;
+
+/// Ansichten des Debuggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Health,
+    Trust,
+    Events,
+    P2P,
+    Realms,
+    Eclvm,
+}
+
+impl Pane {
+    pub const ALL: [Pane; 6] = [
+        Pane::Health,
+        Pane::Trust,
+        Pane::Events,
+        Pane::P2P,
+        Pane::Realms,
+        Pane::Eclvm,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Pane::Health => "Health",
+            Pane::Trust => "Trust",
+            Pane::Events => "Events",
+            Pane::P2P => "P2P",
+            Pane::Realms => "Realms",
+            Pane::Eclvm => "ECLVM",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|p| *p == self).unwrap_or(0)
+    }
+
+    fn offset(self, delta: isize) -> Pane {
+        let len = Self::ALL.len() as isize;
+        Self::ALL[(self.index() as isize + delta).rem_euclid(len) as usize]
+    }
+}
+
+/// Aktion gegen den Node, ausgelöst per Tastatur
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Refresh,
+    /// `POST /state/mode` (Normal, Degraded, EmergencyShutdown)
+    SetMode(&'static str),
+    /// `POST /state/mode/reset`
+    ResetMode,
+    /// `POST /debug/checkpoint`
+    Checkpoint,
+    /// `POST /debug/replay/checkpoint`
+    ReplayCheckpoint,
+}
+
+impl Action {
+    /// Eingriffe, die vor dem Ausführen mit `y` bestätigt werden müssen
+    fn needs_confirmation(&self) -> bool {
+        matches!(
+            self,
+            Action::SetMode("EmergencyShutdown") | Action::ReplayCheckpoint
+        )
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Action::SetMode(mode) => format!("switch node to {}", mode),
+            Action::ReplayCheckpoint => "replay events since last checkpoint".to_string(),
+            other => format!("{:?}", other),
+        }
+    }
+}
+
+/// Ergebnis der Hintergrund-Tasks (Polling, Stream, Aktionen)
+#[derive(Debug)]
+pub enum Update {
+    Snapshot(Value),
+    Events(Vec<Value>),
+    Delta(DeltaEntry),
+    Stream { connected: bool },
+    Info(String),
+    Error(String),
+}
+
+/// Eintrag aus dem Event-Log (`/events`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventEntry {
+    pub sequence: u64,
+    pub timestamp_ms: u64,
+    pub component: String,
+    /// Variante des `StateEvent` (z. B. `PolicyEvaluated`)
+    pub kind: String,
+    pub payload: Value,
+}
+
+impl EventEntry {
+    /// Liest ein `WrappedStateEvent`; `None` bei fehlender Sequenz
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let sequence = value.get("sequence")?.as_u64()?;
+        let (kind, payload) = match value.get("event") {
+            Some(Value::Object(map)) if map.len() == 1 => {
+                let (kind, payload) = map.iter().next()?;
+                (kind.clone(), payload.clone())
+            }
+            Some(Value::String(kind)) => (kind.clone(), Value::Null),
+            Some(other) => ("Unknown".to_string(), other.clone()),
+            None => ("Unknown".to_string(), Value::Null),
+        };
+        Some(Self {
+            sequence,
+            timestamp_ms: value
+                .get("timestamp_ms")
+                .and_then(Value::as_u64)
+                .unwrap_or(0),
+            component: value.get("component").map(value_label).unwrap_or_default(),
+            kind,
+            payload,
+        })
+    }
+
+    /// Case-insensitiver Teilstring-Match auf Komponente, Typ und Payload
+    pub fn matches(&self, filter: &str) -> bool {
+        if filter.is_empty() {
+            return true;
+        }
+        let filter = filter.to_lowercase();
+        self.component.to_lowercase().contains(&filter)
+            || self.kind.to_lowercase().contains(&filter)
+            || self.payload.to_string().to_lowercase().contains(&filter)
+    }
+}
+
+/// Delta aus `/state/stream` (ohne die serialisierten Nutzdaten)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DeltaEntry {
+    pub sequence: u64,
+    #[serde(deserialize_with = "deserialize_label")]
+    pub component: String,
+    #[serde(deserialize_with = "deserialize_label")]
+    pub delta_type: String,
+    pub timestamp_ms: u64,
+    #[serde(default)]
+    pub realm_id: Option<String>,
+}
+
+fn deserialize_label<'de, D: serde::Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    Value::deserialize(d).map(|v| value_label(&v))
+}
+
+/// Enum-Werte als Text (Unit-Varianten sind Strings, sonst erster Key)
+fn value_label(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Object(map) => map.keys().next().cloned().unwrap_or_default(),
+        other => other.to_string(),
+    }
+}
+
+/// Statusmeldung in der Fußzeile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Status {
+    pub message: String,
+    pub error: bool,
+}
+
+/// Gesamter UI-Zustand
+#[derive(Debug)]
+pub struct App {
+    pub node_url: String,
+    pub pane: Pane,
+    pub snapshot: Option<Value>,
+    pub events: VecDeque<EventEntry>,
+    pub deltas: VecDeque<DeltaEntry>,
+    pub deltas_received: u64,
+    pub stream_connected: bool,
+    pub filter: String,
+    pub editing_filter: bool,
+    /// Scroll-Offset im Event-Log (0 = neueste Events)
+    pub scroll: usize,
+    pub status: Option<Status>,
+    pub pending: Option<Action>,
+}
+
+impl App {
+    pub fn new(node_url: impl Into<String>) -> Self {
+        Self {
+            node_url: node_url.into(),
+            pane: Pane::Health,
+            snapshot: None,
+            events: VecDeque::new(),
+            deltas: VecDeque::new(),
+            deltas_received: 0,
+            stream_connected: false,
+            filter: String::new(),
+            editing_filter: false,
+            scroll: 0,
+            status: None,
+            pending: None,
+        }
+    }
+
+    pub fn apply(&mut self, update: Update) {
+        match update {
+            Update::Snapshot(snapshot) => self.snapshot = Some(snapshot),
+            Update::Events(events) => self.merge_events(&events),
+            Update::Delta(delta) => {
+                self.deltas_received += 1;
+                self.deltas.push_front(delta);
+                self.deltas.truncate(MAX_DELTAS);
+            }
+            Update::Stream { connected } => self.stream_connected = connected,
+            Update::Info(message) => self.set_status(message, false),
+            Update::Error(message) => self.set_status(message, true),
+        }
+    }
+
+    /// Übernimmt neue Events; bekannte Sequenzen werden übersprungen
+    fn merge_events(&mut self, events: &[Value]) {
+        let newest = self.events.front().map(|e| e.sequence);
+        let mut fresh: Vec<EventEntry> = events
+            .iter()
+            .filter_map(EventEntry::from_value)
+            .filter(|e| newest.is_none_or(|n| e.sequence > n))
+            .collect();
+        fresh.sort_by_key(|e| e.sequence);
+        for event in fresh {
+            self.events.push_front(event);
+        }
+        self.events.truncate(MAX_EVENTS);
+    }
+
+    /// Höchste bekannte Event-Sequenz (für inkrementelles Polling)
+    pub fn last_sequence(&self) -> Option<u64> {
+        self.events.front().map(|e| e.sequence)
+    }
+
+    /// Events nach aktuellem Filter, neueste zuerst
+    pub fn filtered_events(&self) -> impl Iterator<Item = &EventEntry> {
+        self.events.iter().filter(|e| e.matches(&self.filter))
+    }
+
+    /// Wert aus dem Snapshot per JSON-Pointer (z. B. `/core/trust/avg_trust`)
+    pub fn value(&self, pointer: &str) -> Option<&Value> {
+        self.snapshot.as_ref()?.pointer(pointer)
+    }
+
+    fn set_status(&mut self, message: String, error: bool) {
+        self.status = Some(Status { message, error });
+    }
+
+    /// Verarbeitet einen Tastendruck; liefert ggf. eine auszuführende Aktion
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Some(Action::Quit);
+        }
+
+        if let Some(action) = self.pending.take() {
+            if key.code == KeyCode::Char('y') {
+                return Some(action);
+            }
+            self.set_status("cancelled".to_string(), false);
+            return None;
+        }
+
+        if self.editing_filter {
+            match key.code {
+                KeyCode::Enter => self.editing_filter = false,
+                KeyCode::Esc => {
+                    self.editing_filter = false;
+                    self.filter.clear();
+                }
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                }
+                KeyCode::Char(c) => self.filter.push(c),
+                _ => {}
+            }
+            self.scroll = 0;
+            return None;
+        }
+
+        let action = match key.code {
+            KeyCode::Char('q') => Action::Quit,
+            KeyCode::Char('r') => Action::Refresh,
+            KeyCode::Char('n') => Action::SetMode("Normal"),
+            KeyCode::Char('d') => Action::SetMode("Degraded"),
+            KeyCode::Char('E') => Action::SetMode("EmergencyShutdown"),
+            KeyCode::Char('x') => Action::ResetMode,
+            KeyCode::Char('c') => Action::Checkpoint,
+            KeyCode::Char('p') => Action::ReplayCheckpoint,
+            KeyCode::Tab | KeyCode::Right => {
+                self.pane = self.pane.offset(1);
+                return None;
+            }
+            KeyCode::BackTab | KeyCode::Left => {
+                self.pane = self.pane.offset(-1);
+                return None;
+            }
+            KeyCode::Char(c @ '1'..='6') => {
+                self.pane = Pane::ALL[c as usize - '1' as usize];
+                return None;
+            }
+            KeyCode::Char('/') => {
+                self.pane = Pane::Events;
+                self.editing_filter = true;
+                return None;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.scroll = self.scroll.saturating_add(1);
+                return None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll = self.scroll.saturating_sub(1);
+                return None;
+            }
+            KeyCode::Esc => {
+                self.filter.clear();
+                self.scroll = 0;
+                return None;
+            }
+            _ => return None,
+        };
+
+        if action.needs_confirmation() {
+            self.set_status(format!("{}? press y to confirm", action.describe()), true);
+            self.pending = Some(action);
+            return None;
+        }
+        Some(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn event(sequence: u64, body: Value) -> Value {
+        json!({
+            "id": format!("ev{}", sequence),
+            "timestamp_ms": 1_700_000_000_000u64 + sequence,
+            "parent_ids": [],
+            "component": "ECLVM",
+            "sequence": sequence,
+            "event": body,
+            "signature": null,
+        })
+    }
+
+    #[test]
+    fn test_events_merge_and_filter() {
+        let mut app = App::new("http://localhost:3000");
+        app.apply(Update::Events(vec![
+            event(
+                1,
+                json!({"PolicyEvaluated": {"policy_id": "membership", "passed": true}}),
+            ),
+            event(
+                2,
+                json!({"ExecutionCompleted": {"context_id": "ctx-9", "success": false}}),
+            ),
+        ]));
+        // Überlappendes Polling liefert bekannte Sequenzen erneut
+        app.apply(Update::Events(vec![
+            event(2, json!({"ExecutionCompleted": {"context_id": "ctx-9"}})),
+            event(3, json!("CheckpointCreated")),
+        ]));
+
+        let sequences: Vec<u64> = app.events.iter().map(|e| e.sequence).collect();
+        assert_eq!(sequences, vec![3, 2, 1]);
+        assert_eq!(app.last_sequence(), Some(3));
+        assert_eq!(app.events[0].kind, "CheckpointCreated");
+
+        app.filter = "CTX-9".to_string();
+        let filtered: Vec<u64> = app.filtered_events().map(|e| e.sequence).collect();
+        assert_eq!(filtered, vec![2]);
+
+        app.filter = "eclvm".to_string();
+        assert_eq!(app.filtered_events().count(), 3);
+    }
+
+    #[test]
+    fn test_delta_from_stream_json() {
+        let delta: DeltaEntry = serde_json::from_str(
+            r#"{"sequence":4,"component":"Trust","delta_type":"Update","data":[1,2],"timestamp_ms":9,"realm_id":null}"#,
+        )
+        .unwrap();
+        assert_eq!(delta.component, "Trust");
+        assert_eq!(delta.delta_type, "Update");
+
+        let mut app = App::new("http://localhost:3000");
+        app.apply(Update::Delta(delta));
+        assert_eq!(app.deltas_received, 1);
+    }
+
+    #[test]
+    fn test_dangerous_actions_need_confirmation() {
+        let mut app = App::new("http://localhost:3000");
+        assert_eq!(app.handle_key(key(KeyCode::Char('E'))), None);
+        assert_eq!(
+            app.handle_key(key(KeyCode::Char('y'))),
+            Some(Action::SetMode("EmergencyShutdown"))
+        );
+
+        assert_eq!(app.handle_key(key(KeyCode::Char('p'))), None);
+        assert_eq!(app.handle_key(key(KeyCode::Char('n'))), None);
+        assert!(app.pending.is_none());
+
+        assert_eq!(
+            app.handle_key(key(KeyCode::Char('d'))),
+            Some(Action::SetMode("Degraded"))
+        );
+        assert_eq!(
+            app.handle_key(key(KeyCode::Char('c'))),
+            Some(Action::Checkpoint)
+        );
+    }
+
+    #[test]
+    fn test_filter_editing_and_pane_navigation() {
+        let mut app = App::new("http://localhost:3000");
+        app.handle_key(key(KeyCode::BackTab));
+        assert_eq!(app.pane, Pane::Eclvm);
+        app.handle_key(key(KeyCode::Char('2')));
+        assert_eq!(app.pane, Pane::Trust);
+
+        app.handle_key(key(KeyCode::Char('/')));
+        assert_eq!(app.pane, Pane::Events);
+        for c in "qx".chars() {
+            assert_eq!(app.handle_key(key(KeyCode::Char(c))), None);
+        }
+        app.handle_key(key(KeyCode::Backspace));
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.filter, "q");
+        assert!(!app.editing_filter);
+        assert_eq!(app.handle_key(key(KeyCode::Char('q'))), Some(Action::Quit));
+    }
+}
@@ -0,0 +1,182 @@
+//! HTTP-Client für die Debug-Endpunkte eines laufenden Nodes
+//!
+//! - `GET /state/snapshot` und `GET /events` werden gepollt
+//! - `GET /state/stream` liefert State-Deltas per SSE (mit Reconnect)
+//! - Aktionen: `/state/mode`, `/state/mode/reset`, `/debug/checkpoint`,
+//!   `/debug/replay/checkpoint`
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::app::{Action, DeltaEntry, Update};
+use super::sse::SseParser;
+
+/// Anzahl Events beim ersten Abruf des Event-Logs
+const INITIAL_EVENTS: u32 = 200;
+/// Wartezeit vor einem erneuten Verbindungsversuch zum Stream
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// Client für `<node>/api/v1`
+#[derive(Debug, Clone)]
+pub struct NodeClient {
+    http: reqwest::Client,
+    base: String,
+    token: Option<String>,
+}
+
+impl NodeClient {
+    /// `node_url` ohne API-Präfix, z. B. `http://127.0.0.1:3000`
+    pub fn new(node_url: &str, token: Option<String>) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(5))
+            .build()
+            .context("failed to build HTTP client")?;
+        Ok(Self {
+            http,
+            base: format!("{}/api/v1", node_url.trim_end_matches('/')),
+            token,
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self
+            .http
+            .request(method, format!("{}{}", self.base, path))
+            .timeout(Duration::from_secs(10));
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<Value> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = body
+                .get("message")
+                .or_else(|| body.get("error"))
+                .and_then(Value::as_str)
+                .unwrap_or_else(|| status.canonical_reason().unwrap_or("request failed"));
+            bail!("{} {}", status.as_u16(), message);
+        }
+        Ok(body)
+    }
+
+    /// `GET /state/snapshot`
+    pub async fn snapshot(&self) -> Result<Value> {
+        self.send(self.request(reqwest::Method::GET, "/state/snapshot"))
+            .await
+    }
+
+    /// `GET /events` – ab `since_sequence` oder die letzten Events, aufsteigend
+    pub async fn events(&self, since_sequence: Option<u64>) -> Result<Vec<Value>> {
+        let query = match since_sequence {
+            Some(since) => format!("?since_sequence={}&order=asc&limit=1000", since),
+            None => format!("?order=desc&limit={}", INITIAL_EVENTS),
+        };
+        let body = self
+            .send(self.request(reqwest::Method::GET, &format!("/events{}", query)))
+            .await?;
+        let mut events = match body.get("items") {
+            Some(Value::Array(events)) => events.clone(),
+            _ => bail!("unexpected /events response: {}", body),
+        };
+        if since_sequence.is_none() {
+            events.reverse();
+        }
+        Ok(events)
+    }
+
+    /// Führt eine Aktion aus und liefert eine Statusmeldung
+    pub async fn execute(&self, action: &Action) -> Result<String> {
+        use reqwest::Method;
+
+        match action {
+            Action::SetMode(mode) => {
+                let body = self
+                    .send(
+                        self.request(Method::POST, "/state/mode")
+                            .json(&json!({ "mode": mode })),
+                    )
+                    .await?;
+                Ok(format!("mode: {}", body["mode"].as_str().unwrap_or("?")))
+            }
+            Action::ResetMode => {
+                let body = self
+                    .send(self.request(Method::POST, "/state/mode/reset"))
+                    .await?;
+                Ok(format!(
+                    "circuit breaker reset, mode: {}",
+                    body["mode"].as_str().unwrap_or("?")
+                ))
+            }
+            Action::Checkpoint => {
+                let body = self
+                    .send(self.request(Method::POST, "/debug/checkpoint"))
+                    .await?;
+                Ok(format!(
+                    "checkpoint {} at sequence {}",
+                    body["checkpoint_id"].as_str().unwrap_or("?"),
+                    body["sequence"]
+                ))
+            }
+            Action::ReplayCheckpoint => {
+                let body = self
+                    .send(self.request(Method::POST, "/debug/replay/checkpoint"))
+                    .await?;
+                Ok(format!(
+                    "replayed {} events ({}..{})",
+                    body["events_replayed"], body["from_sequence"], body["to_sequence"]
+                ))
+            }
+            Action::Quit | Action::Refresh => Ok(String::new()),
+        }
+    }
+
+    /// Folgt `/state/stream`, bis der Empfänger geschlossen wird
+    ///
+    /// Verbindungsabbrüche werden gemeldet und nach [`RECONNECT_DELAY`]
+    /// erneut versucht.
+    pub async fn follow_stream(self, tx: UnboundedSender<Update>) {
+        loop {
+            let result = self.stream_once(&tx).await;
+            if tx.send(Update::Stream { connected: false }).is_err() {
+                return;
+            }
+            if let Err(err) = result {
+                let _ = tx.send(Update::Error(format!("stream: {:#}", err)));
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    async fn stream_once(&self, tx: &UnboundedSender<Update>) -> Result<()> {
+        let mut response = self
+            .http
+            .get(format!("{}/state/stream", self.base))
+            .header(reqwest::header::ACCEPT, "text/event-stream");
+        if let Some(token) = &self.token {
+            response = response.bearer_auth(token);
+        }
+        let mut response = response.send().await?.error_for_status()?;
+        let _ = tx.send(Update::Stream { connected: true });
+
+        let mut parser = SseParser::new();
+        while let Some(chunk) = response.chunk().await? {
+            for data in parser.push(&chunk) {
+                // Unbekannte Deltas (andere Node-Version) werden übersprungen
+                if let Ok(delta) = serde_json::from_str::<DeltaEntry>(&data) {
+                    if tx.send(Update::Delta(delta)).is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        bail!("stream closed by node")
+    }
+}
@@ -0,0 +1,176 @@
+//! # Erynoa TUI Debugger
+//!
+//! Terminal-Cockpit, das sich per HTTP an einen laufenden Node hängt.
+//! Läuft vollständig im Terminal (crossterm) und damit auch per SSH auf
+//! Headless-Servern.
+//!
+//! ## Datenquellen
+//!
+//! - `GET /api/v1/state/snapshot` – periodisch gepollt
+//! - `GET /api/v1/state/stream` – State-Deltas per SSE
+//! - `GET /api/v1/events` – Event-Log, inkrementell per `since_sequence`
+//!
+//! ## Ansichten
+//!
+//! Health & Warnings, Trust-Verteilung, Event-Log (filterbar), P2P-Peers &
+//! Privacy-Circuits, Realm-Quotas, ECLVM-Ausführungen.
+//!
+//! ## Aktionen
+//!
+//! | Taste | Endpunkt |
+//! |-------|----------|
+//! | `n` / `d` / `E` | `POST /state/mode` (Normal, Degraded, EmergencyShutdown) |
+//! | `x` | `POST /state/mode/reset` |
+//! | `c` | `POST /debug/checkpoint` |
+//! | `p` | `POST /debug/replay/checkpoint` |
+//!
+//! `E` und `p` müssen mit `y` bestätigt werden.
+//!
+//! Aktionen erfordern ein Session-Token eines Node-Betreibers
+//! (`admin.operators`, Capability `execute:admin`); Debug-Endpunkte gibt es nur
+//! auf dem Admin-Listener, daher verbindet sich der Debugger standardmäßig dort.
+
+mod app;
+mod client;
+mod sse;
+mod ui;
+
+pub use app::{Action, App, DeltaEntry, EventEntry, Pane, Update};
+pub use client::NodeClient;
+pub use sse::SseParser;
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use clap::Parser;
+use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+/// Kommandozeile von `erynoa-debug`
+#[derive(Debug, Parser)]
+#[command(name = "erynoa-debug")]
+#[command(about = "Terminal debugger for a running Erynoa node", long_about = None)]
+pub struct DebugArgs {
+    /// Basis-URL des Nodes (Admin-Listener)
+    #[arg(short, long, default_value = "http://127.0.0.1:3001")]
+    pub url: String,
+
+    /// Bearer-Token für geschützte Endpunkte
+    #[arg(short, long)]
+    pub token: Option<String>,
+
+    /// Polling-Intervall für Snapshot und Event-Log (Millisekunden)
+    #[arg(short, long, default_value = "1000")]
+    pub interval: u64,
+}
+
+/// Einstiegspunkt des Binaries `erynoa-debug`
+pub fn run_debugger() -> Result<()> {
+    let args = DebugArgs::parse();
+    let runtime = Runtime::new()?;
+    let client = NodeClient::new(&args.url, args.token.clone())?;
+
+    let mut terminal = setup_terminal()?;
+    let result = event_loop(&mut terminal, &runtime, client, &args);
+    restore_terminal(&mut terminal)?;
+    result
+}
+
+type Backend = CrosstermBackend<io::Stdout>;
+
+fn setup_terminal() -> Result<Terminal<Backend>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+
+    // Bei Panics das Terminal zurücksetzen, sonst bleibt die SSH-Session im Raw-Mode
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        hook(info);
+    }));
+
+    Ok(Terminal::new(CrosstermBackend::new(stdout))?)
+}
+
+fn restore_terminal(terminal: &mut Terminal<Backend>) -> Result<()> {
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+fn event_loop(
+    terminal: &mut Terminal<Backend>,
+    runtime: &Runtime,
+    client: NodeClient,
+    args: &DebugArgs,
+) -> Result<()> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut app = App::new(args.url.clone());
+    let interval = Duration::from_millis(args.interval.max(100));
+
+    runtime.spawn(client.clone().follow_stream(tx.clone()));
+    let mut last_poll: Option<Instant> = None;
+
+    loop {
+        while let Ok(update) = rx.try_recv() {
+            app.apply(update);
+        }
+
+        if last_poll.is_none_or(|at| at.elapsed() >= interval) {
+            runtime.spawn(poll(client.clone(), app.last_sequence(), tx.clone()));
+            last_poll = Some(Instant::now());
+        }
+
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match app.handle_key(key) {
+            Some(Action::Quit) => return Ok(()),
+            Some(Action::Refresh) => last_poll = None,
+            Some(action) => {
+                let client = client.clone();
+                let tx = tx.clone();
+                runtime.spawn(async move {
+                    let update = match client.execute(&action).await {
+                        Ok(message) => Update::Info(message),
+                        Err(err) => Update::Error(format!("{:?} failed: {:#}", action, err)),
+                    };
+                    let _ = tx.send(update);
+                    poll(client, None, tx).await;
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+/// Ein Polling-Durchlauf für Snapshot und neue Events
+async fn poll(client: NodeClient, since: Option<u64>, tx: UnboundedSender<Update>) {
+    let (snapshot, events) = tokio::join!(client.snapshot(), client.events(since));
+    let updates = [snapshot.map(Update::Snapshot), events.map(Update::Events)];
+    for update in updates {
+        let update = update.unwrap_or_else(|err| Update::Error(format!("{:#}", err)));
+        if tx.send(update).is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Minimaler Server-Sent-Events-Parser für `/state/stream`
+//!
+//! Chunks aus dem HTTP-Body werden gepuffert, bis ein Event durch eine
+//! Leerzeile abgeschlossen ist. Ausgewertet wird nur das `data`-Feld;
+//! Kommentare (`:`) und andere Felder (`event`, `id`, `retry`) werden ignoriert.
+
+/// Inkrementeller SSE-Parser
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: String,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verarbeitet einen Chunk und liefert alle darin abgeschlossenen Events
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.push_str(&String::from_utf8_lossy(chunk));
+
+        let mut events = Vec::new();
+        while let Some(pos) = self.buffer.find('\n') {
+            let line: String = self.buffer.drain(..=pos).collect();
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    events.push(self.data.join("\n"));
+                    self.data.clear();
+                }
+                continue;
+            }
+            if line.starts_with(':') {
+                continue;
+            }
+
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            if field == "data" {
+                self.data
+                    .push(value.strip_prefix(' ').unwrap_or(value).to_string());
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_across_chunks() {
+        let mut parser = SseParser::new();
+        assert!(parser.push(b"data: {\"sequence\":").is_empty());
+        assert!(parser.push(b"1}\n").is_empty());
+        let events = parser.push(b"\ndata: two\r\n\r\n: keep-alive\n\n");
+        assert_eq!(events, vec!["{\"sequence\":1}", "two"]);
+    }
+
+    #[test]
+    fn test_multiline_data_and_ignored_fields() {
+        let mut parser = SseParser::new();
+        let events = parser.push(b"event: delta\nid: 7\ndata: a\ndata:b\n\n");
+        assert_eq!(events, vec!["a\nb"]);
+    }
+}
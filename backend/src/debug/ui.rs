@@ -0,0 +1,576 @@
+//! Rendering der Debugger-Ansichten mit ratatui
+//!
+//! Reine Darstellung des [`App`]-Zustands; fehlende Snapshot-Felder (ältere
+//! Nodes, deaktivierte Features) werden als `-` angezeigt.
+
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Bar, BarChart, BarGroup, Block, Borders, Cell, Gauge, List, ListItem, Paragraph, Row, Table,
+    Tabs,
+};
+use ratatui::Frame;
+use serde_json::Value;
+
+use super::app::{App, EventEntry, Pane};
+
+/// Event-Typen, die im ECLVM-Pane als Ausführungen gelistet werden
+const ECLVM_EVENT_KINDS: [&str; 3] = ["ExecutionStarted", "ExecutionCompleted", "PolicyEvaluated"];
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    draw_header(frame, app, header);
+    match app.pane {
+        Pane::Health => draw_health(frame, app, body),
+        Pane::Trust => draw_trust(frame, app, body),
+        Pane::Events => draw_events(frame, app, body),
+        Pane::P2P => draw_p2p(frame, app, body),
+        Pane::Realms => draw_realms(frame, app, body),
+        Pane::Eclvm => draw_eclvm(frame, app, body),
+    }
+    draw_footer(frame, app, footer);
+}
+
+fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
+    let stream = if app.stream_connected {
+        Span::styled("● stream", Style::default().fg(Color::Green))
+    } else {
+        Span::styled("○ stream", Style::default().fg(Color::Red))
+    };
+    let title = Line::from(vec![
+        Span::raw(format!(" erynoa-debug · {} · mode ", app.node_url)),
+        Span::styled(text(app.value("/system_mode")), mode_style(app)),
+        Span::raw(format!(
+            " · health {} · up {}s · ",
+            float(app.value("/health_score"), 1),
+            text(app.value("/uptime_secs"))
+        )),
+        stream,
+        Span::raw(" "),
+    ]);
+
+    let titles = Pane::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, pane)| format!("{} {}", i + 1, pane.title()));
+    let tabs = Tabs::new(titles)
+        .select(Pane::ALL.iter().position(|p| *p == app.pane).unwrap_or(0))
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+        .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(tabs, area);
+}
+
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let line = if app.editing_filter {
+        Line::from(format!(
+            "filter: {}_  (Enter apply · Esc clear)",
+            app.filter
+        ))
+    } else if let Some(status) = &app.status {
+        let color = if status.error {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        Line::from(Span::styled(
+            status.message.clone(),
+            Style::default().fg(color),
+        ))
+    } else {
+        Line::from(
+            "q quit · Tab/1-6 pane · / filter · r refresh · n/d/E mode · x reset · c checkpoint · p replay",
+        )
+    };
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn draw_health(frame: &mut Frame, app: &App, area: Rect) {
+    let [left, right] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+    let [gauges, details] =
+        Layout::vertical([Constraint::Length(9), Constraint::Min(0)]).areas(left);
+
+    let scores = [
+        ("overall", "/health_score"),
+        ("protection", "/protection/health_score"),
+        ("p2p", "/p2p/health_score"),
+    ];
+    let rows = Layout::vertical([Constraint::Length(3); 3]).split(gauges);
+    for ((label, pointer), row) in scores.iter().zip(rows.iter()) {
+        let score = app
+            .value(pointer)
+            .and_then(Value::as_f64)
+            .unwrap_or(0.0)
+            .clamp(0.0, 100.0);
+        let gauge = Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title(*label))
+            .gauge_style(Style::default().fg(score_color(score)))
+            .ratio(score / 100.0)
+            .label(format!("{:.1}", score));
+        frame.render_widget(gauge, *row);
+    }
+
+    frame.render_widget(
+        key_values(
+            app,
+            "Circuit Breaker & Event-Bus",
+            &[
+                ("mode", "/circuit_breaker/mode"),
+                (
+                    "critical/min",
+                    "/circuit_breaker/critical_count_last_minute",
+                ),
+                ("mode changes", "/circuit_breaker/mode_changes"),
+                ("degraded at", "/circuit_breaker/degraded_threshold"),
+                ("emergency at", "/circuit_breaker/emergency_threshold"),
+                ("bus ingress", "/event_bus/ingress_count"),
+                ("bus processed", "/event_bus/processed_count"),
+                ("bus dropped", "/event_bus/dropped_count"),
+            ],
+        ),
+        details,
+    );
+
+    let warnings: Vec<ListItem> = app
+        .value("/warnings")
+        .and_then(Value::as_array)
+        .map(|warnings| {
+            warnings
+                .iter()
+                .map(|w| ListItem::new(text(Some(w))).style(Style::default().fg(Color::Yellow)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let title = format!("Warnings ({})", warnings.len());
+    frame.render_widget(
+        List::new(warnings).block(Block::default().borders(Borders::ALL).title(title)),
+        right,
+    );
+}
+
+fn draw_trust(frame: &mut Frame, app: &App, area: Rect) {
+    let [chart, stats] =
+        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(area);
+
+    let histogram: Vec<u64> = app
+        .value("/core/trust/distribution/histogram")
+        .and_then(Value::as_array)
+        .map(|buckets| buckets.iter().filter_map(Value::as_u64).collect())
+        .unwrap_or_default();
+    let bars: Vec<Bar> = histogram
+        .iter()
+        .enumerate()
+        .map(|(i, count)| {
+            Bar::default()
+                .value(*count)
+                .label(Line::from(format!("{:.1}", i as f64 / 10.0)))
+        })
+        .collect();
+    let width = (chart.width.saturating_sub(2) / bars.len().max(1) as u16)
+        .saturating_sub(1)
+        .max(1);
+    frame.render_widget(
+        BarChart::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Trust-Verteilung"),
+            )
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(width)
+            .bar_style(Style::default().fg(Color::Cyan)),
+        chart,
+    );
+
+    frame.render_widget(
+        key_values(
+            app,
+            "Trust",
+            &[
+                ("entities", "/core/trust/entities_count"),
+                ("relationships", "/core/trust/relationships_count"),
+                ("avg trust", "/core/trust/avg_trust"),
+                ("gini", "/core/trust/distribution/gini"),
+                ("entropy", "/core/trust/distribution/entropy"),
+                ("asymmetry", "/core/trust/asymmetry_ratio"),
+                ("updates", "/core/trust/updates_total"),
+                ("positive", "/core/trust/positive_updates"),
+                ("negative", "/core/trust/negative_updates"),
+                ("violations", "/core/trust/violations_count"),
+            ],
+        ),
+        stats,
+    );
+}
+
+fn draw_events(frame: &mut Frame, app: &App, area: Rect) {
+    let events: Vec<&EventEntry> = app.filtered_events().collect();
+    let title = if app.filter.is_empty() {
+        format!("Event-Log ({})", events.len())
+    } else {
+        format!(
+            "Event-Log ({} of {}, filter \"{}\")",
+            events.len(),
+            app.events.len(),
+            app.filter
+        )
+    };
+    let rows = events
+        .iter()
+        .skip(app.scroll.min(events.len().saturating_sub(1)))
+        .map(|event| {
+            Row::new(vec![
+                Cell::from(event.sequence.to_string()),
+                Cell::from(event.timestamp_ms.to_string()),
+                Cell::from(event.component.clone()),
+                Cell::from(event.kind.clone()),
+                Cell::from(event.payload.to_string()),
+            ])
+        });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(14),
+            Constraint::Length(12),
+            Constraint::Length(24),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header_row(&[
+        "seq",
+        "timestamp_ms",
+        "component",
+        "event",
+        "payload",
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(table, area);
+}
+
+fn draw_p2p(frame: &mut Frame, app: &App, area: Rect) {
+    let [peers, privacy] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+
+    frame.render_widget(
+        key_values(
+            app,
+            "Peers",
+            &[
+                ("peer id", "/p2p/swarm/peer_id"),
+                ("connected", "/p2p/swarm/connected_peers"),
+                ("inbound", "/p2p/swarm/inbound_connections"),
+                ("outbound", "/p2p/swarm/outbound_connections"),
+                ("errors", "/p2p/swarm/connection_errors"),
+                ("latency ms", "/p2p/swarm/avg_latency_ms"),
+                ("nat", "/p2p/swarm/nat_status"),
+                ("gossip mesh", "/p2p/gossip/mesh_peers"),
+                ("topics", "/p2p/gossip/subscribed_topics"),
+                ("dht table", "/p2p/kademlia/routing_table_size"),
+                ("relay peer", "/p2p/relay/relay_peer"),
+                ("relay circuits", "/p2p/relay/circuits_active"),
+            ],
+        ),
+        peers,
+    );
+    frame.render_widget(
+        key_values(
+            app,
+            "Privacy",
+            &[
+                ("circuits active", "/p2p/privacy/circuits_active"),
+                ("circuits created", "/p2p/privacy/circuits_created"),
+                ("circuits closed", "/p2p/privacy/circuits_closed"),
+                ("avg hops", "/p2p/privacy/avg_hops"),
+                ("messages routed", "/p2p/privacy/messages_routed"),
+                ("private msgs", "/p2p/privacy/private_messages"),
+                ("cover traffic", "/p2p/privacy/cover_traffic_sent"),
+                ("mixing pool", "/p2p/privacy/mixing_pool_size"),
+                ("relay rotations", "/p2p/privacy/relay_rotations"),
+                ("selection fails", "/p2p/privacy/selection_failures"),
+            ],
+        ),
+        privacy,
+    );
+}
+
+fn draw_realms(frame: &mut Frame, app: &App, area: Rect) {
+    let mut realms: Vec<(&String, &Value)> = app
+        .value("/peer/realm/realms")
+        .and_then(Value::as_object)
+        .map(|realms| realms.iter().collect())
+        .unwrap_or_default();
+    realms.sort_by(|a, b| a.0.cmp(b.0));
+
+    let rows = realms.iter().map(|(id, realm)| {
+        let quota = |name: &str| {
+            format!(
+                "{}/{}",
+                text(realm.pointer(&format!("/quota/{}_used", name))),
+                text(realm.pointer(&format!("/quota/{}_limit", name)))
+            )
+        };
+        let health = realm
+            .get("quota_health")
+            .and_then(Value::as_f64)
+            .unwrap_or(0.0);
+        let quarantined = realm
+            .pointer("/quota/quarantined")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let style = if quarantined {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default()
+        };
+        Row::new(vec![
+            Cell::from(id.to_string()),
+            Cell::from(text(realm.get("member_count"))),
+            Cell::from(quota("queue_slots")),
+            Cell::from(quota("storage_bytes")),
+            Cell::from(quota("compute_gas")),
+            Cell::from(quota("events")),
+            Cell::from(quota("crossings")),
+            Cell::from(format!("{:.2}", health))
+                .style(Style::default().fg(score_color(health * 100.0))),
+            Cell::from(if quarantined { "yes" } else { "no" }),
+        ])
+        .style(style)
+    });
+    let title = format!("Realm-Quotas ({})", realms.len());
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(12),
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(22),
+            Constraint::Length(18),
+            Constraint::Length(14),
+            Constraint::Length(12),
+            Constraint::Length(7),
+            Constraint::Length(11),
+        ],
+    )
+    .header(header_row(&[
+        "realm",
+        "members",
+        "queue",
+        "storage bytes",
+        "gas",
+        "events",
+        "crossings",
+        "health",
+        "quarantine",
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(table, area);
+}
+
+fn draw_eclvm(frame: &mut Frame, app: &App, area: Rect) {
+    let [stats, executions] =
+        Layout::horizontal([Constraint::Length(40), Constraint::Min(0)]).areas(area);
+
+    frame.render_widget(
+        key_values(
+            app,
+            "ECLVM",
+            &[
+                ("executed", "/eclvm/policies_executed"),
+                ("passed", "/eclvm/policies_passed"),
+                ("denied", "/eclvm/policies_denied"),
+                ("runtime errors", "/eclvm/policy_runtime_errors"),
+                ("success rate", "/eclvm/policy_success_rate"),
+                ("gas consumed", "/eclvm/total_gas_consumed"),
+                ("mana consumed", "/eclvm/total_mana_consumed"),
+                ("out of gas", "/eclvm/out_of_gas_aborts"),
+                ("mana limited", "/eclvm/mana_rate_limited"),
+                ("avg eval µs", "/eclvm/avg_evaluation_time_us"),
+                ("policies cached", "/eclvm/policies_cached"),
+            ],
+        ),
+        stats,
+    );
+
+    let rows = app
+        .events
+        .iter()
+        .filter(|e| ECLVM_EVENT_KINDS.contains(&e.kind.as_str()))
+        .map(|event| {
+            let field = |name: &str| text(event.payload.get(name));
+            let (id, outcome, gas, mana, duration) = match event.kind.as_str() {
+                "PolicyEvaluated" => (
+                    field("policy_id"),
+                    if event.payload["passed"] == Value::Bool(true) {
+                        "passed"
+                    } else {
+                        "denied"
+                    },
+                    field("gas_used"),
+                    field("mana_used"),
+                    format!("{}µs", field("duration_us")),
+                ),
+                "ExecutionCompleted" => (
+                    field("context_id"),
+                    if event.payload["success"] == Value::Bool(true) {
+                        "ok"
+                    } else {
+                        "failed"
+                    },
+                    field("gas_consumed"),
+                    field("mana_consumed"),
+                    format!("{}ms", field("duration_ms")),
+                ),
+                _ => (
+                    field("context_id"),
+                    "started",
+                    field("gas_budget"),
+                    field("mana_budget"),
+                    "-".to_string(),
+                ),
+            };
+            let color = match outcome {
+                "denied" | "failed" => Color::Red,
+                "started" => Color::Gray,
+                _ => Color::Green,
+            };
+            Row::new(vec![
+                Cell::from(event.sequence.to_string()),
+                Cell::from(event.kind.clone()),
+                Cell::from(id),
+                Cell::from(outcome).style(Style::default().fg(color)),
+                Cell::from(gas),
+                Cell::from(mana),
+                Cell::from(duration),
+            ])
+        });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(19),
+            Constraint::Min(16),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header_row(&[
+        "seq", "event", "id", "outcome", "gas", "mana", "duration",
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(format!(
+        "Executions · {} deltas streamed",
+        app.deltas_received
+    )));
+    frame.render_widget(table, executions);
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn key_values<'a>(app: &App, title: &'a str, fields: &[(&'a str, &str)]) -> Table<'a> {
+    let rows = fields.iter().map(|(label, pointer)| {
+        Row::new(vec![
+            Cell::from(*label).style(Style::default().fg(Color::DarkGray)),
+            Cell::from(text(app.value(pointer))),
+        ])
+    });
+    Table::new(rows, [Constraint::Length(18), Constraint::Min(8)])
+        .block(Block::default().borders(Borders::ALL).title(title))
+}
+
+fn header_row(titles: &[&'static str]) -> Row<'static> {
+    Row::new(titles.to_vec()).style(Style::default().add_modifier(Modifier::BOLD))
+}
+
+/// Kompakte Darstellung eines JSON-Werts
+fn text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => "-".to_string(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Number(n)) if n.is_f64() => format!("{:.3}", n.as_f64().unwrap_or(0.0)),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn float(value: Option<&Value>, precision: usize) -> String {
+    value
+        .and_then(Value::as_f64)
+        .map(|v| format!("{:.*}", precision, v))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn score_color(score: f64) -> Color {
+    if score >= 80.0 {
+        Color::Green
+    } else if score >= 50.0 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
+fn mode_style(app: &App) -> Style {
+    let color = match app.value("/system_mode").and_then(Value::as_str) {
+        Some("Normal") => Color::Green,
+        Some("Degraded") => Color::Yellow,
+        Some(_) => Color::Red,
+        None => Color::DarkGray,
+    };
+    Style::default().fg(color).add_modifier(Modifier::BOLD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::create_unified_state;
+    use crate::debug::app::Update;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn render(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(140, 30)).unwrap();
+        terminal.draw(|frame| draw(frame, app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_panes_render_live_snapshot() {
+        let state = create_unified_state();
+        let snapshot = serde_json::to_value(state.snapshot()).unwrap();
+
+        let mut app = App::new("http://node:3000");
+        app.apply(Update::Snapshot(snapshot));
+
+        assert!(render(&app).contains("mode Normal"));
+        for pane in Pane::ALL {
+            app.pane = pane;
+            let screen = render(&app);
+            assert!(screen.contains(pane.title()), "{:?}", pane);
+        }
+    }
+
+    #[test]
+    fn test_render_without_snapshot() {
+        let mut app = App::new("http://node:3000");
+        for pane in Pane::ALL {
+            app.pane = pane;
+            assert!(render(&app).contains("○ stream"));
+        }
+    }
+}
@@ -629,7 +629,7 @@ impl EventPayload {
 // ============================================================================
 
 /// Abstimmungsrichtung
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum VoteDirection {
     For,
@@ -9,6 +9,7 @@
 //! - [`trust`]: Trust-Strukturen (TrustVector6D, TrustRecord)
 //! - [`identity`]: DID-Strukturen (DID, DIDDocument, Delegation)
 //! - [`event`]: Kausale Events (Event, FinalityState)
+//! - [`passkey`]: Passkey-Schlüssel (Ed25519, ES256) und DID-Ableitung
 //!
 //! ## Design-Prinzipien
 //!
@@ -56,6 +57,7 @@ pub mod event;
 pub mod formula;
 pub mod identity;
 pub mod message;
+pub mod passkey;
 pub mod primitives;
 pub mod realm;
 pub mod saga;
@@ -88,11 +90,12 @@ pub use message::{
     PeerInfoMessage, PingMessage, PongMessage, RealmJoinMessage, SagaIntentMessage,
     SyncRequestMessage, SyncResponseMessage, SyncType, TrustClaimMessage,
 };
+pub use passkey::{PasskeyPublicKey, PublicKeyError};
 pub use primitives::{TemporalCoord, UniversalId};
 pub use realm::{
-    realm_id_from_name, GovernanceType, MemberRole, Partition, Realm, RealmError, RealmId,
+    realm_id_from_name, GovernanceModel, GovernanceType, MemberRole, Partition, Realm, RealmError, RealmId,
     RealmMembership, RealmRules, RootRealm, Rule, RuleCategory, StoreTemplate, StoreType,
-    VirtualRealm, ROOT_REALM_ID,
+    VirtualRealm, GOVERNANCE_RULE_PREFIX, ROOT_REALM_ID,
 };
 pub use saga::{
     saga_id_from_intent, Constraint, Goal, Intent, RealmCrossing, Saga, SagaAction,
@@ -606,6 +609,46 @@ impl InvariantChecker {
         }
         Ok(())
     }
+
+    // ========================================================================
+    // Ledger-Invarianten (Token-Supply)
+    // ========================================================================
+
+    /// Ledger: Umlaufmenge muss `minted - burned` entsprechen
+    ///
+    /// `circulating` ist die Summe aller Holder- und Escrow-Salden eines Assets.
+    pub fn check_total_supply(
+        asset_type: &str,
+        minted: u64,
+        burned: u64,
+        circulating: u64,
+    ) -> Result<(), InvariantViolation> {
+        let expected = minted.checked_sub(burned);
+        if expected != Some(circulating) {
+            return Err(InvariantViolation::LedgerSupplyMismatch {
+                asset_type: asset_type.to_string(),
+                expected: minted.saturating_sub(burned),
+                actual: circulating,
+            });
+        }
+        Ok(())
+    }
+
+    /// Ledger: Soll und Haben einer Buchung müssen ausgeglichen sein
+    pub fn check_double_entry(
+        asset_type: &str,
+        debits: u64,
+        credits: u64,
+    ) -> Result<(), InvariantViolation> {
+        if debits != credits {
+            return Err(InvariantViolation::LedgerUnbalancedEntry {
+                asset_type: asset_type.to_string(),
+                debits,
+                credits,
+            });
+        }
+        Ok(())
+    }
 }
 
 /// Invarianten-Verletzung
@@ -734,6 +777,23 @@ pub enum InvariantViolation {
         higher: EventPriority,
         lower: EventPriority,
     },
+
+    // ========================================================================
+    // Ledger-Invarianten
+    // ========================================================================
+    /// Umlaufmenge weicht von `minted - burned` ab
+    LedgerSupplyMismatch {
+        asset_type: String,
+        expected: u64,
+        actual: u64,
+    },
+
+    /// Buchung mit ungleichem Soll und Haben
+    LedgerUnbalancedEntry {
+        asset_type: String,
+        debits: u64,
+        credits: u64,
+    },
 }
 
 impl std::fmt::Display for InvariantViolation {
@@ -915,6 +975,30 @@ impl std::fmt::Display for InvariantViolation {
                     higher, lower
                 )
             }
+
+            // Ledger
+            Self::LedgerSupplyMismatch {
+                asset_type,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Ledger supply mismatch for '{}': expected {}, circulating {}",
+                    asset_type, expected, actual
+                )
+            }
+            Self::LedgerUnbalancedEntry {
+                asset_type,
+                debits,
+                credits,
+            } => {
+                write!(
+                    f,
+                    "Ledger entry for '{}' unbalanced: debits {} != credits {}",
+                    asset_type, debits, credits
+                )
+            }
         }
     }
 }
@@ -1256,6 +1340,17 @@ mod tests {
         assert_eq!(InvariantChecker::remaining_quota(1200, 1000), 0); // saturating
     }
 
+    #[test]
+    fn test_ledger_supply_checks() {
+        assert!(InvariantChecker::check_total_supply("ERY", 1000, 200, 800).is_ok());
+        assert!(InvariantChecker::check_total_supply("ERY", 1000, 200, 900).is_err());
+        // Mehr verbrannt als gemintet ist immer eine Verletzung
+        assert!(InvariantChecker::check_total_supply("ERY", 100, 200, 0).is_err());
+
+        assert!(InvariantChecker::check_double_entry("ERY", 50, 50).is_ok());
+        assert!(InvariantChecker::check_double_entry("ERY", 50, 49).is_err());
+    }
+
     #[test]
     fn test_priority_admission() {
         // Niedrige Last: Alle Prioritäten erlaubt
@@ -0,0 +1,378 @@
+//! Passkey-Schlüssel
+//!
+//! Parst den bei der Registrierung übermittelten Public Key und prüft
+//! Assertion-Signaturen für die unterstützten COSE-Algorithmen:
+//!
+//! - Ed25519 (`-8`): rohe 32 Bytes oder COSE_Key (OKP, crv 6)
+//! - ES256 (`-7`): COSE_Key (EC2, crv 1), SEC1-Punkt oder SubjectPublicKeyInfo (DER)
+//!
+//! ES256-Signaturen werden als ASN.1 DER (wie von WebAuthn-Authenticatoren
+//! erzeugt) oder als rohes `r ‖ s` (64 Bytes) akzeptiert. Aus dem Schlüssel
+//! leitet sich die DID des Passkeys ab ([`PasskeyPublicKey::did_uri`]).
+
+use ciborium::value::Value;
+use ed25519_dalek::Verifier as _;
+use p256::pkcs8::DecodePublicKey;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use super::identity::DIDNamespace;
+
+/// COSE-Algorithmus-IDs (RFC 9053)
+pub mod cose {
+    /// Ed25519 (EdDSA mit Ed25519-Kurve)
+    pub const ED25519: i32 = -8;
+    /// ES256 (ECDSA mit P-256 und SHA-256)
+    pub const ES256: i32 = -7;
+    /// RS256 (RSASSA-PKCS1-v1_5 mit SHA-256)
+    pub const RS256: i32 = -257;
+}
+
+/// Anzeigename eines COSE-Algorithmus
+pub fn algorithm_name(alg: i32) -> &'static str {
+    match alg {
+        cose::ED25519 => "Ed25519",
+        cose::ES256 => "ES256",
+        cose::RS256 => "RS256",
+        _ => "Unknown",
+    }
+}
+
+/// COSE_Key-Labels und -Werte (RFC 9053)
+const COSE_KTY: i64 = 1;
+const COSE_ALG: i64 = 3;
+const COSE_CRV: i64 = -1;
+const COSE_X: i64 = -2;
+const COSE_Y: i64 = -3;
+const KTY_OKP: i64 = 1;
+const KTY_EC2: i64 = 2;
+const CRV_P256: i64 = 1;
+const CRV_ED25519: i64 = 6;
+const ED25519_KEY_LEN: usize = ed25519_dalek::PUBLIC_KEY_LENGTH;
+
+/// Fehler beim Parsen oder Prüfen eines Passkey-Schlüssels
+#[derive(Debug, Error, PartialEq)]
+pub enum PublicKeyError {
+    #[error("unsupported algorithm: {0}")]
+    UnsupportedAlgorithm(i32),
+
+    #[error("invalid COSE_Key: {0}")]
+    InvalidCoseKey(String),
+
+    #[error("invalid {algorithm} public key: {reason}")]
+    InvalidKey {
+        algorithm: &'static str,
+        reason: String,
+    },
+
+    #[error("invalid signature encoding: {0}")]
+    InvalidSignature(String),
+}
+
+/// Public Key eines registrierten Passkeys
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasskeyPublicKey {
+    Ed25519(ed25519_dalek::VerifyingKey),
+    P256(p256::ecdsa::VerifyingKey),
+}
+
+impl PasskeyPublicKey {
+    /// Parst den bei der Registrierung übermittelten Schlüssel für den COSE-Algorithmus
+    pub fn parse(algorithm: i32, bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        match algorithm {
+            cose::ED25519 => {
+                // Rohe Schlüssel haben genau 32 Bytes und dürfen mit jedem Byte beginnen
+                let raw = if bytes.len() != ED25519_KEY_LEN && is_cbor_map(bytes) {
+                    let map = CoseKey::decode(bytes)?;
+                    map.expect_int(COSE_KTY, KTY_OKP)?;
+                    map.expect_int(COSE_CRV, CRV_ED25519)?;
+                    map.check_alg(cose::ED25519)?;
+                    map.bytes(COSE_X)?
+                } else {
+                    bytes.to_vec()
+                };
+                Self::ed25519(&raw)
+            }
+            cose::ES256 => {
+                if is_cbor_map(bytes) {
+                    let map = CoseKey::decode(bytes)?;
+                    map.expect_int(COSE_KTY, KTY_EC2)?;
+                    map.expect_int(COSE_CRV, CRV_P256)?;
+                    map.check_alg(cose::ES256)?;
+                    let (x, y) = (map.bytes(COSE_X)?, map.bytes(COSE_Y)?);
+                    if x.len() != 32 || y.len() != 32 {
+                        return Err(p256_error("coordinates must be 32 bytes"));
+                    }
+                    let mut sec1 = Vec::with_capacity(65);
+                    sec1.push(0x04);
+                    sec1.extend_from_slice(&x);
+                    sec1.extend_from_slice(&y);
+                    Self::p256_sec1(&sec1)
+                } else if bytes.first() == Some(&0x30) {
+                    p256::ecdsa::VerifyingKey::from_public_key_der(bytes)
+                        .map(Self::P256)
+                        .map_err(|e| p256_error(e.to_string()))
+                } else {
+                    Self::p256_sec1(bytes)
+                }
+            }
+            other => Err(PublicKeyError::UnsupportedAlgorithm(other)),
+        }
+    }
+
+    /// Stellt einen Schlüssel aus der gespeicherten Hex-Form her (siehe [`Self::to_hex`])
+    pub fn from_stored(algorithm: i32, public_key_hex: &str) -> Result<Self, PublicKeyError> {
+        let bytes = hex::decode(public_key_hex).map_err(|e| PublicKeyError::InvalidKey {
+            algorithm: algorithm_name(algorithm),
+            reason: format!("invalid hex: {}", e),
+        })?;
+        match algorithm {
+            cose::ED25519 => Self::ed25519(&bytes),
+            cose::ES256 => Self::p256_sec1(&bytes),
+            other => Err(PublicKeyError::UnsupportedAlgorithm(other)),
+        }
+    }
+
+    /// COSE-Algorithmus-ID
+    pub fn algorithm(&self) -> i32 {
+        match self {
+            Self::Ed25519(_) => cose::ED25519,
+            Self::P256(_) => cose::ES256,
+        }
+    }
+
+    /// Speicherformat: rohe 32 Bytes (Ed25519) oder unkomprimierter SEC1-Punkt (P-256), hex
+    pub fn to_hex(&self) -> String {
+        match self {
+            Self::Ed25519(key) => hex::encode(key.to_bytes()),
+            Self::P256(key) => hex::encode(key.to_encoded_point(false).as_bytes()),
+        }
+    }
+
+    /// 32 Byte Schlüsselmaterial, aus dem die Erynoa-DID abgeleitet wird
+    ///
+    /// Ed25519-Schlüssel direkt; P-256-Schlüssel passen nicht in den
+    /// 32-Byte-Slot der DID und werden gehasht (SHA-256 des komprimierten Punkts).
+    pub fn did_key_material(&self) -> [u8; 32] {
+        match self {
+            Self::Ed25519(key) => key.to_bytes(),
+            Self::P256(key) => Sha256::digest(key.to_encoded_point(true).as_bytes()).into(),
+        }
+    }
+
+    /// DID-URI des Passkeys: `did:erynoa:<namespace>:<hex(did_key_material)>`
+    pub fn did_uri(&self, namespace: DIDNamespace) -> String {
+        format!(
+            "did:erynoa:{}:{}",
+            namespace,
+            hex::encode(self.did_key_material())
+        )
+    }
+
+    /// Prüft eine Assertion-Signatur über `authenticatorData ‖ SHA-256(clientDataJSON)`
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, PublicKeyError> {
+        match self {
+            Self::Ed25519(key) => {
+                let signature = ed25519_dalek::Signature::from_slice(signature).map_err(|_| {
+                    PublicKeyError::InvalidSignature(format!(
+                        "Invalid signature length: {} (expected 64)",
+                        signature.len()
+                    ))
+                })?;
+                Ok(key.verify(message, &signature).is_ok())
+            }
+            Self::P256(key) => {
+                let signature = if signature.len() == 64 {
+                    p256::ecdsa::Signature::from_slice(signature)
+                } else {
+                    p256::ecdsa::Signature::from_der(signature)
+                }
+                .map_err(|e| PublicKeyError::InvalidSignature(e.to_string()))?;
+                let signature = signature.normalize_s().unwrap_or(signature);
+                Ok(key.verify(message, &signature).is_ok())
+            }
+        }
+    }
+
+    fn ed25519(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        ed25519_dalek::VerifyingKey::try_from(bytes)
+            .map(Self::Ed25519)
+            .map_err(|_| PublicKeyError::InvalidKey {
+                algorithm: "Ed25519",
+                reason: format!("expected 32 bytes, got {}", bytes.len()),
+            })
+    }
+
+    fn p256_sec1(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+            .map(Self::P256)
+            .map_err(|_| p256_error(format!("not a valid SEC1 point ({} bytes)", bytes.len())))
+    }
+}
+
+fn p256_error(reason: impl Into<String>) -> PublicKeyError {
+    PublicKeyError::InvalidKey {
+        algorithm: "ES256",
+        reason: reason.into(),
+    }
+}
+
+/// CBOR Major Type 5 (Map)
+fn is_cbor_map(bytes: &[u8]) -> bool {
+    bytes.first().is_some_and(|b| b >> 5 == 5)
+}
+
+/// Dekodierte COSE_Key-Map (nur Integer-Labels)
+struct CoseKey(Vec<(i64, Value)>);
+
+impl CoseKey {
+    fn decode(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        let value: Value = ciborium::de::from_reader(bytes)
+            .map_err(|e| PublicKeyError::InvalidCoseKey(e.to_string()))?;
+        let entries = value
+            .into_map()
+            .map_err(|_| PublicKeyError::InvalidCoseKey("not a map".into()))?;
+        Ok(Self(
+            entries
+                .into_iter()
+                .filter_map(|(k, v)| {
+                    k.as_integer()
+                        .and_then(|i| i64::try_from(i).ok())
+                        .map(|k| (k, v))
+                })
+                .collect(),
+        ))
+    }
+
+    fn get(&self, label: i64) -> Option<&Value> {
+        self.0.iter().find(|(k, _)| *k == label).map(|(_, v)| v)
+    }
+
+    fn int(&self, label: i64) -> Option<i64> {
+        self.get(label)?
+            .as_integer()
+            .and_then(|i| i64::try_from(i).ok())
+    }
+
+    fn expect_int(&self, label: i64, expected: i64) -> Result<(), PublicKeyError> {
+        match self.int(label) {
+            Some(value) if value == expected => Ok(()),
+            value => Err(PublicKeyError::InvalidCoseKey(format!(
+                "label {}: expected {}, got {:?}",
+                label, expected, value
+            ))),
+        }
+    }
+
+    /// `alg` ist im COSE_Key optional, muss aber passen, falls vorhanden
+    fn check_alg(&self, algorithm: i32) -> Result<(), PublicKeyError> {
+        match self.int(COSE_ALG) {
+            None => Ok(()),
+            Some(alg) if alg == i64::from(algorithm) => Ok(()),
+            Some(alg) => Err(PublicKeyError::InvalidCoseKey(format!(
+                "alg {} does not match {}",
+                alg, algorithm
+            ))),
+        }
+    }
+
+    fn bytes(&self, label: i64) -> Result<Vec<u8>, PublicKeyError> {
+        self.get(label)
+            .and_then(Value::as_bytes)
+            .cloned()
+            .ok_or_else(|| PublicKeyError::InvalidCoseKey(format!("label {} missing", label)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+    use serde::Deserialize;
+
+    /// Aufgezeichnete ES256-Assertion (COSE_Key, DER-Signatur)
+    const FIXTURE: &str = include_str!("../../../tests/fixtures/webauthn/es256_assertion.json");
+
+    #[derive(Deserialize)]
+    struct Fixture {
+        cose_key: String,
+        spki: String,
+        client_data_json: String,
+        authenticator_data: String,
+        signature: String,
+    }
+
+    fn decode(s: &str) -> Vec<u8> {
+        URL_SAFE_NO_PAD.decode(s).unwrap()
+    }
+
+    fn signed_data(f: &Fixture) -> Vec<u8> {
+        let mut signed = decode(&f.authenticator_data);
+        signed.extend_from_slice(&Sha256::digest(decode(&f.client_data_json)));
+        signed
+    }
+
+    #[test]
+    fn test_es256_cose_key_and_der_signature() {
+        let f: Fixture = serde_json::from_str(FIXTURE).unwrap();
+        let key = PasskeyPublicKey::parse(cose::ES256, &decode(&f.cose_key)).unwrap();
+        assert_eq!(key.algorithm(), cose::ES256);
+
+        // SPKI liefert denselben Schlüssel; Roundtrip über das Speicherformat
+        let spki = PasskeyPublicKey::parse(cose::ES256, &decode(&f.spki)).unwrap();
+        assert_eq!(spki, key);
+        let stored = PasskeyPublicKey::from_stored(cose::ES256, &key.to_hex()).unwrap();
+        assert_eq!(stored, key);
+
+        let message = signed_data(&f);
+        let der = decode(&f.signature);
+        assert!(key.verify(&message, &der).unwrap());
+
+        // Raw r‖s derselben Signatur
+        let raw = p256::ecdsa::Signature::from_der(&der).unwrap().to_bytes();
+        assert!(key.verify(&message, &raw).unwrap());
+
+        let mut tampered = message.clone();
+        tampered[40] ^= 0xff;
+        assert!(!key.verify(&tampered, &der).unwrap());
+        assert!(key.verify(&message, &der[..10]).is_err());
+
+        // COSE_Key mit falschem Algorithmus
+        assert!(PasskeyPublicKey::parse(cose::ED25519, &decode(&f.cose_key)).is_err());
+        assert_eq!(
+            PasskeyPublicKey::parse(cose::RS256, &decode(&f.cose_key)).unwrap_err(),
+            PublicKeyError::UnsupportedAlgorithm(cose::RS256)
+        );
+    }
+
+    #[test]
+    fn test_ed25519_raw_and_did_material() {
+        let signing = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let raw = signing.verifying_key().to_bytes();
+        let key = PasskeyPublicKey::parse(cose::ED25519, &raw).unwrap();
+        assert_eq!(key.did_key_material(), raw);
+        assert_eq!(
+            key.did_uri(DIDNamespace::Self_),
+            format!("did:erynoa:self:{}", hex::encode(raw))
+        );
+        assert_eq!(key.to_hex(), hex::encode(raw));
+        assert!(PasskeyPublicKey::parse(cose::ED25519, &raw[..16]).is_err());
+
+        // Rohe Schlüssel, deren erstes Byte wie ein CBOR-Map-Header aussieht, bleiben roh
+        let cbor_like = (0u8..=255)
+            .map(|seed| ed25519_dalek::SigningKey::from_bytes(&[seed; 32]))
+            .map(|signing| signing.verifying_key().to_bytes())
+            .find(|raw| is_cbor_map(raw))
+            .unwrap();
+        let key = PasskeyPublicKey::parse(cose::ED25519, &cbor_like).unwrap();
+        assert_eq!(key.did_key_material(), cbor_like);
+
+        let f: Fixture = serde_json::from_str(FIXTURE).unwrap();
+        let p256 = PasskeyPublicKey::parse(cose::ES256, &decode(&f.cose_key)).unwrap();
+        assert_ne!(p256.did_key_material(), [0u8; 32]);
+        assert_ne!(
+            &p256.did_key_material()[..],
+            &p256.to_hex().as_bytes()[..32]
+        );
+    }
+}
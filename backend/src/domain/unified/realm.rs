@@ -18,6 +18,7 @@
 //! - Starke Invariant-Prüfungen für Κ1
 
 use super::primitives::{TemporalCoord, UniversalId};
+use super::trust::TrustDimension;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fmt;
@@ -232,6 +233,140 @@ impl fmt::Display for GovernanceType {
     }
 }
 
+// ============================================================================
+// GovernanceModel
+// ============================================================================
+
+/// Präfix der Regel-IDs, die das Governance-Modell festlegen
+pub const GOVERNANCE_RULE_PREFIX: &str = "governance:";
+
+/// Parametrisiertes Governance-Modell eines Realms
+///
+/// Gewählt über eine Governance-Regel in [`RealmRules`]:
+///
+/// ```text
+/// governance:quadratic
+/// governance:token:<asset>
+/// governance:reputation:<dimension>   (reliability | integrity | … | omega)
+/// governance:delegated:<max_depth>
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GovernanceModel {
+    /// Κ21: Credits mit quadratischen Kosten
+    #[default]
+    Quadratic,
+    /// 1 Token = 1 Stimme (Saldo zur Snapshot-Höhe)
+    Token { asset: String },
+    /// Stimmkraft aus einer Trust-Dimension
+    Reputation { dimension: TrustDimension },
+    /// Liquid Democracy über Delegationsketten
+    Delegated { max_depth: u8 },
+}
+
+impl GovernanceModel {
+    /// Parst eine Regel-ID (`governance:<typ>[:<parameter>]`)
+    pub fn from_rule_id(rule_id: &str) -> Option<Self> {
+        let spec = rule_id.strip_prefix(GOVERNANCE_RULE_PREFIX)?;
+        let (kind, param) = match spec.split_once(':') {
+            Some((kind, param)) => (kind, Some(param)),
+            None => (spec, None),
+        };
+        match (kind, param) {
+            ("quadratic", None) => Some(Self::Quadratic),
+            ("token", Some(asset)) if !asset.is_empty() => Some(Self::Token {
+                asset: asset.to_string(),
+            }),
+            ("reputation", Some(dimension)) => {
+                parse_dimension(dimension).map(|dimension| Self::Reputation { dimension })
+            }
+            ("delegated", Some(depth)) => depth
+                .parse()
+                .ok()
+                .filter(|depth| *depth > 0)
+                .map(|max_depth| Self::Delegated { max_depth }),
+            _ => None,
+        }
+    }
+
+    /// Letzte Modell-Regel eines Regelsets (nach ID sortiert)
+    pub fn from_rules(rules: &RealmRules) -> Option<Self> {
+        let mut ids: Vec<&str> = rules
+            .by_category(RuleCategory::Governance)
+            .into_iter()
+            .map(|rule| rule.id.as_str())
+            .collect();
+        ids.sort_unstable();
+        ids.into_iter().rev().find_map(Self::from_rule_id)
+    }
+
+    /// Modell mit Standard-Parametern für einen Governance-Typ
+    pub fn for_type(governance_type: GovernanceType, native_asset: &str) -> Self {
+        match governance_type {
+            GovernanceType::Quadratic => Self::Quadratic,
+            GovernanceType::Token => Self::Token {
+                asset: native_asset.to_string(),
+            },
+            GovernanceType::Reputation => Self::Reputation {
+                dimension: TrustDimension::Reliability,
+            },
+            GovernanceType::Delegated => Self::Delegated { max_depth: 3 },
+        }
+    }
+
+    /// Zugehöriger Governance-Typ
+    pub fn governance_type(&self) -> GovernanceType {
+        match self {
+            Self::Quadratic => GovernanceType::Quadratic,
+            Self::Token { .. } => GovernanceType::Token,
+            Self::Reputation { .. } => GovernanceType::Reputation,
+            Self::Delegated { .. } => GovernanceType::Delegated,
+        }
+    }
+
+    /// Regel-ID dieses Modells
+    pub fn rule_id(&self) -> String {
+        match self {
+            Self::Quadratic => format!("{GOVERNANCE_RULE_PREFIX}quadratic"),
+            Self::Token { asset } => format!("{GOVERNANCE_RULE_PREFIX}token:{asset}"),
+            Self::Reputation { dimension } => format!(
+                "{GOVERNANCE_RULE_PREFIX}reputation:{}",
+                dimension_name(*dimension)
+            ),
+            Self::Delegated { max_depth } => {
+                format!("{GOVERNANCE_RULE_PREFIX}delegated:{max_depth}")
+            }
+        }
+    }
+
+    /// Realm-Regel, die dieses Modell auswählt
+    pub fn rule(&self) -> Rule {
+        Rule::new(
+            self.rule_id(),
+            format!("{} governance", self.governance_type()),
+            RuleCategory::Governance,
+            "Vote weighting model of the realm",
+        )
+    }
+}
+
+fn parse_dimension(name: &str) -> Option<TrustDimension> {
+    TrustDimension::ALL
+        .into_iter()
+        .find(|d| dimension_name(*d) == name.to_ascii_lowercase())
+}
+
+fn dimension_name(dimension: TrustDimension) -> &'static str {
+    match dimension {
+        TrustDimension::Reliability => "reliability",
+        TrustDimension::Integrity => "integrity",
+        TrustDimension::Competence => "competence",
+        TrustDimension::Prestige => "prestige",
+        TrustDimension::Vigilance => "vigilance",
+        TrustDimension::Omega => "omega",
+    }
+}
+
 // ============================================================================
 // Realm Trait
 // ============================================================================
@@ -765,4 +900,31 @@ mod tests {
         let technical_rules = rules.by_category(RuleCategory::Technical);
         assert_eq!(technical_rules.len(), 28);
     }
+
+    #[test]
+    fn test_model_from_rules() {
+        assert_eq!(
+            GovernanceModel::from_rule_id("governance:token:ERY"),
+            Some(GovernanceModel::Token {
+                asset: "ERY".into()
+            })
+        );
+        assert_eq!(
+            GovernanceModel::from_rule_id("governance:reputation:integrity"),
+            Some(GovernanceModel::Reputation {
+                dimension: TrustDimension::Integrity
+            })
+        );
+        assert_eq!(
+            GovernanceModel::from_rule_id("governance:delegated:0"),
+            None
+        );
+        assert_eq!(GovernanceModel::from_rule_id("gdpr"), None);
+
+        let mut rules = RealmRules::new();
+        rules.add(GovernanceModel::Delegated { max_depth: 2 }.rule());
+        let model = GovernanceModel::from_rules(&rules).unwrap();
+        assert_eq!(model, GovernanceModel::Delegated { max_depth: 2 });
+        assert_eq!(GovernanceModel::from_rule_id(&model.rule_id()), Some(model));
+    }
 }
@@ -44,7 +44,7 @@ use serde::{Deserialize, Serialize};
 ///    ▼
 /// EmergencyShutdown
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, utoipa::ToSchema)]
 #[repr(u8)]
 pub enum SystemMode {
     /// Normaler Betrieb - volle Funktionalität
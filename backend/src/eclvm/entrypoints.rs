@@ -12,19 +12,68 @@
 //! - Policies die nur In-Memory-State lesen/schreiben
 //! - Was-wäre-wenn Szenarien
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::core::state::ECLVMStateContext;
+use crate::core::state::{ECLPolicyType, ECLVMStateContext};
 use crate::eclvm::bytecode::{OpCode, Value};
 use crate::eclvm::programmable_gateway::PolicyExecutionObserver;
 use crate::eclvm::runtime::host::HostInterface;
 use crate::eclvm::runtime::runner::{run_policy, run_policy_with_state_context, PolicyRunContext};
+use crate::eclvm::runtime::vm::ExecutionResult;
 use crate::error::{ApiError, Result};
 
 /// Standard-Gas-Limit pro ECL-Ausführung (Engine-Eintrittspunkte)
 pub const DEFAULT_ENGINE_GAS_LIMIT: u64 = 50_000;
 
+/// Engine eines ECL-Eintrittspunkts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EntrypointKind {
+    Api,
+    Ui,
+    DataLogic,
+    Governance,
+    Controller,
+}
+
+impl EntrypointKind {
+    /// Policy-Typ für Observer und Metriken
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Api => "api",
+            Self::Ui => "ui",
+            Self::DataLogic => "datalogic",
+            Self::Governance => "governance",
+            Self::Controller => "controller",
+        }
+    }
+
+    /// Parst den Policy-Typ (`api`, `ui`, `datalogic`, `governance`, `controller`)
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind.to_ascii_lowercase().as_str() {
+            "api" => Some(Self::Api),
+            "ui" => Some(Self::Ui),
+            "datalogic" | "data_logic" => Some(Self::DataLogic),
+            "governance" => Some(Self::Governance),
+            "controller" => Some(Self::Controller),
+            _ => None,
+        }
+    }
+
+    /// Zugehöriger Policy-Typ im ECLVM-State
+    pub fn policy_type(&self) -> ECLPolicyType {
+        match self {
+            Self::Api => ECLPolicyType::Api,
+            Self::Ui => ECLPolicyType::Ui,
+            Self::DataLogic => ECLPolicyType::DataLogic,
+            Self::Governance => ECLPolicyType::Governance,
+            Self::Controller => ECLPolicyType::Controller,
+        }
+    }
+}
+
 /// ECL-Eintrittspunkte: Registrierung und Ausführung pro Engine (Phase 3.2–3.6).
 ///
 /// - **API:** Route-ID → Bytecode (z. B. POST /realm/:id/action)
@@ -95,6 +144,76 @@ impl<H: HostInterface + Send + Sync> EclEntrypoints<H> {
             .insert(permission_or_resource.into(), bytecode);
     }
 
+    fn handlers(&self, kind: EntrypointKind) -> &HashMap<String, Vec<OpCode>> {
+        match kind {
+            EntrypointKind::Api => &self.api_handlers,
+            EntrypointKind::Ui => &self.ui_handlers,
+            EntrypointKind::DataLogic => &self.datalogic_handlers,
+            EntrypointKind::Governance => &self.governance_handlers,
+            EntrypointKind::Controller => &self.controller_handlers,
+        }
+    }
+
+    fn handlers_mut(&mut self, kind: EntrypointKind) -> &mut HashMap<String, Vec<OpCode>> {
+        match kind {
+            EntrypointKind::Api => &mut self.api_handlers,
+            EntrypointKind::Ui => &mut self.ui_handlers,
+            EntrypointKind::DataLogic => &mut self.datalogic_handlers,
+            EntrypointKind::Governance => &mut self.governance_handlers,
+            EntrypointKind::Controller => &mut self.controller_handlers,
+        }
+    }
+
+    /// Registriert Bytecode für eine beliebige Engine
+    pub fn register(&mut self, kind: EntrypointKind, key: impl Into<String>, bytecode: Vec<OpCode>) {
+        self.handlers_mut(kind).insert(key.into(), bytecode);
+    }
+
+    /// Entfernt einen Handler, gibt `false` zurück wenn unbekannt
+    pub fn unregister(&mut self, kind: EntrypointKind, key: &str) -> bool {
+        self.handlers_mut(kind).remove(key).is_some()
+    }
+
+    pub fn has_handler(&self, kind: EntrypointKind, key: &str) -> bool {
+        self.handlers(kind).contains_key(key)
+    }
+
+    /// Führt einen Handler mit vollständigem Kontext aus.
+    ///
+    /// Im Gegensatz zu `run_api` & Co. liefert diese Variante das komplette
+    /// [`ExecutionResult`] (Logs, Gas, Mana, Dauer) für API-Antworten.
+    pub fn execute(
+        &self,
+        kind: EntrypointKind,
+        key: &str,
+        context: &PolicyRunContext,
+    ) -> Result<ExecutionResult> {
+        let bytecode = self.handlers(kind).get(key).ok_or_else(|| {
+            ApiError::NotFound(format!(
+                "ECL {} handler not found: {}",
+                kind.as_str(),
+                key
+            ))
+        })?;
+        let context = context
+            .clone()
+            .with_policy_id(key)
+            .with_policy_type(kind.as_str());
+        let result = run_policy(bytecode, self.host.as_ref(), &context)?;
+        if let Some(ref obs) = self.observer {
+            obs.on_policy_executed(
+                key,
+                kind.as_str(),
+                result.value.as_bool().unwrap_or(false),
+                result.gas_used,
+                result.mana_used,
+                result.duration_us,
+                Some(&context.realm_id),
+            );
+        }
+        Ok(result)
+    }
+
     fn run_and_notify(
         &self,
         bytecode: &[OpCode],
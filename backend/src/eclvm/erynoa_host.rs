@@ -43,7 +43,7 @@ use crate::domain::DID;
 use crate::eclvm::runtime::host::{HostInterface, HostStoreValue, StoreContext};
 use crate::error::Result;
 use crate::local::realm_storage::StoreValue;
-use crate::local::DecentralizedStorage;
+use crate::local::{DecentralizedStorage, LedgerAccount, NATIVE_ASSET};
 
 /// Erynoa Host - Verbindet ECLVM mit dem echten Backend
 pub struct ErynoaHost {
@@ -212,10 +212,16 @@ impl HostInterface for ErynoaHost {
         Ok(has)
     }
 
-    fn get_balance(&self, _did: &str) -> Result<u64> {
-        // TODO: Implementiere wenn Token-System existiert
-        // Für jetzt: Jeder hat 0 Balance (kein Token-System)
-        Ok(0)
+    fn get_balance(&self, did: &str) -> Result<u64> {
+        // Unbekannte/ungültige DIDs haben keinen Saldo (fail-safe)
+        let Some(holder) = LedgerAccount::holder_id(did) else {
+            return Ok(0);
+        };
+
+        self.storage
+            .ledger
+            .balance_of(&holder, NATIVE_ASSET)
+            .map_err(|e| crate::error::ApiError::Storage(e.to_string()))
     }
 
     fn resolve_did(&self, did: &str) -> Result<bool> {
@@ -625,6 +631,25 @@ mod tests {
         assert!(!host.resolve_did("did:erynoa:self:unknown").unwrap());
     }
 
+    #[test]
+    fn test_erynoa_host_balance_from_ledger() {
+        let (storage, _temp) = setup_storage();
+        let issuer = DID::new_self(b"issuer");
+        let alice = DID::new_self(b"alice");
+        storage
+            .ledger
+            .register_asset(NATIVE_ASSET, issuer.id)
+            .unwrap();
+        storage
+            .ledger
+            .mint(&issuer.id, &alice.id, NATIVE_ASSET, 42, None)
+            .unwrap();
+
+        let host = ErynoaHost::new(Arc::clone(&storage));
+        assert_eq!(host.get_balance(&alice.to_uri()).unwrap(), 42);
+        assert_eq!(host.get_balance("did:erynoa:self:unknown").unwrap(), 0);
+    }
+
     #[test]
     fn test_policy_context_builder() {
         let ctx = PolicyContext::new("did:erynoa:self:alice")
@@ -187,8 +187,8 @@ fn calculate_regen_rate(reliability: f64, config: &ManaConfig) -> u64 {
 
 /// Mana-Manager für alle Nutzer
 pub struct ManaManager {
-    /// Konfiguration
-    config: ManaConfig,
+    /// Konfiguration (zur Laufzeit austauschbar)
+    config: RwLock<ManaConfig>,
     /// Accounts nach DID
     accounts: RwLock<HashMap<String, ManaAccount>>,
 }
@@ -197,11 +197,31 @@ impl ManaManager {
     /// Erstelle neuen Manager
     pub fn new(config: ManaConfig) -> Self {
         Self {
-            config,
+            config: RwLock::new(config),
             accounts: RwLock::new(HashMap::new()),
         }
     }
 
+    /// Aktuelle Konfiguration
+    pub fn config(&self) -> ManaConfig {
+        self.config.read().unwrap().clone()
+    }
+
+    /// Konfiguration austauschen
+    ///
+    /// Bestehende Konten behalten ihren Stand (gekappt auf das neue Maximum);
+    /// Maximum und Regenerationsrate gelten sofort.
+    pub fn set_config(&self, config: ManaConfig) {
+        let mut accounts = self.accounts.write().unwrap();
+        for account in accounts.values_mut() {
+            let reliability = account.trust_snapshot as f64;
+            account.max = calculate_max_mana(reliability, &config);
+            account.regen_rate = calculate_regen_rate(reliability, &config);
+            account.current = account.current.min(account.max);
+        }
+        *self.config.write().unwrap() = config;
+    }
+
     /// Erstelle Manager mit Default-Config
     pub fn default_config() -> Self {
         Self::new(ManaConfig::default())
@@ -209,12 +229,13 @@ impl ManaManager {
 
     /// Hole oder erstelle Account für DID
     pub fn get_or_create(&self, did: &str, trust: &TrustVector6D) -> ManaAccount {
+        let config = self.config();
         let mut accounts = self.accounts.write().unwrap();
 
         accounts
             .entry(did.to_string())
-            .and_modify(|acc| acc.update(trust, &self.config))
-            .or_insert_with(|| ManaAccount::new(trust, &self.config))
+            .and_modify(|acc| acc.update(trust, &config))
+            .or_insert_with(|| ManaAccount::new(trust, &config))
             .clone()
     }
 
@@ -240,12 +261,13 @@ impl ManaManager {
 
     /// Verbrauche Mana nach erfolgreicher Ausführung
     pub fn deduct(&self, did: &str, trust: &TrustVector6D, actual_gas: u64) -> Result<()> {
+        let config = self.config();
         let mut accounts = self.accounts.write().unwrap();
 
         let account = accounts
             .entry(did.to_string())
-            .and_modify(|acc| acc.update(trust, &self.config))
-            .or_insert_with(|| ManaAccount::new(trust, &self.config));
+            .and_modify(|acc| acc.update(trust, &config))
+            .or_insert_with(|| ManaAccount::new(trust, &config));
 
         account.consume(actual_gas)
     }
@@ -321,6 +343,17 @@ impl BandwidthTier {
         }
     }
 
+    /// Multiplikator der API-Request-Quota (Newcomer = 1)
+    pub fn request_multiplier(&self) -> u64 {
+        match self {
+            Self::Newcomer => 1,
+            Self::Limited => 2,
+            Self::Standard => 4,
+            Self::Elevated => 8,
+            Self::Veteran => 16,
+        }
+    }
+
     /// Beschreibung
     pub fn description(&self) -> &'static str {
         match self {
@@ -449,6 +482,23 @@ mod tests {
         assert_eq!(after.current_mana, before.current_mana - 5000);
     }
 
+    #[test]
+    fn test_manager_set_config() {
+        let manager = ManaManager::default_config();
+        let trust = trust_with_r(0.0);
+        let did = "did:erynoa:self:carol";
+        assert_eq!(manager.get_status(did, &trust).max_mana, 10_000);
+
+        manager.set_config(ManaConfig {
+            base_allowance: 2_000,
+            ..ManaConfig::default()
+        });
+        let status = manager.get_status(did, &trust);
+        assert_eq!(status.max_mana, 2_000);
+        assert_eq!(status.current_mana, 2_000);
+        assert_eq!(manager.config().base_allowance, 2_000);
+    }
+
     #[test]
     fn test_sybil_attack_limited() {
         // Simuliere 10 Sybil-Accounts mit Trust 0.0
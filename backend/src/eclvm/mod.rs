@@ -75,7 +75,7 @@ pub use cli::{run_cli, Cli, Commands};
 pub use erynoa_host::{ErynoaHost, PolicyContext};
 pub use mana::{BandwidthTier, ManaAccount, ManaConfig, ManaManager, ManaStatus};
 pub use optimizer::{OptimizationStats, Optimizer};
-pub use entrypoints::{EclEntrypoints, DEFAULT_ENGINE_GAS_LIMIT};
+pub use entrypoints::{EclEntrypoints, EntrypointKind, DEFAULT_ENGINE_GAS_LIMIT};
 pub use programmable_gateway::{
     CompiledPolicy, EclCrossingEvaluator, GatewayDecision, PolicyExecutionObserver,
     ProgrammableGateway, StandardPolicies,
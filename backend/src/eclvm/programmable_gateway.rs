@@ -90,6 +90,9 @@ pub trait EclCrossingEvaluator: Send + Sync {
         from_realm: &RealmId,
         to_realm: &RealmId,
     ) -> Result<bool>;
+
+    /// Mana-Konfiguration zur Laufzeit austauschen (Config-Reload)
+    fn set_mana_config(&self, _config: ManaConfig) {}
 }
 
 impl<H: HostInterface + Send + Sync> EclCrossingEvaluator for ProgrammableGateway<H> {
@@ -103,6 +106,10 @@ impl<H: HostInterface + Send + Sync> EclCrossingEvaluator for ProgrammableGatewa
         let decision = self.validate_crossing(sender, sender_trust, from_realm, to_realm)?;
         Ok(decision.allowed)
     }
+
+    fn set_mana_config(&self, config: ManaConfig) {
+        self.mana_manager.set_config(config);
+    }
 }
 
 /// Kompilierte Policy für ein Realm
@@ -558,7 +565,6 @@ mod tests {
     use crate::domain::realm_id_from_name;
     use crate::eclvm::runtime::host::StubHost;
 
-    #[allow(dead_code)]
     fn setup_gateway() -> ProgrammableGateway<StubHost> {
         let host = Arc::new(StubHost::new());
         ProgrammableGateway::new(host)
@@ -687,4 +693,15 @@ mod tests {
             .unwrap();
         assert!(decision.allowed);
     }
+
+    #[test]
+    fn test_mana_config_reload_via_evaluator() {
+        let gateway = Arc::new(setup_gateway());
+        let evaluator: Arc<dyn EclCrossingEvaluator> = gateway.clone();
+        evaluator.set_mana_config(ManaConfig {
+            base_allowance: 42,
+            ..ManaConfig::default()
+        });
+        assert_eq!(gateway.mana_manager.config().base_allowance, 42);
+    }
 }
@@ -128,6 +128,14 @@ pub fn run_policy(
     host: &dyn HostInterface,
     context: &PolicyRunContext,
 ) -> Result<ExecutionResult> {
+    let span = policy_span(
+        context.policy_id.as_deref(),
+        context.policy_type.as_deref(),
+        &context.realm_id,
+        context.gas_limit(),
+    );
+    let _entered = span.enter();
+
     let mut program = vec![OpCode::PushConst(Value::DID(context.caller_did.clone()))];
     program.extend(bytecode.to_vec());
 
@@ -142,9 +150,35 @@ pub fn run_policy(
     // E2: Mana-Verbrauch aus Budget für Metriken (optional, da Mana meist extern gehandhabt wird)
     result.mana_used = context.budget.mana_used();
 
+    record_policy_result(&span, &result);
     Ok(result)
 }
 
+/// Span `eclvm.run_policy` mit Gas-Attributen; Verbrauch wird nach dem Run nachgetragen
+fn policy_span(
+    policy_id: Option<&str>,
+    policy_type: Option<&str>,
+    realm_id: &str,
+    gas_limit: u64,
+) -> tracing::Span {
+    tracing::info_span!(
+        "eclvm.run_policy",
+        policy.id = policy_id.unwrap_or_default(),
+        policy.kind = policy_type.unwrap_or_default(),
+        realm.id = realm_id,
+        gas.limit = gas_limit,
+        gas.used = tracing::field::Empty,
+        mana.used = tracing::field::Empty,
+        duration_us = tracing::field::Empty,
+    )
+}
+
+fn record_policy_result(span: &tracing::Span, result: &ExecutionResult) {
+    span.record("gas.used", result.gas_used);
+    span.record("mana.used", result.mana_used);
+    span.record("duration_us", result.duration_us);
+}
+
 /// Führt ECL-Bytecode mit explizitem Budget aus (E2 Alternative für Tests/direkten Aufruf).
 pub fn run_policy_with_budget(
     bytecode: &[OpCode],
@@ -208,6 +242,14 @@ pub fn run_policy_with_state_context(
         )));
     }
 
+    let span = policy_span(
+        None,
+        Some("state"),
+        context.realm(),
+        context.budget.limits.gas_limit,
+    );
+    let _entered = span.enter();
+
     // StateHost aus Context erstellen
     let host = StateHost::new(context);
 
@@ -224,6 +266,7 @@ pub fn run_policy_with_state_context(
     result.duration_us = start.elapsed().as_micros() as u64;
     result.mana_used = context.budget.mana_used();
 
+    record_policy_result(&span, &result);
     Ok(result)
 }
 
@@ -391,8 +434,8 @@ mod tests {
             did: "did:test:alice".to_string(),
             display_name: Some("Alice".to_string()),
             trust_score: 0.8,
-            credentials: vec!["email-verified".to_string()],
-            realm_memberships: vec!["realm:test".to_string()],
+            realms: vec!["realm:test".to_string()],
+            created_at: 0,
         });
 
         ctx
@@ -366,8 +366,8 @@ mod tests {
             did: "did:test:alice".to_string(),
             display_name: Some("Alice".to_string()),
             trust_score: 0.8,
-            credentials: vec!["email-verified".to_string(), "kyc-verified".to_string()],
-            realm_memberships: vec!["realm:test".to_string()],
+            realms: vec!["realm:test".to_string()],
+            created_at: 0,
         });
 
         ctx
@@ -391,12 +391,10 @@ mod tests {
         let ctx = create_test_context();
         let host = StateHost::new(&ctx);
 
-        assert!(host
+        // IdentityViewData trägt (noch) keine Credentials: auch bekannte DIDs haben keine
+        assert!(!host
             .has_credential("did:test:alice", "email-verified")
             .unwrap());
-        assert!(host
-            .has_credential("did:test:alice", "kyc-verified")
-            .unwrap());
         assert!(!host
             .has_credential("did:test:alice", "unknown-credential")
             .unwrap());
@@ -90,6 +90,9 @@ pub struct ECLVM<'a> {
 
     /// E5: Realm-ID für per-Realm Gas Tracking
     realm_id: Option<String>,
+
+    /// Log-Nachrichten (`OpCode::Log`) für das ExecutionResult
+    logs: Vec<String>,
 }
 
 /// Ergebnis einer VM-Ausführung
@@ -120,6 +123,7 @@ impl<'a> ECLVM<'a> {
             max_stack_depth: 1024,
             multi_gas: None,
             realm_id: None,
+            logs: Vec::new(),
         }
     }
 
@@ -136,6 +140,7 @@ impl<'a> ECLVM<'a> {
             max_stack_depth,
             multi_gas: None,
             realm_id: None,
+            logs: Vec::new(),
         }
     }
 
@@ -151,6 +156,7 @@ impl<'a> ECLVM<'a> {
             max_stack_depth: 1024,
             multi_gas: None,
             realm_id: None,
+            logs: Vec::new(),
         }
     }
 
@@ -260,7 +266,7 @@ impl<'a> ECLVM<'a> {
                         value: result,
                         gas_used: self.gas_consumed(),
                         mana_used: self.mana_consumed(),
-                        logs: Vec::new(),
+                        logs: std::mem::take(&mut self.logs),
                         duration_us: 0, // Runner setzt echte Dauer
                     });
                 }
@@ -275,7 +281,7 @@ impl<'a> ECLVM<'a> {
             value: self.stack.pop().unwrap_or(Value::Null),
             gas_used: self.gas_consumed(),
             mana_used: self.mana_consumed(),
-            logs: Vec::new(),
+            logs: std::mem::take(&mut self.logs),
             duration_us: 0, // Runner setzt echte Dauer
         })
     }
@@ -783,6 +789,7 @@ impl<'a> ECLVM<'a> {
     fn exec_log(&mut self) -> Result<()> {
         let msg = self.pop_string()?;
         self.host.log(&msg);
+        self.logs.push(msg);
         Ok(())
     }
 
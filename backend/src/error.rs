@@ -9,6 +9,7 @@ use axum::{
 };
 use serde::Serialize;
 use std::fmt;
+use utoipa::ToSchema;
 
 /// Result-Typ für die API
 pub type Result<T> = std::result::Result<T, ApiError>;
@@ -162,12 +163,12 @@ impl ApiError {
 }
 
 /// API Error Response Format
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub error: ErrorDetails,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct ErrorDetails {
     pub code: &'static str,
     pub message: String,
@@ -215,7 +216,8 @@ impl IntoResponse for ApiError {
                 status,
                 [(
                     axum::http::header::RETRY_AFTER,
-                    retry_after.as_secs().to_string(),
+                    // Aufrunden: "0" würde sofortige Wiederholung bedeuten
+                    (retry_after.as_secs_f64().ceil() as u64).max(1).to_string(),
                 )],
                 Json(body),
             )
@@ -57,7 +57,7 @@ pub mod error;
 pub mod server;
 pub mod telemetry;
 
-// === Egui Debugger (optional, feature "debug") ===
+// === TUI Debugger (optional, feature "debug") ===
 #[cfg(feature = "debug")]
 pub mod debug;
 
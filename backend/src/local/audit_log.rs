@@ -0,0 +1,296 @@
+//! Audit Log
+//!
+//! Append-only Protokoll privilegierter API-Aufrufe (wer, was, Zustand
+//! vorher/nachher). Jeder Eintrag enthält den BLAKE3-Hash seines Vorgängers;
+//! nachträgliche Änderungen oder Löschungen brechen die Kette und werden von
+//! [`AuditLog::verify`] erkannt.
+//!
+//! Der Store bietet bewusst keine Update- oder Delete-Operationen.
+
+use anyhow::Result;
+use fjall::Keyspace;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+use super::KvStore;
+
+/// `prev_hash` des ersten Eintrags
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Inhalt eines Audit-Eintrags (ohne Verkettung)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Aufrufer (DID), `None` ohne gültige Session
+    pub actor: Option<String>,
+    /// Aufgelöste Rolle des Aufrufers
+    pub role: Option<String>,
+    /// Routen-Gruppe (z. B. `state`, `debug`, `trust`)
+    pub group: String,
+    /// Listener, über den der Aufruf kam (`public`/`admin`)
+    pub listener: String,
+    /// HTTP-Methode
+    pub method: String,
+    /// Pfad inkl. Query
+    pub path: String,
+    /// Request-Body (JSON, sonst `null`)
+    pub request: Value,
+    /// HTTP-Status der Antwort
+    pub status: u16,
+    /// Zustand vor dem Aufruf (nur bei zugelassenen Aufrufen)
+    pub before: Option<Value>,
+    /// Zustand nach dem Aufruf (nur bei zugelassenen Aufrufen)
+    pub after: Option<Value>,
+}
+
+/// Persistierter, verketteter Audit-Eintrag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Fortlaufende Sequenz (ab 0)
+    pub seq: u64,
+    /// Zeitpunkt (Unix-Millisekunden)
+    pub at_ms: i64,
+    /// Hash des Vorgängers (Hex), [`GENESIS_HASH`] für den ersten Eintrag
+    pub prev_hash: String,
+    /// BLAKE3 über Sequenz, Zeitpunkt, `prev_hash` und Inhalt (Hex)
+    pub hash: String,
+    #[serde(flatten)]
+    pub record: AuditRecord,
+}
+
+impl AuditEntry {
+    fn compute_hash(seq: u64, at_ms: i64, prev_hash: &str, record: &AuditRecord) -> Result<String> {
+        let content = serde_json::to_vec(&(seq, at_ms, prev_hash, record))?;
+        Ok(blake3::hash(&content).to_hex().to_string())
+    }
+
+    /// Stimmt der gespeicherte Hash mit dem Inhalt überein?
+    pub fn is_intact(&self) -> bool {
+        Self::compute_hash(self.seq, self.at_ms, &self.prev_hash, &self.record)
+            .is_ok_and(|hash| hash == self.hash)
+    }
+}
+
+/// Ergebnis einer Ketten-Prüfung
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditVerification {
+    /// Kette vollständig und unverändert?
+    pub valid: bool,
+    /// Anzahl geprüfter Einträge
+    pub entries: u64,
+    /// Hash des letzten Eintrags
+    pub head_hash: String,
+    /// Erster fehlende oder veränderte Eintrag
+    pub first_invalid_seq: Option<u64>,
+}
+
+/// Audit Log (seq -> AuditEntry)
+#[derive(Clone)]
+pub struct AuditLog {
+    entries: KvStore,
+    /// Nächste Sequenz und Hash des letzten Eintrags; serialisiert Appends
+    head: Arc<Mutex<(u64, String)>>,
+}
+
+impl AuditLog {
+    /// Öffnet das Audit Log und setzt die Kette am letzten Eintrag fort
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        let entries = KvStore::new(keyspace, "audit_log")?;
+        let next_seq = entries.len() as u64;
+        let head_hash = match next_seq.checked_sub(1) {
+            Some(last) => entries
+                .get::<_, AuditEntry>(Self::seq_key(last))?
+                .map(|entry| entry.hash)
+                .unwrap_or_else(|| GENESIS_HASH.to_string()),
+            None => GENESIS_HASH.to_string(),
+        };
+        Ok(Self {
+            entries,
+            head: Arc::new(Mutex::new((next_seq, head_hash))),
+        })
+    }
+
+    /// Hängt einen Eintrag an die Kette an
+    pub fn append(&self, record: AuditRecord) -> Result<AuditEntry> {
+        let mut head = self.head.lock();
+        let (seq, prev_hash) = (head.0, head.1.clone());
+        let at_ms = chrono::Utc::now().timestamp_millis();
+        let hash = AuditEntry::compute_hash(seq, at_ms, &prev_hash, &record)?;
+        let entry = AuditEntry {
+            seq,
+            at_ms,
+            prev_hash,
+            hash,
+            record,
+        };
+        self.entries.put(Self::seq_key(seq), &entry)?;
+        *head = (seq + 1, entry.hash.clone());
+        Ok(entry)
+    }
+
+    /// Eintrag mit Sequenz `seq`
+    pub fn get(&self, seq: u64) -> Result<Option<AuditEntry>> {
+        self.entries.get(Self::seq_key(seq))
+    }
+
+    /// Einträge ab `from_seq` (aufsteigend), optional nur eines Aufrufers
+    pub fn range(
+        &self,
+        from_seq: u64,
+        limit: usize,
+        actor: Option<&str>,
+    ) -> Result<Vec<AuditEntry>> {
+        let mut entries = Vec::new();
+        for seq in from_seq..self.len() {
+            if entries.len() >= limit {
+                break;
+            }
+            let Some(entry) = self.get(seq)? else {
+                continue;
+            };
+            if actor.is_none() || entry.record.actor.as_deref() == actor {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Anzahl der Einträge
+    pub fn len(&self) -> u64 {
+        self.head.lock().0
+    }
+
+    /// Noch keine Einträge?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hash des letzten Eintrags ([`GENESIS_HASH`] bei leerem Log)
+    pub fn head_hash(&self) -> String {
+        self.head.lock().1.clone()
+    }
+
+    /// Prüft Vollständigkeit, Hashes und Verkettung aller Einträge
+    pub fn verify(&self) -> Result<AuditVerification> {
+        let (len, head_hash) = self.head.lock().clone();
+        let mut prev_hash = GENESIS_HASH.to_string();
+        let mut first_invalid_seq = None;
+
+        for seq in 0..len {
+            let intact = match self.get(seq)? {
+                Some(entry)
+                    if entry.seq == seq && entry.prev_hash == prev_hash && entry.is_intact() =>
+                {
+                    prev_hash = entry.hash;
+                    true
+                }
+                _ => false,
+            };
+            if !intact {
+                first_invalid_seq = Some(seq);
+                break;
+            }
+        }
+        if first_invalid_seq.is_none() && prev_hash != head_hash {
+            first_invalid_seq = Some(len);
+        }
+
+        Ok(AuditVerification {
+            valid: first_invalid_seq.is_none(),
+            entries: len,
+            head_hash,
+            first_invalid_seq,
+        })
+    }
+
+    fn seq_key(seq: u64) -> String {
+        format!("{:020}", seq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(actor: &str, path: &str) -> AuditRecord {
+        AuditRecord {
+            actor: Some(actor.to_string()),
+            role: Some("node_operator".to_string()),
+            group: "state".to_string(),
+            listener: "public".to_string(),
+            method: "POST".to_string(),
+            path: path.to_string(),
+            request: serde_json::json!({ "mode": "Degraded" }),
+            status: 200,
+            before: Some(serde_json::json!({ "system_mode": "Normal" })),
+            after: Some(serde_json::json!({ "system_mode": "Degraded" })),
+        }
+    }
+
+    #[test]
+    fn test_chain_survives_reopen() {
+        let folder = tempfile::tempdir().unwrap();
+        {
+            let keyspace = fjall::Config::new(folder.path()).open().unwrap();
+            let log = AuditLog::new(&keyspace).unwrap();
+            let first = log
+                .append(record("did:erynoa:self:a", "/api/v1/state/mode"))
+                .unwrap();
+            assert_eq!(first.prev_hash, GENESIS_HASH);
+            let second = log
+                .append(record("did:erynoa:self:b", "/api/v1/debug/checkpoint"))
+                .unwrap();
+            assert_eq!(second.prev_hash, first.hash);
+        }
+
+        let keyspace = fjall::Config::new(folder.path()).open().unwrap();
+        let log = AuditLog::new(&keyspace).unwrap();
+        assert_eq!(log.len(), 2);
+        let third = log
+            .append(record("did:erynoa:self:a", "/api/v1/state/mode/reset"))
+            .unwrap();
+        assert_eq!(third.seq, 2);
+        assert_eq!(third.prev_hash, log.get(1).unwrap().unwrap().hash);
+
+        let verification = log.verify().unwrap();
+        assert!(verification.valid);
+        assert_eq!(verification.entries, 3);
+        assert_eq!(verification.head_hash, third.hash);
+
+        let own = log.range(0, 10, Some("did:erynoa:self:a")).unwrap();
+        assert_eq!(own.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(log.range(1, 1, None).unwrap()[0].seq, 1);
+    }
+
+    #[test]
+    fn test_tampering_breaks_chain() {
+        let folder = tempfile::tempdir().unwrap();
+        let keyspace = fjall::Config::new(folder.path()).open().unwrap();
+        let log = AuditLog::new(&keyspace).unwrap();
+        for _ in 0..3 {
+            log.append(record("did:erynoa:self:a", "/api/v1/state/mode"))
+                .unwrap();
+        }
+
+        // Direkter Schreibzugriff auf die Partition am Store vorbei
+        let mut forged = log.get(1).unwrap().unwrap();
+        forged.record.actor = Some("did:erynoa:self:someone-else".to_string());
+        log.entries.put(AuditLog::seq_key(1), &forged).unwrap();
+
+        let verification = log.verify().unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.first_invalid_seq, Some(1));
+
+        // Neu berechneter Hash passt nicht mehr zum Nachfolger
+        forged.hash =
+            AuditEntry::compute_hash(forged.seq, forged.at_ms, &forged.prev_hash, &forged.record)
+                .unwrap();
+        log.entries.put(AuditLog::seq_key(1), &forged).unwrap();
+        assert_eq!(log.verify().unwrap().first_invalid_seq, Some(2));
+
+        // Gelöschter Eintrag
+        log.entries.delete(AuditLog::seq_key(0)).unwrap();
+        assert_eq!(log.verify().unwrap().first_invalid_seq, Some(0));
+    }
+}
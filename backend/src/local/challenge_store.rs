@@ -0,0 +1,154 @@
+//! Challenge Store
+//!
+//! Persistiert die für WebAuthn-Zeremonien ausgegebenen Challenges mit Ablauf.
+//! Jede Challenge ist genau einmal einlösbar: `consume` entfernt sie, sodass
+//! eine aufgezeichnete Assertion nicht erneut vorgelegt werden kann.
+//! Abgelaufene, nie eingelöste Challenges entfernt [`ChallengeStore::spawn_pruner`].
+
+use anyhow::Result;
+use fjall::Keyspace;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::KvStore;
+
+/// Ausgegebene Challenge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeRecord {
+    /// Tracking-ID (wird dem Client als `challenge_id` mitgegeben)
+    pub id: String,
+    /// Challenge (Base64URL, so wie sie im `clientDataJSON` erscheint)
+    pub challenge: String,
+    /// Ausgestellt (Unix-Sekunden)
+    pub created_at: i64,
+    /// Ablauf (Unix-Sekunden)
+    pub expires_at: i64,
+}
+
+/// Challenge Store (challenge -> ChallengeRecord)
+#[derive(Clone)]
+pub struct ChallengeStore {
+    challenges: KvStore,
+    /// Macht Lesen + Löschen in `consume` atomar
+    consume_lock: Arc<Mutex<()>>,
+}
+
+impl ChallengeStore {
+    /// Erstellt einen neuen Challenge Store
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Ok(Self {
+            challenges: KvStore::new(keyspace, "auth_challenges")?,
+            consume_lock: Arc::new(Mutex::new(())),
+        })
+    }
+
+    /// Speichert eine ausgegebene Challenge
+    pub fn put(&self, record: &ChallengeRecord) -> Result<()> {
+        self.challenges.put(&record.challenge, record)
+    }
+
+    /// Löst eine Challenge ein (einmalig); `None` wenn unbekannt, verbraucht
+    /// oder abgelaufen
+    pub fn consume(&self, challenge: &str, now: i64) -> Result<Option<ChallengeRecord>> {
+        let _guard = self.consume_lock.lock();
+        let Some(record) = self.challenges.get::<_, ChallengeRecord>(challenge)? else {
+            return Ok(None);
+        };
+        if !self.challenges.delete(challenge)? {
+            return Ok(None);
+        }
+        Ok((now < record.expires_at).then_some(record))
+    }
+
+    /// Entfernt abgelaufene Challenges, gibt die Anzahl zurück
+    pub fn prune(&self, now: i64) -> Result<usize> {
+        let expired = self
+            .challenges
+            .iter::<ChallengeRecord>()
+            .filter_map(|item| match item {
+                Ok((_, record)) if record.expires_at <= now => Some(Ok(record.challenge)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for challenge in &expired {
+            self.challenges.delete(challenge)?;
+        }
+        Ok(expired.len())
+    }
+
+    /// Startet einen Task, der abgelaufene Challenges periodisch entfernt
+    /// (erster Lauf sofort)
+    pub fn spawn_pruner(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let store = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match store.prune(chrono::Utc::now().timestamp()) {
+                    Ok(pruned) if pruned > 0 => {
+                        tracing::debug!(pruned, "Expired auth challenges pruned");
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!(error = %e, "Challenge pruning failed"),
+                }
+            }
+        })
+    }
+
+    /// Anzahl offener Challenges
+    pub fn count(&self) -> usize {
+        self.challenges.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(challenge: &str, expires_at: i64) -> ChallengeRecord {
+        ChallengeRecord {
+            id: format!("id-{challenge}"),
+            challenge: challenge.to_string(),
+            created_at: 0,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_concurrent_consume_succeeds_once() {
+        let folder = tempfile::tempdir().unwrap();
+        let keyspace = fjall::Config::new(folder.path()).open().unwrap();
+        let store = ChallengeStore::new(&keyspace).unwrap();
+        store.put(&record("c1", 100)).unwrap();
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let store = store.clone();
+                std::thread::spawn(move || store.consume("c1", 10).unwrap().is_some())
+            })
+            .collect();
+        let consumed = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|ok| *ok)
+            .count();
+        assert_eq!(consumed, 1);
+        assert_eq!(store.count(), 0);
+    }
+
+    #[test]
+    fn test_prune_removes_expired() {
+        let folder = tempfile::tempdir().unwrap();
+        let keyspace = fjall::Config::new(folder.path()).open().unwrap();
+        let store = ChallengeStore::new(&keyspace).unwrap();
+        store.put(&record("old", 10)).unwrap();
+        store.put(&record("fresh", 100)).unwrap();
+
+        assert_eq!(store.prune(50).unwrap(), 1);
+        assert_eq!(store.count(), 1);
+        assert!(store.consume("fresh", 50).unwrap().is_some());
+    }
+}
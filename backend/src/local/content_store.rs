@@ -88,6 +88,8 @@ pub struct ContentStore {
     by_creator: KvStore,
     /// Index: Tag -> CIDs
     by_tag: KvStore,
+    /// Registrierte Buckets (Name -> Erstellungszeitpunkt)
+    buckets: KvStore,
 
     // ─────────────────────────────────────────────────────────────────────────
     // METRICS (Phase 2)
@@ -119,6 +121,7 @@ impl ContentStore {
             metadata: KvStore::new(keyspace, "content_meta")?,
             by_creator: KvStore::new(keyspace, "content_by_creator")?,
             by_tag: KvStore::new(keyspace, "content_by_tag")?,
+            buckets: KvStore::new(keyspace, "content_buckets")?,
             metrics: Arc::new(StoreMetrics::new()),
             dedup_hits: Arc::new(AtomicU64::new(0)),
             dedup_misses: Arc::new(AtomicU64::new(0)),
@@ -234,6 +237,16 @@ impl ContentStore {
         Ok(Some(StoredContent { metadata, data }))
     }
 
+    /// Metadaten aller gespeicherten Inhalte
+    pub fn list_metadata(&self) -> Result<Vec<ContentMetadata>> {
+        let mut all = Vec::new();
+        for result in self.metadata.iter::<ContentMetadata>() {
+            let (_, metadata) = result?;
+            all.push(metadata);
+        }
+        Ok(all)
+    }
+
     /// Prüft ob Content existiert
     pub fn exists(&self, cid: &ContentId) -> Result<bool> {
         Ok(self
@@ -267,8 +280,12 @@ impl ContentStore {
 
     /// Holt alle CIDs eines Erstellers
     pub fn get_by_creator(&self, creator: &DID) -> Result<Vec<ContentId>> {
-        let creator_key = creator.to_string();
-        let cids: Vec<String> = self.by_creator.get(&creator_key)?.unwrap_or_default();
+        self.get_by_creator_uri(&creator.to_string())
+    }
+
+    /// Wie [`get_by_creator`](Self::get_by_creator), mit DID-URI als Schlüssel
+    pub fn get_by_creator_uri(&self, creator: &str) -> Result<Vec<ContentId>> {
+        let cids: Vec<String> = self.by_creator.get(creator)?.unwrap_or_default();
         Ok(cids.into_iter().map(ContentId::from_hash).collect())
     }
 
@@ -278,6 +295,83 @@ impl ContentStore {
         Ok(cids.into_iter().map(ContentId::from_hash).collect())
     }
 
+    /// Ergänzt Tags eines vorhandenen Contents (auch nach Dedup)
+    ///
+    /// Gibt `false` zurück, wenn der Content nicht existiert.
+    pub fn add_tags(&self, cid: &ContentId, tags: &[String]) -> Result<bool> {
+        let Some(mut metadata) = self.get_metadata(cid)? else {
+            return Ok(false);
+        };
+
+        for tag in tags {
+            if !metadata.tags.contains(tag) {
+                metadata.tags.push(tag.clone());
+            }
+            let mut cids: Vec<String> = self.by_tag.get(tag)?.unwrap_or_default();
+            if !cids.contains(&cid.0) {
+                cids.push(cid.0.clone());
+                self.by_tag.put(tag, &cids)?;
+            }
+        }
+
+        self.metadata.put(cid.as_str(), &metadata)?;
+        Ok(true)
+    }
+
+    /// Entfernt einen Tag von einem Content (inkl. Index)
+    ///
+    /// Gibt `false` zurück, wenn der Content den Tag nicht trug.
+    pub fn remove_tag(&self, cid: &ContentId, tag: &str) -> Result<bool> {
+        let Some(mut metadata) = self.get_metadata(cid)? else {
+            return Ok(false);
+        };
+        let before = metadata.tags.len();
+        metadata.tags.retain(|t| t != tag);
+        if metadata.tags.len() == before {
+            return Ok(false);
+        }
+        self.metadata.put(cid.as_str(), &metadata)?;
+
+        let mut cids: Vec<String> = self.by_tag.get(tag)?.unwrap_or_default();
+        cids.retain(|c| c != &cid.0);
+        if cids.is_empty() {
+            self.by_tag.delete(tag)?;
+        } else {
+            self.by_tag.put(tag, &cids)?;
+        }
+        Ok(true)
+    }
+
+    /// Registriert einen Bucket; `false` wenn er bereits existiert
+    pub fn create_bucket(&self, name: &str) -> Result<bool> {
+        if self.bucket_exists(name)? {
+            return Ok(false);
+        }
+        self.buckets.put(name, &chrono::Utc::now().timestamp())?;
+        Ok(true)
+    }
+
+    /// Prüft ob ein Bucket registriert ist
+    pub fn bucket_exists(&self, name: &str) -> Result<bool> {
+        Ok(self.buckets.get::<_, i64>(name)?.is_some())
+    }
+
+    /// Entfernt einen Bucket aus der Registry (Inhalte bleiben unberührt)
+    pub fn delete_bucket(&self, name: &str) -> Result<bool> {
+        self.buckets.delete(name)
+    }
+
+    /// Alle registrierten Buckets, alphabetisch
+    pub fn list_buckets(&self) -> Result<Vec<String>> {
+        let mut names = Vec::new();
+        for result in self.buckets.iter::<i64>() {
+            let (key, _) = result?;
+            names.push(String::from_utf8_lossy(&key).into_owned());
+        }
+        names.sort();
+        Ok(names)
+    }
+
     /// Löscht Content (nur wenn nicht mehr referenziert)
     pub fn delete(&self, cid: &ContentId) -> Result<bool> {
         // Hole Größe vor dem Löschen für Metriken
@@ -466,6 +560,29 @@ mod tests {
         assert_eq!(retrieved, Some(data));
     }
 
+    #[test]
+    fn test_tags_and_buckets() {
+        let store = create_test_store();
+
+        let cid = store
+            .put(b"bucketed".to_vec(), "text/plain", None, vec![])
+            .unwrap();
+        assert!(store.add_tags(&cid, &["bucket:a".to_string()]).unwrap());
+        assert_eq!(store.get_by_tag("bucket:a").unwrap(), vec![cid.clone()]);
+
+        assert!(store.remove_tag(&cid, "bucket:a").unwrap());
+        assert!(!store.remove_tag(&cid, "bucket:a").unwrap());
+        assert!(store.get_by_tag("bucket:a").unwrap().is_empty());
+        assert!(store.get_metadata(&cid).unwrap().unwrap().tags.is_empty());
+
+        assert!(store.create_bucket("b").unwrap());
+        assert!(store.create_bucket("a").unwrap());
+        assert!(!store.create_bucket("a").unwrap());
+        assert_eq!(store.list_buckets().unwrap(), vec!["a", "b"]);
+        assert!(store.delete_bucket("a").unwrap());
+        assert!(!store.bucket_exists("a").unwrap());
+    }
+
     #[test]
     fn test_content_dedup() {
         let store = create_test_store();
@@ -560,6 +677,32 @@ mod tests {
         assert_eq!(urgent.len(), 1);
     }
 
+    #[test]
+    fn test_list_metadata() {
+        let store = create_test_store();
+        assert!(store.list_metadata().unwrap().is_empty());
+
+        store
+            .put(b"A".to_vec(), "text/plain", None, vec![])
+            .unwrap();
+        store
+            .put(b"B".to_vec(), "image/png", None, vec!["logo".to_string()])
+            .unwrap();
+        // Dedup: kein zweiter Eintrag
+        store
+            .put(b"A".to_vec(), "text/plain", None, vec![])
+            .unwrap();
+
+        let mut types: Vec<String> = store
+            .list_metadata()
+            .unwrap()
+            .into_iter()
+            .map(|m| m.content_type)
+            .collect();
+        types.sort();
+        assert_eq!(types, vec!["image/png", "text/plain"]);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Phase 2: Metrics Tests
     // ─────────────────────────────────────────────────────────────────────────
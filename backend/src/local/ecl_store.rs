@@ -0,0 +1,103 @@
+//! ECL Handler Store
+//!
+//! Persistiert die von Realm-Admins hochgeladenen ECL-Handler (Quelltext und
+//! kompilierter Bytecode) pro Realm, Engine und Route/Komponente. Der
+//! [`EclService`](crate::peer::EclService) registriert sie nach einem Neustart
+//! erneut in den `EclEntrypoints` des Realms.
+
+use anyhow::Result;
+use fjall::Keyspace;
+use serde::{Deserialize, Serialize};
+
+use super::KvStore;
+use crate::eclvm::{EntrypointKind, OpCode};
+
+/// Persistierter ECL-Handler
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EclHandlerRecord {
+    /// Realm (Schlüssel im Realm-State)
+    pub realm: String,
+    /// Engine des Eintrittspunkts
+    pub kind: EntrypointKind,
+    /// Route-ID, Component-ID oder Permission/Resource
+    pub key: String,
+    /// ECL-Quelltext (fehlt bei direkt hochgeladenem Bytecode)
+    pub source: Option<String>,
+    /// Registrierter Bytecode
+    pub bytecode: Vec<OpCode>,
+    /// BLAKE3-Hash des Bytecodes (Hex)
+    pub hash: String,
+    /// Wurde der Optimizer angewendet?
+    pub optimized: bool,
+    /// Hochgeladen von (DID-URI)
+    pub uploaded_by: String,
+    /// Zeitpunkt (Unix-Sekunden)
+    pub uploaded_at: i64,
+}
+
+impl EclHandlerRecord {
+    /// Store-Schlüssel `realm/kind/key`
+    pub fn store_key(realm: &str, kind: EntrypointKind, key: &str) -> String {
+        format!("{}/{}/{}", realm, kind.as_str(), key)
+    }
+}
+
+/// ECL Handler Store (`realm/kind/key` -> EclHandlerRecord)
+#[derive(Clone)]
+pub struct EclHandlerStore {
+    handlers: KvStore,
+}
+
+impl EclHandlerStore {
+    /// Erstellt einen neuen ECL Handler Store
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Ok(Self {
+            handlers: KvStore::new(keyspace, "ecl_handlers")?,
+        })
+    }
+
+    /// Speichert (oder ersetzt) einen Handler
+    pub fn put(&self, record: &EclHandlerRecord) -> Result<()> {
+        let key = EclHandlerRecord::store_key(&record.realm, record.kind, &record.key);
+        self.handlers.put(key, record)
+    }
+
+    /// Lädt einen Handler
+    pub fn get(
+        &self,
+        realm: &str,
+        kind: EntrypointKind,
+        key: &str,
+    ) -> Result<Option<EclHandlerRecord>> {
+        self.handlers
+            .get(EclHandlerRecord::store_key(realm, kind, key))
+    }
+
+    /// Entfernt einen Handler, gibt `false` zurück wenn unbekannt
+    pub fn delete(&self, realm: &str, kind: EntrypointKind, key: &str) -> Result<bool> {
+        self.handlers
+            .delete(EclHandlerRecord::store_key(realm, kind, key))
+    }
+
+    /// Alle Handler eines Realms (sortiert nach Engine und Schlüssel)
+    pub fn list(&self, realm: &str) -> Result<Vec<EclHandlerRecord>> {
+        let mut records = self
+            .handlers
+            .iter::<EclHandlerRecord>()
+            .map(|item| item.map(|(_, record)| record))
+            .filter(|item| item.as_ref().map_or(true, |record| record.realm == realm))
+            .collect::<Result<Vec<_>>>()?;
+        records.sort_by(|a, b| {
+            a.kind
+                .as_str()
+                .cmp(b.kind.as_str())
+                .then_with(|| a.key.cmp(&b.key))
+        });
+        Ok(records)
+    }
+
+    /// Anzahl der gespeicherten Handler
+    pub fn count(&self) -> usize {
+        self.handlers.len()
+    }
+}
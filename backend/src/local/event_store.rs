@@ -7,6 +7,10 @@
 //! - DAG-spezifische Metriken (max_depth, avg_parents)
 //! - Finality-Tracking
 //! - Snapshot-Pattern für konsistente Reads
+//!
+//! Ledger-Events (Transfer, Mint, Burn, Delegate) werden verbucht, sobald sie
+//! beim Speichern oder per [`EventStore::update_finality`] die Mindest-Finalität
+//! des [`Ledger`] erreichen.
 
 use anyhow::Result;
 use fjall::Keyspace;
@@ -15,6 +19,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
+use super::ledger::{Ledger, LedgerError};
 use super::metrics::{StoreMetrics, StoreMetricsSnapshot};
 use super::KvStore;
 use crate::domain::{Event, EventId, FinalityLevel, FinalityState};
@@ -63,6 +68,9 @@ pub struct EventStore {
 
     /// Anzahl Events mit >= 1 Bestätigung
     confirmed_count: Arc<AtomicU64>,
+
+    /// Ledger für finalisierte Token-Events
+    ledger: Option<Ledger>,
 }
 
 impl EventStore {
@@ -78,6 +86,7 @@ impl EventStore {
             avg_parents: Arc::new(RwLock::new(0.0)),
             finalized_count: Arc::new(AtomicU64::new(0)),
             confirmed_count: Arc::new(AtomicU64::new(0)),
+            ledger: None,
         };
 
         // Initial count setzen
@@ -86,6 +95,12 @@ impl EventStore {
         Ok(store)
     }
 
+    /// Verbucht finalisierte Ledger-Events in `ledger`
+    pub fn with_ledger(mut self, ledger: Ledger) -> Self {
+        self.ledger = Some(ledger);
+        self
+    }
+
     /// Speichert ein Event mit Metriken-Tracking
     pub fn put(&self, event: Event) -> Result<()> {
         let start = Instant::now();
@@ -134,7 +149,25 @@ impl EventStore {
         // DAG-Metriken
         self.update_dag_metrics(depth, parents_count);
 
-        Ok(())
+        self.post_final(&event)
+    }
+
+    /// Übergibt ein Event an den Ledger
+    ///
+    /// Noch nicht finale Events werden beim nächsten Finalitäts-Update
+    /// erneut angeboten; fachlich abgelehnte Events werden nur protokolliert.
+    fn post_final(&self, event: &Event) -> Result<()> {
+        let Some(ledger) = &self.ledger else {
+            return Ok(());
+        };
+        match ledger.apply_event(event) {
+            Ok(_) | Err(LedgerError::NotFinal { .. }) => Ok(()),
+            Err(LedgerError::Storage(e)) => Err(e),
+            Err(e) => {
+                tracing::warn!(event_id = %event.id, error = %e, "Ledger rejected finalized event");
+                Ok(())
+            }
+        }
     }
 
     /// Aktualisiert DAG-spezifische Metriken
@@ -249,8 +282,10 @@ impl EventStore {
             let was_confirmed = stored.confirmations > 0;
 
             stored.finality = finality.clone();
+            stored.event.finality = finality.clone();
             stored.confirmations = confirmations;
             self.events.put(id.to_string(), &stored)?;
+            self.post_final(&stored.event)?;
 
             // Finality-Metriken aktualisieren
             let is_finalized = finality.level == FinalityLevel::Eternal;
@@ -0,0 +1,273 @@
+//! Governance Store
+//!
+//! Persistiert Realm-Proposals samt Stimmen, Auszählung und Ausführung für
+//! den [`GovernanceService`](crate::peer::GovernanceService).
+//!
+//! Pro Realm werden zusätzlich die vergebenen Voting-Credits, das per
+//! Governance erweiterte Regelset, die ausgeführten Konfigurationsänderungen
+//! und der Kopf der Governance-Eventkette gespeichert. Nach einem Neustart wird daraus die `QuadraticGovernance`
+//! des Realms rekonstruiert.
+
+use anyhow::Result;
+use fjall::Keyspace;
+use serde::{Deserialize, Serialize};
+
+use super::realm_storage::SchemaChange;
+use super::KvStore;
+use crate::domain::unified::{GovernanceModel, RealmRules, Rule, VoteDirection, DID};
+
+/// Typisierte Aktion, die ein angenommenes Proposal im Realm ausführt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GovernanceAction {
+    /// Regeln zum Realm-Regelset hinzufügen (Κ1: nur hinzufügen)
+    AddRules { rules: Vec<Rule> },
+    /// Minimum-Trust für Membership setzen
+    SetMinTrust { min_trust: f32 },
+    /// Schema eines Shared Stores weiterentwickeln
+    EvolveSchema {
+        store: String,
+        changes: Vec<SchemaChange>,
+        #[serde(default)]
+        description: String,
+    },
+    /// ECL-Policy des Realms ersetzen
+    ReplacePolicy {
+        /// Bisherige Policy (wird deaktiviert)
+        #[serde(default)]
+        old_policy: Option<String>,
+        /// Neue Policy (wird aktiviert)
+        new_policy: String,
+    },
+    /// Realm-Quota anpassen (nur gesetzte Limits)
+    AdjustQuota { limits: QuotaLimits },
+}
+
+impl GovernanceAction {
+    /// Typ-Tag (für Events und Metriken)
+    pub fn type_tag(&self) -> &'static str {
+        match self {
+            Self::AddRules { .. } => "add_rules",
+            Self::SetMinTrust { .. } => "set_min_trust",
+            Self::EvolveSchema { .. } => "evolve_schema",
+            Self::ReplacePolicy { .. } => "replace_policy",
+            Self::AdjustQuota { .. } => "adjust_quota",
+        }
+    }
+}
+
+/// Neue Quota-Limits eines Realms
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuotaLimits {
+    #[serde(default)]
+    pub queue_slots: Option<u64>,
+    #[serde(default)]
+    pub storage_bytes: Option<u64>,
+    #[serde(default)]
+    pub compute_gas: Option<u64>,
+    #[serde(default)]
+    pub events: Option<u64>,
+    #[serde(default)]
+    pub crossings: Option<u64>,
+}
+
+impl QuotaLimits {
+    /// Ist kein Limit gesetzt?
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Lebenszyklus eines Proposals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalPhase {
+    /// Abstimmung läuft
+    Active,
+    /// Abgelehnt (Quorum oder Zustimmung verfehlt)
+    Rejected,
+    /// Angenommen und Aktion ausgeführt
+    Executed,
+    /// Angenommen, Aktion fehlgeschlagen
+    Failed,
+}
+
+/// Persistierte Stimme
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteRecord {
+    /// Abstimmende DID
+    pub voter: DID,
+    /// Richtung
+    pub direction: VoteDirection,
+    /// Anzahl Stimmen
+    pub vote_count: u64,
+    /// Verbrauchte Credits (Κ21: n²)
+    pub credit_cost: u64,
+    /// ID des `EventPayload::Vote`-Events
+    pub event_id: String,
+    /// Zeitpunkt (Unix-Sekunden)
+    pub at: i64,
+}
+
+/// Auszählungsergebnis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TallyRecord {
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub votes_abstain: u64,
+    pub credits_spent: u64,
+    pub unique_voters: usize,
+    pub approval_ratio: f64,
+    pub quorum_reached: bool,
+    pub passed: bool,
+    /// Gesamte Stimmkraft aller Mitglieder (gewichtete Modelle)
+    #[serde(default)]
+    pub total_power: u64,
+    /// Über Delegation gezählte Stimmen
+    #[serde(default)]
+    pub delegated_votes: u64,
+}
+
+/// Persistiertes Proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalRecord {
+    /// Proposal-ID (Hex-ID des `EventPayload::Proposal`-Events)
+    pub id: String,
+    /// Realm (Schlüssel im Realm-State)
+    pub realm: String,
+    /// Titel
+    pub title: String,
+    /// Beschreibung
+    pub description: String,
+    /// Antragsteller
+    pub proposer: DID,
+    /// Auszuführende Aktion
+    pub action: GovernanceAction,
+    /// Governance-Modell bei Erstellung
+    #[serde(default)]
+    pub model: GovernanceModel,
+    /// Ledger-Journal-Höhe bei Erstellung (Token-Snapshot)
+    #[serde(default)]
+    pub snapshot_height: u64,
+    /// Lebenszyklus-Phase
+    pub phase: ProposalPhase,
+    /// Abgegebene Stimmen in Reihenfolge
+    pub votes: Vec<VoteRecord>,
+    /// Endergebnis nach Finalisierung
+    pub tally: Option<TallyRecord>,
+    /// Fehler bei der Ausführung
+    pub execution_error: Option<String>,
+    /// Erstellt (Unix-Sekunden)
+    pub created_at: i64,
+    /// Ende der Abstimmung (Unix-Sekunden)
+    pub closes_at: i64,
+    /// Finalisiert (Unix-Sekunden)
+    pub finalized_at: Option<i64>,
+}
+
+impl ProposalRecord {
+    /// Abstimmung noch offen?
+    pub fn is_active(&self) -> bool {
+        self.phase == ProposalPhase::Active
+    }
+
+    /// Frist abgelaufen, aber noch nicht finalisiert?
+    pub fn is_due(&self, now: i64) -> bool {
+        self.is_active() && now >= self.closes_at
+    }
+}
+
+/// Vergebene Voting-Credits eines Mitglieds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditGrant {
+    pub voter: DID,
+    pub credits: u64,
+}
+
+/// Governance-Zustand eines Realms
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RealmGovernanceRecord {
+    /// Initiale Credits pro Mitglied (aus Trust abgeleitet)
+    pub grants: Vec<CreditGrant>,
+    /// Per Governance hinzugefügte Regeln
+    pub rules: RealmRules,
+    /// Letztes Governance-Event des Realms (Hex-ID)
+    pub head: Option<String>,
+    /// Lamport-Zähler der Governance-Eventkette
+    pub lamport: u32,
+    /// Per Governance-Regel gewähltes Modell (zuletzt ausgeführte Regel)
+    #[serde(default)]
+    pub model: Option<GovernanceModel>,
+    /// Ausgeführte Realm-Konfigurationsänderungen (JSON, in Ausführungsreihenfolge)
+    #[serde(default)]
+    pub config_changes: Vec<String>,
+}
+
+/// Governance Store (proposal_id -> ProposalRecord, realm -> RealmGovernanceRecord)
+#[derive(Clone)]
+pub struct GovernanceStore {
+    proposals: KvStore,
+    realms: KvStore,
+}
+
+impl GovernanceStore {
+    /// Erstellt einen neuen Governance Store
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Ok(Self {
+            proposals: KvStore::new(keyspace, "governance_proposals")?,
+            realms: KvStore::new(keyspace, "governance_realms")?,
+        })
+    }
+
+    /// Speichert (oder überschreibt) ein Proposal
+    pub fn put(&self, record: &ProposalRecord) -> Result<()> {
+        self.proposals.put(&record.id, record)
+    }
+
+    /// Lädt ein Proposal
+    pub fn get(&self, proposal_id: &str) -> Result<Option<ProposalRecord>> {
+        self.proposals.get(proposal_id)
+    }
+
+    /// Alle Proposals, optional eines Realms (neueste zuerst)
+    pub fn list(&self, realm: Option<&str>) -> Result<Vec<ProposalRecord>> {
+        let mut records = self
+            .proposals
+            .iter::<ProposalRecord>()
+            .map(|item| item.map(|(_, record)| record))
+            .filter(|item| match (item, realm) {
+                (Ok(record), Some(realm)) => record.realm == realm,
+                _ => true,
+            })
+            .collect::<Result<Vec<_>>>()?;
+        records.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        Ok(records)
+    }
+
+    /// Governance-Zustand eines Realms (leer, falls unbekannt)
+    pub fn realm(&self, realm: &str) -> Result<RealmGovernanceRecord> {
+        Ok(self.realms.get(realm)?.unwrap_or_default())
+    }
+
+    /// Speichert den Governance-Zustand eines Realms
+    pub fn put_realm(&self, realm: &str, record: &RealmGovernanceRecord) -> Result<()> {
+        self.realms.put(realm, record)
+    }
+
+    /// Realms mit Governance-Zustand
+    pub fn realm_names(&self) -> Result<Vec<String>> {
+        self.realms
+            .iter::<RealmGovernanceRecord>()
+            .map(|item| item.map(|(key, _)| String::from_utf8_lossy(&key).into_owned()))
+            .collect()
+    }
+
+    /// Anzahl der gespeicherten Proposals
+    pub fn count(&self) -> usize {
+        self.proposals.len()
+    }
+}
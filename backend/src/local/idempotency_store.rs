@@ -0,0 +1,155 @@
+//! Idempotency Store
+//!
+//! Persistiert Antworten schreibender API-Requests unter ihrem
+//! `Idempotency-Key` (pro Aufrufer) bis zum Ablauf der TTL. Wiederholt ein
+//! Client den Request – etwa nach einem Timeout –, wird die gespeicherte
+//! Antwort ausgeliefert statt erneut Events zu erzeugen.
+
+use anyhow::Result;
+use fjall::Keyspace;
+use serde::{Deserialize, Serialize};
+
+use super::KvStore;
+
+/// Gespeicherte Antwort zu einem Idempotency-Key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    /// Key inkl. Aufrufer (`<did|anonymous>:<Idempotency-Key>`)
+    pub key: String,
+    /// BLAKE3 über Methode, Pfad und Body des ersten Requests (Hex)
+    pub fingerprint: String,
+    /// HTTP-Status der Antwort
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Antwort-Body (UTF-8)
+    pub body: String,
+    /// Gespeichert (Unix-Sekunden)
+    pub created_at: i64,
+    /// Ablauf (Unix-Sekunden)
+    pub expires_at: i64,
+}
+
+impl IdempotencyRecord {
+    /// Fingerprint eines Requests
+    pub fn fingerprint(method: &str, path: &str, body: &[u8]) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(method.as_bytes());
+        hasher.update(b" ");
+        hasher.update(path.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(body);
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+/// Idempotency Store (key -> IdempotencyRecord)
+#[derive(Clone)]
+pub struct IdempotencyStore {
+    responses: KvStore,
+}
+
+impl IdempotencyStore {
+    /// Erstellt einen neuen Idempotency Store
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Ok(Self {
+            responses: KvStore::new(keyspace, "idempotency")?,
+        })
+    }
+
+    /// Speichert die Antwort zu einem Key
+    pub fn put(&self, record: &IdempotencyRecord) -> Result<()> {
+        self.responses.put(&record.key, record)
+    }
+
+    /// Gespeicherte Antwort; abgelaufene Einträge werden entfernt
+    pub fn get(&self, key: &str, now: i64) -> Result<Option<IdempotencyRecord>> {
+        let Some(record) = self.responses.get::<_, IdempotencyRecord>(key)? else {
+            return Ok(None);
+        };
+        if record.expires_at <= now {
+            self.responses.delete(key)?;
+            return Ok(None);
+        }
+        Ok(Some(record))
+    }
+
+    /// Entfernt abgelaufene Einträge, gibt die Anzahl zurück
+    pub fn prune(&self, now: i64) -> Result<usize> {
+        let expired = self
+            .responses
+            .iter::<IdempotencyRecord>()
+            .filter_map(|item| match item {
+                Ok((_, record)) if record.expires_at <= now => Some(Ok(record.key)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for key in &expired {
+            self.responses.delete(key)?;
+        }
+        Ok(expired.len())
+    }
+
+    /// Anzahl gespeicherter Antworten
+    pub fn count(&self) -> usize {
+        self.responses.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(key: &str, expires_at: i64) -> IdempotencyRecord {
+        IdempotencyRecord {
+            key: key.to_string(),
+            fingerprint: IdempotencyRecord::fingerprint("POST", "/api/v1/realms", b"{}"),
+            status: 201,
+            content_type: Some("application/json".into()),
+            body: r#"{"realm_id":"guild"}"#.into(),
+            created_at: 100,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_get_put_and_expiry() {
+        let folder = tempfile::tempdir().unwrap();
+        let keyspace = fjall::Config::new(folder.path()).open().unwrap();
+        let store = IdempotencyStore::new(&keyspace).unwrap();
+
+        store.put(&record("did:erynoa:self:a:k1", 200)).unwrap();
+        store.put(&record("did:erynoa:self:a:k2", 300)).unwrap();
+        assert_eq!(
+            store.get("did:erynoa:self:a:k1", 150).unwrap(),
+            Some(record("did:erynoa:self:a:k1", 200))
+        );
+        assert!(store.get("did:erynoa:self:b:k1", 150).unwrap().is_none());
+
+        // Abgelaufen: nicht mehr geliefert und entfernt
+        assert!(store.get("did:erynoa:self:a:k1", 200).unwrap().is_none());
+        assert_eq!(store.count(), 1);
+        assert_eq!(store.prune(299).unwrap(), 0);
+        assert_eq!(store.prune(300).unwrap(), 1);
+        assert_eq!(store.count(), 0);
+    }
+
+    #[test]
+    fn test_fingerprint_covers_method_path_and_body() {
+        let base =
+            IdempotencyRecord::fingerprint("POST", "/api/v1/trust/update", b"{\"delta\":0.1}");
+        assert_eq!(
+            base,
+            IdempotencyRecord::fingerprint("POST", "/api/v1/trust/update", b"{\"delta\":0.1}")
+        );
+        assert_ne!(
+            base,
+            IdempotencyRecord::fingerprint("POST", "/api/v1/trust/update", b"{\"delta\":0.2}")
+        );
+        assert_ne!(
+            base,
+            IdempotencyRecord::fingerprint("POST", "/api/v1/realms", b"{\"delta\":0.1}")
+        );
+    }
+}
@@ -21,6 +21,7 @@ use std::time::Instant;
 
 use super::metrics::{StoreMetrics, StoreMetricsSnapshot};
 use super::KvStore;
+use crate::domain::unified::PasskeyPublicKey;
 use crate::domain::{DIDNamespace, DID};
 
 /// Gespeicherte Identität
@@ -87,6 +88,8 @@ pub struct IdentityStore {
     passkey_credentials: KvStore,
     /// Passkey DID Index (did -> credential_id)
     passkey_did_index: KvStore,
+    /// Macht Prüfen + Schreiben des Signaturzählers atomar
+    sign_count_lock: Arc<parking_lot::Mutex<()>>,
 
     // ─────────────────────────────────────────────────────────────────────────
     // METRICS (Phase 2)
@@ -122,6 +125,7 @@ impl IdentityStore {
             vouch_records: KvStore::new(keyspace, "vouch_records")?,
             passkey_credentials: KvStore::new(keyspace, "passkey_credentials")?,
             passkey_did_index: KvStore::new(keyspace, "passkey_did_index")?,
+            sign_count_lock: Arc::new(parking_lot::Mutex::new(())),
             metrics: Arc::new(StoreMetrics::new()),
             local_identities: Arc::new(AtomicU64::new(0)),
             external_identities: Arc::new(AtomicU64::new(0)),
@@ -423,6 +427,39 @@ impl IdentityStore {
         Ok(all)
     }
 
+    /// Setzt einen Metadaten-Eintrag einer bekannten Identität
+    pub fn set_metadata(&self, did: &DID, key: &str, value: &str) -> Result<()> {
+        let mut identity = self.get(did)?.context("Identity not found")?;
+        identity.metadata.insert(key.to_string(), value.to_string());
+        self.identities.put(did.to_string(), &identity)
+    }
+
+    /// Löst einen Alias (`metadata["alias"]`) auf
+    ///
+    /// Identitäten mit `metadata["realm"] == realm_id` haben Vorrang vor
+    /// Identitäten ohne Realm-Bindung; Aliase anderer Realms werden ignoriert.
+    pub fn resolve_alias(&self, alias: &str, realm_id: &str) -> Result<Option<StoredIdentity>> {
+        let mut global = None;
+        for result in self.identities.iter::<StoredIdentity>() {
+            let (_, identity) = result?;
+            let matches = identity
+                .metadata
+                .get("alias")
+                .is_some_and(|a| a.eq_ignore_ascii_case(alias));
+            if !matches {
+                continue;
+            }
+            match identity.metadata.get("realm") {
+                Some(realm) if realm == realm_id => return Ok(Some(identity)),
+                Some(_) => {}
+                None => {
+                    global.get_or_insert(identity);
+                }
+            }
+        }
+        Ok(global)
+    }
+
     /// Anzahl der gespeicherten Identitäten
     pub fn count(&self) -> usize {
         self.identities.len()
@@ -437,6 +474,26 @@ impl IdentityStore {
         &self,
         credential: &crate::api::v1::auth::StoredPasskeyCredential,
     ) -> Result<()> {
+        // Eine Credential-ID bleibt an ihre DID gebunden
+        if let Some(existing) = self.get_passkey_credential(&credential.credential_id)? {
+            if existing.did != credential.did {
+                bail!(
+                    "Credential {} already registered for another DID",
+                    credential.credential_id
+                );
+            }
+        }
+
+        // Die DID muss aus dem Schlüssel abgeleitet sein
+        let key = PasskeyPublicKey::from_stored(credential.algorithm, &credential.public_key_hex)?;
+        let did: DID = credential.did.parse()?;
+        if key.did_uri(did.namespace) != credential.did {
+            bail!(
+                "Passkey DID {} does not match its public key",
+                credential.did
+            );
+        }
+
         // Speichere Credential unter credential_id
         self.passkey_credentials
             .put(&credential.credential_id, credential)?;
@@ -445,16 +502,9 @@ impl IdentityStore {
         self.passkey_did_index
             .put(&credential.did, &credential.credential_id)?;
 
-        // Optional: Auch als StoredIdentity speichern für Kompatibilität
-        // mit dem bestehenden Identitätssystem
-        let namespace = credential
-            .namespace
-            .parse::<DIDNamespace>()
-            .unwrap_or(DIDNamespace::Self_);
-
-        // Parse public key hex to bytes
-        let pk_bytes = hex::decode(&credential.public_key_hex).unwrap_or_default();
-        let did = DID::new(namespace, &pk_bytes);
+        // Auch als StoredIdentity speichern (Kompatibilität mit dem
+        // bestehenden Identitätssystem), unter `did.to_string()` wie alle
+        // anderen Identitäten
 
         let identity = StoredIdentity {
             did: did.clone(),
@@ -475,9 +525,9 @@ impl IdentityStore {
             vouch_stake: 0.0,
         };
 
-        self.identities.put(credential.did.clone(), &identity)?;
+        self.identities.put(did.to_string(), &identity)?;
         self.pubkey_index
-            .put(&credential.public_key_hex, &credential.did)?;
+            .put(&credential.public_key_hex, &did.to_string())?;
 
         // Metriken
         self.metrics.increment_count();
@@ -506,17 +556,32 @@ impl IdentityStore {
         }
     }
 
-    /// Aktualisiert den last_used_at Timestamp eines Passkey Credentials
-    pub fn update_passkey_last_used(&self, credential_id: &str) -> Result<()> {
-        if let Some(mut credential) =
+    /// Prüft und übernimmt den Signaturzähler eines Passkey Credentials
+    ///
+    /// `check` erhält den gespeicherten Zähler; nur wenn er zustimmt, werden
+    /// Zähler und last_used_at geschrieben. Beides läuft unter einer Sperre:
+    /// Von zwei gleichzeitigen Assertions mit demselben Zähler kommt nur eine
+    /// durch.
+    pub fn advance_passkey_sign_count<E>(
+        &self,
+        credential_id: &str,
+        sign_count: u32,
+        check: impl FnOnce(u32) -> std::result::Result<(), E>,
+    ) -> Result<std::result::Result<(), E>> {
+        let _guard = self.sign_count_lock.lock();
+        let Some(mut credential) =
             self.passkey_credentials
                 .get::<_, crate::api::v1::auth::StoredPasskeyCredential>(credential_id)?
-        {
-            credential.last_used_at = Some(chrono::Utc::now().timestamp());
-            credential.sign_count += 1;
-            self.passkey_credentials.put(credential_id, &credential)?;
+        else {
+            bail!("unknown passkey credential: {credential_id}");
+        };
+        if let Err(e) = check(credential.sign_count) {
+            return Ok(Err(e));
         }
-        Ok(())
+        credential.last_used_at = Some(chrono::Utc::now().timestamp());
+        credential.sign_count = sign_count;
+        self.passkey_credentials.put(credential_id, &credential)?;
+        Ok(Ok(()))
     }
 
     /// Löscht ein Passkey Credential
@@ -528,7 +593,9 @@ impl IdentityStore {
             // Lösche Indizes
             self.passkey_did_index.delete(&credential.did)?;
             self.pubkey_index.delete(&credential.public_key_hex)?;
-            self.identities.delete(&credential.did)?;
+            if let Ok(did) = credential.did.parse::<DID>() {
+                self.identities.delete(did.to_string())?;
+            }
 
             // Lösche Credential
             self.passkey_credentials.delete(credential_id)?;
@@ -720,6 +787,98 @@ mod tests {
         assert_eq!(retrieved.unwrap().did, identity.did);
     }
 
+    #[test]
+    fn test_passkey_identity_uses_key_derived_did() {
+        use crate::api::v1::auth::StoredPasskeyCredential;
+        use crate::domain::unified::passkey::cose;
+
+        let store = create_test_store();
+        let signing = p256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let key = PasskeyPublicKey::P256(*signing.verifying_key());
+        let credential = |did: String| StoredPasskeyCredential {
+            credential_id: "cred-es256".into(),
+            public_key_hex: key.to_hex(),
+            algorithm: cose::ES256,
+            did,
+            namespace: "self".into(),
+            display_name: None,
+            transports: vec![],
+            sign_count: 0,
+            created_at: 0,
+            last_used_at: None,
+        };
+
+        // DID, die nicht zum Schlüssel passt
+        let foreign = format!("did:erynoa:self:{}", "cd".repeat(32));
+        assert!(store
+            .store_passkey_credential(&credential(foreign))
+            .is_err());
+
+        let uri = key.did_uri(DIDNamespace::Self_);
+        store
+            .store_passkey_credential(&credential(uri.clone()))
+            .unwrap();
+        let did: DID = uri.parse().unwrap();
+        let identity = store.get(&did).unwrap().expect("stored under its DID");
+        assert_eq!(identity.did, did);
+        assert_eq!(
+            store.get_by_pubkey(&key.to_hex()).unwrap().unwrap().did,
+            did
+        );
+    }
+
+    #[test]
+    fn test_concurrent_sign_count_advances_once() {
+        use crate::api::v1::auth::StoredPasskeyCredential;
+        use crate::domain::unified::passkey::cose;
+
+        let store = create_test_store();
+        let signing = p256::ecdsa::SigningKey::from_slice(&[9u8; 32]).unwrap();
+        let key = PasskeyPublicKey::P256(*signing.verifying_key());
+        store
+            .store_passkey_credential(&StoredPasskeyCredential {
+                credential_id: "cred-count".into(),
+                public_key_hex: key.to_hex(),
+                algorithm: cose::ES256,
+                did: key.did_uri(DIDNamespace::Self_),
+                namespace: "self".into(),
+                display_name: None,
+                transports: vec![],
+                sign_count: 4,
+                created_at: 0,
+                last_used_at: None,
+            })
+            .unwrap();
+
+        // Acht Assertions mit demselben Zähler: genau eine wird übernommen
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let store = store.clone();
+                std::thread::spawn(move || {
+                    store
+                        .advance_passkey_sign_count("cred-count", 5, |stored| {
+                            if 5 > stored {
+                                Ok(())
+                            } else {
+                                Err(stored)
+                            }
+                        })
+                        .unwrap()
+                        .is_ok()
+                })
+            })
+            .collect();
+        let accepted = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|ok| *ok)
+            .count();
+        assert_eq!(accepted, 1);
+        assert!(store
+            .advance_passkey_sign_count("unknown", 1, |_| Ok::<_, ()>(()))
+            .is_err());
+    }
+
     #[test]
     fn test_vouched_identity() {
         let store = create_test_store();
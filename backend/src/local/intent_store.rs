@@ -0,0 +1,120 @@
+//! Intent Store
+//!
+//! Persistiert über die API eingereichte Intents samt Originaltext, Autor und
+//! Lebenszyklus. Nach `Resolve` verweist der Record auf die Saga im
+//! [`SagaStore`](super::SagaStore); deren Phase bestimmt dann den Status.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use fjall::Keyspace;
+use serde::{Deserialize, Serialize};
+
+use super::KvStore;
+use crate::domain::{Intent, UniversalId};
+
+/// Lebenszyklus eines Intents vor bzw. ohne Saga
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntentPhase {
+    /// Eingereicht, noch nicht aufgelöst
+    Pending,
+    /// In eine Saga aufgelöst (Status folgt der Saga)
+    Resolved,
+    /// Vor der Ausführung abgebrochen
+    Cancelled,
+    /// Auflösung fehlgeschlagen
+    Failed,
+}
+
+/// Gespeicherter Intent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntentRecord {
+    /// Geparster Intent
+    pub intent: Intent,
+    /// Ziel im Originalwortlaut
+    pub goal: String,
+    /// Autor (DID-URI)
+    pub author: String,
+    /// Phase
+    pub phase: IntentPhase,
+    /// Saga nach `Resolve`
+    pub saga_id: Option<UniversalId>,
+    /// Ziel-Realm wie angefragt
+    pub target_realm: Option<String>,
+    /// Client-Metadaten
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    /// Fehler bei Auflösung oder Abbruchgrund
+    pub error: Option<String>,
+    /// Eingereicht (Unix-Sekunden)
+    pub created_at: i64,
+    /// Letzte Änderung (Unix-Sekunden)
+    pub updated_at: i64,
+}
+
+impl IntentRecord {
+    /// Neuer Record für einen eingereichten Intent
+    pub fn new(intent: Intent, goal: impl Into<String>, author: impl Into<String>) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        Self {
+            intent,
+            goal: goal.into(),
+            author: author.into(),
+            phase: IntentPhase::Pending,
+            saga_id: None,
+            target_realm: None,
+            metadata: BTreeMap::new(),
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Intent-ID
+    pub fn id(&self) -> UniversalId {
+        self.intent.id
+    }
+}
+
+/// Intent Store (intent_id hex -> IntentRecord)
+#[derive(Clone)]
+pub struct IntentStore {
+    intents: KvStore,
+}
+
+impl IntentStore {
+    /// Erstellt einen neuen Intent Store
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Ok(Self {
+            intents: KvStore::new(keyspace, "intents")?,
+        })
+    }
+
+    /// Speichert (oder ersetzt) einen Record
+    pub fn put(&self, record: &IntentRecord) -> Result<()> {
+        self.intents.put(record.id().to_hex(), record)
+    }
+
+    /// Holt einen Record per Intent-ID
+    pub fn get(&self, id: &UniversalId) -> Result<Option<IntentRecord>> {
+        self.intents.get(id.to_hex())
+    }
+
+    /// Alle Records eines Autors, älteste zuerst
+    pub fn list_by_author(&self, author: &str) -> Result<Vec<IntentRecord>> {
+        let mut records = Vec::new();
+        for item in self.intents.iter::<IntentRecord>() {
+            let (_, record) = item?;
+            if record.author == author {
+                records.push(record);
+            }
+        }
+        records.sort_by_key(|r| (r.created_at, r.id().to_hex()));
+        Ok(records)
+    }
+
+    /// Anzahl gespeicherter Intents
+    pub fn count(&self) -> usize {
+        self.intents.len()
+    }
+}
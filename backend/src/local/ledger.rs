@@ -0,0 +1,1481 @@
+//! Ledger Store
+//!
+//! Nativer Token- und Asset-Ledger mit doppelter Buchführung.
+//!
+//! ## Modell
+//!
+//! Jede Wertbewegung ist ein [`JournalEntry`] mit genau einer Soll- und einer
+//! Haben-Buchung über denselben Betrag. Konten sind Holder (DIDs), Escrow-Locks
+//! und das Issuance-Konto, das Mint/Burn gegenbucht:
+//!
+//! ```text
+//! Mint:     Soll Holder(to)      / Haben Issuance
+//! Burn:     Soll Issuance        / Haben Holder(from)
+//! Transfer: Soll Holder(to)      / Haben Holder(from)
+//! Lock:     Soll Escrow(lock_id) / Haben Holder(owner)
+//! Unlock:   Soll Holder(to)      / Haben Escrow(lock_id)
+//! ```
+//!
+//! ## Features
+//!
+//! - Salden pro Konto und Asset
+//! - Postings nur aus finalisierten Events (≥ `min_finality`), idempotent per Event-ID
+//! - Mint-Autorisierung über Asset-Issuer oder `Capability`-Delegation
+//! - Escrow-Locks mit Release-Conditions
+//! - Supply-Invariante `Σ Salden = minted - burned` via [`InvariantChecker`]
+
+use anyhow::Result;
+use fjall::Keyspace;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use thiserror::Error;
+
+use super::metrics::{StoreMetrics, StoreMetricsSnapshot};
+use super::KvStore;
+use crate::domain::unified::{
+    Capability, Delegation, Event, EventPayload, FinalityLevel, InvariantChecker,
+    InvariantViolation, SagaAction, TemporalCoord, UniversalId,
+};
+
+/// Natives Asset des Netzwerks
+pub const NATIVE_ASSET: &str = "ERY";
+
+// ============================================================================
+// Typen
+// ============================================================================
+
+/// Konto im Ledger
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "id", rename_all = "snake_case")]
+pub enum LedgerAccount {
+    /// Konto einer Identität
+    Holder(UniversalId),
+    /// Escrow-Konto eines Locks
+    Escrow(UniversalId),
+    /// Gegenkonto für Mint und Burn
+    Issuance,
+}
+
+impl LedgerAccount {
+    /// Storage-Key des Kontos
+    pub fn key(&self) -> String {
+        match self {
+            Self::Holder(id) => format!("holder:{}", id.to_hex()),
+            Self::Escrow(id) => format!("escrow:{}", id.to_hex()),
+            Self::Issuance => "issuance".to_string(),
+        }
+    }
+
+    /// Holder-ID aus DID-URI (`did:erynoa:<ns>:<hex-id>`) oder reiner Hex-ID
+    ///
+    /// Nutzt die ID aus der URI direkt statt `DID::parse`, das die Hex-ID
+    /// als Public Key interpretiert und eine neue ID ableitet.
+    pub fn holder_id(did: &str) -> Option<UniversalId> {
+        let did = did.trim().split('#').next()?;
+        let id_hex = did.rsplit(':').next()?;
+        UniversalId::from_hex(id_hex).ok()
+    }
+}
+
+impl std::fmt::Display for LedgerAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.key())
+    }
+}
+
+/// Art einer Buchung
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    Transfer,
+    Mint,
+    Burn,
+    Lock,
+    Unlock,
+}
+
+/// Einzelne Buchungszeile (Soll oder Haben)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Posting {
+    pub account: LedgerAccount,
+    pub asset_type: String,
+    pub debit: u64,
+    pub credit: u64,
+}
+
+/// Journal-Eintrag mit genau einer Soll- und einer Haben-Seite
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Fortlaufende Nummer
+    pub seq: u64,
+    /// Art der Buchung
+    pub kind: EntryKind,
+    /// Asset
+    pub asset_type: String,
+    /// Betrag
+    pub amount: u64,
+    /// Empfangendes Konto (Soll)
+    pub debit: LedgerAccount,
+    /// Abgebendes Konto (Haben)
+    pub credit: LedgerAccount,
+    /// Auslösendes Event oder Lock
+    pub source: Option<UniversalId>,
+    /// Zeitstempel (Unix-Sekunden)
+    pub timestamp: i64,
+}
+
+impl JournalEntry {
+    /// Soll- und Haben-Posting des Eintrags
+    pub fn postings(&self) -> [Posting; 2] {
+        [
+            Posting {
+                account: self.debit.clone(),
+                asset_type: self.asset_type.clone(),
+                debit: self.amount,
+                credit: 0,
+            },
+            Posting {
+                account: self.credit.clone(),
+                asset_type: self.asset_type.clone(),
+                debit: 0,
+                credit: self.amount,
+            },
+        ]
+    }
+}
+
+/// Supply eines Assets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct AssetSupply {
+    pub minted: u64,
+    pub burned: u64,
+}
+
+impl AssetSupply {
+    /// Erwartete Umlaufmenge
+    pub fn circulating(&self) -> u64 {
+        self.minted.saturating_sub(self.burned)
+    }
+}
+
+/// Registriertes Asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetDefinition {
+    pub asset_type: String,
+    /// Darf minten und Mint-Rechte delegieren
+    pub issuer: UniversalId,
+    pub created_at: i64,
+}
+
+/// Status eines Escrow-Locks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LockStatus {
+    Locked,
+    Released { to: UniversalId },
+    Refunded,
+}
+
+/// Escrow-Lock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscrowLock {
+    pub lock_id: UniversalId,
+    pub owner: UniversalId,
+    pub asset_type: String,
+    pub amount: u64,
+    pub release_conditions: Vec<String>,
+    pub fulfilled: Vec<String>,
+    pub status: LockStatus,
+    pub created_at: i64,
+}
+
+impl EscrowLock {
+    /// Noch nicht erfüllte Release-Conditions
+    pub fn pending_conditions(&self) -> Vec<String> {
+        self.release_conditions
+            .iter()
+            .filter(|c| !self.fulfilled.contains(c))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Ergebnis von [`Ledger::apply_event`]
+#[derive(Debug, Clone)]
+pub enum ApplyOutcome {
+    /// Buchung erstellt
+    Posted(JournalEntry),
+    /// Delegation gespeichert oder widerrufen
+    DelegationUpdated,
+    /// Event wurde bereits verbucht
+    AlreadyApplied,
+    /// Event ist für den Ledger irrelevant
+    Ignored,
+}
+
+/// Ledger-Fehler
+#[derive(Debug, Error)]
+pub enum LedgerError {
+    #[error("Insufficient balance on {account}: {available} < {required} {asset_type}")]
+    InsufficientBalance {
+        account: String,
+        asset_type: String,
+        available: u64,
+        required: u64,
+    },
+
+    #[error("{actor} not authorized to {action} {asset_type}")]
+    Unauthorized {
+        actor: String,
+        action: String,
+        asset_type: String,
+    },
+
+    #[error("Unknown asset: {0}")]
+    UnknownAsset(String),
+
+    #[error("Asset already registered: {0}")]
+    AssetExists(String),
+
+    #[error("Event not final: {actual:?} < {required:?}")]
+    NotFinal {
+        required: FinalityLevel,
+        actual: FinalityLevel,
+    },
+
+    #[error("Amount must be positive")]
+    ZeroAmount,
+
+    #[error("Balance overflow on {0}")]
+    Overflow(String),
+
+    #[error("Lock not found: {0}")]
+    LockNotFound(String),
+
+    #[error("Lock already exists: {0}")]
+    LockExists(String),
+
+    #[error("Lock already closed: {0}")]
+    LockClosed(String),
+
+    #[error("Release conditions not met for {lock_id}: {missing:?}")]
+    ConditionsNotMet {
+        lock_id: String,
+        missing: Vec<String>,
+    },
+
+    #[error("Invalid delegation: {0}")]
+    InvalidDelegation(String),
+
+    #[error("Invariant violated: {0}")]
+    Invariant(#[from] InvariantViolation),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] anyhow::Error),
+}
+
+/// Konfiguration des Ledgers
+#[derive(Debug, Clone)]
+pub struct LedgerConfig {
+    /// Minimale Finalität, ab der Events verbucht werden
+    pub min_finality: FinalityLevel,
+}
+
+impl Default for LedgerConfig {
+    fn default() -> Self {
+        Self {
+            min_finality: FinalityLevel::Witnessed,
+        }
+    }
+}
+
+/// Snapshot des Ledgers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerSnapshot {
+    pub accounts: usize,
+    pub journal_entries: u64,
+    pub assets: usize,
+    pub open_locks: usize,
+    pub delegations: usize,
+    pub metrics: StoreMetricsSnapshot,
+}
+
+// ============================================================================
+// Ledger
+// ============================================================================
+
+/// Ledger Store für Salden, Journal, Supply und Escrow
+#[derive(Clone)]
+pub struct Ledger {
+    /// Salden (account_key -> {asset -> amount})
+    balances: KvStore,
+    /// Journal (seq -> JournalEntry)
+    journal: KvStore,
+    /// Verbuchte Events und Saga-Operationen (event_id/op_id -> seq)
+    applied: KvStore,
+    /// Supply (asset -> AssetSupply)
+    supply: KvStore,
+    /// Assets (asset -> AssetDefinition)
+    assets: KvStore,
+    /// Escrow-Locks (lock_id -> EscrowLock)
+    locks: KvStore,
+    /// Mint-/Burn-Delegationen (delegation_id -> Delegation)
+    delegations: KvStore,
+
+    config: LedgerConfig,
+
+    /// Serialisiert Schreibzugriffe (Read-Modify-Write auf Salden)
+    write_lock: Arc<Mutex<()>>,
+    /// Nächste Journal-Sequenz
+    next_seq: Arc<AtomicU64>,
+    /// Gemeinsame Metriken
+    metrics: Arc<StoreMetrics>,
+}
+
+impl Ledger {
+    /// Erstellt einen neuen Ledger mit Default-Konfiguration
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Self::with_config(keyspace, LedgerConfig::default())
+    }
+
+    /// Erstellt einen neuen Ledger
+    pub fn with_config(keyspace: &Keyspace, config: LedgerConfig) -> Result<Self> {
+        let journal = KvStore::new(keyspace, "ledger_journal")?;
+        let next_seq = journal.len() as u64;
+
+        Ok(Self {
+            balances: KvStore::new(keyspace, "ledger_balances")?,
+            journal,
+            applied: KvStore::new(keyspace, "ledger_applied")?,
+            supply: KvStore::new(keyspace, "ledger_supply")?,
+            assets: KvStore::new(keyspace, "ledger_assets")?,
+            locks: KvStore::new(keyspace, "ledger_locks")?,
+            delegations: KvStore::new(keyspace, "ledger_delegations")?,
+            config,
+            write_lock: Arc::new(Mutex::new(())),
+            next_seq: Arc::new(AtomicU64::new(next_seq)),
+            metrics: Arc::new(StoreMetrics::new()),
+        })
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Saldo eines Kontos für ein Asset
+    pub fn balance(&self, account: &LedgerAccount, asset_type: &str) -> Result<u64> {
+        Ok(self
+            .balances(account)?
+            .get(asset_type)
+            .copied()
+            .unwrap_or(0))
+    }
+
+    /// Saldo einer Identität für ein Asset
+    pub fn balance_of(&self, holder: &UniversalId, asset_type: &str) -> Result<u64> {
+        self.balance(&LedgerAccount::Holder(*holder), asset_type)
+    }
+
+    /// Alle Salden eines Kontos
+    pub fn balances(&self, account: &LedgerAccount) -> Result<BTreeMap<String, u64>> {
+        let start = Instant::now();
+        let result: Option<BTreeMap<String, u64>> = self.balances.get(account.key())?;
+        self.metrics
+            .record_read(start.elapsed().as_micros() as u64, 64);
+        Ok(result.unwrap_or_default())
+    }
+
+    /// Supply eines Assets
+    pub fn supply(&self, asset_type: &str) -> Result<AssetSupply> {
+        Ok(self.supply.get(asset_type)?.unwrap_or_default())
+    }
+
+    /// Registriertes Asset
+    pub fn asset(&self, asset_type: &str) -> Result<Option<AssetDefinition>> {
+        self.assets.get(asset_type)
+    }
+
+    /// Escrow-Lock
+    pub fn lock_info(&self, lock_id: &UniversalId) -> Result<Option<EscrowLock>> {
+        self.locks.get(lock_id.to_hex())
+    }
+
+    /// Wurde das Event bereits verbucht?
+    pub fn is_applied(&self, event_id: &UniversalId) -> Result<bool> {
+        self.applied.contains(event_id.to_hex())
+    }
+
+    /// Die letzten `limit` Journal-Einträge (neueste zuerst)
+    pub fn journal(&self, limit: usize) -> Result<Vec<JournalEntry>> {
+        let end = self.next_seq.load(Ordering::SeqCst);
+        let mut entries = Vec::new();
+        for seq in (0..end).rev().take(limit) {
+            if let Some(entry) = self.journal.get(Self::seq_key(seq))? {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Anzahl der Journal-Einträge
+    pub fn journal_len(&self) -> u64 {
+        self.next_seq.load(Ordering::SeqCst)
+    }
+
+    /// Holder-Salden eines Assets zur Snapshot-Höhe (Journal-Einträge `< height`)
+    ///
+    /// Rekonstruiert die Salden aus dem Journal, unabhängig von späteren Buchungen.
+    pub fn balances_at(&self, asset_type: &str, height: u64) -> Result<HashMap<UniversalId, u64>> {
+        let mut balances: HashMap<UniversalId, u64> = HashMap::new();
+        for seq in 0..height.min(self.journal_len()) {
+            let Some(entry) = self.journal.get::<_, JournalEntry>(Self::seq_key(seq))? else {
+                continue;
+            };
+            if entry.asset_type != asset_type {
+                continue;
+            }
+            if let LedgerAccount::Holder(id) = entry.debit {
+                *balances.entry(id).or_default() += entry.amount;
+            }
+            if let LedgerAccount::Holder(id) = entry.credit {
+                let balance = balances.entry(id).or_default();
+                *balance = balance.saturating_sub(entry.amount);
+            }
+        }
+        Ok(balances)
+    }
+
+    /// Gültige, nicht widerrufene Delegationen
+    pub fn active_delegations(&self) -> Result<Vec<Delegation>> {
+        let mut delegations = Vec::new();
+        for item in self.delegations.iter::<Delegation>() {
+            let (_, delegation) = item?;
+            if delegation.is_valid(&TemporalCoord::now(0, &delegation.delegate)) {
+                delegations.push(delegation);
+            }
+        }
+        Ok(delegations)
+    }
+
+    // ========================================================================
+    // Assets & Autorisierung
+    // ========================================================================
+
+    /// Registriert ein Asset mit seinem Issuer
+    pub fn register_asset(
+        &self,
+        asset_type: &str,
+        issuer: UniversalId,
+    ) -> Result<AssetDefinition, LedgerError> {
+        let _guard = self.write_lock.lock();
+        if self.assets.contains(asset_type)? {
+            return Err(LedgerError::AssetExists(asset_type.to_string()));
+        }
+        let definition = AssetDefinition {
+            asset_type: asset_type.to_string(),
+            issuer,
+            created_at: chrono::Utc::now().timestamp(),
+        };
+        self.assets.put(asset_type, &definition)?;
+        Ok(definition)
+    }
+
+    /// Speichert eine Delegation (für Mint-/Burn-Rechte)
+    pub fn grant_delegation(&self, delegation: Delegation) -> Result<(), LedgerError> {
+        InvariantChecker::check_delegation_trust_factor(delegation.trust_factor)?;
+        self.delegations.put(delegation.id.to_hex(), &delegation)?;
+        Ok(())
+    }
+
+    /// Widerruft eine Delegation, gibt `false` zurück wenn unbekannt
+    pub fn revoke_delegation(&self, delegation_id: &UniversalId) -> Result<bool, LedgerError> {
+        let key = delegation_id.to_hex();
+        match self.delegations.get::<_, Delegation>(&key)? {
+            Some(mut delegation) => {
+                delegation.revoke();
+                self.delegations.put(&key, &delegation)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Darf `actor` das Asset minten?
+    ///
+    /// Erlaubt sind der Issuer und Delegierte des Issuers mit `custom:mint:<asset>`,
+    /// `custom:mint:*`, `execute:mint:<asset>` oder `*`.
+    pub fn can_mint(&self, actor: &UniversalId, asset_type: &str) -> Result<bool, LedgerError> {
+        let definition = self
+            .asset(asset_type)?
+            .ok_or_else(|| LedgerError::UnknownAsset(asset_type.to_string()))?;
+        if &definition.issuer == actor {
+            return Ok(true);
+        }
+        self.has_capability(&definition.issuer, actor, "mint", asset_type)
+    }
+
+    /// Darf `actor` Assets von `from` verbrennen?
+    ///
+    /// Erlaubt sind der Besitzer selbst und Delegierte mit `custom:burn:<asset>`.
+    pub fn can_burn(
+        &self,
+        actor: &UniversalId,
+        from: &UniversalId,
+        asset_type: &str,
+    ) -> Result<bool, LedgerError> {
+        if actor == from {
+            return Ok(true);
+        }
+        self.has_capability(from, actor, "burn", asset_type)
+    }
+
+    fn has_capability(
+        &self,
+        delegator: &UniversalId,
+        delegate: &UniversalId,
+        action: &str,
+        asset_type: &str,
+    ) -> Result<bool, LedgerError> {
+        let required = [
+            Capability::Custom {
+                name: action.to_string(),
+                params: asset_type.to_string(),
+            },
+            Capability::Custom {
+                name: action.to_string(),
+                params: "*".to_string(),
+            },
+            Capability::Execute {
+                action: format!("{}:{}", action, asset_type),
+            },
+        ];
+        let now = TemporalCoord::now(0, delegate);
+
+        for item in self.delegations.iter::<Delegation>() {
+            let (_, delegation) = item?;
+            if &delegation.delegator != delegator
+                || &delegation.delegate != delegate
+                || !delegation.is_valid(&now)
+            {
+                continue;
+            }
+            if delegation
+                .capabilities
+                .iter()
+                .any(|cap| required.iter().any(|req| cap.implies(req)))
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    // ========================================================================
+    // Buchungen
+    // ========================================================================
+
+    /// Überweist `amount` von `from` an `to`
+    pub fn transfer(
+        &self,
+        from: &UniversalId,
+        to: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        source: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        let _guard = self.write_lock.lock();
+        self.transfer_locked(from, to, asset_type, amount, source)
+    }
+
+    fn transfer_locked(
+        &self,
+        from: &UniversalId,
+        to: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        source: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        self.post(
+            EntryKind::Transfer,
+            asset_type,
+            amount,
+            LedgerAccount::Holder(*to),
+            LedgerAccount::Holder(*from),
+            source,
+        )
+    }
+
+    /// Mintet `amount` an `to` (autorisiert über `actor`)
+    pub fn mint(
+        &self,
+        actor: &UniversalId,
+        to: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        source: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        let _guard = self.write_lock.lock();
+        self.mint_locked(actor, to, asset_type, amount, source)
+    }
+
+    fn mint_locked(
+        &self,
+        actor: &UniversalId,
+        to: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        source: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        if !self.can_mint(actor, asset_type)? {
+            return Err(Self::unauthorized(actor, "mint", asset_type));
+        }
+        self.post(
+            EntryKind::Mint,
+            asset_type,
+            amount,
+            LedgerAccount::Holder(*to),
+            LedgerAccount::Issuance,
+            source,
+        )
+    }
+
+    /// Verbrennt `amount` von `from` (autorisiert über `actor`)
+    pub fn burn(
+        &self,
+        actor: &UniversalId,
+        from: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        source: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        let _guard = self.write_lock.lock();
+        self.burn_locked(actor, from, asset_type, amount, source)
+    }
+
+    fn burn_locked(
+        &self,
+        actor: &UniversalId,
+        from: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        source: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        if !self.can_burn(actor, from, asset_type)? {
+            return Err(Self::unauthorized(actor, "burn", asset_type));
+        }
+        self.post(
+            EntryKind::Burn,
+            asset_type,
+            amount,
+            LedgerAccount::Issuance,
+            LedgerAccount::Holder(*from),
+            source,
+        )
+    }
+
+    // ========================================================================
+    // Escrow
+    // ========================================================================
+
+    /// Sperrt `amount` des Besitzers in einem Escrow-Lock
+    ///
+    /// Ohne `lock_id` wird eine ID aus Besitzer, Asset, Betrag und Journal-Position
+    /// abgeleitet.
+    pub fn lock(
+        &self,
+        owner: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        lock_id: Option<UniversalId>,
+        release_conditions: Vec<String>,
+    ) -> Result<EscrowLock, LedgerError> {
+        let _guard = self.write_lock.lock();
+        self.lock_locked(owner, asset_type, amount, lock_id, release_conditions)
+            .map(|(lock, _)| lock)
+    }
+
+    fn lock_locked(
+        &self,
+        owner: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        lock_id: Option<UniversalId>,
+        release_conditions: Vec<String>,
+    ) -> Result<(EscrowLock, JournalEntry), LedgerError> {
+        let lock_id = lock_id.unwrap_or_else(|| {
+            let content = [
+                owner.as_bytes().as_slice(),
+                asset_type.as_bytes(),
+                &amount.to_le_bytes(),
+                &self.next_seq.load(Ordering::SeqCst).to_le_bytes(),
+            ]
+            .concat();
+            UniversalId::new(UniversalId::TAG_SAGA, 1, &content)
+        });
+        let key = lock_id.to_hex();
+        if self.locks.contains(&key)? {
+            return Err(LedgerError::LockExists(key));
+        }
+
+        let entry = self.post(
+            EntryKind::Lock,
+            asset_type,
+            amount,
+            LedgerAccount::Escrow(lock_id),
+            LedgerAccount::Holder(*owner),
+            Some(lock_id),
+        )?;
+
+        let lock = EscrowLock {
+            lock_id,
+            owner: *owner,
+            asset_type: asset_type.to_string(),
+            amount,
+            release_conditions,
+            fulfilled: Vec::new(),
+            status: LockStatus::Locked,
+            created_at: chrono::Utc::now().timestamp(),
+        };
+        self.locks.put(&key, &lock)?;
+        Ok((lock, entry))
+    }
+
+    /// Markiert eine Release-Condition als erfüllt
+    pub fn fulfill_condition(
+        &self,
+        lock_id: &UniversalId,
+        condition: &str,
+    ) -> Result<EscrowLock, LedgerError> {
+        let _guard = self.write_lock.lock();
+        let mut lock = self.open_lock(lock_id)?;
+        if lock.release_conditions.iter().any(|c| c == condition)
+            && !lock.fulfilled.iter().any(|c| c == condition)
+        {
+            lock.fulfilled.push(condition.to_string());
+            self.locks.put(lock_id.to_hex(), &lock)?;
+        }
+        Ok(lock)
+    }
+
+    /// Gibt einen Lock frei
+    ///
+    /// Rückerstattung an den Besitzer (`to == None` oder `to == owner`) ist immer
+    /// möglich. Freigabe an Dritte erfordert, dass alle Release-Conditions erfüllt sind.
+    pub fn unlock(
+        &self,
+        lock_id: &UniversalId,
+        to: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        let _guard = self.write_lock.lock();
+        self.unlock_locked(lock_id, to)
+    }
+
+    fn unlock_locked(
+        &self,
+        lock_id: &UniversalId,
+        to: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        let mut lock = self.open_lock(lock_id)?;
+        let recipient = to.unwrap_or(lock.owner);
+        let refund = recipient == lock.owner;
+
+        if !refund {
+            let missing = lock.pending_conditions();
+            if !missing.is_empty() {
+                return Err(LedgerError::ConditionsNotMet {
+                    lock_id: lock_id.to_hex(),
+                    missing,
+                });
+            }
+        }
+
+        let entry = self.post(
+            EntryKind::Unlock,
+            &lock.asset_type,
+            lock.amount,
+            LedgerAccount::Holder(recipient),
+            LedgerAccount::Escrow(*lock_id),
+            Some(*lock_id),
+        )?;
+
+        lock.status = if refund {
+            LockStatus::Refunded
+        } else {
+            LockStatus::Released { to: recipient }
+        };
+        self.locks.put(lock_id.to_hex(), &lock)?;
+        Ok(entry)
+    }
+
+    fn open_lock(&self, lock_id: &UniversalId) -> Result<EscrowLock, LedgerError> {
+        let lock: EscrowLock = self
+            .locks
+            .get(lock_id.to_hex())?
+            .ok_or_else(|| LedgerError::LockNotFound(lock_id.to_hex()))?;
+        if lock.status != LockStatus::Locked {
+            return Err(LedgerError::LockClosed(lock_id.to_hex()));
+        }
+        Ok(lock)
+    }
+
+    // ========================================================================
+    // Event- und Saga-Integration
+    // ========================================================================
+
+    /// Verbucht ein finalisiertes Event
+    ///
+    /// Idempotent per Event-ID. Events unterhalb von `min_finality` werden
+    /// mit [`LedgerError::NotFinal`] abgelehnt und können später erneut
+    /// angewendet werden.
+    pub fn apply_event(&self, event: &Event) -> Result<ApplyOutcome, LedgerError> {
+        if !Self::is_ledger_event(&event.payload) {
+            return Ok(ApplyOutcome::Ignored);
+        }
+        if event.finality.level < self.config.min_finality {
+            return Err(LedgerError::NotFinal {
+                required: self.config.min_finality,
+                actual: event.finality.level,
+            });
+        }
+
+        let _guard = self.write_lock.lock();
+        if self.is_applied(&event.id)? {
+            return Ok(ApplyOutcome::AlreadyApplied);
+        }
+
+        let source = Some(event.id);
+        let outcome = match &event.payload {
+            EventPayload::Transfer {
+                from,
+                to,
+                amount,
+                asset_type,
+            } => {
+                if &event.author != from {
+                    return Err(Self::unauthorized(&event.author, "transfer", asset_type));
+                }
+                ApplyOutcome::Posted(self.transfer_locked(from, to, asset_type, *amount, source)?)
+            }
+            EventPayload::Mint {
+                to,
+                amount,
+                asset_type,
+            } => ApplyOutcome::Posted(self.mint_locked(
+                &event.author,
+                to,
+                asset_type,
+                *amount,
+                source,
+            )?),
+            EventPayload::Burn {
+                from,
+                amount,
+                asset_type,
+            } => ApplyOutcome::Posted(self.burn_locked(
+                &event.author,
+                from,
+                asset_type,
+                *amount,
+                source,
+            )?),
+            EventPayload::Delegate {
+                from,
+                to,
+                capabilities,
+                trust_factor,
+            } => {
+                if &event.author != from {
+                    return Err(LedgerError::InvalidDelegation(
+                        "author is not the delegator".to_string(),
+                    ));
+                }
+                InvariantChecker::check_delegation_trust_factor(*trust_factor)?;
+                let capabilities = capabilities
+                    .iter()
+                    .map(|c| Capability::parse(c))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| LedgerError::InvalidDelegation(e.to_string()))?;
+                let mut delegation = Delegation::new(*from, *to, *trust_factor, capabilities);
+                // Event-ID als Delegations-ID, damit DelegationRevoke sie referenzieren kann
+                delegation.id = event.id;
+                self.grant_delegation(delegation)?;
+                ApplyOutcome::DelegationUpdated
+            }
+            EventPayload::DelegationRevoke { delegation_id } => {
+                if let Some(delegation) = self
+                    .delegations
+                    .get::<_, Delegation>(delegation_id.to_hex())?
+                {
+                    if delegation.delegator != event.author {
+                        return Err(LedgerError::InvalidDelegation(
+                            "author is not the delegator".to_string(),
+                        ));
+                    }
+                    self.revoke_delegation(delegation_id)?;
+                }
+                ApplyOutcome::DelegationUpdated
+            }
+            _ => ApplyOutcome::Ignored,
+        };
+
+        self.applied
+            .put(event.id.to_hex(), &self.next_seq.load(Ordering::SeqCst))?;
+        Ok(outcome)
+    }
+
+    fn is_ledger_event(payload: &EventPayload) -> bool {
+        matches!(
+            payload,
+            EventPayload::Transfer { .. }
+                | EventPayload::Mint { .. }
+                | EventPayload::Burn { .. }
+                | EventPayload::Delegate { .. }
+                | EventPayload::DelegationRevoke { .. }
+        )
+    }
+
+    /// Führt eine Saga-Aktion gegen den Ledger aus
+    ///
+    /// Gibt `None` für Aktionen ohne Ledger-Bezug zurück. Bei Mint/Burn mit
+    /// `authorization` muss die referenzierte Delegation gültig sein und
+    /// `actor` als Delegierten haben.
+    pub fn apply_saga_action(
+        &self,
+        actor: &UniversalId,
+        action: &SagaAction,
+    ) -> Result<Option<JournalEntry>, LedgerError> {
+        let _guard = self.write_lock.lock();
+        self.apply_saga_action_locked(actor, action)
+    }
+
+    /// Wie [`Ledger::apply_saga_action`], aber idempotent per Operations-ID
+    ///
+    /// Der Saga-Executor vergibt pro Schritt (und Kompensation) eine
+    /// deterministische `op_id`. Wird ein Schritt nach einem Neustart erneut
+    /// ausgeführt, liefert der Aufruf den bereits gebuchten Eintrag zurück,
+    /// statt doppelt zu buchen.
+    pub fn apply_saga_action_once(
+        &self,
+        op_id: &UniversalId,
+        actor: &UniversalId,
+        action: &SagaAction,
+    ) -> Result<Option<JournalEntry>, LedgerError> {
+        let _guard = self.write_lock.lock();
+        let key = op_id.to_hex();
+        if let Some(seq) = self.applied.get::<_, Option<u64>>(&key)? {
+            return Ok(match seq {
+                Some(seq) => self.journal.get(Self::seq_key(seq))?,
+                None => None,
+            });
+        }
+
+        let entry = self.apply_saga_action_locked(actor, action)?;
+        self.applied.put(&key, &entry.as_ref().map(|e| e.seq))?;
+        Ok(entry)
+    }
+
+    fn apply_saga_action_locked(
+        &self,
+        actor: &UniversalId,
+        action: &SagaAction,
+    ) -> Result<Option<JournalEntry>, LedgerError> {
+        let entry = match action {
+            SagaAction::Transfer {
+                from,
+                to,
+                amount,
+                asset_type,
+            } => Some(self.transfer_locked(from, to, asset_type, *amount, None)?),
+            SagaAction::Mint {
+                asset_type,
+                amount,
+                to,
+                authorization,
+            } => {
+                self.check_authorization(actor, authorization.as_ref(), "mint", asset_type)?;
+                Some(self.mint_locked(actor, to, asset_type, *amount, None)?)
+            }
+            SagaAction::Burn {
+                asset_type,
+                amount,
+                from,
+                authorization,
+            } => {
+                self.check_authorization(actor, authorization.as_ref(), "burn", asset_type)?;
+                Some(self.burn_locked(actor, from, asset_type, *amount, None)?)
+            }
+            SagaAction::Lock {
+                owner,
+                asset_type,
+                amount,
+                lock_id,
+                release_conditions,
+            } => {
+                let (_, entry) = self.lock_locked(
+                    owner,
+                    asset_type,
+                    *amount,
+                    *lock_id,
+                    release_conditions.clone(),
+                )?;
+                Some(entry)
+            }
+            SagaAction::Unlock { lock_id, to } => Some(self.unlock_locked(lock_id, *to)?),
+            _ => None,
+        };
+        Ok(entry)
+    }
+
+    fn check_authorization(
+        &self,
+        actor: &UniversalId,
+        authorization: Option<&UniversalId>,
+        action: &str,
+        asset_type: &str,
+    ) -> Result<(), LedgerError> {
+        let Some(delegation_id) = authorization else {
+            return Ok(());
+        };
+        let delegation: Delegation = self
+            .delegations
+            .get(delegation_id.to_hex())?
+            .ok_or_else(|| LedgerError::InvalidDelegation(delegation_id.to_hex()))?;
+        if &delegation.delegate != actor || !delegation.is_valid(&TemporalCoord::now(0, actor)) {
+            return Err(Self::unauthorized(actor, action, asset_type));
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // Invarianten
+    // ========================================================================
+
+    /// Prüft Supply- und Double-Entry-Invarianten über den gesamten Ledger
+    pub fn verify_invariants(&self) -> Result<Vec<InvariantViolation>> {
+        let mut circulating: BTreeMap<String, u64> = BTreeMap::new();
+        for item in self.balances.iter::<BTreeMap<String, u64>>() {
+            let (_, balances) = item?;
+            for (asset, amount) in balances {
+                let sum = circulating.entry(asset).or_insert(0);
+                *sum = sum.saturating_add(amount);
+            }
+        }
+
+        let mut violations = Vec::new();
+        let mut assets: BTreeMap<String, AssetSupply> = BTreeMap::new();
+        for item in self.supply.iter::<AssetSupply>() {
+            let (key, supply) = item?;
+            assets.insert(String::from_utf8_lossy(&key).into_owned(), supply);
+        }
+        for asset in circulating.keys() {
+            assets.entry(asset.clone()).or_default();
+        }
+        for (asset, supply) in &assets {
+            let actual = circulating.get(asset).copied().unwrap_or(0);
+            if let Err(v) =
+                InvariantChecker::check_total_supply(asset, supply.minted, supply.burned, actual)
+            {
+                violations.push(v);
+            }
+        }
+
+        for item in self.journal.iter::<JournalEntry>() {
+            let (_, entry) = item?;
+            let postings = entry.postings();
+            let debits = postings.iter().map(|p| p.debit).sum();
+            let credits = postings.iter().map(|p| p.credit).sum();
+            if let Err(v) = InvariantChecker::check_double_entry(&entry.asset_type, debits, credits)
+            {
+                violations.push(v);
+            }
+        }
+
+        Ok(violations)
+    }
+
+    // ========================================================================
+    // Metriken
+    // ========================================================================
+
+    /// Snapshot für Monitoring
+    pub fn snapshot(&self) -> LedgerSnapshot {
+        let open_locks = self
+            .locks
+            .iter::<EscrowLock>()
+            .filter_map(|r| r.ok())
+            .filter(|(_, l)| l.status == LockStatus::Locked)
+            .count();
+
+        LedgerSnapshot {
+            accounts: self.balances.len(),
+            journal_entries: self.journal_len(),
+            assets: self.assets.len(),
+            open_locks,
+            delegations: self.delegations.len(),
+            metrics: self.metrics.snapshot(),
+        }
+    }
+
+    /// Metriken-Snapshot
+    pub fn metrics_snapshot(&self) -> StoreMetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    // ========================================================================
+    // Intern
+    // ========================================================================
+
+    fn seq_key(seq: u64) -> String {
+        format!("{:020}", seq)
+    }
+
+    fn unauthorized(actor: &UniversalId, action: &str, asset_type: &str) -> LedgerError {
+        LedgerError::Unauthorized {
+            actor: actor.to_hex(),
+            action: action.to_string(),
+            asset_type: asset_type.to_string(),
+        }
+    }
+
+    /// Bucht einen Eintrag. Erwartet, dass `write_lock` gehalten wird.
+    fn post(
+        &self,
+        kind: EntryKind,
+        asset_type: &str,
+        amount: u64,
+        debit: LedgerAccount,
+        credit: LedgerAccount,
+        source: Option<UniversalId>,
+    ) -> Result<JournalEntry, LedgerError> {
+        let start = Instant::now();
+        if amount == 0 {
+            return Err(LedgerError::ZeroAmount);
+        }
+
+        // Haben-Seite: Deckung prüfen (Issuance ist unbegrenzt)
+        let credit_balances = match &credit {
+            LedgerAccount::Issuance => None,
+            account => {
+                let balances = self.balances(account)?;
+                let available = balances.get(asset_type).copied().unwrap_or(0);
+                if available < amount {
+                    self.metrics.record_error();
+                    return Err(LedgerError::InsufficientBalance {
+                        account: account.key(),
+                        asset_type: asset_type.to_string(),
+                        available,
+                        required: amount,
+                    });
+                }
+                Some(balances)
+            }
+        };
+
+        let mut supply = self.supply(asset_type)?;
+        match (&debit, &credit) {
+            (_, LedgerAccount::Issuance) => {
+                supply.minted = supply
+                    .minted
+                    .checked_add(amount)
+                    .ok_or_else(|| LedgerError::Overflow(asset_type.to_string()))?;
+            }
+            (LedgerAccount::Issuance, _) => {
+                supply.burned = supply
+                    .burned
+                    .checked_add(amount)
+                    .ok_or_else(|| LedgerError::Overflow(asset_type.to_string()))?;
+            }
+            _ => {}
+        }
+
+        let seq = self.next_seq.load(Ordering::SeqCst);
+        let entry = JournalEntry {
+            seq,
+            kind,
+            asset_type: asset_type.to_string(),
+            amount,
+            debit,
+            credit,
+            source,
+            timestamp: chrono::Utc::now().timestamp(),
+        };
+        let postings = entry.postings();
+        InvariantChecker::check_double_entry(
+            asset_type,
+            postings.iter().map(|p| p.debit).sum(),
+            postings.iter().map(|p| p.credit).sum(),
+        )?;
+
+        // Soll-Seite vorab berechnen, damit ein Overflow nichts halb verbucht.
+        // Bei Selbstüberweisungen bleibt der Saldo unverändert.
+        let debit_balances = match &entry.debit {
+            LedgerAccount::Issuance => None,
+            account if account == &entry.credit => None,
+            account => {
+                let mut balances = self.balances(account)?;
+                let current = balances.entry(asset_type.to_string()).or_insert(0);
+                *current = current
+                    .checked_add(amount)
+                    .ok_or_else(|| LedgerError::Overflow(account.key()))?;
+                Some(balances)
+            }
+        };
+
+        if entry.debit != entry.credit {
+            if let Some(mut balances) = credit_balances {
+                let current = balances.entry(asset_type.to_string()).or_insert(0);
+                *current -= amount;
+                self.balances.put(entry.credit.key(), &balances)?;
+            }
+        }
+        if let Some(balances) = debit_balances {
+            self.balances.put(entry.debit.key(), &balances)?;
+        }
+        if matches!(entry.debit, LedgerAccount::Issuance)
+            || matches!(entry.credit, LedgerAccount::Issuance)
+        {
+            self.supply.put(asset_type, &supply)?;
+        }
+        self.journal.put(Self::seq_key(seq), &entry)?;
+        self.next_seq.store(seq + 1, Ordering::SeqCst);
+
+        self.metrics
+            .record_write(start.elapsed().as_micros() as u64, 256);
+        Ok(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::unified::DID;
+
+    fn create_test_ledger() -> Ledger {
+        let folder = tempfile::tempdir().unwrap();
+        let keyspace = fjall::Config::new(folder.path()).open().unwrap();
+        Ledger::new(&keyspace).unwrap()
+    }
+
+    fn id(name: &str) -> UniversalId {
+        DID::new_self(name.as_bytes()).id
+    }
+
+    fn final_event(author: UniversalId, payload: EventPayload, lamport: u32) -> Event {
+        let mut event = Event::new(author, vec![], payload, lamport);
+        event.finality.level = FinalityLevel::Witnessed;
+        event
+    }
+
+    #[test]
+    fn test_mint_transfer_burn() {
+        let ledger = create_test_ledger();
+        let (issuer, alice, bob) = (id("issuer"), id("alice"), id("bob"));
+        ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+
+        ledger
+            .mint(&issuer, &alice, NATIVE_ASSET, 100, None)
+            .unwrap();
+        ledger
+            .transfer(&alice, &bob, NATIVE_ASSET, 30, None)
+            .unwrap();
+        ledger.burn(&bob, &bob, NATIVE_ASSET, 10, None).unwrap();
+
+        assert_eq!(ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 70);
+        assert_eq!(ledger.balance_of(&bob, NATIVE_ASSET).unwrap(), 20);
+        let supply = ledger.supply(NATIVE_ASSET).unwrap();
+        assert_eq!((supply.minted, supply.burned), (100, 10));
+        assert_eq!(ledger.journal_len(), 3);
+        assert!(ledger.verify_invariants().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_balances_at_snapshot_height() {
+        let ledger = create_test_ledger();
+        let (issuer, alice, bob) = (id("issuer"), id("alice"), id("bob"));
+        ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+        ledger
+            .mint(&issuer, &alice, NATIVE_ASSET, 100, None)
+            .unwrap();
+        let height = ledger.journal_len();
+        ledger
+            .transfer(&alice, &bob, NATIVE_ASSET, 60, None)
+            .unwrap();
+
+        let snapshot = ledger.balances_at(NATIVE_ASSET, height).unwrap();
+        assert_eq!(snapshot.get(&alice), Some(&100));
+        assert_eq!(snapshot.get(&bob), None);
+        let current = ledger.balances_at(NATIVE_ASSET, u64::MAX).unwrap();
+        assert_eq!((current[&alice], current[&bob]), (40, 60));
+    }
+
+    #[test]
+    fn test_insufficient_balance_and_unauthorized() {
+        let ledger = create_test_ledger();
+        let (issuer, alice, bob) = (id("issuer"), id("alice"), id("bob"));
+        ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+        ledger
+            .mint(&issuer, &alice, NATIVE_ASSET, 10, None)
+            .unwrap();
+
+        assert!(matches!(
+            ledger.transfer(&alice, &bob, NATIVE_ASSET, 11, None),
+            Err(LedgerError::InsufficientBalance { .. })
+        ));
+        assert!(matches!(
+            ledger.mint(&alice, &alice, NATIVE_ASSET, 1, None),
+            Err(LedgerError::Unauthorized { .. })
+        ));
+        assert!(matches!(
+            ledger.burn(&bob, &alice, NATIVE_ASSET, 1, None),
+            Err(LedgerError::Unauthorized { .. })
+        ));
+        assert!(matches!(
+            ledger.mint(&issuer, &alice, "GOLD", 1, None),
+            Err(LedgerError::UnknownAsset(_))
+        ));
+        assert_eq!(ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_apply_event_requires_finality_and_is_idempotent() {
+        let ledger = create_test_ledger();
+        let (issuer, alice) = (id("issuer"), id("alice"));
+        ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+
+        let payload = EventPayload::Mint {
+            to: alice,
+            amount: 50,
+            asset_type: NATIVE_ASSET.to_string(),
+        };
+        let mut event = Event::new(issuer, vec![], payload, 1);
+        assert!(matches!(
+            ledger.apply_event(&event),
+            Err(LedgerError::NotFinal { .. })
+        ));
+
+        event.finality.level = FinalityLevel::Witnessed;
+        assert!(matches!(
+            ledger.apply_event(&event).unwrap(),
+            ApplyOutcome::Posted(_)
+        ));
+        assert!(matches!(
+            ledger.apply_event(&event).unwrap(),
+            ApplyOutcome::AlreadyApplied
+        ));
+        assert_eq!(ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_delegated_mint_and_revoke() {
+        let ledger = create_test_ledger();
+        let (issuer, minter, alice) = (id("issuer"), id("minter"), id("alice"));
+        ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+        assert!(!ledger.can_mint(&minter, NATIVE_ASSET).unwrap());
+
+        let delegate = final_event(
+            issuer,
+            EventPayload::Delegate {
+                from: issuer,
+                to: minter,
+                capabilities: vec!["custom:mint:ERY".to_string()],
+                trust_factor: 0.8,
+            },
+            1,
+        );
+        ledger.apply_event(&delegate).unwrap();
+        assert!(ledger.can_mint(&minter, NATIVE_ASSET).unwrap());
+        ledger.mint(&minter, &alice, NATIVE_ASSET, 5, None).unwrap();
+
+        let revoke = final_event(
+            issuer,
+            EventPayload::DelegationRevoke {
+                delegation_id: delegate.id,
+            },
+            2,
+        );
+        ledger.apply_event(&revoke).unwrap();
+        assert!(!ledger.can_mint(&minter, NATIVE_ASSET).unwrap());
+    }
+
+    #[test]
+    fn test_escrow_release_conditions() {
+        let ledger = create_test_ledger();
+        let (issuer, alice, bob) = (id("issuer"), id("alice"), id("bob"));
+        ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+        ledger
+            .mint(&issuer, &alice, NATIVE_ASSET, 100, None)
+            .unwrap();
+
+        let lock = ledger
+            .lock(
+                &alice,
+                NATIVE_ASSET,
+                40,
+                None,
+                vec!["delivered".to_string()],
+            )
+            .unwrap();
+        assert_eq!(ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 60);
+        assert_eq!(
+            ledger
+                .balance(&LedgerAccount::Escrow(lock.lock_id), NATIVE_ASSET)
+                .unwrap(),
+            40
+        );
+
+        assert!(matches!(
+            ledger.unlock(&lock.lock_id, Some(bob)),
+            Err(LedgerError::ConditionsNotMet { .. })
+        ));
+        ledger
+            .fulfill_condition(&lock.lock_id, "delivered")
+            .unwrap();
+        ledger.unlock(&lock.lock_id, Some(bob)).unwrap();
+
+        assert_eq!(ledger.balance_of(&bob, NATIVE_ASSET).unwrap(), 40);
+        assert!(matches!(
+            ledger.unlock(&lock.lock_id, None),
+            Err(LedgerError::LockClosed(_))
+        ));
+        assert!(ledger.verify_invariants().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_holder_id_from_did_uri() {
+        let did = DID::new_self(b"alice");
+        assert_eq!(LedgerAccount::holder_id(&did.to_uri()), Some(did.id));
+        assert_eq!(LedgerAccount::holder_id(&did.id.to_hex()), Some(did.id));
+        assert_eq!(LedgerAccount::holder_id("did:erynoa:self:zz"), None);
+    }
+
+    #[test]
+    fn test_escrow_refund_always_allowed() {
+        let ledger = create_test_ledger();
+        let (issuer, alice) = (id("issuer"), id("alice"));
+        ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+        ledger
+            .mint(&issuer, &alice, NATIVE_ASSET, 10, None)
+            .unwrap();
+
+        let action = SagaAction::Lock {
+            owner: alice,
+            asset_type: NATIVE_ASSET.to_string(),
+            amount: 10,
+            lock_id: None,
+            release_conditions: vec!["never".to_string()],
+        };
+        let entry = ledger.apply_saga_action(&alice, &action).unwrap().unwrap();
+        let lock_id = entry.source.unwrap();
+
+        ledger.unlock(&lock_id, None).unwrap();
+        assert_eq!(ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 10);
+        assert_eq!(
+            ledger.lock_info(&lock_id).unwrap().unwrap().status,
+            LockStatus::Refunded
+        );
+    }
+}
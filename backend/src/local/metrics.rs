@@ -142,6 +142,12 @@ pub struct StoreMetrics {
     /// Maximale Schreib-Latenz (µs)
     pub max_write_latency_us: AtomicU64,
 
+    /// Verteilung der Lese-Latenzen
+    pub read_latency: LatencyHistogram,
+
+    /// Verteilung der Schreib-Latenzen
+    pub write_latency: LatencyHistogram,
+
     // ─────────────────────────────────────────────────────────────────────────
     // TIMESTAMPS
     // ─────────────────────────────────────────────────────────────────────────
@@ -176,6 +182,8 @@ impl StoreMetrics {
             avg_write_latency_us: RwLock::new(0.0),
             max_read_latency_us: AtomicU64::new(0),
             max_write_latency_us: AtomicU64::new(0),
+            read_latency: LatencyHistogram::new(),
+            write_latency: LatencyHistogram::new(),
             created_at_ms: now,
             last_operation_ms: AtomicU64::new(now),
             last_success_ms: AtomicU64::new(now),
@@ -208,6 +216,7 @@ impl StoreMetrics {
 
         // Update max latency
         update_max(&self.max_read_latency_us, latency_us);
+        self.read_latency.observe(latency_us);
 
         // Cache tracking (if bytes > 0, it was a hit conceptually)
         if bytes > 0 {
@@ -239,6 +248,7 @@ impl StoreMetrics {
 
         // Update max latency
         update_max(&self.max_write_latency_us, latency_us);
+        self.write_latency.observe(latency_us);
     }
 
     /// Lösch-Operation aufzeichnen
@@ -391,6 +401,8 @@ impl StoreMetrics {
             avg_write_latency_us: self.avg_write_latency_us.read().map(|v| *v).unwrap_or(0.0),
             max_read_latency_us: self.max_read_latency_us.load(Ordering::Relaxed),
             max_write_latency_us: self.max_write_latency_us.load(Ordering::Relaxed),
+            read_latency: self.read_latency.snapshot(),
+            write_latency: self.write_latency.snapshot(),
             created_at_ms: self.created_at_ms,
             last_operation_ms: self.last_operation_ms.load(Ordering::Relaxed),
             last_success_ms: self.last_success_ms.load(Ordering::Relaxed),
@@ -452,6 +464,79 @@ impl StorageMetrics for StoreMetrics {
     }
 }
 
+// ============================================================================
+// LATENCY HISTOGRAM
+// ============================================================================
+
+/// Obere Bucket-Grenzen der Latenz-Histogramme (µs)
+pub const LATENCY_BUCKETS_US: [u64; 10] = [
+    10, 50, 100, 250, 500, 1_000, 5_000, 10_000, 50_000, 100_000,
+];
+
+/// Lock-freies Latenz-Histogramm mit festen Buckets
+///
+/// Der letzte Bucket zählt alle Werte oberhalb von `LATENCY_BUCKETS_US`.
+#[derive(Debug)]
+pub struct LatencyHistogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS_US.len() + 1],
+    sum_us: AtomicU64,
+}
+
+impl LatencyHistogram {
+    pub fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            sum_us: AtomicU64::new(0),
+        }
+    }
+
+    /// Latenz in Mikrosekunden einsortieren
+    pub fn observe(&self, latency_us: u64) {
+        let idx = LATENCY_BUCKETS_US
+            .iter()
+            .position(|bound| latency_us <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_US.len());
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(latency_us, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> LatencyHistogramSnapshot {
+        LatencyHistogramSnapshot {
+            bounds_us: LATENCY_BUCKETS_US.to_vec(),
+            counts: self
+                .buckets
+                .iter()
+                .map(|b| b.load(Ordering::Relaxed))
+                .collect(),
+            sum_us: self.sum_us.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Snapshot eines Latenz-Histogramms
+///
+/// `counts` ist nicht kumulativ und hat einen Eintrag mehr als `bounds_us`
+/// (Überlauf-Bucket).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LatencyHistogramSnapshot {
+    pub bounds_us: Vec<u64>,
+    pub counts: Vec<u64>,
+    pub sum_us: u64,
+}
+
+impl LatencyHistogramSnapshot {
+    /// Anzahl aller Beobachtungen
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+}
+
 // ============================================================================
 // SNAPSHOT TYPE
 // ============================================================================
@@ -476,6 +561,10 @@ pub struct StoreMetricsSnapshot {
     pub avg_write_latency_us: f64,
     pub max_read_latency_us: u64,
     pub max_write_latency_us: u64,
+    #[serde(default)]
+    pub read_latency: LatencyHistogramSnapshot,
+    #[serde(default)]
+    pub write_latency: LatencyHistogramSnapshot,
 
     // Timestamps
     pub created_at_ms: u64,
@@ -505,6 +594,8 @@ impl Default for StoreMetricsSnapshot {
             avg_write_latency_us: 0.0,
             max_read_latency_us: 0,
             max_write_latency_us: 0,
+            read_latency: LatencyHistogramSnapshot::default(),
+            write_latency: LatencyHistogramSnapshot::default(),
             created_at_ms: 0,
             last_operation_ms: 0,
             last_success_ms: 0,
@@ -745,6 +836,22 @@ mod tests {
         assert!(((*avg) - 200.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_latency_histogram() {
+        let metrics = StoreMetrics::new();
+
+        metrics.record_read(5, 1);
+        metrics.record_read(120, 1);
+        metrics.record_read(2_000_000, 1);
+
+        let histogram = metrics.snapshot().read_latency;
+        assert_eq!(histogram.count(), 3);
+        assert_eq!(histogram.sum_us, 2_000_125);
+        assert_eq!(histogram.counts[0], 1); // <= 10µs
+        assert_eq!(histogram.counts[3], 1); // <= 250µs
+        assert_eq!(histogram.counts[LATENCY_BUCKETS_US.len()], 1); // Überlauf
+    }
+
     #[test]
     fn test_record_delete() {
         let metrics = StoreMetrics::new();
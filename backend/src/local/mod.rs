@@ -30,15 +30,25 @@
 //! - Health-Score-Berechnung pro Store
 
 pub mod archive;
+mod audit_log;
 pub mod blueprint_marketplace;
+mod challenge_store;
 mod content_store;
+mod ecl_store;
 mod event_store;
+mod governance_store;
+mod idempotency_store;
 mod identity_store;
+mod intent_store;
 mod kv_store;
+mod ledger;
 pub mod metrics;
 pub mod realm_storage;
+mod saga_store;
+mod session_store;
 mod trust_store;
 
+pub use audit_log::{AuditEntry, AuditLog, AuditRecord, AuditVerification, GENESIS_HASH};
 pub use blueprint_marketplace::{
     // Blueprint-Typen
     Blueprint,
@@ -70,10 +80,24 @@ pub use blueprint_marketplace::{
     SearchResult,
     SemVer,
 };
+pub use challenge_store::{ChallengeRecord, ChallengeStore};
 pub use content_store::{ContentId, ContentMetadata, ContentStore, ContentStoreSnapshot, StoredContent};
+pub use ecl_store::{EclHandlerRecord, EclHandlerStore};
 pub use event_store::{EventStore, EventStoreSnapshot, StoredEvent};
+pub use governance_store::{
+    CreditGrant, GovernanceAction, GovernanceStore, ProposalPhase, ProposalRecord, QuotaLimits,
+    RealmGovernanceRecord, TallyRecord, VoteRecord,
+};
+pub use idempotency_store::{IdempotencyRecord, IdempotencyStore};
 pub use identity_store::{IdentityStore, IdentityStoreSnapshot, StoredIdentity};
+pub use intent_store::{IntentPhase, IntentRecord, IntentStore};
 pub use kv_store::KvStore;
+pub use ledger::{
+    ApplyOutcome, AssetDefinition, AssetSupply, EntryKind, EscrowLock, JournalEntry, Ledger,
+    LedgerAccount, LedgerConfig, LedgerError, LedgerSnapshot, LockStatus, Posting, NATIVE_ASSET,
+};
+pub use saga_store::{CompensationRecord, SagaPhase, SagaRecord, SagaStore};
+pub use session_store::{SessionRecord, SessionStore};
 pub use trust_store::TrustStoreSnapshot;
 pub use realm_storage::{
     PrefixBuilder,
@@ -102,7 +126,8 @@ pub use archive::{
 
 // Storage Metrics Framework (Phase 1)
 pub use metrics::{
-    AggregateMetricsSnapshot, StorageMetrics, StoreMetrics, StoreMetricsSnapshot,
+    AggregateMetricsSnapshot, LatencyHistogram, LatencyHistogramSnapshot, StorageMetrics,
+    StoreMetrics, StoreMetricsSnapshot, LATENCY_BUCKETS_US,
 };
 
 use anyhow::Result;
@@ -122,6 +147,15 @@ use std::sync::Arc;
 /// - `events`: Kausaler Event-DAG
 /// - `trust`: Trust-Vektoren zwischen Entitäten
 /// - `content`: Content Addressable Storage (BLAKE3)
+/// - `ledger`: Token-Salden, Journal und Escrow-Locks
+/// - `sagas`: Saga-Ausführungszustand (Κ22-Κ24)
+/// - `intents`: Über die API eingereichte Intents
+/// - `governance`: Realm-Proposals, Stimmen und Voting-Credits (Κ21)
+/// - `ecl`: Per Realm hochgeladene ECL-Handler (Quelltext + Bytecode)
+/// - `sessions`: Login-Sessions und Token-Signaturschlüssel
+/// - `challenges`: Offene WebAuthn-Challenges (einmalig, mit Ablauf)
+/// - `audit_log`: Hash-verkettetes Protokoll privilegierter API-Aufrufe
+/// - `idempotency`: Antworten schreibender Requests je Idempotency-Key (mit TTL)
 /// - `realm_storage`: Dynamische Realm-Stores mit Prefixing
 #[derive(Clone)]
 pub struct DecentralizedStorage {
@@ -135,6 +169,24 @@ pub struct DecentralizedStorage {
     pub trust: TrustStore,
     /// Content Addressable Storage
     pub content: ContentStore,
+    /// Token- und Asset-Ledger
+    pub ledger: Ledger,
+    /// Persistierte Sagas (Executor-Zustand)
+    pub sagas: SagaStore,
+    /// Eingereichte Intents (vor und nach Auflösung)
+    pub intents: IntentStore,
+    /// Realm-Governance (Proposals, Stimmen)
+    pub governance: GovernanceStore,
+    /// ECL-Handler der Realms
+    pub ecl: EclHandlerStore,
+    /// Login-Sessions (Passkey → Session-Token)
+    pub sessions: SessionStore,
+    /// WebAuthn-Challenges (einmalig einlösbar)
+    pub challenges: ChallengeStore,
+    /// Audit Log privilegierter Aufrufe (append-only)
+    pub audit: AuditLog,
+    /// Gespeicherte Antworten je Idempotency-Key
+    pub idempotency: IdempotencyStore,
     /// Realm Storage (Dynamische Stores)
     pub realm: RealmStorage,
 }
@@ -145,9 +197,18 @@ impl DecentralizedStorage {
         let keyspace = Arc::new(fjall::Config::new(path.as_ref().join("data")).open()?);
 
         let identities = IdentityStore::new(&keyspace)?;
-        let events = EventStore::new(&keyspace)?;
+        let ledger = Ledger::new(&keyspace)?;
+        let events = EventStore::new(&keyspace)?.with_ledger(ledger.clone());
         let trust = TrustStore::new(&keyspace)?;
         let content = ContentStore::new(&keyspace)?;
+        let sagas = SagaStore::new(&keyspace)?;
+        let intents = IntentStore::new(&keyspace)?;
+        let governance = GovernanceStore::new(&keyspace)?;
+        let ecl = EclHandlerStore::new(&keyspace)?;
+        let sessions = SessionStore::new(&keyspace)?;
+        let challenges = ChallengeStore::new(&keyspace)?;
+        let audit = AuditLog::new(&keyspace)?;
+        let idempotency = IdempotencyStore::new(&keyspace)?;
         let realm = RealmStorage::new(&keyspace, RealmStorageConfig::default())?;
 
         Ok(Self {
@@ -156,6 +217,15 @@ impl DecentralizedStorage {
             events,
             trust,
             content,
+            ledger,
+            sagas,
+            intents,
+            governance,
+            ecl,
+            sessions,
+            challenges,
+            audit,
+            idempotency,
             realm,
         })
     }
@@ -166,9 +236,18 @@ impl DecentralizedStorage {
         let keyspace = Arc::new(fjall::Config::new(folder.path()).open()?);
 
         let identities = IdentityStore::new(&keyspace)?;
-        let events = EventStore::new(&keyspace)?;
+        let ledger = Ledger::new(&keyspace)?;
+        let events = EventStore::new(&keyspace)?.with_ledger(ledger.clone());
         let trust = TrustStore::new(&keyspace)?;
         let content = ContentStore::new(&keyspace)?;
+        let sagas = SagaStore::new(&keyspace)?;
+        let intents = IntentStore::new(&keyspace)?;
+        let governance = GovernanceStore::new(&keyspace)?;
+        let ecl = EclHandlerStore::new(&keyspace)?;
+        let sessions = SessionStore::new(&keyspace)?;
+        let challenges = ChallengeStore::new(&keyspace)?;
+        let audit = AuditLog::new(&keyspace)?;
+        let idempotency = IdempotencyStore::new(&keyspace)?;
         let realm = RealmStorage::new(&keyspace, RealmStorageConfig::default())?;
 
         Ok(Self {
@@ -177,6 +256,15 @@ impl DecentralizedStorage {
             events,
             trust,
             content,
+            ledger,
+            sagas,
+            intents,
+            governance,
+            ecl,
+            sessions,
+            challenges,
+            audit,
+            idempotency,
             realm,
         })
     }
@@ -189,9 +277,18 @@ impl DecentralizedStorage {
         let keyspace = Arc::new(fjall::Config::new(path.as_ref().join("data")).open()?);
 
         let identities = IdentityStore::new(&keyspace)?;
-        let events = EventStore::new(&keyspace)?;
+        let ledger = Ledger::new(&keyspace)?;
+        let events = EventStore::new(&keyspace)?.with_ledger(ledger.clone());
         let trust = TrustStore::new(&keyspace)?;
         let content = ContentStore::new(&keyspace)?;
+        let sagas = SagaStore::new(&keyspace)?;
+        let intents = IntentStore::new(&keyspace)?;
+        let governance = GovernanceStore::new(&keyspace)?;
+        let ecl = EclHandlerStore::new(&keyspace)?;
+        let sessions = SessionStore::new(&keyspace)?;
+        let challenges = ChallengeStore::new(&keyspace)?;
+        let audit = AuditLog::new(&keyspace)?;
+        let idempotency = IdempotencyStore::new(&keyspace)?;
         let realm = RealmStorage::new(&keyspace, realm_config)?;
 
         Ok(Self {
@@ -200,6 +297,15 @@ impl DecentralizedStorage {
             events,
             trust,
             content,
+            ledger,
+            sagas,
+            intents,
+            governance,
+            ecl,
+            sessions,
+            challenges,
+            audit,
+            idempotency,
             realm,
         })
     }
@@ -327,14 +433,18 @@ impl DecentralizedStorage {
 
     /// Aggregierte Metriken für alle Stores
     pub fn aggregate_metrics(&self) -> AggregateMetricsSnapshot {
-        let stores = vec![
+        AggregateMetricsSnapshot::from_stores(&self.store_metrics())
+    }
+
+    /// Metriken-Snapshots aller Stores, benannt nach Store
+    pub fn store_metrics(&self) -> Vec<(String, StoreMetricsSnapshot)> {
+        vec![
             ("identities".to_string(), self.identities.metrics().snapshot()),
             ("events".to_string(), self.events.metrics().snapshot()),
             ("trust".to_string(), self.trust.metrics().snapshot()),
             ("content".to_string(), self.content.metrics().snapshot()),
-        ];
-
-        AggregateMetricsSnapshot::from_stores(&stores)
+            ("ledger".to_string(), self.ledger.metrics_snapshot()),
+        ]
     }
 
     // ─────────────────────────────────────────────────────────────────────────
@@ -914,6 +1024,42 @@ mod tests {
         assert_eq!(snapshot.total_entries(), 1);
     }
 
+    #[test]
+    fn test_finalized_event_posts_to_ledger() {
+        use crate::domain::{Event, EventPayload, FinalityLevel, FinalityState, TemporalCoord, DID};
+
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let (issuer, alice) = (DID::new_self(b"issuer").id, DID::new_self(b"alice").id);
+        storage.ledger.register_asset(NATIVE_ASSET, issuer).unwrap();
+
+        let mint = EventPayload::Mint {
+            to: alice,
+            amount: 50,
+            asset_type: NATIVE_ASSET.to_string(),
+        };
+        let event = Event::new(issuer, vec![], mint, 1);
+        let event_id = event.id;
+        storage.events.put(event).unwrap();
+
+        // Nascent: noch keine Buchung
+        assert_eq!(storage.ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 0);
+        assert_eq!(storage.ledger.journal_len(), 0);
+
+        let mut finality = FinalityState::nascent(TemporalCoord::new(1000, 0, 0));
+        finality.level = FinalityLevel::Witnessed;
+        storage
+            .events
+            .update_finality(&event_id, finality.clone(), 3)
+            .unwrap();
+        assert_eq!(storage.ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 50);
+        assert_eq!(storage.ledger.journal_len(), 1);
+
+        // Weitere Finalitäts-Stufen buchen nicht erneut
+        finality.level = FinalityLevel::Eternal;
+        storage.events.update_finality(&event_id, finality, 9).unwrap();
+        assert_eq!(storage.ledger.journal_len(), 1);
+    }
+
     #[test]
     fn test_store_metrics_basic() {
         let metrics = StoreMetrics::new();
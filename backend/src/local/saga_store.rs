@@ -0,0 +1,182 @@
+//! Saga Store
+//!
+//! Persistiert Sagas samt Ausführungszustand für den Saga-Executor.
+//!
+//! Nach jedem Schritt schreibt der Executor den kompletten [`SagaRecord`]
+//! zurück. Nach einem Neustart liefert [`SagaStore::in_flight`] alle Sagas,
+//! deren Ausführung oder Kompensation noch nicht abgeschlossen ist.
+
+use anyhow::Result;
+use fjall::Keyspace;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::KvStore;
+use crate::domain::unified::{Saga, SagaId, SagaStatus, UniversalId};
+
+/// Ausführungsphase einer persistierten Saga
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaPhase {
+    /// Eingereicht, aber noch nicht gestartet
+    Submitted,
+    /// Schritte werden ausgeführt
+    Running,
+    /// Kompensationen laufen (Κ24)
+    Compensating,
+    /// Endzustand erreicht
+    Finished,
+}
+
+/// Ergebnis einer Kompensation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompensationRecord {
+    /// Index des kompensierten Schritts
+    pub step_index: usize,
+    /// Erfolgreich?
+    pub success: bool,
+    /// Journal-Sequenz der Gegenbuchung (falls Ledger-Aktion)
+    pub journal_seq: Option<u64>,
+    /// Fehlermeldung
+    pub error: Option<String>,
+    /// Zeitpunkt (Unix-Sekunden)
+    pub at: i64,
+}
+
+/// Persistierter Ausführungszustand einer Saga
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SagaRecord {
+    /// Saga inkl. Schritt-Status
+    pub saga: Saga,
+    /// Ausführungsphase
+    pub phase: SagaPhase,
+    /// Escrow-Locks pro Lock-Schritt (step_index -> lock_id)
+    pub lock_ids: BTreeMap<usize, UniversalId>,
+    /// Journal-Sequenzen pro Schritt (step_index -> seq)
+    pub journal_seqs: BTreeMap<usize, u64>,
+    /// Startzeitpunkte pro Schritt (Unix-Sekunden)
+    pub step_started_at: BTreeMap<usize, i64>,
+    /// Abschlusszeitpunkte pro Schritt (Unix-Sekunden)
+    pub step_completed_at: BTreeMap<usize, i64>,
+    /// Ausgeführte Kompensationen in Ausführungsreihenfolge
+    pub compensations: Vec<CompensationRecord>,
+    /// Abbruch angefordert (Cancel/Rollback)
+    pub cancel_requested: bool,
+    /// Grund für den Abbruch
+    pub cancel_reason: Option<String>,
+    /// Endstatus nach erfolgreicher Kompensation
+    pub compensation_target: Option<SagaStatus>,
+    /// Letzter Fehler
+    pub error: Option<String>,
+    /// Erstellt (Unix-Sekunden)
+    pub created_at: i64,
+    /// Letztes Update (Unix-Sekunden)
+    pub updated_at: i64,
+    /// Endzustand erreicht (Unix-Sekunden)
+    pub finished_at: Option<i64>,
+}
+
+impl SagaRecord {
+    /// Neuer Record für eine eingereichte Saga
+    pub fn new(saga: Saga) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        Self {
+            saga,
+            phase: SagaPhase::Submitted,
+            lock_ids: BTreeMap::new(),
+            journal_seqs: BTreeMap::new(),
+            step_started_at: BTreeMap::new(),
+            step_completed_at: BTreeMap::new(),
+            compensations: Vec::new(),
+            cancel_requested: false,
+            cancel_reason: None,
+            compensation_target: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+            finished_at: None,
+        }
+    }
+
+    /// Saga-ID
+    pub fn id(&self) -> SagaId {
+        self.saga.id
+    }
+
+    /// Endzustand erreicht?
+    pub fn is_finished(&self) -> bool {
+        self.phase == SagaPhase::Finished
+    }
+
+    /// Muss nach einem Neustart fortgesetzt werden?
+    pub fn is_in_flight(&self) -> bool {
+        matches!(self.phase, SagaPhase::Running | SagaPhase::Compensating)
+    }
+
+    /// Markiert den Record als aktualisiert
+    pub fn touch(&mut self) {
+        self.updated_at = chrono::Utc::now().timestamp();
+    }
+
+    /// Setzt den Endzustand
+    pub fn finish(&mut self, status: SagaStatus) {
+        self.saga.status = status;
+        self.phase = SagaPhase::Finished;
+        self.touch();
+        self.finished_at = Some(self.updated_at);
+    }
+}
+
+/// Saga Store (saga_id -> SagaRecord)
+#[derive(Clone)]
+pub struct SagaStore {
+    sagas: KvStore,
+}
+
+impl SagaStore {
+    /// Erstellt einen neuen Saga Store
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Ok(Self {
+            sagas: KvStore::new(keyspace, "sagas")?,
+        })
+    }
+
+    /// Speichert (oder überschreibt) einen Record
+    pub fn put(&self, record: &SagaRecord) -> Result<()> {
+        self.sagas.put(record.id().to_hex(), record)
+    }
+
+    /// Lädt einen Record
+    pub fn get(&self, saga_id: &SagaId) -> Result<Option<SagaRecord>> {
+        self.sagas.get(saga_id.to_hex())
+    }
+
+    /// Alle Records (neueste zuerst)
+    pub fn list(&self) -> Result<Vec<SagaRecord>> {
+        let mut records = self
+            .sagas
+            .iter::<SagaRecord>()
+            .map(|item| item.map(|(_, record)| record))
+            .collect::<Result<Vec<_>>>()?;
+        records.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| a.id().to_hex().cmp(&b.id().to_hex()))
+        });
+        Ok(records)
+    }
+
+    /// Records, deren Ausführung oder Kompensation fortgesetzt werden muss
+    pub fn in_flight(&self) -> Result<Vec<SagaRecord>> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .filter(SagaRecord::is_in_flight)
+            .collect())
+    }
+
+    /// Anzahl der gespeicherten Sagas
+    pub fn count(&self) -> usize {
+        self.sagas.len()
+    }
+}
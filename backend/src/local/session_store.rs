@@ -0,0 +1,171 @@
+//! Session Store
+//!
+//! Persistiert Login-Sessions nach erfolgreicher Passkey-Verifikation sowie
+//! den Ed25519-Signaturschlüssel des Servers für Session-Tokens.
+//!
+//! Access-Tokens werden nicht gespeichert – sie tragen die Session-ID (`sid`)
+//! und sind nur gültig, solange die Session hier nicht widerrufen ist.
+//! Refresh-Tokens werden ausschließlich als BLAKE3-Hash abgelegt; rotiert
+//! wird per Compare-and-Swap ([`SessionStore::rotate_refresh`]).
+
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use fjall::Keyspace;
+use parking_lot::Mutex;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::KvStore;
+
+const SIGNING_KEY: &str = "session_signing_key";
+
+/// Persistierte Session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// Session-ID (`sid`-Claim)
+    pub id: String,
+    /// Authentifizierte DID (URI)
+    pub did: String,
+    /// Credential, mit dem sich die DID angemeldet hat
+    pub credential_id: String,
+    /// Audience der ausgestellten Tokens
+    pub audience: String,
+    /// Optionaler Realm-Scope
+    pub realm: Option<String>,
+    /// Gewährte Capabilities (`Capability::to_string_repr`)
+    pub capabilities: Vec<String>,
+    /// BLAKE3-Hash des aktuellen Refresh-Secrets (Hex)
+    pub refresh_hash: String,
+    /// Anzahl Refresh-Rotationen
+    pub refresh_count: u32,
+    /// Erstellt (Unix-Sekunden)
+    pub created_at: i64,
+    /// Ende der Refresh-Gültigkeit (Unix-Sekunden)
+    pub expires_at: i64,
+    /// Widerrufen (Unix-Sekunden)
+    pub revoked_at: Option<i64>,
+}
+
+impl SessionRecord {
+    /// Session noch nutzbar?
+    pub fn is_active(&self, now: i64) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+}
+
+/// Session Store (session_id -> SessionRecord, Signaturschlüssel)
+#[derive(Clone)]
+pub struct SessionStore {
+    sessions: KvStore,
+    keys: KvStore,
+    /// Macht Lesen + Schreiben in `rotate_refresh` und `revoke` atomar
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl SessionStore {
+    /// Erstellt einen neuen Session Store
+    pub fn new(keyspace: &Keyspace) -> Result<Self> {
+        Ok(Self {
+            sessions: KvStore::new(keyspace, "auth_sessions")?,
+            keys: KvStore::new(keyspace, "auth_keys")?,
+            write_lock: Arc::new(Mutex::new(())),
+        })
+    }
+
+    /// Speichert (oder überschreibt) eine Session
+    pub fn put(&self, record: &SessionRecord) -> Result<()> {
+        self.sessions.put(&record.id, record)
+    }
+
+    /// Lädt eine Session
+    pub fn get(&self, session_id: &str) -> Result<Option<SessionRecord>> {
+        self.sessions.get(session_id)
+    }
+
+    /// Ersetzt eine Session nur, wenn ihr gespeicherter Refresh-Hash noch
+    /// `expected_hash` ist und sie nicht widerrufen wurde
+    ///
+    /// `false`: eine gleichzeitige Rotation oder ein Widerruf kam zuvor.
+    pub fn rotate_refresh(&self, expected_hash: &str, record: &SessionRecord) -> Result<bool> {
+        let _guard = self.write_lock.lock();
+        match self.get(&record.id)? {
+            Some(stored) if stored.refresh_hash == expected_hash && stored.revoked_at.is_none() => {
+                self.put(record)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Widerruft eine Session, gibt `false` zurück wenn unbekannt
+    pub fn revoke(&self, session_id: &str, now: i64) -> Result<bool> {
+        let _guard = self.write_lock.lock();
+        match self.get(session_id)? {
+            Some(mut record) => {
+                if record.revoked_at.is_none() {
+                    record.revoked_at = Some(now);
+                    self.put(&record)?;
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Widerruft alle aktiven Sessions einer DID, gibt die Anzahl zurück
+    pub fn revoke_all(&self, did: &str, now: i64) -> Result<usize> {
+        let mut revoked = 0;
+        for record in self.list(did)? {
+            if record.is_active(now) {
+                self.revoke(&record.id, now)?;
+                revoked += 1;
+            }
+        }
+        Ok(revoked)
+    }
+
+    /// Alle Sessions einer DID (neueste zuerst)
+    pub fn list(&self, did: &str) -> Result<Vec<SessionRecord>> {
+        let mut records = self
+            .sessions
+            .iter::<SessionRecord>()
+            .map(|item| item.map(|(_, record)| record))
+            .filter(|item| item.as_ref().map_or(true, |record| record.did == did))
+            .collect::<Result<Vec<_>>>()?;
+        records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+        Ok(records)
+    }
+
+    /// Entfernt abgelaufene und widerrufene Sessions, gibt die Anzahl zurück
+    pub fn prune(&self, now: i64) -> Result<usize> {
+        let stale = self
+            .sessions
+            .iter::<SessionRecord>()
+            .filter_map(|item| match item {
+                Ok((_, record)) if !record.is_active(now) => Some(Ok(record.id)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for id in &stale {
+            self.sessions.delete(id)?;
+        }
+        Ok(stale.len())
+    }
+
+    /// Signaturschlüssel des Servers (wird beim ersten Zugriff erzeugt)
+    pub fn signing_key(&self) -> Result<SigningKey> {
+        if let Some(bytes) = self.keys.get::<_, [u8; 32]>(SIGNING_KEY)? {
+            return Ok(SigningKey::from_bytes(&bytes));
+        }
+        let key = SigningKey::generate(&mut OsRng);
+        self.keys.put(SIGNING_KEY, &key.to_bytes())?;
+        Ok(key)
+    }
+
+    /// Anzahl der gespeicherten Sessions
+    pub fn count(&self) -> usize {
+        self.sessions.len()
+    }
+}
@@ -185,8 +185,12 @@ impl TrustStore {
 
     /// Holt alle ausgehenden Trusts eines Subjekts
     pub fn get_outgoing(&self, from: &DID) -> Result<Vec<StoredTrust>> {
-        let from_str = from.to_string();
-        let to_list: Vec<String> = self.outgoing.get(&from_str)?.unwrap_or_default();
+        self.get_outgoing_by_uri(&from.to_string())
+    }
+
+    /// Wie [`get_outgoing`](Self::get_outgoing), mit DID-URI als Schlüssel
+    pub fn get_outgoing_by_uri(&self, from_str: &str) -> Result<Vec<StoredTrust>> {
+        let to_list: Vec<String> = self.outgoing.get(from_str)?.unwrap_or_default();
 
         let mut trusts = Vec::new();
         for to_str in to_list {
@@ -200,8 +204,12 @@ impl TrustStore {
 
     /// Holt alle eingehenden Trusts eines Subjekts
     pub fn get_incoming(&self, to: &DID) -> Result<Vec<StoredTrust>> {
-        let to_str = to.to_string();
-        let from_list: Vec<String> = self.incoming.get(&to_str)?.unwrap_or_default();
+        self.get_incoming_by_uri(&to.to_string())
+    }
+
+    /// Wie [`get_incoming`](Self::get_incoming), mit DID-URI als Schlüssel
+    pub fn get_incoming_by_uri(&self, to_str: &str) -> Result<Vec<StoredTrust>> {
+        let from_list: Vec<String> = self.incoming.get(to_str)?.unwrap_or_default();
 
         let mut trusts = Vec::new();
         for from_str in from_list {
@@ -13,11 +13,19 @@ use std::env;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let subscriber = get_subscriber("erynoa-backend".into(), "info".into(), std::io::stdout);
-    init_subscriber(subscriber);
-
     let settings = Settings::load().expect("Failed to load configuration");
 
+    let (subscriber, telemetry) = get_subscriber(
+        "erynoa-backend".into(),
+        "info".into(),
+        &settings.telemetry,
+        std::io::stdout,
+    )?;
+    init_subscriber(subscriber);
+    if settings.telemetry.otlp_endpoint.is_some() && !telemetry.exporting() {
+        tracing::warn!("otlp_endpoint is set, but the binary was built without feature \"otel\"");
+    }
+
     // Parse CLI arguments for static file serving
     // Usage: erynoa-api [--static-dir <path>]
     let args: Vec<String> = env::args().collect();
@@ -0,0 +1,842 @@
+//! # ECL-Handler pro Realm
+//!
+//! Realm-Admins laden ECL-Quelltext (oder fertigen Bytecode) für eine Engine
+//! und eine Route/Komponente hoch. Der Service kompiliert, optimiert optional,
+//! cached den Bytecode und registriert ihn in den [`EclEntrypoints`] des Realms.
+//!
+//! ```text
+//! upload ──▶ parse + compile ──▶ optimize? ──▶ Cache (BLAKE3) ──▶ EclHandlerStore
+//!                                                                   │
+//!                                               EclEntrypoints<ErynoaHost> (pro Realm)
+//!                                                                   │
+//! run ──▶ Mana-Preflight (ManaManager) ──▶ execute ──▶ Mana abziehen ──▶ EclOutcome
+//! ```
+//!
+//! ## Entscheidungen
+//!
+//! Ein Handler erlaubt, wenn sein Rückgabewert wahr ist. Scheitert ein
+//! `require`/Assert, wird das als Ablehnung mit Begründung gemeldet – nicht
+//! als Fehler. Logs (`emit`) und verbrauchtes Gas gehen in die Antwort ein.
+//!
+//! ## Persistenz
+//!
+//! Handler liegen im [`EclHandlerStore`](crate::local::EclHandlerStore) und
+//! werden beim ersten Zugriff auf ein Realm erneut registriert.
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::core::state::{ECLPolicyType, ECLVMBudgetLimits};
+use crate::core::SharedUnifiedState;
+use crate::domain::unified::{TrustVector6D, UniversalId};
+use crate::eclvm::compiler::Compiler;
+use crate::eclvm::parser::Parser;
+use crate::eclvm::runtime::runner::{run_policy, PolicyRunContext};
+use crate::eclvm::runtime::vm::ExecutionResult;
+use crate::eclvm::{
+    EclEntrypoints, EntrypointKind, ErynoaHost, ManaConfig, ManaManager, OpCode, Optimizer,
+    PolicyExecutionObserver, Value, DEFAULT_ENGINE_GAS_LIMIT,
+};
+use crate::error::ApiError;
+use crate::local::{DecentralizedStorage, EclHandlerRecord};
+use crate::peer::query::resolve_realm;
+use crate::peer::saga_executor::stored_did;
+
+// ============================================================================
+// Fehler
+// ============================================================================
+
+/// Fehler des EclService
+#[derive(Debug, Error)]
+pub enum EclServiceError {
+    #[error("Unknown realm: {0}")]
+    UnknownRealm(String),
+
+    #[error("{caller} is not an admin of realm {realm}")]
+    NotAdmin { caller: String, realm: String },
+
+    #[error("Invalid handler: {0}")]
+    InvalidHandler(String),
+
+    #[error("Compilation failed: {0}")]
+    Compile(String),
+
+    #[error("No {kind} handler '{key}' in realm {realm}")]
+    HandlerNotFound {
+        realm: String,
+        kind: &'static str,
+        key: String,
+    },
+
+    #[error("Insufficient mana, retry after {}s", .0.as_secs())]
+    InsufficientMana(Duration),
+
+    #[error("Execution failed: {0}")]
+    Execution(String),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] anyhow::Error),
+}
+
+/// Ergebnis von EclService-Operationen
+pub type EclServiceResult<T> = Result<T, EclServiceError>;
+
+// ============================================================================
+// Konfiguration & Typen
+// ============================================================================
+
+/// Konfiguration des EclService
+#[derive(Debug, Clone)]
+pub struct EclServiceConfig {
+    /// Gas-Limit, wenn der Aufrufer keins angibt
+    pub default_gas_limit: u64,
+    /// Obergrenze für angefragte Gas-Limits
+    pub max_gas_limit: u64,
+    /// Optimizer standardmäßig anwenden
+    pub optimize: bool,
+    /// Maximale Länge eines Quelltexts (Bytes)
+    pub max_source_bytes: usize,
+    /// Maximale Anzahl kompilierter Programme im Cache
+    pub cache_capacity: usize,
+}
+
+impl Default for EclServiceConfig {
+    fn default() -> Self {
+        Self {
+            default_gas_limit: DEFAULT_ENGINE_GAS_LIMIT,
+            max_gas_limit: 1_000_000,
+            optimize: true,
+            max_source_bytes: 64 * 1024,
+            cache_capacity: 256,
+        }
+    }
+}
+
+/// Hochgeladener Handler: ECL-Quelltext oder kompilierter Bytecode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EclSource {
+    Source(String),
+    Bytecode(Vec<OpCode>),
+}
+
+/// Upload eines Handlers durch einen Realm-Admin
+#[derive(Debug, Clone)]
+pub struct EclUpload {
+    /// Realm (Name, Hex-ID oder `root`)
+    pub realm: String,
+    pub kind: EntrypointKind,
+    /// Route-ID, Component-ID oder Permission/Resource
+    pub key: String,
+    pub uploader: UniversalId,
+    pub source: EclSource,
+    /// Optimizer anwenden (Standard aus der Konfiguration)
+    pub optimize: Option<bool>,
+}
+
+/// Aufruf eines registrierten Handlers
+#[derive(Debug, Clone)]
+pub struct EclInvocation {
+    pub realm: String,
+    pub kind: EntrypointKind,
+    pub key: String,
+    pub caller: UniversalId,
+    pub gas_limit: Option<u64>,
+}
+
+/// Ergebnis einer ECL-Ausführung
+#[derive(Debug, Clone, Serialize)]
+pub struct EclOutcome {
+    pub realm: String,
+    /// Engine (fehlt bei Ad-hoc-Ausführung über `/ecl/run`)
+    pub kind: Option<EntrypointKind>,
+    pub key: String,
+    /// Aufrufer (DID-URI)
+    pub caller: String,
+    /// Entscheidung: Rückgabewert wahr und kein `require` verletzt
+    pub decision: bool,
+    /// Rückgabewert als JSON
+    pub value: serde_json::Value,
+    /// Grund einer Ablehnung (verletztes `require`/Assert)
+    pub reason: Option<String>,
+    pub logs: Vec<String>,
+    pub gas_used: u64,
+    pub mana_used: u64,
+    /// Verbleibendes Mana des Aufrufers nach Abzug
+    pub mana_remaining: u64,
+    pub duration_us: u64,
+}
+
+// ============================================================================
+// Service
+// ============================================================================
+
+type RealmEntrypoints = EclEntrypoints<ErynoaHost>;
+
+/// Per-Realm ECL-Handler mit Kompilier-Cache und Mana-Preflight
+#[derive(Clone)]
+pub struct EclService {
+    storage: DecentralizedStorage,
+    unified_state: SharedUnifiedState,
+    config: EclServiceConfig,
+    host: Arc<ErynoaHost>,
+    mana: Arc<ManaManager>,
+    /// EclEntrypoints pro Realm (lazy aus dem Store registriert)
+    realms: Arc<RwLock<HashMap<String, RealmEntrypoints>>>,
+    /// Kompilierter Bytecode nach BLAKE3(Quelltext, Optimizer)
+    cache: Arc<Mutex<HashMap<String, Arc<Vec<OpCode>>>>>,
+}
+
+impl EclService {
+    /// Erstelle Service auf Storage und Realm-State
+    pub fn new(
+        storage: DecentralizedStorage,
+        unified_state: SharedUnifiedState,
+        config: EclServiceConfig,
+    ) -> Self {
+        let host = Arc::new(ErynoaHost::new(Arc::new(storage.clone())));
+        Self {
+            storage,
+            unified_state,
+            config,
+            host,
+            mana: Arc::new(ManaManager::default_config()),
+            realms: Arc::new(RwLock::new(HashMap::new())),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Gemeinsamen ManaManager verwenden (z. B. mit dem Gateway)
+    pub fn with_mana(mut self, mana: Arc<ManaManager>) -> Self {
+        self.mana = mana;
+        self
+    }
+
+    /// Mana-Konfiguration zur Laufzeit austauschen (Config-Reload)
+    pub fn set_mana_config(&self, config: ManaConfig) {
+        self.mana.set_config(config);
+    }
+
+    /// Kompiliert, cached, persistiert und registriert einen Handler
+    pub fn upload(&self, upload: EclUpload) -> EclServiceResult<EclHandlerRecord> {
+        let realm = self.realm_key(&upload.realm)?;
+        if !self.is_admin(&realm, &upload.uploader)? {
+            return Err(EclServiceError::NotAdmin {
+                caller: stored_did(&self.storage, &upload.uploader).to_uri(),
+                realm,
+            });
+        }
+        let key = upload.key.trim();
+        if key.is_empty() {
+            return Err(EclServiceError::InvalidHandler(
+                "key must not be empty".to_string(),
+            ));
+        }
+
+        let optimize = upload.optimize.unwrap_or(self.config.optimize);
+        let (source, bytecode) = match upload.source {
+            EclSource::Source(source) => {
+                let bytecode = self.compile(&source, optimize)?;
+                (Some(source), bytecode.as_ref().clone())
+            }
+            EclSource::Bytecode(bytecode) if bytecode.is_empty() => {
+                return Err(EclServiceError::InvalidHandler(
+                    "bytecode must not be empty".to_string(),
+                ))
+            }
+            EclSource::Bytecode(bytecode) if optimize => {
+                (None, Optimizer::new().optimize(bytecode))
+            }
+            EclSource::Bytecode(bytecode) => (None, bytecode),
+        };
+
+        let record = EclHandlerRecord {
+            realm: realm.clone(),
+            kind: upload.kind,
+            key: key.to_string(),
+            source,
+            hash: bytecode_hash(&bytecode),
+            bytecode,
+            optimized: optimize,
+            uploaded_by: stored_did(&self.storage, &upload.uploader).to_uri(),
+            uploaded_at: chrono::Utc::now().timestamp(),
+        };
+        self.storage.ecl.put(&record)?;
+
+        let mut realms = self.realms.write();
+        self.load(&mut realms, &realm)?.register(
+            record.kind,
+            record.key.clone(),
+            record.bytecode.clone(),
+        );
+        self.unified_state
+            .eclvm
+            .register_policy_to_realm(&realm, &policy_id(record.kind, &record.key));
+        Ok(record)
+    }
+
+    /// Entfernt einen Handler (nur Realm-Admins)
+    pub fn remove(
+        &self,
+        realm: &str,
+        kind: EntrypointKind,
+        key: &str,
+        admin: &UniversalId,
+    ) -> EclServiceResult<bool> {
+        let realm = self.realm_key(realm)?;
+        if !self.is_admin(&realm, admin)? {
+            return Err(EclServiceError::NotAdmin {
+                caller: stored_did(&self.storage, admin).to_uri(),
+                realm,
+            });
+        }
+        let removed = self.storage.ecl.delete(&realm, kind, key)?;
+        let mut realms = self.realms.write();
+        self.load(&mut realms, &realm)?.unregister(kind, key);
+        Ok(removed)
+    }
+
+    /// Registrierte Handler eines Realms
+    pub fn list(&self, realm: &str) -> EclServiceResult<Vec<EclHandlerRecord>> {
+        let realm = self.realm_key(realm)?;
+        Ok(self.storage.ecl.list(&realm)?)
+    }
+
+    /// Führt einen registrierten Handler für den Aufrufer aus
+    pub fn run(&self, invocation: EclInvocation) -> EclServiceResult<EclOutcome> {
+        let realm = self.realm_key(&invocation.realm)?;
+        {
+            let mut realms = self.realms.write();
+            let entrypoints = self.load(&mut realms, &realm)?;
+            if !entrypoints.has_handler(invocation.kind, &invocation.key) {
+                return Err(EclServiceError::HandlerNotFound {
+                    realm,
+                    kind: invocation.kind.as_str(),
+                    key: invocation.key,
+                });
+            }
+        }
+        let estimate = self
+            .storage
+            .ecl
+            .get(&realm, invocation.kind, &invocation.key)?
+            .map(|record| estimate_gas(&record.bytecode))
+            .unwrap_or_default();
+
+        let kind = invocation.kind;
+        let key = invocation.key.clone();
+        self.metered(
+            &realm,
+            &invocation.caller,
+            kind.policy_type(),
+            invocation.gas_limit,
+            estimate,
+            |context| {
+                let realms = self.realms.read();
+                let entrypoints = realms
+                    .get(context.realm_id.as_str())
+                    .ok_or_else(|| ApiError::NotFound(format!("ECL realm {}", context.realm_id)))?;
+                entrypoints.execute(kind, &key, context)
+            },
+        )
+        .map(|mut outcome| {
+            outcome.kind = Some(kind);
+            outcome.key = invocation.key;
+            outcome
+        })
+    }
+
+    /// Kompiliert (gecached) und führt Quelltext ad hoc im Realm aus
+    pub fn run_source(
+        &self,
+        realm: &str,
+        caller: &UniversalId,
+        source: EclSource,
+        gas_limit: Option<u64>,
+    ) -> EclServiceResult<EclOutcome> {
+        let realm = self.realm_key(realm)?;
+        let bytecode = match source {
+            EclSource::Source(source) => self.compile(&source, self.config.optimize)?,
+            EclSource::Bytecode(bytecode) => Arc::new(bytecode),
+        };
+        let key = format!("inline:{}", &bytecode_hash(&bytecode)[..16]);
+        let estimate = estimate_gas(&bytecode);
+        let outcome = self.metered(
+            &realm,
+            caller,
+            ECLPolicyType::Custom,
+            gas_limit,
+            estimate,
+            |context| {
+                let context = context
+                    .clone()
+                    .with_policy_id(&key)
+                    .with_policy_type("custom");
+                let result = run_policy(&bytecode, self.host.as_ref(), &context)?;
+                self.unified_state.eclvm.policy_executed(
+                    result.value.as_bool().unwrap_or(false),
+                    ECLPolicyType::Custom,
+                    result.gas_used,
+                    result.mana_used,
+                    result.duration_us,
+                    Some(&context.realm_id),
+                );
+                Ok(result)
+            },
+        )?;
+        Ok(EclOutcome { key, ..outcome })
+    }
+
+    /// Mana-Status des Aufrufers
+    pub fn mana_status(&self, caller: &UniversalId) -> crate::eclvm::ManaStatus {
+        let did = stored_did(&self.storage, caller);
+        self.mana.get_status(&did.to_uri(), &self.trust(caller))
+    }
+
+    // ------------------------------------------------------------------------
+    // Intern
+    // ------------------------------------------------------------------------
+
+    /// Mana-Preflight, Ausführung mit Budget und Abzug des verbrauchten Gas
+    fn metered(
+        &self,
+        realm: &str,
+        caller: &UniversalId,
+        policy_type: ECLPolicyType,
+        gas_limit: Option<u64>,
+        estimate: u64,
+        execute: impl FnOnce(&PolicyRunContext) -> crate::error::Result<ExecutionResult>,
+    ) -> EclServiceResult<EclOutcome> {
+        let caller_uri = stored_did(&self.storage, caller).to_uri();
+        let trust = self.trust(caller);
+        let gas_limit = gas_limit
+            .unwrap_or(self.config.default_gas_limit)
+            .clamp(1, self.config.max_gas_limit);
+
+        if let Err(ApiError::RateLimited { retry_after }) =
+            self.mana
+                .preflight_check(&caller_uri, &trust, estimate.min(gas_limit))
+        {
+            self.unified_state.eclvm.rate_limited();
+            return Err(EclServiceError::InsufficientMana(retry_after));
+        }
+
+        let context = PolicyRunContext::with_limits(
+            caller_uri.clone(),
+            realm,
+            ECLVMBudgetLimits {
+                gas_limit,
+                ..ECLVMBudgetLimits::default()
+            },
+        );
+        let (decision, value, reason, logs, gas_used, mana_used, duration_us) =
+            match execute(&context) {
+                Ok(result) => (
+                    result.value.as_bool().unwrap_or(result.value.is_truthy()),
+                    value_json(&result.value),
+                    None,
+                    result.logs,
+                    result.gas_used,
+                    result.mana_used,
+                    result.duration_us,
+                ),
+                Err(e) => match denial_reason(&e) {
+                    Some(reason) => {
+                        let (gas, mana) = (context.budget.gas_used(), context.budget.mana_used());
+                        let duration_us = context.budget.elapsed_ms() * 1_000;
+                        self.unified_state.eclvm.policy_executed(
+                            false,
+                            policy_type,
+                            gas,
+                            mana,
+                            duration_us,
+                            Some(realm),
+                        );
+                        (
+                            false,
+                            serde_json::Value::Bool(false),
+                            Some(reason),
+                            Vec::new(),
+                            gas,
+                            mana,
+                            duration_us,
+                        )
+                    }
+                    None => {
+                        if context.budget.is_exhausted() {
+                            self.unified_state.eclvm.out_of_gas();
+                        } else {
+                            self.unified_state.eclvm.policy_runtime_error();
+                        }
+                        let _ = self
+                            .mana
+                            .deduct(&caller_uri, &trust, context.budget.gas_used());
+                        return Err(EclServiceError::Execution(error_message(&e)));
+                    }
+                },
+            };
+
+        // Preflight garantiert das Minimum; Überverbrauch leert das Konto
+        if self.mana.deduct(&caller_uri, &trust, gas_used).is_err() {
+            let current = self.mana.get_or_create(&caller_uri, &trust).current();
+            let _ = self.mana.deduct(&caller_uri, &trust, current);
+        }
+        let mana_remaining = self.mana.get_or_create(&caller_uri, &trust).current();
+
+        Ok(EclOutcome {
+            realm: realm.to_string(),
+            kind: None,
+            key: String::new(),
+            caller: caller_uri,
+            decision,
+            value,
+            reason,
+            logs,
+            gas_used,
+            mana_used,
+            mana_remaining,
+            duration_us,
+        })
+    }
+
+    /// Parse + Compile (+ Optimize) mit Cache
+    fn compile(&self, source: &str, optimize: bool) -> EclServiceResult<Arc<Vec<OpCode>>> {
+        if source.trim().is_empty() {
+            return Err(EclServiceError::InvalidHandler(
+                "source must not be empty".to_string(),
+            ));
+        }
+        if source.len() > self.config.max_source_bytes {
+            return Err(EclServiceError::InvalidHandler(format!(
+                "source exceeds {} bytes",
+                self.config.max_source_bytes
+            )));
+        }
+
+        let cache_key = format!("{}:{}", blake3::hash(source.as_bytes()).to_hex(), optimize);
+        if let Some(bytecode) = self.cache.lock().get(&cache_key) {
+            return Ok(bytecode.clone());
+        }
+
+        let compiled = Parser::parse(source).and_then(|program| Compiler::new().compile(&program));
+        self.unified_state
+            .eclvm
+            .policy_compiled(compiled.is_ok(), ECLPolicyType::Custom);
+        let bytecode = compiled.map_err(|e| EclServiceError::Compile(e.to_string()))?;
+        let bytecode = Arc::new(if optimize {
+            Optimizer::new().optimize(bytecode)
+        } else {
+            bytecode
+        });
+
+        let mut cache = self.cache.lock();
+        if cache.len() >= self.config.cache_capacity {
+            cache.clear();
+        }
+        cache.insert(cache_key, bytecode.clone());
+        Ok(bytecode)
+    }
+
+    /// EclEntrypoints eines Realms, beim ersten Zugriff aus dem Store geladen
+    fn load<'a>(
+        &self,
+        realms: &'a mut HashMap<String, RealmEntrypoints>,
+        realm: &str,
+    ) -> EclServiceResult<&'a mut RealmEntrypoints> {
+        if !realms.contains_key(realm) {
+            let mut entrypoints =
+                EclEntrypoints::new(self.host.clone()).with_observer(Arc::new(StateObserver {
+                    state: self.unified_state.clone(),
+                }));
+            for record in self.storage.ecl.list(realm)? {
+                entrypoints.register(record.kind, record.key, record.bytecode);
+            }
+            realms.insert(realm.to_string(), entrypoints);
+        }
+        Ok(realms
+            .get_mut(realm)
+            .expect("realm entrypoints inserted above"))
+    }
+
+    fn trust(&self, id: &UniversalId) -> TrustVector6D {
+        self.storage
+            .trust
+            .compute_reputation(&stored_did(&self.storage, id))
+            .unwrap_or_default()
+    }
+
+    fn realm_key(&self, realm: &str) -> EclServiceResult<String> {
+        let unknown = || EclServiceError::UnknownRealm(realm.to_string());
+        let realms = self
+            .unified_state
+            .peer
+            .realm
+            .realms
+            .read()
+            .map_err(|_| unknown())?;
+        let hex = resolve_realm(realm).to_hex();
+        realms
+            .get_key_value(realm)
+            .or_else(|| realms.get_key_value(&hex))
+            .map(|(key, _)| key.clone())
+            .ok_or_else(unknown)
+    }
+
+    fn is_admin(&self, realm: &str, id: &UniversalId) -> EclServiceResult<bool> {
+        let realms = self
+            .unified_state
+            .peer
+            .realm
+            .realms
+            .read()
+            .map_err(|_| EclServiceError::UnknownRealm(realm.to_string()))?;
+        Ok(realms
+            .get(realm)
+            .is_some_and(|state| state.is_admin_by_id(id)))
+    }
+}
+
+/// Leitet Engine-Ausführungen in die ECLVM-Metriken des Unified State weiter
+struct StateObserver {
+    state: SharedUnifiedState,
+}
+
+impl PolicyExecutionObserver for StateObserver {
+    fn on_policy_executed(
+        &self,
+        _policy_id: &str,
+        policy_type: &str,
+        passed: bool,
+        gas_used: u64,
+        mana_used: u64,
+        duration_us: u64,
+        realm_id: Option<&str>,
+    ) {
+        let policy_type = EntrypointKind::parse(policy_type)
+            .map(|kind| kind.policy_type())
+            .unwrap_or(ECLPolicyType::Custom);
+        self.state.eclvm.policy_executed(
+            passed,
+            policy_type,
+            gas_used,
+            mana_used,
+            duration_us,
+            realm_id,
+        );
+    }
+
+    fn on_crossing_policy_evaluated(
+        &self,
+        from_realm: &str,
+        to_realm: &str,
+        _entity_id: &str,
+        allowed: bool,
+        _trust_score: f64,
+        _policy_id: Option<&str>,
+    ) {
+        self.state
+            .eclvm
+            .crossing_policy_evaluated(allowed, from_realm, to_realm);
+    }
+}
+
+fn policy_id(kind: EntrypointKind, key: &str) -> String {
+    format!("{}:{}", kind.as_str(), key)
+}
+
+fn bytecode_hash(bytecode: &[OpCode]) -> String {
+    let bytes = serde_json::to_vec(bytecode).unwrap_or_default();
+    blake3::hash(&bytes).to_hex().to_string()
+}
+
+/// Gas-Schätzung für den Preflight: Summe der Instruktionskosten
+fn estimate_gas(bytecode: &[OpCode]) -> u64 {
+    bytecode.iter().map(OpCode::gas_cost).sum()
+}
+
+/// Fehlermeldung der VM (`ApiError::Internal` verbirgt sie im Display)
+fn error_message(error: &ApiError) -> String {
+    match error {
+        ApiError::Internal(inner) => inner.to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Verletztes `require`/Assert → Ablehnungsgrund statt Fehler
+fn denial_reason(error: &ApiError) -> Option<String> {
+    let message = error_message(error);
+    if let Some(reason) = message.strip_prefix("Require failed: ") {
+        Some(reason.to_string())
+    } else if message.starts_with("Assertion failed") {
+        Some(message)
+    } else {
+        None
+    }
+}
+
+fn value_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Bool(b) => serde_json::Value::Bool(*b),
+        Value::Number(n) => serde_json::json!(n),
+        Value::String(s) | Value::DID(s) => serde_json::Value::String(s.clone()),
+        Value::TrustVector(tv) => serde_json::json!(tv),
+        Value::Array(items) => serde_json::Value::Array(items.iter().map(value_json).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UnifiedState;
+
+    const GATE: &str = r#"
+policy "gate" {
+    emit "checked"
+    require 2 > 1, "math works"
+}
+"#;
+
+    const DENY: &str = r#"
+policy "deny" {
+    require 1 > 2, "members only"
+}
+"#;
+
+    fn id(name: &str) -> UniversalId {
+        UniversalId::new(UniversalId::TAG_DID, 1, name.as_bytes())
+    }
+
+    fn setup(admin: UniversalId) -> (EclService, SharedUnifiedState) {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let state = Arc::new(UnifiedState::new());
+        state.peer.realm.register_realm("guild", 0.3, "quadratic");
+        {
+            let realms = state.peer.realm.realms.read().unwrap();
+            realms["guild"].add_admin_by_id(admin, None);
+        }
+        let service = EclService::new(storage, state.clone(), EclServiceConfig::default());
+        (service, state)
+    }
+
+    fn upload(admin: UniversalId, kind: EntrypointKind, key: &str, source: &str) -> EclUpload {
+        EclUpload {
+            realm: "guild".to_string(),
+            kind,
+            key: key.to_string(),
+            uploader: admin,
+            source: EclSource::Source(source.to_string()),
+            optimize: None,
+        }
+    }
+
+    fn invocation(kind: EntrypointKind, key: &str, caller: UniversalId) -> EclInvocation {
+        EclInvocation {
+            realm: "guild".to_string(),
+            kind,
+            key: key.to_string(),
+            caller,
+            gas_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_upload_run_and_restore() {
+        let (admin, caller) = (id("admin"), id("caller"));
+        let (service, state) = setup(admin);
+
+        assert!(matches!(
+            service.upload(upload(caller, EntrypointKind::Api, "post:/action", GATE)),
+            Err(EclServiceError::NotAdmin { .. })
+        ));
+        assert!(matches!(
+            service.upload(upload(admin, EntrypointKind::Api, "broken", "policy {")),
+            Err(EclServiceError::Compile(_))
+        ));
+
+        let record = service
+            .upload(upload(admin, EntrypointKind::Api, "post:/action", GATE))
+            .unwrap();
+        assert!(record.optimized);
+        service
+            .upload(upload(admin, EntrypointKind::Ui, "admin-panel", DENY))
+            .unwrap();
+
+        let outcome = service
+            .run(invocation(EntrypointKind::Api, "post:/action", caller))
+            .unwrap();
+        assert!(outcome.decision);
+        assert_eq!(outcome.logs, vec!["emit:checked".to_string()]);
+        assert!(outcome.gas_used > 0);
+        assert!(outcome.mana_remaining < service.mana_status(&caller).max_mana);
+
+        let denied = service
+            .run(invocation(EntrypointKind::Ui, "admin-panel", caller))
+            .unwrap();
+        assert!(!denied.decision);
+        assert_eq!(denied.reason.as_deref(), Some("members only"));
+
+        assert!(matches!(
+            service.run(invocation(
+                EntrypointKind::Controller,
+                "post:/action",
+                caller
+            )),
+            Err(EclServiceError::HandlerNotFound { .. })
+        ));
+        let realm_ecl = state.snapshot().eclvm.realm_ecl["guild"].clone();
+        assert_eq!(realm_ecl.policies_executed, 2);
+
+        // Neuer Service auf demselben Storage registriert die Handler erneut
+        let restored = EclService::new(
+            service.storage.clone(),
+            state.clone(),
+            EclServiceConfig::default(),
+        );
+        assert_eq!(restored.list("guild").unwrap().len(), 2);
+        assert!(
+            restored
+                .run(invocation(EntrypointKind::Api, "post:/action", caller))
+                .unwrap()
+                .decision
+        );
+        assert!(restored
+            .remove("guild", EntrypointKind::Ui, "admin-panel", &admin)
+            .unwrap());
+        assert_eq!(restored.list("guild").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_mana_preflight_and_inline_run() {
+        let (admin, caller) = (id("admin"), id("caller"));
+        let (service, state) = setup(admin);
+        let outcome = service
+            .run_source("guild", &caller, EclSource::Source(GATE.to_string()), None)
+            .unwrap();
+        assert!(outcome.decision);
+        assert!(outcome.key.starts_with("inline:"));
+
+        // Konto leeren → Preflight lehnt ab
+        let did = stored_did(&service.storage, &caller).to_uri();
+        let trust = service.trust(&caller);
+        let current = service.mana.get_or_create(&did, &trust).current();
+        service.mana.deduct(&did, &trust, current).unwrap();
+        assert!(matches!(
+            service.run_source("guild", &caller, EclSource::Source(GATE.to_string()), None),
+            Err(EclServiceError::InsufficientMana(_))
+        ));
+        assert_eq!(state.snapshot().eclvm.mana_rate_limited, 1);
+
+        // Config-Reload: neues Maximum gilt sofort für bestehende Konten
+        let before = service.mana_status(&caller).max_mana;
+        service.set_mana_config(ManaConfig {
+            base_allowance: 1,
+            ..ManaConfig::default()
+        });
+        assert!(service.mana_status(&caller).max_mana < before);
+    }
+}
@@ -253,11 +253,27 @@ impl GatewayGuard {
         self
     }
 
+    /// Mana-Konfiguration der ECL-Crossing-Prüfung austauschen (Config-Reload)
+    pub fn set_mana_config(&self, config: crate::eclvm::ManaConfig) {
+        if let Some(evaluator) = &self.ecl_evaluator {
+            evaluator.set_mana_config(config);
+        }
+    }
+
     /// Realm als ECL-Entry-Policy-Realm markieren (ECL wird nach Regel-Check ausgeführt)
     pub fn register_realm_ecl_entry(&mut self, realm: RealmId) {
         self.realms_with_ecl_entry.insert(realm);
     }
 
+    /// Beim Crossing angewandte Dämpfungsmatrix (`None` wenn deaktiviert)
+    ///
+    /// Standard-Dämpfungsfaktor 0.7 für Cross-Realm.
+    pub fn dampening_matrix(&self) -> Option<TrustDampeningMatrix> {
+        self.config
+            .apply_trust_dampening
+            .then(|| TrustDampeningMatrix::generic_crossing(0.7))
+    }
+
     /// Κ23: Validiere Realm-Crossing
     ///
     /// `cross(s, 𝒞₁, 𝒞₂) requires G(s, 𝒞₂) = true`
@@ -265,6 +281,11 @@ impl GatewayGuard {
     /// Bei erfolgreichem Crossing enthält das Ergebnis:
     /// - `stores_to_initialize`: Personal-Stores die für das neue Mitglied erstellt werden sollen
     /// - `setup_policy`: Optional ECL-Policy zur Ausführung
+    #[tracing::instrument(
+        name = "gateway.validate_crossing",
+        skip_all,
+        fields(did = %did.to_uri(), from = %from_realm, to = %to_realm)
+    )]
     pub fn validate_crossing(
         &self,
         did: &DID,
@@ -308,12 +329,9 @@ impl GatewayGuard {
         }
 
         // 3. Berechne gedämpften Trust
-        let dampened = if self.config.apply_trust_dampening {
-            // Verwende Standard-Dämpfungsfaktor 0.7 für Cross-Realm
-            let matrix = TrustDampeningMatrix::generic_crossing(0.7);
-            matrix.apply(trust)
-        } else {
-            trust.clone()
+        let dampened = match self.dampening_matrix() {
+            Some(matrix) => matrix.apply(trust),
+            None => trust.clone(),
         };
 
         let mut allowed = violations.is_empty();
@@ -0,0 +1,1383 @@
+//! # Realm-Governance
+//!
+//! Proposal-Lebenszyklus pro Realm auf Basis von [`QuadraticGovernance`] (Κ21).
+//!
+//! ```text
+//! propose ──▶ EventPayload::Proposal ──▶ Active ──vote*──▶ EventPayload::Vote
+//!                                          │
+//!                                 closes_at erreicht
+//!                                          ▼
+//!                                  tally + finalize ──▶ StateEvent::ProposalResolved
+//!                               ┌──────────┴──────────┐
+//!                            passed                rejected
+//!                               ▼
+//!                  GovernanceAction ausführen → Executed | Failed
+//! ```
+//!
+//! ## Credits
+//!
+//! Jedes Realm-Mitglied erhält beim ersten Kontakt mit der Realm-Governance
+//! `max(min_credits, credits_per_trust × mean(𝕎))` Voting-Credits. Mitglieder
+//! werden beim Erstellen eines Proposals registriert, damit das Quorum relativ
+//! zur Mitgliederzahl berechnet wird.
+//!
+//! ## Aktionen
+//!
+//! Angenommene Proposals werden als [`GovernanceAction`] im Realm ausgeführt:
+//! Regeln hinzufügen (Κ1), `min_trust` setzen, Store-Schema weiterentwickeln,
+//! ECL-Policy ersetzen oder [`RealmQuota`](crate::core::state::RealmQuota) anpassen.
+//!
+//! ## Persistenz
+//!
+//! Proposals, Stimmen und Credits liegen im
+//! [`GovernanceStore`](crate::local::GovernanceStore). Die `QuadraticGovernance`
+//! eines Realms wird beim ersten Zugriff daraus rekonstruiert. Alle Events
+//! eines Realms bilden eine Kette, die bei der Realm-ID beginnt, damit
+//! Event-IDs realmübergreifend eindeutig sind.
+//!
+//! Ausgeführte Aktionen ändern den Realm-State nur über
+//! `StateEvent::RealmLifecycle` mit `RealmAction::ConfigChanged`
+//! ([`RealmConfigChange`] als JSON). Die Payloads werden im Realm-Record
+//! gespeichert und beim Start bzw. bei erneuter Registrierung des Realms
+//! wieder eingespielt. Finalisiert wird nur durch den Finalizer-Task oder
+//! `POST /governance/proposals/{id}/finalize`.
+
+use chrono::{DateTime, TimeZone, Utc};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::core::state::{
+    RealmConfigChange, RealmSpecificState, ResourceType, WrappedStateEvent,
+};
+use crate::core::{SharedUnifiedState, StateEvent};
+use crate::domain::unified::{
+    Event, EventPayload, GovernanceModel, GovernanceType, Hash32, RealmAction, UniversalId,
+    VoteDirection, DID, GOVERNANCE_RULE_PREFIX,
+};
+use crate::local::{
+    CreditGrant, DecentralizedStorage, GovernanceAction, ProposalPhase, ProposalRecord,
+    RealmGovernanceRecord, TallyRecord, VoteRecord, NATIVE_ASSET,
+};
+use crate::peer::query::resolve_realm;
+use crate::peer::saga_executor::stored_did;
+use crate::protection::quadratic::{
+    self, GovernanceError, ProposalStatus, QuadraticConfig, QuadraticGovernance,
+};
+use crate::protection::vote_weighting::{weighting_for, WeightResolution, WeightingContext};
+
+// ============================================================================
+// Fehler
+// ============================================================================
+
+/// Fehler des GovernanceService
+#[derive(Debug, Error)]
+pub enum GovernanceServiceError {
+    #[error(transparent)]
+    Voting(#[from] GovernanceError),
+
+    #[error("Proposal not found: {0}")]
+    ProposalNotFound(String),
+
+    #[error("Unknown realm: {0}")]
+    UnknownRealm(String),
+
+    #[error("{member} is not a member of realm {realm}")]
+    NotAMember { member: String, realm: String },
+
+    #[error("Invalid proposal: {0}")]
+    InvalidProposal(String),
+
+    #[error("Voting is open until {0}")]
+    VotingOpen(i64),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] anyhow::Error),
+}
+
+/// Ergebnis von GovernanceService-Operationen
+pub type GovernanceServiceResult<T> = Result<T, GovernanceServiceError>;
+
+// ============================================================================
+// Konfiguration
+// ============================================================================
+
+/// Konfiguration des GovernanceService
+#[derive(Debug, Clone)]
+pub struct GovernanceConfig {
+    /// Quorum, Zustimmung und Standard-Abstimmungsdauer
+    pub voting: QuadraticConfig,
+    /// Credits bei vollem Trust (mean(𝕎) = 1.0)
+    pub credits_per_trust: u64,
+    /// Mindest-Credits pro Mitglied
+    pub min_credits: u64,
+    /// Maximale Abstimmungsdauer (Sekunden)
+    pub max_voting_period_secs: u64,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            voting: QuadraticConfig::default(),
+            credits_per_trust: 100,
+            min_credits: 1,
+            max_voting_period_secs: 30 * 86_400,
+        }
+    }
+}
+
+/// Antrag für ein neues Proposal
+#[derive(Debug, Clone)]
+pub struct NewProposal {
+    /// Realm (Name, Hex-ID oder `root`)
+    pub realm: String,
+    /// Antragsteller
+    pub proposer: UniversalId,
+    pub title: String,
+    pub description: String,
+    /// Bei Annahme auszuführende Aktion
+    pub action: GovernanceAction,
+    /// Abstimmungsdauer (Standard: `voting.proposal_duration_hours`)
+    pub voting_period_secs: Option<u64>,
+}
+
+// ============================================================================
+// GovernanceService
+// ============================================================================
+
+/// Realm-Governance mit persistenten Proposals und typisierten Aktionen
+#[derive(Clone)]
+pub struct GovernanceService {
+    storage: DecentralizedStorage,
+    unified_state: SharedUnifiedState,
+    config: GovernanceConfig,
+    /// QuadraticGovernance pro Realm (lazy aus dem Store rekonstruiert)
+    realms: Arc<Mutex<HashMap<String, QuadraticGovernance>>>,
+}
+
+impl GovernanceService {
+    /// Erstelle Service auf Storage und Realm-State
+    pub fn new(
+        storage: DecentralizedStorage,
+        unified_state: SharedUnifiedState,
+        config: GovernanceConfig,
+    ) -> Self {
+        let service = Self {
+            storage,
+            unified_state,
+            config,
+            realms: Arc::new(Mutex::new(HashMap::new())),
+        };
+        match service.storage.governance.realm_names() {
+            Ok(realms) => {
+                for realm in realms {
+                    if let Err(e) = service.restore_realm_config(&realm) {
+                        tracing::warn!(
+                            realm = %realm,
+                            error = %e,
+                            "Governance config restore failed"
+                        );
+                    }
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "Governance realms could not be listed"),
+        }
+        service
+    }
+
+    /// Spielt die per Governance ausgeführten Konfigurationsänderungen eines
+    /// Realms erneut in den Unified State ein (nach Neustart oder erneuter
+    /// Registrierung des Realms). Unbekannte Realms werden übersprungen.
+    pub fn restore_realm_config(&self, realm: &str) -> GovernanceServiceResult<()> {
+        let Ok(key) = self.realm_key(realm) else {
+            return Ok(());
+        };
+        let events: Vec<WrappedStateEvent> = self
+            .storage
+            .governance
+            .realm(&key)?
+            .config_changes
+            .into_iter()
+            .enumerate()
+            .map(|(sequence, config)| {
+                WrappedStateEvent::new(
+                    StateEvent::RealmLifecycle {
+                        realm_id: key.clone(),
+                        action: RealmAction::ConfigChanged,
+                        config: Some(config),
+                    },
+                    vec![],
+                    sequence as u64,
+                )
+            })
+            .collect();
+        if !events.is_empty() {
+            self.unified_state.replay_events(&events);
+        }
+        Ok(())
+    }
+
+    /// Erstellt ein Proposal und vergibt Credits an alle Realm-Mitglieder
+    pub fn propose(&self, proposal: NewProposal) -> GovernanceServiceResult<ProposalRecord> {
+        let realm = self.realm_key(&proposal.realm)?;
+        self.ensure_member(&realm, &proposal.proposer)?;
+        self.validate_action(&realm, &proposal.action)?;
+        if proposal.title.trim().is_empty() {
+            return Err(GovernanceServiceError::InvalidProposal(
+                "title must not be empty".to_string(),
+            ));
+        }
+        let period = proposal
+            .voting_period_secs
+            .unwrap_or(self.config.voting.proposal_duration_hours * 3_600);
+        if period == 0 || period > self.config.max_voting_period_secs {
+            return Err(GovernanceServiceError::InvalidProposal(format!(
+                "voting period must be between 1 and {} seconds",
+                self.config.max_voting_period_secs
+            )));
+        }
+
+        let proposer = stored_did(&self.storage, &proposal.proposer);
+        let members = self.members(&realm)?;
+
+        let mut realms = self.realms.lock();
+        let result: GovernanceServiceResult<ProposalRecord> = (|| {
+            let governance = self.governance(&mut realms, &realm)?;
+            let mut state = self.storage.governance.realm(&realm)?;
+            let model = self.model(&realm, &state)?;
+            if model == GovernanceModel::Quadratic {
+                for member in &members {
+                    self.ensure_voter(governance, &mut state, stored_did(&self.storage, member));
+                }
+            }
+
+            let description_hash = description_hash(&proposal.description, &proposal.action);
+            let event_id = self.append_event(
+                &realm,
+                &mut state,
+                proposal.proposer,
+                EventPayload::Proposal {
+                    proposer: proposal.proposer,
+                    title: proposal.title.clone(),
+                    description_hash,
+                },
+            )?;
+            let id = event_id.to_hex();
+
+            // Gewichtete Modelle zählen ohne Credits aus (siehe `weighted_tally`)
+            let period = chrono::Duration::seconds(period as i64);
+            let (created_at, closes_at) = if model == GovernanceModel::Quadratic {
+                let created = governance.create_proposal_with_period(
+                    id.clone(),
+                    proposal.title.clone(),
+                    proposal.description.clone(),
+                    proposer.clone(),
+                    period,
+                );
+                (created.created_at, created.closes_at)
+            } else {
+                let now = Utc::now();
+                (now, now + period)
+            };
+            let record = ProposalRecord {
+                id,
+                realm: realm.clone(),
+                title: proposal.title,
+                description: proposal.description,
+                proposer,
+                action: proposal.action,
+                model,
+                snapshot_height: self.storage.ledger.journal_len(),
+                phase: ProposalPhase::Active,
+                votes: Vec::new(),
+                tally: None,
+                execution_error: None,
+                created_at: created_at.timestamp(),
+                closes_at: closes_at.timestamp(),
+                finalized_at: None,
+            };
+            self.storage.governance.put_realm(&realm, &state)?;
+            self.storage.governance.put(&record)?;
+            Ok(record)
+        })();
+        if result.is_err() {
+            realms.remove(&realm);
+        }
+        drop(realms);
+
+        let record = result?;
+        self.unified_state.log_and_apply(
+            StateEvent::ProposalCreated {
+                proposal_id: record.id.clone(),
+                realm_id: record.realm.clone(),
+                proposer_id: record.proposer.to_uri(),
+                proposal_type: record.action.type_tag().to_string(),
+                deadline_ms: record.closes_at as u128 * 1_000,
+            },
+            vec![],
+        );
+        Ok(record)
+    }
+
+    /// Gibt `vote_count` Stimmen ab
+    ///
+    /// Quadratic: Kosten `vote_count²` Credits (Κ21). Gewichtete Modelle
+    /// erwarten genau eine Stimme; ihr Gewicht wird bei der Auszählung bestimmt.
+    pub fn vote(
+        &self,
+        proposal_id: &str,
+        voter: &UniversalId,
+        direction: VoteDirection,
+        vote_count: u64,
+    ) -> GovernanceServiceResult<ProposalRecord> {
+        let mut record = self.load(proposal_id)?;
+        if !record.is_active() || record.is_due(Utc::now().timestamp()) {
+            return Err(GovernanceError::ProposalClosed.into());
+        }
+        self.ensure_member(&record.realm, voter)?;
+        let voter_did = stored_did(&self.storage, voter);
+
+        let mut realms = self.realms.lock();
+        let result: GovernanceServiceResult<()> = (|| {
+            // Im Lock neu laden: gleichzeitige Stimmen derselben DID (z. B.
+            // Retries nach Timeout) sehen einander – eine Stimme pro DID
+            record = self.load(proposal_id)?;
+            if record.votes.iter().any(|v| v.voter == voter_did) {
+                return Err(GovernanceError::AlreadyVoted.into());
+            }
+            let governance = self.governance(&mut realms, &record.realm)?;
+            let mut state = self.storage.governance.realm(&record.realm)?;
+            let (credit_cost, at) = if record.model == GovernanceModel::Quadratic {
+                self.ensure_voter(governance, &mut state, voter_did.clone());
+                let vote = governance.vote(
+                    &record.id,
+                    voter_did.clone(),
+                    to_quadratic(direction),
+                    vote_count,
+                )?;
+                (vote.credit_cost, vote.timestamp.timestamp())
+            } else {
+                if vote_count != 1 {
+                    return Err(GovernanceError::InvalidVoteCount(format!(
+                        "{} governance weighs a single ballot",
+                        record.model.governance_type()
+                    ))
+                    .into());
+                }
+                (0, Utc::now().timestamp())
+            };
+            let proposal_event = UniversalId::from_hex(&record.id)
+                .map_err(|_| GovernanceServiceError::ProposalNotFound(record.id.clone()))?;
+            let event_id = self.append_event(
+                &record.realm,
+                &mut state,
+                *voter,
+                EventPayload::Vote {
+                    voter: *voter,
+                    proposal_id: proposal_event,
+                    direction,
+                    weight: vote_count,
+                },
+            )?;
+            record.votes.push(VoteRecord {
+                voter: voter_did.clone(),
+                direction,
+                vote_count,
+                credit_cost,
+                event_id: event_id.to_hex(),
+                at,
+            });
+            self.storage.governance.put_realm(&record.realm, &state)?;
+            self.storage.governance.put(&record)?;
+            Ok(())
+        })();
+        if result.is_err() {
+            realms.remove(&record.realm);
+        }
+        drop(realms);
+
+        result?;
+        self.unified_state.log_and_apply(
+            StateEvent::VoteCast {
+                proposal_id: record.id.clone(),
+                voter_id: voter_did.to_uri(),
+                vote: direction == VoteDirection::For,
+                weight: vote_count as f64,
+            },
+            vec![],
+        );
+        Ok(record)
+    }
+
+    /// Finalisiert ein Proposal nach Ablauf der Frist (idempotent)
+    pub fn finalize(&self, proposal_id: &str) -> GovernanceServiceResult<ProposalRecord> {
+        self.finalize_at(proposal_id, Utc::now().timestamp())
+    }
+
+    /// Finalisiert ein Proposal zum Zeitpunkt `now` (Unix-Sekunden)
+    pub fn finalize_at(
+        &self,
+        proposal_id: &str,
+        now: i64,
+    ) -> GovernanceServiceResult<ProposalRecord> {
+        let mut record = self.load(proposal_id)?;
+        if !record.is_active() {
+            return Ok(record);
+        }
+        if now < record.closes_at {
+            return Err(GovernanceServiceError::VotingOpen(record.closes_at));
+        }
+
+        let mut realms = self.realms.lock();
+        let result: GovernanceServiceResult<()> = (|| {
+            let mut state = self.storage.governance.realm(&record.realm)?;
+            let (tally, resolution) = if record.model == GovernanceModel::Quadratic {
+                let governance = self.governance(&mut realms, &record.realm)?;
+                let tally = governance.tally(&record.id)?;
+                governance.finalize(&record.id)?;
+                let credits: HashMap<UniversalId, u64> = state
+                    .grants
+                    .iter()
+                    .map(|grant| (grant.voter.id, grant.credits))
+                    .collect();
+                let voters = record.votes.iter().map(|v| v.voter.id).collect();
+                (
+                    TallyRecord {
+                        votes_for: tally.votes_for,
+                        votes_against: tally.votes_against,
+                        votes_abstain: tally.votes_abstain,
+                        credits_spent: tally.credits_spent,
+                        unique_voters: tally.unique_voters,
+                        approval_ratio: tally.approval_ratio,
+                        quorum_reached: tally.quorum_reached,
+                        passed: tally.passed,
+                        total_power: credits.values().sum(),
+                        delegated_votes: 0,
+                    },
+                    WeightResolution::direct(&credits, &voters),
+                )
+            } else {
+                self.weighted_tally(&record)?
+            };
+            self.record_metrics(&record.model, &resolution);
+
+            record.phase = if tally.passed {
+                match self.execute_action(&record, &mut state) {
+                    Ok(()) => ProposalPhase::Executed,
+                    Err(e) => {
+                        record.execution_error = Some(e);
+                        ProposalPhase::Failed
+                    }
+                }
+            } else {
+                ProposalPhase::Rejected
+            };
+            record.tally = Some(tally);
+            record.finalized_at = Some(now);
+            self.storage.governance.put_realm(&record.realm, &state)?;
+            self.storage.governance.put(&record)?;
+            Ok(())
+        })();
+        if result.is_err() {
+            realms.remove(&record.realm);
+        }
+        drop(realms);
+
+        result?;
+        let tally = record.tally.as_ref();
+        self.unified_state.log_and_apply(
+            StateEvent::ProposalResolved {
+                proposal_id: record.id.clone(),
+                accepted: tally.is_some_and(|t| t.passed),
+                yes_votes: tally.map(|t| t.votes_for).unwrap_or(0),
+                no_votes: tally.map(|t| t.votes_against).unwrap_or(0),
+                execution_status: match record.phase {
+                    ProposalPhase::Executed => Some("executed".to_string()),
+                    ProposalPhase::Failed => record
+                        .execution_error
+                        .as_ref()
+                        .map(|e| format!("failed: {e}")),
+                    _ => None,
+                },
+            },
+            vec![],
+        );
+        Ok(record)
+    }
+
+    /// Finalisiert alle Proposals mit abgelaufener Frist
+    pub fn finalize_due(&self) -> GovernanceServiceResult<Vec<ProposalRecord>> {
+        self.finalize_due_at(Utc::now().timestamp())
+    }
+
+    /// Finalisiert alle zum Zeitpunkt `now` fälligen Proposals
+    pub fn finalize_due_at(&self, now: i64) -> GovernanceServiceResult<Vec<ProposalRecord>> {
+        let due: Vec<String> = self
+            .storage
+            .governance
+            .list(None)?
+            .into_iter()
+            .filter(|record| record.is_due(now))
+            .map(|record| record.id)
+            .collect();
+        due.iter().map(|id| self.finalize_at(id, now)).collect()
+    }
+
+    /// Startet einen Task, der fällige Proposals periodisch finalisiert
+    pub fn spawn_finalizer(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let service = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match service.finalize_due() {
+                    Ok(finalized) if !finalized.is_empty() => {
+                        tracing::info!(count = finalized.len(), "Finalized governance proposals");
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!(error = %e, "Governance finalizer failed"),
+                }
+            }
+        })
+    }
+
+    /// Lädt ein Proposal
+    pub fn get(&self, proposal_id: &str) -> GovernanceServiceResult<Option<ProposalRecord>> {
+        Ok(self.storage.governance.get(proposal_id)?)
+    }
+
+    /// Proposals, optional eines Realms (neueste zuerst)
+    pub fn list(&self, realm: Option<&str>) -> GovernanceServiceResult<Vec<ProposalRecord>> {
+        let realm = realm.map(|r| self.realm_key(r)).transpose()?;
+        Ok(self.storage.governance.list(realm.as_deref())?)
+    }
+
+    /// Verbleibende Credits eines Mitglieds im Realm
+    pub fn credits(&self, realm: &str, member: &UniversalId) -> GovernanceServiceResult<u64> {
+        let realm = self.realm_key(realm)?;
+        let mut realms = self.realms.lock();
+        let governance = self.governance(&mut realms, &realm)?;
+        Ok(governance.get_credits(&stored_did(&self.storage, member)))
+    }
+
+    // ========================================================================
+    // Interne Helfer
+    // ========================================================================
+
+    fn load(&self, proposal_id: &str) -> GovernanceServiceResult<ProposalRecord> {
+        self.storage
+            .governance
+            .get(proposal_id)?
+            .ok_or_else(|| GovernanceServiceError::ProposalNotFound(proposal_id.to_string()))
+    }
+
+    /// QuadraticGovernance des Realms (bei Bedarf aus dem Store rekonstruiert)
+    fn governance<'a>(
+        &self,
+        realms: &'a mut HashMap<String, QuadraticGovernance>,
+        realm: &str,
+    ) -> GovernanceServiceResult<&'a mut QuadraticGovernance> {
+        if !realms.contains_key(realm) {
+            let restored = self.restore(realm)?;
+            realms.insert(realm.to_string(), restored);
+        }
+        Ok(realms
+            .get_mut(realm)
+            .expect("realm governance inserted above"))
+    }
+
+    fn restore(&self, realm: &str) -> GovernanceServiceResult<QuadraticGovernance> {
+        let mut governance = QuadraticGovernance::new(self.config.voting.clone());
+        for grant in self.storage.governance.realm(realm)?.grants {
+            governance.add_credits(&grant.voter, grant.credits);
+        }
+        for record in self.storage.governance.list(Some(realm))? {
+            if record.model != GovernanceModel::Quadratic {
+                continue;
+            }
+            governance.restore_proposal(quadratic::Proposal {
+                id: record.id.clone(),
+                title: record.title.clone(),
+                description: record.description.clone(),
+                proposer: record.proposer.clone(),
+                created_at: timestamp(record.created_at),
+                closes_at: timestamp(record.closes_at),
+                status: match record.phase {
+                    ProposalPhase::Active => ProposalStatus::Active,
+                    ProposalPhase::Rejected => ProposalStatus::Rejected,
+                    ProposalPhase::Executed | ProposalPhase::Failed => ProposalStatus::Passed,
+                },
+            });
+            for vote in &record.votes {
+                governance.restore_vote(
+                    &record.id,
+                    quadratic::Vote {
+                        voter: vote.voter.clone(),
+                        direction: to_quadratic(vote.direction),
+                        vote_count: vote.vote_count,
+                        credit_cost: vote.credit_cost,
+                        timestamp: timestamp(vote.at),
+                    },
+                );
+            }
+        }
+        Ok(governance)
+    }
+
+    /// Registriert ein Mitglied mit trust-basierten Credits (einmalig pro Realm)
+    fn ensure_voter(
+        &self,
+        governance: &mut QuadraticGovernance,
+        state: &mut RealmGovernanceRecord,
+        voter: DID,
+    ) {
+        if governance.is_voter(&voter) {
+            return;
+        }
+        let trust = self
+            .storage
+            .trust
+            .compute_reputation(&voter)
+            .unwrap_or_default();
+        let credits = ((trust.mean() as f64 * self.config.credits_per_trust as f64).round() as u64)
+            .max(self.config.min_credits);
+        governance.add_credits(&voter, credits);
+        state.grants.push(CreditGrant { voter, credits });
+    }
+
+    /// Hängt ein Event an die Governance-Kette des Realms an
+    fn append_event(
+        &self,
+        realm: &str,
+        state: &mut RealmGovernanceRecord,
+        author: UniversalId,
+        payload: EventPayload,
+    ) -> GovernanceServiceResult<UniversalId> {
+        let parent = state
+            .head
+            .as_deref()
+            .and_then(|head| UniversalId::from_hex(head).ok())
+            .unwrap_or_else(|| resolve_realm(realm));
+        state.lamport += 1;
+        let event = Event::new(author, vec![parent], payload, state.lamport);
+        let id = event.id;
+        self.storage.events.put(event)?;
+        state.head = Some(id.to_hex());
+        Ok(id)
+    }
+
+    /// Governance-Modell des Realms
+    ///
+    /// Reihenfolge: zuletzt per Proposal ausgeführte Modell-Regel, aktive
+    /// Realm-Regeln, persistiertes Regelset, `governance_type` des Realms.
+    fn model(
+        &self,
+        realm: &str,
+        state: &RealmGovernanceRecord,
+    ) -> GovernanceServiceResult<GovernanceModel> {
+        if let Some(model) = &state.model {
+            return Ok(model.clone());
+        }
+        self.with_realm(realm, |_, realm_state| {
+            let active = realm_state
+                .active_rules
+                .read()
+                .map(|rules| rules.clone())
+                .unwrap_or_default();
+            active
+                .iter()
+                .rev()
+                .find_map(|id| GovernanceModel::from_rule_id(id))
+                .or_else(|| GovernanceModel::from_rules(&state.rules))
+                .unwrap_or_else(|| {
+                    let governance_type = match realm_state
+                        .governance_type
+                        .read()
+                        .map(|t| t.to_ascii_lowercase())
+                        .as_deref()
+                    {
+                        Ok("token") => GovernanceType::Token,
+                        Ok("reputation") => GovernanceType::Reputation,
+                        Ok("delegated" | "liquid") => GovernanceType::Delegated,
+                        _ => GovernanceType::Quadratic,
+                    };
+                    GovernanceModel::for_type(governance_type, NATIVE_ASSET)
+                })
+        })
+    }
+
+    /// Auszählung gewichteter Modelle (Token, Reputation, Delegated)
+    fn weighted_tally(
+        &self,
+        record: &ProposalRecord,
+    ) -> GovernanceServiceResult<(TallyRecord, WeightResolution)> {
+        let weighting = weighting_for(&record.model).ok_or_else(|| {
+            GovernanceServiceError::InvalidProposal("model has no vote weighting".to_string())
+        })?;
+        let members = self.members(&record.realm)?;
+        let balances = match &record.model {
+            GovernanceModel::Token { asset } => self
+                .storage
+                .ledger
+                .balances_at(asset, record.snapshot_height)?,
+            _ => HashMap::new(),
+        };
+        let trust = match record.model {
+            GovernanceModel::Reputation { .. } => members
+                .iter()
+                .map(|m| {
+                    let did = stored_did(&self.storage, m);
+                    let trust = self
+                        .storage
+                        .trust
+                        .compute_reputation(&did)
+                        .unwrap_or_default();
+                    (*m, trust)
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+        let delegations = match record.model {
+            GovernanceModel::Delegated { .. } => self.storage.ledger.active_delegations()?,
+            _ => Vec::new(),
+        };
+        let ctx = WeightingContext {
+            members,
+            balances,
+            trust,
+            delegations,
+        };
+        let voters = record.votes.iter().map(|v| v.voter.id).collect();
+        let resolution = weighting.resolve(&ctx, &voters);
+
+        let (mut votes_for, mut votes_against, mut votes_abstain) = (0u64, 0u64, 0u64);
+        for vote in &record.votes {
+            let weight = resolution.weights.get(&vote.voter.id).copied().unwrap_or(0);
+            match vote.direction {
+                VoteDirection::For => votes_for += weight,
+                VoteDirection::Against => votes_against += weight,
+                VoteDirection::Abstain => votes_abstain += weight,
+            }
+        }
+        let decisive = votes_for + votes_against;
+        let approval_ratio = if decisive > 0 {
+            votes_for as f64 / decisive as f64
+        } else {
+            0.0
+        };
+        let quorum_reached = resolution.total_power == 0
+            || resolution.participating_power() as f64 / resolution.total_power as f64
+                >= self.config.voting.quorum_ratio;
+        let tally = TallyRecord {
+            votes_for,
+            votes_against,
+            votes_abstain,
+            credits_spent: 0,
+            unique_voters: record.votes.len(),
+            approval_ratio,
+            quorum_reached,
+            passed: quorum_reached && approval_ratio >= self.config.voting.approval_threshold,
+            total_power: resolution.total_power,
+            delegated_votes: resolution.delegated_votes,
+        };
+        Ok((tally, resolution))
+    }
+
+    /// Überträgt Machtverteilung und Delegationen in den GovernanceState
+    fn record_metrics(&self, model: &GovernanceModel, resolution: &WeightResolution) {
+        let metrics = &self.unified_state.governance;
+        metrics.power_check(resolution.calcified, resolution.gini);
+        if let GovernanceModel::Delegated { .. } = model {
+            metrics
+                .votes_delegated
+                .fetch_add(resolution.delegated_votes, Ordering::Relaxed);
+            metrics
+                .max_delegation_depth
+                .fetch_max(resolution.max_delegation_depth as u64, Ordering::Relaxed);
+            metrics
+                .circular_delegations_prevented
+                .fetch_add(resolution.cycles_prevented as u64, Ordering::Relaxed);
+            if let Ok(active) = self.storage.ledger.active_delegations() {
+                metrics
+                    .delegations_active
+                    .store(active.len() as u64, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Schlüssel des Realms im Realm-State
+    fn realm_key(&self, realm: &str) -> GovernanceServiceResult<String> {
+        self.with_realm(realm, |key, _| key.to_string())
+    }
+
+    fn with_realm<T>(
+        &self,
+        realm: &str,
+        f: impl FnOnce(&str, &RealmSpecificState) -> T,
+    ) -> GovernanceServiceResult<T> {
+        let unknown = || GovernanceServiceError::UnknownRealm(realm.to_string());
+        let realms = self
+            .unified_state
+            .peer
+            .realm
+            .realms
+            .read()
+            .map_err(|_| unknown())?;
+        let hex = resolve_realm(realm).to_hex();
+        let (key, state) = realms
+            .get_key_value(realm)
+            .or_else(|| realms.get_key_value(&hex))
+            .ok_or_else(unknown)?;
+        Ok(f(key, state))
+    }
+
+    fn members(&self, realm: &str) -> GovernanceServiceResult<Vec<UniversalId>> {
+        self.with_realm(realm, |_, state| {
+            let mut members: Vec<UniversalId> = state
+                .members_by_id
+                .read()
+                .map(|m| m.iter().copied().collect())
+                .unwrap_or_default();
+            members.sort_by_key(|id| id.to_hex());
+            members
+        })
+    }
+
+    fn ensure_member(&self, realm: &str, id: &UniversalId) -> GovernanceServiceResult<()> {
+        let is_member = self.with_realm(realm, |_, state| {
+            state
+                .members_by_id
+                .read()
+                .map(|m| m.contains(id))
+                .unwrap_or(false)
+        })?;
+        if is_member {
+            Ok(())
+        } else {
+            Err(GovernanceServiceError::NotAMember {
+                member: id.to_hex(),
+                realm: realm.to_string(),
+            })
+        }
+    }
+
+    /// Prüft eine Aktion vor der Abstimmung
+    fn validate_action(
+        &self,
+        realm: &str,
+        action: &GovernanceAction,
+    ) -> GovernanceServiceResult<()> {
+        let invalid = |message: &str| Err(GovernanceServiceError::InvalidProposal(message.into()));
+        match action {
+            GovernanceAction::AddRules { rules } => {
+                if rules.is_empty() || rules.iter().any(|r| r.id.trim().is_empty()) {
+                    return invalid("add_rules needs rules with non-empty ids");
+                }
+                let unknown_model = rules.iter().any(|r| {
+                    r.id.starts_with(GOVERNANCE_RULE_PREFIX)
+                        && GovernanceModel::from_rule_id(&r.id).is_none()
+                });
+                if unknown_model {
+                    return invalid("unknown governance model rule");
+                }
+            }
+            GovernanceAction::SetMinTrust { min_trust } => {
+                if !(0.0..=1.0).contains(min_trust) {
+                    return invalid("min_trust must be in [0, 1]");
+                }
+            }
+            GovernanceAction::EvolveSchema { store, changes, .. } => {
+                if changes.is_empty() {
+                    return invalid("evolve_schema needs at least one change");
+                }
+                let schema = self
+                    .storage
+                    .realm
+                    .get_schema(&resolve_realm(realm), store, None)
+                    .map_err(|e| GovernanceServiceError::InvalidProposal(e.to_string()))?;
+                if schema.personal {
+                    return invalid("personal stores are not governed by the realm");
+                }
+            }
+            GovernanceAction::ReplacePolicy {
+                old_policy,
+                new_policy,
+            } => {
+                if new_policy.trim().is_empty() || old_policy.as_ref() == Some(new_policy) {
+                    return invalid("replace_policy needs a new, different policy");
+                }
+            }
+            GovernanceAction::AdjustQuota { limits } => {
+                if limits.is_empty() {
+                    return invalid("adjust_quota needs at least one limit");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Führt die Aktion eines angenommenen Proposals im Realm aus
+    fn execute_action(
+        &self,
+        record: &ProposalRecord,
+        state: &mut RealmGovernanceRecord,
+    ) -> Result<(), String> {
+        if let GovernanceAction::EvolveSchema {
+            store,
+            changes,
+            description,
+        } = &record.action
+        {
+            let description = if description.is_empty() {
+                record.title.clone()
+            } else {
+                description.clone()
+            };
+            return self
+                .storage
+                .realm
+                .evolve_schema(
+                    &resolve_realm(&record.realm),
+                    &record.proposer,
+                    store,
+                    changes.clone(),
+                    description,
+                )
+                .map(|_| ())
+                .map_err(|e| e.to_string());
+        }
+
+        let key = self.realm_key(&record.realm).map_err(|e| e.to_string())?;
+        let change = match &record.action {
+            GovernanceAction::AddRules { rules } => {
+                let mut change = RealmConfigChange::default();
+                for rule in rules {
+                    state.rules.add(rule.clone());
+                    change.add_rules.push(rule.id.clone());
+                    if let Some(model) = GovernanceModel::from_rule_id(&rule.id) {
+                        change.governance_type = Some(model.governance_type().to_string());
+                        state.model = Some(model);
+                    }
+                }
+                change
+            }
+            GovernanceAction::SetMinTrust { min_trust } => RealmConfigChange {
+                min_trust: Some(*min_trust),
+                ..Default::default()
+            },
+            GovernanceAction::ReplacePolicy {
+                old_policy,
+                new_policy,
+            } => RealmConfigChange {
+                deactivate_policy: old_policy.clone(),
+                activate_policy: Some(new_policy.clone()),
+                ..Default::default()
+            },
+            GovernanceAction::AdjustQuota { limits } => RealmConfigChange {
+                quota_limits: [
+                    (ResourceType::QueueSlots, limits.queue_slots),
+                    (ResourceType::StorageBytes, limits.storage_bytes),
+                    (ResourceType::ComputeGas, limits.compute_gas),
+                    (ResourceType::Events, limits.events),
+                    (ResourceType::Crossings, limits.crossings),
+                ]
+                .into_iter()
+                .filter_map(|(resource, limit)| limit.map(|limit| (resource, limit)))
+                .collect(),
+                ..Default::default()
+            },
+            GovernanceAction::EvolveSchema { .. } => return Ok(()),
+        };
+
+        // Als StateEvent loggen und im Realm-Record persistieren (Replay nach Neustart)
+        let config = serde_json::to_string(&change).map_err(|e| e.to_string())?;
+        self.unified_state.log_and_apply(
+            StateEvent::RealmLifecycle {
+                realm_id: key,
+                action: RealmAction::ConfigChanged,
+                config: Some(config.clone()),
+            },
+            vec![],
+        );
+        state.config_changes.push(config);
+        Ok(())
+    }
+}
+
+fn to_quadratic(direction: VoteDirection) -> quadratic::VoteDirection {
+    match direction {
+        VoteDirection::For => quadratic::VoteDirection::For,
+        VoteDirection::Against => quadratic::VoteDirection::Against,
+        VoteDirection::Abstain => quadratic::VoteDirection::Abstain,
+    }
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(secs, 0).single().unwrap_or_default()
+}
+
+/// Hash über Beschreibung und Aktion (für `EventPayload::Proposal`)
+fn description_hash(description: &str, action: &GovernanceAction) -> Hash32 {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(description.as_bytes());
+    hasher.update(&serde_json::to_vec(action).unwrap_or_default());
+    Hash32(*hasher.finalize().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UnifiedState;
+    use crate::domain::unified::{Capability, Delegation, Rule, RuleCategory};
+    use crate::local::{QuotaLimits, SchemaChange, SchemaFieldType, StoreSchema};
+    use std::sync::atomic::Ordering;
+
+    fn id(name: &str) -> UniversalId {
+        DID::new_self(name.as_bytes()).id
+    }
+
+    fn setup(members: &[UniversalId]) -> (GovernanceService, SharedUnifiedState) {
+        setup_with(members, "quadratic")
+    }
+
+    fn setup_with(
+        members: &[UniversalId],
+        governance_type: &str,
+    ) -> (GovernanceService, SharedUnifiedState) {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let state = Arc::new(UnifiedState::new());
+        state
+            .peer
+            .realm
+            .register_realm("guild", 0.3, governance_type);
+        {
+            let realms = state.peer.realm.realms.read().unwrap();
+            for member in members {
+                realms.get("guild").unwrap().add_member_by_id(*member, None);
+            }
+        }
+        let service = GovernanceService::new(storage, state.clone(), GovernanceConfig::default());
+        (service, state)
+    }
+
+    fn proposal(proposer: UniversalId, action: GovernanceAction) -> NewProposal {
+        NewProposal {
+            realm: "guild".into(),
+            proposer,
+            title: "Raise the bar".into(),
+            description: "Members need more trust".into(),
+            action,
+            voting_period_secs: Some(60),
+        }
+    }
+
+    #[test]
+    fn test_passed_proposal_executes_action() {
+        let (alice, bob, carol) = (id("alice"), id("bob"), id("carol"));
+        let (service, state) = setup(&[alice, bob, carol]);
+
+        let record = service
+            .propose(proposal(
+                alice,
+                GovernanceAction::SetMinTrust { min_trust: 0.6 },
+            ))
+            .unwrap();
+        service
+            .vote(&record.id, &alice, VoteDirection::For, 3)
+            .unwrap();
+        service
+            .vote(&record.id, &bob, VoteDirection::Against, 1)
+            .unwrap();
+
+        // Credits aus Trust (Newcomer), Κ21: 3 Stimmen kosten 9
+        let credits = service.credits("guild", &alice).unwrap();
+        assert_eq!(credits + 9, service.credits("guild", &carol).unwrap());
+        assert!(matches!(
+            service.vote(&record.id, &alice, VoteDirection::For, 1),
+            Err(GovernanceServiceError::Voting(
+                GovernanceError::AlreadyVoted
+            ))
+        ));
+        assert!(matches!(
+            service.finalize(&record.id),
+            Err(GovernanceServiceError::VotingOpen(_))
+        ));
+
+        let finalized = service.finalize_due_at(record.closes_at).unwrap();
+        assert_eq!(finalized.len(), 1);
+        assert_eq!(finalized[0].phase, ProposalPhase::Executed);
+        assert_eq!(finalized[0].tally.as_ref().unwrap().votes_for, 3);
+        let realms = state.peer.realm.realms.read().unwrap();
+        assert_eq!(*realms["guild"].min_trust.read().unwrap(), 0.6);
+
+        // Proposal + 2 Votes als auditierbare Events
+        assert_eq!(service.storage.events.count(), 3);
+    }
+
+    #[test]
+    fn test_executed_actions_are_logged_and_survive_restart() {
+        let (alice, bob) = (id("alice"), id("bob"));
+        let (service, state) = setup(&[alice, bob]);
+        let actions = [
+            GovernanceAction::SetMinTrust { min_trust: 0.7 },
+            GovernanceAction::ReplacePolicy {
+                old_policy: None,
+                new_policy: "strict".into(),
+            },
+            GovernanceAction::AdjustQuota {
+                limits: QuotaLimits {
+                    events: Some(42),
+                    ..Default::default()
+                },
+            },
+        ];
+        for action in actions {
+            let record = service.propose(proposal(alice, action)).unwrap();
+            service
+                .vote(&record.id, &alice, VoteDirection::For, 2)
+                .unwrap();
+            let record = service.finalize_at(&record.id, record.closes_at).unwrap();
+            assert_eq!(record.phase, ProposalPhase::Executed);
+        }
+
+        let logged = state
+            .event_log
+            .events_since(0)
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e.event,
+                    StateEvent::RealmLifecycle {
+                        action: RealmAction::ConfigChanged,
+                        ..
+                    }
+                )
+            })
+            .count();
+        assert_eq!(logged, 3);
+
+        // Neuer Unified State (Neustart): Realm wird erneut registriert
+        let restarted = Arc::new(UnifiedState::new());
+        restarted.peer.realm.register_realm("guild", 0.3, "quadratic");
+        let _service = GovernanceService::new(
+            service.storage.clone(),
+            restarted.clone(),
+            GovernanceConfig::default(),
+        );
+        let realms = restarted.peer.realm.realms.read().unwrap();
+        let guild = &realms["guild"];
+        assert_eq!(*guild.min_trust.read().unwrap(), 0.7);
+        assert!(guild
+            .active_policies
+            .read()
+            .unwrap()
+            .contains(&"strict".to_string()));
+        assert_eq!(guild.quota.snapshot().events_limit, 42);
+    }
+
+    #[test]
+    fn test_rejected_proposal_and_membership() {
+        let (alice, bob, mallory) = (id("alice"), id("bob"), id("mallory"));
+        let (service, state) = setup(&[alice, bob]);
+        let action = GovernanceAction::AdjustQuota {
+            limits: QuotaLimits {
+                events: Some(5),
+                ..Default::default()
+            },
+        };
+
+        assert!(matches!(
+            service.propose(proposal(mallory, action.clone())),
+            Err(GovernanceServiceError::NotAMember { .. })
+        ));
+        let record = service.propose(proposal(alice, action)).unwrap();
+        assert!(matches!(
+            service.vote(&record.id, &mallory, VoteDirection::For, 1),
+            Err(GovernanceServiceError::NotAMember { .. })
+        ));
+        service
+            .vote(&record.id, &alice, VoteDirection::For, 1)
+            .unwrap();
+        service
+            .vote(&record.id, &bob, VoteDirection::Against, 2)
+            .unwrap();
+
+        let record = service.finalize_at(&record.id, record.closes_at).unwrap();
+        assert_eq!(record.phase, ProposalPhase::Rejected);
+        let realms = state.peer.realm.realms.read().unwrap();
+        assert_eq!(realms["guild"].quota.snapshot().events_limit, 10_000);
+    }
+
+    #[test]
+    fn test_restore_after_restart_and_schema_evolution() {
+        let (alice, bob) = (id("alice"), id("bob"));
+        let (service, state) = setup(&[alice, bob]);
+        let realm = resolve_realm("guild");
+        let schema = StoreSchema::new("posts", false).with_field("title", SchemaFieldType::String);
+        service
+            .storage
+            .realm
+            .create_store(&realm, &stored_did(&service.storage, &alice), schema)
+            .unwrap();
+
+        let action = GovernanceAction::EvolveSchema {
+            store: "posts".into(),
+            changes: vec![SchemaChange::AddField {
+                name: "tags".into(),
+                field_type: SchemaFieldType::String,
+                default: None,
+            }],
+            description: String::new(),
+        };
+        let record = service.propose(proposal(alice, action)).unwrap();
+        service
+            .vote(&record.id, &alice, VoteDirection::For, 2)
+            .unwrap();
+        let remaining = service.credits("guild", &alice).unwrap();
+
+        // Neuer Service auf demselben Storage rekonstruiert Credits und Stimmen
+        let restarted =
+            GovernanceService::new(service.storage.clone(), state, GovernanceConfig::default());
+        assert_eq!(restarted.credits("guild", &alice).unwrap(), remaining);
+        assert!(matches!(
+            restarted.vote(&record.id, &alice, VoteDirection::For, 1),
+            Err(GovernanceServiceError::Voting(
+                GovernanceError::AlreadyVoted
+            ))
+        ));
+
+        let record = restarted.finalize_at(&record.id, record.closes_at).unwrap();
+        assert_eq!(record.phase, ProposalPhase::Executed);
+        let schema = restarted
+            .storage
+            .realm
+            .get_schema(&realm, "posts", None)
+            .unwrap();
+        assert!(schema.fields.contains_key("tags"));
+
+        let rules = GovernanceAction::AddRules {
+            rules: vec![Rule::new("gdpr", "GDPR", RuleCategory::Compliance, "EU")],
+        };
+        let invalid = GovernanceAction::SetMinTrust { min_trust: 1.5 };
+        assert!(restarted.propose(proposal(bob, rules)).is_ok());
+        assert!(matches!(
+            restarted.propose(proposal(bob, invalid)),
+            Err(GovernanceServiceError::InvalidProposal(_))
+        ));
+    }
+
+    #[test]
+    fn test_concurrent_votes_count_once_per_did() {
+        let (alice, bob) = (id("alice"), id("bob"));
+        let (service, _state) = setup_with(&[alice, bob], "token");
+        let record = service
+            .propose(proposal(
+                alice,
+                GovernanceAction::SetMinTrust { min_trust: 0.4 },
+            ))
+            .unwrap();
+
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| service.vote(&record.id, &bob, VoteDirection::For, 1)))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        assert!(results.iter().filter_map(|r| r.as_ref().err()).all(|e| matches!(
+            e,
+            GovernanceServiceError::Voting(GovernanceError::AlreadyVoted)
+        )));
+        assert_eq!(service.get(&record.id).unwrap().unwrap().votes.len(), 1);
+    }
+
+    #[test]
+    fn test_token_snapshot_then_switch_to_delegated() {
+        let (alice, bob, carol) = (id("alice"), id("bob"), id("carol"));
+        let (service, state) = setup_with(&[alice, bob, carol], "token");
+        let ledger = &service.storage.ledger;
+        ledger.register_asset(NATIVE_ASSET, alice).unwrap();
+        ledger
+            .mint(&alice, &alice, NATIVE_ASSET, 100, None)
+            .unwrap();
+        ledger.mint(&alice, &bob, NATIVE_ASSET, 300, None).unwrap();
+
+        let switch = GovernanceAction::AddRules {
+            rules: vec![GovernanceModel::Delegated { max_depth: 2 }.rule()],
+        };
+        let record = service.propose(proposal(alice, switch.clone())).unwrap();
+        assert_eq!(
+            record.model,
+            GovernanceModel::Token {
+                asset: NATIVE_ASSET.into()
+            }
+        );
+        // Spätere Buchungen ändern die Snapshot-Salden nicht
+        ledger
+            .transfer(&bob, &alice, NATIVE_ASSET, 300, None)
+            .unwrap();
+        assert!(matches!(
+            service.vote(&record.id, &alice, VoteDirection::For, 2),
+            Err(GovernanceServiceError::Voting(
+                GovernanceError::InvalidVoteCount(_)
+            ))
+        ));
+        service
+            .vote(&record.id, &alice, VoteDirection::For, 1)
+            .unwrap();
+        service
+            .vote(&record.id, &bob, VoteDirection::Against, 1)
+            .unwrap();
+        let record = service.finalize_at(&record.id, record.closes_at).unwrap();
+        let tally = record.tally.unwrap();
+        assert_eq!(
+            (tally.votes_for, tally.votes_against, tally.total_power),
+            (100, 300, 400)
+        );
+        assert_eq!(record.phase, ProposalPhase::Rejected);
+
+        // Neuer Snapshot: alice hält alles und wechselt das Modell
+        let record = service.propose(proposal(alice, switch)).unwrap();
+        service
+            .vote(&record.id, &alice, VoteDirection::For, 1)
+            .unwrap();
+        let record = service.finalize_at(&record.id, record.closes_at).unwrap();
+        assert_eq!(record.phase, ProposalPhase::Executed);
+
+        // Delegated: carol delegiert an alice
+        ledger
+            .grant_delegation(Delegation::new(
+                carol,
+                alice,
+                1.0,
+                vec![Capability::Delegate { max_depth: 2 }],
+            ))
+            .unwrap();
+        let action = GovernanceAction::SetMinTrust { min_trust: 0.4 };
+        let record = service.propose(proposal(bob, action)).unwrap();
+        assert_eq!(record.model, GovernanceModel::Delegated { max_depth: 2 });
+        service
+            .vote(&record.id, &alice, VoteDirection::For, 1)
+            .unwrap();
+        service
+            .vote(&record.id, &bob, VoteDirection::Against, 1)
+            .unwrap();
+        let record = service.finalize_at(&record.id, record.closes_at).unwrap();
+        let tally = record.tally.unwrap();
+        assert_eq!((tally.votes_for, tally.votes_against), (2, 1));
+        assert_eq!(tally.delegated_votes, 1);
+        assert_eq!(record.phase, ProposalPhase::Executed);
+
+        let metrics = &state.governance;
+        assert_eq!(metrics.max_delegation_depth.load(Ordering::Relaxed), 1);
+        assert_eq!(metrics.delegations_active.load(Ordering::Relaxed), 1);
+        let realms = state.peer.realm.realms.read().unwrap();
+        assert_eq!(
+            *realms["guild"].governance_type.read().unwrap(),
+            "delegated"
+        );
+    }
+}
@@ -0,0 +1,1251 @@
+//! # Intent-Grammatik
+//!
+//! Deterministische, offline Grammatik für natürlichsprachliche Intents (Κ22).
+//!
+//! ## Grammatik
+//!
+//! ```text
+//! intent    := sequence
+//! sequence  := clause ((connector)? clause | modifier)*
+//! connector := "and" | "then" | "," | ";"
+//! clause    := transfer | attest | delegate | query | create | "(" sequence ")"
+//! transfer  := ("send" | "transfer" | "pay") amount [asset] "to" recipient
+//! attest    := ("attest" | "certify" | "verify") recipient ["claim" | "that" | "as"] text
+//! delegate  := ("delegate" | "grant" | "authorize") capability ("," capability)* "to" recipient
+//!              ["for" duration] ["with" "trust" number]
+//! query     := ("query" | "find" | "search") text
+//! create    := ("create" | "new") entity_type ["with" key "=" value ("," key "=" value)*]
+//! modifier  := "if" "trust"["." dim] (">" | ">=") number          → MinTrust
+//!            | "max" "cost" number ("gas" | "mana") [number unit] → MaxCost
+//!            | ("before" | "within") duration                     → Timeout
+//!            | ("before" | "within") ("@" lamport | "lamport" n)  → Deadline
+//!            | "in" "realm" realm ("," realm)*                    → RealmRestriction
+//!            | "human" "only" | "only" "humans"                   → HumanOnly
+//! recipient := "did:erynoa:" namespace ":" hex | ["@"] alias
+//! duration  := number ("s" | "m" | "h" | "d" | "w")
+//! text      := "\"" ... "\"" | word+
+//! ```
+//!
+//! Beispiel: `send 25 ERY to did:erynoa:self:… before 3d if trust.R > 0.6`
+//!
+//! Fehler tragen den Byte-Bereich im Eingabetext und – wo möglich – einen
+//! Korrekturvorschlag.
+
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::{DIDNamespace, RealmId, UniversalId, ROOT_REALM_ID};
+
+const TRANSFER_VERBS: &[&str] = &["send", "transfer", "pay"];
+const ATTEST_VERBS: &[&str] = &["attest", "certify", "verify"];
+const DELEGATE_VERBS: &[&str] = &["delegate", "grant", "authorize"];
+const QUERY_VERBS: &[&str] = &["query", "find", "search"];
+const CREATE_VERBS: &[&str] = &["create", "new"];
+
+/// Vertrauensdimensionen für `if trust.<dim> > x`
+const TRUST_DIMENSIONS: &[&str] = &["R", "I", "C", "P", "V", "Ω"];
+
+// ============================================================================
+// Spans & Fehler
+// ============================================================================
+
+/// Byte-Bereich im Eingabetext
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    /// Start (inklusive)
+    pub start: usize,
+    /// Ende (exklusive)
+    pub end: usize,
+}
+
+impl Span {
+    fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    fn to(self, other: Span) -> Span {
+        Span::new(self.start, other.end)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Syntaxfehler mit Position und Vorschlag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// Fehlerbeschreibung
+    pub message: String,
+    /// Betroffener Bereich
+    pub span: Span,
+    /// Korrekturvorschlag
+    pub suggestion: Option<String>,
+}
+
+impl SyntaxError {
+    fn new(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            message: message.into(),
+            span,
+            suggestion: None,
+        }
+    }
+
+    fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+type GrammarResult<T> = Result<T, SyntaxError>;
+
+// ============================================================================
+// AST
+// ============================================================================
+
+/// Empfänger einer Aktion
+#[derive(Debug, Clone, PartialEq)]
+pub enum Recipient {
+    /// Vollständige DID
+    Id(UniversalId),
+    /// Realm-lokaler Alias (über `IdentityStore`-Metadaten aufzulösen)
+    Alias(String),
+}
+
+/// Empfänger mit Position
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipientRef {
+    pub recipient: Recipient,
+    pub span: Span,
+}
+
+/// Geparste Aktion
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClauseKind {
+    Transfer {
+        amount: u64,
+        asset_type: Option<String>,
+        to: RecipientRef,
+    },
+    Attest {
+        subject: RecipientRef,
+        claim: String,
+    },
+    Delegate {
+        capabilities: Vec<String>,
+        to: RecipientRef,
+        ttl_seconds: Option<u64>,
+        trust_factor: Option<f32>,
+    },
+    Query {
+        predicate: String,
+    },
+    Create {
+        entity_type: String,
+        params: Vec<(String, serde_json::Value)>,
+    },
+    /// Geklammerte Teilsequenz → `Goal::Complex`
+    Group {
+        description: String,
+        clauses: Vec<Clause>,
+    },
+}
+
+/// Aktion mit Position
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clause {
+    pub kind: ClauseKind,
+    pub span: Span,
+}
+
+/// Geparste Bedingung (gilt für den gesamten Intent)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Modifier {
+    /// `if trust[.dim] > x`
+    MinTrust { dimension: String, value: f32 },
+    /// `max cost N gas [M mana]`
+    MaxCost { gas: u64, mana: u64 },
+    /// `before 3d`
+    Timeout { seconds: u64 },
+    /// `before @1200`
+    Deadline { lamport: u32 },
+    /// `in realm …`
+    Realms { realm_ids: Vec<RealmId> },
+    /// `human only`
+    HumanOnly,
+}
+
+/// Ergebnis des Parsens
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntentAst {
+    /// Aktionen in Reihenfolge
+    pub clauses: Vec<Clause>,
+    /// Bedingungen in Reihenfolge
+    pub modifiers: Vec<Modifier>,
+}
+
+impl IntentAst {
+    /// Erstes Realm aus `in realm …`
+    pub fn context_realm(&self) -> Option<RealmId> {
+        self.modifiers.iter().find_map(|m| match m {
+            Modifier::Realms { realm_ids } => realm_ids.first().copied(),
+            _ => None,
+        })
+    }
+
+    /// Timeout aus `before <duration>`
+    pub fn timeout_seconds(&self) -> Option<u64> {
+        self.modifiers.iter().rev().find_map(|m| match m {
+            Modifier::Timeout { seconds } => Some(*seconds),
+            _ => None,
+        })
+    }
+}
+
+// ============================================================================
+// Lexer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Word(String),
+    Str(String),
+    Punct(&'static str),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    span: Span,
+}
+
+fn lex(text: &str) -> GrammarResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let punct = match c {
+            ',' => Some(","),
+            ';' => Some(";"),
+            '(' => Some("("),
+            ')' => Some(")"),
+            '=' => Some("="),
+            _ => None,
+        };
+        if let Some(p) = punct {
+            chars.next();
+            tokens.push(Token {
+                tok: Tok::Punct(p),
+                span: Span::new(start, start + 1),
+            });
+            continue;
+        }
+
+        match c {
+            '>' | '<' => {
+                chars.next();
+                let (p, end) = match chars.peek() {
+                    Some(&(i, '=')) => {
+                        chars.next();
+                        (if c == '>' { ">=" } else { "<=" }, i + 1)
+                    }
+                    _ => (if c == '>' { ">" } else { "<" }, start + 1),
+                };
+                tokens.push(Token {
+                    tok: Tok::Punct(p),
+                    span: Span::new(start, end),
+                });
+            }
+            '≥' | '≤' => {
+                chars.next();
+                tokens.push(Token {
+                    tok: Tok::Punct(if c == '≥' { ">=" } else { "<=" }),
+                    span: Span::new(start, start + c.len_utf8()),
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut value = String::new();
+                let mut end = None;
+                for (i, ch) in chars.by_ref() {
+                    if ch == '"' {
+                        end = Some(i + 1);
+                        break;
+                    }
+                    value.push(ch);
+                }
+                let Some(end) = end else {
+                    return Err(SyntaxError::new(
+                        "unterminated string",
+                        Span::new(start, text.len()),
+                    )
+                    .suggest("close the string with `\"`"));
+                };
+                tokens.push(Token {
+                    tok: Tok::Str(value),
+                    span: Span::new(start, end),
+                });
+            }
+            _ => {
+                let mut end = start;
+                while let Some(&(i, ch)) = chars.peek() {
+                    if ch.is_whitespace() || ",;()=<>≥≤\"".contains(ch) {
+                        break;
+                    }
+                    end = i + ch.len_utf8();
+                    chars.next();
+                }
+                tokens.push(Token {
+                    tok: Tok::Word(text[start..end].to_string()),
+                    span: Span::new(start, end),
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+/// Parst einen Intent-Text in einen [`IntentAst`]
+pub fn parse(text: &str) -> GrammarResult<IntentAst> {
+    let tokens = lex(text)?;
+    if tokens.is_empty() {
+        return Err(SyntaxError::new("empty intent", Span::new(0, text.len()))
+            .suggest("e.g. `send 25 ERY to did:erynoa:self:<hex>`"));
+    }
+
+    let mut parser = Parser {
+        text,
+        tokens,
+        pos: 0,
+        modifiers: Vec::new(),
+    };
+    let clauses = parser.parse_sequence(false)?;
+    Ok(IntentAst {
+        clauses,
+        modifiers: parser.modifiers,
+    })
+}
+
+/// Parst eine DID-URI (`did:erynoa:<namespace>:<hex-id>`) in ihre [`UniversalId`]
+///
+/// Anders als [`DID::parse`](crate::domain::DID::parse) wird der Hex-Teil als
+/// ID interpretiert – passend zu [`DID::to_uri`](crate::domain::DID::to_uri).
+pub fn parse_did_uri(uri: &str) -> Result<UniversalId, String> {
+    let rest = uri
+        .strip_prefix("did:erynoa:")
+        .ok_or_else(|| format!("expected did:erynoa:<namespace>:<hex>, got `{uri}`"))?;
+    let (namespace, id) = rest
+        .split_once(':')
+        .ok_or_else(|| format!("missing namespace in `{uri}`"))?;
+    DIDNamespace::from_str(namespace)
+        .map_err(|_| format!("unknown DID namespace `{namespace}`"))?;
+    let id = id.split('#').next().unwrap_or(id);
+    UniversalId::from_hex(id).map_err(|_| format!("expected 64 hex characters, got `{id}`"))
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    modifiers: Vec<Modifier>,
+}
+
+impl Parser<'_> {
+    // ------------------------------------------------------------------------
+    // Token-Hilfen
+    // ------------------------------------------------------------------------
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.peek_at(0)
+    }
+
+    fn word_at(&self, offset: usize) -> Option<String> {
+        match self.peek_at(offset) {
+            Some(Token {
+                tok: Tok::Word(w), ..
+            }) => Some(w.to_lowercase()),
+            _ => None,
+        }
+    }
+
+    fn is_word_at(&self, offset: usize, words: &[&str]) -> bool {
+        self.word_at(offset)
+            .is_some_and(|w| words.contains(&w.as_str()))
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some(Token { tok: Tok::Punct(p), .. }) if *p == punct)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    /// Span des aktuellen Tokens bzw. Textende
+    fn here(&self) -> Span {
+        self.peek()
+            .map(|t| t.span)
+            .unwrap_or_else(|| Span::new(self.text.len(), self.text.len()))
+    }
+
+    fn prev_span(&self) -> Span {
+        self.pos
+            .checked_sub(1)
+            .and_then(|i| self.tokens.get(i))
+            .map(|t| t.span)
+            .unwrap_or_else(|| Span::new(0, 0))
+    }
+
+    fn expect_word(&mut self, word: &str, suggestion: &str) -> GrammarResult<Span> {
+        if self.is_word_at(0, &[word]) {
+            return Ok(self
+                .advance()
+                .map(|t| t.span)
+                .unwrap_or_else(|| self.here()));
+        }
+        Err(self
+            .unexpected(&format!("expected `{word}`"))
+            .suggest(suggestion))
+    }
+
+    fn take_word(&mut self, what: &str, suggestion: &str) -> GrammarResult<(String, Span)> {
+        match self.peek() {
+            Some(Token {
+                tok: Tok::Word(w),
+                span,
+            }) => {
+                let result = (w.clone(), *span);
+                self.pos += 1;
+                Ok(result)
+            }
+            _ => Err(self
+                .unexpected(&format!("expected {what}"))
+                .suggest(suggestion)),
+        }
+    }
+
+    fn unexpected(&self, message: &str) -> SyntaxError {
+        match self.peek() {
+            Some(token) => SyntaxError::new(
+                format!(
+                    "{message}, found `{}`",
+                    &self.text[token.span.start..token.span.end]
+                ),
+                token.span,
+            ),
+            None => SyntaxError::new(format!("{message}, found end of input"), self.here()),
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Struktur
+    // ------------------------------------------------------------------------
+
+    fn at_clause_start(&self) -> bool {
+        self.is_punct("(")
+            || self.word_at(0).is_some_and(|w| {
+                [
+                    TRANSFER_VERBS,
+                    ATTEST_VERBS,
+                    DELEGATE_VERBS,
+                    QUERY_VERBS,
+                    CREATE_VERBS,
+                ]
+                .iter()
+                .any(|verbs| verbs.contains(&w.as_str()))
+            })
+    }
+
+    fn at_connector(&self) -> bool {
+        self.is_punct(",") || self.is_punct(";") || self.is_word_at(0, &["and", "then"])
+    }
+
+    fn at_modifier(&self) -> bool {
+        match self.word_at(0).as_deref() {
+            Some("if") | Some("before") | Some("within") => true,
+            Some("max") => self.is_word_at(1, &["cost"]),
+            Some("in") => self.is_word_at(1, &["realm", "realms"]),
+            Some("human") | Some("humans") => self.is_word_at(1, &["only"]),
+            Some("only") => self.is_word_at(1, &["human", "humans"]),
+            _ => false,
+        }
+    }
+
+    /// Ende von Freitext (`text`)
+    fn at_text_end(&self) -> bool {
+        self.peek().is_none() || self.is_punct(")") || self.at_connector() || self.at_modifier()
+    }
+
+    fn parse_sequence(&mut self, nested: bool) -> GrammarResult<Vec<Clause>> {
+        let mut clauses = vec![self.parse_clause()?];
+
+        loop {
+            if self.peek().is_none() {
+                break;
+            }
+            if self.is_punct(")") {
+                if nested {
+                    break;
+                }
+                return Err(SyntaxError::new("unmatched `)`", self.here())
+                    .suggest("remove the `)` or open a group with `(`"));
+            }
+            if self.at_modifier() {
+                self.parse_modifier()?;
+                continue;
+            }
+            if self.at_connector() {
+                self.advance();
+                // "and then"
+                if self.is_word_at(0, &["then"]) {
+                    self.advance();
+                }
+                if self.at_modifier() {
+                    continue;
+                }
+                if self.peek().is_none() || self.is_punct(")") {
+                    return Err(SyntaxError::new(
+                        "expected an action after connector",
+                        self.here(),
+                    )
+                    .suggest("remove the trailing connector"));
+                }
+                clauses.push(self.parse_clause()?);
+                continue;
+            }
+            if self.at_clause_start() {
+                clauses.push(self.parse_clause()?);
+                continue;
+            }
+            return Err(self
+                .unexpected("expected `and`, `then` or a constraint")
+                .suggest("quote free text (e.g. `query \"...\"`) or add a constraint such as `if trust > 0.5`"));
+        }
+
+        Ok(clauses)
+    }
+
+    fn parse_clause(&mut self) -> GrammarResult<Clause> {
+        if self.is_punct("(") {
+            let open = self
+                .advance()
+                .map(|t| t.span)
+                .unwrap_or_else(|| self.here());
+            let clauses = self.parse_sequence(true)?;
+            if !self.is_punct(")") {
+                return Err(SyntaxError::new("missing `)`", open.to(self.here()))
+                    .suggest("close the group with `)`"));
+            }
+            let close = self
+                .advance()
+                .map(|t| t.span)
+                .unwrap_or_else(|| self.here());
+            let description = self.text[open.end..close.start].trim().to_string();
+            return Ok(Clause {
+                kind: ClauseKind::Group {
+                    description,
+                    clauses,
+                },
+                span: open.to(close),
+            });
+        }
+
+        let Some(verb) = self.word_at(0) else {
+            return Err(self
+                .unexpected("expected an action")
+                .suggest("start with send, attest, delegate, query or create"));
+        };
+        let start = self.here();
+        let verb = verb.as_str();
+
+        let kind = if TRANSFER_VERBS.contains(&verb) {
+            self.advance();
+            self.parse_transfer()?
+        } else if ATTEST_VERBS.contains(&verb) {
+            self.advance();
+            self.parse_attest()?
+        } else if DELEGATE_VERBS.contains(&verb) {
+            self.advance();
+            self.parse_delegate()?
+        } else if QUERY_VERBS.contains(&verb) {
+            self.advance();
+            let predicate = self.parse_text(
+                "a query predicate",
+                "e.g. `find \"events where type = attest | count\"`",
+            )?;
+            ClauseKind::Query { predicate }
+        } else if CREATE_VERBS.contains(&verb) {
+            self.advance();
+            self.parse_create()?
+        } else {
+            let verbs = [
+                TRANSFER_VERBS,
+                ATTEST_VERBS,
+                DELEGATE_VERBS,
+                QUERY_VERBS,
+                CREATE_VERBS,
+            ]
+            .concat();
+            let error = SyntaxError::new(format!("unknown action `{verb}`"), start);
+            return Err(match closest(verb, &verbs) {
+                Some(candidate) => error.suggest(format!("did you mean `{candidate}`?")),
+                None => error.suggest("start with send, attest, delegate, query or create"),
+            });
+        };
+
+        Ok(Clause {
+            kind,
+            span: start.to(self.prev_span()),
+        })
+    }
+
+    // ------------------------------------------------------------------------
+    // Aktionen
+    // ------------------------------------------------------------------------
+
+    fn parse_transfer(&mut self) -> GrammarResult<ClauseKind> {
+        let (amount, amount_span) = self.parse_integer("an amount", "e.g. `send 25 ERY to …`")?;
+        if amount == 0 {
+            return Err(SyntaxError::new(
+                "transfer amount must be greater than zero",
+                amount_span,
+            ));
+        }
+
+        let asset_type = if self.is_word_at(0, &["to"]) {
+            None
+        } else {
+            let (asset, span) =
+                self.take_word("an asset type or `to`", "e.g. `send 25 ERY to …`")?;
+            if !asset
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(
+                    SyntaxError::new(format!("invalid asset type `{asset}`"), span)
+                        .suggest("asset types consist of letters, digits, `_` and `-`"),
+                );
+            }
+            Some(asset)
+        };
+
+        self.expect_word("to", "e.g. `send 25 ERY to did:erynoa:self:<hex>`")?;
+        let to = self.parse_recipient()?;
+        Ok(ClauseKind::Transfer {
+            amount,
+            asset_type,
+            to,
+        })
+    }
+
+    fn parse_attest(&mut self) -> GrammarResult<ClauseKind> {
+        let subject = self.parse_recipient()?;
+        if self.is_word_at(0, &["claim", "that", "as"]) {
+            self.advance();
+        }
+        let claim = self.parse_text("a claim", "e.g. `attest @bob claim \"email-verified\"`")?;
+        Ok(ClauseKind::Attest { subject, claim })
+    }
+
+    fn parse_delegate(&mut self) -> GrammarResult<ClauseKind> {
+        let mut capabilities = Vec::new();
+        loop {
+            let (capability, _) =
+                self.take_word("a capability", "e.g. `delegate read, write to @bob`")?;
+            capabilities.push(capability);
+            if self.is_punct(",") {
+                self.advance();
+                continue;
+            }
+            if self.is_word_at(0, &["to"]) {
+                break;
+            }
+            return Err(self
+                .unexpected("expected `,` or `to`")
+                .suggest("separate capabilities with `,` and name the delegate with `to …`"));
+        }
+        self.expect_word("to", "e.g. `delegate read to @bob`")?;
+        let to = self.parse_recipient()?;
+
+        let mut ttl_seconds = None;
+        let mut trust_factor = None;
+        loop {
+            if self.is_word_at(0, &["for"]) {
+                self.advance();
+                ttl_seconds = Some(self.parse_duration()?);
+            } else if self.is_word_at(0, &["with"]) && self.is_word_at(1, &["trust"]) {
+                self.advance();
+                self.advance();
+                trust_factor = Some(self.parse_unit_interval()?);
+            } else {
+                break;
+            }
+        }
+
+        Ok(ClauseKind::Delegate {
+            capabilities,
+            to,
+            ttl_seconds,
+            trust_factor,
+        })
+    }
+
+    fn parse_create(&mut self) -> GrammarResult<ClauseKind> {
+        let (entity_type, _) =
+            self.take_word("an entity type", "e.g. `create realm with name=\"guild\"`")?;
+        let mut params = Vec::new();
+
+        if self.is_word_at(0, &["with"]) {
+            self.advance();
+            loop {
+                let (key, _) = self.take_word("a parameter name", "e.g. `with name=\"guild\"`")?;
+                if !self.is_punct("=") {
+                    return Err(self
+                        .unexpected("expected `=`")
+                        .suggest(format!("e.g. `{key}=value`")));
+                }
+                self.advance();
+                let value = match self.advance() {
+                    Some(Token {
+                        tok: Tok::Str(s), ..
+                    }) => serde_json::Value::String(s),
+                    Some(Token {
+                        tok: Tok::Word(w), ..
+                    }) => literal(&w),
+                    _ => {
+                        self.pos = self.pos.saturating_sub(1);
+                        return Err(self.unexpected("expected a value"));
+                    }
+                };
+                params.push((key, value));
+
+                // `, key=value` setzt die Liste fort, sonst Connector
+                let continues = self.is_punct(",")
+                    && matches!(
+                        self.peek_at(1),
+                        Some(Token {
+                            tok: Tok::Word(_),
+                            ..
+                        })
+                    )
+                    && matches!(
+                        self.peek_at(2),
+                        Some(Token {
+                            tok: Tok::Punct("="),
+                            ..
+                        })
+                    );
+                if !continues {
+                    break;
+                }
+                self.advance();
+            }
+        }
+
+        Ok(ClauseKind::Create {
+            entity_type,
+            params,
+        })
+    }
+
+    fn parse_recipient(&mut self) -> GrammarResult<RecipientRef> {
+        let (word, span) = self.take_word(
+            "a recipient",
+            "use a DID (did:erynoa:self:<hex>) or an alias such as `@bob`",
+        )?;
+
+        if word.starts_with("did:") {
+            let id = parse_did_uri(&word).map_err(|e| {
+                SyntaxError::new(e, span).suggest("use did:erynoa:<namespace>:<64 hex characters>")
+            })?;
+            return Ok(RecipientRef {
+                recipient: Recipient::Id(id),
+                span,
+            });
+        }
+
+        let alias = word.strip_prefix('@').unwrap_or(&word);
+        let valid = !alias.is_empty()
+            && alias
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        if !valid {
+            return Err(
+                SyntaxError::new(format!("invalid recipient `{word}`"), span)
+                    .suggest("use a DID (did:erynoa:self:<hex>) or an alias such as `@bob`"),
+            );
+        }
+        Ok(RecipientRef {
+            recipient: Recipient::Alias(alias.to_string()),
+            span,
+        })
+    }
+
+    fn parse_text(&mut self, what: &str, suggestion: &str) -> GrammarResult<String> {
+        if let Some(Token {
+            tok: Tok::Str(s), ..
+        }) = self.peek()
+        {
+            let s = s.clone();
+            self.advance();
+            return Ok(s);
+        }
+
+        let start = self.pos;
+        while !self.at_text_end() {
+            match self.peek() {
+                Some(Token {
+                    tok: Tok::Word(_), ..
+                }) => {
+                    self.advance();
+                }
+                _ => break,
+            }
+        }
+        if self.pos == start {
+            return Err(self
+                .unexpected(&format!("expected {what}"))
+                .suggest(suggestion));
+        }
+        let span = self.tokens[start].span.to(self.prev_span());
+        Ok(self.text[span.start..span.end].to_string())
+    }
+
+    // ------------------------------------------------------------------------
+    // Bedingungen
+    // ------------------------------------------------------------------------
+
+    fn parse_modifier(&mut self) -> GrammarResult<()> {
+        let keyword = self.word_at(0).unwrap_or_default();
+        let modifier = match keyword.as_str() {
+            "if" => {
+                self.advance();
+                self.parse_trust_condition()?
+            }
+            "max" => {
+                self.advance();
+                self.advance();
+                self.parse_max_cost()?
+            }
+            "before" | "within" => {
+                self.advance();
+                self.parse_deadline()?
+            }
+            "in" => {
+                self.advance();
+                self.advance();
+                self.parse_realms()?
+            }
+            _ => {
+                // "human only" / "only humans"
+                self.advance();
+                self.advance();
+                Modifier::HumanOnly
+            }
+        };
+        self.modifiers.push(modifier);
+        Ok(())
+    }
+
+    fn parse_trust_condition(&mut self) -> GrammarResult<Modifier> {
+        let (word, span) = self.take_word("`trust`", "e.g. `if trust.R > 0.6`")?;
+        let (head, dimension) = match word.split_once('.') {
+            Some((head, dim)) => (head, dim),
+            None => (word.as_str(), "R"),
+        };
+        if !head.eq_ignore_ascii_case("trust") {
+            return Err(
+                SyntaxError::new(format!("unknown condition `{word}`"), span)
+                    .suggest("only trust conditions are supported, e.g. `if trust.R > 0.6`"),
+            );
+        }
+        let dimension = if dimension.eq_ignore_ascii_case("omega") {
+            "Ω".to_string()
+        } else {
+            dimension.to_uppercase()
+        };
+        if !TRUST_DIMENSIONS.contains(&dimension.as_str()) {
+            return Err(
+                SyntaxError::new(format!("unknown trust dimension `{dimension}`"), span)
+                    .suggest("trust dimensions are R, I, C, P, V and Ω"),
+            );
+        }
+
+        match self.peek() {
+            Some(Token {
+                tok: Tok::Punct(">") | Tok::Punct(">="),
+                ..
+            }) => {
+                self.advance();
+            }
+            Some(Token {
+                tok: Tok::Punct("<") | Tok::Punct("<="),
+                span,
+            }) => {
+                return Err(
+                    SyntaxError::new("only lower trust bounds are supported", *span)
+                        .suggest("use `>` or `>=`"),
+                );
+            }
+            _ => {
+                return Err(self
+                    .unexpected("expected `>` or `>=`")
+                    .suggest("e.g. `if trust.R > 0.6`"))
+            }
+        }
+
+        let value = self.parse_unit_interval()?;
+        Ok(Modifier::MinTrust { dimension, value })
+    }
+
+    fn parse_max_cost(&mut self) -> GrammarResult<Modifier> {
+        let mut gas = None;
+        let mut mana = None;
+
+        loop {
+            let (value, _) = self.parse_integer("a cost", "e.g. `max cost 500 gas`")?;
+            let (unit, span) =
+                self.take_word("`gas` or `mana`", "e.g. `max cost 500 gas 20 mana`")?;
+            let slot = match unit.to_lowercase().as_str() {
+                "gas" => &mut gas,
+                "mana" => &mut mana,
+                other => {
+                    let error = SyntaxError::new(format!("unknown cost unit `{other}`"), span);
+                    return Err(match closest(other, &["gas", "mana"]) {
+                        Some(c) => error.suggest(format!("did you mean `{c}`?")),
+                        None => error.suggest("costs are given in `gas` or `mana`"),
+                    });
+                }
+            };
+            if slot.is_some() {
+                return Err(SyntaxError::new(
+                    format!("duplicate cost unit `{unit}`"),
+                    span,
+                ));
+            }
+            *slot = Some(value);
+
+            let more = matches!(self.peek(), Some(Token { tok: Tok::Word(w), .. }) if w.chars().all(|c| c.is_ascii_digit() || c == '_'));
+            if !more {
+                break;
+            }
+        }
+
+        let (gas, mana) = (gas.unwrap_or(0), mana.unwrap_or(0));
+        if gas == 0 && mana == 0 {
+            return Err(SyntaxError::new(
+                "max cost must be greater than zero",
+                self.prev_span(),
+            ));
+        }
+        Ok(Modifier::MaxCost { gas, mana })
+    }
+
+    fn parse_deadline(&mut self) -> GrammarResult<Modifier> {
+        let lamport = if self.is_word_at(0, &["lamport"]) {
+            self.advance();
+            Some(self.parse_integer("a Lamport time", "e.g. `before lamport 1200`")?)
+        } else if let Some(w) = self.word_at(0).filter(|w| w.starts_with('@')) {
+            let span = self.here();
+            self.advance();
+            let value = w[1..].replace('_', "").parse::<u64>().map_err(|_| {
+                SyntaxError::new(format!("invalid Lamport time `{w}`"), span)
+                    .suggest("e.g. `before @1200`")
+            })?;
+            Some((value, span))
+        } else {
+            None
+        };
+
+        if let Some((lamport, span)) = lamport {
+            let lamport = u32::try_from(lamport)
+                .map_err(|_| SyntaxError::new("Lamport time out of range", span))?;
+            return Ok(Modifier::Deadline { lamport });
+        }
+        Ok(Modifier::Timeout {
+            seconds: self.parse_duration()?,
+        })
+    }
+
+    fn parse_realms(&mut self) -> GrammarResult<Modifier> {
+        let mut realm_ids = Vec::new();
+        loop {
+            let (word, span) =
+                self.take_word("a realm", "use `root` or a 64-character realm id")?;
+            let realm = if word.eq_ignore_ascii_case("root") {
+                ROOT_REALM_ID
+            } else {
+                let hex = word.strip_prefix("realm:").unwrap_or(&word);
+                UniversalId::from_hex(hex).map_err(|_| {
+                    SyntaxError::new(format!("unknown realm `{word}`"), span)
+                        .suggest("use `root` or a 64-character realm id")
+                })?
+            };
+            realm_ids.push(realm);
+
+            // `, <realm>` setzt die Liste fort, `, <aktion>` nicht
+            let continues = self.is_punct(",")
+                && matches!(self.peek_at(1), Some(Token { tok: Tok::Word(w), .. })
+                    if w.eq_ignore_ascii_case("root") || w.len() >= 64);
+            if !continues {
+                break;
+            }
+            self.advance();
+        }
+        Ok(Modifier::Realms { realm_ids })
+    }
+
+    // ------------------------------------------------------------------------
+    // Literale
+    // ------------------------------------------------------------------------
+
+    fn parse_integer(&mut self, what: &str, suggestion: &str) -> GrammarResult<(u64, Span)> {
+        let (word, span) = self.take_word(what, suggestion)?;
+        let digits = word.replace('_', "");
+        if digits.contains('.') && digits.parse::<f64>().is_ok() {
+            return Err(
+                SyntaxError::new(format!("`{word}` is not a whole number"), span)
+                    .suggest("amounts are given in whole base units"),
+            );
+        }
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(
+                SyntaxError::new(format!("expected {what}, found `{word}`"), span)
+                    .suggest(suggestion),
+            );
+        }
+        let value = digits
+            .parse::<u64>()
+            .map_err(|_| SyntaxError::new(format!("`{word}` is too large"), span))?;
+        Ok((value, span))
+    }
+
+    fn parse_unit_interval(&mut self) -> GrammarResult<f32> {
+        let (word, span) = self.take_word("a number between 0 and 1", "e.g. `0.6`")?;
+        let value = word.parse::<f32>().map_err(|_| {
+            SyntaxError::new(format!("`{word}` is not a number"), span).suggest("e.g. `0.6`")
+        })?;
+        if !(0.0..=1.0).contains(&value) {
+            return Err(
+                SyntaxError::new(format!("`{word}` is outside [0, 1]"), span)
+                    .suggest("trust values range from 0 to 1"),
+            );
+        }
+        Ok(value)
+    }
+
+    /// `3d`, `12h`, `30 minutes`, …
+    fn parse_duration(&mut self) -> GrammarResult<u64> {
+        const SUGGESTION: &str = "use e.g. `3d`, `12h` or `30m`";
+        let (word, span) = self.take_word("a duration", SUGGESTION)?;
+        let split = word
+            .find(|c: char| !c.is_ascii_digit() && c != '_')
+            .unwrap_or(word.len());
+        let (number, unit, span) = if split == word.len() {
+            // Einheit als eigenes Wort
+            let (unit, unit_span) = self.take_word("a time unit", SUGGESTION)?;
+            (word.clone(), unit.to_lowercase(), span.to(unit_span))
+        } else {
+            (
+                word[..split].to_string(),
+                word[split..].to_lowercase(),
+                span,
+            )
+        };
+
+        let value: u64 = number.replace('_', "").parse().map_err(|_| {
+            SyntaxError::new(format!("invalid duration `{word}`"), span).suggest(SUGGESTION)
+        })?;
+        let factor = match unit.as_str() {
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+            "d" | "day" | "days" => 86_400,
+            "w" | "week" | "weeks" => 604_800,
+            other => {
+                let error = SyntaxError::new(format!("unknown time unit `{other}`"), span);
+                return Err(
+                    match closest(other, &["seconds", "minutes", "hours", "days", "weeks"]) {
+                        Some(c) => error.suggest(format!("did you mean `{c}`?")),
+                        None => error.suggest(SUGGESTION),
+                    },
+                );
+            }
+        };
+        if value == 0 {
+            return Err(SyntaxError::new("duration must be greater than zero", span));
+        }
+        value
+            .checked_mul(factor)
+            .ok_or_else(|| SyntaxError::new("duration too large", span))
+    }
+}
+
+/// Wert eines `key=value`-Parameters
+fn literal(word: &str) -> serde_json::Value {
+    if let Ok(b) = word.parse::<bool>() {
+        return serde_json::Value::Bool(b);
+    }
+    if let Ok(i) = word.parse::<i64>() {
+        return serde_json::Value::from(i);
+    }
+    if let Ok(f) = word.parse::<f64>() {
+        return serde_json::Value::from(f);
+    }
+    serde_json::Value::String(word.to_string())
+}
+
+/// Ähnlichstes Schlüsselwort (Levenshtein ≤ 2)
+fn closest<'k>(word: &str, candidates: &[&'k str]) -> Option<&'k str> {
+    candidates
+        .iter()
+        .map(|c| (edit_distance(word, c), *c))
+        .filter(|(d, _)| *d <= 2)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = current;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOB: &str =
+        "did:erynoa:self:0001000100000000000000000000000000000000000000000000000000000b0b";
+
+    #[test]
+    fn test_parse_transfer_with_constraints() {
+        let text = format!("send 25 ERY to {BOB} before 3d if trust.R > 0.6");
+        let ast = parse(&text).unwrap();
+
+        assert_eq!(ast.clauses.len(), 1);
+        match &ast.clauses[0].kind {
+            ClauseKind::Transfer {
+                amount,
+                asset_type,
+                to,
+            } => {
+                assert_eq!(*amount, 25);
+                assert_eq!(asset_type.as_deref(), Some("ERY"));
+                assert_eq!(to.recipient, Recipient::Id(parse_did_uri(BOB).unwrap()));
+            }
+            other => panic!("expected transfer, got {other:?}"),
+        }
+        assert_eq!(ast.timeout_seconds(), Some(3 * 86_400));
+        assert!(ast.modifiers.contains(&Modifier::MinTrust {
+            dimension: "R".into(),
+            value: 0.6
+        }));
+    }
+
+    #[test]
+    fn test_parse_groups_and_modifiers() {
+        let ast = parse(
+            "(send 5 to @alice and send 3 USD to @carol) then attest @bob claim \"kyc\" \
+             in realm root, max cost 500 gas 20 mana and human only",
+        )
+        .unwrap();
+
+        assert_eq!(ast.clauses.len(), 2);
+        match &ast.clauses[0].kind {
+            ClauseKind::Group {
+                description,
+                clauses,
+            } => {
+                assert_eq!(clauses.len(), 2);
+                assert!(description.starts_with("send 5 to @alice"));
+            }
+            other => panic!("expected group, got {other:?}"),
+        }
+        assert_eq!(ast.context_realm(), Some(ROOT_REALM_ID));
+        assert!(ast
+            .modifiers
+            .contains(&Modifier::MaxCost { gas: 500, mana: 20 }));
+        assert!(ast.modifiers.contains(&Modifier::HumanOnly));
+    }
+
+    #[test]
+    fn test_parse_delegate_and_create() {
+        let ast =
+            parse("delegate read, write to @bob for 12h with trust 0.5; create realm with name=\"guild\", open=true")
+                .unwrap();
+        assert_eq!(
+            ast.clauses[0].kind,
+            ClauseKind::Delegate {
+                capabilities: vec!["read".into(), "write".into()],
+                to: RecipientRef {
+                    recipient: Recipient::Alias("bob".into()),
+                    span: Span::new(24, 28),
+                },
+                ttl_seconds: Some(12 * 3600),
+                trust_factor: Some(0.5),
+            }
+        );
+        match &ast.clauses[1].kind {
+            ClauseKind::Create { params, .. } => {
+                assert_eq!(params[0], ("name".into(), serde_json::json!("guild")));
+                assert_eq!(params[1], ("open".into(), serde_json::json!(true)));
+            }
+            other => panic!("expected create, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_errors_carry_span_and_suggestion() {
+        let err = parse("sned 5 ERY to @bob").unwrap_err();
+        assert_eq!(err.span, Span::new(0, 4));
+        assert_eq!(err.suggestion.as_deref(), Some("did you mean `send`?"));
+
+        let err = parse("send 2.5 ERY to @bob").unwrap_err();
+        assert_eq!(err.span, Span::new(5, 8));
+
+        let err = parse("send 5 ERY @bob").unwrap_err();
+        assert!(err.message.contains("expected `to`"));
+        assert_eq!(err.span, Span::new(11, 15));
+
+        let err = parse("send 5 ERY to @bob if trust.X > 0.2").unwrap_err();
+        assert!(err.message.contains("trust dimension"));
+
+        let err = parse("send 5 ERY to @bob before 3 fortnights").unwrap_err();
+        assert!(err.message.contains("time unit"));
+    }
+}
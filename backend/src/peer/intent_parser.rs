@@ -10,9 +10,18 @@
 //!
 //! Der Parser unterstützt:
 //! - Strukturierte Intents (JSON)
-//! - Natürlichsprachliche Intents (via Patterns)
-
-use crate::domain::{Constraint, Goal, Intent, RealmId, DID, ROOT_REALM_ID};
+//! - Natürlichsprachliche Intents (via [`intent_grammar`](super::intent_grammar))
+//!
+//! Aliase wie `@bob` werden über einen [`AliasResolver`] (z.B. den
+//! [`IdentityStore`]) im Kontext-Realm aufgelöst.
+
+use super::intent_grammar::{
+    self, Clause, ClauseKind, Modifier, Recipient, RecipientRef, Span, SyntaxError,
+};
+use super::query::Query;
+use crate::domain::{Constraint, Cost, Goal, Intent, RealmId, UniversalId, DID, ROOT_REALM_ID};
+use crate::local::IdentityStore;
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Fehler beim Intent-Parsing
@@ -29,6 +38,50 @@ pub enum ParseError {
 
     #[error("Invalid constraint: {0}")]
     InvalidConstraint(String),
+
+    #[error("Syntax error at {span}: {message}")]
+    Syntax {
+        message: String,
+        span: Span,
+        suggestion: Option<String>,
+    },
+
+    #[error("Unknown recipient '{alias}' at {span}")]
+    UnknownRecipient {
+        alias: String,
+        span: Span,
+        suggestion: Option<String>,
+    },
+}
+
+impl ParseError {
+    /// Position im Eingabetext (nur für natürlichsprachliche Intents)
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Syntax { span, .. } | Self::UnknownRecipient { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
+
+    /// Korrekturvorschlag
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::Syntax { suggestion, .. } | Self::UnknownRecipient { suggestion, .. } => {
+                suggestion.as_deref()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<SyntaxError> for ParseError {
+    fn from(e: SyntaxError) -> Self {
+        Self::Syntax {
+            message: e.message,
+            span: e.span,
+            suggestion: e.suggestion,
+        }
+    }
 }
 
 /// Ergebnis von Parse-Operationen
@@ -55,11 +108,26 @@ pub type ParseResult<T> = Result<T, ParseError>;
 ///    └───────────┘
 /// ```
 pub struct IntentParser {
-    /// Pattern-Matcher für natürlichsprachliche Intents
-    patterns: Vec<PatternMatcher>,
-
     /// Konfiguration
     config: IntentParserConfig,
+
+    /// Auflösung von Empfänger-Aliasen
+    resolver: Option<Arc<dyn AliasResolver>>,
+}
+
+/// Löst realm-lokale Aliase (`@bob`) in IDs auf
+pub trait AliasResolver: Send + Sync {
+    /// `None`, wenn der Alias im Realm unbekannt ist
+    fn resolve(&self, alias: &str, realm: &RealmId) -> Option<UniversalId>;
+}
+
+impl AliasResolver for IdentityStore {
+    fn resolve(&self, alias: &str, realm: &RealmId) -> Option<UniversalId> {
+        self.resolve_alias(alias, &realm.to_hex())
+            .ok()
+            .flatten()
+            .map(|identity| identity.did.id)
+    }
 }
 
 /// Konfiguration für IntentParser
@@ -73,6 +141,9 @@ pub struct IntentParserConfig {
 
     /// Maximale Constraints pro Intent
     pub max_constraints: usize,
+
+    /// Asset-Typ für Transfers ohne Angabe
+    pub default_asset: String,
 }
 
 impl Default for IntentParserConfig {
@@ -81,54 +152,24 @@ impl Default for IntentParserConfig {
             default_timeout_hours: 24,
             default_realm: ROOT_REALM_ID,
             max_constraints: 10,
+            default_asset: "ERY".to_string(),
         }
     }
 }
 
-/// Pattern für natürlichsprachliche Intents
-struct PatternMatcher {
-    /// Regex/Keywords
-    keywords: Vec<String>,
-    /// Ziel-Goal-Typ
-    goal_type: GoalType,
-}
-
-#[derive(Debug, Clone, Copy)]
-enum GoalType {
-    Transfer,
-    Attest,
-    Delegate,
-    Query,
-    Create,
-}
-
 impl IntentParser {
     /// Erstelle neuen IntentParser
     pub fn new(config: IntentParserConfig) -> Self {
-        let patterns = vec![
-            PatternMatcher {
-                keywords: vec!["send".into(), "transfer".into(), "pay".into()],
-                goal_type: GoalType::Transfer,
-            },
-            PatternMatcher {
-                keywords: vec!["attest".into(), "verify".into(), "certify".into()],
-                goal_type: GoalType::Attest,
-            },
-            PatternMatcher {
-                keywords: vec!["delegate".into(), "authorize".into(), "grant".into()],
-                goal_type: GoalType::Delegate,
-            },
-            PatternMatcher {
-                keywords: vec!["query".into(), "find".into(), "search".into()],
-                goal_type: GoalType::Query,
-            },
-            PatternMatcher {
-                keywords: vec!["create".into(), "new".into(), "mint".into()],
-                goal_type: GoalType::Create,
-            },
-        ];
+        Self {
+            config,
+            resolver: None,
+        }
+    }
 
-        Self { patterns, config }
+    /// Mit Alias-Auflösung (z.B. über den IdentityStore)
+    pub fn with_alias_resolver(mut self, resolver: Arc<dyn AliasResolver>) -> Self {
+        self.resolver = Some(resolver);
+        self
     }
 
     /// Erstelle mit Default-Config
@@ -142,6 +183,26 @@ impl IntentParser {
         source: DID,
         goal: Goal,
         constraints: Vec<Constraint>,
+    ) -> ParseResult<Intent> {
+        self.parse_structured_for(source.id, goal, constraints)
+    }
+
+    /// Wie [`parse_structured`](Self::parse_structured), mit bereits aufgelöster Quell-ID
+    pub fn parse_structured_for(
+        &self,
+        source: UniversalId,
+        goal: Goal,
+        constraints: Vec<Constraint>,
+    ) -> ParseResult<Intent> {
+        self.build_intent(source, goal, constraints, self.config.default_realm)
+    }
+
+    fn build_intent(
+        &self,
+        source: UniversalId,
+        goal: Goal,
+        constraints: Vec<Constraint>,
+        realm: RealmId,
     ) -> ParseResult<Intent> {
         // Validiere Constraints
         if constraints.len() > self.config.max_constraints {
@@ -152,12 +213,7 @@ impl IntentParser {
             )));
         }
 
-        let mut intent = Intent::new(
-            source.id.clone(),
-            goal,
-            self.config.default_realm.clone(),
-            0,
-        );
+        let mut intent = Intent::new(source, goal, realm, 0);
 
         for constraint in constraints {
             intent = intent.with_constraint(constraint);
@@ -209,54 +265,107 @@ impl IntentParser {
         self.parse_structured(source, goal, vec![])
     }
 
-    /// Parse natürlichsprachlichen Intent (vereinfacht)
+    /// Parse natürlichsprachlichen Intent
+    ///
+    /// Beispiel: `send 25 ERY to did:erynoa:self:… before 3d if trust.R > 0.6`.
+    /// Mehrere Aktionen ergeben ein `Goal::Complex`, Klammern verschachtelte
+    /// Teilziele.
     pub fn parse_natural(&self, source: DID, text: &str) -> ParseResult<Intent> {
-        let text_lower = text.to_lowercase();
-
-        // Finde passendes Pattern
-        let goal_type = self
-            .patterns
-            .iter()
-            .find(|p| p.keywords.iter().any(|k| text_lower.contains(k)))
-            .map(|p| p.goal_type);
-
-        match goal_type {
-            Some(GoalType::Transfer) => {
-                // Extrahiere Betrag und Empfänger (vereinfacht)
-                let goal = Goal::Complex {
-                    description: text.to_string(),
-                    sub_goals: vec![Goal::Transfer {
-                        to: DID::new_self(b"unknown").id,
-                        amount: 0,
-                        asset_type: "ERY".to_string(),
-                    }],
-                };
-                self.parse_structured(source, goal, vec![])
-            }
-            Some(GoalType::Query) => {
-                let goal = Goal::Query {
-                    predicate: text.to_string(),
-                };
-                self.parse_structured(source, goal, vec![])
-            }
-            Some(GoalType::Attest) => {
-                let goal = Goal::Complex {
-                    description: text.to_string(),
-                    sub_goals: vec![Goal::Attest {
-                        subject: DID::new_self(b"unknown").id,
-                        claim: text.to_string(),
-                    }],
-                };
-                self.parse_structured(source, goal, vec![])
-            }
-            _ => {
-                // Fallback: Complex Goal
-                let goal = Goal::Complex {
-                    description: text.to_string(),
-                    sub_goals: vec![],
-                };
-                self.parse_structured(source, goal, vec![])
-            }
+        self.parse_natural_for(source.id, text)
+    }
+
+    /// Wie [`parse_natural`](Self::parse_natural), mit bereits aufgelöster Quell-ID
+    pub fn parse_natural_for(&self, source: UniversalId, text: &str) -> ParseResult<Intent> {
+        let ast = intent_grammar::parse(text)?;
+        let realm = ast.context_realm().unwrap_or(self.config.default_realm);
+
+        let goal = match ast.clauses.as_slice() {
+            [clause] => self.lower_clause(clause, &realm)?,
+            clauses => Goal::Complex {
+                description: text.trim().to_string(),
+                sub_goals: clauses
+                    .iter()
+                    .map(|c| self.lower_clause(c, &realm))
+                    .collect::<ParseResult<_>>()?,
+            },
+        };
+
+        let constraints = ast.modifiers.iter().filter_map(lower_modifier).collect();
+        let mut intent = self.build_intent(source, goal, constraints, realm)?;
+        if let Some(timeout) = ast.timeout_seconds() {
+            intent = intent.with_timeout(timeout);
+        }
+
+        self.validate(&intent)?;
+        Ok(intent)
+    }
+
+    fn lower_clause(&self, clause: &Clause, realm: &RealmId) -> ParseResult<Goal> {
+        Ok(match &clause.kind {
+            ClauseKind::Transfer {
+                amount,
+                asset_type,
+                to,
+            } => Goal::Transfer {
+                to: self.resolve(to, realm)?,
+                amount: *amount,
+                asset_type: asset_type
+                    .clone()
+                    .unwrap_or_else(|| self.config.default_asset.clone()),
+            },
+            ClauseKind::Attest { subject, claim } => Goal::Attest {
+                subject: self.resolve(subject, realm)?,
+                claim: claim.clone(),
+            },
+            ClauseKind::Delegate {
+                capabilities,
+                to,
+                ttl_seconds,
+                trust_factor,
+            } => Goal::Delegate {
+                to: self.resolve(to, realm)?,
+                capabilities: capabilities.clone(),
+                trust_factor: trust_factor.unwrap_or(1.0),
+                ttl_seconds: ttl_seconds.unwrap_or(self.config.default_timeout_hours * 3600),
+            },
+            ClauseKind::Query { predicate } => Goal::Query {
+                predicate: predicate.clone(),
+            },
+            ClauseKind::Create {
+                entity_type,
+                params,
+            } => Goal::Create {
+                entity_type: entity_type.clone(),
+                params: params.iter().cloned().collect(),
+            },
+            ClauseKind::Group {
+                description,
+                clauses,
+            } => Goal::Complex {
+                description: description.clone(),
+                sub_goals: clauses
+                    .iter()
+                    .map(|c| self.lower_clause(c, realm))
+                    .collect::<ParseResult<_>>()?,
+            },
+        })
+    }
+
+    fn resolve(&self, recipient: &RecipientRef, realm: &RealmId) -> ParseResult<UniversalId> {
+        match &recipient.recipient {
+            Recipient::Id(id) => Ok(*id),
+            Recipient::Alias(alias) => self
+                .resolver
+                .as_ref()
+                .and_then(|r| r.resolve(alias, realm))
+                .ok_or_else(|| ParseError::UnknownRecipient {
+                    alias: alias.clone(),
+                    span: recipient.span,
+                    suggestion: Some(
+                        "use a full DID (did:erynoa:self:<hex>) or register the alias in the identity metadata"
+                            .to_string(),
+                    ),
+                }),
         }
     }
 
@@ -276,6 +385,9 @@ impl IntentParser {
                     return Err(ParseError::MissingField("capabilities".to_string()));
                 }
             }
+            Goal::Query { predicate } => {
+                Query::parse(predicate).map_err(|e| ParseError::InvalidFormat(e.to_string()))?;
+            }
             _ => {}
         }
 
@@ -311,6 +423,30 @@ impl IntentParser {
     }
 }
 
+/// Bedingung → Constraint (`Timeout` wird direkt auf den Intent angewendet)
+fn lower_modifier(modifier: &Modifier) -> Option<Constraint> {
+    match modifier {
+        Modifier::MinTrust { dimension, value } if dimension == "R" => {
+            Some(Constraint::MinTrust { value: *value })
+        }
+        Modifier::MinTrust { dimension, value } => Some(Constraint::Custom {
+            name: "min_trust".to_string(),
+            value: serde_json::json!({ "dimension": dimension, "value": value }),
+        }),
+        Modifier::MaxCost { gas, mana } => Some(Constraint::MaxCost {
+            cost: Cost::new(*gas, *mana, 0.0),
+            amount: None,
+            asset_type: None,
+        }),
+        Modifier::Deadline { lamport } => Some(Constraint::Deadline { lamport: *lamport }),
+        Modifier::Realms { realm_ids } => Some(Constraint::RealmRestriction {
+            realm_ids: realm_ids.clone(),
+        }),
+        Modifier::HumanOnly => Some(Constraint::HumanOnly),
+        Modifier::Timeout { .. } => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,15 +510,73 @@ mod tests {
     fn test_parse_natural_transfer() {
         let parser = IntentParser::default();
         let alice = DID::new(DIDNamespace::Self_, b"alice");
+        let bob = DID::new(DIDNamespace::Self_, b"bob");
 
-        let intent = parser.parse_natural(alice, "send 100 ERY to Bob").unwrap();
+        let text = format!("send 25 ERY to {} before 3d if trust.R > 0.6", bob.to_uri());
+        let intent = parser.parse_natural(alice.clone(), &text).unwrap();
 
+        assert_eq!(intent.source, alice.id);
+        assert_eq!(intent.timeout_seconds, 3 * 86_400);
         match intent.goal {
-            Goal::Complex { description, .. } => {
-                assert!(description.contains("send"));
+            Goal::Transfer {
+                to,
+                amount,
+                asset_type,
+            } => {
+                assert_eq!(to, bob.id);
+                assert_eq!(amount, 25);
+                assert_eq!(asset_type, "ERY");
+            }
+            _ => panic!("Expected Transfer goal"),
+        }
+        assert!(matches!(
+            intent.constraints.as_slice(),
+            [Constraint::MinTrust { value }] if (*value - 0.6).abs() < f32::EPSILON
+        ));
+    }
+
+    #[test]
+    fn test_parse_natural_resolves_aliases() {
+        let storage = crate::local::DecentralizedStorage::open_temporary().unwrap();
+        let bob = storage
+            .identities
+            .create_identity(DIDNamespace::Self_)
+            .unwrap();
+        storage
+            .identities
+            .set_metadata(&bob.did, "alias", "bob")
+            .unwrap();
+
+        let parser =
+            IntentParser::default().with_alias_resolver(Arc::new(storage.identities.clone()));
+        let alice = DID::new(DIDNamespace::Self_, b"alice");
+
+        let intent = parser
+            .parse_natural(
+                alice.clone(),
+                "send 5 to @bob and (attest @bob claim \"kyc\" then query members) human only",
+            )
+            .unwrap();
+        match &intent.goal {
+            Goal::Complex { sub_goals, .. } => {
+                assert!(
+                    matches!(sub_goals[0], Goal::Transfer { to, amount: 5, .. } if to == bob.did.id)
+                );
+                assert!(
+                    matches!(&sub_goals[1], Goal::Complex { sub_goals, .. } if sub_goals.len() == 2)
+                );
             }
             _ => panic!("Expected Complex goal"),
         }
+        assert!(matches!(
+            intent.constraints.as_slice(),
+            [Constraint::HumanOnly]
+        ));
+
+        let err = parser.parse_natural(alice, "send 5 to @carol").unwrap_err();
+        assert!(matches!(err, ParseError::UnknownRecipient { ref alias, .. } if alias == "carol"));
+        assert_eq!(err.span().map(|s| (s.start, s.end)), Some((10, 16)));
+        assert!(err.suggestion().is_some());
     }
 
     #[test]
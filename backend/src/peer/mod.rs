@@ -9,24 +9,41 @@
 //! │                         PEER LAYER                                 │
 //! ├─────────────────────────────────────────────────────────────────────┤
 //! │  intent_parser   - Parst Nutzer-Intents (Κ22)                      │
+//! │  intent_grammar  - Grammatik für natürlichsprachliche Intents      │
 //! │  saga_composer   - Komponiert Sagas aus Intents (Κ22)              │
+//! │  saga_executor   - Führt Sagas aus, kompensiert bei Fehlern (Κ24)  │
+//! │  saga_simulator  - Dry-Run: Ergebnis, Kosten, benötigter Trust     │
+//! │  query           - Lesende Query-Prädikate für Goal::Query         │
+//! │  governance      - Realm-Proposals mit Quadratic Voting (Κ21)      │
+//! │  ecl_service     - ECL-Handler pro Realm (API/UI/Controller)       │
 //! │  gateway         - Cross-Realm Gateway Guard (Κ23)                 │
 //! │  p2p             - libp2p Netzwerk-Schicht (Κ9, Κ10, Κ23)         │
 //! └─────────────────────────────────────────────────────────────────────┘
 //! ```
 
+pub mod ecl_service;
 pub mod gateway;
+pub mod governance;
+pub mod intent_grammar;
 pub mod intent_parser;
+pub mod query;
 pub mod saga_composer;
+pub mod saga_executor;
+pub mod saga_simulator;
 
 // P2P-Modul (feature-gated)
 #[cfg(feature = "p2p")]
 pub mod p2p;
 
 // Re-exports
+pub use ecl_service::{EclInvocation, EclOutcome, EclService, EclServiceConfig, EclSource, EclUpload};
 pub use gateway::GatewayGuard;
+pub use governance::{GovernanceConfig, GovernanceService, NewProposal};
 pub use intent_parser::IntentParser;
+pub use query::{Query, QueryEngine, QueryOutput};
 pub use saga_composer::SagaComposer;
+pub use saga_executor::{SagaExecutor, SagaExecutorConfig, SagaUpdate};
+pub use saga_simulator::{SagaSimulator, SimulationReport};
 
 // P2P Re-exports
 #[cfg(feature = "p2p")]
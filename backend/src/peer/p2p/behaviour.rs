@@ -79,8 +79,21 @@ pub struct ErynoaBehaviour {
 impl ErynoaBehaviour {
     /// Erstelle neues Behaviour
     ///
-    /// Initialisiert alle libp2p-Protokolle inkl. NAT-Traversal.
+    /// Initialisiert alle libp2p-Protokolle inkl. NAT-Traversal. Der
+    /// Relay-Client-Transport wird verworfen; für einen laufenden Swarm
+    /// [`Self::new_with_relay_transport`] verwenden.
     pub fn new(keypair: &Keypair, config: &P2PConfig) -> Result<Self> {
+        Self::new_with_relay_transport(keypair, config).map(|(behaviour, _)| behaviour)
+    }
+
+    /// Erstelle Behaviour samt zugehörigem Relay-Client-Transport
+    ///
+    /// Der Transport muss Teil des Swarm-Transports sein: Wird er gedroppt,
+    /// bricht `relay::client::Behaviour` beim nächsten Poll ab.
+    pub fn new_with_relay_transport(
+        keypair: &Keypair,
+        config: &P2PConfig,
+    ) -> Result<(Self, relay::client::Transport)> {
         let peer_id = PeerId::from(keypair.public());
 
         // Kademlia
@@ -126,13 +139,11 @@ impl ErynoaBehaviour {
 
         // Relay-Client
         let (relay_transport, relay_client) = relay::client::new(peer_id);
-        // Note: relay_transport wird im TransportBuilder verwendet
-        let _ = relay_transport;
 
         // UPnP
         let upnp = upnp::tokio::Behaviour::default();
 
-        Ok(Self {
+        let behaviour = Self {
             kademlia,
             gossipsub,
             request_response,
@@ -144,7 +155,8 @@ impl ErynoaBehaviour {
             dcutr,
             relay_client,
             upnp,
-        })
+        };
+        Ok((behaviour, relay_transport))
     }
 
     /// Erstelle Behaviour mit Relay-Server-Funktionalität
@@ -154,15 +166,7 @@ impl ErynoaBehaviour {
         keypair: &Keypair,
         config: &P2PConfig,
     ) -> Result<(Self, relay::client::Transport)> {
-        let peer_id = PeerId::from(keypair.public());
-
-        // Standard-Behaviour erstellen
-        let behaviour = Self::new(keypair, config)?;
-
-        // Relay-Transport für Relay-Server
-        let (relay_transport, _relay_behaviour) = relay::client::new(peer_id);
-
-        Ok((behaviour, relay_transport))
+        Self::new_with_relay_transport(keypair, config)
     }
 
     /// Baue AutoNAT-Behaviour
@@ -0,0 +1,115 @@
+//! # Injizierbare Zeitquelle
+//!
+//! Zeitabhängige P2P-Komponenten (`MixingPool`, `CoverTrafficGenerator`,
+//! `SyncTiming`) lesen die Zeit über [`Clock`] statt direkt über
+//! `Instant::now()`. Im Betrieb ist das [`SystemClock`]; der
+//! Netzwerk-Simulator setzt eine [`SimClock`] ein, die nur mit der
+//! virtuellen Simulationszeit voranschreitet.
+//!
+//! ```rust,ignore
+//! let clock = Arc::new(SimClock::new());
+//! let pool = MixingPool::new(config, tx).with_clock(clock.clone()).with_seed(7);
+//! clock.advance(Duration::from_millis(600));
+//! let released = pool.poll();
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Zeitquelle für zeitabhängige Komponenten
+pub trait Clock: Send + Sync {
+    /// Aktueller Zeitpunkt
+    fn now(&self) -> Instant;
+}
+
+/// Geteilte Zeitquelle
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Wall-Clock (`Instant::now()`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Standard-Zeitquelle für den Betrieb
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// Virtuelle Uhr: steht still, bis sie explizit vorgestellt wird
+#[derive(Debug)]
+pub struct SimClock {
+    base: Instant,
+    offset_us: AtomicU64,
+}
+
+impl SimClock {
+    /// Neue Uhr bei virtueller Zeit 0
+    pub fn new() -> Self {
+        Self {
+            base: Instant::now(),
+            offset_us: AtomicU64::new(0),
+        }
+    }
+
+    /// Virtuelle Zeit seit Start
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_micros(self.offset_us.load(Ordering::SeqCst))
+    }
+
+    /// Uhr um `by` vorstellen
+    pub fn advance(&self, by: Duration) {
+        self.offset_us
+            .fetch_add(by.as_micros() as u64, Ordering::SeqCst);
+    }
+
+    /// Uhr auf virtuelle Zeit `at` setzen (nie rückwärts)
+    pub fn set(&self, at: Duration) {
+        self.offset_us
+            .fetch_max(at.as_micros() as u64, Ordering::SeqCst);
+    }
+
+    /// Virtuelle Zeit eines von dieser Uhr gelieferten Zeitpunkts
+    pub fn since_start(&self, instant: Instant) -> Duration {
+        instant.saturating_duration_since(self.base)
+    }
+}
+
+impl Default for SimClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SimClock {
+    fn now(&self) -> Instant {
+        self.base + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sim_clock_only_moves_when_advanced() {
+        let clock = SimClock::new();
+        let start = clock.now();
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(clock.now() - start, Duration::from_millis(250));
+
+        // set() läuft nie rückwärts
+        clock.set(Duration::from_millis(100));
+        assert_eq!(clock.elapsed(), Duration::from_millis(250));
+        clock.set(Duration::from_secs(1));
+        assert_eq!(clock.since_start(clock.now()), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,1090 @@
+//! # Testnet-Cluster-Orchestrator
+//!
+//! Startet N [`TestnetSwarm`]-Nodes in-process auf Loopback, mit
+//! deterministischen Keypairs und konfigurierbaren Rollen, und fährt
+//! skriptbare Szenarien gegen sie.
+//!
+//! ## Szenarien
+//!
+//! - **Partition / Heal**: Peers gruppenweise blockieren und wieder verbinden
+//! - **Kill / Restart**: Node stoppen und mit gleicher Identität neu starten
+//! - **Latenz-Injektion**: [`NetworkConditions`] pro Node (Latenz, Loss, Bandbreite)
+//! - **Assertions**: Konvergenz der `UnifiedSnapshot`-Merkle-Roots,
+//!   Mesh-Peers, Routing-Table-Größe, verbundene Peers
+//!
+//! ## State-Konvergenz
+//!
+//! Jeder Node hält einen eigenen [`UnifiedState`]. Über das State-Topic
+//! verbreitete [`ClusterRecord`]s werden idempotent in eine geordnete Menge
+//! übernommen, deren Serialisierung als `StateComponent::Event` in den
+//! Merkle-Tracker geht. Alle Nodes mit gleicher Record-Menge haben damit
+//! denselben Root. Ein Anti-Entropy-Task republiziert periodisch alle
+//! bekannten Records, damit Nodes nach Heal/Restart aufholen.
+//!
+//! ## Beispiel
+//!
+//! ```rust,ignore
+//! let config = ClusterConfig::new(42).with_relays(2).with_clients(3);
+//! let mut cluster = TestnetCluster::launch(config).await?;
+//! let scenario = Scenario::from_json(include_str!("partition.json"))?;
+//! let report = cluster.run_scenario(&scenario).await;
+//! assert!(report.passed);
+//! cluster.shutdown().await;
+//! ```
+
+use crate::core::state::{MerkleHash, UnifiedState};
+use crate::domain::unified::component::StateComponent;
+use crate::peer::p2p::testnet::{
+    TestnetCommand, TestnetConfig, TestnetEvent, TestnetRole, TestnetStats, TestnetSwarm,
+};
+use crate::peer::p2p::timing::NetworkConditions;
+use anyhow::{anyhow, Result};
+use libp2p::{identity::Keypair, Multiaddr, PeerId};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::btree_map::{BTreeMap, Entry};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+/// Gossipsub-Topic für Cluster-State-Records
+pub const CLUSTER_STATE_TOPIC: &str = "/erynoa/testnet/state/v1";
+
+// ============================================================================
+// CONFIGURATION
+// ============================================================================
+
+/// Node-Spezifikation im Cluster
+#[derive(Debug, Clone)]
+pub struct ClusterNodeSpec {
+    /// Name (für Logs und Szenarien)
+    pub name: String,
+    /// Testnet-Konfiguration (Rolle, Privacy, Multi-Circuit)
+    pub config: TestnetConfig,
+}
+
+impl ClusterNodeSpec {
+    /// Relay-Node (`TestnetConfig::relay`)
+    pub fn relay(name: impl Into<String>, index: usize) -> Self {
+        Self::from_config(name, TestnetConfig::relay(index))
+    }
+
+    /// Client-Node (`TestnetConfig::client`)
+    pub fn client(name: impl Into<String>) -> Self {
+        Self::from_config(name, TestnetConfig::client())
+    }
+
+    /// NAT-Client (`TestnetConfig::nat_client`)
+    pub fn nat_client(name: impl Into<String>) -> Self {
+        Self::from_config(name, TestnetConfig::nat_client())
+    }
+
+    /// High-Privacy-Client (`TestnetConfig::high_privacy`)
+    pub fn high_privacy(name: impl Into<String>) -> Self {
+        Self::from_config(name, TestnetConfig::high_privacy())
+    }
+
+    /// Spezifikation aus beliebiger Konfiguration
+    ///
+    /// Staggered Start und mDNS werden abgeschaltet: der Orchestrator
+    /// verbindet die Nodes selbst, und mDNS würde Partitionen unterlaufen.
+    pub fn from_config(name: impl Into<String>, mut config: TestnetConfig) -> Self {
+        config.start_delay = Duration::ZERO;
+        config.p2p.enable_mdns = false;
+        config.verbose_nat_logging = false;
+        config.enable_metrics = false;
+        config
+            .auto_subscribe_topics
+            .push(CLUSTER_STATE_TOPIC.to_string());
+        Self {
+            name: name.into(),
+            config,
+        }
+    }
+}
+
+/// Cluster-Konfiguration
+#[derive(Debug, Clone)]
+pub struct ClusterConfig {
+    /// Seed für Keypairs und simulierten Packet-Loss
+    pub seed: u64,
+    /// Erster TCP-Port (0 = ephemere Ports)
+    pub base_port: u16,
+    /// Nodes in Startreihenfolge (Node 0 ist Genesis)
+    pub nodes: Vec<ClusterNodeSpec>,
+    /// Jeder Node wählt alle zuvor gestarteten Nodes an
+    pub full_mesh: bool,
+    /// Intervall für Anti-Entropy-Republish
+    pub anti_entropy_interval: Duration,
+    /// Poll-Intervall für Assertions
+    pub poll_interval: Duration,
+}
+
+impl ClusterConfig {
+    /// Leerer Cluster mit Seed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            base_port: 0,
+            nodes: Vec::new(),
+            full_mesh: true,
+            anti_entropy_interval: Duration::from_secs(2),
+            poll_interval: Duration::from_millis(200),
+        }
+    }
+
+    /// N Relay-Nodes anhängen
+    pub fn with_relays(mut self, count: usize) -> Self {
+        let offset = self.count_role(|r| r.is_relay());
+        for i in 0..count {
+            let index = offset + i;
+            self.nodes
+                .push(ClusterNodeSpec::relay(format!("relay{}", index), index));
+        }
+        self
+    }
+
+    /// N Client-Nodes anhängen
+    pub fn with_clients(mut self, count: usize) -> Self {
+        let offset = self.count_role(|r| *r == TestnetRole::Client);
+        for i in 0..count {
+            self.nodes
+                .push(ClusterNodeSpec::client(format!("client{}", offset + i)));
+        }
+        self
+    }
+
+    /// N NAT-Clients anhängen
+    pub fn with_nat_clients(mut self, count: usize) -> Self {
+        let offset = self.count_role(|r| r.is_behind_nat());
+        for i in 0..count {
+            self.nodes
+                .push(ClusterNodeSpec::nat_client(format!("nat{}", offset + i)));
+        }
+        self
+    }
+
+    /// N High-Privacy-Clients anhängen
+    pub fn with_high_privacy(mut self, count: usize) -> Self {
+        let offset = self
+            .nodes
+            .iter()
+            .filter(|n| n.name.starts_with("private"))
+            .count();
+        for i in 0..count {
+            self.nodes.push(ClusterNodeSpec::high_privacy(format!(
+                "private{}",
+                offset + i
+            )));
+        }
+        self
+    }
+
+    /// Einzelnen Node anhängen
+    pub fn with_node(mut self, spec: ClusterNodeSpec) -> Self {
+        self.nodes.push(spec);
+        self
+    }
+
+    /// Feste Ports ab `base_port` verwenden
+    pub fn with_base_port(mut self, port: u16) -> Self {
+        self.base_port = port;
+        self
+    }
+
+    fn count_role(&self, pred: impl Fn(&TestnetRole) -> bool) -> usize {
+        self.nodes.iter().filter(|n| pred(&n.config.role)).count()
+    }
+}
+
+/// Deterministisches Ed25519-Keypair aus Seed und Node-Index
+///
+/// Gleiche Eingaben ergeben dieselbe Peer-ID, sodass Bootstrap-Adressen
+/// für Child-Prozesse im Voraus berechnet werden können.
+pub fn deterministic_keypair(seed: u64, index: usize) -> Keypair {
+    let mut hasher = Sha256::new();
+    hasher.update(b"erynoa-testnet-cluster");
+    hasher.update(seed.to_be_bytes());
+    hasher.update((index as u64).to_be_bytes());
+    let mut secret: [u8; 32] = hasher.finalize().into();
+    Keypair::ed25519_from_bytes(&mut secret).expect("32-byte ed25519 secret")
+}
+
+// ============================================================================
+// REPLICATED CLUSTER STATE
+// ============================================================================
+
+/// Über Gossipsub verbreiteter State-Record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterRecord {
+    /// Erzeugender Node
+    pub origin: String,
+    /// Sequenznummer pro Origin
+    pub seq: u64,
+    /// Nutzdaten
+    pub payload: Vec<u8>,
+}
+
+impl ClusterRecord {
+    fn key(&self) -> String {
+        format!("{}:{:020}", self.origin, self.seq)
+    }
+}
+
+/// Replizierter State eines Cluster-Nodes
+pub struct ClusterNodeState {
+    unified: UnifiedState,
+    records: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl ClusterNodeState {
+    fn new() -> Self {
+        Self {
+            unified: UnifiedState::new(),
+            records: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Records übernehmen; Merkle-Tracker nur bei Änderung aktualisieren
+    fn apply(&self, records: &[ClusterRecord]) -> bool {
+        let Ok(mut known) = self.records.lock() else {
+            return false;
+        };
+        let mut changed = false;
+        for record in records {
+            if let Entry::Vacant(entry) = known.entry(record.key()) {
+                entry.insert(record.payload.clone());
+                changed = true;
+            }
+        }
+        if changed {
+            let encoded = bincode::serialize(&*known).unwrap_or_default();
+            self.unified
+                .update_with_merkle(StateComponent::Event, &encoded);
+        }
+        changed
+    }
+
+    fn all_records(&self) -> Vec<ClusterRecord> {
+        self.records
+            .lock()
+            .map(|known| {
+                known
+                    .iter()
+                    .filter_map(|(key, payload)| {
+                        let (origin, seq) = key.rsplit_once(':')?;
+                        Some(ClusterRecord {
+                            origin: origin.to_string(),
+                            seq: seq.parse().ok()?,
+                            payload: payload.clone(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Anzahl bekannter Records
+    pub fn record_count(&self) -> usize {
+        self.records.lock().map(|r| r.len()).unwrap_or(0)
+    }
+
+    /// Merkle-Root aus dem `UnifiedSnapshot`
+    pub fn merkle_root(&self) -> MerkleHash {
+        self.unified.snapshot().merkle_tracker.root_hash
+    }
+
+    /// Zugriff auf den vollständigen Unified State
+    pub fn unified(&self) -> &UnifiedState {
+        &self.unified
+    }
+}
+
+// ============================================================================
+// CLUSTER
+// ============================================================================
+
+/// Laufender Node im Cluster
+struct ClusterNode {
+    spec: ClusterNodeSpec,
+    index: usize,
+    peer_id: PeerId,
+    listen_addr: Option<Multiaddr>,
+    command_tx: Option<mpsc::Sender<TestnetCommand>>,
+    tasks: Vec<JoinHandle<()>>,
+    state: Arc<ClusterNodeState>,
+    next_seq: Arc<AtomicU64>,
+}
+
+impl ClusterNode {
+    fn is_alive(&self) -> bool {
+        self.command_tx.is_some()
+    }
+
+    fn dial_addr(&self) -> Option<Multiaddr> {
+        self.listen_addr
+            .clone()
+            .map(|a| a.with(libp2p::multiaddr::Protocol::P2p(self.peer_id)))
+    }
+}
+
+/// In-Process-Cluster aus [`TestnetSwarm`]s
+pub struct TestnetCluster {
+    config: ClusterConfig,
+    nodes: Vec<ClusterNode>,
+}
+
+impl TestnetCluster {
+    /// Alle Nodes nacheinander starten und verbinden
+    pub async fn launch(config: ClusterConfig) -> Result<Self> {
+        if config.nodes.is_empty() {
+            return Err(anyhow!("Cluster needs at least one node"));
+        }
+
+        let nodes = config
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, spec)| ClusterNode {
+                spec: spec.clone(),
+                index,
+                peer_id: PeerId::from(deterministic_keypair(config.seed, index).public()),
+                listen_addr: None,
+                command_tx: None,
+                tasks: Vec::new(),
+                state: Arc::new(ClusterNodeState::new()),
+                next_seq: Arc::new(AtomicU64::new(0)),
+            })
+            .collect();
+
+        let mut cluster = Self { config, nodes };
+        for index in 0..cluster.nodes.len() {
+            cluster.start_node(index).await?;
+        }
+
+        tracing::info!(
+            nodes = cluster.nodes.len(),
+            seed = cluster.config.seed,
+            "🧪 Testnet cluster launched"
+        );
+        Ok(cluster)
+    }
+
+    /// Anzahl Nodes (inkl. gestoppter)
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Ist der Cluster leer?
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Peer-ID eines Nodes
+    pub fn peer_id(&self, index: usize) -> Option<PeerId> {
+        self.nodes.get(index).map(|n| n.peer_id)
+    }
+
+    /// Node-Index anhand des Namens
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|n| n.spec.name == name)
+    }
+
+    /// Replizierter State eines Nodes
+    pub fn state(&self, index: usize) -> Option<Arc<ClusterNodeState>> {
+        self.nodes.get(index).map(|n| n.state.clone())
+    }
+
+    async fn start_node(&mut self, index: usize) -> Result<()> {
+        let seed = self.config.seed;
+        let base_port = self.config.base_port;
+        let anti_entropy_interval = self.config.anti_entropy_interval;
+
+        let peers: Vec<Multiaddr> = if self.config.full_mesh {
+            self.nodes
+                .iter()
+                .filter(|n| n.index != index && n.is_alive())
+                .filter_map(|n| n.dial_addr())
+                .collect()
+        } else {
+            self.nodes
+                .first()
+                .filter(|n| n.index != index && n.is_alive())
+                .and_then(|n| n.dial_addr())
+                .into_iter()
+                .collect()
+        };
+        let relays: Vec<String> = self
+            .nodes
+            .iter()
+            .filter(|n| n.spec.config.role.is_relay() && n.index != index && n.is_alive())
+            .filter_map(|n| n.dial_addr())
+            .map(|a| a.to_string())
+            .collect();
+
+        let node = &mut self.nodes[index];
+        let mut testnet_config = node.spec.config.clone();
+        let port = if base_port == 0 {
+            0
+        } else {
+            base_port + index as u16
+        };
+        testnet_config.p2p.listen_addresses = vec![format!("/ip4/127.0.0.1/tcp/{}", port)];
+        testnet_config.p2p.bootstrap_peers = peers.iter().map(|a| a.to_string()).collect();
+        if testnet_config.role.is_behind_nat() {
+            testnet_config.p2p.nat.relay_servers = relays;
+        }
+        testnet_config.rng_seed = Some(seed.wrapping_add(index as u64));
+
+        let keypair = deterministic_keypair(seed, index);
+        let (mut swarm, mut event_rx) = TestnetSwarm::new(keypair, testnet_config)?;
+        let command_tx = swarm.command_sender();
+
+        let name = node.spec.name.clone();
+        let swarm_task = tokio::spawn(async move {
+            if let Err(e) = swarm.run().await {
+                tracing::warn!(node = %name, error = %e, "Cluster node stopped with error");
+            }
+        });
+
+        // Ingest: State-Records aus Gossipsub übernehmen
+        let state = node.state.clone();
+        let ingest_task = tokio::spawn(async move {
+            loop {
+                match event_rx.recv().await {
+                    Ok(TestnetEvent::GossipMessage { topic, data, .. })
+                        if topic.as_str() == CLUSTER_STATE_TOPIC =>
+                    {
+                        if let Ok(records) = serde_json::from_slice::<Vec<ClusterRecord>>(&data) {
+                            state.apply(&records);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        // Anti-Entropy: bekannte Records periodisch republizieren
+        let state = node.state.clone();
+        let ae_tx = command_tx.clone();
+        let anti_entropy_task = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(anti_entropy_interval);
+            loop {
+                interval.tick().await;
+                let records = state.all_records();
+                if records.is_empty() {
+                    continue;
+                }
+                let Ok(data) = serde_json::to_vec(&records) else {
+                    continue;
+                };
+                let command = TestnetCommand::Publish {
+                    topic: CLUSTER_STATE_TOPIC.to_string(),
+                    data,
+                };
+                if ae_tx.send(command).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        // Listen-Adresse abwarten
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let listen_addr = loop {
+            let (tx, rx) = oneshot::channel();
+            command_tx
+                .send(TestnetCommand::GetListenAddresses { response: tx })
+                .await
+                .map_err(|_| anyhow!("Node {} stopped during startup", index))?;
+            if let Some(addr) = rx.await.ok().and_then(|a| a.into_iter().next()) {
+                break addr;
+            }
+            if Instant::now() > deadline {
+                return Err(anyhow!("Node {} did not start listening", index));
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        };
+
+        tracing::info!(
+            node = %node.spec.name,
+            role = node.spec.config.role.name(),
+            peer_id = %node.peer_id,
+            addr = %listen_addr,
+            "🧪 Cluster node started"
+        );
+
+        node.listen_addr = Some(listen_addr);
+        node.command_tx = Some(command_tx);
+        node.tasks = vec![swarm_task, ingest_task, anti_entropy_task];
+        Ok(())
+    }
+
+    async fn send(&self, index: usize, command: TestnetCommand) -> Result<()> {
+        let node = self
+            .nodes
+            .get(index)
+            .ok_or_else(|| anyhow!("Unknown node index {}", index))?;
+        let tx = node
+            .command_tx
+            .as_ref()
+            .ok_or_else(|| anyhow!("Node {} is not running", node.spec.name))?;
+        tx.send(command)
+            .await
+            .map_err(|_| anyhow!("Node {} is not running", node.spec.name))
+    }
+
+    /// Record von einem Node aus publizieren (lokal angewendet + gossiped)
+    pub async fn publish(&self, index: usize, payload: Vec<u8>) -> Result<()> {
+        let node = self
+            .nodes
+            .get(index)
+            .ok_or_else(|| anyhow!("Unknown node index {}", index))?;
+        let record = ClusterRecord {
+            origin: node.spec.name.clone(),
+            seq: node.next_seq.fetch_add(1, Ordering::Relaxed),
+            payload,
+        };
+        node.state.apply(std::slice::from_ref(&record));
+        let data = serde_json::to_vec(&vec![record])?;
+        self.send(
+            index,
+            TestnetCommand::Publish {
+                topic: CLUSTER_STATE_TOPIC.to_string(),
+                data,
+            },
+        )
+        .await
+    }
+
+    /// Cluster in Gruppen partitionieren (Nodes außerhalb aller Gruppen bleiben verbunden)
+    pub async fn partition(&self, groups: &[Vec<usize>]) -> Result<()> {
+        for (gi, group) in groups.iter().enumerate() {
+            for (gj, other) in groups.iter().enumerate() {
+                if gi == gj {
+                    continue;
+                }
+                for &a in group {
+                    for &b in other {
+                        let peer_id = self
+                            .peer_id(b)
+                            .ok_or_else(|| anyhow!("Unknown node index {}", b))?;
+                        if self.nodes[a].is_alive() {
+                            self.send(a, TestnetCommand::BlockPeer { peer_id }).await?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Alle Blockierungen aufheben und Nodes wieder verbinden
+    pub async fn heal(&self) -> Result<()> {
+        let alive: Vec<usize> = self
+            .nodes
+            .iter()
+            .filter(|n| n.is_alive())
+            .map(|n| n.index)
+            .collect();
+        for &a in &alive {
+            for &b in &alive {
+                if a != b {
+                    let peer_id = self.nodes[b].peer_id;
+                    self.send(a, TestnetCommand::UnblockPeer { peer_id })
+                        .await?;
+                }
+            }
+        }
+        for &a in &alive {
+            for &b in alive.iter().filter(|&&b| b < a) {
+                if let Some(addr) = self.nodes[b].dial_addr() {
+                    self.send(a, TestnetCommand::Dial { addr }).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Node stoppen (State bleibt für Restart erhalten)
+    pub async fn kill(&mut self, index: usize) -> Result<()> {
+        let node = self
+            .nodes
+            .get_mut(index)
+            .ok_or_else(|| anyhow!("Unknown node index {}", index))?;
+        if let Some(tx) = node.command_tx.take() {
+            let _ = tx.send(TestnetCommand::Shutdown).await;
+        }
+        for task in node.tasks.drain(..) {
+            task.abort();
+        }
+        tracing::info!(node = %node.spec.name, "🧪 Cluster node killed");
+        Ok(())
+    }
+
+    /// Gestoppten Node mit derselben Identität neu starten
+    pub async fn restart(&mut self, index: usize) -> Result<()> {
+        if self
+            .nodes
+            .get(index)
+            .ok_or_else(|| anyhow!("Unknown node index {}", index))?
+            .is_alive()
+        {
+            return Err(anyhow!("Node {} is still running", index));
+        }
+        self.start_node(index).await
+    }
+
+    /// Simulierte Link-Bedingungen für ausgehende Nachrichten eines Nodes
+    pub async fn set_conditions(
+        &self,
+        index: usize,
+        conditions: Option<NetworkConditions>,
+    ) -> Result<()> {
+        self.send(index, TestnetCommand::SetConditions { conditions })
+            .await
+    }
+
+    /// Live-Statistiken eines Nodes
+    pub async fn stats(&self, index: usize) -> Result<TestnetStats> {
+        let (tx, rx) = oneshot::channel();
+        self.send(index, TestnetCommand::GetStats { response: tx })
+            .await?;
+        rx.await
+            .map_err(|_| anyhow!("Node {} dropped stats request", index))
+    }
+
+    /// Merkle-Roots aller laufenden Nodes
+    pub fn merkle_roots(&self) -> Vec<(String, MerkleHash)> {
+        self.nodes
+            .iter()
+            .filter(|n| n.is_alive())
+            .map(|n| (n.spec.name.clone(), n.state.merkle_root()))
+            .collect()
+    }
+
+    /// Haben alle laufenden Nodes denselben (nicht-leeren) Merkle-Root?
+    pub fn is_converged(&self) -> bool {
+        let roots = self.merkle_roots();
+        match roots.first() {
+            Some((_, first)) => *first != [0u8; 32] && roots.iter().all(|(_, r)| r == first),
+            None => false,
+        }
+    }
+
+    /// Warten bis alle laufenden Nodes konvergiert sind
+    pub async fn wait_for_convergence(&self, timeout: Duration) -> Result<MerkleHash> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.is_converged() {
+                return Ok(self.merkle_roots()[0].1);
+            }
+            if Instant::now() > deadline {
+                let roots: Vec<String> = self
+                    .merkle_roots()
+                    .iter()
+                    .map(|(name, root)| format!("{}={}", name, hex::encode(&root[..4])))
+                    .collect();
+                return Err(anyhow!("Cluster did not converge: {}", roots.join(", ")));
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Warten bis ein Stats-Prädikat für einen Node erfüllt ist
+    pub async fn wait_for_stats(
+        &self,
+        index: usize,
+        timeout: Duration,
+        predicate: impl Fn(&TestnetStats) -> bool,
+    ) -> Result<TestnetStats> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let stats = self.stats(index).await?;
+            if predicate(&stats) {
+                return Ok(stats);
+            }
+            if Instant::now() > deadline {
+                return Err(anyhow!(
+                    "Node {} stats condition not met: peers={}, routing_table={}, mesh={:?}",
+                    index,
+                    stats.connected_peers,
+                    stats.routing_table_size,
+                    stats.mesh_peers
+                ));
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Szenario ausführen; bricht beim ersten fehlgeschlagenen Schritt ab
+    pub async fn run_scenario(&mut self, scenario: &Scenario) -> ScenarioReport {
+        let mut outcomes = Vec::with_capacity(scenario.steps.len());
+        let mut passed = true;
+
+        for (index, step) in scenario.steps.iter().enumerate() {
+            let started = Instant::now();
+            let result = self.run_step(step).await;
+            let outcome = StepOutcome {
+                index,
+                step: step.clone(),
+                passed: result.is_ok(),
+                elapsed_ms: started.elapsed().as_millis() as u64,
+                detail: result.err().map(|e| e.to_string()),
+            };
+            tracing::info!(
+                scenario = %scenario.name,
+                step = index,
+                passed = outcome.passed,
+                detail = ?outcome.detail,
+                "🧪 Scenario step"
+            );
+            passed = outcome.passed;
+            outcomes.push(outcome);
+            if !passed {
+                break;
+            }
+        }
+
+        ScenarioReport {
+            name: scenario.name.clone(),
+            passed,
+            steps: outcomes,
+        }
+    }
+
+    async fn run_step(&mut self, step: &ScenarioStep) -> Result<()> {
+        match step {
+            ScenarioStep::Wait { ms } => {
+                tokio::time::sleep(Duration::from_millis(*ms)).await;
+                Ok(())
+            }
+            ScenarioStep::Publish { node, payload } => {
+                self.publish(*node, payload.as_bytes().to_vec()).await
+            }
+            ScenarioStep::Partition { groups } => self.partition(groups).await,
+            ScenarioStep::Heal => self.heal().await,
+            ScenarioStep::Kill { node } => self.kill(*node).await,
+            ScenarioStep::Restart { node } => self.restart(*node).await,
+            ScenarioStep::InjectLatency {
+                node,
+                latency_ms,
+                packet_loss,
+                bandwidth_kbps,
+            } => {
+                let peers = self.stats(*node).await?.connected_peers as u32;
+                let conditions =
+                    NetworkConditions::new(*latency_ms, *packet_loss, *bandwidth_kbps, peers);
+                self.set_conditions(*node, Some(conditions)).await
+            }
+            ScenarioStep::ClearLatency { node } => self.set_conditions(*node, None).await,
+            ScenarioStep::AssertConverged { timeout_ms } => self
+                .wait_for_convergence(Duration::from_millis(*timeout_ms))
+                .await
+                .map(|_| ()),
+            ScenarioStep::AssertDiverged => {
+                if self.is_converged() {
+                    Err(anyhow!("Cluster is converged but divergence was expected"))
+                } else {
+                    Ok(())
+                }
+            }
+            ScenarioStep::AssertConnectedPeers {
+                node,
+                min,
+                timeout_ms,
+            } => self
+                .wait_for_stats(*node, Duration::from_millis(*timeout_ms), |s| {
+                    s.connected_peers >= *min
+                })
+                .await
+                .map(|_| ()),
+            ScenarioStep::AssertMeshPeers {
+                node,
+                topic,
+                min,
+                timeout_ms,
+            } => {
+                let topic = topic
+                    .clone()
+                    .unwrap_or_else(|| CLUSTER_STATE_TOPIC.to_string());
+                self.wait_for_stats(*node, Duration::from_millis(*timeout_ms), |s| {
+                    s.mesh_peers.get(&topic).copied().unwrap_or(0) >= *min
+                })
+                .await
+                .map(|_| ())
+            }
+            ScenarioStep::AssertRoutingTable {
+                node,
+                min,
+                timeout_ms,
+            } => self
+                .wait_for_stats(*node, Duration::from_millis(*timeout_ms), |s| {
+                    s.routing_table_size >= *min
+                })
+                .await
+                .map(|_| ()),
+        }
+    }
+
+    /// Alle Nodes stoppen
+    pub async fn shutdown(&mut self) {
+        for index in 0..self.nodes.len() {
+            let _ = self.kill(index).await;
+        }
+    }
+}
+
+// ============================================================================
+// SCENARIOS
+// ============================================================================
+
+/// Skriptbares Cluster-Szenario (JSON-serialisierbar)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    pub steps: Vec<ScenarioStep>,
+}
+
+impl Scenario {
+    /// Szenario aus JSON laden
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| anyhow!("Invalid scenario: {}", e))
+    }
+
+    /// Standard-Szenario: Publish → Partition → Divergenz → Heal → Konvergenz
+    pub fn partition_and_rejoin(node_count: usize) -> Self {
+        let half = node_count / 2;
+        Self {
+            name: "partition-and-rejoin".to_string(),
+            steps: vec![
+                ScenarioStep::AssertMeshPeers {
+                    node: 0,
+                    topic: None,
+                    min: 1,
+                    timeout_ms: 15_000,
+                },
+                ScenarioStep::Publish {
+                    node: 0,
+                    payload: "genesis".to_string(),
+                },
+                ScenarioStep::AssertConverged { timeout_ms: 15_000 },
+                ScenarioStep::Partition {
+                    groups: vec![(0..half).collect(), (half..node_count).collect()],
+                },
+                ScenarioStep::Publish {
+                    node: node_count - 1,
+                    payload: "minority-write".to_string(),
+                },
+                ScenarioStep::Wait { ms: 1_000 },
+                ScenarioStep::AssertDiverged,
+                ScenarioStep::Heal,
+                ScenarioStep::AssertConverged { timeout_ms: 30_000 },
+            ],
+        }
+    }
+}
+
+/// Einzelner Szenario-Schritt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ScenarioStep {
+    Wait {
+        ms: u64,
+    },
+    Publish {
+        node: usize,
+        payload: String,
+    },
+    Partition {
+        groups: Vec<Vec<usize>>,
+    },
+    Heal,
+    Kill {
+        node: usize,
+    },
+    Restart {
+        node: usize,
+    },
+    InjectLatency {
+        node: usize,
+        latency_ms: u32,
+        #[serde(default)]
+        packet_loss: f32,
+        #[serde(default = "default_bandwidth_kbps")]
+        bandwidth_kbps: u32,
+    },
+    ClearLatency {
+        node: usize,
+    },
+    AssertConverged {
+        timeout_ms: u64,
+    },
+    AssertDiverged,
+    AssertConnectedPeers {
+        node: usize,
+        min: usize,
+        timeout_ms: u64,
+    },
+    AssertMeshPeers {
+        node: usize,
+        #[serde(default)]
+        topic: Option<String>,
+        min: usize,
+        timeout_ms: u64,
+    },
+    AssertRoutingTable {
+        node: usize,
+        min: usize,
+        timeout_ms: u64,
+    },
+}
+
+fn default_bandwidth_kbps() -> u32 {
+    NetworkConditions::default().bandwidth_kbps
+}
+
+/// Ergebnis eines Szenario-Schritts
+#[derive(Debug, Clone, Serialize)]
+pub struct StepOutcome {
+    pub index: usize,
+    pub step: ScenarioStep,
+    pub passed: bool,
+    pub elapsed_ms: u64,
+    pub detail: Option<String>,
+}
+
+/// Ergebnis eines Szenarios
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub passed: bool,
+    pub steps: Vec<StepOutcome>,
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_keypair_is_stable() {
+        let a = PeerId::from(deterministic_keypair(7, 0).public());
+        let b = PeerId::from(deterministic_keypair(7, 0).public());
+        let c = PeerId::from(deterministic_keypair(7, 1).public());
+        let d = PeerId::from(deterministic_keypair(8, 0).public());
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+    }
+
+    #[test]
+    fn test_cluster_config_roles() {
+        let config = ClusterConfig::new(1)
+            .with_relays(2)
+            .with_clients(2)
+            .with_nat_clients(1)
+            .with_high_privacy(1);
+
+        let names: Vec<_> = config.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["relay0", "relay1", "client0", "client1", "nat0", "private0"]
+        );
+        assert!(matches!(
+            config.nodes[1].config.role,
+            TestnetRole::Relay { index: 1 }
+        ));
+        assert!(config
+            .nodes
+            .iter()
+            .all(|n| n.config.start_delay.is_zero() && !n.config.p2p.enable_mdns));
+    }
+
+    #[test]
+    fn test_node_state_converges_independent_of_order() {
+        let a = ClusterNodeState::new();
+        let b = ClusterNodeState::new();
+        let r1 = ClusterRecord {
+            origin: "n0".into(),
+            seq: 0,
+            payload: b"x".to_vec(),
+        };
+        let r2 = ClusterRecord {
+            origin: "n1".into(),
+            seq: 0,
+            payload: b"y".to_vec(),
+        };
+
+        a.apply(&[r1.clone()]);
+        a.apply(&[r2.clone()]);
+        b.apply(&[r2.clone(), r1.clone()]);
+        assert_eq!(a.merkle_root(), b.merkle_root());
+
+        // Duplikate ändern nichts
+        assert!(!a.apply(&[r1]));
+        assert_eq!(a.record_count(), 2);
+        assert_eq!(a.all_records().len(), 2);
+    }
+
+    #[test]
+    fn test_scenario_json_roundtrip() {
+        let json = r#"{
+            "name": "latency",
+            "steps": [
+                {"action": "inject_latency", "node": 1, "latency_ms": 200, "packet_loss": 0.1},
+                {"action": "publish", "node": 0, "payload": "hello"},
+                {"action": "assert_converged", "timeout_ms": 5000},
+                {"action": "heal"}
+            ]
+        }"#;
+        let scenario = Scenario::from_json(json).unwrap();
+        assert_eq!(scenario.steps.len(), 4);
+        assert!(matches!(
+            scenario.steps[0],
+            ScenarioStep::InjectLatency {
+                latency_ms: 200,
+                bandwidth_kbps: 10000,
+                ..
+            }
+        ));
+        assert!(Scenario::from_json("{}").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cluster_publish_converges() {
+        let config = ClusterConfig::new(42).with_relays(1).with_clients(2);
+        let mut cluster = TestnetCluster::launch(config).await.unwrap();
+
+        let stats = cluster
+            .wait_for_stats(0, Duration::from_secs(20), |s| {
+                s.mesh_peers.get(CLUSTER_STATE_TOPIC).copied().unwrap_or(0) >= 1
+            })
+            .await
+            .unwrap();
+        assert!(stats.connected_peers >= 1);
+
+        cluster.publish(1, b"hello".to_vec()).await.unwrap();
+        cluster
+            .wait_for_convergence(Duration::from_secs(20))
+            .await
+            .unwrap();
+
+        cluster.kill(2).await.unwrap();
+        cluster.publish(0, b"while-down".to_vec()).await.unwrap();
+        cluster.restart(2).await.unwrap();
+        cluster
+            .wait_for_convergence(Duration::from_secs(20))
+            .await
+            .unwrap();
+        assert_eq!(cluster.state(2).unwrap().record_count(), 2);
+
+        cluster.shutdown().await;
+    }
+}
@@ -61,12 +61,18 @@
 #[cfg(feature = "p2p")]
 pub mod behaviour;
 #[cfg(feature = "p2p")]
+pub mod clock;
+#[cfg(feature = "p2p")]
+pub mod cluster;
+#[cfg(feature = "p2p")]
 pub mod config;
 #[cfg(feature = "p2p")]
 pub mod identity;
 #[cfg(feature = "p2p")]
 pub mod protocol;
 #[cfg(feature = "p2p")]
+pub mod simulation;
+#[cfg(feature = "p2p")]
 pub mod swarm;
 #[cfg(feature = "p2p")]
 pub mod testnet;
@@ -100,15 +106,24 @@ pub mod censorship;
 #[cfg(feature = "p2p")]
 pub use behaviour::ErynoaBehaviour;
 #[cfg(feature = "p2p")]
+pub use clock::{Clock, SharedClock, SimClock, SystemClock};
+#[cfg(feature = "p2p")]
+pub use cluster::{ClusterConfig, ClusterNodeSpec, Scenario, ScenarioReport, TestnetCluster};
+#[cfg(feature = "p2p")]
 pub use config::P2PConfig;
 #[cfg(feature = "p2p")]
 pub use identity::PeerIdentity;
 #[cfg(feature = "p2p")]
-pub use protocol::{SyncProtocol, SyncRequest, SyncResponse};
+pub use protocol::{SyncProtocol, SyncRequest, SyncResponse, TracedSyncRequest};
+#[cfg(feature = "p2p")]
+pub use simulation::{LinkConfig, SimNetwork, SimNode, SimReport};
 #[cfg(feature = "p2p")]
 pub use swarm::{IncomingSyncRequest, SwarmCommand, SwarmEvent2, SwarmManager};
 #[cfg(feature = "p2p")]
-pub use testnet::{TestnetBehaviour, TestnetEvent, TestnetSwarm};
+pub use testnet::{
+    TestnetBehaviour, TestnetCommand, TestnetConfig, TestnetEvent, TestnetRole, TestnetStatus,
+    TestnetSwarm,
+};
 #[cfg(feature = "p2p")]
 pub use timing::{NetworkConditions, NetworkQuality, SyncTiming, TimingManager, TimingStatus};
 #[cfg(feature = "p2p")]
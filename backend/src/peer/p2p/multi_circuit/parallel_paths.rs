@@ -309,7 +309,8 @@ impl ConfluxManager {
         // Ensure wir haben genug Circuits
         self.ensure_circuits(sensitivity).await?;
 
-        let circuits = self.circuits.read();
+        // Snapshot statt Guard: der Lock darf nicht über die Sends gehalten werden
+        let circuits = self.circuits.read().clone();
 
         match sensitivity {
             SensitivityLevel::Low | SensitivityLevel::Medium => {
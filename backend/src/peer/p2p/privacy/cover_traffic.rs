@@ -46,9 +46,11 @@
 //! └─────────────────────────────────────────────────────────────────┘
 //! ```
 
+use crate::peer::p2p::clock::{system_clock, SharedClock};
 use libp2p::PeerId;
-use parking_lot::RwLock;
-use rand::Rng;
+use parking_lot::{Mutex, RwLock};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
@@ -583,22 +585,56 @@ pub struct CoverTrafficGenerator {
     cover_sent: AtomicU64,
     /// Start-Zeitpunkt
     started_at: Instant,
+    /// Zeitquelle (Wall-Clock oder Simulator)
+    clock: SharedClock,
+    /// Zufall für Poisson-Intervalle und Size-Classes
+    rng: Mutex<StdRng>,
 }
 
 impl CoverTrafficGenerator {
     /// Erstelle neuen Cover-Traffic Generator
     pub fn new(config: CoverTrafficConfig, output_tx: mpsc::Sender<CoverMessage>) -> Self {
+        let clock = system_clock();
         Self {
             config,
             output_tx,
             cover_sent: AtomicU64::new(0),
-            started_at: Instant::now(),
+            started_at: clock.now(),
+            clock,
+            rng: Mutex::new(StdRng::from_entropy()),
         }
     }
 
+    /// Zeitquelle setzen (Simulator: [`SimClock`](crate::peer::p2p::clock::SimClock))
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.started_at = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// Zufall deterministisch seeden (gleicher Seed → gleiche Intervalle)
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
     /// Generiere eine Dummy-Nachricht (RL10)
     fn generate_dummy(&self, route: Vec<PeerId>) -> CoverMessage {
-        CoverMessage::new_random(route)
+        let size_class = SIZE_CLASSES[self.rng.lock().gen_range(0..SIZE_CLASSES.len())];
+        let mut dummy = CoverMessage::new_with_size(route, size_class);
+        dummy.created_at = self.clock.now();
+        dummy
+    }
+
+    /// Nächste Dummy-Nachricht für `route` erzeugen und zählen
+    ///
+    /// `None`, wenn keine Route verfügbar ist.
+    pub fn tick(&self, route: Vec<PeerId>) -> Option<CoverMessage> {
+        if route.is_empty() {
+            return None;
+        }
+        self.cover_sent.fetch_add(1, Ordering::Relaxed);
+        Some(self.generate_dummy(route))
     }
 
     /// Exponentieller Delay für Poisson-Prozess
@@ -606,13 +642,13 @@ impl CoverTrafficGenerator {
     /// ```text
     /// delay = -1/λ × ln(U), U ~ Uniform(0, 1)
     /// ```
-    fn sample_poisson_delay(&self) -> Duration {
+    pub fn sample_poisson_delay(&self) -> Duration {
         let lambda = self.config.effective_rate();
         if lambda == 0.0 {
             return Duration::from_secs(3600);
         }
 
-        let u: f64 = rand::thread_rng().gen();
+        let u: f64 = self.rng.lock().gen();
         // Vermeiden von ln(0)
         let u_clamped = u.max(1e-10);
         let delay_secs = -1.0 / lambda * u_clamped.ln();
@@ -644,13 +680,10 @@ impl CoverTrafficGenerator {
             tokio::time::sleep(delay).await;
 
             // Generiere und sende Dummy
-            let route = route_generator();
-            if route.is_empty() {
+            let Some(dummy) = self.tick(route_generator()) else {
                 tracing::trace!("No route available for cover traffic");
                 continue;
-            }
-
-            let dummy = self.generate_dummy(route);
+            };
             let size = dummy.size_class;
 
             if self.output_tx.send(dummy).await.is_err() {
@@ -658,8 +691,6 @@ impl CoverTrafficGenerator {
                 break;
             }
 
-            self.cover_sent.fetch_add(1, Ordering::Relaxed);
-
             tracing::trace!(
                 size_class = size,
                 total_sent = self.cover_sent.load(Ordering::Relaxed),
@@ -670,7 +701,7 @@ impl CoverTrafficGenerator {
 
     /// Hole Statistiken
     pub fn stats(&self) -> CoverGeneratorStats {
-        let elapsed = self.started_at.elapsed();
+        let elapsed = self.clock.now().saturating_duration_since(self.started_at);
         let sent = self.cover_sent.load(Ordering::Relaxed);
 
         CoverGeneratorStats {
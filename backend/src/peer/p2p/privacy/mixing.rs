@@ -53,9 +53,11 @@
 //! ```
 
 use crate::core::state::{StateEvent, StateEventEmitter, NoOpEmitter};
+use crate::peer::p2p::clock::{system_clock, SharedClock};
 use libp2p::PeerId;
 use parking_lot::Mutex;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -93,6 +95,9 @@ pub const DEFAULT_TARGET_DELAY_MS: u64 = 100;
 /// Rate-Monitor Window (Sekunden)
 pub const RATE_MONITOR_WINDOW_SECS: u64 = 60;
 
+/// Intervall des periodischen Flush-Checks (ms)
+pub const FLUSH_CHECK_INTERVAL_MS: u64 = 50;
+
 // ============================================================================
 // CONFIGURATION
 // ============================================================================
@@ -208,6 +213,17 @@ struct PooledMessage {
     assigned_delay: Duration,
 }
 
+/// Vom Pool freigegebene Nachricht mit Sendezeitpunkt
+#[derive(Debug, Clone)]
+pub struct MixOutput {
+    /// Ziel (nächster Hop oder finales Ziel)
+    pub next_hop: PeerId,
+    /// Payload (verschlüsselt)
+    pub payload: Vec<u8>,
+    /// Frühester Sendezeitpunkt
+    pub release_at: Instant,
+}
+
 // ============================================================================
 // TRAFFIC RATE MONITOR (RL25)
 // ============================================================================
@@ -234,7 +250,11 @@ impl TrafficRateMonitor {
 
     /// Registriere neue Nachricht
     pub fn record(&mut self) {
-        let now = Instant::now();
+        self.record_at(Instant::now());
+    }
+
+    /// Registriere neue Nachricht zum Zeitpunkt `now`
+    pub fn record_at(&mut self, now: Instant) {
         self.message_timestamps.push_back(now);
 
         // Alte Timestamps entfernen
@@ -322,6 +342,10 @@ pub struct MixingPool {
     threshold_flush_count: AtomicU64,
     /// Statistik: Standard-Flushes
     standard_flush_count: AtomicU64,
+    /// Zeitquelle (Wall-Clock oder Simulator)
+    clock: SharedClock,
+    /// Zufall für Delays, Probabilistic Forwarding und Shuffle
+    rng: Mutex<StdRng>,
 
     // ========================================================================
     // StateEvent-Integration (v0.4.0)
@@ -350,6 +374,8 @@ impl MixingPool {
             prob_forward_count: AtomicU64::new(0),
             threshold_flush_count: AtomicU64::new(0),
             standard_flush_count: AtomicU64::new(0),
+            clock: system_clock(),
+            rng: Mutex::new(StdRng::from_entropy()),
             state_event_emitter: Arc::new(NoOpEmitter),
             total_delay_ms: AtomicU64::new(0),
             total_flushed_count: AtomicU64::new(0),
@@ -372,6 +398,18 @@ impl MixingPool {
         self.state_event_emitter = emitter;
     }
 
+    /// Zeitquelle setzen (Simulator: [`SimClock`](crate::peer::p2p::clock::SimClock))
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Zufall deterministisch seeden (gleicher Seed → gleiche Delays und Reihenfolge)
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
     /// LAMP: Berechne dynamisches k_opt (RL25)
     ///
     /// ```text
@@ -403,8 +441,7 @@ impl MixingPool {
     ///
     /// Nutzt Inverse-CDF-Methode: X = μ - b × sign(U - 0.5) × ln(1 - 2|U - 0.5|)
     fn sample_laplace_delay(&self) -> f64 {
-        let mut rng = rand::thread_rng();
-        let u: f64 = rng.gen();
+        let u: f64 = self.rng.lock().gen();
 
         // Laplace-Sampling via Inverse-CDF
         let sign = if u < 0.5 { -1.0 } else { 1.0 };
@@ -413,47 +450,63 @@ impl MixingPool {
         (sign * self.laplace_scale * abs_term).abs()
     }
 
-    /// Füge Nachricht zum Pool hinzu (RL8 + RL25 LAMP)
+    /// Füge Nachricht zum Pool hinzu und sende Freigegebenes (RL8 + RL25 LAMP)
     pub async fn add_message(&self, payload: Vec<u8>, next_hop: PeerId) {
-        let mut rng = rand::thread_rng();
+        let outputs = self.push(payload, next_hop);
+        self.dispatch(outputs);
+    }
+
+    /// Füge Nachricht zum Pool hinzu, ohne zu senden (RL8 + RL25 LAMP)
+    ///
+    /// Liefert die dadurch freigegebenen Nachrichten mit Sendezeitpunkt;
+    /// leer, solange die Nachricht im Pool wartet.
+    pub fn push(&self, payload: Vec<u8>, next_hop: PeerId) -> Vec<MixOutput> {
+        let now = self.clock.now();
 
         // LAMP: Rate-Monitor updaten
-        self.rate_monitor.lock().record();
+        self.rate_monitor.lock().record_at(now);
 
         // LAMP: Probabilistic Forwarding Check (RL25)
-        if self.config.lamp_threshold_enabled
-            && rng.gen::<f64>() < self.config.lamp_prob_forward_rate
-        {
-            // Sofortiges Forwarding (minimal delay)
-            let minimal_delay = Duration::from_millis(
-                rng.gen_range(5..=self.config.tau_min.as_millis() as u64 / 2),
-            );
+        let minimal_delay = {
+            let mut rng = self.rng.lock();
+            if self.config.lamp_threshold_enabled
+                && rng.gen::<f64>() < self.config.lamp_prob_forward_rate
+            {
+                let upper = (self.config.tau_min.as_millis() as u64 / 2).max(5);
+                Some(Duration::from_millis(rng.gen_range(5..=upper)))
+            } else {
+                None
+            }
+        };
 
+        if let Some(minimal_delay) = minimal_delay {
+            // Sofortiges Forwarding (minimal delay)
             self.prob_forward_count.fetch_add(1, Ordering::Relaxed);
 
-            let output_tx = self.output_tx.clone();
-            tokio::spawn(async move {
-                tokio::time::sleep(minimal_delay).await;
-                let _ = output_tx.send((next_hop, payload)).await;
-            });
-
             tracing::trace!(
                 delay_ms = minimal_delay.as_millis(),
                 "LAMP probabilistic forward"
             );
-            return;
+            return vec![MixOutput {
+                next_hop,
+                payload,
+                release_at: now + minimal_delay,
+            }];
         }
 
         // Standard-Pfad: Laplace-Noise + Uniform-Basis (RL8)
         let laplace_delay = self.sample_laplace_delay();
-        let uniform_delay =
-            rng.gen_range(self.config.tau_min.as_millis()..=self.config.tau_max.as_millis()) as f64;
+        let uniform_delay = self
+            .rng
+            .lock()
+            .gen_range(self.config.tau_min.as_millis()..=self.config.tau_max.as_millis())
+            as f64;
         let total_delay_ms = (laplace_delay + uniform_delay) as u64;
         let assigned_delay = Duration::from_millis(total_delay_ms);
 
         let message = PooledMessage {
             payload,
-            arrival_time: Instant::now(),
+            arrival_time: now,
             next_hop,
             assigned_delay,
         };
@@ -475,30 +528,73 @@ impl MixingPool {
             }
         };
 
-        if let Some(flush_type) = should_flush {
-            self.do_flush(flush_type).await;
+        match should_flush {
+            Some(flush_type) => self.do_flush(flush_type),
+            None => Vec::new(),
         }
     }
 
     /// Führe Flush durch
-    async fn do_flush(&self, flush_type: FlushType) {
+    fn do_flush(&self, flush_type: FlushType) -> Vec<MixOutput> {
         match flush_type {
             FlushType::Threshold(k_opt) => {
                 self.threshold_flush_count.fetch_add(1, Ordering::Relaxed);
-                self.trigger_threshold_flush(k_opt).await;
+                self.trigger_threshold_flush(k_opt)
             }
             FlushType::Standard => {
                 self.standard_flush_count.fetch_add(1, Ordering::Relaxed);
-                self.trigger_flush().await;
+                self.trigger_flush()
             }
         }
     }
 
+    /// Freigegebene Nachrichten zum Sendezeitpunkt in den Output-Channel legen
+    fn dispatch(&self, outputs: Vec<MixOutput>) {
+        if outputs.is_empty() {
+            return;
+        }
+
+        let output_tx = self.output_tx.clone();
+        let clock = self.clock.clone();
+        tokio::spawn(async move {
+            for out in outputs {
+                let remaining = out.release_at.saturating_duration_since(clock.now());
+                if !remaining.is_zero() {
+                    tokio::time::sleep(remaining).await;
+                }
+
+                let _ = output_tx.send((out.next_hop, out.payload)).await;
+            }
+        });
+    }
+
+    /// Sendezeitpunkte für einen Flush
+    ///
+    /// Nachrichten gehen in Shuffle-Reihenfolge nacheinander raus: jede
+    /// frühestens `arrival + delay × factor` und nie vor ihrer Vorgängerin.
+    fn schedule(&self, messages: Vec<PooledMessage>, delay_factor: f64) -> Vec<MixOutput> {
+        let mut release_at = self.clock.now();
+        messages
+            .into_iter()
+            .map(|msg| {
+                let delay = Duration::from_millis(
+                    (msg.assigned_delay.as_millis() as f64 * delay_factor) as u64,
+                );
+                release_at = release_at.max(msg.arrival_time + delay);
+                MixOutput {
+                    next_hop: msg.next_hop,
+                    payload: msg.payload,
+                    release_at,
+                }
+            })
+            .collect()
+    }
+
     /// LAMP: Threshold-Flush (RL25) - kompaktere Delays
     ///
     /// Bei Threshold-Flush werden die Delays um Faktor 1/√k skaliert,
     /// was niedrigere Latenz bei gleicher Anonymität ermöglicht.
-    async fn trigger_threshold_flush(&self, k_opt: usize) {
+    fn trigger_threshold_flush(&self, k_opt: usize) -> Vec<MixOutput> {
         let messages = {
             let mut buffer = self.buffer.lock();
 
@@ -507,19 +603,19 @@ impl MixingPool {
             let mut to_flush: Vec<_> = buffer.drain(..count).collect();
 
             // Zufällige Permutation (RL8)
-            Self::shuffle(&mut to_flush);
+            Self::shuffle(&mut *self.rng.lock(), &mut to_flush);
 
             to_flush
         };
 
         if messages.is_empty() {
-            return;
+            return Vec::new();
         }
 
         // LAMP: Kompaktere Delays (τ/√k statt τ)
         let delay_factor = 1.0 / (messages.len() as f64).sqrt();
 
-        // Statistiken VOR dem Spawn berechnen (v0.4.0)
+        // Statistiken (v0.4.0)
         let total_delay: u64 = messages.iter()
             .map(|m| m.assigned_delay.as_millis() as u64)
             .sum();
@@ -535,22 +631,7 @@ impl MixingPool {
         };
         let pool_size_after = self.buffer.lock().len();
 
-        let output_tx = self.output_tx.clone();
-        tokio::spawn(async move {
-            for msg in messages {
-                let elapsed = msg.arrival_time.elapsed();
-                // Skalierter Delay
-                let scaled_delay = Duration::from_millis(
-                    (msg.assigned_delay.as_millis() as f64 * delay_factor) as u64,
-                );
-
-                if let Some(remaining) = scaled_delay.checked_sub(elapsed) {
-                    tokio::time::sleep(remaining).await;
-                }
-
-                let _ = output_tx.send((msg.next_hop, msg.payload)).await;
-            }
-        });
+        let outputs = self.schedule(messages, delay_factor);
 
         // Globale Statistik aktualisieren
         self.total_delay_ms.fetch_add(total_delay, Ordering::Relaxed);
@@ -571,22 +652,24 @@ impl MixingPool {
             avg_delay_ms = avg_delay,
             "LAMP threshold flush"
         );
+
+        outputs
     }
 
     /// Standard-Flush
-    async fn trigger_flush(&self) {
+    fn trigger_flush(&self) -> Vec<MixOutput> {
         let messages = {
             let mut buffer = self.buffer.lock();
             let mut to_flush: Vec<_> = buffer.drain(..).collect();
 
             // Zufällige Permutation (RL8: output_order = random_permutation)
-            Self::shuffle(&mut to_flush);
+            Self::shuffle(&mut *self.rng.lock(), &mut to_flush);
 
             to_flush
         };
 
         if messages.is_empty() {
-            return;
+            return Vec::new();
         }
 
         // Statistiken für StateEvent
@@ -613,29 +696,17 @@ impl MixingPool {
             pool_size_after: pool_size_after as u64,
         });
 
-        let output_tx = self.output_tx.clone();
-        tokio::spawn(async move {
-            for msg in messages {
-                // Warte restlichen Delay
-                let elapsed = msg.arrival_time.elapsed();
-                if let Some(remaining) = msg.assigned_delay.checked_sub(elapsed) {
-                    tokio::time::sleep(remaining).await;
-                }
-
-                let _ = output_tx.send((msg.next_hop, msg.payload)).await;
-            }
-        });
-
         tracing::debug!(
             messages_flushed = flushed_count,
             avg_delay_ms = avg_delay,
             "Standard flush triggered"
         );
+
+        self.schedule(messages, 1.0)
     }
 
     /// Fisher-Yates Shuffle
-    fn shuffle<T>(slice: &mut [T]) {
-        let mut rng = rand::thread_rng();
+    fn shuffle<T>(rng: &mut impl Rng, slice: &mut [T]) {
         for i in (1..slice.len()).rev() {
             let j = rng.gen_range(0..=i);
             slice.swap(i, j);
@@ -651,7 +722,8 @@ impl MixingPool {
         if buffer.len() >= k_opt {
             // Älteste Nachricht prüfen
             if let Some(oldest) = buffer.front() {
-                return oldest.arrival_time.elapsed() > self.config.tau_max
+                let waited = self.clock.now().saturating_duration_since(oldest.arrival_time);
+                return waited > self.config.tau_max
                     || buffer.len() >= self.config.k_max;
             }
         }
@@ -659,21 +731,29 @@ impl MixingPool {
         false
     }
 
+    /// Periodischer Flush-Check, ohne zu senden
+    ///
+    /// Liefert die freigegebenen Nachrichten; leer, wenn kein Flush fällig ist.
+    pub fn poll(&self) -> Vec<MixOutput> {
+        if !self.should_flush() {
+            return Vec::new();
+        }
+
+        if self.config.lamp_threshold_enabled {
+            let k_opt = self.calculate_k_opt();
+            self.trigger_threshold_flush(k_opt)
+        } else {
+            self.trigger_flush()
+        }
+    }
+
     /// Periodischer Flush-Check (als Background-Task)
     pub async fn run_flush_loop(self: Arc<Self>) {
-        let mut interval = tokio::time::interval(Duration::from_millis(50));
+        let mut interval = tokio::time::interval(Duration::from_millis(FLUSH_CHECK_INTERVAL_MS));
 
         loop {
             interval.tick().await;
-
-            if self.should_flush() {
-                if self.config.lamp_threshold_enabled {
-                    let k_opt = self.calculate_k_opt();
-                    self.trigger_threshold_flush(k_opt).await;
-                } else {
-                    self.trigger_flush().await;
-                }
-            }
+            self.dispatch(self.poll());
         }
     }
 
@@ -817,7 +897,7 @@ mod tests {
         let mut data: Vec<i32> = (0..100).collect();
         let original = data.clone();
 
-        MixingPool::shuffle(&mut data);
+        MixingPool::shuffle(&mut rand::thread_rng(), &mut data);
 
         // Länge bleibt gleich
         assert_eq!(data.len(), original.len());
@@ -130,8 +130,8 @@ pub use relay_selection::{
 
 #[cfg(feature = "privacy")]
 pub use mixing::{
-    LampStats, MixingPool, MixingPoolConfig, TrafficRateMonitor, K_MAX, K_MIN, TAU_MAX_MS,
-    TAU_MIN_MS,
+    LampStats, MixOutput, MixingPool, MixingPoolConfig, TrafficRateMonitor, K_MAX, K_MIN,
+    TAU_MAX_MS, TAU_MIN_MS,
 };
 
 #[cfg(feature = "privacy")]
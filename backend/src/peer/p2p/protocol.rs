@@ -115,6 +115,26 @@ pub enum SyncRequest {
         #[serde(skip_serializing_if = "Option::is_none")]
         sender_id: Option<UniversalId>,
     },
+
+    /// Privacy-Layer: Onion-Paket an den nächsten Hop (RL2-RL4)
+    PrivacyPacket {
+        /// Onion-verschlüsseltes Paket (nur der Empfänger kann eine Schicht entfernen)
+        packet: Vec<u8>,
+    },
+}
+
+/// Sync-Request auf dem Draht, optional mit W3C Trace Context
+///
+/// Ohne `traceparent` ist die Serialisierung identisch zu einem nackten
+/// [`SyncRequest`]; Peers ohne Tracing bleiben damit kompatibel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TracedSyncRequest {
+    /// Eigentlicher Request
+    #[serde(flatten)]
+    pub request: SyncRequest,
+    /// W3C `traceparent` des anfragenden Spans
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traceparent: Option<String>,
 }
 
 /// Sync-Response
@@ -192,6 +212,9 @@ pub enum SyncResponse {
         responder_id: Option<UniversalId>,
     },
 
+    /// Privacy-Layer: Paket angenommen (ohne Aussage über Weiterleitung)
+    PacketAccepted,
+
     /// Fehler
     Error {
         /// Fehler-Code
@@ -494,6 +517,18 @@ impl SyncRequest {
     }
 }
 
+impl TracedSyncRequest {
+    /// Serialisiere Request samt Trace Context
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(|e| anyhow!("Serialization failed: {}", e))
+    }
+
+    /// Deserialisiere Request (mit oder ohne Trace Context)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bytes).map_err(|e| anyhow!("Deserialization failed: {}", e))
+    }
+}
+
 impl SyncResponse {
     /// Serialisiere Response
     pub fn to_bytes(&self) -> Result<Vec<u8>> {
@@ -646,6 +681,8 @@ pub mod error_codes {
     pub const NOT_A_MEMBER: u32 = 7;
     /// Proof abgelaufen
     pub const PROOF_EXPIRED: u32 = 8;
+    /// Request-Typ vom Peer nicht unterstützt
+    pub const NOT_SUPPORTED: u32 = 9;
 }
 
 #[cfg(test)]
@@ -691,6 +728,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_traced_request_is_wire_compatible() {
+        let request = SyncRequest::Ping {
+            timestamp: 42,
+            sender_id: None,
+        };
+
+        // Ohne Trace Context: identisch zum nackten Request
+        let untraced = TracedSyncRequest {
+            request: request.clone(),
+            traceparent: None,
+        };
+        assert_eq!(untraced.to_bytes().unwrap(), request.to_bytes().unwrap());
+        let decoded = TracedSyncRequest::from_bytes(&request.to_bytes().unwrap()).unwrap();
+        assert!(decoded.traceparent.is_none());
+
+        let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let traced = TracedSyncRequest {
+            request,
+            traceparent: Some(traceparent.to_string()),
+        };
+        let decoded = TracedSyncRequest::from_bytes(&traced.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded.traceparent.as_deref(), Some(traceparent));
+        assert!(matches!(
+            decoded.request,
+            SyncRequest::Ping { timestamp: 42, .. }
+        ));
+    }
+
     #[test]
     fn test_response_serialization() {
         let response = SyncResponse::Events {
@@ -0,0 +1,2211 @@
+//! # Deterministischer Netzwerk-Simulator
+//!
+//! Discrete-Event-Simulation des P2P-Stacks mit virtueller Zeit, geseedetem
+//! Zufall und konfigurierbaren Links (Latenz, Jitter, Loss, Bandbreite).
+//! Gleicher Seed + gleiche Konfiguration ergeben denselben Event-Trace, so
+//! dass Anonymitäts-, Propagations- und Finalitäts-Kennzahlen in CI als
+//! Regressionstest laufen können.
+//!
+//! ## Aufbau
+//!
+//! ```text
+//! ┌──────────────────────────────────────────────────────────────┐
+//! │  SimNetwork                                                  │
+//! │  • Event-Queue (virtuelle µs, FIFO bei Gleichstand)          │
+//! │  • StdRng (Seed) für Loss, Jitter und Node-Entscheidungen    │
+//! │  • LinkConfig pro gerichtetem Link (Default + Overrides)     │
+//! │  • SimTrace (JSONL + SHA-256-Digest)                         │
+//! ├──────────────────────────────────────────────────────────────┤
+//! │  SimNode-Modelle                                             │
+//! │  • GossipNode     - Mesh-Flooding mit Dedup (Gossipsub D)    │
+//! │  • MixRelay/Client - LAMP-Pool (τ, Laplace-Noise, k-Flush)   │
+//! │  • FinalityNode   - Proposal → Votes → 2/3-Quorum            │
+//! ├──────────────────────────────────────────────────────────────┤
+//! │  Echte Komponenten auf SimNetwork::clock (SimClock)          │
+//! │  • PoolRelay      - MixingPool::push/poll        (privacy)   │
+//! │  • CoverClient    - CoverTrafficGenerator::tick  (privacy)   │
+//! │  • SyncNode       - TimingManager (event_sync)               │
+//! └──────────────────────────────────────────────────────────────┘
+//! ```
+//!
+//! Die Modelle verwenden dieselben Parameter wie die Produktiv-Komponenten
+//! (`GossipsubConfig::mesh_n`, `MixingPoolConfig`, Cover-Traffic-Rate),
+//! laufen aber vollständig ohne Tokio-Timer und Sockets.
+//!
+//! ## Echte Komponenten
+//!
+//! `MixingPool`, `CoverTrafficGenerator` und `SyncTiming` lesen die Zeit über
+//! eine injizierbare [`Clock`](super::clock::Clock) und ziehen Zufall aus
+//! einem seedbaren RNG. [`SimNetwork::clock`] liefert eine
+//! [`SimClock`](super::clock::SimClock), die vor jedem Event auf dessen
+//! virtuellen Zeitpunkt springt. Die Knoten oben rufen den Produktiv-Code
+//! synchron auf (`MixingPool::push`/`poll`, `CoverTrafficGenerator::tick`,
+//! `SyncTiming::should_sync_at`) und machen aus den zurückgegebenen
+//! Sendezeitpunkten Simulator-Timer. Flush-Verhalten, Cover-Rate und
+//! Sync-Intervalle sind damit bei gleichem Seed exakt reproduzierbar
+//! (siehe `simulate_privacy`).
+//!
+//! ## In-Memory-Transport
+//!
+//! Für Integrationstests mit echten [`SwarmManager`](super::swarm::SwarmManager)-
+//! Instanzen liefert [`memory_transport`] einen libp2p-Transport über
+//! `/memory/<n>`-Adressen (Noise + Yamux wie TCP), der mit
+//! `SwarmManager::run_with_transport` genutzt wird (siehe
+//! `test_swarm_managers_over_memory_transport`). Dieser Test läuft als
+//! einziger auf Wall-Clock, weil libp2p-Timer (Gossipsub-Heartbeat, Yamux)
+//! nicht injizierbar sind; er prüft nur Konnektivität, keine Zeiten.
+//!
+//! ## Beispiel
+//!
+//! ```rust,ignore
+//! let report = simulate_gossip(&GossipScenario {
+//!     seed: 7,
+//!     nodes: 50,
+//!     link: LinkConfig::wan(),
+//!     ..Default::default()
+//! });
+//! assert!(report.metric("gossip.propagation_ms").unwrap().p95 < 500.0);
+//! ```
+
+use crate::peer::p2p::clock::{Clock, SimClock};
+use crate::peer::p2p::config::GossipsubConfig;
+#[cfg(feature = "privacy")]
+use crate::peer::p2p::privacy::{
+    mixing::FLUSH_CHECK_INTERVAL_MS, CoverTrafficConfig, CoverTrafficGenerator, MixOutput,
+    MixingPool, MixingPoolConfig,
+};
+use crate::peer::p2p::timing::{NetworkConditions, TimingManager};
+use anyhow::Result;
+use libp2p::core::muxing::StreamMuxerBox;
+use libp2p::core::transport::{Boxed, MemoryTransport};
+use libp2p::{identity::Keypair, PeerId, Transport};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Index eines simulierten Nodes
+pub type NodeId = usize;
+
+// ============================================================================
+// LINKS
+// ============================================================================
+
+/// Eigenschaften eines gerichteten Links
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LinkConfig {
+    /// Einweg-Latenz
+    pub latency: Duration,
+    /// Gleichverteilter Jitter (± auf die Latenz)
+    pub jitter: Duration,
+    /// Verlustrate (0.0 - 1.0)
+    pub loss_rate: f64,
+    /// Bandbreite in bit/s (0 = unbegrenzt)
+    pub bandwidth_bps: u64,
+}
+
+impl Default for LinkConfig {
+    fn default() -> Self {
+        Self::lan()
+    }
+}
+
+impl LinkConfig {
+    /// LAN: 1ms, verlustfrei, unbegrenzt
+    pub fn lan() -> Self {
+        Self {
+            latency: Duration::from_millis(1),
+            jitter: Duration::ZERO,
+            loss_rate: 0.0,
+            bandwidth_bps: 0,
+        }
+    }
+
+    /// WAN: 40ms ± 10ms, 1% Loss, 10 Mbit/s
+    pub fn wan() -> Self {
+        Self {
+            latency: Duration::from_millis(40),
+            jitter: Duration::from_millis(10),
+            loss_rate: 0.01,
+            bandwidth_bps: 10_000_000,
+        }
+    }
+
+    /// Link aus gemessenen [`NetworkConditions`] (RTT halbiert)
+    pub fn from_conditions(conditions: &NetworkConditions) -> Self {
+        Self {
+            latency: Duration::from_millis(conditions.latency_ms as u64 / 2),
+            jitter: Duration::ZERO,
+            loss_rate: conditions.packet_loss as f64,
+            bandwidth_bps: conditions.bandwidth_kbps as u64 * 1000,
+        }
+    }
+
+    /// Unterbrochener Link (Partition)
+    pub fn down() -> Self {
+        Self {
+            loss_rate: 1.0,
+            ..Self::lan()
+        }
+    }
+}
+
+// ============================================================================
+// MESSAGES & TRACE
+// ============================================================================
+
+/// Nutzlast einer simulierten Nachricht
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SimPayload {
+    /// Gossipsub-Message (Flooding über den Mesh)
+    Gossip { id: u64, published_at_us: u64 },
+    /// Onion-Paket; `route` enthält die restlichen Hops inkl. Ziel
+    Onion {
+        id: u64,
+        route: Vec<NodeId>,
+        sent_at_us: u64,
+    },
+    /// Cover-Traffic (wird vom Relay nach dem Mixing verworfen)
+    Cover,
+    /// Block-Proposal
+    Proposal { height: u64, proposed_at_us: u64 },
+    /// Vote für ein Proposal
+    Vote { height: u64, proposed_at_us: u64 },
+    /// Sync-Anfrage
+    SyncRequest { seq: u64, sent_at_us: u64 },
+    /// Antwort auf eine Sync-Anfrage
+    SyncResponse { seq: u64, sent_at_us: u64 },
+    /// Beliebige Nutzlast für eigene Modelle
+    Custom(Vec<u8>),
+}
+
+/// Simulierte Nachricht
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimMessage {
+    /// Nutzlast
+    pub payload: SimPayload,
+    /// Größe auf dem Draht (für Bandbreiten-Modell)
+    pub size: usize,
+}
+
+impl SimMessage {
+    /// Nachricht mit Größe
+    pub fn new(payload: SimPayload, size: usize) -> Self {
+        Self { payload, size }
+    }
+}
+
+/// Eintrag im Event-Trace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// Virtuelle Zeit in µs
+    pub at_us: u64,
+    /// Ereignis
+    pub event: TraceEvent,
+}
+
+/// Trace-Ereignisse
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TraceEvent {
+    /// Nachricht zugestellt
+    Delivered {
+        from: NodeId,
+        to: NodeId,
+        message: SimMessage,
+    },
+    /// Nachricht auf dem Link verloren
+    Dropped { from: NodeId, to: NodeId },
+    /// Timer eines Nodes ausgelöst
+    Timer { node: NodeId, token: u64 },
+    /// Von einem Modell aufgezeichnete Metrik
+    Metric {
+        node: NodeId,
+        name: String,
+        value: f64,
+    },
+}
+
+/// Reproduzierbarer Event-Trace
+#[derive(Debug, Clone, Default)]
+pub struct SimTrace {
+    entries: Vec<TraceEntry>,
+}
+
+impl SimTrace {
+    /// Alle Einträge in Ausführungsreihenfolge
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// Anzahl Einträge
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Leer?
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Trace als JSON-Lines (ein Eintrag pro Zeile)
+    pub fn to_jsonl(&self) -> String {
+        self.entries
+            .iter()
+            .filter_map(|e| serde_json::to_string(e).ok())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// SHA-256 über den JSONL-Trace (hex) – stabil für gleichen Seed
+    pub fn digest(&self) -> String {
+        hex::encode(Sha256::digest(self.to_jsonl().as_bytes()))
+    }
+}
+
+// ============================================================================
+// METRICS
+// ============================================================================
+
+/// Zusammenfassung einer Metrik
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub count: usize,
+    pub min: f64,
+    pub mean: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub max: f64,
+}
+
+impl MetricSummary {
+    /// Aus Rohwerten (Nearest-Rank-Perzentile)
+    pub fn from_values(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let rank = |p: f64| {
+            let idx = ((p * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+            sorted[idx - 1]
+        };
+        Some(Self {
+            count: sorted.len(),
+            min: sorted[0],
+            mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+            p50: rank(0.5),
+            p95: rank(0.95),
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+/// Ergebnis eines Simulationslaufs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimReport {
+    /// Seed des Laufs
+    pub seed: u64,
+    /// Virtuelle Endzeit in ms
+    pub elapsed_ms: u64,
+    /// Zugestellte Nachrichten
+    pub delivered: u64,
+    /// Verlorene Nachrichten
+    pub dropped: u64,
+    /// Gesendete Bytes
+    pub bytes_sent: u64,
+    /// Metriken nach Name
+    pub metrics: BTreeMap<String, MetricSummary>,
+    /// SHA-256 des Event-Traces
+    pub trace_digest: String,
+}
+
+impl SimReport {
+    /// Metrik-Zusammenfassung nach Name
+    pub fn metric(&self, name: &str) -> Option<&MetricSummary> {
+        self.metrics.get(name)
+    }
+}
+
+// ============================================================================
+// NODE TRAIT
+// ============================================================================
+
+/// Verhalten eines simulierten Nodes
+///
+/// Alle Callbacks laufen synchron in virtueller Zeit; Effekte (Senden,
+/// Timer, Metriken) gehen ausschließlich über den [`SimContext`].
+pub trait SimNode: Send {
+    /// Simulation startet
+    fn on_start(&mut self, _ctx: &mut SimContext<'_>) {}
+
+    /// Nachricht empfangen
+    fn on_message(&mut self, ctx: &mut SimContext<'_>, from: NodeId, message: SimMessage);
+
+    /// Timer abgelaufen
+    fn on_timer(&mut self, _ctx: &mut SimContext<'_>, _token: u64) {}
+}
+
+enum Action {
+    Send { to: NodeId, message: SimMessage },
+    Timer { after: Duration, token: u64 },
+    Metric { name: String, value: f64 },
+}
+
+/// Zugriff eines Nodes auf Zeit, Zufall und Netzwerk
+pub struct SimContext<'a> {
+    node: NodeId,
+    node_count: usize,
+    now_us: u64,
+    clock: &'a SimClock,
+    rng: &'a mut StdRng,
+    actions: Vec<Action>,
+}
+
+impl<'a> SimContext<'a> {
+    /// Eigene Node-ID
+    pub fn id(&self) -> NodeId {
+        self.node
+    }
+
+    /// Anzahl Nodes im Netz
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Virtuelle Zeit
+    pub fn now(&self) -> Duration {
+        Duration::from_micros(self.now_us)
+    }
+
+    /// Virtuelle Zeit in µs
+    pub fn now_us(&self) -> u64 {
+        self.now_us
+    }
+
+    /// Virtuelle Zeit als `Instant` der [`SimNetwork::clock`]
+    pub fn instant(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Geseedeter Zufall (einzige erlaubte Zufallsquelle)
+    pub fn rng(&mut self) -> &mut StdRng {
+        self.rng
+    }
+
+    /// Nachricht senden (Link-Modell bestimmt Ankunft oder Verlust)
+    pub fn send(&mut self, to: NodeId, message: SimMessage) {
+        self.actions.push(Action::Send { to, message });
+    }
+
+    /// Timer setzen
+    pub fn set_timer(&mut self, after: Duration, token: u64) {
+        self.actions.push(Action::Timer { after, token });
+    }
+
+    /// Metrik aufzeichnen
+    pub fn record(&mut self, name: impl Into<String>, value: f64) {
+        self.actions.push(Action::Metric {
+            name: name.into(),
+            value,
+        });
+    }
+}
+
+// ============================================================================
+// SIMULATION CORE
+// ============================================================================
+
+enum EventKind {
+    Deliver {
+        from: NodeId,
+        to: NodeId,
+        message: SimMessage,
+    },
+    Timer {
+        node: NodeId,
+        token: u64,
+    },
+}
+
+struct Scheduled {
+    at_us: u64,
+    seq: u64,
+    kind: EventKind,
+}
+
+impl PartialEq for Scheduled {
+    fn eq(&self, other: &Self) -> bool {
+        self.at_us == other.at_us && self.seq == other.seq
+    }
+}
+
+impl Eq for Scheduled {}
+
+impl PartialOrd for Scheduled {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scheduled {
+    // Min-Heap: früheste Zeit zuerst, bei Gleichstand Einfüge-Reihenfolge
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .at_us
+            .cmp(&self.at_us)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+/// Deterministisches Simulations-Netzwerk
+pub struct SimNetwork {
+    seed: u64,
+    rng: StdRng,
+    now_us: u64,
+    /// Uhr für echte Komponenten, folgt `now_us`
+    clock: Arc<SimClock>,
+    seq: u64,
+    queue: BinaryHeap<Scheduled>,
+    nodes: Vec<Box<dyn SimNode>>,
+    default_link: LinkConfig,
+    links: HashMap<(NodeId, NodeId), LinkConfig>,
+    /// Link belegt bis (Bandbreiten-Queueing)
+    busy_until: HashMap<(NodeId, NodeId), u64>,
+    trace: SimTrace,
+    metrics: BTreeMap<String, Vec<f64>>,
+    delivered: u64,
+    dropped: u64,
+    bytes_sent: u64,
+    started: bool,
+}
+
+impl SimNetwork {
+    /// Leeres Netz mit Seed und Default-Link
+    pub fn new(seed: u64, default_link: LinkConfig) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            now_us: 0,
+            clock: Arc::new(SimClock::new()),
+            seq: 0,
+            queue: BinaryHeap::new(),
+            nodes: Vec::new(),
+            default_link,
+            links: HashMap::new(),
+            busy_until: HashMap::new(),
+            trace: SimTrace::default(),
+            metrics: BTreeMap::new(),
+            delivered: 0,
+            dropped: 0,
+            bytes_sent: 0,
+            started: false,
+        }
+    }
+
+    /// Node hinzufügen
+    pub fn add_node(&mut self, node: Box<dyn SimNode>) -> NodeId {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    /// Anzahl Nodes
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Gerichteten Link überschreiben
+    pub fn set_link(&mut self, from: NodeId, to: NodeId, link: LinkConfig) {
+        self.links.insert((from, to), link);
+    }
+
+    /// Link in beide Richtungen überschreiben
+    pub fn set_link_bidirectional(&mut self, a: NodeId, b: NodeId, link: LinkConfig) {
+        self.set_link(a, b, link);
+        self.set_link(b, a, link);
+    }
+
+    /// Netz in Gruppen partitionieren (Links zwischen Gruppen fallen aus)
+    pub fn partition(&mut self, groups: &[Vec<NodeId>]) {
+        for (i, a) in groups.iter().enumerate() {
+            for b in groups.iter().skip(i + 1) {
+                for &x in a {
+                    for &y in b {
+                        self.set_link_bidirectional(x, y, LinkConfig::down());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Alle Link-Overrides entfernen
+    pub fn heal(&mut self) {
+        self.links.clear();
+    }
+
+    /// Virtuelle Zeit
+    pub fn now(&self) -> Duration {
+        Duration::from_micros(self.now_us)
+    }
+
+    /// Virtuelle Uhr für Komponenten mit injizierter [`Clock`]
+    ///
+    /// Steht während eines Events still und springt zwischen Events auf deren
+    /// Zeitpunkt.
+    pub fn clock(&self) -> Arc<SimClock> {
+        self.clock.clone()
+    }
+
+    /// Event-Trace
+    pub fn trace(&self) -> &SimTrace {
+        &self.trace
+    }
+
+    /// Rohwerte einer Metrik
+    pub fn metric_values(&self, name: &str) -> &[f64] {
+        self.metrics.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Simulation bis `until` (virtuell) oder leerer Queue laufen lassen
+    pub fn run_until(&mut self, until: Duration) {
+        let until_us = until.as_micros() as u64;
+        if !self.started {
+            self.started = true;
+            for node in 0..self.nodes.len() {
+                self.dispatch(node, |n, ctx| n.on_start(ctx));
+            }
+        }
+
+        while self.queue.peek().is_some_and(|e| e.at_us <= until_us) {
+            let Some(event) = self.queue.pop() else {
+                break;
+            };
+            self.now_us = event.at_us;
+            self.clock.set(self.now());
+            match event.kind {
+                EventKind::Deliver { from, to, message } => {
+                    self.delivered += 1;
+                    self.push_trace(TraceEvent::Delivered {
+                        from,
+                        to,
+                        message: message.clone(),
+                    });
+                    self.dispatch(to, |n, ctx| n.on_message(ctx, from, message));
+                }
+                EventKind::Timer { node, token } => {
+                    self.push_trace(TraceEvent::Timer { node, token });
+                    self.dispatch(node, |n, ctx| n.on_timer(ctx, token));
+                }
+            }
+        }
+        self.now_us = self.now_us.max(until_us);
+        self.clock.set(self.now());
+    }
+
+    /// Zusammenfassung des bisherigen Laufs
+    pub fn report(&self) -> SimReport {
+        SimReport {
+            seed: self.seed,
+            elapsed_ms: self.now_us / 1000,
+            delivered: self.delivered,
+            dropped: self.dropped,
+            bytes_sent: self.bytes_sent,
+            metrics: self
+                .metrics
+                .iter()
+                .filter_map(|(k, v)| MetricSummary::from_values(v).map(|s| (k.clone(), s)))
+                .collect(),
+            trace_digest: self.trace.digest(),
+        }
+    }
+
+    fn dispatch(&mut self, node: NodeId, f: impl FnOnce(&mut dyn SimNode, &mut SimContext<'_>)) {
+        let mut ctx = SimContext {
+            node,
+            node_count: self.nodes.len(),
+            now_us: self.now_us,
+            clock: &self.clock,
+            rng: &mut self.rng,
+            actions: Vec::new(),
+        };
+        f(self.nodes[node].as_mut(), &mut ctx);
+        let actions = ctx.actions;
+
+        for action in actions {
+            match action {
+                Action::Send { to, message } => self.transmit(node, to, message),
+                Action::Timer { after, token } => {
+                    let at_us = self.now_us + after.as_micros() as u64;
+                    self.schedule(at_us, EventKind::Timer { node, token });
+                }
+                Action::Metric { name, value } => {
+                    self.metrics.entry(name.clone()).or_default().push(value);
+                    self.push_trace(TraceEvent::Metric { node, name, value });
+                }
+            }
+        }
+    }
+
+    /// Link-Modell: Serialisierung (Bandbreite, FIFO pro Link) + Latenz ± Jitter
+    fn transmit(&mut self, from: NodeId, to: NodeId, message: SimMessage) {
+        if to >= self.nodes.len() {
+            return;
+        }
+        let link = self
+            .links
+            .get(&(from, to))
+            .copied()
+            .unwrap_or(self.default_link);
+        self.bytes_sent += message.size as u64;
+
+        if link.loss_rate > 0.0 && self.rng.gen::<f64>() < link.loss_rate {
+            self.dropped += 1;
+            self.push_trace(TraceEvent::Dropped { from, to });
+            return;
+        }
+
+        let mut depart_us = self.now_us;
+        if link.bandwidth_bps > 0 {
+            let tx_us = (message.size as u64 * 8 * 1_000_000).div_ceil(link.bandwidth_bps);
+            let busy = self.busy_until.entry((from, to)).or_insert(0);
+            depart_us = (*busy).max(self.now_us) + tx_us;
+            *busy = depart_us;
+        }
+
+        let latency_us = link.latency.as_micros() as i64;
+        let jitter_us = link.jitter.as_micros() as i64;
+        let jitter = if jitter_us > 0 {
+            self.rng.gen_range(-jitter_us..=jitter_us)
+        } else {
+            0
+        };
+        let at_us = depart_us + (latency_us + jitter).max(0) as u64;
+        self.schedule(at_us, EventKind::Deliver { from, to, message });
+    }
+
+    fn schedule(&mut self, at_us: u64, kind: EventKind) {
+        self.seq += 1;
+        self.queue.push(Scheduled {
+            at_us,
+            seq: self.seq,
+            kind,
+        });
+    }
+
+    fn push_trace(&mut self, event: TraceEvent) {
+        self.trace.entries.push(TraceEntry {
+            at_us: self.now_us,
+            event,
+        });
+    }
+}
+
+// ============================================================================
+// GOSSIP MODEL
+// ============================================================================
+
+/// Gossipsub-Modell: Flooding über einen festen Mesh mit Duplikat-Filter
+pub struct GossipNode {
+    mesh: Vec<NodeId>,
+    seen: BTreeSet<u64>,
+    /// Zu publizierende Messages (Zeitpunkt, ID)
+    publish: Vec<(Duration, u64)>,
+    message_size: usize,
+}
+
+impl GossipNode {
+    /// Node mit Mesh-Nachbarn
+    pub fn new(mesh: Vec<NodeId>, message_size: usize) -> Self {
+        Self {
+            mesh,
+            seen: BTreeSet::new(),
+            publish: Vec::new(),
+            message_size,
+        }
+    }
+
+    /// Message `id` zum Zeitpunkt `at` publizieren
+    pub fn publish_at(mut self, at: Duration, id: u64) -> Self {
+        self.publish.push((at, id));
+        self
+    }
+
+    fn forward(&mut self, ctx: &mut SimContext<'_>, from: Option<NodeId>, payload: SimPayload) {
+        for &peer in &self.mesh {
+            if Some(peer) != from {
+                ctx.send(peer, SimMessage::new(payload.clone(), self.message_size));
+            }
+        }
+    }
+}
+
+impl SimNode for GossipNode {
+    fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+        for (at, id) in &self.publish {
+            ctx.set_timer(*at, *id);
+        }
+    }
+
+    fn on_message(&mut self, ctx: &mut SimContext<'_>, from: NodeId, message: SimMessage) {
+        if let SimPayload::Gossip {
+            id,
+            published_at_us,
+        } = message.payload
+        {
+            if !self.seen.insert(id) {
+                ctx.record("gossip.duplicates", 1.0);
+                return;
+            }
+            let delay_ms = (ctx.now_us() - published_at_us) as f64 / 1000.0;
+            ctx.record("gossip.propagation_ms", delay_ms);
+            self.forward(ctx, Some(from), message.payload);
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut SimContext<'_>, token: u64) {
+        self.seen.insert(token);
+        let payload = SimPayload::Gossip {
+            id: token,
+            published_at_us: ctx.now_us(),
+        };
+        self.forward(ctx, None, payload);
+    }
+}
+
+/// Zufälliger, zusammenhängender Mesh mit Grad ≈ `degree`
+///
+/// Ring als Zusammenhangs-Garantie plus zufällige Kanten, bis jeder Node
+/// `degree` Nachbarn hat (soweit möglich). Deterministisch über `rng`.
+pub fn build_mesh(nodes: usize, degree: usize, rng: &mut StdRng) -> Vec<Vec<NodeId>> {
+    let mut mesh: Vec<BTreeSet<NodeId>> = vec![BTreeSet::new(); nodes];
+    if nodes < 2 {
+        return vec![Vec::new(); nodes];
+    }
+    for i in 0..nodes {
+        let j = (i + 1) % nodes;
+        if i != j {
+            mesh[i].insert(j);
+            mesh[j].insert(i);
+        }
+    }
+    let degree = degree.min(nodes - 1);
+    for i in 0..nodes {
+        let mut attempts = 0;
+        while mesh[i].len() < degree && attempts < nodes * 4 {
+            attempts += 1;
+            let j = rng.gen_range(0..nodes);
+            if j != i && mesh[j].len() < degree {
+                mesh[i].insert(j);
+                mesh[j].insert(i);
+            }
+        }
+    }
+    mesh.into_iter().map(|s| s.into_iter().collect()).collect()
+}
+
+/// Szenario: Gossip-Propagation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipScenario {
+    pub seed: u64,
+    pub nodes: usize,
+    /// Mesh-Grad (Default: `GossipsubConfig::mesh_n`)
+    pub mesh_n: usize,
+    pub link: LinkConfig,
+    /// Anzahl Messages (Publisher reihum)
+    pub messages: u64,
+    /// Abstand zwischen Publishes
+    pub interval: Duration,
+    pub message_size: usize,
+}
+
+impl Default for GossipScenario {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            nodes: 20,
+            mesh_n: GossipsubConfig::default().mesh_n,
+            link: LinkConfig::lan(),
+            messages: 10,
+            interval: Duration::from_millis(100),
+            message_size: 512,
+        }
+    }
+}
+
+/// Gossip-Propagation simulieren
+///
+/// Metriken: `gossip.propagation_ms` (pro Empfang), `gossip.duplicates`,
+/// `gossip.coverage` (Anteil erreichter Nodes je Message).
+pub fn simulate_gossip(scenario: &GossipScenario) -> SimReport {
+    let mut sim = SimNetwork::new(scenario.seed, scenario.link);
+    let mesh = build_mesh(scenario.nodes, scenario.mesh_n, &mut sim.rng);
+
+    for (i, peers) in mesh.into_iter().enumerate() {
+        let mut node = GossipNode::new(peers, scenario.message_size);
+        for id in 0..scenario.messages {
+            if scenario.nodes > 0 && (id as usize) % scenario.nodes == i {
+                node = node.publish_at(scenario.interval * id as u32, id);
+            }
+        }
+        sim.add_node(Box::new(node));
+    }
+
+    let horizon = scenario.interval * scenario.messages as u32 + Duration::from_secs(30);
+    sim.run_until(horizon);
+
+    // Coverage: Empfänger je Message (+ Publisher) / Nodes
+    let mut receivers: BTreeMap<u64, BTreeSet<NodeId>> = BTreeMap::new();
+    for entry in sim.trace.entries() {
+        if let TraceEvent::Delivered {
+            to,
+            message:
+                SimMessage {
+                    payload: SimPayload::Gossip { id, .. },
+                    ..
+                },
+            ..
+        } = &entry.event
+        {
+            receivers.entry(*id).or_default().insert(*to);
+        }
+    }
+    for id in 0..scenario.messages {
+        let publisher = (id as usize) % scenario.nodes.max(1);
+        let reached = receivers
+            .get(&id)
+            .map(|r| r.iter().filter(|&&n| n != publisher).count() + 1)
+            .unwrap_or(1);
+        sim.metrics
+            .entry("gossip.coverage".into())
+            .or_default()
+            .push(reached as f64 / scenario.nodes.max(1) as f64);
+    }
+
+    sim.report()
+}
+
+// ============================================================================
+// MIXING MODEL (LAMP, RL8 + RL25)
+// ============================================================================
+
+/// Parameter des Mixing-Modells (Spiegel von `MixingPoolConfig`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MixModelConfig {
+    /// Minimale Verzögerung τ_min
+    pub tau_min: Duration,
+    /// Maximale Verzögerung τ_max
+    pub tau_max: Duration,
+    /// Threshold-Flush ab k Messages im Pool
+    pub k_threshold: usize,
+    /// ε für Laplace-Noise
+    pub epsilon: f64,
+    /// Sensitivität Δf für Laplace-Noise (ms)
+    pub sensitivity: f64,
+    /// LAMP: Threshold-Flush aktiv
+    pub lamp_threshold_enabled: bool,
+    /// LAMP: Probabilistic-Forwarding-Rate
+    pub lamp_prob_forward_rate: f64,
+}
+
+impl Default for MixModelConfig {
+    // Werte wie `privacy::mixing` (K_MIN, TAU_MIN_MS, TAU_MAX_MS, ...)
+    fn default() -> Self {
+        Self {
+            tau_min: Duration::from_millis(50),
+            tau_max: Duration::from_millis(500),
+            k_threshold: 3,
+            epsilon: 0.1,
+            sensitivity: 100.0,
+            lamp_threshold_enabled: true,
+            lamp_prob_forward_rate: 0.3,
+        }
+    }
+}
+
+#[cfg(feature = "privacy")]
+impl From<&crate::peer::p2p::privacy::MixingPoolConfig> for MixModelConfig {
+    fn from(config: &crate::peer::p2p::privacy::MixingPoolConfig) -> Self {
+        Self {
+            tau_min: config.tau_min,
+            tau_max: config.tau_max,
+            k_threshold: config.k_min,
+            epsilon: config.epsilon,
+            sensitivity: config.sensitivity,
+            lamp_threshold_enabled: config.lamp_threshold_enabled,
+            lamp_prob_forward_rate: config.lamp_prob_forward_rate,
+        }
+    }
+}
+
+impl MixModelConfig {
+    /// Delay wie `MixingPool::add_message`: |Laplace(Δf/ε)| + U(τ_min, τ_max)
+    fn sample_delay(&self, rng: &mut StdRng) -> Duration {
+        let u: f64 = rng.gen();
+        let sign = if u < 0.5 { -1.0 } else { 1.0 };
+        let scale = self.sensitivity / self.epsilon;
+        let laplace = (sign * scale * (1.0 - 2.0 * (u - 0.5).abs()).ln()).abs();
+        let tau_min = self.tau_min.as_millis() as u64;
+        let tau_max = self.tau_max.as_millis() as u64;
+        let uniform = rng.gen_range(tau_min..=tau_max.max(tau_min)) as f64;
+        Duration::from_micros(((laplace + uniform) * 1000.0) as u64)
+    }
+}
+
+struct Pooled {
+    seq: u64,
+    release_at_us: u64,
+    message: SimMessage,
+}
+
+/// Mix-Relay: puffert Onion- und Cover-Pakete und gibt sie verzögert frei
+pub struct MixRelay {
+    config: MixModelConfig,
+    pool: Vec<Pooled>,
+    next_seq: u64,
+}
+
+impl MixRelay {
+    /// Relay mit Modell-Parametern
+    pub fn new(config: MixModelConfig) -> Self {
+        Self {
+            config,
+            pool: Vec::new(),
+            next_seq: 0,
+        }
+    }
+
+    fn release(&mut self, ctx: &mut SimContext<'_>, pooled: Pooled, anonymity_set: usize) {
+        ctx.record("mix.anonymity_set", anonymity_set as f64);
+        forward_onion(ctx, pooled.message);
+    }
+}
+
+/// Onion an den nächsten Hop weiterreichen; Cover endet hier
+/// (ununterscheidbar bis zum Flush)
+fn forward_onion(ctx: &mut SimContext<'_>, message: SimMessage) {
+    if let SimPayload::Onion {
+        id,
+        mut route,
+        sent_at_us,
+    } = message.payload
+    {
+        if route.is_empty() {
+            return;
+        }
+        let next = route.remove(0);
+        ctx.send(
+            next,
+            SimMessage::new(
+                SimPayload::Onion {
+                    id,
+                    route,
+                    sent_at_us,
+                },
+                message.size,
+            ),
+        );
+    }
+}
+
+impl SimNode for MixRelay {
+    fn on_message(&mut self, ctx: &mut SimContext<'_>, _from: NodeId, message: SimMessage) {
+        if !matches!(
+            message.payload,
+            SimPayload::Onion { .. } | SimPayload::Cover
+        ) {
+            return;
+        }
+
+        // LAMP Probabilistic Forwarding: sofort, Anonymitätsmenge 1
+        if self.config.lamp_threshold_enabled
+            && ctx.rng().gen::<f64>() < self.config.lamp_prob_forward_rate
+        {
+            let seq = self.next_seq;
+            self.next_seq += 1;
+            let pooled = Pooled {
+                seq,
+                release_at_us: ctx.now_us(),
+                message,
+            };
+            self.release(ctx, pooled, 1);
+            return;
+        }
+
+        let delay = self.config.sample_delay(ctx.rng());
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.pool.push(Pooled {
+            seq,
+            release_at_us: ctx.now_us() + delay.as_micros() as u64,
+            message,
+        });
+        ctx.set_timer(delay, seq);
+
+        // Threshold-Flush: gesamter Pool auf einmal
+        if self.config.lamp_threshold_enabled && self.pool.len() >= self.config.k_threshold {
+            let batch = std::mem::take(&mut self.pool);
+            let size = batch.len();
+            for pooled in batch {
+                self.release(ctx, pooled, size);
+            }
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut SimContext<'_>, token: u64) {
+        let Some(pos) = self.pool.iter().position(|p| p.seq == token) else {
+            return; // bereits per Threshold-Flush freigegeben
+        };
+        if self.pool[pos].release_at_us > ctx.now_us() {
+            return;
+        }
+        let size = self.pool.len();
+        let pooled = self.pool.remove(pos);
+        self.release(ctx, pooled, size);
+    }
+}
+
+const TIMER_REAL: u64 = 0;
+const TIMER_COVER: u64 = 1;
+
+/// Mix-Client: sendet Onion-Nachrichten über zufällige Relay-Routen und Cover-Traffic
+pub struct MixClient {
+    relays: Vec<NodeId>,
+    clients: Vec<NodeId>,
+    hops: usize,
+    /// Poisson-Rate realer Nachrichten (1/s)
+    message_rate: f64,
+    /// Poisson-Rate Cover-Traffic (1/s)
+    cover_rate: f64,
+    /// Nach dieser Zeit keine neuen Nachrichten
+    send_until: Duration,
+    message_size: usize,
+    next_id: u64,
+}
+
+impl MixClient {
+    fn next_interval(rng: &mut StdRng, rate: f64) -> Duration {
+        // Exponentialverteilte Inter-Arrival-Zeit
+        let u: f64 = rng.gen_range(f64::EPSILON..1.0);
+        Duration::from_secs_f64(-u.ln() / rate)
+    }
+
+    fn random_route(&self, ctx: &mut SimContext<'_>) -> Vec<NodeId> {
+        let mut available = self.relays.clone();
+        let mut route = Vec::with_capacity(self.hops);
+        for _ in 0..self.hops.min(available.len()) {
+            let idx = ctx.rng().gen_range(0..available.len());
+            route.push(available.remove(idx));
+        }
+        route
+    }
+}
+
+impl SimNode for MixClient {
+    fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+        if self.message_rate > 0.0 {
+            let after = Self::next_interval(ctx.rng(), self.message_rate);
+            ctx.set_timer(after, TIMER_REAL);
+        }
+        if self.cover_rate > 0.0 {
+            let after = Self::next_interval(ctx.rng(), self.cover_rate);
+            ctx.set_timer(after, TIMER_COVER);
+        }
+    }
+
+    fn on_message(&mut self, ctx: &mut SimContext<'_>, _from: NodeId, message: SimMessage) {
+        if let SimPayload::Onion { sent_at_us, .. } = message.payload {
+            ctx.record(
+                "mix.latency_ms",
+                (ctx.now_us() - sent_at_us) as f64 / 1000.0,
+            );
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut SimContext<'_>, token: u64) {
+        if ctx.now() > self.send_until || self.relays.is_empty() {
+            return;
+        }
+        let route = self.random_route(ctx);
+        let first_hop = route[0];
+
+        if token == TIMER_REAL {
+            let candidates: Vec<NodeId> = self
+                .clients
+                .iter()
+                .copied()
+                .filter(|&c| c != ctx.id())
+                .collect();
+            if !candidates.is_empty() {
+                let dest = candidates[ctx.rng().gen_range(0..candidates.len())];
+                let mut rest: Vec<NodeId> = route[1..].to_vec();
+                rest.push(dest);
+                let id = (ctx.id() as u64) << 32 | self.next_id;
+                self.next_id += 1;
+                let payload = SimPayload::Onion {
+                    id,
+                    route: rest,
+                    sent_at_us: ctx.now_us(),
+                };
+                ctx.send(first_hop, SimMessage::new(payload, self.message_size));
+                ctx.record("mix.sent", 1.0);
+            }
+            let after = Self::next_interval(ctx.rng(), self.message_rate);
+            ctx.set_timer(after, TIMER_REAL);
+        } else {
+            ctx.send(
+                first_hop,
+                SimMessage::new(SimPayload::Cover, self.message_size),
+            );
+            let after = Self::next_interval(ctx.rng(), self.cover_rate);
+            ctx.set_timer(after, TIMER_COVER);
+        }
+    }
+}
+
+/// Szenario: Anonymitätsmenge im Mix-Netz
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MixScenario {
+    pub seed: u64,
+    pub relays: usize,
+    pub clients: usize,
+    /// Hops pro Route (RL2-RL4: 3)
+    pub hops: usize,
+    pub link: LinkConfig,
+    pub mix: MixModelConfig,
+    /// Reale Nachrichten pro Client und Sekunde
+    pub message_rate: f64,
+    /// Cover-Nachrichten pro Client und Sekunde
+    pub cover_rate: f64,
+    pub duration: Duration,
+    pub message_size: usize,
+}
+
+impl Default for MixScenario {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            relays: 5,
+            clients: 10,
+            hops: 3,
+            link: LinkConfig::wan(),
+            mix: MixModelConfig::default(),
+            message_rate: 2.0,
+            cover_rate: 2.0,
+            duration: Duration::from_secs(10),
+            message_size: 1024,
+        }
+    }
+}
+
+/// Mix-Netz simulieren
+///
+/// Metriken: `mix.anonymity_set` (Pool-Größe bei Freigabe),
+/// `mix.latency_ms` (Ende-zu-Ende), `mix.sent`.
+pub fn simulate_mixing(scenario: &MixScenario) -> SimReport {
+    let mut sim = SimNetwork::new(scenario.seed, scenario.link);
+    let relays: Vec<NodeId> = (0..scenario.relays).collect();
+    let clients: Vec<NodeId> = (scenario.relays..scenario.relays + scenario.clients).collect();
+
+    for _ in &relays {
+        sim.add_node(Box::new(MixRelay::new(scenario.mix.clone())));
+    }
+    for _ in &clients {
+        sim.add_node(Box::new(MixClient {
+            relays: relays.clone(),
+            clients: clients.clone(),
+            hops: scenario.hops.max(1),
+            message_rate: scenario.message_rate,
+            cover_rate: scenario.cover_rate,
+            send_until: scenario.duration,
+            message_size: scenario.message_size,
+            next_id: 0,
+        }));
+    }
+
+    sim.run_until(scenario.duration + Duration::from_secs(30));
+    sim.report()
+}
+
+// ============================================================================
+// ECHTE PRIVACY-KOMPONENTEN (MixingPool, CoverTrafficGenerator)
+// ============================================================================
+
+#[cfg(feature = "privacy")]
+const TIMER_FLUSH_CHECK: u64 = u64::MAX;
+
+/// Relay mit echtem [`MixingPool`] auf der virtuellen Uhr
+///
+/// Nachrichten gehen als Token durch den Pool; die Sendezeitpunkte aus
+/// `MixingPool::push`/`poll` werden zu Simulator-Timern. Der periodische
+/// Flush-Check läuft im Takt von `MixingPool::run_flush_loop`.
+#[cfg(feature = "privacy")]
+pub struct PoolRelay {
+    pool: Arc<MixingPool>,
+    hop: PeerId,
+    pending: HashMap<u64, SimMessage>,
+    next_token: u64,
+}
+
+#[cfg(feature = "privacy")]
+impl PoolRelay {
+    /// Relay um einen Pool, der mit [`SimNetwork::clock`] gebaut wurde
+    pub fn new(pool: Arc<MixingPool>) -> Self {
+        Self {
+            pool,
+            hop: PeerId::random(),
+            pending: HashMap::new(),
+            next_token: 0,
+        }
+    }
+
+    fn schedule(&self, ctx: &mut SimContext<'_>, outputs: Vec<MixOutput>) {
+        let anonymity_set = outputs.len();
+        for output in outputs {
+            let Ok(token) = output.payload.as_slice().try_into().map(u64::from_be_bytes) else {
+                continue;
+            };
+            ctx.record("mix.anonymity_set", anonymity_set as f64);
+            let after = output.release_at.saturating_duration_since(ctx.instant());
+            ctx.set_timer(after, token);
+        }
+    }
+}
+
+#[cfg(feature = "privacy")]
+impl SimNode for PoolRelay {
+    fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+        ctx.set_timer(
+            Duration::from_millis(FLUSH_CHECK_INTERVAL_MS),
+            TIMER_FLUSH_CHECK,
+        );
+    }
+
+    fn on_message(&mut self, ctx: &mut SimContext<'_>, _from: NodeId, message: SimMessage) {
+        if !matches!(
+            message.payload,
+            SimPayload::Onion { .. } | SimPayload::Cover
+        ) {
+            return;
+        }
+        let token = self.next_token;
+        self.next_token += 1;
+        self.pending.insert(token, message);
+        let outputs = self.pool.push(token.to_be_bytes().to_vec(), self.hop);
+        self.schedule(ctx, outputs);
+    }
+
+    fn on_timer(&mut self, ctx: &mut SimContext<'_>, token: u64) {
+        if token == TIMER_FLUSH_CHECK {
+            let outputs = self.pool.poll();
+            self.schedule(ctx, outputs);
+            ctx.set_timer(
+                Duration::from_millis(FLUSH_CHECK_INTERVAL_MS),
+                TIMER_FLUSH_CHECK,
+            );
+        } else if let Some(message) = self.pending.remove(&token) {
+            forward_onion(ctx, message);
+        }
+    }
+}
+
+/// Cover-Quelle mit echtem [`CoverTrafficGenerator`] auf der virtuellen Uhr
+///
+/// Intervalle (Poisson, λ aus `CoverTrafficConfig`) und Size-Classes stammen
+/// vom Generator; jeder Dummy geht an ein zufälliges Relay.
+#[cfg(feature = "privacy")]
+pub struct CoverClient {
+    generator: Arc<CoverTrafficGenerator>,
+    relays: Vec<(NodeId, PeerId)>,
+    send_until: Duration,
+}
+
+#[cfg(feature = "privacy")]
+impl CoverClient {
+    /// Cover-Quelle um einen Generator, der mit [`SimNetwork::clock`] gebaut wurde
+    pub fn new(
+        generator: Arc<CoverTrafficGenerator>,
+        relays: Vec<NodeId>,
+        send_until: Duration,
+    ) -> Self {
+        Self {
+            generator,
+            relays: relays.into_iter().map(|r| (r, PeerId::random())).collect(),
+            send_until,
+        }
+    }
+}
+
+#[cfg(feature = "privacy")]
+impl SimNode for CoverClient {
+    fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+        if self.generator.stats().config_rate > 0.0 {
+            ctx.set_timer(self.generator.sample_poisson_delay(), TIMER_COVER);
+        }
+    }
+
+    fn on_message(&mut self, _ctx: &mut SimContext<'_>, _from: NodeId, _message: SimMessage) {}
+
+    fn on_timer(&mut self, ctx: &mut SimContext<'_>, _token: u64) {
+        if ctx.now() > self.send_until || self.relays.is_empty() {
+            return;
+        }
+        let (relay, peer) = self.relays[ctx.rng().gen_range(0..self.relays.len())];
+        if let Some(dummy) = self.generator.tick(vec![peer]) {
+            ctx.send(relay, SimMessage::new(SimPayload::Cover, dummy.size_class));
+            ctx.record("cover.sent", 1.0);
+        }
+        ctx.set_timer(self.generator.sample_poisson_delay(), TIMER_COVER);
+    }
+}
+
+/// Szenario: Mix-Netz aus echten `MixingPool`s und `CoverTrafficGenerator`s
+#[cfg(feature = "privacy")]
+#[derive(Debug, Clone)]
+pub struct PrivacyScenario {
+    pub seed: u64,
+    pub relays: usize,
+    pub clients: usize,
+    /// Hops pro Route (RL2-RL4: 3)
+    pub hops: usize,
+    pub link: LinkConfig,
+    pub pool: MixingPoolConfig,
+    pub cover: CoverTrafficConfig,
+    /// Reale Nachrichten pro Client und Sekunde
+    pub message_rate: f64,
+    pub duration: Duration,
+    pub message_size: usize,
+}
+
+#[cfg(feature = "privacy")]
+impl Default for PrivacyScenario {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            relays: 5,
+            clients: 10,
+            hops: 3,
+            link: LinkConfig::wan(),
+            pool: MixingPoolConfig::default(),
+            cover: CoverTrafficConfig::for_relay(),
+            message_rate: 2.0,
+            duration: Duration::from_secs(30),
+            message_size: 1024,
+        }
+    }
+}
+
+/// Mix-Netz mit den Produktiv-Komponenten simulieren
+///
+/// Relays sind [`PoolRelay`]s, jeder Client sendet reale Onions (Modell) und
+/// hat eine [`CoverClient`]-Quelle. Pools und Generatoren laufen auf
+/// [`SimNetwork::clock`] und werden aus dem Szenario-Seed geseedet.
+///
+/// Metriken: `mix.anonymity_set`, `mix.latency_ms`, `mix.sent`, `cover.sent`.
+#[cfg(feature = "privacy")]
+pub fn simulate_privacy(scenario: &PrivacyScenario) -> SimReport {
+    let mut sim = SimNetwork::new(scenario.seed, scenario.link);
+    let clock = sim.clock();
+    let relays: Vec<NodeId> = (0..scenario.relays).collect();
+    let clients: Vec<NodeId> = (scenario.relays..scenario.relays + scenario.clients).collect();
+
+    for &relay in &relays {
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        let pool = MixingPool::new(scenario.pool.clone(), tx)
+            .with_clock(clock.clone())
+            .with_seed(scenario.seed ^ relay as u64);
+        sim.add_node(Box::new(PoolRelay::new(Arc::new(pool))));
+    }
+    for _ in &clients {
+        sim.add_node(Box::new(MixClient {
+            relays: relays.clone(),
+            clients: clients.clone(),
+            hops: scenario.hops.max(1),
+            message_rate: scenario.message_rate,
+            cover_rate: 0.0,
+            send_until: scenario.duration,
+            message_size: scenario.message_size,
+            next_id: 0,
+        }));
+    }
+    for &client in &clients {
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        let generator = CoverTrafficGenerator::new(scenario.cover.clone(), tx)
+            .with_clock(clock.clone())
+            .with_seed(scenario.seed ^ client as u64);
+        sim.add_node(Box::new(CoverClient::new(
+            Arc::new(generator),
+            relays.clone(),
+            scenario.duration,
+        )));
+    }
+
+    sim.run_until(scenario.duration + Duration::from_secs(30));
+    sim.report()
+}
+
+// ============================================================================
+// FINALITY MODEL (Κ10)
+// ============================================================================
+
+/// Finalitäts-Modell: Proposer broadcastet, alle voten an alle, final bei Quorum
+pub struct FinalityNode {
+    /// Proposer-Intervall (nur Node 0 proposed)
+    block_interval: Option<Duration>,
+    blocks: u64,
+    quorum: usize,
+    votes: BTreeMap<u64, BTreeSet<NodeId>>,
+    finalized: BTreeSet<u64>,
+    message_size: usize,
+}
+
+impl FinalityNode {
+    fn broadcast(&self, ctx: &mut SimContext<'_>, payload: SimPayload) {
+        for peer in 0..ctx.node_count() {
+            if peer != ctx.id() {
+                ctx.send(peer, SimMessage::new(payload.clone(), self.message_size));
+            }
+        }
+    }
+
+    fn add_vote(
+        &mut self,
+        ctx: &mut SimContext<'_>,
+        voter: NodeId,
+        height: u64,
+        proposed_at_us: u64,
+    ) {
+        let votes = self.votes.entry(height).or_default();
+        votes.insert(voter);
+        if votes.len() >= self.quorum && self.finalized.insert(height) {
+            ctx.record(
+                "finality.latency_ms",
+                (ctx.now_us() - proposed_at_us) as f64 / 1000.0,
+            );
+        }
+    }
+
+    fn vote(&mut self, ctx: &mut SimContext<'_>, height: u64, proposed_at_us: u64) {
+        if self
+            .votes
+            .get(&height)
+            .is_some_and(|v| v.contains(&ctx.id()))
+        {
+            return;
+        }
+        let me = ctx.id();
+        self.add_vote(ctx, me, height, proposed_at_us);
+        self.broadcast(
+            ctx,
+            SimPayload::Vote {
+                height,
+                proposed_at_us,
+            },
+        );
+    }
+}
+
+impl SimNode for FinalityNode {
+    fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+        if let Some(interval) = self.block_interval {
+            for height in 0..self.blocks {
+                ctx.set_timer(interval * height as u32, height);
+            }
+        }
+    }
+
+    fn on_message(&mut self, ctx: &mut SimContext<'_>, from: NodeId, message: SimMessage) {
+        match message.payload {
+            SimPayload::Proposal {
+                height,
+                proposed_at_us,
+            } => self.vote(ctx, height, proposed_at_us),
+            SimPayload::Vote {
+                height,
+                proposed_at_us,
+            } => {
+                self.add_vote(ctx, from, height, proposed_at_us);
+                // Vote vor Proposal gesehen: trotzdem mitstimmen
+                self.vote(ctx, height, proposed_at_us);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut SimContext<'_>, height: u64) {
+        let proposed_at_us = ctx.now_us();
+        self.broadcast(
+            ctx,
+            SimPayload::Proposal {
+                height,
+                proposed_at_us,
+            },
+        );
+        self.vote(ctx, height, proposed_at_us);
+    }
+}
+
+/// Szenario: Finalitäts-Latenz
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalityScenario {
+    pub seed: u64,
+    pub validators: usize,
+    pub link: LinkConfig,
+    pub blocks: u64,
+    pub block_interval: Duration,
+    pub message_size: usize,
+}
+
+impl Default for FinalityScenario {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            validators: 7,
+            link: LinkConfig::wan(),
+            blocks: 10,
+            block_interval: Duration::from_secs(1),
+            message_size: 256,
+        }
+    }
+}
+
+/// Finalität simulieren
+///
+/// Quorum ist ⌈2n/3⌉ (BFT). Metrik: `finality.latency_ms` pro Node und Höhe.
+pub fn simulate_finality(scenario: &FinalityScenario) -> SimReport {
+    let mut sim = SimNetwork::new(scenario.seed, scenario.link);
+    let quorum = (2 * scenario.validators).div_ceil(3);
+
+    for i in 0..scenario.validators {
+        sim.add_node(Box::new(FinalityNode {
+            block_interval: (i == 0).then_some(scenario.block_interval),
+            blocks: scenario.blocks,
+            quorum,
+            votes: BTreeMap::new(),
+            finalized: BTreeSet::new(),
+            message_size: scenario.message_size,
+        }));
+    }
+
+    sim.run_until(scenario.block_interval * scenario.blocks as u32 + Duration::from_secs(30));
+    sim.report()
+}
+
+// ============================================================================
+// SYNC-TIMING (TimingManager, Κ9)
+// ============================================================================
+
+const TIMER_SYNC: u64 = 0;
+
+/// Sync-Node mit echtem [`TimingManager`] auf der virtuellen Uhr
+///
+/// Fragt `peer` im Takt von `event_sync` an, speist die gemessene RTT über
+/// `update_conditions_at` zurück und fällt bei Timeout in den exponentiellen
+/// Backoff. Beantwortet Anfragen anderer Nodes.
+pub struct SyncNode {
+    peer: NodeId,
+    timing: TimingManager,
+    clock: Arc<SimClock>,
+    timeout: Duration,
+    /// Laufende Anfrage (Sequenznummer)
+    in_flight: Option<u64>,
+    next_seq: u64,
+}
+
+impl SyncNode {
+    /// Node mit Default-Timings, der `peer` anfragt
+    pub fn new(peer: NodeId, clock: Arc<SimClock>) -> Self {
+        Self {
+            peer,
+            timing: TimingManager::default(),
+            clock,
+            timeout: Duration::from_secs(1),
+            in_flight: None,
+            next_seq: 0,
+        }
+    }
+
+    fn sync(&mut self, ctx: &mut SimContext<'_>) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.in_flight = Some(seq);
+        ctx.send(
+            self.peer,
+            SimMessage::new(
+                SimPayload::SyncRequest {
+                    seq,
+                    sent_at_us: ctx.now_us(),
+                },
+                64,
+            ),
+        );
+        ctx.record("sync.started_ms", ctx.now_us() as f64 / 1000.0);
+        // Timeout-Token 1 + seq, 0 ist der Sync-Timer
+        ctx.set_timer(self.timeout, seq + 1);
+    }
+
+    fn schedule_next(&self, ctx: &mut SimContext<'_>) {
+        let after = self.timing.event_sync.time_until_sync_at(self.clock.now());
+        // Auf volle µs aufrunden, sonst feuert der Timer knapp vor Fälligkeit
+        let after_us = after.as_nanos().div_ceil(1000) as u64;
+        ctx.set_timer(Duration::from_micros(after_us), TIMER_SYNC);
+    }
+}
+
+impl SimNode for SyncNode {
+    fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+        ctx.set_timer(Duration::ZERO, TIMER_SYNC);
+    }
+
+    fn on_message(&mut self, ctx: &mut SimContext<'_>, from: NodeId, message: SimMessage) {
+        match message.payload {
+            SimPayload::SyncRequest { seq, sent_at_us } => {
+                ctx.send(
+                    from,
+                    SimMessage::new(SimPayload::SyncResponse { seq, sent_at_us }, 64),
+                );
+            }
+            SimPayload::SyncResponse { seq, sent_at_us } if self.in_flight == Some(seq) => {
+                self.in_flight = None;
+                let rtt_ms = (ctx.now_us() - sent_at_us) / 1000;
+                let now = self.clock.now();
+                let peers = ctx.node_count().saturating_sub(1) as u32;
+                self.timing.update_conditions_at(
+                    NetworkConditions::new(rtt_ms as u32, 0.0, 10_000, peers),
+                    now,
+                );
+                self.timing.event_sync.mark_synced_at(now);
+                ctx.record(
+                    "sync.interval_ms",
+                    self.timing.event_sync.current_interval.as_secs_f64() * 1000.0,
+                );
+                self.schedule_next(ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut SimContext<'_>, token: u64) {
+        if token == TIMER_SYNC {
+            if self.in_flight.is_some() {
+                return;
+            }
+            if self.timing.event_sync.should_sync_at(self.clock.now()) {
+                self.sync(ctx);
+            } else {
+                self.schedule_next(ctx);
+            }
+        } else if self.in_flight == Some(token - 1) {
+            // Timeout: Backoff, nächster Versuch nach dem verlängerten Intervall
+            self.in_flight = None;
+            self.timing.event_sync.exponential_backoff();
+            let interval = self.timing.event_sync.current_interval;
+            ctx.record("sync.backoff_interval_ms", interval.as_secs_f64() * 1000.0);
+            ctx.set_timer(interval, TIMER_SYNC);
+        }
+    }
+}
+
+// ============================================================================
+// IN-MEMORY TRANSPORT
+// ============================================================================
+
+/// libp2p-Transport über `/memory/<n>` (Noise + Yamux wie der TCP-Stack)
+///
+/// Für Integrationstests mehrerer `SwarmManager` in einem Prozess, siehe
+/// `SwarmManager::run_with_transport`.
+pub fn memory_transport(keypair: &Keypair) -> Result<Boxed<(PeerId, StreamMuxerBox)>> {
+    Ok(MemoryTransport::default()
+        .upgrade(libp2p::core::upgrade::Version::V1Lazy)
+        .authenticate(libp2p::noise::Config::new(keypair)?)
+        .multiplex(libp2p::yamux::Config::default())
+        .boxed())
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_trace() {
+        let scenario = GossipScenario {
+            seed: 42,
+            link: LinkConfig::wan(),
+            ..Default::default()
+        };
+        let a = simulate_gossip(&scenario);
+        let b = simulate_gossip(&scenario);
+        assert_eq!(a.trace_digest, b.trace_digest);
+        assert_eq!(a.delivered, b.delivered);
+
+        let c = simulate_gossip(&GossipScenario {
+            seed: 43,
+            ..scenario
+        });
+        assert_ne!(a.trace_digest, c.trace_digest);
+    }
+
+    #[test]
+    fn test_gossip_reaches_all_nodes_on_lossless_links() {
+        let report = simulate_gossip(&GossipScenario {
+            seed: 1,
+            nodes: 30,
+            ..Default::default()
+        });
+        let coverage = report.metric("gossip.coverage").unwrap();
+        assert_eq!(coverage.min, 1.0);
+        // LAN: 1ms pro Hop, Mesh-Durchmesser klein
+        assert!(report.metric("gossip.propagation_ms").unwrap().max < 30.0);
+    }
+
+    #[test]
+    fn test_link_latency_and_bandwidth() {
+        struct Sink;
+        impl SimNode for Sink {
+            fn on_message(&mut self, ctx: &mut SimContext<'_>, _: NodeId, _: SimMessage) {
+                ctx.record("arrival_ms", ctx.now_us() as f64 / 1000.0);
+            }
+        }
+        struct Source;
+        impl SimNode for Source {
+            fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+                for _ in 0..2 {
+                    ctx.send(1, SimMessage::new(SimPayload::Custom(vec![]), 1250));
+                }
+            }
+            fn on_message(&mut self, _: &mut SimContext<'_>, _: NodeId, _: SimMessage) {}
+        }
+
+        let mut sim = SimNetwork::new(0, LinkConfig::lan());
+        sim.add_node(Box::new(Source));
+        sim.add_node(Box::new(Sink));
+        // 10ms Latenz, 1 Mbit/s → 1250 Bytes = 10ms Serialisierung
+        sim.set_link(
+            0,
+            1,
+            LinkConfig {
+                latency: Duration::from_millis(10),
+                jitter: Duration::ZERO,
+                loss_rate: 0.0,
+                bandwidth_bps: 1_000_000,
+            },
+        );
+        sim.run_until(Duration::from_secs(1));
+        assert_eq!(sim.metric_values("arrival_ms"), &[20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_partition_drops_messages() {
+        let mut sim = SimNetwork::new(3, LinkConfig::lan());
+        let mesh = build_mesh(4, 3, &mut sim.rng);
+        for (i, peers) in mesh.into_iter().enumerate() {
+            let mut node = GossipNode::new(peers, 64);
+            if i == 0 {
+                node = node.publish_at(Duration::ZERO, 1);
+            }
+            sim.add_node(Box::new(node));
+        }
+        sim.partition(&[vec![0, 1], vec![2, 3]]);
+        sim.run_until(Duration::from_secs(1));
+
+        let report = sim.report();
+        assert!(report.dropped > 0);
+        assert_eq!(report.metric("gossip.propagation_ms").unwrap().count, 1);
+    }
+
+    #[test]
+    fn test_mixing_anonymity_set() {
+        let report = simulate_mixing(&MixScenario {
+            seed: 9,
+            ..Default::default()
+        });
+        let anon = report.metric("mix.anonymity_set").unwrap();
+        assert!(anon.count > 0);
+        assert!(anon.max >= 3.0);
+        assert!(report.metric("mix.latency_ms").unwrap().count > 0);
+
+        // Ohne Cover-Traffic und Threshold bleibt die Anonymitätsmenge kleiner
+        let sparse = simulate_mixing(&MixScenario {
+            seed: 9,
+            cover_rate: 0.0,
+            message_rate: 0.2,
+            ..Default::default()
+        });
+        assert!(sparse.metric("mix.anonymity_set").unwrap().mean < anon.mean);
+    }
+
+    #[test]
+    fn test_finality_latency() {
+        let report = simulate_finality(&FinalityScenario {
+            seed: 5,
+            link: LinkConfig::lan(),
+            ..Default::default()
+        });
+        let finality = report.metric("finality.latency_ms").unwrap();
+        // Jeder Validator finalisiert jede Höhe
+        assert_eq!(finality.count, 7 * 10);
+        // Proposal + Vote: zwei Hops à 1ms
+        assert!(finality.max <= 2.0);
+    }
+
+    #[test]
+    fn test_metric_summary() {
+        let s = MetricSummary::from_values(&[4.0, 1.0, 3.0, 2.0]).unwrap();
+        assert_eq!(s.min, 1.0);
+        assert_eq!(s.max, 4.0);
+        assert_eq!(s.p50, 2.0);
+        assert_eq!(s.mean, 2.5);
+        assert!(MetricSummary::from_values(&[]).is_none());
+    }
+
+    /// Beantwortet Sync-Anfragen, synct selbst nicht
+    struct SyncEcho;
+    impl SimNode for SyncEcho {
+        fn on_message(&mut self, ctx: &mut SimContext<'_>, from: NodeId, message: SimMessage) {
+            if let SimPayload::SyncRequest { seq, sent_at_us } = message.payload {
+                ctx.send(
+                    from,
+                    SimMessage::new(SimPayload::SyncResponse { seq, sent_at_us }, 64),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sync_timing_follows_virtual_clock() {
+        let mut sim = SimNetwork::new(11, LinkConfig::lan());
+        sim.add_node(Box::new(SyncNode::new(1, sim.clock())));
+        sim.add_node(Box::new(SyncEcho));
+        sim.run_until(Duration::from_secs(120));
+
+        // Erster Sync sofort, danach genau RTT + event_sync.current_interval
+        let started = sim.metric_values("sync.started_ms").to_vec();
+        let intervals = sim.metric_values("sync.interval_ms").to_vec();
+        assert_eq!(started[0], 0.0);
+        assert!(started.len() >= 10);
+        for i in 0..started.len() - 1 {
+            let rtt = 2.0; // 2 × 1ms LAN
+            let gap = started[i + 1] - started[i] - rtt;
+            assert!(
+                (gap - intervals[i]).abs() < 0.01,
+                "gap {gap} vs {}",
+                intervals[i]
+            );
+        }
+        // LAN mit einem Peer: τ-Faktor sinkt Richtung 0.54 → Intervall < Basis (10s)
+        assert!(intervals.windows(2).all(|w| w[1] <= w[0]));
+        assert!(*intervals.last().unwrap() < 6_000.0);
+        assert!(intervals.iter().all(|&i| i >= 2_000.0));
+    }
+
+    #[test]
+    fn test_sync_timing_backs_off_during_partition() {
+        let mut sim = SimNetwork::new(12, LinkConfig::lan());
+        sim.add_node(Box::new(SyncNode::new(1, sim.clock())));
+        sim.add_node(Box::new(SyncEcho));
+        sim.partition(&[vec![0], vec![1]]);
+        sim.run_until(Duration::from_secs(200));
+
+        // 10s → ×2 → ×4 (gekappt bei max_interval 60s)
+        let backoff = sim.metric_values("sync.backoff_interval_ms").to_vec();
+        assert_eq!(&backoff[..3], &[20_000.0, 60_000.0, 60_000.0]);
+        assert!(sim.metric_values("sync.interval_ms").is_empty());
+
+        // Nach dem Heilen: erfolgreicher Sync setzt das Intervall neu
+        sim.heal();
+        sim.run_until(Duration::from_secs(400));
+        let intervals = sim.metric_values("sync.interval_ms");
+        assert!(!intervals.is_empty());
+        assert!(intervals.iter().all(|&i| i < 60_000.0));
+    }
+
+    #[cfg(feature = "privacy")]
+    fn pool_relay_network(
+        config: MixingPoolConfig,
+        sends_ms: &[u64],
+    ) -> (SimNetwork, Arc<MixingPool>) {
+        struct Source(Vec<u64>);
+        impl SimNode for Source {
+            fn on_start(&mut self, ctx: &mut SimContext<'_>) {
+                for (i, &at) in self.0.iter().enumerate() {
+                    ctx.set_timer(Duration::from_millis(at), i as u64);
+                }
+            }
+            fn on_message(&mut self, _: &mut SimContext<'_>, _: NodeId, _: SimMessage) {}
+            fn on_timer(&mut self, ctx: &mut SimContext<'_>, token: u64) {
+                let payload = SimPayload::Onion {
+                    id: token,
+                    route: vec![2],
+                    sent_at_us: ctx.now_us(),
+                };
+                ctx.send(1, SimMessage::new(payload, 512));
+            }
+        }
+        struct Sink;
+        impl SimNode for Sink {
+            fn on_message(&mut self, ctx: &mut SimContext<'_>, _: NodeId, _: SimMessage) {
+                ctx.record("arrival_ms", ctx.now_us() as f64 / 1000.0);
+            }
+        }
+
+        let mut sim = SimNetwork::new(0, LinkConfig::lan());
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        let pool = Arc::new(
+            MixingPool::new(config, tx)
+                .with_clock(sim.clock())
+                .with_seed(21),
+        );
+        sim.add_node(Box::new(Source(sends_ms.to_vec())));
+        sim.add_node(Box::new(PoolRelay::new(pool.clone())));
+        sim.add_node(Box::new(Sink));
+        (sim, pool)
+    }
+
+    #[cfg(feature = "privacy")]
+    #[test]
+    fn test_mixing_pool_threshold_flush_in_virtual_time() {
+        let config = MixingPoolConfig {
+            lamp_adaptive_k: false,
+            lamp_prob_forward_rate: 0.0,
+            k_min: 3,
+            epsilon: 1000.0, // Laplace-Anteil < 1ms
+            ..Default::default()
+        };
+        let run = || {
+            let (mut sim, pool) = pool_relay_network(config.clone(), &[0, 0, 100]);
+            sim.run_until(Duration::from_secs(5));
+            (sim.metric_values("arrival_ms").to_vec(), pool.stats())
+        };
+        let (arrivals, stats) = run();
+
+        // k_min = 3 erreicht erst mit der dritten Nachricht (Ankunft 101ms);
+        // danach hält der Pool jede um höchstens τ_max/√3
+        assert_eq!(arrivals.len(), 3);
+        assert!(arrivals.windows(2).all(|w| w[0] <= w[1]));
+        assert!(arrivals[0] >= 102.0);
+        assert!(arrivals[2] <= 101.0 + 501.0 / 3f64.sqrt() + 1.0);
+        assert_eq!(stats.threshold_flush_count, 1);
+        assert_eq!(stats.buffer_size, 0);
+
+        // Gleicher Seed → identische Freigabezeiten
+        assert_eq!(run().0, arrivals);
+    }
+
+    #[cfg(feature = "privacy")]
+    #[test]
+    fn test_mixing_pool_holds_until_tau_max_in_virtual_time() {
+        let config = MixingPoolConfig {
+            lamp_threshold_enabled: false,
+            lamp_adaptive_k: false,
+            k_min: 3,
+            epsilon: 1000.0,
+            ..Default::default()
+        };
+        let (mut sim, pool) = pool_relay_network(config, &[0, 0, 0]);
+
+        // Ankunft 1ms; Flush-Check alle 50ms, Flush erst bei Wartezeit > τ_max (500ms)
+        sim.run_until(Duration::from_millis(549));
+        assert!(sim.metric_values("arrival_ms").is_empty());
+        assert_eq!(pool.stats().buffer_size, 3);
+
+        sim.run_until(Duration::from_secs(2));
+        assert_eq!(sim.metric_values("arrival_ms"), &[551.0, 551.0, 551.0]);
+        assert_eq!(sim.metric_values("mix.anonymity_set"), &[3.0, 3.0, 3.0]);
+        assert_eq!(pool.stats().buffer_size, 0);
+    }
+
+    #[cfg(feature = "privacy")]
+    #[test]
+    fn test_cover_generator_rate_in_virtual_time() {
+        use crate::peer::p2p::privacy::PeerType;
+
+        struct Sink;
+        impl SimNode for Sink {
+            fn on_message(&mut self, _: &mut SimContext<'_>, _: NodeId, _: SimMessage) {}
+        }
+
+        let run = |seed: u64| {
+            let mut sim = SimNetwork::new(seed, LinkConfig::lan());
+            let (tx, _rx) = tokio::sync::mpsc::channel(1);
+            let config = CoverTrafficConfig {
+                peer_type: PeerType::FullRelay, // λ = 0.2/s
+                overhead_ratio: 1.0,
+                enabled: true,
+            };
+            let generator = Arc::new(
+                CoverTrafficGenerator::new(config, tx)
+                    .with_clock(sim.clock())
+                    .with_seed(seed),
+            );
+            sim.add_node(Box::new(Sink));
+            sim.add_node(Box::new(CoverClient::new(
+                generator.clone(),
+                vec![0],
+                Duration::from_secs(1000),
+            )));
+            sim.run_until(Duration::from_secs(1000));
+            (generator.stats(), sim.report())
+        };
+
+        let (stats, report) = run(4);
+        assert_eq!(stats.elapsed_secs, 1000.0);
+        assert_eq!(
+            report.metric("cover.sent").unwrap().count as u64,
+            stats.cover_sent
+        );
+        assert!(
+            (stats.effective_rate - 0.2).abs() < 0.05,
+            "rate {}",
+            stats.effective_rate
+        );
+
+        let (again, repeat) = run(4);
+        assert_eq!(again.cover_sent, stats.cover_sent);
+        assert_eq!(repeat.trace_digest, report.trace_digest);
+    }
+
+    #[cfg(feature = "privacy")]
+    #[test]
+    fn test_privacy_scenario_same_seed_same_trace() {
+        let scenario = PrivacyScenario {
+            seed: 3,
+            duration: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let a = simulate_privacy(&scenario);
+        let b = simulate_privacy(&scenario);
+        assert_eq!(a.trace_digest, b.trace_digest);
+        assert!(a.metric("mix.latency_ms").unwrap().count > 0);
+        assert!(a.metric("cover.sent").unwrap().count > 0);
+        assert!(a.metric("mix.anonymity_set").unwrap().max >= 3.0);
+
+        let c = simulate_privacy(&PrivacyScenario {
+            seed: 4,
+            ..scenario
+        });
+        assert_ne!(a.trace_digest, c.trace_digest);
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_builds() {
+        use futures::StreamExt;
+        use libp2p::swarm::{dummy, SwarmEvent};
+
+        let keypair = Keypair::generate_ed25519();
+        let transport = memory_transport(&keypair).unwrap();
+        let mut swarm = libp2p::Swarm::new(
+            transport,
+            dummy::Behaviour,
+            keypair.public().to_peer_id(),
+            libp2p::swarm::Config::with_tokio_executor(),
+        );
+        swarm.listen_on("/memory/0".parse().unwrap()).unwrap();
+        let event = swarm.select_next_some().await;
+        assert!(matches!(event, SwarmEvent::NewListenAddr { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_swarm_managers_over_memory_transport() {
+        use crate::peer::p2p::config::P2PConfig;
+        use crate::peer::p2p::identity::PeerIdentity;
+        use crate::peer::p2p::swarm::{SwarmEvent2, SwarmManager};
+        use crate::peer::p2p::topics::TopicMessage;
+        use std::sync::Arc;
+        use tokio::sync::broadcast::Receiver;
+        use tokio::time::{sleep, timeout, Instant};
+
+        // Stern um Node 0: Node 1 und Node 3 sind nur über Node 0 verbunden
+        const NODES: u64 = 4;
+        let base = u64::from(rand::random::<u32>()) << 8;
+        let nodes: Vec<_> = (0..NODES)
+            .map(|i| {
+                let config = P2PConfig {
+                    listen_addresses: vec![format!("/memory/{}", base + i)],
+                    bootstrap_peers: if i == 0 {
+                        vec![]
+                    } else {
+                        vec![format!("/memory/{base}")]
+                    },
+                    enable_mdns: false,
+                    ..P2PConfig::default()
+                };
+                let identity = PeerIdentity::generate();
+                let transport = memory_transport(&identity.keypair()).unwrap();
+                let (manager, _sync_rx) = SwarmManager::new(config, identity);
+                (Arc::new(manager), transport)
+            })
+            .collect();
+        let managers: Vec<Arc<SwarmManager>> = nodes.iter().map(|(m, _)| m.clone()).collect();
+        let mut hub_events = managers[0].event_receiver();
+        let mut leaf_events = managers[3].event_receiver();
+
+        let mut tasks = Vec::new();
+        for (manager, transport) in nodes {
+            tasks.push(tokio::spawn(async move {
+                manager.run_with_transport(transport).await
+            }));
+            // Node 0 muss lauschen, bevor die anderen ihn anwählen
+            while !managers[0].is_running() {
+                sleep(Duration::from_millis(10)).await;
+            }
+        }
+
+        let mut connected = BTreeSet::new();
+        timeout(Duration::from_secs(10), async {
+            while connected.len() < NODES as usize - 1 {
+                if let Ok(SwarmEvent2::PeerConnected { peer_id }) = hub_events.recv().await {
+                    connected.insert(peer_id);
+                }
+            }
+        })
+        .await
+        .expect("leaves did not connect to the hub");
+
+        // Commands erreichen den laufenden Swarm
+        for manager in &managers {
+            manager.join_realm("sim").await.unwrap();
+        }
+
+        async fn received(events: &mut Receiver<SwarmEvent2>, sender: &str) -> bool {
+            while let Ok(event) = events.try_recv() {
+                if let SwarmEvent2::GossipMessage {
+                    message: TopicMessage::Event { sender: s, .. },
+                    ..
+                } = event
+                {
+                    if s == sender {
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+
+        // Bis der Mesh steht, kann Publish mangels Peers fehlschlagen
+        let deadline = Instant::now() + Duration::from_secs(20);
+        let mut delivered = false;
+        while !delivered && Instant::now() < deadline {
+            let _ = managers[1]
+                .publish_event("sim", b"block".to_vec(), "node-1")
+                .await;
+            sleep(Duration::from_millis(250)).await;
+            delivered = received(&mut leaf_events, "node-1").await;
+        }
+        assert!(
+            delivered,
+            "gossip from node 1 did not reach node 3 via the hub"
+        );
+
+        for task in tasks {
+            task.abort();
+        }
+    }
+}
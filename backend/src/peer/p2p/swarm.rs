@@ -29,7 +29,7 @@ use crate::peer::p2p::identity::{PeerIdentity, SignedPeerInfo};
 use crate::peer::p2p::privacy::{
     CoverMessage, PrivacyService, PrivacyServiceConfig, RelayCandidate, SensitivityLevel,
 };
-use crate::peer::p2p::protocol::{SyncRequest, SyncResponse};
+use crate::peer::p2p::protocol::{SyncRequest, SyncResponse, TracedSyncRequest};
 use crate::peer::p2p::topics::{RealmTopic, TopicManager, TopicMessage, SignedTopicMessage, SignatureError};
 use crate::peer::p2p::trust_gate::TrustGate;
 use anyhow::{anyhow, Result};
@@ -41,11 +41,12 @@ use libp2p::mdns;
 use libp2p::request_response::{self, OutboundRequestId, ResponseChannel};
 use libp2p::swarm::{dial_opts::DialOpts, SwarmEvent};
 use libp2p::{Multiaddr, PeerId, Swarm, Transport};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::sync::{broadcast, mpsc, oneshot};
+use tracing::Instrument;
 
 /// Swarm Manager Command
 #[derive(Debug)]
@@ -79,6 +80,8 @@ pub enum SwarmCommand {
     SendRequest {
         peer_id: PeerId,
         request: SyncRequest,
+        /// W3C `traceparent` des anfragenden Spans
+        traceparent: Option<String>,
         response: oneshot::Sender<Result<SyncResponse>>,
     },
     /// DHT Put
@@ -161,9 +164,24 @@ pub enum SwarmEvent2 {
 pub struct IncomingSyncRequest {
     pub peer_id: PeerId,
     pub request: SyncRequest,
+    /// W3C `traceparent` des anfragenden Peers
+    pub traceparent: Option<String>,
     pub channel: ResponseChannel<Vec<u8>>,
 }
 
+impl IncomingSyncRequest {
+    /// Span für die Bearbeitung, eingehängt in den Trace des anfragenden Peers
+    pub fn span(&self) -> tracing::Span {
+        let span = tracing::info_span!(
+            "p2p.handle_sync_request",
+            otel.kind = "server",
+            peer = %self.peer_id,
+        );
+        crate::telemetry::set_parent(&span, self.traceparent.as_deref());
+        span
+    }
+}
+
 /// Signatur-Statistiken für SwarmManager (v0.4.0)
 #[derive(Debug, Clone, Default)]
 pub struct SignatureStats {
@@ -212,6 +230,9 @@ pub struct SwarmManager {
     /// Command-Sender
     command_tx: mpsc::Sender<SwarmCommand>,
 
+    /// Command-Receiver (wird von `run_with_transport` übernommen)
+    command_rx: Mutex<Option<mpsc::Receiver<SwarmCommand>>>,
+
     /// Event-Receiver (broadcast für multiple consumers)
     event_tx: broadcast::Sender<SwarmEvent2>,
 
@@ -270,7 +291,7 @@ impl SwarmManager {
         config: P2PConfig,
         identity: PeerIdentity,
     ) -> (Self, mpsc::Receiver<IncomingSyncRequest>) {
-        let (command_tx, _) = mpsc::channel(256);
+        let (command_tx, command_rx) = mpsc::channel(256);
         let (event_tx, _) = broadcast::channel(256);
         let (sync_request_tx, sync_request_rx) = mpsc::channel(256);
 
@@ -283,6 +304,7 @@ impl SwarmManager {
                 topics: TopicManager::new_arc(),
                 trust_gate,
                 command_tx,
+                command_rx: Mutex::new(Some(command_rx)),
                 event_tx,
                 sync_request_tx,
                 running: Arc::new(RwLock::new(false)),
@@ -459,8 +481,41 @@ impl SwarmManager {
             .multiplex(libp2p::yamux::Config::default())
             .boxed();
 
-        // Baue Behaviour
-        let behaviour = ErynoaBehaviour::new(&self.identity.keypair(), &self.config)?;
+        self.run_with_transport(transport).await
+    }
+
+    /// Starte den Swarm über einen beliebigen Transport
+    ///
+    /// Z.B. `simulation::memory_transport` für mehrere Nodes in einem Prozess.
+    pub async fn run_with_transport(
+        &self,
+        transport: libp2p::core::transport::Boxed<(
+            PeerId,
+            libp2p::core::muxing::StreamMuxerBox,
+        )>,
+    ) -> Result<()> {
+        // Command-Channel: Receiver gehört dem laufenden Swarm
+        let mut command_rx = self
+            .command_rx
+            .lock()
+            .take()
+            .ok_or_else(|| anyhow!("Swarm already running"))?;
+
+        // Baue Behaviour; der Relay-Client braucht seinen Transport im Swarm
+        let keypair = self.identity.keypair();
+        let (behaviour, relay_transport) =
+            ErynoaBehaviour::new_with_relay_transport(&keypair, &self.config)?;
+        let relay_transport = relay_transport
+            .upgrade(libp2p::core::upgrade::Version::V1Lazy)
+            .authenticate(libp2p::noise::Config::new(&keypair)?)
+            .multiplex(libp2p::yamux::Config::default())
+            .map(|(peer_id, muxer), _| {
+                (peer_id, libp2p::core::muxing::StreamMuxerBox::new(muxer))
+            });
+        let transport = transport
+            .or_transport(relay_transport)
+            .map(|output, _| output.into_inner())
+            .boxed();
 
         // Baue Swarm
         let swarm_config = libp2p::swarm::Config::with_tokio_executor();
@@ -487,10 +542,6 @@ impl SwarmManager {
         // Setze Running-State
         *self.running.write() = true;
 
-        // Command-Channel
-        let (_command_tx, mut command_rx) = mpsc::channel::<SwarmCommand>(256);
-        // Update self.command_tx würde &mut self benötigen, daher hier separat
-
         tracing::info!(peer_id = %self.peer_id(), "Swarm started");
 
         // Starte Privacy-Service Background-Tasks (Phase 2 Woche 8)
@@ -532,6 +583,8 @@ impl SwarmManager {
             service.stop();
         }
 
+        // Receiver zurückgeben, damit der Swarm erneut gestartet werden kann
+        *self.command_rx.lock() = Some(command_rx);
         *self.running.write() = false;
         tracing::info!("Swarm stopped");
         Ok(())
@@ -737,10 +790,11 @@ impl SwarmManager {
                     return;
                 }
 
-                if let Ok(sync_req) = SyncRequest::from_bytes(&request) {
+                if let Ok(traced) = TracedSyncRequest::from_bytes(&request) {
                     let _ = self.sync_request_tx.try_send(IncomingSyncRequest {
                         peer_id: peer,
-                        request: sync_req,
+                        request: traced.request,
+                        traceparent: traced.traceparent,
                         channel,
                     });
                 }
@@ -868,9 +922,14 @@ impl SwarmManager {
             SwarmCommand::SendRequest {
                 peer_id,
                 request,
+                traceparent,
                 response,
             } => {
-                let bytes = match request.to_bytes() {
+                let traced = TracedSyncRequest {
+                    request,
+                    traceparent,
+                };
+                let bytes = match traced.to_bytes() {
                     Ok(b) => b,
                     Err(e) => {
                         let _ = response.send(Err(e));
@@ -1099,17 +1158,30 @@ impl SwarmManager {
             limit,
         };
 
+        let span = tracing::info_span!(
+            "p2p.sync_request",
+            otel.kind = "client",
+            peer = %peer_id,
+            realm.id = realm_id,
+        );
+        let traceparent = span.in_scope(crate::telemetry::current_traceparent);
+
         let (tx, rx) = oneshot::channel();
-        self.command_tx
-            .send(SwarmCommand::SendRequest {
-                peer_id,
-                request,
-                response: tx,
-            })
-            .await
-            .map_err(|_| anyhow!("Failed to send command"))?;
+        async {
+            self.command_tx
+                .send(SwarmCommand::SendRequest {
+                    peer_id,
+                    request,
+                    traceparent,
+                    response: tx,
+                })
+                .await
+                .map_err(|_| anyhow!("Failed to send command"))?;
 
-        rx.await.map_err(|_| anyhow!("Channel closed"))?
+            rx.await.map_err(|_| anyhow!("Channel closed"))?
+        }
+        .instrument(span)
+        .await
     }
 
     /// Get connected peers
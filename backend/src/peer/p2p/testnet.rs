@@ -71,8 +71,15 @@
 //! - **Κ19**: Anti-Calcification (Relay-Power-Limits)
 //! - **Κ20**: Diversity-Requirement (Multi-Jurisdiction)
 
+#[cfg(feature = "privacy")]
+use crate::core::PrivacyObserver;
+use crate::core::{
+    GossipObserver, KademliaObserver, RelayObserver, SharedUnifiedState, StateIntegrator,
+    SwarmObserver,
+};
 use crate::peer::p2p::config::{P2PConfig, PrivacyConfig, SyncConfig};
-use crate::peer::p2p::protocol::SyncCodec;
+use crate::peer::p2p::protocol::{error_codes, SyncCodec, SyncRequest, SyncResponse};
+use crate::peer::p2p::timing::NetworkConditions;
 use anyhow::{anyhow, Result};
 use futures::StreamExt;
 use libp2p::autonat;
@@ -84,20 +91,26 @@ use libp2p::mdns;
 use libp2p::ping;
 use libp2p::relay;
 use libp2p::request_response::{self, ProtocolSupport};
-use libp2p::swarm::{NetworkBehaviour, SwarmEvent};
+use libp2p::swarm::{behaviour::toggle::Toggle, NetworkBehaviour, SwarmEvent};
 use libp2p::upnp;
 use libp2p::{identity::Keypair, Multiaddr, PeerId, StreamProtocol, Swarm, Transport};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::{broadcast, mpsc, oneshot};
 
 // Privacy-Layer Imports (Feature-gated)
 #[cfg(feature = "privacy")]
-use crate::peer::p2p::privacy::{PrivacyService, PrivacyServiceConfig, SensitivityLevel};
+use crate::peer::p2p::privacy::{
+    CoverMessage, OnionDecryptor, PrivacyService, PrivacyServiceConfig, ProcessingResult,
+    RelayCandidate, SensitivityLevel,
+};
 
 // Performance Imports (Feature-gated)
 #[cfg(feature = "privacy")]
@@ -115,6 +128,13 @@ use crate::peer::p2p::censorship::{BootstrapConfig, BootstrapHelper};
 // TESTNET CONFIGURATION (V2.6)
 // ============================================================================
 
+/// Maximale AutoNAT-Confidence (Anzahl bestätigender Probes)
+const AUTONAT_CONFIDENCE_MAX: usize = 3;
+
+/// HKDF-Domain für den aus der Node-Identität abgeleiteten Onion-Schlüssel
+#[cfg(feature = "privacy")]
+pub const ONION_KEY_DOMAIN: &[u8] = b"erynoa-testnet-onion-v1";
+
 /// Testnet-spezifische Konfiguration mit Feature-Toggles
 #[derive(Debug, Clone)]
 pub struct TestnetConfig {
@@ -144,6 +164,12 @@ pub struct TestnetConfig {
 
     /// Debug-Logging für NAT-Events
     pub verbose_nat_logging: bool,
+
+    /// Seed für simulierte Link-Bedingungen (Packet-Loss); `None` = Entropy
+    pub rng_seed: Option<u64>,
+
+    /// Intervall für den Abgleich der Live-Metriken in einen angehängten `UnifiedState`
+    pub state_sync_interval: Duration,
 }
 
 impl Default for TestnetConfig {
@@ -161,6 +187,8 @@ impl Default for TestnetConfig {
             ],
             enable_metrics: true,
             verbose_nat_logging: true,
+            rng_seed: None,
+            state_sync_interval: Duration::from_secs(5),
         }
     }
 }
@@ -266,8 +294,8 @@ pub struct TestnetBehaviour {
     /// Peer-Identifikation und Protokoll-Negotiation
     pub identify: identify::Behaviour,
 
-    /// mDNS für LAN-Discovery
-    pub mdns: mdns::tokio::Behaviour,
+    /// mDNS für LAN-Discovery (abschaltbar via `P2PConfig::enable_mdns`)
+    pub mdns: Toggle<mdns::tokio::Behaviour>,
 
     /// Ping für Connection-Health und Keep-Alive
     pub ping: ping::Behaviour,
@@ -314,8 +342,17 @@ impl TestnetBehaviour {
         // Identify mit Agent-Version
         let identify = Self::build_identify(keypair)?;
 
-        // mDNS für lokale Discovery
-        let mdns = mdns::tokio::Behaviour::new(mdns::Config::default(), peer_id)?;
+        // mDNS für lokale Discovery (im Cluster-Modus deaktiviert, damit
+        // Partitionen nicht durch LAN-Discovery unterlaufen werden)
+        let mdns = if config.enable_mdns {
+            Some(mdns::tokio::Behaviour::new(
+                mdns::Config::default(),
+                peer_id,
+            )?)
+        } else {
+            None
+        }
+        .into();
 
         // Ping mit Keep-Alive
         let ping_config = ping::Config::new().with_interval(Duration::from_secs(15));
@@ -434,6 +471,7 @@ impl TestnetBehaviour {
             boot_delay: Duration::from_secs(5), // Schneller Boot im Testnet
             throttle_server_period: Duration::from_secs(1),
             only_global_ips: false, // Im Testnet auch private IPs erlauben
+            confidence_max: AUTONAT_CONFIDENCE_MAX,
             ..Default::default()
         };
 
@@ -555,6 +593,9 @@ pub enum TestnetEvent {
         circuit_id: String,
         is_cover_traffic: bool,
     },
+    /// Privacy-Nachricht empfangen (letzte Onion-Schicht entfernt)
+    #[cfg(feature = "privacy")]
+    PrivacyMessageReceived { payload: Vec<u8> },
     /// Mixing-Pool geflusht (RL8, RL25)
     #[cfg(feature = "privacy")]
     MixingPoolFlushed {
@@ -603,6 +644,63 @@ pub enum TestnetEvent {
     Stats(TestnetStats),
 }
 
+// ============================================================================
+// TESTNET COMMANDS
+// ============================================================================
+
+/// Steuer-Kommando an einen laufenden [`TestnetSwarm`]
+///
+/// Wird vom Cluster-Orchestrator genutzt, um Szenarien (Partition, Rejoin,
+/// Kill, Latenz-Injektion) gegen laufende Nodes zu fahren.
+#[derive(Debug)]
+pub enum TestnetCommand {
+    /// Nachricht über Gossipsub veröffentlichen (unterliegt `NetworkConditions`)
+    Publish { topic: String, data: Vec<u8> },
+    /// Adresse anwählen
+    Dial { addr: Multiaddr },
+    /// Peer blockieren: Verbindung trennen, neue Verbindungen ablehnen
+    BlockPeer { peer_id: PeerId },
+    /// Blockierung aufheben
+    UnblockPeer { peer_id: PeerId },
+    /// Simulierte Link-Bedingungen setzen (`None` = ideale Verbindung)
+    SetConditions {
+        conditions: Option<NetworkConditions>,
+    },
+    /// Aktuelle Statistiken abfragen
+    GetStats {
+        response: oneshot::Sender<TestnetStats>,
+    },
+    /// Lokale Listen-Adressen abfragen
+    GetListenAddresses {
+        response: oneshot::Sender<Vec<Multiaddr>>,
+    },
+    /// Strukturierten Node-Status abfragen
+    GetStatus {
+        response: oneshot::Sender<TestnetStatus>,
+    },
+    /// Relay-Kandidaten für Onion-Routing und Multi-Circuit setzen
+    #[cfg(feature = "privacy")]
+    UpdateRelayCandidates { candidates: Vec<RelayCandidate> },
+    /// Nachricht über den Privacy-Layer senden (Onion + Mixing)
+    #[cfg(feature = "privacy")]
+    SendPrivate {
+        destination: PeerId,
+        data: Vec<u8>,
+        sensitivity: SensitivityLevel,
+        response: oneshot::Sender<Result<()>>,
+    },
+    /// Nachricht über parallele Circuits senden (RL28)
+    #[cfg(feature = "privacy")]
+    SendMultiCircuit {
+        destination: PeerId,
+        data: Vec<u8>,
+        sensitivity: SensitivityLevel,
+        response: oneshot::Sender<Result<()>>,
+    },
+    /// Event-Loop beenden
+    Shutdown,
+}
+
 /// Transport-Typ für Verbindungen
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransportType {
@@ -617,18 +715,70 @@ pub enum TransportType {
 }
 
 /// NAT-Status mit Details
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct NatStatus {
     /// NAT-Typ
     pub nat_type: NatType,
     /// Externe Adresse (falls bekannt)
     pub external_addr: Option<Multiaddr>,
-    /// Confidence Level
+    /// Confidence Level (0.0 = unbekannt, 1.0 = maximal bestätigt)
     pub confidence: f32,
 }
 
+impl NatStatus {
+    /// Leite Status aus dem AutoNAT-Behaviour ab
+    ///
+    /// AutoNAT zählt bestätigende Probes bis `confidence_max`; der erste
+    /// bestätigte Status entspricht Confidence 0. Für `Unknown` ist die
+    /// Confidence immer 0.0.
+    fn from_autonat(behaviour: &autonat::Behaviour) -> Self {
+        let status = behaviour.nat_status();
+        let nat_type = match &status {
+            autonat::NatStatus::Public(_) => NatType::Public,
+            autonat::NatStatus::Private => NatType::Symmetric,
+            autonat::NatStatus::Unknown => NatType::Unknown,
+        };
+        let external_addr = match status {
+            autonat::NatStatus::Public(addr) => Some(addr),
+            _ => None,
+        };
+        let confidence = if nat_type == NatType::Unknown {
+            0.0
+        } else {
+            (behaviour.confidence().min(AUTONAT_CONFIDENCE_MAX) + 1) as f32
+                / (AUTONAT_CONFIDENCE_MAX + 1) as f32
+        };
+
+        Self {
+            nat_type,
+            external_addr,
+            confidence,
+        }
+    }
+
+    /// Name für `SwarmObserver::on_nat_status_changed`
+    fn observer_name(&self) -> &'static str {
+        match self.nat_type {
+            NatType::Public => "public",
+            NatType::Cone | NatType::Symmetric => "private",
+            NatType::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for NatStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.nat_type)?;
+        if let Some(addr) = &self.external_addr {
+            write!(f, " ({})", addr)?;
+        }
+        write!(f, " [confidence {:.2}]", self.confidence)
+    }
+}
+
 /// NAT-Typ-Klassifikation
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NatType {
     /// Öffentliche IP
     Public,
@@ -637,11 +787,12 @@ pub enum NatType {
     /// Symmetric NAT (Relay benötigt)
     Symmetric,
     /// Unbekannt
+    #[default]
     Unknown,
 }
 
 /// Periodische Testnet-Statistiken
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct TestnetStats {
     /// Verbundene Peers
     pub connected_peers: usize,
@@ -651,8 +802,14 @@ pub struct TestnetStats {
     pub relay_reservations: usize,
     /// Gossipsub-Mesh-Peers pro Topic
     pub mesh_peers: HashMap<String, usize>,
+    /// Anzahl eigener Gossipsub-Subscriptions
+    pub subscribed_topics: usize,
     /// Kademlia Routing Table Size
     pub routing_table_size: usize,
+    /// Kademlia-Bootstrap abgeschlossen
+    pub kademlia_bootstrapped: bool,
+    /// Aktueller AutoNAT-Status
+    pub nat_status: NatStatus,
     /// Gesendete Nachrichten
     pub messages_sent: u64,
     /// Empfangene Nachrichten
@@ -666,9 +823,9 @@ pub struct TestnetStats {
 
 /// Privacy-Layer Statistiken
 #[cfg(feature = "privacy")]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PrivacyStats {
-    /// Aktive Circuits
+    /// Aktive Circuits (gecachte Onion-Routen + Conflux-Circuits)
     pub active_circuits: usize,
     /// Cached Circuits
     pub cached_circuits: usize,
@@ -676,10 +833,37 @@ pub struct PrivacyStats {
     pub cover_traffic_sent: u64,
     /// Mixing-Pool Größe
     pub mixing_pool_size: usize,
+    /// Über den Privacy-Layer gesendete Nachrichten
+    pub messages_sent: u64,
+    /// Über den Privacy-Layer empfangene Pakete
+    pub messages_received: u64,
     /// Multi-Circuit aktiv
     pub multi_circuit_active: bool,
 }
 
+/// Strukturierter Node-Status (für `/status` und Dashboards)
+#[derive(Debug, Clone, Serialize)]
+pub struct TestnetStatus {
+    /// Eigene Peer-ID
+    pub peer_id: String,
+    /// Node-Rolle
+    pub role: String,
+    /// Lokale Listen-Adressen
+    pub listen_addresses: Vec<Multiaddr>,
+    /// Bestätigte externe Adressen
+    pub external_addresses: Vec<Multiaddr>,
+    /// Live-Metriken aus den Behaviours
+    #[serde(flatten)]
+    pub stats: TestnetStats,
+}
+
+/// Ausgabe-Kanäle des Privacy-Service: (Mixing-Output, Cover-Traffic)
+#[cfg(feature = "privacy")]
+type PrivacyChannels = (
+    mpsc::Receiver<(PeerId, Vec<u8>)>,
+    mpsc::Receiver<CoverMessage>,
+);
+
 // ============================================================================
 // TESTNET SWARM
 // ============================================================================
@@ -697,13 +881,48 @@ pub struct TestnetSwarm {
     stats: Arc<TestnetStatsCounter>,
     /// Privacy-Service (V2.6, feature-gated)
     #[cfg(feature = "privacy")]
-    privacy_service: Option<Arc<tokio::sync::RwLock<PrivacyService>>>,
+    privacy_service: Option<Arc<PrivacyService>>,
+    /// Ausgabe-Kanäle des Privacy-Service (Mixing-Output, Cover-Traffic), von `run()` übernommen
+    #[cfg(feature = "privacy")]
+    privacy_channels: Option<PrivacyChannels>,
+    /// X25519-Public-Key für Onion-Schichten an diesen Node
+    #[cfg(feature = "privacy")]
+    onion_public_key: Option<x25519_dalek::PublicKey>,
+    /// Bekannte Relay-Kandidaten (Route-Auswahl, Hop-Auflösung)
+    #[cfg(feature = "privacy")]
+    relay_candidates: Arc<parking_lot::RwLock<Vec<RelayCandidate>>>,
+    /// Ausgehende Privacy-Pakete (an Peer, Onion-Paket)
+    #[cfg(feature = "privacy")]
+    packet_tx: mpsc::UnboundedSender<(PeerId, Vec<u8>)>,
     /// Multi-Circuit Manager (RL28, feature-gated)
     #[cfg(feature = "privacy")]
-    multi_circuit: Option<Arc<tokio::sync::RwLock<ConfluxManager>>>,
+    multi_circuit: Option<Arc<ConfluxManager>>,
     /// Circuit Cache (RL23, feature-gated)
     #[cfg(feature = "privacy")]
     circuit_cache: Option<Arc<CircuitCache>>,
+    /// Empfänger für ausgehende Privacy-Pakete (von `run()` übernommen)
+    packet_rx: Option<mpsc::UnboundedReceiver<(PeerId, Vec<u8>)>>,
+    /// Spiegelung der Live-Metriken in einen `UnifiedState` (optional)
+    state: Option<Arc<StateIntegrator>>,
+    /// Letzter bekannter AutoNAT-Status
+    nat_status: NatStatus,
+    /// Kademlia-Bootstrap abgeschlossen
+    kademlia_bootstrapped: bool,
+    /// Hintergrund-Tasks (werden beim Shutdown abgebrochen)
+    background_tasks: Vec<tokio::task::JoinHandle<()>>,
+    /// Kommando-Kanal (Sender für externe Steuerung)
+    command_tx: mpsc::Sender<TestnetCommand>,
+    /// Kommando-Kanal (Receiver, wird von `run()` übernommen)
+    command_rx: Option<mpsc::Receiver<TestnetCommand>>,
+    /// Verzögerte Publishes (simulierte Latenz)
+    delayed_tx: mpsc::UnboundedSender<(String, Vec<u8>)>,
+    delayed_rx: Option<mpsc::UnboundedReceiver<(String, Vec<u8>)>>,
+    /// Blockierte Peers (simulierte Partition)
+    blocked_peers: HashSet<PeerId>,
+    /// Simulierte Link-Bedingungen für ausgehende Nachrichten
+    conditions: Option<NetworkConditions>,
+    /// RNG für Packet-Loss (deterministisch bei gesetztem `rng_seed`)
+    rng: StdRng,
 }
 
 /// Thread-safe Statistik-Counter
@@ -773,30 +992,53 @@ impl TestnetSwarm {
         let swarm = Swarm::new(combined_transport, behaviour, peer_id, swarm_config);
 
         let (event_tx, event_rx) = broadcast::channel(256);
+        let (command_tx, command_rx) = mpsc::channel(256);
+        let (delayed_tx, delayed_rx) = mpsc::unbounded_channel();
+        let rng = match testnet_config.rng_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
 
         // Privacy-Service initialisieren (V2.6)
+        // Der Onion-Schlüssel wird aus der Node-Identität abgeleitet, damit
+        // Relay-Kandidaten ihn aus dem Keypair reproduzieren können.
         #[cfg(feature = "privacy")]
-        let privacy_service = if testnet_config.enable_privacy && testnet_config.p2p.privacy.enabled
-        {
-            let privacy_config = if testnet_config.role.is_relay() {
-                PrivacyServiceConfig::for_relay()
+        let (privacy_service, privacy_channels, onion_public_key) =
+            if testnet_config.enable_privacy && testnet_config.p2p.privacy.enabled {
+                let privacy_config = if testnet_config.role.is_relay() {
+                    PrivacyServiceConfig::for_relay()
+                } else {
+                    PrivacyServiceConfig::default()
+                };
+                let (mut service, output_rx, cover_rx) = PrivacyService::new(privacy_config);
+                let onion_secret = keypair
+                    .derive_secret(ONION_KEY_DOMAIN)
+                    .map(x25519_dalek::StaticSecret::from);
+                let onion_public_key = onion_secret.as_ref().map(x25519_dalek::PublicKey::from);
+                if let Some(secret) = onion_secret {
+                    service = service.with_decryptor(OnionDecryptor::new(secret));
+                }
+                (
+                    Some(Arc::new(service)),
+                    Some((output_rx, cover_rx)),
+                    onion_public_key,
+                )
             } else {
-                PrivacyServiceConfig::default()
+                (None, None, None)
             };
-            Some(Arc::new(tokio::sync::RwLock::new(PrivacyService::new(
-                privacy_config,
-            ))))
-        } else {
-            None
+
+        #[cfg(feature = "privacy")]
+        let (packet_tx, packet_rx) = {
+            let (tx, rx) = mpsc::unbounded_channel();
+            (tx, Some(rx))
         };
+        #[cfg(not(feature = "privacy"))]
+        let packet_rx = None;
 
         // Multi-Circuit Manager initialisieren (RL28)
         #[cfg(feature = "privacy")]
         let multi_circuit = if testnet_config.enable_multi_circuit {
-            let conflux_config = ConfluxConfig::default();
-            Some(Arc::new(tokio::sync::RwLock::new(ConfluxManager::new(
-                conflux_config,
-            ))))
+            Some(Arc::new(ConfluxManager::new(ConfluxConfig::default())))
         } else {
             None
         };
@@ -821,9 +1063,29 @@ impl TestnetSwarm {
                 #[cfg(feature = "privacy")]
                 privacy_service,
                 #[cfg(feature = "privacy")]
+                privacy_channels,
+                #[cfg(feature = "privacy")]
+                onion_public_key,
+                #[cfg(feature = "privacy")]
+                relay_candidates: Arc::new(parking_lot::RwLock::new(Vec::new())),
+                #[cfg(feature = "privacy")]
+                packet_tx,
+                #[cfg(feature = "privacy")]
                 multi_circuit,
                 #[cfg(feature = "privacy")]
                 circuit_cache,
+                packet_rx,
+                state: None,
+                nat_status: NatStatus::default(),
+                kademlia_bootstrapped: false,
+                background_tasks: Vec::new(),
+                command_tx,
+                command_rx: Some(command_rx),
+                delayed_tx,
+                delayed_rx: Some(delayed_rx),
+                blocked_peers: HashSet::new(),
+                conditions: None,
+                rng,
             },
             event_rx,
         ))
@@ -849,6 +1111,21 @@ impl TestnetSwarm {
         self.event_tx.subscribe()
     }
 
+    /// Kommando-Sender für die Steuerung des laufenden Swarms
+    pub fn command_sender(&self) -> mpsc::Sender<TestnetCommand> {
+        self.command_tx.clone()
+    }
+
+    /// Live-Metriken in einen `UnifiedState` spiegeln (`P2PState`)
+    ///
+    /// Events laufen über die P2P-Observer des `StateIntegrator`; absolute
+    /// Werte (Mesh, Routing-Table, Circuits, Mixing-Pool) werden alle
+    /// `state_sync_interval` abgeglichen.
+    pub fn with_state(mut self, state: SharedUnifiedState) -> Self {
+        self.state = Some(Arc::new(StateIntegrator::new(state)));
+        self
+    }
+
     /// Swarm starten und Event-Loop ausführen (V2.6 Extended)
     pub async fn run(&mut self) -> Result<()> {
         let config = &self.config.p2p;
@@ -930,18 +1207,22 @@ impl TestnetSwarm {
 
         // Stats-Timer für periodische Statistiken
         let mut stats_interval = tokio::time::interval(Duration::from_secs(30));
+        let mut state_sync_interval = tokio::time::interval(self.config.state_sync_interval);
+
+        let mut command_rx = self
+            .command_rx
+            .take()
+            .ok_or_else(|| anyhow!("Testnet swarm is already running"))?;
+        let mut delayed_rx = self
+            .delayed_rx
+            .take()
+            .ok_or_else(|| anyhow!("Testnet swarm is already running"))?;
+
+        let mut packet_rx = self.packet_rx.take();
 
         // Privacy-Layer Background Tasks starten (V2.6)
         #[cfg(feature = "privacy")]
-        if let Some(ref privacy_service) = self.privacy_service {
-            let ps = privacy_service.clone();
-            let event_tx = self.event_tx.clone();
-            tokio::spawn(async move {
-                // Privacy-Service Background Loop
-                // (Mixing-Pool Flush, Cover-Traffic, Compliance-Check)
-                tracing::debug!("Privacy-Service background tasks started");
-            });
-        }
+        self.start_privacy_tasks();
 
         // Event-Loop
         loop {
@@ -949,11 +1230,143 @@ impl TestnetSwarm {
                 event = self.swarm.select_next_some() => {
                     self.handle_swarm_event(event);
                 }
+                Some(command) = command_rx.recv() => {
+                    if !self.handle_command(command) {
+                        tracing::info!(peer_id = %self.peer_id, "🛑 Testnet swarm shutting down");
+                        self.stop_background_tasks();
+                        return Ok(());
+                    }
+                }
+                Some((topic, data)) = delayed_rx.recv() => {
+                    if let Err(e) = self.publish(&topic, data) {
+                        tracing::debug!(topic = %topic, error = %e, "Delayed publish failed");
+                    }
+                }
+                Some((peer_id, packet)) = recv_packet(&mut packet_rx) => {
+                    self.send_privacy_packet(peer_id, packet);
+                }
                 _ = stats_interval.tick() => {
                     self.emit_stats();
                 }
+                _ = state_sync_interval.tick(), if self.state.is_some() => {
+                    self.sync_state();
+                }
+            }
+        }
+    }
+
+    /// Hintergrund-Tasks (Privacy-Service, Kanal-Pumpen) abbrechen
+    fn stop_background_tasks(&mut self) {
+        #[cfg(feature = "privacy")]
+        if let Some(service) = &self.privacy_service {
+            service.stop();
+        }
+        for task in self.background_tasks.drain(..) {
+            task.abort();
+        }
+    }
+
+    /// Verarbeite Steuer-Kommando. Gibt `false` zurück, wenn der Loop enden soll.
+    fn handle_command(&mut self, command: TestnetCommand) -> bool {
+        match command {
+            TestnetCommand::Publish { topic, data } => self.publish_with_conditions(topic, data),
+            TestnetCommand::Dial { addr } => {
+                if let Err(e) = self.swarm.dial(addr.clone()) {
+                    tracing::debug!(addr = %addr, error = %e, "Dial failed");
+                }
+            }
+            TestnetCommand::BlockPeer { peer_id } => {
+                self.blocked_peers.insert(peer_id);
+                let _ = self.swarm.disconnect_peer_id(peer_id);
+                self.swarm
+                    .behaviour_mut()
+                    .gossipsub
+                    .remove_explicit_peer(&peer_id);
+                self.swarm.behaviour_mut().kademlia.remove_peer(&peer_id);
+            }
+            TestnetCommand::UnblockPeer { peer_id } => {
+                self.blocked_peers.remove(&peer_id);
+            }
+            TestnetCommand::SetConditions { conditions } => {
+                self.conditions = conditions;
+            }
+            TestnetCommand::GetStats { response } => {
+                let _ = response.send(self.collect_stats());
+            }
+            TestnetCommand::GetListenAddresses { response } => {
+                let _ = response.send(self.swarm.listeners().cloned().collect());
+            }
+            TestnetCommand::GetStatus { response } => {
+                let _ = response.send(self.status());
+            }
+            #[cfg(feature = "privacy")]
+            TestnetCommand::UpdateRelayCandidates { candidates } => {
+                self.update_relay_candidates(candidates);
+            }
+            #[cfg(feature = "privacy")]
+            TestnetCommand::SendPrivate {
+                destination,
+                data,
+                sensitivity,
+                response,
+            } => {
+                let send = self.private_send(destination, data, sensitivity);
+                tokio::spawn(async move {
+                    let _ = response.send(send.await);
+                });
+            }
+            #[cfg(feature = "privacy")]
+            TestnetCommand::SendMultiCircuit {
+                destination,
+                data,
+                sensitivity,
+                response,
+            } => {
+                let send = self.multi_circuit_send(destination, data, sensitivity);
+                tokio::spawn(async move {
+                    let _ = response.send(send.await);
+                });
+            }
+            TestnetCommand::Shutdown => return false,
+        }
+        true
+    }
+
+    /// Publish unter simulierten Link-Bedingungen
+    ///
+    /// Packet-Loss verwirft die Nachricht, Latenz (RTT/2) plus
+    /// Serialisierungszeit über `bandwidth_kbps` verzögert sie.
+    fn publish_with_conditions(&mut self, topic: String, data: Vec<u8>) {
+        let Some(conditions) = &self.conditions else {
+            if let Err(e) = self.publish(&topic, data) {
+                tracing::debug!(topic = %topic, error = %e, "Publish failed");
+            }
+            return;
+        };
+
+        if conditions.packet_loss > 0.0 && self.rng.gen::<f32>() < conditions.packet_loss {
+            tracing::trace!(topic = %topic, "Simulated packet loss");
+            return;
+        }
+
+        let transfer_ms = if conditions.bandwidth_kbps > 0 {
+            (data.len() as u64 * 8) / conditions.bandwidth_kbps as u64
+        } else {
+            0
+        };
+        let delay = Duration::from_millis(conditions.latency_ms as u64 / 2 + transfer_ms);
+        if delay.is_zero() {
+            if let Err(e) = self.publish(&topic, data) {
+                tracing::debug!(topic = %topic, error = %e, "Publish failed");
             }
+            return;
         }
+
+        let delayed_tx = self.delayed_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let _ = delayed_tx.send((topic, data));
+        });
     }
 
     /// Handle SwarmEvent (V2.6)
@@ -965,8 +1378,20 @@ impl TestnetSwarm {
             SwarmEvent::ConnectionEstablished {
                 peer_id, endpoint, ..
             } => {
+                if self.blocked_peers.contains(&peer_id) {
+                    tracing::debug!(peer_id = %peer_id, "⛔ Rejecting blocked peer");
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                    return;
+                }
                 let is_inbound = endpoint.is_listener();
                 let transport = self.detect_transport_type(&endpoint);
+                self.observe(|s| {
+                    s.on_peer_connected(
+                        &peer_id.to_string(),
+                        is_inbound,
+                        transport == TransportType::Relay,
+                    )
+                });
 
                 tracing::info!(
                     peer_id = %peer_id,
@@ -981,8 +1406,16 @@ impl TestnetSwarm {
                     transport,
                 });
             }
-            SwarmEvent::ConnectionClosed { peer_id, cause, .. } => {
+            SwarmEvent::ConnectionClosed {
+                peer_id,
+                cause,
+                num_established,
+                ..
+            } => {
                 tracing::info!(peer_id = %peer_id, cause = ?cause, "🔌 Connection closed");
+                if num_established == 0 {
+                    self.observe(|s| s.on_peer_disconnected(&peer_id.to_string()));
+                }
                 let _ = self
                     .event_tx
                     .send(TestnetEvent::PeerDisconnected { peer_id });
@@ -1007,12 +1440,14 @@ impl TestnetSwarm {
                     error = %error,
                     "❌ Outgoing connection error"
                 );
+                self.observe(|s| s.on_connection_error(&error.to_string()));
                 let _ = self
                     .event_tx
                     .send(TestnetEvent::ConnectionError { peer_id });
             }
             SwarmEvent::ExternalAddrConfirmed { address } => {
                 tracing::info!(addr = %address, "✅ External address confirmed");
+                self.observe(|s| s.on_external_address(&address.to_string()));
                 let _ = self.event_tx.send(TestnetEvent::ExternalAddressConfirmed {
                     address: address.clone(),
                 });
@@ -1043,21 +1478,88 @@ impl TestnetSwarm {
         }
     }
 
-    /// Emittiere periodische Statistiken
-    fn emit_stats(&self) {
-        let stats = TestnetStats {
+    /// Aktuelle Statistiken aus den Live-Behaviours sammeln
+    fn collect_stats(&mut self) -> TestnetStats {
+        let gossipsub = &self.swarm.behaviour().gossipsub;
+        let mesh_peers = gossipsub
+            .topics()
+            .map(|topic| (topic.to_string(), gossipsub.mesh_peers(topic).count()))
+            .collect();
+
+        let subscribed_topics = gossipsub.topics().count();
+
+        let routing_table_size = self.routing_table_size();
+
+        TestnetStats {
             connected_peers: self.swarm.connected_peers().count(),
             active_relay_circuits: self.stats.relay_circuits.load(Ordering::Relaxed) as usize,
             relay_reservations: self.stats.relay_reservations.load(Ordering::Relaxed) as usize,
-            mesh_peers: HashMap::new(), // TODO: Fill from gossipsub
-            routing_table_size: 0,      // TODO: Get from kademlia
+            mesh_peers,
+            subscribed_topics,
+            routing_table_size,
+            kademlia_bootstrapped: self.kademlia_bootstrapped,
+            nat_status: self.nat_status.clone(),
             messages_sent: self.stats.messages_sent.load(Ordering::Relaxed),
             messages_received: self.stats.messages_received.load(Ordering::Relaxed),
             #[cfg(feature = "privacy")]
             privacy_stats: self.get_privacy_stats(),
             uptime_secs: self.started_at.elapsed().as_secs(),
+        }
+    }
+
+    /// Anzahl Einträge über alle Kademlia-Buckets
+    fn routing_table_size(&mut self) -> usize {
+        self.swarm
+            .behaviour_mut()
+            .kademlia
+            .kbuckets()
+            .map(|bucket| bucket.num_entries())
+            .sum()
+    }
+
+    /// Strukturierter Node-Status
+    pub fn status(&mut self) -> TestnetStatus {
+        TestnetStatus {
+            peer_id: self.peer_id.to_string(),
+            role: self.config.role.name().to_string(),
+            listen_addresses: self.swarm.listeners().cloned().collect(),
+            external_addresses: self.swarm.external_addresses().cloned().collect(),
+            stats: self.collect_stats(),
+        }
+    }
+
+    /// Absolute Metriken in den angehängten `UnifiedState` übernehmen
+    fn sync_state(&mut self) {
+        if self.state.is_none() {
+            return;
+        }
+        let stats = self.collect_stats();
+        let Some(state) = &self.state else {
+            return;
         };
 
+        state.on_peer_count(stats.connected_peers);
+        state.on_mesh_status(stats.mesh_peers.values().sum(), stats.subscribed_topics);
+        state.on_routing_table_update(stats.routing_table_size);
+        state.on_circuits_active(stats.active_relay_circuits);
+
+        #[cfg(feature = "privacy")]
+        if let Some(privacy) = &stats.privacy_stats {
+            state.on_pool_status(privacy.active_circuits, privacy.mixing_pool_size);
+        }
+    }
+
+    /// Observer-Aufruf, falls ein `UnifiedState` angehängt ist
+    fn observe(&self, f: impl FnOnce(&StateIntegrator)) {
+        if let Some(state) = &self.state {
+            f(state);
+        }
+    }
+
+    /// Emittiere periodische Statistiken
+    fn emit_stats(&mut self) {
+        let stats = self.collect_stats();
+
         if self.config.enable_metrics {
             tracing::info!(
                 peers = stats.connected_peers,
@@ -1077,12 +1579,27 @@ impl TestnetSwarm {
             return None;
         }
 
+        let service = self.privacy_service.as_ref().map(|ps| ps.stats());
+        let conflux_circuits = self
+            .multi_circuit
+            .as_ref()
+            .map(|mc| mc.circuit_count())
+            .unwrap_or(0);
+        let cached_circuits = self
+            .circuit_cache
+            .as_ref()
+            .map(|c| c.stats().circuits_by_level.iter().sum())
+            .unwrap_or(0);
+
         Some(PrivacyStats {
-            active_circuits: 0, // TODO: Get from privacy_service
-            cached_circuits: self.circuit_cache.as_ref().map(|c| c.len()).unwrap_or(0),
+            active_circuits: service.as_ref().map(|s| s.cached_routes).unwrap_or(0)
+                + conflux_circuits,
+            cached_circuits,
             cover_traffic_sent: self.stats.cover_traffic_sent.load(Ordering::Relaxed),
-            mixing_pool_size: 0, // TODO: Get from privacy_service
-            multi_circuit_active: self.multi_circuit.is_some(),
+            mixing_pool_size: service.as_ref().map(|s| s.mixing.buffer_size).unwrap_or(0),
+            messages_sent: service.as_ref().map(|s| s.messages_sent).unwrap_or(0),
+            messages_received: service.as_ref().map(|s| s.messages_received).unwrap_or(0),
+            multi_circuit_active: conflux_circuits > 0,
         })
     }
 
@@ -1103,7 +1620,79 @@ impl TestnetSwarm {
             TestnetBehaviourEvent::RelayServer(event) => self.handle_relay_server_event(event),
             TestnetBehaviourEvent::Upnp(event) => self.handle_upnp_event(event),
             TestnetBehaviourEvent::Ping(event) => self.handle_ping_event(event),
-            TestnetBehaviourEvent::RequestResponse(_) => {} // Handled separately
+            TestnetBehaviourEvent::RequestResponse(event) => {
+                self.handle_request_response_event(event)
+            }
+        }
+    }
+
+    fn handle_request_response_event(&mut self, event: request_response::Event<Vec<u8>, Vec<u8>>) {
+        match event {
+            request_response::Event::Message {
+                peer,
+                message:
+                    request_response::Message::Request {
+                        request, channel, ..
+                    },
+                ..
+            } => {
+                let response = match SyncRequest::from_bytes(&request) {
+                    Ok(SyncRequest::PrivacyPacket { packet }) => {
+                        self.handle_privacy_packet(peer, packet)
+                    }
+                    Ok(SyncRequest::Ping { timestamp, .. }) => SyncResponse::Pong {
+                        timestamp,
+                        server_timestamp: std::time::SystemTime::now()
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .map(|d| d.as_millis() as u64)
+                            .unwrap_or(0),
+                        responder_id: None,
+                    },
+                    Ok(_) => SyncResponse::error(
+                        error_codes::NOT_SUPPORTED,
+                        "Request not supported by testnet node",
+                    ),
+                    Err(e) => SyncResponse::error(error_codes::INVALID_REQUEST, e.to_string()),
+                };
+                let bytes = response.to_bytes().unwrap_or_default();
+                if self
+                    .swarm
+                    .behaviour_mut()
+                    .request_response
+                    .send_response(channel, bytes)
+                    .is_err()
+                {
+                    tracing::debug!(peer_id = %peer, "Response channel closed");
+                }
+            }
+            request_response::Event::Message {
+                peer,
+                message: request_response::Message::Response { response, .. },
+                ..
+            } => {
+                if let Ok(SyncResponse::Error { code, message }) =
+                    SyncResponse::from_bytes(&response)
+                {
+                    tracing::debug!(peer_id = %peer, code = code, message = %message, "Request rejected by peer");
+                }
+            }
+            request_response::Event::OutboundFailure { peer, error, .. } => {
+                tracing::debug!(peer_id = %peer, error = %error, "Outbound request failed");
+            }
+            _ => {}
+        }
+    }
+
+    /// Onion-Paket per Request-Response an den nächsten Hop senden
+    fn send_privacy_packet(&mut self, peer_id: PeerId, packet: Vec<u8>) {
+        match (SyncRequest::PrivacyPacket { packet }).to_bytes() {
+            Ok(bytes) => {
+                self.swarm
+                    .behaviour_mut()
+                    .request_response
+                    .send_request(&peer_id, bytes);
+            }
+            Err(e) => tracing::warn!(error = %e, "Failed to encode privacy packet"),
         }
     }
 
@@ -1150,7 +1739,13 @@ impl TestnetSwarm {
                 propagation_source,
                 ..
             } => {
+                if self.blocked_peers.contains(&propagation_source) {
+                    return;
+                }
                 self.stats.messages_received.fetch_add(1, Ordering::Relaxed);
+                self.observe(|s| {
+                    s.on_message_received(message.topic.as_str(), &propagation_source.to_string())
+                });
 
                 // Erkenne Privacy-Layer-Nachrichten
                 let is_private = message.topic.to_string().contains("/private/")
@@ -1197,6 +1792,9 @@ impl TestnetSwarm {
                 {
                     if num_remaining == 0 {
                         tracing::info!("🎉 Kademlia bootstrap complete!");
+                        self.kademlia_bootstrapped = true;
+                        let size = self.routing_table_size();
+                        self.observe(|s| s.on_bootstrap_complete(size));
                         let _ = self.event_tx.send(TestnetEvent::KademliaBootstrapComplete);
                     }
                 }
@@ -1210,6 +1808,8 @@ impl TestnetSwarm {
                 // bucket_range.1 ist Distance::MAX der Bucket, wir approximieren die Bucket-Größe
                 let bucket_idx = bucket_range.1.ilog2().unwrap_or(0) as usize;
                 tracing::debug!(peer_id = %peer, is_new = is_new_peer, bucket = bucket_idx, "Kademlia routing updated");
+                let size = self.routing_table_size();
+                self.observe(|s| s.on_routing_table_update(size));
                 let _ = self.event_tx.send(TestnetEvent::KademliaRoutingUpdate {
                     peer_id: peer,
                     bucket_size: bucket_idx,
@@ -1256,7 +1856,10 @@ impl TestnetSwarm {
         }
     }
 
-    fn handle_autonat_event(&self, event: autonat::Event) {
+    fn handle_autonat_event(&mut self, event: autonat::Event) {
+        // Confidence ändert sich auch ohne Statuswechsel (bestätigende Probes)
+        self.nat_status = NatStatus::from_autonat(&self.swarm.behaviour().autonat);
+
         match event {
             autonat::Event::InboundProbe(probe) => {
                 if self.config.verbose_nat_logging {
@@ -1275,24 +1878,11 @@ impl TestnetSwarm {
                     "🌐 AutoNAT status changed"
                 );
 
-                let nat_type = match &new {
-                    autonat::NatStatus::Public(_) => NatType::Public,
-                    autonat::NatStatus::Private => NatType::Symmetric,
-                    autonat::NatStatus::Unknown => NatType::Unknown,
-                };
-
-                let external_addr = match &new {
-                    autonat::NatStatus::Public(addr) => Some(addr.clone()),
-                    _ => None,
-                };
-
-                let _ = self.event_tx.send(TestnetEvent::AutoNatStatus {
-                    nat_status: NatStatus {
-                        nat_type,
-                        external_addr,
-                        confidence: 0.8, // TODO: Get from AutoNAT
-                    },
-                });
+                let nat_status = self.nat_status.clone();
+                self.observe(|s| s.on_nat_status_changed(nat_status.observer_name()));
+                let _ = self
+                    .event_tx
+                    .send(TestnetEvent::AutoNatStatus { nat_status });
             }
         }
     }
@@ -1311,6 +1901,7 @@ impl TestnetSwarm {
                     connection = ?connection_id,
                     "✅ DCUTR: Direct connection established (holepunching success)!"
                 );
+                self.observe(|s| s.on_dcutr_attempt(true));
                 let _ = self
                     .event_tx
                     .send(TestnetEvent::DirectConnectionEstablished {
@@ -1323,6 +1914,7 @@ impl TestnetSwarm {
                     error = ?error,
                     "❌ DCUTR: Direct connection upgrade failed"
                 );
+                self.observe(|s| s.on_dcutr_attempt(false));
                 let _ = self.event_tx.send(TestnetEvent::DirectConnectionFailed {
                     peer_id: remote_peer_id,
                 });
@@ -1342,6 +1934,12 @@ impl TestnetSwarm {
                     renewal = renewal,
                     "🔄 Relay reservation accepted!"
                 );
+                if !renewal {
+                    self.stats
+                        .relay_reservations
+                        .fetch_add(1, Ordering::Relaxed);
+                }
+                self.observe(|s| s.on_reservation_accepted(&relay_peer_id.to_string()));
                 let _ = self.event_tx.send(TestnetEvent::RelayReservation {
                     relay_peer: relay_peer_id,
                 });
@@ -1399,7 +1997,8 @@ impl TestnetSwarm {
                     dst = %dst_peer_id,
                     "📡 Relay server: Circuit accepted - NOW SERVING!"
                 );
-                // WICHTIG: Sende Event für Statistik
+                self.stats.relay_circuits.fetch_add(1, Ordering::Relaxed);
+                self.observe(|s| s.on_circuit_opened());
                 let _ = self.event_tx.send(TestnetEvent::RelayCircuitOpened {
                     src_peer_id,
                     dst_peer_id,
@@ -1426,7 +2025,12 @@ impl TestnetSwarm {
                     error = ?error,
                     "📡 Relay server: Circuit closed"
                 );
-                // WICHTIG: Sende Event für Statistik
+                let _ = self.stats.relay_circuits.fetch_update(
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                    |v| Some(v.saturating_sub(1)),
+                );
+                self.observe(RelayObserver::on_circuit_closed);
                 let _ = self.event_tx.send(TestnetEvent::RelayCircuitClosed {
                     src_peer_id,
                     dst_peer_id,
@@ -1518,6 +2122,9 @@ impl TestnetSwarm {
         match event.result {
             Ok(rtt) => {
                 let rtt_ms = rtt.as_millis() as u64;
+                self.observe(|s| {
+                    s.on_latency_measured(&event.peer.to_string(), rtt.as_micros() as u64)
+                });
                 tracing::trace!(
                     peer_id = %event.peer,
                     rtt_ms = rtt_ms,
@@ -1569,13 +2176,13 @@ impl TestnetSwarm {
 
     /// Nachricht über Gossipsub veröffentlichen
     pub fn publish(&mut self, topic: &str, data: Vec<u8>) -> Result<()> {
-        let topic = gossipsub::IdentTopic::new(topic);
         self.swarm
             .behaviour_mut()
             .gossipsub
-            .publish(topic, data)
+            .publish(gossipsub::IdentTopic::new(topic), data)
             .map_err(|e| anyhow!("Publish failed: {:?}", e))?;
         self.stats.messages_sent.fetch_add(1, Ordering::Relaxed);
+        self.observe(|s| s.on_message_sent(topic));
         Ok(())
     }
 
@@ -1644,40 +2251,202 @@ impl TestnetSwarm {
     // ========================================================================
 
     /// Privacy-Nachricht senden (V2.6)
+    ///
+    /// Onion-verschlüsselt über eine Route aus den Relay-Kandidaten und
+    /// anschließend über den Mixing-Pool an den ersten Hop übergeben.
     #[cfg(feature = "privacy")]
-    pub async fn send_private(&self, destination: PeerId, data: Vec<u8>) -> Result<()> {
-        if let Some(ref privacy_service) = self.privacy_service {
-            let mut ps = privacy_service.write().await;
-            // TODO: Implement actual privacy message sending
-            tracing::info!(dest = %destination, "Sending privacy message");
-            Ok(())
-        } else {
-            Err(anyhow!("Privacy-Layer not enabled"))
-        }
+    pub async fn send_private(
+        &self,
+        destination: PeerId,
+        data: Vec<u8>,
+        sensitivity: SensitivityLevel,
+    ) -> Result<()> {
+        self.private_send(destination, data, sensitivity).await
     }
 
     /// Multi-Circuit Nachricht senden (RL28)
+    ///
+    /// Conflux wählt Strategie und Circuits nach Sensitivität, die Zustellung
+    /// läuft über den Privacy-Service.
     #[cfg(feature = "privacy")]
     pub async fn send_multi_circuit(
         &self,
         destination: PeerId,
         data: Vec<u8>,
-        sensitivity: &str,
+        sensitivity: SensitivityLevel,
     ) -> Result<()> {
-        if let Some(ref multi_circuit) = self.multi_circuit {
-            let mc = multi_circuit.read().await;
-            // TODO: Implement multi-circuit transmission
-            tracing::info!(
-                dest = %destination,
-                sensitivity = sensitivity,
-                "Sending via multi-circuit"
-            );
+        self.multi_circuit_send(destination, data, sensitivity)
+            .await
+    }
+
+    /// Relay-Kandidaten für Route-Auswahl und Hop-Auflösung setzen
+    #[cfg(feature = "privacy")]
+    pub fn update_relay_candidates(&self, candidates: Vec<RelayCandidate>) {
+        if let Some(multi_circuit) = &self.multi_circuit {
+            multi_circuit.set_candidates(candidates.clone());
+        }
+        *self.relay_candidates.write() = candidates;
+    }
+
+    /// X25519-Public-Key, mit dem Onion-Schichten für diesen Node verschlüsselt werden
+    #[cfg(feature = "privacy")]
+    pub fn onion_public_key(&self) -> Option<x25519_dalek::PublicKey> {
+        self.onion_public_key
+    }
+
+    /// Privacy-Send als eigenständiges Future (auch aus dem Event-Loop spawnbar)
+    #[cfg(feature = "privacy")]
+    fn private_send(
+        &self,
+        destination: PeerId,
+        data: Vec<u8>,
+        sensitivity: SensitivityLevel,
+    ) -> impl std::future::Future<Output = Result<()>> + Send + 'static {
+        let service = self.privacy_service.clone();
+        let candidates = self.relay_candidates.read().clone();
+        let state = self.state.clone();
+
+        async move {
+            let service = service.ok_or_else(|| anyhow!("Privacy-Layer not enabled"))?;
+            service
+                .send_message(destination, data, sensitivity, &candidates)
+                .await
+                .map_err(|e| anyhow!("Privacy send failed: {}", e))?;
+            if let Some(state) = state {
+                state.on_private_message();
+            }
+            tracing::debug!(dest = %destination, sensitivity = ?sensitivity, "🧅 Privacy message queued");
+            Ok(())
+        }
+    }
+
+    /// Multi-Circuit-Send als eigenständiges Future
+    #[cfg(feature = "privacy")]
+    fn multi_circuit_send(
+        &self,
+        destination: PeerId,
+        data: Vec<u8>,
+        sensitivity: SensitivityLevel,
+    ) -> impl std::future::Future<Output = Result<()>> + Send + 'static {
+        let multi_circuit = self.multi_circuit.clone();
+        let event_tx = self.event_tx.clone();
+        let send = self.private_send(destination, data.clone(), sensitivity);
+
+        async move {
+            let multi_circuit =
+                multi_circuit.ok_or_else(|| anyhow!("Multi-Circuit not enabled"))?;
+            let result = multi_circuit
+                .multi_path_send(&data, sensitivity)
+                .await
+                .map_err(|e| anyhow!("Multi-circuit send failed: {}", e))?;
+            send.await?;
+
+            let _ = event_tx.send(TestnetEvent::MultiCircuitEstablished {
+                circuit_count: result.circuits_used,
+                strategy: format!("{:?}", result.strategy),
+            });
             Ok(())
-        } else {
-            Err(anyhow!("Multi-Circuit not enabled"))
         }
     }
 
+    /// Privacy-Service-Tasks starten und Ausgabe-Kanäle an den Swarm koppeln
+    #[cfg(feature = "privacy")]
+    fn start_privacy_tasks(&mut self) {
+        let Some(service) = self.privacy_service.clone() else {
+            return;
+        };
+
+        // Mixing-Pool Flush, Cover-Traffic, Route-Cleanup, Compliance-Check
+        let candidates = self.relay_candidates.clone();
+        let background = service.clone();
+        self.background_tasks.push(tokio::spawn(async move {
+            let route_provider = move || candidates.read().iter().map(|c| c.peer_id).collect();
+            if let Err(e) = background.run_background_tasks(route_provider).await {
+                tracing::error!(error = %e, "Privacy service background tasks failed");
+            }
+        }));
+
+        let Some((mut output_rx, mut cover_rx)) = self.privacy_channels.take() else {
+            return;
+        };
+
+        // Mixing-Pool-Output → Event-Loop (Request-Response an ersten Hop)
+        let packet_tx = self.packet_tx.clone();
+        self.background_tasks.push(tokio::spawn(async move {
+            while let Some(packet) = output_rx.recv().await {
+                if packet_tx.send(packet).is_err() {
+                    break;
+                }
+            }
+        }));
+
+        // Cover-Traffic → erster Hop der Dummy-Route (RL10, RL18)
+        let packet_tx = self.packet_tx.clone();
+        let stats = self.stats.clone();
+        let state = self.state.clone();
+        self.background_tasks.push(tokio::spawn(async move {
+            while let Some(cover) = cover_rx.recv().await {
+                let Some(first_hop) = cover.route.first().copied() else {
+                    continue;
+                };
+                stats.cover_traffic_sent.fetch_add(1, Ordering::Relaxed);
+                if let Some(state) = &state {
+                    state.on_cover_traffic();
+                }
+                if packet_tx.send((first_hop, cover.payload)).is_err() {
+                    break;
+                }
+            }
+        }));
+
+        tracing::debug!("Privacy-Service background tasks started");
+    }
+
+    /// Eingehendes Onion-Paket: Schicht entfernen, weiterleiten oder zustellen
+    #[cfg(feature = "privacy")]
+    fn handle_privacy_packet(&self, source: PeerId, packet: Vec<u8>) -> SyncResponse {
+        let Some(service) = self.privacy_service.clone() else {
+            return SyncResponse::error(error_codes::NOT_SUPPORTED, "Privacy-Layer not enabled");
+        };
+        let candidates = self.relay_candidates.clone();
+        let packet_tx = self.packet_tx.clone();
+        let event_tx = self.event_tx.clone();
+
+        tokio::spawn(async move {
+            match service.process_incoming(source, packet).await {
+                Ok(ProcessingResult::FinalDestination { payload }) => {
+                    let _ = event_tx.send(TestnetEvent::PrivacyMessageReceived { payload });
+                }
+                Ok(ProcessingResult::Forwarded {
+                    next_relay,
+                    payload,
+                }) => {
+                    let next_hop = candidates
+                        .read()
+                        .iter()
+                        .find(|c| c.public_key == next_relay)
+                        .map(|c| c.peer_id);
+                    match next_hop {
+                        Some(peer_id) => {
+                            let _ = packet_tx.send((peer_id, payload));
+                        }
+                        None => tracing::debug!("Unknown next hop, dropping privacy packet"),
+                    }
+                }
+                Err(e) => {
+                    tracing::debug!(source = %source, error = %e, "Privacy packet rejected");
+                }
+            }
+        });
+
+        SyncResponse::PacketAccepted
+    }
+
+    #[cfg(not(feature = "privacy"))]
+    fn handle_privacy_packet(&self, _source: PeerId, _packet: Vec<u8>) -> SyncResponse {
+        SyncResponse::error(error_codes::NOT_SUPPORTED, "Privacy-Layer not enabled")
+    }
+
     /// Privacy-Statistiken abrufen (V2.6)
     #[cfg(feature = "privacy")]
     pub async fn privacy_stats(&self) -> Option<PrivacyStats> {
@@ -1700,6 +2469,16 @@ fn extract_peer_id(addr: &Multiaddr) -> Option<PeerId> {
     })
 }
 
+/// Nächstes ausgehendes Privacy-Paket (blockiert dauerhaft ohne Privacy-Layer)
+async fn recv_packet(
+    rx: &mut Option<mpsc::UnboundedReceiver<(PeerId, Vec<u8>)>>,
+) -> Option<(PeerId, Vec<u8>)> {
+    match rx {
+        Some(rx) => rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
 // ============================================================================
 // BUILDER PATTERN (V2.6)
 // ============================================================================
@@ -1792,6 +2571,18 @@ impl TestnetSwarmBuilder {
         self
     }
 
+    /// mDNS aktivieren/deaktivieren
+    pub fn with_mdns(mut self, enabled: bool) -> Self {
+        self.config.p2p.enable_mdns = enabled;
+        self
+    }
+
+    /// Seed für simulierte Link-Bedingungen setzen
+    pub fn rng_seed(mut self, seed: u64) -> Self {
+        self.config.rng_seed = Some(seed);
+        self
+    }
+
     /// Swarm bauen
     pub fn build(self) -> Result<(TestnetSwarm, broadcast::Receiver<TestnetEvent>)> {
         let keypair = self.keypair.unwrap_or_else(Keypair::generate_ed25519);
@@ -1867,6 +2658,42 @@ mod tests {
         assert_eq!(nat_status.nat_type, NatType::Cone);
     }
 
+    #[test]
+    fn test_nat_status_default_and_names() {
+        let status = NatStatus::default();
+        assert_eq!(status.nat_type, NatType::Unknown);
+        assert_eq!(status.confidence, 0.0);
+        assert_eq!(status.observer_name(), "unknown");
+
+        let public = NatStatus {
+            nat_type: NatType::Public,
+            external_addr: None,
+            confidence: 1.0,
+        };
+        assert_eq!(public.observer_name(), "public");
+    }
+
+    #[test]
+    fn test_testnet_status_serialization_flattens_stats() {
+        let status = TestnetStatus {
+            peer_id: PeerId::random().to_string(),
+            role: "relay".to_string(),
+            listen_addresses: vec!["/ip4/127.0.0.1/tcp/4001".parse().unwrap()],
+            external_addresses: vec![],
+            stats: TestnetStats {
+                connected_peers: 3,
+                kademlia_bootstrapped: true,
+                ..Default::default()
+            },
+        };
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["role"], "relay");
+        assert_eq!(json["connected_peers"], 3);
+        assert_eq!(json["kademlia_bootstrapped"], true);
+        assert_eq!(json["nat_status"]["nat_type"], "unknown");
+    }
+
     #[test]
     fn test_transport_type() {
         assert_eq!(TransportType::Quic, TransportType::Quic);
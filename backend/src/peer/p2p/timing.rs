@@ -86,6 +86,11 @@ impl NetworkConditions {
 
     /// Aktualisiere Bedingungen mit exponentieller Glättung
     pub fn update_smoothed(&mut self, new: &NetworkConditions, alpha: f32) {
+        self.update_smoothed_at(new, alpha, Instant::now());
+    }
+
+    /// Wie [`update_smoothed`](Self::update_smoothed), gemessen zum Zeitpunkt `now`
+    pub fn update_smoothed_at(&mut self, new: &NetworkConditions, alpha: f32, now: Instant) {
         let alpha = alpha.clamp(0.0, 1.0);
         self.latency_ms =
             ((1.0 - alpha) * self.latency_ms as f32 + alpha * new.latency_ms as f32) as u32;
@@ -93,13 +98,18 @@ impl NetworkConditions {
         self.bandwidth_kbps =
             ((1.0 - alpha) * self.bandwidth_kbps as f32 + alpha * new.bandwidth_kbps as f32) as u32;
         self.peer_count = new.peer_count; // Peer-Count direkt übernehmen
-        self.last_updated = Some(Instant::now());
+        self.last_updated = Some(now);
     }
 
     /// Prüfe ob Bedingungen veraltet sind
     pub fn is_stale(&self, max_age: Duration) -> bool {
+        self.is_stale_at(max_age, Instant::now())
+    }
+
+    /// Prüfe ob Bedingungen zum Zeitpunkt `now` veraltet sind
+    pub fn is_stale_at(&self, max_age: Duration, now: Instant) -> bool {
         match self.last_updated {
-            Some(t) => t.elapsed() > max_age,
+            Some(t) => now.saturating_duration_since(t) > max_age,
             None => true,
         }
     }
@@ -211,23 +221,38 @@ impl SyncTiming {
 
     /// Markiere erfolgreichen Sync
     pub fn mark_synced(&mut self) {
-        self.last_sync = Some(Instant::now());
+        self.mark_synced_at(Instant::now());
+    }
+
+    /// Markiere erfolgreichen Sync zum Zeitpunkt `now`
+    pub fn mark_synced_at(&mut self, now: Instant) {
+        self.last_sync = Some(now);
         self.reset_backoff();
     }
 
     /// Prüfe ob Sync fällig ist
     pub fn should_sync(&self) -> bool {
+        self.should_sync_at(Instant::now())
+    }
+
+    /// Prüfe ob Sync zum Zeitpunkt `now` fällig ist
+    pub fn should_sync_at(&self, now: Instant) -> bool {
         match self.last_sync {
-            Some(t) => t.elapsed() >= self.current_interval,
+            Some(t) => now.saturating_duration_since(t) >= self.current_interval,
             None => true,
         }
     }
 
     /// Zeit bis zum nächsten Sync
     pub fn time_until_sync(&self) -> Duration {
+        self.time_until_sync_at(Instant::now())
+    }
+
+    /// Zeit von `now` bis zum nächsten Sync
+    pub fn time_until_sync_at(&self, now: Instant) -> Duration {
         match self.last_sync {
             Some(t) => {
-                let elapsed = t.elapsed();
+                let elapsed = now.saturating_duration_since(t);
                 if elapsed >= self.current_interval {
                     Duration::ZERO
                 } else {
@@ -294,7 +319,12 @@ impl Default for TimingManager {
 impl TimingManager {
     /// Aktualisiere Netzwerk-Bedingungen und passe alle Timings an
     pub fn update_conditions(&mut self, conditions: NetworkConditions) {
-        self.conditions.update_smoothed(&conditions, 0.3);
+        self.update_conditions_at(conditions, Instant::now());
+    }
+
+    /// Wie [`update_conditions`](Self::update_conditions), gemessen zum Zeitpunkt `now`
+    pub fn update_conditions_at(&mut self, conditions: NetworkConditions, now: Instant) {
+        self.conditions.update_smoothed_at(&conditions, 0.3, now);
         self.adjust_all();
     }
 
@@ -466,6 +496,27 @@ mod tests {
         assert!(timing.should_sync());
     }
 
+    #[test]
+    fn test_sync_timing_at_explicit_time() {
+        use crate::peer::p2p::clock::{Clock, SimClock};
+
+        let clock = SimClock::new();
+        let mut timing = SyncTiming::new(Duration::from_secs(10));
+
+        timing.mark_synced_at(clock.now());
+        assert!(!timing.should_sync_at(clock.now()));
+
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(
+            timing.time_until_sync_at(clock.now()),
+            Duration::from_secs(6)
+        );
+
+        clock.advance(Duration::from_secs(6));
+        assert!(timing.should_sync_at(clock.now()));
+        assert_eq!(timing.time_until_sync_at(clock.now()), Duration::ZERO);
+    }
+
     #[test]
     fn test_timing_manager() {
         let mut manager = TimingManager::default();
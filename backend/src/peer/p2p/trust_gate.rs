@@ -40,8 +40,8 @@ use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 /// Unterstützt nun Lookups sowohl über `PeerId` als auch `UniversalId`.
 /// Emittiert StateEvents für Trust-Änderungen und Banning (v0.4.0).
 pub struct TrustGate {
-    /// Konfiguration
-    config: TrustGateConfig,
+    /// Konfiguration (zur Laufzeit änderbar, siehe [`TrustGate::set_min_incoming_trust`])
+    config: RwLock<TrustGateConfig>,
 
     /// Bekannte Peers (PeerId → PeerTrustInfo)
     known_peers: RwLock<HashMap<PeerId, PeerTrustInfo>>,
@@ -226,7 +226,7 @@ impl TrustGate {
     /// Erstelle neuen TrustGate
     pub fn new(config: TrustGateConfig) -> Self {
         Self {
-            config,
+            config: RwLock::new(config),
             known_peers: RwLock::new(HashMap::new()),
             universal_id_to_peer: RwLock::new(HashMap::new()),
             banned_peers: RwLock::new(HashMap::new()),
@@ -256,6 +256,19 @@ impl TrustGate {
         self.state_event_emitter = emitter;
     }
 
+    /// Aktuelle Konfiguration
+    pub fn config(&self) -> TrustGateConfig {
+        self.config.read().clone()
+    }
+
+    /// Minimum Trust-R für eingehende Verbindungen ändern (Config-Reload)
+    ///
+    /// Gilt für die nächste Verbindungs-Entscheidung; bestehende
+    /// Verbindungen bleiben erhalten.
+    pub fn set_min_incoming_trust(&self, min_trust_r: f64) {
+        self.config.write().min_incoming_trust_r = min_trust_r;
+    }
+
     /// Statistiken: Trust-Updates, Bans, Connection-Decisions (v0.4.0)
     pub fn event_counts(&self) -> (u64, u64, u64) {
         (
@@ -299,7 +312,7 @@ impl TrustGate {
                     .duration_since(UNIX_EPOCH)
                     .unwrap()
                     .as_secs();
-                if now - since < self.config.newcomer_grace_period.as_secs() {
+                if now - since < self.config.read().newcomer_grace_period.as_secs() {
                     return ConnectionDecision {
                         allowed: true,
                         level: ConnectionLevel::Limited,
@@ -330,7 +343,7 @@ impl TrustGate {
 
     /// Entscheidung für unbekannten Peer
     fn decide_for_unknown_peer(&self, _peer_id: &PeerId) -> ConnectionDecision {
-        if self.config.reject_unknown_peers {
+        if self.config.read().reject_unknown_peers {
             return ConnectionDecision {
                 allowed: false,
                 level: ConnectionLevel::Blocked,
@@ -350,13 +363,13 @@ impl TrustGate {
 
     /// Konvertiere Trust-Werte zu ConnectionLevel
     fn trust_to_level(&self, trust_r: f64, trust_omega: f64) -> ConnectionLevel {
-        if trust_r < self.config.min_incoming_trust_r {
+        if trust_r < self.config.read().min_incoming_trust_r {
             return ConnectionLevel::Blocked;
         }
 
         if trust_r >= 0.9 && trust_omega >= 2.0 {
             ConnectionLevel::Trusted
-        } else if trust_r >= 0.7 && trust_omega >= self.config.min_relay_trust_omega {
+        } else if trust_r >= 0.7 && trust_omega >= self.config.read().min_relay_trust_omega {
             ConnectionLevel::Full
         } else if trust_r >= 0.5 {
             ConnectionLevel::Standard
@@ -375,7 +388,7 @@ impl TrustGate {
         }
 
         // Prüfe Freshness
-        let max_age = self.config.newcomer_grace_period.as_secs() * 2;
+        let max_age = self.config.read().newcomer_grace_period.as_secs() * 2;
         if !signed_info.is_valid(max_age) {
             return Err(anyhow!("Peer info is too old"));
         }
@@ -677,7 +690,7 @@ impl TrustGate {
         }
 
         // Unbekannte UniversalId
-        if self.config.reject_unknown_peers {
+        if self.config.read().reject_unknown_peers {
             ConnectionDecision {
                 allowed: false,
                 level: ConnectionLevel::Blocked,
@@ -874,6 +887,16 @@ mod tests {
         assert_eq!(gate.trust_to_level(0.95, 2.5), ConnectionLevel::Trusted);
     }
 
+    #[test]
+    fn test_set_min_incoming_trust() {
+        let gate = TrustGate::new(test_config());
+        assert_eq!(gate.trust_to_level(0.3, 0.2), ConnectionLevel::Limited);
+
+        gate.set_min_incoming_trust(0.4);
+        assert_eq!(gate.config().min_incoming_trust_r, 0.4);
+        assert_eq!(gate.trust_to_level(0.3, 0.2), ConnectionLevel::Blocked);
+    }
+
     #[test]
     fn test_ban_peer() {
         let gate = TrustGate::new(test_config());
@@ -0,0 +1,1434 @@
+//! # Query-Sprache für `Goal::Query`
+//!
+//! Typisierte, rein lesende Abfragen über die lokalen Daten des Knotens.
+//!
+//! ## Grammatik
+//!
+//! ```text
+//! query     := source ["where" condition ("and" condition)*] ("|" stage)*
+//! source    := "events" | "trust" | "balances"
+//!            | "members" "(" realm ")"
+//!            | "records" "(" realm "," store ["," owner] ")"
+//! condition := field op operand
+//! op        := "=" | "!=" | ">" | ">=" | "<" | "<=" | "in" | "contains"
+//! operand   := literal | "[" literal ("," literal)* "]" | "members" "(" realm ")"
+//! literal   := number | duration | "\"" text "\"" | did | word | true | false | null
+//! duration  := number ("s" | "m" | "h" | "d" | "w")        → Sekunden
+//! stage     := "select" field ("," field)*
+//!            | "count" ["by" field]
+//!            | ("sum" | "avg" | "min" | "max") field
+//!            | "top" n "by" field ["asc" | "desc"]
+//!            | "limit" n
+//! ```
+//!
+//! Beispiel – welche Mitglieder von Realm X haben Y in der letzten Woche attestiert:
+//!
+//! ```text
+//! events where type = attest and subject = did:erynoa:self:… and
+//!     author in members(X) and age <= 7d | count by author
+//! ```
+//!
+//! Alternativ kann das Prädikat die JSON-Form von [`Query`] sein.
+//!
+//! ## Quellen
+//!
+//! | Quelle     | Store          | Felder                                          |
+//! |------------|----------------|-------------------------------------------------|
+//! | `events`   | `EventStore`   | id, type, author, lamport, time, age, Payload   |
+//! | `trust`    | `TrustStore`   | from, to, r, i, c, p, v, omega, norm, age       |
+//! | `members`  | `UnifiedState` | realm, member                                   |
+//! | `records`  | `RealmStorage` | key, owner, Felder des Werts                    |
+//! | `balances` | `Ledger`       | holder, asset_type, balance                     |
+//!
+//! IDs erscheinen als Hex; DID-Literale werden beim Parsen normalisiert.
+//! `trust` braucht einen Filter auf `from` oder `to`, `balances` auf `holder`.
+//! Persönliche Stores sind nur für ihren Besitzer lesbar.
+//!
+//! ## Metering
+//!
+//! Jede gelesene Zeile kostet Gas ([`gas_costs::STORAGE_READ`], Trust-Kanten
+//! [`gas_costs::TRUST_LOOKUP`]), jede Pipeline-Stufe [`gas_costs::HASH_COMPUTE`]
+//! pro Zeile; das Ergebnis kostet Mana pro angefangenem KB. Abgerechnet wird
+//! über den [`ExecutionContext`].
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::core::SharedUnifiedState;
+use crate::domain::unified::{
+    realm_id_from_name, Cost, EventPayload, Goal, Intent, RealmId, UniversalId, ROOT_REALM_ID,
+};
+use crate::execution::{
+    gas_costs, mana_costs, ExecutionContext, ExecutionError, TrustContext, WorldState,
+};
+use crate::local::{DecentralizedStorage, LedgerAccount, StoreValue, StoredEvent};
+use crate::peer::intent_grammar::parse_did_uri;
+use crate::peer::saga_executor::{stored_did, subject_did};
+
+// ============================================================================
+// Fehler
+// ============================================================================
+
+/// Fehler beim Parsen oder Ausführen einer Query
+#[derive(Debug, Error)]
+pub enum QueryError {
+    #[error("Query syntax error at {position}: {message}")]
+    Syntax { message: String, position: usize },
+
+    #[error("Query needs a filter on {0}")]
+    MissingFilter(String),
+
+    #[error("Invalid query: {0}")]
+    Invalid(String),
+
+    #[error("Privacy violation: {0}")]
+    Privacy(String),
+
+    #[error("Unknown realm: {0}")]
+    UnknownRealm(String),
+
+    #[error("Intent is not a query")]
+    NotAQuery,
+
+    #[error("Execution error: {0}")]
+    Execution(#[from] ExecutionError),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] anyhow::Error),
+}
+
+/// Ergebnis von Query-Operationen
+pub type QueryResult<T> = Result<T, QueryError>;
+
+// ============================================================================
+// AST
+// ============================================================================
+
+/// Datenquelle einer Query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QuerySource {
+    /// Events aus dem DAG
+    Events,
+    /// Trust-Kanten
+    Trust,
+    /// Ledger-Salden
+    Balances,
+    /// Mitglieder eines Realms
+    Members { realm: String },
+    /// Einträge eines Realm-Stores
+    Records {
+        realm: String,
+        store: String,
+        /// Besitzer eines persönlichen Stores (Standard: Aufrufer)
+        #[serde(default)]
+        owner: Option<String>,
+    },
+}
+
+/// Vergleichsoperator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    In,
+    Contains,
+}
+
+/// Rechte Seite einer Bedingung
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operand {
+    /// Literal (Zahl, String, Liste, …)
+    Value(Value),
+    /// Mitgliederliste eines Realms (als Hex-IDs)
+    Members(String),
+}
+
+/// Filterbedingung `field op operand`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    /// Feldpfad (`.` für verschachtelte Felder)
+    pub field: String,
+    /// Operator
+    pub op: CompareOp,
+    /// Vergleichswert
+    pub value: Operand,
+}
+
+/// Pipeline-Stufe (Projektion oder Aggregat)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum Stage {
+    Select {
+        fields: Vec<String>,
+    },
+    Count {
+        #[serde(default)]
+        by: Option<String>,
+    },
+    Sum {
+        field: String,
+    },
+    Avg {
+        field: String,
+    },
+    Min {
+        field: String,
+    },
+    Max {
+        field: String,
+    },
+    Top {
+        n: usize,
+        by: String,
+        #[serde(default)]
+        ascending: bool,
+    },
+    Limit {
+        n: usize,
+    },
+}
+
+/// Typisierte Query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Query {
+    /// Datenquelle
+    pub source: QuerySource,
+    /// Filter (UND-verknüpft)
+    #[serde(default)]
+    pub filters: Vec<Condition>,
+    /// Projektionen und Aggregate in Reihenfolge
+    #[serde(default)]
+    pub stages: Vec<Stage>,
+}
+
+impl Query {
+    /// Parst ein Prädikat (Query-Sprache oder JSON)
+    pub fn parse(text: &str) -> QueryResult<Self> {
+        let trimmed = text.trim();
+        if trimmed.starts_with('{') {
+            return serde_json::from_str(trimmed).map_err(|e| QueryError::Syntax {
+                message: e.to_string(),
+                position: e.column().saturating_sub(1),
+            });
+        }
+        Parser::new(text)?.parse_query()
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Word(String),
+    Str(String),
+    Sym(&'static str),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    pos: usize,
+}
+
+const SYMBOLS: &[&str] = &[
+    "==", "!=", ">=", "<=", "=", ">", "<", "|", "(", ")", "[", "]", ",",
+];
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '.' | ':' | '-' | '#')
+}
+
+fn syntax(message: impl Into<String>, position: usize) -> QueryError {
+    QueryError::Syntax {
+        message: message.into(),
+        position,
+    }
+}
+
+fn lex(text: &str) -> QueryResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some(&(pos, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            let mut s = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, ch)) => s.push(ch),
+                    None => return Err(syntax("unterminated string", pos)),
+                }
+            }
+            tokens.push(Token {
+                tok: Tok::Str(s),
+                pos,
+            });
+        } else if is_word_char(c) {
+            let mut end = pos;
+            while let Some(&(i, ch)) = chars.peek() {
+                if !is_word_char(ch) {
+                    break;
+                }
+                end = i + ch.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token {
+                tok: Tok::Word(text[pos..end].to_string()),
+                pos,
+            });
+        } else {
+            let symbol = SYMBOLS
+                .iter()
+                .find(|s| text[pos..].starts_with(**s))
+                .ok_or_else(|| syntax(format!("unexpected character `{c}`"), pos))?;
+            for _ in 0..symbol.len() {
+                chars.next();
+            }
+            tokens.push(Token {
+                tok: Tok::Sym(symbol),
+                pos,
+            });
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn new(text: &str) -> QueryResult<Self> {
+        Ok(Self {
+            tokens: lex(text)?,
+            pos: 0,
+            end: text.len(),
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn here(&self) -> usize {
+        self.peek().map(|t| t.pos).unwrap_or(self.end)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat_word(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token {
+                tok: Tok::Word(w), ..
+            }) if w.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn eat_sym(&mut self, symbol: &str) -> bool {
+        match self.peek() {
+            Some(Token {
+                tok: Tok::Sym(s), ..
+            }) if *s == symbol => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_sym(&mut self, symbol: &str) -> QueryResult<()> {
+        if self.eat_sym(symbol) {
+            Ok(())
+        } else {
+            Err(syntax(format!("expected `{symbol}`"), self.here()))
+        }
+    }
+
+    /// Wort oder String (Feldnamen, Realms, Stores)
+    fn name(&mut self, what: &str) -> QueryResult<String> {
+        let position = self.here();
+        match self.next() {
+            Some(Token {
+                tok: Tok::Word(w) | Tok::Str(w),
+                ..
+            }) => Ok(w),
+            _ => Err(syntax(format!("expected {what}"), position)),
+        }
+    }
+
+    fn number(&mut self, what: &str) -> QueryResult<usize> {
+        let position = self.here();
+        self.name(what)?
+            .parse()
+            .map_err(|_| syntax(format!("expected {what}"), position))
+    }
+
+    fn parse_query(&mut self) -> QueryResult<Query> {
+        let source = self.parse_source()?;
+
+        let mut filters = Vec::new();
+        if self.eat_word("where") {
+            loop {
+                filters.push(self.parse_condition()?);
+                if !self.eat_word("and") {
+                    break;
+                }
+            }
+        }
+
+        let mut stages = Vec::new();
+        while self.eat_sym("|") {
+            stages.push(self.parse_stage()?);
+        }
+
+        if self.peek().is_some() {
+            return Err(syntax(
+                "unexpected input; expected `where`, `and` or `|`",
+                self.here(),
+            ));
+        }
+        Ok(Query {
+            source,
+            filters,
+            stages,
+        })
+    }
+
+    fn parse_source(&mut self) -> QueryResult<QuerySource> {
+        let position = self.here();
+        let word = self.name("a source")?.to_lowercase();
+        match word.as_str() {
+            "events" => Ok(QuerySource::Events),
+            "trust" => Ok(QuerySource::Trust),
+            "balances" => Ok(QuerySource::Balances),
+            "members" => {
+                self.expect_sym("(")?;
+                let realm = self.name("a realm")?;
+                self.expect_sym(")")?;
+                Ok(QuerySource::Members { realm })
+            }
+            "records" => {
+                self.expect_sym("(")?;
+                let realm = self.name("a realm")?;
+                self.expect_sym(",")?;
+                let store = self.name("a store name")?;
+                let owner = if self.eat_sym(",") {
+                    Some(self.name("an owner DID")?)
+                } else {
+                    None
+                };
+                self.expect_sym(")")?;
+                Ok(QuerySource::Records {
+                    realm,
+                    store,
+                    owner,
+                })
+            }
+            other => Err(syntax(
+                format!(
+                    "unknown source `{other}`; expected events, trust, balances, members or records"
+                ),
+                position,
+            )),
+        }
+    }
+
+    fn parse_condition(&mut self) -> QueryResult<Condition> {
+        let field = self.name("a field")?;
+        let position = self.here();
+        let op = match self.next().map(|t| t.tok) {
+            Some(Tok::Sym("=" | "==")) => CompareOp::Eq,
+            Some(Tok::Sym("!=")) => CompareOp::Ne,
+            Some(Tok::Sym(">")) => CompareOp::Gt,
+            Some(Tok::Sym(">=")) => CompareOp::Ge,
+            Some(Tok::Sym("<")) => CompareOp::Lt,
+            Some(Tok::Sym("<=")) => CompareOp::Le,
+            Some(Tok::Word(w)) if w.eq_ignore_ascii_case("in") => CompareOp::In,
+            Some(Tok::Word(w)) if w.eq_ignore_ascii_case("contains") => CompareOp::Contains,
+            _ => return Err(syntax("expected a comparison operator", position)),
+        };
+        let value = self.parse_operand()?;
+        Ok(Condition { field, op, value })
+    }
+
+    fn parse_operand(&mut self) -> QueryResult<Operand> {
+        if self.eat_word("members") {
+            self.expect_sym("(")?;
+            let realm = self.name("a realm")?;
+            self.expect_sym(")")?;
+            return Ok(Operand::Members(realm));
+        }
+        if self.eat_sym("[") {
+            let mut items = Vec::new();
+            if !self.eat_sym("]") {
+                loop {
+                    items.push(self.parse_literal()?);
+                    if self.eat_sym("]") {
+                        break;
+                    }
+                    self.expect_sym(",")?;
+                }
+            }
+            return Ok(Operand::Value(Value::Array(items)));
+        }
+        self.parse_literal().map(Operand::Value)
+    }
+
+    fn parse_literal(&mut self) -> QueryResult<Value> {
+        let position = self.here();
+        match self.next().map(|t| t.tok) {
+            Some(Tok::Str(s)) => Ok(Value::String(s)),
+            Some(Tok::Word(w)) => literal(&w).map_err(|message| syntax(message, position)),
+            _ => Err(syntax("expected a value", position)),
+        }
+    }
+
+    fn parse_stage(&mut self) -> QueryResult<Stage> {
+        let position = self.here();
+        let word = self.name("a stage")?.to_lowercase();
+        let stage = match word.as_str() {
+            "select" => {
+                let mut fields = vec![self.name("a field")?];
+                while self.eat_sym(",") {
+                    fields.push(self.name("a field")?);
+                }
+                Stage::Select { fields }
+            }
+            "count" => {
+                let by = if self.eat_word("by") {
+                    Some(self.name("a field")?)
+                } else {
+                    None
+                };
+                Stage::Count { by }
+            }
+            "sum" => Stage::Sum {
+                field: self.name("a field")?,
+            },
+            "avg" => Stage::Avg {
+                field: self.name("a field")?,
+            },
+            "min" => Stage::Min {
+                field: self.name("a field")?,
+            },
+            "max" => Stage::Max {
+                field: self.name("a field")?,
+            },
+            "top" => {
+                let n = self.number("a row count")?;
+                if !self.eat_word("by") {
+                    return Err(syntax("expected `by`", self.here()));
+                }
+                let by = self.name("a field")?;
+                let ascending = self.eat_word("asc");
+                if !ascending {
+                    self.eat_word("desc");
+                }
+                Stage::Top { n, by, ascending }
+            }
+            "limit" => Stage::Limit {
+                n: self.number("a row count")?,
+            },
+            other => {
+                return Err(syntax(
+                    format!(
+                        "unknown stage `{other}`; expected select, count, sum, avg, min, max, top or limit"
+                    ),
+                    position,
+                ))
+            }
+        };
+        Ok(stage)
+    }
+}
+
+/// Literal aus einem Wort: Bool, Null, Hex-ID, DID, Dauer, Zahl oder Text
+fn literal(word: &str) -> Result<Value, String> {
+    match word {
+        "true" => return Ok(Value::Bool(true)),
+        "false" => return Ok(Value::Bool(false)),
+        "null" => return Ok(Value::Null),
+        _ => {}
+    }
+    if word.len() == 64 && word.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Ok(Value::String(word.to_lowercase()));
+    }
+    if word.starts_with("did:") {
+        return parse_did_uri(word).map(|id| Value::String(id.to_hex()));
+    }
+    if let Some(seconds) = duration_seconds(word) {
+        return Ok(json!(seconds));
+    }
+    if let Ok(n) = word.parse::<i64>() {
+        return Ok(json!(n));
+    }
+    if let Ok(n) = word.parse::<f64>() {
+        if n.is_finite() {
+            return Ok(json!(n));
+        }
+    }
+    Ok(Value::String(word.to_string()))
+}
+
+fn duration_seconds(word: &str) -> Option<u64> {
+    let unit = word.chars().last()?;
+    let factor = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 3_600,
+        'd' => 86_400,
+        'w' => 604_800,
+        _ => return None,
+    };
+    let amount: u64 = word[..word.len() - 1].parse().ok()?;
+    amount.checked_mul(factor)
+}
+
+// ============================================================================
+// QueryEngine
+// ============================================================================
+
+/// Konfiguration der QueryEngine
+#[derive(Debug, Clone)]
+pub struct QueryConfig {
+    /// Maximal gelesene Zeilen ohne Index (Events, Records)
+    pub max_scan: usize,
+    /// Maximal zurückgegebene Zeilen
+    pub max_rows: usize,
+}
+
+impl Default for QueryConfig {
+    fn default() -> Self {
+        Self {
+            max_scan: 10_000,
+            max_rows: 1_000,
+        }
+    }
+}
+
+/// Ergebnis einer Query
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryOutput {
+    /// Ergebniszeilen
+    pub rows: Vec<Value>,
+    /// Gelesene Zeilen
+    pub scanned: usize,
+    /// Wurde das Ergebnis auf `max_rows` gekürzt?
+    pub truncated: bool,
+    /// Verbrauchtes Gas/Mana
+    pub cost: Cost,
+}
+
+/// Führt [`Query`]s gegen EventStore, TrustStore, RealmStorage und Ledger aus
+#[derive(Clone)]
+pub struct QueryEngine {
+    storage: DecentralizedStorage,
+    unified_state: Option<SharedUnifiedState>,
+    config: QueryConfig,
+}
+
+/// Filter mit aufgelösten `members(..)`-Operanden
+struct Filter {
+    field: String,
+    op: CompareOp,
+    value: Value,
+}
+
+impl QueryEngine {
+    /// Erstelle Engine auf dem gegebenen Storage
+    pub fn new(storage: DecentralizedStorage) -> Self {
+        Self {
+            storage,
+            unified_state: None,
+            config: QueryConfig::default(),
+        }
+    }
+
+    /// Mit UnifiedState (Realm-Mitgliedschaften)
+    pub fn with_unified_state(mut self, state: SharedUnifiedState) -> Self {
+        self.unified_state = Some(state);
+        self
+    }
+
+    /// Mit eigener Konfiguration
+    pub fn with_config(mut self, config: QueryConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Führt das Prädikat eines `Goal::Query`-Intents im Budget des Intents aus
+    pub fn execute_intent(&self, intent: &Intent) -> QueryResult<(Query, QueryOutput)> {
+        let Goal::Query { predicate } = &intent.goal else {
+            return Err(QueryError::NotAQuery);
+        };
+        let query = Query::parse(predicate)?;
+
+        let remaining = intent.budget.remaining();
+        let trust = self
+            .storage
+            .trust
+            .compute_reputation(&stored_did(&self.storage, &intent.source))
+            .unwrap_or_default();
+        let mut ctx = ExecutionContext::new(
+            WorldState::new(0),
+            remaining.gas,
+            remaining.mana,
+            TrustContext::direct(intent.source, trust),
+        );
+        let output = self.execute(&query, &intent.source, &mut ctx)?;
+        Ok((query, output))
+    }
+
+    /// Führt eine Query für `caller` aus und rechnet über `ctx` ab
+    pub fn execute(
+        &self,
+        query: &Query,
+        caller: &UniversalId,
+        ctx: &mut ExecutionContext,
+    ) -> QueryResult<QueryOutput> {
+        let (gas_before, mana_before) = (ctx.gas_used(), ctx.mana_used());
+        let filters = self.resolve_filters(&query.filters, ctx)?;
+
+        let mut rows = match &query.source {
+            QuerySource::Events => self.event_rows(&filters, ctx)?,
+            QuerySource::Trust => self.trust_rows(&filters, ctx)?,
+            QuerySource::Balances => self.balance_rows(&filters, ctx)?,
+            QuerySource::Members { realm } => self.member_rows(realm, ctx)?,
+            QuerySource::Records {
+                realm,
+                store,
+                owner,
+            } => self.record_rows(realm, store, owner.as_deref(), caller, ctx)?,
+        };
+        let scanned = rows.len();
+        rows.retain(|row| filters.iter().all(|f| f.matches(row)));
+
+        for stage in &query.stages {
+            ctx.consume_gas(gas_costs::HASH_COMPUTE.saturating_mul(rows.len().max(1) as u64))?;
+            rows = apply_stage(rows, stage);
+        }
+
+        let truncated = rows.len() > self.config.max_rows;
+        rows.truncate(self.config.max_rows);
+
+        let bytes = serde_json::to_vec(&rows).map(|b| b.len()).unwrap_or(0);
+        let kilobytes = (bytes as u64).div_ceil(1024).max(1);
+        ctx.consume_mana(kilobytes * mana_costs::STORAGE_PER_KB)?;
+
+        Ok(QueryOutput {
+            rows,
+            scanned,
+            truncated,
+            cost: Cost::new(
+                ctx.gas_used() - gas_before,
+                ctx.mana_used() - mana_before,
+                0.0,
+            ),
+        })
+    }
+
+    fn resolve_filters(
+        &self,
+        conditions: &[Condition],
+        ctx: &mut ExecutionContext,
+    ) -> QueryResult<Vec<Filter>> {
+        conditions
+            .iter()
+            .map(|condition| {
+                let value = match &condition.value {
+                    Operand::Value(value) => value.clone(),
+                    Operand::Members(realm) => {
+                        ctx.consume_gas(gas_costs::STORAGE_READ)?;
+                        let members = self.members(realm)?;
+                        Value::Array(
+                            members
+                                .iter()
+                                .map(|id| Value::String(id.to_hex()))
+                                .collect(),
+                        )
+                    }
+                };
+                Ok(Filter {
+                    field: condition.field.clone(),
+                    op: condition.op,
+                    value,
+                })
+            })
+            .collect()
+    }
+
+    // ========================================================================
+    // Quellen
+    // ========================================================================
+
+    fn event_rows(
+        &self,
+        filters: &[Filter],
+        ctx: &mut ExecutionContext,
+    ) -> QueryResult<Vec<Value>> {
+        let events: Vec<StoredEvent> = match index_ids(filters, "author") {
+            Some(authors) => {
+                let mut events = Vec::new();
+                for author in authors {
+                    events.extend(self.storage.events.get_by_subject(&author.to_string())?);
+                }
+                events
+            }
+            None => self.storage.events.get_recent(self.config.max_scan)?,
+        };
+
+        let now = chrono::Utc::now().timestamp();
+        let mut rows = Vec::with_capacity(events.len());
+        for stored in &events {
+            ctx.consume_gas(gas_costs::STORAGE_READ)?;
+            rows.push(event_row(stored, now));
+        }
+        Ok(rows)
+    }
+
+    fn trust_rows(
+        &self,
+        filters: &[Filter],
+        ctx: &mut ExecutionContext,
+    ) -> QueryResult<Vec<Value>> {
+        let edges = if let Some(ids) = index_ids(filters, "from") {
+            let mut edges = Vec::new();
+            for id in ids {
+                edges.extend(
+                    self.storage
+                        .trust
+                        .get_outgoing(&stored_did(&self.storage, &id))?,
+                );
+            }
+            edges
+        } else if let Some(ids) = index_ids(filters, "to") {
+            let mut edges = Vec::new();
+            for id in ids {
+                edges.extend(
+                    self.storage
+                        .trust
+                        .get_incoming(&stored_did(&self.storage, &id))?,
+                );
+            }
+            edges
+        } else {
+            return Err(QueryError::MissingFilter(
+                "`from` or `to` for trust".to_string(),
+            ));
+        };
+
+        let now = chrono::Utc::now().timestamp();
+        let mut rows = Vec::with_capacity(edges.len());
+        for edge in edges {
+            ctx.consume_gas(gas_costs::TRUST_LOOKUP)?;
+            let t = edge.trust;
+            rows.push(json!({
+                "from": edge.from.id.to_hex(),
+                "to": edge.to.id.to_hex(),
+                "r": t.r,
+                "i": t.i,
+                "c": t.c,
+                "p": t.p,
+                "v": t.v,
+                "omega": t.omega,
+                "norm": t.weighted_norm(&[1.0; 6]),
+                "updated_at": edge.updated_at,
+                "age": (now - edge.updated_at).max(0),
+            }));
+        }
+        Ok(rows)
+    }
+
+    fn balance_rows(
+        &self,
+        filters: &[Filter],
+        ctx: &mut ExecutionContext,
+    ) -> QueryResult<Vec<Value>> {
+        let holders = index_ids(filters, "holder")
+            .ok_or_else(|| QueryError::MissingFilter("`holder` for balances".to_string()))?;
+        let mut rows = Vec::new();
+        for holder in holders {
+            ctx.consume_gas(gas_costs::STORAGE_READ)?;
+            let balances = self
+                .storage
+                .ledger
+                .balances(&LedgerAccount::Holder(holder))?;
+            rows.extend(balances.into_iter().map(|(asset_type, balance)| {
+                json!({
+                    "holder": holder.to_hex(),
+                    "asset_type": asset_type,
+                    "balance": balance,
+                })
+            }));
+        }
+        Ok(rows)
+    }
+
+    fn member_rows(&self, realm: &str, ctx: &mut ExecutionContext) -> QueryResult<Vec<Value>> {
+        let members = self.members(realm)?;
+        ctx.consume_gas(gas_costs::STORAGE_READ.saturating_mul(members.len().max(1) as u64))?;
+        Ok(members
+            .into_iter()
+            .map(|id| json!({ "realm": realm, "member": id.to_hex() }))
+            .collect())
+    }
+
+    fn record_rows(
+        &self,
+        realm: &str,
+        store: &str,
+        owner: Option<&str>,
+        caller: &UniversalId,
+        ctx: &mut ExecutionContext,
+    ) -> QueryResult<Vec<Value>> {
+        let realm_id = resolve_realm(realm);
+        let caller_did = subject_did(caller);
+        let schema = self
+            .storage
+            .realm
+            .get_schema(&realm_id, store, Some(&caller_did))?;
+
+        if let Some(owner) = owner {
+            let owner = parse_id(owner)
+                .ok_or_else(|| QueryError::Invalid(format!("invalid owner `{owner}`")))?;
+            if !schema.personal {
+                return Err(QueryError::Invalid(format!(
+                    "store `{store}` is shared and has no owner"
+                )));
+            }
+            if owner != *caller {
+                return Err(QueryError::Privacy(format!(
+                    "personal store `{store}` is only readable by its owner"
+                )));
+            }
+        }
+
+        let entries = self.storage.realm.query_all(
+            &realm_id,
+            &caller_did,
+            store,
+            Some(self.config.max_scan),
+        )?;
+        let mut rows = Vec::with_capacity(entries.len());
+        for (key, value) in entries {
+            ctx.consume_gas(gas_costs::STORAGE_READ)?;
+            let mut row = Map::new();
+            row.insert("key".into(), Value::String(key));
+            if schema.personal {
+                row.insert("owner".into(), Value::String(caller.to_hex()));
+            }
+            match store_value_json(&value) {
+                Value::Object(fields) => row.extend(fields),
+                other => {
+                    row.insert("value".into(), other);
+                }
+            }
+            rows.push(Value::Object(row));
+        }
+        Ok(rows)
+    }
+
+    // ========================================================================
+    // Helpers
+    // ========================================================================
+
+    /// Mitglieder eines Realms (Name, Hex-ID oder `root`)
+    fn members(&self, realm: &str) -> QueryResult<Vec<UniversalId>> {
+        let unknown = || QueryError::UnknownRealm(realm.to_string());
+        let state = self.unified_state.as_ref().ok_or_else(unknown)?;
+        let realms = state.peer.realm.realms.read().map_err(|_| unknown())?;
+        let realm_state = realms
+            .get(realm)
+            .or_else(|| realms.get(&resolve_realm(realm).to_hex()))
+            .ok_or_else(unknown)?;
+        let mut members: Vec<UniversalId> = realm_state
+            .members_by_id
+            .read()
+            .map(|m| m.iter().copied().collect())
+            .unwrap_or_default();
+        members.sort_by_key(|id| id.to_hex());
+        Ok(members)
+    }
+}
+
+impl Filter {
+    fn matches(&self, row: &Value) -> bool {
+        let actual = field(row, &self.field).unwrap_or(&Value::Null);
+        match self.op {
+            CompareOp::Eq => values_equal(actual, &self.value),
+            CompareOp::Ne => !values_equal(actual, &self.value),
+            CompareOp::Gt => compare(actual, &self.value) == Some(Ordering::Greater),
+            CompareOp::Ge => matches!(
+                compare(actual, &self.value),
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            CompareOp::Lt => compare(actual, &self.value) == Some(Ordering::Less),
+            CompareOp::Le => matches!(
+                compare(actual, &self.value),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
+            CompareOp::In => match &self.value {
+                Value::Array(items) => items.iter().any(|item| values_equal(actual, item)),
+                other => values_equal(actual, other),
+            },
+            CompareOp::Contains => match (actual, &self.value) {
+                (Value::String(haystack), Value::String(needle)) => haystack.contains(needle),
+                (Value::Array(items), needle) => items.iter().any(|i| values_equal(i, needle)),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// IDs aus `field = id` oder `field in [ids]` (Index-Lookup statt Scan)
+fn index_ids(filters: &[Filter], name: &str) -> Option<Vec<UniversalId>> {
+    filters
+        .iter()
+        .filter(|f| f.field == name)
+        .find_map(|f| match (&f.op, &f.value) {
+            (CompareOp::Eq, Value::String(s)) => parse_id(s).map(|id| vec![id]),
+            (CompareOp::In, Value::Array(items)) => items
+                .iter()
+                .map(|item| item.as_str().and_then(parse_id))
+                .collect(),
+            _ => None,
+        })
+}
+
+fn parse_id(s: &str) -> Option<UniversalId> {
+    if s.starts_with("did:") {
+        parse_did_uri(s).ok()
+    } else {
+        UniversalId::from_hex(s).ok()
+    }
+}
+
+pub(crate) fn resolve_realm(realm: &str) -> RealmId {
+    if realm.eq_ignore_ascii_case("root") {
+        return ROOT_REALM_ID;
+    }
+    let hex = realm.strip_prefix("realm:").unwrap_or(realm);
+    UniversalId::from_hex(hex).unwrap_or_else(|_| realm_id_from_name(realm))
+}
+
+fn field<'a>(row: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(row, |value, key| value.get(key))
+}
+
+fn values_equal(a: &Value, b: &Value) -> bool {
+    compare(a, b) == Some(Ordering::Equal)
+}
+
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64()?.partial_cmp(&y.as_f64()?),
+        (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+        (Value::Bool(x), Value::Bool(y)) => Some(x.cmp(y)),
+        (x, y) if x == y => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+fn store_value_json(value: &StoreValue) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+fn event_row(stored: &StoredEvent, now: i64) -> Value {
+    let event = &stored.event;
+    let time = (event.coord.wall_time() / 1_000_000) as i64;
+    let mut row = Map::new();
+    row.insert("id".into(), Value::String(event.id.to_hex()));
+    row.insert("type".into(), json!(event.payload.type_tag()));
+    row.insert("author".into(), Value::String(event.author.to_hex()));
+    row.insert("lamport".into(), json!(event.coord.lamport()));
+    row.insert("time".into(), json!(time));
+    row.insert("age".into(), json!((now - time).max(0)));
+    row.insert(
+        "finality".into(),
+        json!(format!("{:?}", stored.finality.level).to_lowercase()),
+    );
+    row.insert("confirmations".into(), json!(stored.confirmations));
+    row.extend(payload_fields(&event.payload));
+    Value::Object(row)
+}
+
+fn payload_fields(payload: &EventPayload) -> Map<String, Value> {
+    let fields = match payload {
+        EventPayload::Genesis { did, .. } => json!({ "subject": did.id.to_hex() }),
+        EventPayload::Transfer {
+            from,
+            to,
+            amount,
+            asset_type,
+        } => json!({
+            "from": from.to_hex(), "to": to.to_hex(),
+            "amount": amount, "asset_type": asset_type,
+        }),
+        EventPayload::Mint {
+            to,
+            amount,
+            asset_type,
+        } => json!({ "to": to.to_hex(), "amount": amount, "asset_type": asset_type }),
+        EventPayload::Burn {
+            from,
+            amount,
+            asset_type,
+        } => json!({ "from": from.to_hex(), "amount": amount, "asset_type": asset_type }),
+        EventPayload::Attest { subject, claim, .. } => {
+            json!({ "subject": subject.to_hex(), "claim": claim })
+        }
+        EventPayload::Delegate {
+            from,
+            to,
+            capabilities,
+            trust_factor,
+        } => json!({
+            "from": from.to_hex(), "to": to.to_hex(),
+            "capabilities": capabilities, "trust_factor": trust_factor,
+        }),
+        EventPayload::DelegationRevoke { delegation_id } => {
+            json!({ "delegation_id": delegation_id.to_hex() })
+        }
+        EventPayload::Proposal {
+            proposer, title, ..
+        } => json!({ "proposer": proposer.to_hex(), "title": title }),
+        EventPayload::Vote {
+            voter,
+            proposal_id,
+            direction,
+            weight,
+        } => json!({
+            "voter": voter.to_hex(), "proposal_id": proposal_id.to_hex(),
+            "direction": direction, "weight": weight,
+        }),
+        EventPayload::SagaStep {
+            saga_id,
+            step_index,
+            action,
+            ..
+        } => json!({ "saga_id": saga_id.to_hex(), "step_index": step_index, "action": action }),
+        EventPayload::Witness {
+            event_id,
+            witness,
+            trust_at_witness,
+        } => json!({
+            "event_id": event_id.to_hex(), "witness": witness.to_hex(),
+            "trust_at_witness": trust_at_witness,
+        }),
+        EventPayload::AnchorConfirm { anchor_system, .. } => {
+            json!({ "anchor_system": anchor_system })
+        }
+        EventPayload::Custom { event_type, .. } => json!({ "event_type": event_type }),
+        EventPayload::CredentialIssue {
+            subject,
+            credential_type,
+            claims,
+        } => json!({
+            "subject": subject.to_hex(), "credential_type": credential_type, "claims": claims,
+        }),
+        EventPayload::CredentialRevoke {
+            credential_id,
+            reason,
+        } => json!({ "credential_id": credential_id.to_hex(), "reason": reason }),
+        EventPayload::TrustUpdate {
+            subject,
+            dimension,
+            delta,
+            reason,
+        } => json!({
+            "subject": subject.to_hex(), "dimension": dimension.symbol().to_string(),
+            "delta": delta, "reason": reason,
+        }),
+    };
+    match fields {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+// ============================================================================
+// Pipeline
+// ============================================================================
+
+fn apply_stage(rows: Vec<Value>, stage: &Stage) -> Vec<Value> {
+    match stage {
+        Stage::Select { fields } => rows
+            .iter()
+            .map(|row| {
+                let projected: Map<String, Value> = fields
+                    .iter()
+                    .map(|f| (f.clone(), field(row, f).cloned().unwrap_or(Value::Null)))
+                    .collect();
+                Value::Object(projected)
+            })
+            .collect(),
+        Stage::Count { by: None } => vec![json!({ "count": rows.len() })],
+        Stage::Count { by: Some(by) } => {
+            let mut groups: Vec<(Value, usize)> = Vec::new();
+            let mut index: HashMap<String, usize> = HashMap::new();
+            for row in &rows {
+                let value = field(row, by).cloned().unwrap_or(Value::Null);
+                let key = value.to_string();
+                match index.get(&key) {
+                    Some(&i) => groups[i].1 += 1,
+                    None => {
+                        index.insert(key, groups.len());
+                        groups.push((value, 1));
+                    }
+                }
+            }
+            groups.sort_by_key(|g| std::cmp::Reverse(g.1));
+            groups
+                .into_iter()
+                .map(|(value, count)| {
+                    let mut row = Map::new();
+                    row.insert(by.clone(), value);
+                    row.insert("count".into(), json!(count));
+                    Value::Object(row)
+                })
+                .collect()
+        }
+        Stage::Sum { field: name } => {
+            vec![json!({ "sum": numbers(&rows, name).sum::<f64>() })]
+        }
+        Stage::Avg { field: name } => {
+            let values: Vec<f64> = numbers(&rows, name).collect();
+            let avg =
+                (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64);
+            vec![json!({ "avg": avg, "n": values.len() })]
+        }
+        Stage::Min { field: name } => {
+            vec![json!({ "min": numbers(&rows, name).reduce(f64::min) })]
+        }
+        Stage::Max { field: name } => {
+            vec![json!({ "max": numbers(&rows, name).reduce(f64::max) })]
+        }
+        Stage::Top { n, by, ascending } => {
+            let mut rows = rows;
+            rows.sort_by(|a, b| {
+                let a = field(a, by).unwrap_or(&Value::Null);
+                let b = field(b, by).unwrap_or(&Value::Null);
+                let ordering = compare(a, b).unwrap_or(Ordering::Equal);
+                if *ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            });
+            rows.truncate(*n);
+            rows
+        }
+        Stage::Limit { n } => {
+            let mut rows = rows;
+            rows.truncate(*n);
+            rows
+        }
+    }
+}
+
+fn numbers<'a>(rows: &'a [Value], name: &'a str) -> impl Iterator<Item = f64> + 'a {
+    rows.iter()
+        .filter_map(move |row| field(row, name).and_then(Value::as_f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UnifiedState;
+    use crate::domain::unified::{DIDNamespace, Event, DID};
+    use crate::local::SchemaFieldType;
+    use crate::local::StoreSchema;
+    use std::sync::Arc;
+
+    fn id(name: &[u8]) -> UniversalId {
+        UniversalId::new(UniversalId::TAG_DID, 1, name)
+    }
+
+    #[test]
+    fn test_parse_pipeline_and_json_form() {
+        let subject = DID::new(DIDNamespace::Self_, b"subject");
+        let text = format!(
+            "events where type = attest and subject = {} and author in members(guild) \
+             and age <= 7d | count by author | top 3 by count",
+            subject.to_uri()
+        );
+        let query = Query::parse(&text).unwrap();
+
+        assert_eq!(query.source, QuerySource::Events);
+        assert_eq!(query.filters.len(), 4);
+        assert_eq!(
+            query.filters[1].value,
+            Operand::Value(Value::String(subject.id.to_hex()))
+        );
+        assert_eq!(query.filters[2].value, Operand::Members("guild".into()));
+        assert_eq!(query.filters[3].value, Operand::Value(json!(604_800)));
+        assert_eq!(
+            query.stages[1],
+            Stage::Top {
+                n: 3,
+                by: "count".into(),
+                ascending: false
+            }
+        );
+
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(Query::parse(&json).unwrap(), query);
+
+        let err = Query::parse("events where type ~ attest").unwrap_err();
+        assert!(matches!(err, QueryError::Syntax { position: 18, .. }));
+        assert!(Query::parse("people").is_err());
+    }
+
+    #[test]
+    fn test_members_attested_subject_last_week() {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let state = Arc::new(UnifiedState::new());
+        let (alice, bob, mallory, subject) = (id(b"alice"), id(b"bob"), id(b"mallory"), id(b"y"));
+        state.peer.realm.register_realm("guild", 0.0, "democratic");
+        {
+            let realms = state.peer.realm.realms.read().unwrap();
+            let guild = realms.get("guild").unwrap();
+            guild.add_member_by_id(alice, None);
+            guild.add_member_by_id(bob, None);
+        }
+
+        // Event-IDs hängen von den Parents ab → Kette bilden
+        let mut parents = vec![];
+        for (lamport, author) in [alice, alice, mallory].into_iter().enumerate() {
+            let event = Event::new(
+                author,
+                parents,
+                EventPayload::Attest {
+                    subject,
+                    claim: "skilled".into(),
+                    evidence_hash: None,
+                },
+                lamport as u32 + 1,
+            );
+            parents = vec![event.id];
+            storage.events.put(event).unwrap();
+        }
+
+        let engine = QueryEngine::new(storage).with_unified_state(state);
+        let query = Query::parse(&format!(
+            "events where type = attest and subject = {} and author in members(guild) \
+             and age <= 7d | count by author",
+            subject.to_hex()
+        ))
+        .unwrap();
+        let mut ctx = ExecutionContext::default_for_testing();
+        let output = engine.execute(&query, &alice, &mut ctx).unwrap();
+
+        assert_eq!(
+            output.rows,
+            vec![json!({ "author": alice.to_hex(), "count": 2 })]
+        );
+        assert!(output.cost.gas > 0 && output.cost.mana > 0);
+        assert_eq!(ctx.gas_used(), output.cost.gas);
+    }
+
+    #[test]
+    fn test_personal_store_only_for_owner() {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let (alice, bob) = (id(b"alice"), id(b"bob"));
+        let realm = realm_id_from_name("guild");
+        let schema = StoreSchema::new("notes", true).with_field("score", SchemaFieldType::Number);
+        storage
+            .realm
+            .create_store(&realm, &subject_did(&alice), schema)
+            .unwrap();
+        for (key, score) in [("a", 3.0), ("b", 7.0)] {
+            let value = StoreValue::Object(HashMap::from([(
+                "score".to_string(),
+                StoreValue::Number(score),
+            )]));
+            storage
+                .realm
+                .put(&realm, &subject_did(&alice), "notes", key, value)
+                .unwrap();
+        }
+        let engine = QueryEngine::new(storage);
+
+        let own = Query::parse("records(guild, notes) | avg score").unwrap();
+        let mut ctx = ExecutionContext::default_for_testing();
+        let output = engine.execute(&own, &alice, &mut ctx).unwrap();
+        assert_eq!(output.rows, vec![json!({ "avg": 5.0, "n": 2 })]);
+
+        let foreign = Query::parse(&format!("records(guild, notes, {})", alice.to_hex())).unwrap();
+        let err = engine.execute(&foreign, &bob, &mut ctx).unwrap_err();
+        assert!(matches!(err, QueryError::Privacy(_)));
+    }
+
+    #[test]
+    fn test_metering_and_required_filters() {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let alice = id(b"alice");
+        let mut parents = vec![];
+        for lamport in 1..=5 {
+            let event = Event::new(
+                alice,
+                parents,
+                EventPayload::Custom {
+                    event_type: "ping".into(),
+                    data: vec![],
+                },
+                lamport,
+            );
+            parents = vec![event.id];
+            storage.events.put(event).unwrap();
+        }
+        let engine = QueryEngine::new(storage);
+
+        let mut ctx = ExecutionContext::minimal();
+        ctx.gas_remaining = gas_costs::STORAGE_READ * 2;
+        let err = engine
+            .execute(&Query::parse("events | count").unwrap(), &alice, &mut ctx)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            QueryError::Execution(ExecutionError::GasExhausted { .. })
+        ));
+
+        let err = engine
+            .execute(
+                &Query::parse("trust").unwrap(),
+                &alice,
+                &mut ExecutionContext::minimal(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, QueryError::MissingFilter(_)));
+    }
+}
@@ -23,11 +23,13 @@
 //! ```
 
 use crate::domain::{
-    Constraint, Goal, Intent, RealmId, Saga, SagaAction, SagaCompensation, SagaStep, UniversalId,
-    DID,
+    Constraint, Cost, CostTable, Goal, Intent, RealmId, Saga, SagaAction, SagaCompensation,
+    SagaStep, UniversalId, DID,
 };
 use thiserror::Error;
 
+use super::query::Query;
+
 /// Fehler bei Saga-Komposition
 #[derive(Debug, Error)]
 pub enum CompositionError {
@@ -90,6 +92,9 @@ pub struct SagaComposerConfig {
 
     /// Maximum Schritte pro Saga
     pub max_steps: usize,
+
+    /// Kosten-Tabelle für `expected_cost` der Schritte
+    pub cost_table: CostTable,
 }
 
 impl Default for SagaComposerConfig {
@@ -98,6 +103,7 @@ impl Default for SagaComposerConfig {
             default_lock_duration: 3600, // 1 Stunde
             auto_compensation: true,
             max_steps: 20,
+            cost_table: CostTable::default(),
         }
     }
 }
@@ -140,6 +146,13 @@ impl SagaComposer {
                 sub_goals,
             } => self.compose_complex(intent.source_did(), description, sub_goals)?,
         };
+        let steps = steps
+            .into_iter()
+            .map(|step| {
+                let cost = step_cost(&step.action, &self.config.cost_table);
+                step.with_expected_cost(cost)
+            })
+            .collect::<Vec<_>>();
 
         // Prüfe Constraints
         self.validate_constraints(&steps, &intent.constraints)?;
@@ -303,6 +316,10 @@ impl SagaComposer {
         _querier: &UniversalId,
         predicate: &str,
     ) -> CompositionResult<Vec<SagaStep>> {
+        // Nur ausführbare Prädikate werden komponiert
+        Query::parse(predicate)
+            .map_err(|e| CompositionError::UnsupportedGoal(format!("query: {e}")))?;
+
         let step = SagaStep::new(
             0,
             format!("Execute query: {}", predicate),
@@ -440,14 +457,8 @@ impl SagaComposer {
                     }
                 }
                 Constraint::MaxCost { amount, cost, .. } => {
-                    // Berechne geschätzte Kosten
-                    let estimated_cost = steps.len() as u64 * 10; // Vereinfacht: 10 pro Step
-                    let max_amount = amount.unwrap_or(cost.gas);
-                    if estimated_cost > max_amount {
-                        return Err(CompositionError::InsufficientBudget {
-                            need: estimated_cost,
-                            have: max_amount,
-                        });
+                    if let Some((need, have)) = exceeded_cost(&total_cost(steps), cost, *amount) {
+                        return Err(CompositionError::InsufficientBudget { need, have });
                     }
                 }
                 _ => {}
@@ -490,6 +501,8 @@ impl SagaComposer {
             },
         )
         .with_realm_crossing(from_realm, to_realm);
+        let cost = step_cost(&gateway_step.action, &self.config.cost_table);
+        let gateway_step = gateway_step.with_expected_cost(cost);
 
         // Re-Index existierende Steps
         for step in &mut saga.steps {
@@ -509,6 +522,57 @@ impl SagaComposer {
     }
 }
 
+/// Erwartete Kosten einer Saga-Aktion laut [`CostTable`]
+///
+/// Ledger-Buchungen kosten ein Event plus zwei Postings, Policies einen
+/// VM-Aufruf mit Host-Zugriff, Gateway-Prüfungen eine Trust-Abfrage.
+pub fn step_cost(action: &SagaAction, table: &CostTable) -> Cost {
+    let postings = table.event_create.seq(table.storage_put_base.scale(2.0));
+    match action {
+        SagaAction::Transfer { .. }
+        | SagaAction::Lock { .. }
+        | SagaAction::Unlock { .. }
+        | SagaAction::Mint { .. }
+        | SagaAction::Burn { .. } => postings,
+        SagaAction::Attest { .. } => table.event_create.seq(table.trust_update),
+        SagaAction::Delegate { .. } => table.event_create.seq(table.trust_delegate),
+        SagaAction::ExecutePolicy { params, .. } => table
+            .vm_op_cost("call", params.len())
+            .seq(table.vm_host_call),
+        SagaAction::CrossRealm { .. } | SagaAction::GatewayCheck { .. } => {
+            table.trust_query.seq(table.storage_get)
+        }
+        SagaAction::WaitFor { .. } => table.storage_query_base,
+        _ => table.p2p_publish,
+    }
+}
+
+/// Summe der erwarteten Kosten (sequentielle Komposition)
+pub fn total_cost(steps: &[SagaStep]) -> Cost {
+    steps
+        .iter()
+        .fold(Cost::ZERO, |acc, step| acc.seq(step.expected_cost))
+}
+
+/// Erste überschrittene Grenze eines `MaxCost`-Constraints als `(need, have)`
+///
+/// Gas und Mana werden nur geprüft, wenn sie gesetzt sind; `amount`
+/// (Kompatibilität) begrenzt Gas + Mana gemeinsam.
+pub(crate) fn exceeded_cost(
+    estimated: &Cost,
+    limit: &Cost,
+    amount: Option<u64>,
+) -> Option<(u64, u64)> {
+    [
+        (limit.gas > 0).then_some((estimated.gas, limit.gas)),
+        (limit.mana > 0).then_some((estimated.mana, limit.mana)),
+        amount.map(|a| (estimated.gas.saturating_add(estimated.mana), a)),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|(need, have)| need > have)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -535,6 +599,7 @@ mod tests {
 
         assert_eq!(saga.steps.len(), 2);
         assert_eq!(saga.status, SagaStatus::Pending);
+        assert!(saga.steps.iter().all(|s| s.expected_cost.mana > 0));
 
         // Erster Schritt: Lock
         match &saga.steps[0].action {
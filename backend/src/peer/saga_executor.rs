@@ -0,0 +1,1485 @@
+//! # Saga Executor
+//!
+//! Führt komponierte Sagas gegen die echten Subsysteme aus (Κ22-Κ24).
+//!
+//! ## Ablauf
+//!
+//! ```text
+//! submit(saga) ──▶ SagaStore (Submitted)
+//!                      │
+//! execute/spawn ───────▼
+//! ┌───────────────────────────────────────────────┐
+//! │ für jeden Schritt (topologisch):              │
+//! │   Running → persist → Aktion → advance        │
+//! │   → persist → SagaUpdate + SagaProgress       │
+//! └───────────────┬───────────────────────────────┘
+//!                 │ Fehler / Cancel / Timeout
+//!                 ▼
+//! ┌───────────────────────────────────────────────┐
+//! │ Κ24: Kompensationen in umgekehrter Reihenfolge│
+//! │   → Compensated / Cancelled / TimedOut        │
+//! └───────────────────────────────────────────────┘
+//! ```
+//!
+//! ## Subsysteme
+//!
+//! - Ledger-Aktionen (Transfer, Lock, Unlock, Mint, Burn) über
+//!   [`Ledger::apply_saga_action_once`](crate::local::Ledger::apply_saga_action_once)
+//!   mit deterministischer Operations-ID pro Schritt – ein nach einem Neustart
+//!   wiederholter Schritt bucht nicht doppelt
+//! - `GatewayCheck` und `CrossRealm` über den [`GatewayGuard`] (Κ23)
+//! - `ExecutePolicy` über einen [`SagaPolicyRunner`] (z.B. [`EclEntrypoints`])
+//! - `WaitFor` über [`SagaExecutor::signal`] oder einen [`SagaConditionEvaluator`],
+//!   jeweils mit Timeout
+//!
+//! Ein Transfer, dem ein passender Lock-Schritt derselben Saga vorausgeht, wird
+//! als Freigabe des Escrow-Locks an den Empfänger gebucht.
+
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::broadcast;
+use tracing::Instrument;
+
+use crate::core::{SharedUnifiedState, StateEvent};
+use crate::domain::unified::{
+    Capability, DIDNamespace, Delegation, InvariantChecker, RealmId, Saga, SagaAction, SagaId,
+    SagaStatus, SagaStep, StepResult, StepStatus, TemporalCoord, UniversalId, DID, ROOT_REALM_ID,
+};
+use crate::eclvm::runtime::host::HostInterface;
+use crate::eclvm::EclEntrypoints;
+use crate::local::{
+    CompensationRecord, DecentralizedStorage, JournalEntry, LockStatus, SagaPhase, SagaRecord,
+};
+use crate::peer::gateway::GatewayGuard;
+use crate::peer::saga_simulator::SagaSimulator;
+
+// ============================================================================
+// Fehler
+// ============================================================================
+
+/// Fehler bei Executor-Operationen
+#[derive(Debug, Error)]
+pub enum SagaExecutorError {
+    #[error("Saga not found: {0}")]
+    NotFound(String),
+
+    #[error("Saga already running: {0}")]
+    AlreadyRunning(String),
+
+    #[error("Saga already finished: {0}")]
+    AlreadyFinished(String),
+
+    #[error("Invalid saga: {0}")]
+    InvalidSaga(String),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] anyhow::Error),
+}
+
+/// Ergebnis von Executor-Operationen
+pub type SagaExecutorResult<T> = Result<T, SagaExecutorError>;
+
+// ============================================================================
+// Erweiterungspunkte
+// ============================================================================
+
+/// Führt `ExecutePolicy`-Schritte aus
+pub trait SagaPolicyRunner: Send + Sync {
+    /// Führt die Policy aus; `Ok(false)` bedeutet, dass die Policy ablehnt
+    fn run_policy(
+        &self,
+        policy_id: &str,
+        caller: &UniversalId,
+        realm: &RealmId,
+        params: &HashMap<String, serde_json::Value>,
+    ) -> Result<bool, String>;
+}
+
+impl<H: HostInterface + Send + Sync> SagaPolicyRunner for EclEntrypoints<H> {
+    fn run_policy(
+        &self,
+        policy_id: &str,
+        caller: &UniversalId,
+        realm: &RealmId,
+        _params: &HashMap<String, serde_json::Value>,
+    ) -> Result<bool, String> {
+        // Die API-Engine kennt keine Aufrufparameter; Policies lesen ihren Kontext über den Host
+        let caller = format!("did:erynoa:{}:{}", DIDNamespace::Self_, caller.to_hex());
+        self.run_api(policy_id, &caller, &realm.to_hex(), None)
+            .map(|value| value.as_bool().unwrap_or(true))
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Wertet `WaitFor`-Bedingungen aus
+pub trait SagaConditionEvaluator: Send + Sync {
+    /// `Some(true)`: erfüllt, `Some(false)`: nicht mehr erfüllbar, `None`: weiter warten
+    fn evaluate(&self, saga: &Saga, condition: &str) -> Option<bool>;
+}
+
+// ============================================================================
+// Konfiguration & Updates
+// ============================================================================
+
+/// Konfiguration für den SagaExecutor
+#[derive(Debug, Clone)]
+pub struct SagaExecutorConfig {
+    /// Timeout für `WaitFor` ohne eigenes `timeout_seconds`
+    pub default_wait_timeout: Duration,
+    /// Poll-Intervall für `WaitFor` und Cancel-Anfragen
+    pub poll_interval: Duration,
+    /// Maximale Wartezeit von Cancel/Rollback auf eine laufende Ausführung
+    pub cancel_timeout: Duration,
+}
+
+impl Default for SagaExecutorConfig {
+    fn default() -> Self {
+        Self {
+            default_wait_timeout: Duration::from_secs(300),
+            poll_interval: Duration::from_millis(100),
+            cancel_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Fortschritt eines einzelnen Schritts
+#[derive(Debug, Clone, Serialize)]
+pub struct SagaStepUpdate {
+    /// Schritt-Index
+    pub index: usize,
+    /// Neuer Schritt-Status
+    pub status: StepStatus,
+    /// Journal-Sequenz der Buchung (falls Ledger-Aktion)
+    pub journal_seq: Option<u64>,
+    /// Ergebnis- oder Fehlermeldung
+    pub message: Option<String>,
+}
+
+/// Zustandsänderung einer Saga (für StreamSagaUpdates)
+#[derive(Debug, Clone, Serialize)]
+pub struct SagaUpdate {
+    /// Saga-ID
+    pub saga_id: SagaId,
+    /// Saga-Status
+    pub status: SagaStatus,
+    /// Ausführungsphase
+    pub phase: SagaPhase,
+    /// Betroffener Schritt
+    pub step: Option<SagaStepUpdate>,
+    /// Zeitpunkt (Unix-Sekunden)
+    pub timestamp: i64,
+}
+
+/// Art eines Abbruchs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AbortKind {
+    /// Cancel → `Cancelled`
+    Cancel,
+    /// Rollback → `Compensated`
+    Rollback,
+}
+
+impl AbortKind {
+    fn target(self) -> SagaStatus {
+        match self {
+            Self::Cancel => SagaStatus::Cancelled,
+            Self::Rollback => SagaStatus::Compensated,
+        }
+    }
+}
+
+/// Ergebnis einer ausgeführten Aktion
+#[derive(Debug, Clone, Default, Serialize)]
+struct StepOutput {
+    journal_seq: Option<u64>,
+    lock_id: Option<UniversalId>,
+    message: Option<String>,
+}
+
+impl StepOutput {
+    fn ledger(entry: Option<JournalEntry>) -> Self {
+        Self {
+            journal_seq: entry.map(|e| e.seq),
+            ..Default::default()
+        }
+    }
+
+    fn message(message: impl Into<String>) -> Self {
+        Self {
+            message: Some(message.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FailureKind {
+    Failed,
+    TimedOut,
+    Aborted(AbortKind),
+}
+
+/// Fehlgeschlagene Aktion
+#[derive(Debug, Clone)]
+struct StepFailure {
+    error: String,
+    kind: FailureKind,
+}
+
+impl StepFailure {
+    fn failed(error: impl std::fmt::Display) -> Self {
+        Self {
+            error: error.to_string(),
+            kind: FailureKind::Failed,
+        }
+    }
+}
+
+/// Entfernt die Saga beim Drop aus der Menge laufender Ausführungen
+struct RunningGuard {
+    running: Arc<Mutex<HashSet<SagaId>>>,
+    saga_id: SagaId,
+}
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        self.running.lock().remove(&self.saga_id);
+    }
+}
+
+// ============================================================================
+// SagaExecutor
+// ============================================================================
+
+/// Saga Executor (Κ22-Κ24)
+///
+/// Clone ist günstig; alle Klone teilen Storage, Signale und Update-Kanal.
+#[derive(Clone)]
+pub struct SagaExecutor {
+    storage: DecentralizedStorage,
+    config: SagaExecutorConfig,
+    gateway: Arc<RwLock<Option<Arc<GatewayGuard>>>>,
+    policy_runner: Arc<RwLock<Option<Arc<dyn SagaPolicyRunner>>>>,
+    condition_evaluator: Arc<RwLock<Option<Arc<dyn SagaConditionEvaluator>>>>,
+    unified_state: Option<SharedUnifiedState>,
+    signals: Arc<Mutex<HashSet<String>>>,
+    running: Arc<Mutex<HashSet<SagaId>>>,
+    aborts: Arc<Mutex<HashMap<SagaId, AbortKind>>>,
+    updates: broadcast::Sender<SagaUpdate>,
+}
+
+impl SagaExecutor {
+    /// Erstelle neuen Executor auf dem gegebenen Storage
+    pub fn new(storage: DecentralizedStorage, config: SagaExecutorConfig) -> Self {
+        let (updates, _) = broadcast::channel(256);
+        Self {
+            storage,
+            config,
+            gateway: Arc::new(RwLock::new(None)),
+            policy_runner: Arc::new(RwLock::new(None)),
+            condition_evaluator: Arc::new(RwLock::new(None)),
+            unified_state: None,
+            signals: Arc::new(Mutex::new(HashSet::new())),
+            running: Arc::new(Mutex::new(HashSet::new())),
+            aborts: Arc::new(Mutex::new(HashMap::new())),
+            updates,
+        }
+    }
+
+    /// Mit UnifiedState (loggt `StateEvent::SagaProgress` pro Schritt)
+    pub fn with_unified_state(mut self, state: SharedUnifiedState) -> Self {
+        self.unified_state = Some(state);
+        self
+    }
+
+    /// GatewayGuard für `GatewayCheck`/`CrossRealm` setzen (Κ23)
+    pub fn set_gateway(&self, gateway: Arc<GatewayGuard>) {
+        *self.gateway.write() = Some(gateway);
+    }
+
+    /// Policy-Runner für `ExecutePolicy` setzen
+    pub fn set_policy_runner(&self, runner: Arc<dyn SagaPolicyRunner>) {
+        *self.policy_runner.write() = Some(runner);
+    }
+
+    /// Evaluator für `WaitFor`-Bedingungen setzen
+    pub fn set_condition_evaluator(&self, evaluator: Arc<dyn SagaConditionEvaluator>) {
+        *self.condition_evaluator.write() = Some(evaluator);
+    }
+
+    /// Dry-Run-Simulator mit den aktuell gesetzten Erweiterungspunkten
+    pub fn simulator(&self) -> SagaSimulator {
+        let mut simulator = SagaSimulator::new(self.storage.clone());
+        if let Some(gateway) = self.gateway.read().clone() {
+            simulator = simulator.with_gateway(gateway);
+        }
+        if let Some(runner) = self.policy_runner.read().clone() {
+            simulator = simulator.with_policy_runner(runner);
+        }
+        if let Some(evaluator) = self.condition_evaluator.read().clone() {
+            simulator = simulator.with_condition_evaluator(evaluator);
+        }
+        simulator
+    }
+
+    /// Abonniere Saga-Updates
+    pub fn subscribe(&self) -> broadcast::Receiver<SagaUpdate> {
+        self.updates.subscribe()
+    }
+
+    /// Meldet eine erfüllte `WaitFor`-Bedingung
+    ///
+    /// Gilt für alle wartenden Sagas; `"<saga_id_hex>:<condition>"` gilt nur
+    /// für die angegebene Saga.
+    pub fn signal(&self, condition: impl Into<String>) {
+        self.signals.lock().insert(condition.into());
+    }
+
+    /// Läuft die Saga gerade?
+    pub fn is_running(&self, saga_id: &SagaId) -> bool {
+        self.running.lock().contains(saga_id)
+    }
+
+    /// Persistierter Zustand einer Saga
+    pub fn get(&self, saga_id: &SagaId) -> SagaExecutorResult<Option<SagaRecord>> {
+        Ok(self.storage.sagas.get(saga_id)?)
+    }
+
+    /// Alle Sagas (neueste zuerst)
+    pub fn list(&self) -> SagaExecutorResult<Vec<SagaRecord>> {
+        Ok(self.storage.sagas.list()?)
+    }
+
+    /// Reicht eine Saga zur Ausführung ein
+    ///
+    /// Idempotent: existiert die Saga bereits, wird der gespeicherte Zustand
+    /// zurückgegeben.
+    pub fn submit(&self, saga: Saga) -> SagaExecutorResult<SagaRecord> {
+        if let Some(existing) = self.storage.sagas.get(&saga.id)? {
+            return Ok(existing);
+        }
+        Self::validate(&saga)?;
+
+        let record = SagaRecord::new(saga);
+        self.storage.sagas.put(&record)?;
+        self.publish(&record, None);
+        Ok(record)
+    }
+
+    /// Führt eine eingereichte Saga bis zum Endzustand aus
+    pub async fn execute(&self, saga_id: &SagaId) -> SagaExecutorResult<SagaRecord> {
+        let record = self.load(saga_id)?;
+        if record.is_finished() {
+            return Err(SagaExecutorError::AlreadyFinished(saga_id.to_hex()));
+        }
+        let _guard = self.claim(saga_id)?;
+        let span = tracing::info_span!("saga.execute", saga.id = %saga_id.to_hex());
+        self.run(record).instrument(span).await
+    }
+
+    /// Startet die Ausführung im Hintergrund
+    pub fn spawn(&self, saga_id: SagaId) -> SagaExecutorResult<()> {
+        let record = self.load(&saga_id)?;
+        if record.is_finished() {
+            return Err(SagaExecutorError::AlreadyFinished(saga_id.to_hex()));
+        }
+        if self.is_running(&saga_id) {
+            return Err(SagaExecutorError::AlreadyRunning(saga_id.to_hex()));
+        }
+
+        let executor = self.clone();
+        // Hintergrund-Ausführung bleibt Teil des Traces des Aufrufers
+        tokio::spawn(
+            async move {
+                if let Err(e) = executor.execute(&saga_id).await {
+                    tracing::warn!(saga_id = %saga_id.to_hex(), error = %e, "Saga execution failed");
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+        Ok(())
+    }
+
+    /// Setzt nach einem Neustart alle unterbrochenen Sagas fort
+    ///
+    /// Gibt die Anzahl der wieder aufgenommenen Sagas zurück.
+    pub fn recover(&self) -> SagaExecutorResult<usize> {
+        let mut resumed = 0;
+        for record in self.storage.sagas.in_flight()? {
+            match self.spawn(record.id()) {
+                Ok(()) => resumed += 1,
+                Err(SagaExecutorError::AlreadyRunning(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if resumed > 0 {
+            tracing::info!(resumed, "Resumed in-flight sagas");
+        }
+        Ok(resumed)
+    }
+
+    /// Bricht eine Saga ab und kompensiert alle abgeschlossenen Schritte
+    pub async fn cancel(
+        &self,
+        saga_id: &SagaId,
+        reason: Option<String>,
+    ) -> SagaExecutorResult<SagaRecord> {
+        let record = self.load(saga_id)?;
+        if record.is_finished() {
+            return Err(SagaExecutorError::AlreadyFinished(saga_id.to_hex()));
+        }
+        self.abort(record, AbortKind::Cancel, reason).await
+    }
+
+    /// Erzwingt die Kompensation einer Saga
+    ///
+    /// Anders als [`SagaExecutor::cancel`] auch für abgeschlossene Sagas sowie
+    /// für Sagas, deren Kompensation zuvor teilweise fehlgeschlagen ist.
+    pub async fn rollback(
+        &self,
+        saga_id: &SagaId,
+        reason: Option<String>,
+    ) -> SagaExecutorResult<SagaRecord> {
+        let record = self.load(saga_id)?;
+        if record.is_finished()
+            && !matches!(
+                record.saga.status,
+                SagaStatus::Completed | SagaStatus::Failed { .. }
+            )
+        {
+            return Err(SagaExecutorError::AlreadyFinished(saga_id.to_hex()));
+        }
+        self.abort(record, AbortKind::Rollback, reason).await
+    }
+
+    // ========================================================================
+    // Ausführung
+    // ========================================================================
+
+    async fn abort(
+        &self,
+        mut record: SagaRecord,
+        kind: AbortKind,
+        reason: Option<String>,
+    ) -> SagaExecutorResult<SagaRecord> {
+        let saga_id = record.id();
+
+        match self.claim(&saga_id) {
+            Ok(_guard) => {
+                record.cancel_requested = true;
+                record.cancel_reason = reason;
+                self.compensate(record, kind.target()).await
+            }
+            Err(SagaExecutorError::AlreadyRunning(_)) => {
+                // Laufende Ausführung kompensiert selbst, sobald sie die Anfrage sieht
+                self.aborts.lock().insert(saga_id, kind);
+                let deadline = Instant::now() + self.config.cancel_timeout;
+                while self.is_running(&saga_id) && Instant::now() < deadline {
+                    tokio::time::sleep(self.config.poll_interval).await;
+                }
+                let mut record = self.load(&saga_id)?;
+                if record.cancel_reason.is_none() && reason.is_some() {
+                    record.cancel_reason = reason;
+                    self.storage.sagas.put(&record)?;
+                }
+                Ok(record)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn run(&self, mut record: SagaRecord) -> SagaExecutorResult<SagaRecord> {
+        let saga_id = record.id();
+
+        if record.phase == SagaPhase::Compensating {
+            let target = record
+                .compensation_target
+                .clone()
+                .unwrap_or(SagaStatus::Compensated);
+            return self.compensate(record, target).await;
+        }
+
+        // Nach einem Neustart: unterbrochene Schritte erneut ausführen (idempotent per op_id)
+        for step in &mut record.saga.steps {
+            if step.status == StepStatus::Running {
+                step.status = StepStatus::Pending;
+            }
+        }
+        if record.phase == SagaPhase::Submitted {
+            record.phase = SagaPhase::Running;
+            record.saga.status = SagaStatus::InProgress {
+                current_step: record.saga.current_step_index().unwrap_or(0),
+            };
+            record.touch();
+            self.persist(&record)?;
+            self.publish(&record, None);
+        }
+
+        loop {
+            if let Some(kind) = self.take_abort(&saga_id) {
+                record.cancel_requested = true;
+                return self.compensate(record, kind.target()).await;
+            }
+            let Some(idx) = record.saga.current_step_index() else {
+                break;
+            };
+
+            let completed: Vec<usize> = record
+                .saga
+                .steps
+                .iter()
+                .filter(|s| s.status == StepStatus::Completed)
+                .map(|s| s.index)
+                .collect();
+            let outcome = if record.saga.steps[idx].can_execute(&completed) {
+                record.saga.steps[idx].status = StepStatus::Running;
+                record
+                    .step_started_at
+                    .insert(idx, chrono::Utc::now().timestamp());
+                record.touch();
+                self.persist(&record)?;
+                self.publish_step(&record, idx, None);
+
+                let span = tracing::info_span!(
+                    "saga.step",
+                    saga.id = %saga_id.to_hex(),
+                    step.index = idx,
+                    step.action = action_name(&record.saga.steps[idx].action),
+                    step.outcome = tracing::field::Empty,
+                );
+                let outcome = self
+                    .execute_step(&mut record, idx)
+                    .instrument(span.clone())
+                    .await;
+                span.record(
+                    "step.outcome",
+                    if outcome.is_ok() { "success" } else { "failed" },
+                );
+                outcome
+            } else {
+                Err(StepFailure::failed(format!(
+                    "dependencies of step {} not satisfied",
+                    idx
+                )))
+            };
+
+            if let Err(StepFailure {
+                kind: FailureKind::Aborted(kind),
+                ..
+            }) = &outcome
+            {
+                // Unterbrochener Schritt hat nichts bewirkt
+                record.saga.steps[idx].status = StepStatus::Skipped;
+                record.cancel_requested = true;
+                self.aborts.lock().remove(&saga_id);
+                return self.compensate(record, kind.target()).await;
+            }
+
+            let (result, message, failure) = match outcome {
+                Ok(output) => {
+                    if let Some(seq) = output.journal_seq {
+                        record.journal_seqs.insert(idx, seq);
+                    }
+                    if let Some(lock_id) = output.lock_id {
+                        record.lock_ids.insert(idx, lock_id);
+                    }
+                    let result = StepResult::Success {
+                        output: serde_json::to_vec(&output).unwrap_or_default(),
+                        cost: record.saga.steps[idx].expected_cost,
+                    };
+                    (result, output.message, None)
+                }
+                Err(failure) => {
+                    let result = StepResult::Failed {
+                        error: failure.error.clone(),
+                        retriable: false,
+                    };
+                    (result, Some(failure.error.clone()), Some(failure))
+                }
+            };
+
+            // advance() arbeitet auf dem ersten ausstehenden Schritt
+            record.saga.steps[idx].status = StepStatus::Pending;
+            let lamport = record.saga.updated_at.lamport().saturating_add(1);
+            record
+                .saga
+                .advance(result, lamport)
+                .map_err(|e| SagaExecutorError::InvalidSaga(e.to_string()))?;
+            record
+                .step_completed_at
+                .insert(idx, chrono::Utc::now().timestamp());
+            record.touch();
+            self.persist(&record)?;
+            self.publish_step(&record, idx, message);
+            self.log_progress(&record, idx + 1, false);
+
+            if let Some(failure) = failure {
+                record.error = Some(failure.error);
+                let target = match failure.kind {
+                    FailureKind::TimedOut => SagaStatus::TimedOut,
+                    _ => SagaStatus::Compensated,
+                };
+                return self.compensate(record, target).await;
+            }
+        }
+
+        record.error = None;
+        record.finish(SagaStatus::Completed);
+        self.persist(&record)?;
+        self.publish(&record, None);
+        Ok(record)
+    }
+
+    /// Κ24: Kompensiert alle abgeschlossenen Schritte in umgekehrter Reihenfolge
+    async fn compensate(
+        &self,
+        mut record: SagaRecord,
+        target: SagaStatus,
+    ) -> SagaExecutorResult<SagaRecord> {
+        record.phase = SagaPhase::Compensating;
+        record.compensation_target = Some(target.clone());
+        record.touch();
+        self.persist(&record)?;
+        self.publish(&record, None);
+
+        let indices: Vec<usize> = record
+            .saga
+            .steps
+            .iter()
+            .rev()
+            .filter(|s| s.status == StepStatus::Completed && s.compensation.is_some())
+            .map(|s| s.index)
+            .collect();
+
+        let mut failures = Vec::new();
+        for idx in indices {
+            let Some(compensation) = record.saga.steps[idx].compensation.clone() else {
+                continue;
+            };
+            let op_id = Self::op_id(&record.id(), idx, "compensate");
+            let span = tracing::info_span!(
+                "saga.compensate",
+                saga.id = %record.id().to_hex(),
+                step.index = idx,
+                step.action = action_name(&compensation.action),
+            );
+            let outcome = span.in_scope(|| {
+                self.execute_ledger_action(&record, idx, &op_id, &compensation.action)
+            });
+
+            let at = chrono::Utc::now().timestamp();
+            let message = match outcome {
+                Ok(output) => {
+                    record.saga.steps[idx].status = StepStatus::Compensated;
+                    record.compensations.push(CompensationRecord {
+                        step_index: idx,
+                        success: true,
+                        journal_seq: output.journal_seq,
+                        error: None,
+                        at,
+                    });
+                    Some(compensation.description)
+                }
+                Err(failure) => {
+                    failures.push(format!("step {}: {}", idx, failure.error));
+                    record.compensations.push(CompensationRecord {
+                        step_index: idx,
+                        success: false,
+                        journal_seq: None,
+                        error: Some(failure.error.clone()),
+                        at,
+                    });
+                    Some(failure.error)
+                }
+            };
+            record.touch();
+            self.persist(&record)?;
+            self.publish_step(&record, idx, message);
+            self.log_progress(&record, idx + 1, true);
+        }
+
+        for step in &mut record.saga.steps {
+            if step.status == StepStatus::Pending {
+                step.status = StepStatus::Skipped;
+            }
+        }
+
+        if failures.is_empty() {
+            record.finish(target);
+        } else {
+            let at_step = match &record.saga.status {
+                SagaStatus::Failed { at_step, .. } => *at_step,
+                _ => record.saga.steps.len().saturating_sub(1),
+            };
+            let error = format!("compensation failed: {}", failures.join("; "));
+            record.error = Some(error.clone());
+            record.finish(SagaStatus::Failed { at_step, error });
+        }
+        self.persist(&record)?;
+        self.publish(&record, None);
+        Ok(record)
+    }
+
+    async fn execute_step(
+        &self,
+        record: &mut SagaRecord,
+        idx: usize,
+    ) -> Result<StepOutput, StepFailure> {
+        let step = record.saga.steps[idx].clone();
+        let actor = record.saga.executor;
+        let op_id = Self::op_id(&record.id(), idx, "step");
+
+        match &step.action {
+            SagaAction::Transfer { .. }
+            | SagaAction::Lock { .. }
+            | SagaAction::Unlock { .. }
+            | SagaAction::Mint { .. }
+            | SagaAction::Burn { .. } => {
+                self.execute_ledger_action(record, idx, &op_id, &step.action)
+            }
+            SagaAction::Delegate {
+                from,
+                to,
+                capabilities,
+                trust_factor,
+            } => {
+                Self::check_owner(&actor, from)?;
+                InvariantChecker::check_delegation_trust_factor(*trust_factor)
+                    .map_err(StepFailure::failed)?;
+                let capabilities = capabilities
+                    .iter()
+                    .map(|c| Capability::parse(c))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(StepFailure::failed)?;
+                let mut delegation = Delegation::new(*from, *to, *trust_factor, capabilities);
+                delegation.id = op_id;
+                self.storage
+                    .ledger
+                    .grant_delegation(delegation)
+                    .map_err(StepFailure::failed)?;
+                Ok(StepOutput::message(format!(
+                    "delegation {} granted",
+                    op_id.to_hex()
+                )))
+            }
+            SagaAction::GatewayCheck {
+                subject,
+                target_realm,
+                required_trust,
+            } => {
+                let from = step
+                    .realm_crossing
+                    .as_ref()
+                    .map(|c| c.from)
+                    .unwrap_or(ROOT_REALM_ID);
+                self.check_crossing(subject, &from, target_realm, *required_trust)
+            }
+            SagaAction::CrossRealm {
+                from_realm,
+                to_realm,
+                subject,
+            } => self.check_crossing(subject, from_realm, to_realm, 0.0),
+            SagaAction::ExecutePolicy { policy_id, params } => {
+                let runner = self
+                    .policy_runner
+                    .read()
+                    .clone()
+                    .ok_or_else(|| StepFailure::failed("no policy runner configured"))?;
+                let realm = step
+                    .realm_crossing
+                    .as_ref()
+                    .map(|c| c.to)
+                    .unwrap_or(ROOT_REALM_ID);
+                match runner.run_policy(policy_id, &actor, &realm, params) {
+                    Ok(true) => Ok(StepOutput::message(format!("policy {} allowed", policy_id))),
+                    Ok(false) => Err(StepFailure::failed(format!("policy {} denied", policy_id))),
+                    Err(e) => Err(StepFailure::failed(e)),
+                }
+            }
+            SagaAction::WaitFor {
+                condition,
+                timeout_seconds,
+                ..
+            } => {
+                self.wait_for(&record.saga, condition, *timeout_seconds)
+                    .await
+            }
+            other => Err(StepFailure::failed(format!(
+                "unsupported saga action: {}",
+                action_name(other)
+            ))),
+        }
+    }
+
+    /// Ledger-Aktionen für Schritte und Kompensationen
+    fn execute_ledger_action(
+        &self,
+        record: &SagaRecord,
+        idx: usize,
+        op_id: &UniversalId,
+        action: &SagaAction,
+    ) -> Result<StepOutput, StepFailure> {
+        let actor = record.saga.executor;
+        let ledger = &self.storage.ledger;
+
+        let (action, lock_id) = match action {
+            SagaAction::Lock {
+                owner,
+                asset_type,
+                amount,
+                lock_id,
+                release_conditions,
+            } => {
+                Self::check_owner(&actor, owner)?;
+                let lock_id = lock_id.unwrap_or_else(|| Self::op_id(&record.id(), idx, "lock"));
+                let action = SagaAction::Lock {
+                    owner: *owner,
+                    asset_type: asset_type.clone(),
+                    amount: *amount,
+                    lock_id: Some(lock_id),
+                    release_conditions: release_conditions.clone(),
+                };
+                (action, Some(lock_id))
+            }
+            SagaAction::Unlock { lock_id, to } => {
+                let lock_id = Self::resolve_lock_id(&record.saga, &record.lock_ids, idx, lock_id).ok_or_else(|| {
+                    StepFailure::failed("no saga lock for placeholder lock id")
+                })?;
+                self.check_lock_owner(&actor, &lock_id)?;
+                let action = SagaAction::Unlock { lock_id, to: *to };
+                (action, None)
+            }
+            SagaAction::Transfer {
+                from,
+                to,
+                amount,
+                asset_type,
+            } => {
+                Self::check_owner(&actor, from)?;
+                let action = match self.matching_lock(record, idx, from, asset_type, *amount)? {
+                    Some(lock_id) => SagaAction::Unlock {
+                        lock_id,
+                        to: Some(*to),
+                    },
+                    None => action.clone(),
+                };
+                (action, None)
+            }
+            SagaAction::Mint { .. } | SagaAction::Burn { .. } => (action.clone(), None),
+            other => {
+                return Err(StepFailure::failed(format!(
+                    "unsupported ledger action: {}",
+                    action_name(other)
+                )))
+            }
+        };
+
+        let entry = ledger
+            .apply_saga_action_once(op_id, &actor, &action)
+            .map_err(StepFailure::failed)?;
+        Ok(StepOutput {
+            lock_id,
+            ..StepOutput::ledger(entry)
+        })
+    }
+
+    async fn wait_for(
+        &self,
+        saga: &Saga,
+        condition: &str,
+        timeout_seconds: u64,
+    ) -> Result<StepOutput, StepFailure> {
+        let timeout = if timeout_seconds > 0 {
+            Duration::from_secs(timeout_seconds)
+        } else {
+            self.config.default_wait_timeout
+        };
+        let deadline = Instant::now() + timeout;
+        let scoped = format!("{}:{}", saga.id.to_hex(), condition);
+
+        loop {
+            if self
+                .signals
+                .lock()
+                .iter()
+                .any(|s| s == condition || *s == scoped)
+            {
+                return Ok(StepOutput::message(format!(
+                    "condition '{}' met",
+                    condition
+                )));
+            }
+
+            let evaluator = self.condition_evaluator.read().clone();
+            match evaluator.and_then(|e| e.evaluate(saga, condition)) {
+                Some(true) => {
+                    return Ok(StepOutput::message(format!(
+                        "condition '{}' met",
+                        condition
+                    )))
+                }
+                Some(false) => {
+                    return Err(StepFailure::failed(format!(
+                        "condition '{}' can no longer be met",
+                        condition
+                    )))
+                }
+                None => {}
+            }
+
+            if let Some(kind) = self.aborts.lock().get(&saga.id).copied() {
+                return Err(StepFailure {
+                    error: "aborted".to_string(),
+                    kind: FailureKind::Aborted(kind),
+                });
+            }
+            if Instant::now() >= deadline {
+                return Err(StepFailure {
+                    error: format!("timed out waiting for '{}'", condition),
+                    kind: FailureKind::TimedOut,
+                });
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    fn check_crossing(
+        &self,
+        subject: &UniversalId,
+        from: &RealmId,
+        to: &RealmId,
+        required_trust: f32,
+    ) -> Result<StepOutput, StepFailure> {
+        let gateway = self
+            .gateway
+            .read()
+            .clone()
+            .ok_or_else(|| StepFailure::failed("no gateway configured"))?;
+        check_crossing(&gateway, subject, from, to, required_trust)
+            .map(StepOutput::message)
+            .map_err(StepFailure::failed)
+    }
+
+    // ========================================================================
+    // Helpers
+    // ========================================================================
+
+    /// Deterministische Operations-ID (idempotente Ledger-Buchungen)
+    pub(crate) fn op_id(saga_id: &SagaId, idx: usize, kind: &str) -> UniversalId {
+        let content = [
+            saga_id.as_bytes().as_slice(),
+            &(idx as u64).to_le_bytes(),
+            kind.as_bytes(),
+        ]
+        .concat();
+        UniversalId::new(UniversalId::TAG_SAGA, 1, &content)
+    }
+
+    fn validate(saga: &Saga) -> SagaExecutorResult<()> {
+        if saga.steps.is_empty() {
+            return Err(SagaExecutorError::InvalidSaga("saga has no steps".into()));
+        }
+        for (position, step) in saga.steps.iter().enumerate() {
+            if step.index != position {
+                return Err(SagaExecutorError::InvalidSaga(format!(
+                    "step at position {} has index {}",
+                    position, step.index
+                )));
+            }
+            if let Some(dep) = step.dependencies.iter().find(|d| **d >= position) {
+                return Err(SagaExecutorError::InvalidSaga(format!(
+                    "step {} depends on later step {}",
+                    position, dep
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_owner(actor: &UniversalId, owner: &UniversalId) -> Result<(), StepFailure> {
+        if actor != owner {
+            return Err(StepFailure::failed(format!(
+                "executor {} may not move funds of {}",
+                actor.to_hex(),
+                owner.to_hex()
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_lock_owner(
+        &self,
+        actor: &UniversalId,
+        lock_id: &UniversalId,
+    ) -> Result<(), StepFailure> {
+        let lock = self
+            .storage
+            .ledger
+            .lock_info(lock_id)
+            .map_err(StepFailure::failed)?
+            .ok_or_else(|| StepFailure::failed(format!("lock not found: {}", lock_id.to_hex())))?;
+        Self::check_owner(actor, &lock.owner)
+    }
+
+    /// Ersetzt den Platzhalter `UniversalId::NULL` durch den Lock der Saga
+    ///
+    /// Reihenfolge: Lock des Schritts selbst (Kompensation eines Lock-Schritts),
+    /// dann Abhängigkeiten, dann der letzte vorherige Lock.
+    pub(crate) fn resolve_lock_id(
+        saga: &Saga,
+        lock_ids: &BTreeMap<usize, UniversalId>,
+        idx: usize,
+        lock_id: &UniversalId,
+    ) -> Option<UniversalId> {
+        if !lock_id.is_null() {
+            return Some(*lock_id);
+        }
+        lock_ids
+            .get(&idx)
+            .or_else(|| {
+                saga.steps[idx]
+                    .dependencies
+                    .iter()
+                    .rev()
+                    .find_map(|d| lock_ids.get(d))
+            })
+            .or_else(|| lock_ids.range(..idx).next_back().map(|(_, id)| id))
+            .copied()
+    }
+
+    /// Offener Saga-Lock, der exakt den Transfer deckt
+    fn matching_lock(
+        &self,
+        record: &SagaRecord,
+        idx: usize,
+        from: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+    ) -> Result<Option<UniversalId>, StepFailure> {
+        let step: &SagaStep = &record.saga.steps[idx];
+        let candidates = step
+            .dependencies
+            .iter()
+            .filter_map(|d| record.lock_ids.get(d))
+            .chain(record.lock_ids.range(..idx).rev().map(|(_, id)| id));
+
+        for lock_id in candidates {
+            let Some(lock) = self
+                .storage
+                .ledger
+                .lock_info(lock_id)
+                .map_err(StepFailure::failed)?
+            else {
+                continue;
+            };
+            if lock.status == LockStatus::Locked
+                && lock.owner == *from
+                && lock.asset_type == asset_type
+                && lock.amount == amount
+            {
+                return Ok(Some(*lock_id));
+            }
+        }
+        Ok(None)
+    }
+
+    fn load(&self, saga_id: &SagaId) -> SagaExecutorResult<SagaRecord> {
+        self.storage
+            .sagas
+            .get(saga_id)?
+            .ok_or_else(|| SagaExecutorError::NotFound(saga_id.to_hex()))
+    }
+
+    fn claim(&self, saga_id: &SagaId) -> SagaExecutorResult<RunningGuard> {
+        if !self.running.lock().insert(*saga_id) {
+            return Err(SagaExecutorError::AlreadyRunning(saga_id.to_hex()));
+        }
+        Ok(RunningGuard {
+            running: self.running.clone(),
+            saga_id: *saga_id,
+        })
+    }
+
+    fn take_abort(&self, saga_id: &SagaId) -> Option<AbortKind> {
+        self.aborts.lock().remove(saga_id)
+    }
+
+    fn persist(&self, record: &SagaRecord) -> SagaExecutorResult<()> {
+        Ok(self.storage.sagas.put(record)?)
+    }
+
+    fn publish(&self, record: &SagaRecord, step: Option<SagaStepUpdate>) {
+        // Kein Empfänger ist kein Fehler
+        let _ = self.updates.send(SagaUpdate {
+            saga_id: record.id(),
+            status: record.saga.status.clone(),
+            phase: record.phase,
+            step,
+            timestamp: record.updated_at,
+        });
+    }
+
+    fn publish_step(&self, record: &SagaRecord, idx: usize, message: Option<String>) {
+        let update = SagaStepUpdate {
+            index: idx,
+            status: record.saga.steps[idx].status,
+            journal_seq: record.journal_seqs.get(&idx).copied(),
+            message,
+        };
+        self.publish(record, Some(update));
+    }
+
+    fn log_progress(&self, record: &SagaRecord, step: usize, compensation: bool) {
+        let Some(state) = &self.unified_state else {
+            return;
+        };
+        let realms: BTreeSet<String> = record
+            .saga
+            .steps
+            .iter()
+            .filter_map(|s| s.realm_crossing.as_ref())
+            .flat_map(|c| [c.from.to_hex(), c.to.to_hex()])
+            .collect();
+        state.log_and_apply(
+            StateEvent::SagaProgress {
+                saga_id: record.id().to_hex(),
+                step,
+                total_steps: record.saga.steps.len(),
+                cross_realm: !realms.is_empty(),
+                compensation_triggered: compensation,
+                realms: realms.into_iter().collect(),
+            },
+            vec![],
+        );
+    }
+}
+
+/// Gateway-Prüfung für `GatewayCheck`/`CrossRealm` (Κ23)
+///
+/// Liefert eine Erfolgsmeldung oder den Ablehnungsgrund.
+pub(crate) fn check_crossing(
+    gateway: &GatewayGuard,
+    subject: &UniversalId,
+    from: &RealmId,
+    to: &RealmId,
+    required_trust: f32,
+) -> Result<String, String> {
+    let result = gateway
+        .validate_crossing(&subject_did(subject), from, to)
+        .map_err(|e| e.to_string())?;
+    if !result.allowed {
+        return Err(format!(
+            "crossing into realm {} denied: {}",
+            to.to_hex(),
+            result.violations.join("; ")
+        ));
+    }
+
+    let trust = result.dampened_trust.weighted_norm(&[1.0; 6]);
+    if trust < required_trust {
+        return Err(format!(
+            "insufficient dampened trust for realm {}: {:.3} < {:.3}",
+            to.to_hex(),
+            trust,
+            required_trust
+        ));
+    }
+    Ok(format!(
+        "crossing {} → {} allowed",
+        from.to_hex(),
+        to.to_hex()
+    ))
+}
+
+/// DID-Hülle für Stores, die Subjekte ausschließlich über die ID identifizieren
+pub(crate) fn subject_did(id: &UniversalId) -> DID {
+    DID {
+        id: *id,
+        namespace: DIDNamespace::Self_,
+        public_key: [0u8; 32],
+        created_at: TemporalCoord::default(),
+    }
+}
+
+/// Gespeicherte DID zu einer ID (Trust-Keys enthalten den Namespace)
+pub(crate) fn stored_did(storage: &DecentralizedStorage, id: &UniversalId) -> DID {
+    storage
+        .identities
+        .list_all()
+        .ok()
+        .and_then(|all| all.into_iter().find(|i| i.did.id == *id))
+        .map(|i| i.did)
+        .unwrap_or_else(|| subject_did(id))
+}
+
+/// Kurzname einer Saga-Aktion (für APIs und Fehlermeldungen)
+pub fn action_name(action: &SagaAction) -> &'static str {
+    match action {
+        SagaAction::Transfer { .. } => "transfer",
+        SagaAction::Attest { .. } => "attest",
+        SagaAction::Delegate { .. } => "delegate",
+        SagaAction::ExecutePolicy { .. } => "execute_policy",
+        SagaAction::CrossRealm { .. } => "cross_realm",
+        SagaAction::Lock { .. } => "lock",
+        SagaAction::Unlock { .. } => "unlock",
+        SagaAction::WaitFor { .. } => "wait_for",
+        SagaAction::Mint { .. } => "mint",
+        SagaAction::Burn { .. } => "burn",
+        SagaAction::GatewayCheck { .. } => "gateway_check",
+        SagaAction::ExternalChain { .. } => "external_chain",
+        SagaAction::Custom { .. } => "custom",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::unified::{Goal, Intent, SagaCompensation};
+    use crate::local::NATIVE_ASSET;
+    use crate::peer::SagaComposer;
+
+    fn test_config() -> SagaExecutorConfig {
+        SagaExecutorConfig {
+            default_wait_timeout: Duration::from_millis(100),
+            poll_interval: Duration::from_millis(5),
+            cancel_timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn setup(balance: u64) -> (SagaExecutor, UniversalId, UniversalId) {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let alice = UniversalId::new(UniversalId::TAG_DID, 1, b"alice");
+        let bob = UniversalId::new(UniversalId::TAG_DID, 1, b"bob");
+        storage.ledger.register_asset(NATIVE_ASSET, alice).unwrap();
+        storage
+            .ledger
+            .mint(&alice, &alice, NATIVE_ASSET, balance, None)
+            .unwrap();
+        (SagaExecutor::new(storage, test_config()), alice, bob)
+    }
+
+    fn transfer_saga(from: UniversalId, to: UniversalId, amount: u64) -> Saga {
+        let intent = Intent::new(
+            from,
+            Goal::Transfer {
+                to,
+                amount,
+                asset_type: NATIVE_ASSET.into(),
+            },
+            ROOT_REALM_ID,
+            1,
+        );
+        SagaComposer::default().compose(&intent).unwrap()
+    }
+
+    fn balance(executor: &SagaExecutor, holder: &UniversalId) -> u64 {
+        executor
+            .storage
+            .ledger
+            .balance_of(holder, NATIVE_ASSET)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_transfer_saga_completes() {
+        let (executor, alice, bob) = setup(100);
+        let saga = transfer_saga(alice, bob, 60);
+        let record = executor.submit(saga).unwrap();
+        let mut updates = executor.subscribe();
+
+        let record = executor.execute(&record.id()).await.unwrap();
+
+        assert_eq!(record.saga.status, SagaStatus::Completed);
+        assert_eq!(record.phase, SagaPhase::Finished);
+        assert_eq!(balance(&executor, &alice), 40);
+        assert_eq!(balance(&executor, &bob), 60);
+        // Transfer gibt den Lock aus Schritt 0 frei
+        let lock_id = record.lock_ids[&0];
+        let lock = executor
+            .storage
+            .ledger
+            .lock_info(&lock_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(lock.status, LockStatus::Released { to: bob });
+        assert!(executor
+            .storage
+            .ledger
+            .verify_invariants()
+            .unwrap()
+            .is_empty());
+
+        let mut last = None;
+        while let Ok(update) = updates.try_recv() {
+            last = Some(update);
+        }
+        assert_eq!(last.unwrap().phase, SagaPhase::Finished);
+    }
+
+    #[tokio::test]
+    async fn test_failed_step_compensates_in_reverse() {
+        let (executor, alice, bob) = setup(100);
+        let mut saga = transfer_saga(alice, bob, 60);
+        // Policy-Schritt ohne Runner schlägt fehl
+        saga.steps.insert(
+            1,
+            SagaStep::new(
+                1,
+                "Check policy",
+                SagaAction::ExecutePolicy {
+                    policy_id: "transfer_limit".into(),
+                    params: HashMap::new(),
+                },
+            ),
+        );
+        saga.steps[2].index = 2;
+
+        let record = executor.submit(saga).unwrap();
+        let record = executor.execute(&record.id()).await.unwrap();
+
+        assert_eq!(record.saga.status, SagaStatus::Compensated);
+        assert_eq!(record.saga.steps[0].status, StepStatus::Compensated);
+        assert_eq!(record.saga.steps[1].status, StepStatus::Failed);
+        assert_eq!(record.saga.steps[2].status, StepStatus::Skipped);
+        assert_eq!(record.compensations.len(), 1);
+        assert!(record.compensations[0].success);
+        assert_eq!(balance(&executor, &alice), 100);
+        assert_eq!(balance(&executor, &bob), 0);
+    }
+
+    #[tokio::test]
+    async fn test_recover_resumes_without_double_posting() {
+        let (executor, alice, bob) = setup(100);
+        let record = executor.submit(transfer_saga(alice, bob, 100)).unwrap();
+        let saga_id = record.id();
+
+        // Absturz simulieren: Lock gebucht, Schritt aber noch als Running persistiert
+        let mut record = record;
+        let op_id = SagaExecutor::op_id(&saga_id, 0, "step");
+        executor
+            .execute_ledger_action(&record, 0, &op_id, &record.saga.steps[0].action.clone())
+            .unwrap();
+        record.phase = SagaPhase::Running;
+        record.saga.steps[0].status = StepStatus::Running;
+        executor.storage.sagas.put(&record).unwrap();
+
+        let restarted = SagaExecutor::new(executor.storage.clone(), test_config());
+        let mut updates = restarted.subscribe();
+        assert_eq!(restarted.recover().unwrap(), 1);
+        loop {
+            let update = updates.recv().await.unwrap();
+            if update.phase == SagaPhase::Finished {
+                break;
+            }
+        }
+
+        let record = restarted.get(&saga_id).unwrap().unwrap();
+        assert_eq!(record.saga.status, SagaStatus::Completed);
+        assert_eq!(balance(&restarted, &alice), 0);
+        assert_eq!(balance(&restarted, &bob), 100);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_signal_and_timeout() {
+        let (executor, alice, bob) = setup(100);
+        let mut saga = transfer_saga(alice, bob, 10);
+        saga.steps.push(
+            SagaStep::new(
+                2,
+                "Wait for receipt",
+                SagaAction::WaitFor {
+                    condition: "receipt".into(),
+                    timeout_lamport: 0,
+                    timeout_seconds: 0,
+                },
+            )
+            .with_compensation(SagaCompensation::new(
+                "noop",
+                SagaAction::Custom {
+                    action_type: "noop".into(),
+                    data: vec![],
+                },
+            )),
+        );
+        let record = executor.submit(saga.clone()).unwrap();
+        executor.signal(format!("{}:receipt", record.id().to_hex()));
+        let record = executor.execute(&record.id()).await.unwrap();
+        assert_eq!(record.saga.status, SagaStatus::Completed);
+
+        // Ohne Signal → Timeout, der Lock aus Schritt 0 wird erstattet
+        let mut intent_saga = transfer_saga(alice, bob, 20);
+        intent_saga.id = UniversalId::new(UniversalId::TAG_SAGA, 1, b"timeout-saga");
+        intent_saga.steps.truncate(1);
+        intent_saga.steps.push(SagaStep::new(
+            1,
+            "Wait forever",
+            SagaAction::WaitFor {
+                condition: "never".into(),
+                timeout_lamport: 0,
+                timeout_seconds: 0,
+            },
+        ));
+        let record = executor.submit(intent_saga).unwrap();
+        let record = executor.execute(&record.id()).await.unwrap();
+        assert_eq!(record.saga.status, SagaStatus::TimedOut);
+        assert_eq!(record.saga.steps[0].status, StepStatus::Compensated);
+        assert_eq!(balance(&executor, &alice), 90);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_running_saga() {
+        let (executor, alice, bob) = setup(100);
+        let mut saga = transfer_saga(alice, bob, 30);
+        saga.steps.truncate(1);
+        saga.steps.push(SagaStep::new(
+            1,
+            "Wait for approval",
+            SagaAction::WaitFor {
+                condition: "approval".into(),
+                timeout_lamport: 0,
+                timeout_seconds: 60,
+            },
+        ));
+        let saga_id = executor.submit(saga).unwrap().id();
+        executor.spawn(saga_id).unwrap();
+
+        // Warten bis der Lock gebucht ist und WaitFor läuft
+        for _ in 0..200 {
+            let record = executor.get(&saga_id).unwrap().unwrap();
+            if record.saga.steps[1].status == StepStatus::Running {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(balance(&executor, &alice), 70);
+
+        let record = executor
+            .cancel(&saga_id, Some("user abort".into()))
+            .await
+            .unwrap();
+        assert_eq!(record.saga.status, SagaStatus::Cancelled);
+        assert_eq!(record.saga.steps[1].status, StepStatus::Skipped);
+        assert_eq!(record.cancel_reason.as_deref(), Some("user abort"));
+        assert_eq!(balance(&executor, &alice), 100);
+
+        assert!(matches!(
+            executor.cancel(&saga_id, None).await,
+            Err(SagaExecutorError::AlreadyFinished(_))
+        ));
+    }
+
+    #[test]
+    fn test_submit_rejects_forward_dependencies() {
+        let (executor, alice, bob) = setup(10);
+        let mut saga = transfer_saga(alice, bob, 5);
+        saga.steps[0].dependencies = vec![1];
+        assert!(matches!(
+            executor.submit(saga),
+            Err(SagaExecutorError::InvalidSaga(_))
+        ));
+    }
+}
@@ -0,0 +1,1205 @@
+//! # Saga Simulator
+//!
+//! Dry-Run für Intents und Sagas: sagt Ergebnis, Kosten und benötigten Trust
+//! voraus, ohne den Ledger oder andere Stores zu verändern.
+//!
+//! ## Ablauf
+//!
+//! ```text
+//! Intent ──▶ SagaComposer (Constraints erst im Simulator) ──▶ Saga
+//!                                                              │
+//! ┌────────────────────────────────────────────────────────────▼─┐
+//! │ Constraints: MinTrust, MaxCost, Realms, HumanOnly, Deadline  │
+//! ├──────────────────────────────────────────────────────────────┤
+//! │ Schritte wie im SagaExecutor:                                │
+//! │   Ledger-Aktionen ──▶ LedgerView (Copy-on-Write-Overlay)     │
+//! │   GatewayCheck/CrossRealm ──▶ GatewayGuard (nur lesend)      │
+//! │   ExecutePolicy ──▶ SagaPolicyRunner                         │
+//! │   WaitFor ──▶ SagaConditionEvaluator (sonst: ungewiss)       │
+//! ├──────────────────────────────────────────────────────────────┤
+//! │ Fehler bei Schritt i ──▶ Κ24-Kompensationen i-1..0 (Overlay) │
+//! └──────────────────────────────────────────────────────────────┘
+//!                                                              │
+//!                                                              ▼
+//!                                                      SimulationReport
+//! ```
+//!
+//! Die Vorhersage ist so genau wie die Stores zum Zeitpunkt der Simulation;
+//! zwischenzeitliche Buchungen anderer Sagas werden nicht berücksichtigt.
+//! Die Delegations-Autorisierung von Mint/Burn (`authorization`) wird nicht
+//! geprüft, nur die Berechtigung des Ausführenden.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::domain::unified::{
+    Budget, Capability, Constraint, Cost, CostTable, Intent, InvariantChecker, RealmId, Saga,
+    SagaAction, SagaStep, TrustDimension, UniversalId, DID, ROOT_REALM_ID,
+};
+use crate::local::{DecentralizedStorage, LedgerAccount, LedgerError, LockStatus};
+use crate::peer::gateway::GatewayGuard;
+use crate::peer::saga_composer::{
+    exceeded_cost, step_cost, CompositionResult, SagaComposer, SagaComposerConfig,
+};
+use crate::peer::saga_executor::{
+    action_name, check_crossing, subject_did, SagaConditionEvaluator, SagaExecutor,
+    SagaPolicyRunner,
+};
+
+// ============================================================================
+// Report
+// ============================================================================
+
+/// Vorhergesagtes Ergebnis eines Schritts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimulationOutcome {
+    /// Schritt würde gelingen
+    Success,
+    /// Schritt würde fehlschlagen
+    Failure,
+    /// Ergebnis hängt von externen Ereignissen ab (z.B. `WaitFor`)
+    Uncertain,
+    /// Schritt würde nach einem Fehler nicht mehr ausgeführt
+    Skipped,
+}
+
+/// Vorhergesagte Saldoänderung
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BalanceChange {
+    /// Kontoinhaber
+    pub holder: UniversalId,
+    /// Asset
+    pub asset_type: String,
+    /// Saldo vor dem Schritt
+    pub before: u64,
+    /// Saldo nach dem Schritt
+    pub after: u64,
+}
+
+/// Vorhersage für einen Saga-Schritt
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulatedStep {
+    /// Schritt-Index
+    pub index: usize,
+    /// Kurzname der Aktion
+    pub action: &'static str,
+    /// Beschreibung aus der Saga
+    pub description: String,
+    /// Vorhergesagtes Ergebnis
+    pub outcome: SimulationOutcome,
+    /// Begründung (Fehlergrund oder Hinweis)
+    pub reason: Option<String>,
+    /// Erwartete Kosten
+    pub cost: Cost,
+    /// Saldoänderungen des Schritts
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+/// Vorhersage für eine Kompensation (Κ24)
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulatedCompensation {
+    /// Index des kompensierten Schritts
+    pub step_index: usize,
+    /// Beschreibung der Kompensation
+    pub description: String,
+    /// Kurzname der Aktion
+    pub action: &'static str,
+    /// Vorhergesagtes Ergebnis
+    pub outcome: SimulationOutcome,
+    /// Fehlergrund
+    pub reason: Option<String>,
+    /// Saldoänderungen der Kompensation
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+/// Verletzter Constraint
+#[derive(Debug, Clone, Serialize)]
+pub struct ConstraintViolation {
+    /// Type-Tag des Constraints (`min_trust`, `max_cost`, …)
+    pub constraint: &'static str,
+    /// Beschreibung
+    pub message: String,
+    /// Betroffener Schritt
+    pub step: Option<usize>,
+}
+
+/// Hinweis ohne Einfluss auf `would_succeed`
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationWarning {
+    /// Maschinenlesbarer Code (`no_trust_data`, `wait_unresolved`, …)
+    pub code: &'static str,
+    /// Beschreibung
+    pub message: String,
+    /// Betroffener Schritt
+    pub step: Option<usize>,
+}
+
+/// Ergebnis eines Dry-Runs
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationReport {
+    /// Simulierte Saga
+    pub saga: Saga,
+    /// Vorhersage pro Schritt
+    pub steps: Vec<SimulatedStep>,
+    /// Kosten der tatsächlich ausgeführten Schritte
+    pub total_cost: Cost,
+    /// Kosten aller geplanten Schritte
+    pub planned_cost: Cost,
+    /// Würde die Saga vollständig und ohne Constraint-Verletzung gelingen?
+    pub would_succeed: bool,
+    /// Erster fehlschlagender Schritt
+    pub failed_at: Option<usize>,
+    /// Grund des Fehlschlags
+    pub failure_reason: Option<String>,
+    /// Verletzte Constraints
+    pub violations: Vec<ConstraintViolation>,
+    /// Vorhergesagte Kompensationen (umgekehrte Reihenfolge)
+    pub compensations: Vec<SimulatedCompensation>,
+    /// Hinweise
+    pub warnings: Vec<SimulationWarning>,
+    /// Höchster geforderter Trust (Constraints und Gateway-Prüfungen)
+    pub required_trust: Option<f32>,
+}
+
+impl SimulationReport {
+    /// Einzeilige Zusammenfassung
+    pub fn summary(&self) -> String {
+        if let Some(idx) = self.failed_at {
+            let action = self.steps.get(idx).map(|s| s.action).unwrap_or("unknown");
+            return format!(
+                "fails at step {} ({}): {}",
+                idx,
+                action,
+                self.failure_reason.as_deref().unwrap_or("unknown error")
+            );
+        }
+        if !self.violations.is_empty() {
+            let messages: Vec<_> = self.violations.iter().map(|v| v.message.as_str()).collect();
+            return format!(
+                "violates {} constraint(s): {}",
+                self.violations.len(),
+                messages.join("; ")
+            );
+        }
+        format!(
+            "would succeed: {} step(s), gas {}, mana {}",
+            self.steps.len(),
+            self.total_cost.gas,
+            self.total_cost.mana
+        )
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary())
+    }
+}
+
+// ============================================================================
+// SagaSimulator
+// ============================================================================
+
+/// Dry-Run-Simulator für Sagas
+///
+/// Nutzt dieselben Erweiterungspunkte wie der [`SagaExecutor`], ruft aber
+/// keine schreibenden Store-Operationen auf.
+#[derive(Clone)]
+pub struct SagaSimulator {
+    storage: DecentralizedStorage,
+    gateway: Option<Arc<GatewayGuard>>,
+    policy_runner: Option<Arc<dyn SagaPolicyRunner>>,
+    condition_evaluator: Option<Arc<dyn SagaConditionEvaluator>>,
+    cost_table: CostTable,
+}
+
+impl SagaSimulator {
+    /// Erstelle Simulator auf dem gegebenen Storage
+    pub fn new(storage: DecentralizedStorage) -> Self {
+        Self {
+            storage,
+            gateway: None,
+            policy_runner: None,
+            condition_evaluator: None,
+            cost_table: CostTable::default(),
+        }
+    }
+
+    /// Mit GatewayGuard für `GatewayCheck`/`CrossRealm`
+    pub fn with_gateway(mut self, gateway: Arc<GatewayGuard>) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
+    /// Mit Policy-Runner für `ExecutePolicy`
+    pub fn with_policy_runner(mut self, runner: Arc<dyn SagaPolicyRunner>) -> Self {
+        self.policy_runner = Some(runner);
+        self
+    }
+
+    /// Mit Evaluator für `WaitFor`-Bedingungen
+    pub fn with_condition_evaluator(mut self, evaluator: Arc<dyn SagaConditionEvaluator>) -> Self {
+        self.condition_evaluator = Some(evaluator);
+        self
+    }
+
+    /// Mit eigener Kosten-Tabelle
+    pub fn with_cost_table(mut self, cost_table: CostTable) -> Self {
+        self.cost_table = cost_table;
+        self
+    }
+
+    /// Komponiert und simuliert einen Intent
+    ///
+    /// Constraints werden nicht vom Composer abgelehnt, sondern als
+    /// [`ConstraintViolation`]s gemeldet.
+    pub fn simulate_intent(&self, intent: &Intent) -> CompositionResult<SimulationReport> {
+        let composer = SagaComposer::new(SagaComposerConfig {
+            cost_table: self.cost_table.clone(),
+            ..Default::default()
+        });
+        let mut unconstrained = intent.clone();
+        unconstrained.constraints.clear();
+        let saga = composer.compose(&unconstrained)?;
+        Ok(self.simulate(&saga, &intent.constraints, Some(&intent.budget)))
+    }
+
+    /// Simuliert eine Saga gegen den aktuellen Zustand
+    pub fn simulate(
+        &self,
+        saga: &Saga,
+        constraints: &[Constraint],
+        budget: Option<&Budget>,
+    ) -> SimulationReport {
+        let mut run = SimulationRun {
+            simulator: self,
+            saga,
+            view: LedgerView::new(self),
+            lock_ids: BTreeMap::new(),
+            warnings: Vec::new(),
+        };
+
+        let costs: Vec<Cost> = saga.steps.iter().map(|s| self.cost_of(s)).collect();
+        let planned_cost = costs.iter().fold(Cost::ZERO, |acc, c| acc.seq(*c));
+
+        let mut steps = Vec::with_capacity(saga.steps.len());
+        let mut total_cost = Cost::ZERO;
+        let mut failure: Option<(usize, String)> = None;
+        for (idx, step) in saga.steps.iter().enumerate() {
+            let mut simulated = SimulatedStep {
+                index: idx,
+                action: action_name(&step.action),
+                description: step.description.clone(),
+                outcome: SimulationOutcome::Skipped,
+                reason: None,
+                cost: costs[idx],
+                balance_changes: Vec::new(),
+            };
+            if failure.is_none() {
+                total_cost = total_cost.seq(costs[idx]);
+                match run.step(idx, step) {
+                    Ok(prediction) => {
+                        simulated.outcome = prediction.outcome;
+                        simulated.reason = prediction.note;
+                        simulated.balance_changes = prediction.changes;
+                    }
+                    Err(reason) => {
+                        simulated.outcome = SimulationOutcome::Failure;
+                        simulated.reason = Some(reason.clone());
+                        failure = Some((idx, reason));
+                    }
+                }
+            }
+            steps.push(simulated);
+        }
+
+        let compensations = match &failure {
+            Some(_) => run.compensate(&steps),
+            None => Vec::new(),
+        };
+        let violations = run.check_constraints(constraints, budget, &planned_cost);
+        let required_trust = required_trust(saga, constraints);
+        let (failed_at, failure_reason) = failure.unzip();
+
+        SimulationReport {
+            saga: saga.clone(),
+            would_succeed: failed_at.is_none() && violations.is_empty(),
+            steps,
+            total_cost,
+            planned_cost,
+            failed_at,
+            failure_reason,
+            violations,
+            compensations,
+            warnings: run.warnings,
+            required_trust,
+        }
+    }
+
+    /// Geplante Kosten; Schritte ohne `expected_cost` werden neu geschätzt
+    fn cost_of(&self, step: &SagaStep) -> Cost {
+        if step.expected_cost == Cost::ZERO {
+            step_cost(&step.action, &self.cost_table)
+        } else {
+            step.expected_cost
+        }
+    }
+
+    /// Bekannte DID zu einer ID (für Namespace und Trust-Lookup)
+    fn known_dids(&self) -> HashMap<UniversalId, DID> {
+        self.storage
+            .identities
+            .list_all()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|identity| (identity.did.id, identity.did))
+            .collect()
+    }
+}
+
+// ============================================================================
+// Simulationslauf
+// ============================================================================
+
+/// Vorhersage eines erfolgreichen (oder ungewissen) Schritts
+struct StepPrediction {
+    outcome: SimulationOutcome,
+    note: Option<String>,
+    changes: Vec<BalanceChange>,
+}
+
+impl StepPrediction {
+    fn success(changes: Vec<BalanceChange>) -> Self {
+        Self {
+            outcome: SimulationOutcome::Success,
+            note: None,
+            changes,
+        }
+    }
+
+    fn note(message: impl Into<String>) -> Self {
+        Self {
+            outcome: SimulationOutcome::Success,
+            note: Some(message.into()),
+            changes: Vec::new(),
+        }
+    }
+}
+
+struct SimulationRun<'a> {
+    simulator: &'a SagaSimulator,
+    saga: &'a Saga,
+    view: LedgerView<'a>,
+    /// Locks pro Schritt (wie `SagaRecord::lock_ids`)
+    lock_ids: BTreeMap<usize, UniversalId>,
+    warnings: Vec<SimulationWarning>,
+}
+
+impl SimulationRun<'_> {
+    fn step(&mut self, idx: usize, step: &SagaStep) -> Result<StepPrediction, String> {
+        let actor = self.saga.executor;
+        match &step.action {
+            SagaAction::Transfer { .. }
+            | SagaAction::Lock { .. }
+            | SagaAction::Unlock { .. }
+            | SagaAction::Mint { .. }
+            | SagaAction::Burn { .. } => {
+                let changes = self.ledger_action(idx, &step.action, "lock")?;
+                Ok(StepPrediction::success(changes))
+            }
+            SagaAction::Delegate {
+                from,
+                capabilities,
+                trust_factor,
+                ..
+            } => {
+                check_owner(&actor, from)?;
+                InvariantChecker::check_delegation_trust_factor(*trust_factor)
+                    .map_err(|e| e.to_string())?;
+                for capability in capabilities {
+                    Capability::parse(capability).map_err(|e| e.to_string())?;
+                }
+                Ok(StepPrediction::note("delegation would be granted"))
+            }
+            SagaAction::GatewayCheck {
+                subject,
+                target_realm,
+                required_trust,
+            } => {
+                let from = step
+                    .realm_crossing
+                    .as_ref()
+                    .map(|c| c.from)
+                    .unwrap_or(ROOT_REALM_ID);
+                self.crossing(subject, &from, target_realm, *required_trust)
+            }
+            SagaAction::CrossRealm {
+                from_realm,
+                to_realm,
+                subject,
+            } => self.crossing(subject, from_realm, to_realm, 0.0),
+            SagaAction::ExecutePolicy { policy_id, params } => {
+                let runner = self
+                    .simulator
+                    .policy_runner
+                    .as_ref()
+                    .ok_or("no policy runner configured")?;
+                let realm = step
+                    .realm_crossing
+                    .as_ref()
+                    .map(|c| c.to)
+                    .unwrap_or(ROOT_REALM_ID);
+                match runner.run_policy(policy_id, &actor, &realm, params)? {
+                    true => Ok(StepPrediction::note(format!(
+                        "policy {} allowed",
+                        policy_id
+                    ))),
+                    false => Err(format!("policy {} denied", policy_id)),
+                }
+            }
+            SagaAction::WaitFor { condition, .. } => {
+                let evaluation = self
+                    .simulator
+                    .condition_evaluator
+                    .as_ref()
+                    .and_then(|e| e.evaluate(self.saga, condition));
+                match evaluation {
+                    Some(true) => Ok(StepPrediction::note(format!(
+                        "condition '{}' met",
+                        condition
+                    ))),
+                    Some(false) => Err(format!("condition '{}' can no longer be met", condition)),
+                    None => {
+                        let message = format!("condition '{}' is not yet decided", condition);
+                        self.warn("wait_unresolved", message.clone(), Some(idx));
+                        Ok(StepPrediction {
+                            outcome: SimulationOutcome::Uncertain,
+                            note: Some(message),
+                            changes: Vec::new(),
+                        })
+                    }
+                }
+            }
+            other => Err(format!("unsupported saga action: {}", action_name(other))),
+        }
+    }
+
+    fn crossing(
+        &self,
+        subject: &UniversalId,
+        from: &RealmId,
+        to: &RealmId,
+        required_trust: f32,
+    ) -> Result<StepPrediction, String> {
+        let gateway = self
+            .simulator
+            .gateway
+            .as_ref()
+            .ok_or("no gateway configured")?;
+        check_crossing(gateway, subject, from, to, required_trust).map(StepPrediction::note)
+    }
+
+    /// Ledger-Aktion auf dem Overlay (spiegelt `SagaExecutor::execute_ledger_action`)
+    fn ledger_action(
+        &mut self,
+        idx: usize,
+        action: &SagaAction,
+        lock_kind: &str,
+    ) -> Result<Vec<BalanceChange>, String> {
+        let actor = self.saga.executor;
+        match action {
+            SagaAction::Lock {
+                owner,
+                asset_type,
+                amount,
+                lock_id,
+                release_conditions,
+            } => {
+                check_owner(&actor, owner)?;
+                let lock_id =
+                    lock_id.unwrap_or_else(|| SagaExecutor::op_id(&self.saga.id, idx, lock_kind));
+                let changes =
+                    self.view
+                        .lock(lock_id, owner, asset_type, *amount, release_conditions)?;
+                self.lock_ids.insert(idx, lock_id);
+                Ok(changes)
+            }
+            SagaAction::Unlock { lock_id, to } => {
+                let lock_id =
+                    SagaExecutor::resolve_lock_id(self.saga, &self.lock_ids, idx, lock_id)
+                        .ok_or("no saga lock for placeholder lock id")?;
+                let owner = self.view.lock_owner(&lock_id)?;
+                check_owner(&actor, &owner)?;
+                self.view.unlock(&lock_id, *to)
+            }
+            SagaAction::Transfer {
+                from,
+                to,
+                amount,
+                asset_type,
+            } => {
+                check_owner(&actor, from)?;
+                match self.matching_lock(idx, from, asset_type, *amount) {
+                    Some(lock_id) => self.view.unlock(&lock_id, Some(*to)),
+                    None => self.view.transfer(from, to, asset_type, *amount),
+                }
+            }
+            SagaAction::Mint {
+                asset_type,
+                amount,
+                to,
+                ..
+            } => {
+                let ledger = &self.simulator.storage.ledger;
+                if !ledger
+                    .can_mint(&actor, asset_type)
+                    .map_err(|e| e.to_string())?
+                {
+                    return Err(unauthorized(&actor, "mint", asset_type));
+                }
+                self.view.credit(to, asset_type, *amount).map(|c| vec![c])
+            }
+            SagaAction::Burn {
+                asset_type,
+                amount,
+                from,
+                ..
+            } => {
+                let ledger = &self.simulator.storage.ledger;
+                if !ledger
+                    .can_burn(&actor, from, asset_type)
+                    .map_err(|e| e.to_string())?
+                {
+                    return Err(unauthorized(&actor, "burn", asset_type));
+                }
+                self.view.debit(from, asset_type, *amount).map(|c| vec![c])
+            }
+            other => Err(format!("unsupported ledger action: {}", action_name(other))),
+        }
+    }
+
+    /// Offener Saga-Lock, der exakt den Transfer deckt
+    fn matching_lock(
+        &self,
+        idx: usize,
+        from: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+    ) -> Option<UniversalId> {
+        self.saga.steps[idx]
+            .dependencies
+            .iter()
+            .filter_map(|d| self.lock_ids.get(d))
+            .chain(self.lock_ids.range(..idx).rev().map(|(_, id)| id))
+            .find(|lock_id| {
+                self.view.locks.get(*lock_id).is_some_and(|lock| {
+                    lock.open
+                        && lock.owner == *from
+                        && lock.asset_type == asset_type
+                        && lock.amount == amount
+                })
+            })
+            .copied()
+    }
+
+    /// Κ24: Kompensationen abgeschlossener Schritte in umgekehrter Reihenfolge
+    fn compensate(&mut self, steps: &[SimulatedStep]) -> Vec<SimulatedCompensation> {
+        let saga = self.saga;
+        let mut compensations = Vec::new();
+        for simulated in steps.iter().rev() {
+            if simulated.outcome != SimulationOutcome::Success {
+                continue;
+            }
+            let Some(compensation) = &saga.steps[simulated.index].compensation else {
+                continue;
+            };
+            let result = self.ledger_action(simulated.index, &compensation.action, "lock");
+            let (outcome, reason, balance_changes) = match result {
+                Ok(changes) => (SimulationOutcome::Success, None, changes),
+                Err(e) => (SimulationOutcome::Failure, Some(e), Vec::new()),
+            };
+            compensations.push(SimulatedCompensation {
+                step_index: simulated.index,
+                description: compensation.description.clone(),
+                action: action_name(&compensation.action),
+                outcome,
+                reason,
+                balance_changes,
+            });
+        }
+        compensations
+    }
+
+    // ========================================================================
+    // Constraints
+    // ========================================================================
+
+    fn check_constraints(
+        &mut self,
+        constraints: &[Constraint],
+        budget: Option<&Budget>,
+        planned_cost: &Cost,
+    ) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+        let counterparts = self.counterparts();
+        let known = if counterparts.is_empty() {
+            HashMap::new()
+        } else {
+            self.simulator.known_dids()
+        };
+
+        for constraint in constraints {
+            let tag = constraint.type_tag();
+            match constraint {
+                Constraint::MinTrust { value } => {
+                    self.check_trust(
+                        &counterparts,
+                        &known,
+                        TrustDimension::Reliability,
+                        *value,
+                        &mut violations,
+                    );
+                }
+                Constraint::Custom { name, value } if name == "min_trust" => {
+                    let Some((dimension, min)) = custom_min_trust(value) else {
+                        self.warn(
+                            "invalid_constraint",
+                            format!("cannot interpret min_trust constraint {}", value),
+                            None,
+                        );
+                        continue;
+                    };
+                    self.check_trust(&counterparts, &known, dimension, min, &mut violations);
+                }
+                Constraint::MaxCost { cost, amount, .. } => {
+                    if let Some((need, have)) = exceeded_cost(planned_cost, cost, *amount) {
+                        violations.push(ConstraintViolation {
+                            constraint: tag,
+                            message: format!("estimated cost {} exceeds limit {}", need, have),
+                            step: None,
+                        });
+                    }
+                }
+                Constraint::RealmRestriction { realm_ids } => {
+                    for (idx, realm) in target_realms(self.saga) {
+                        if !realm_ids.contains(&realm) {
+                            violations.push(ConstraintViolation {
+                                constraint: tag,
+                                message: format!("realm {} is not allowed", realm.to_hex()),
+                                step: Some(idx),
+                            });
+                        }
+                    }
+                }
+                Constraint::HumanOnly => {
+                    for (idx, id) in &counterparts {
+                        match known.get(id) {
+                            Some(did) if did.is_human_capable() => {}
+                            Some(did) => violations.push(ConstraintViolation {
+                                constraint: tag,
+                                message: format!("{} is not human-capable", did.to_uri()),
+                                step: Some(*idx),
+                            }),
+                            None => self.warn(
+                                "unknown_identity",
+                                format!("identity {} is unknown", id.to_hex()),
+                                Some(*idx),
+                            ),
+                        }
+                    }
+                }
+                Constraint::Deadline { lamport } => {
+                    let finish = self
+                        .saga
+                        .created_at
+                        .lamport()
+                        .saturating_add(self.saga.steps.len() as u32);
+                    if finish > *lamport {
+                        violations.push(ConstraintViolation {
+                            constraint: tag,
+                            message: format!(
+                                "saga finishes at lamport {} after deadline {}",
+                                finish, lamport
+                            ),
+                            step: None,
+                        });
+                    }
+                }
+                Constraint::Custom { .. } => {}
+            }
+        }
+
+        if let Some(budget) = budget {
+            if !budget.can_afford(planned_cost) {
+                violations.push(ConstraintViolation {
+                    constraint: "budget",
+                    message: format!(
+                        "estimated cost (gas {}, mana {}) exceeds remaining budget (gas {}, mana {})",
+                        planned_cost.gas,
+                        planned_cost.mana,
+                        budget.remaining().gas,
+                        budget.remaining().mana
+                    ),
+                    step: None,
+                });
+            }
+        }
+        violations
+    }
+
+    fn check_trust(
+        &mut self,
+        counterparts: &[(usize, UniversalId)],
+        known: &HashMap<UniversalId, DID>,
+        dimension: TrustDimension,
+        min: f32,
+        violations: &mut Vec<ConstraintViolation>,
+    ) {
+        let trust = &self.simulator.storage.trust;
+        for (idx, id) in counterparts {
+            let did = known.get(id).cloned().unwrap_or_else(|| subject_did(id));
+            if trust
+                .get_incoming(&did)
+                .map(|t| t.is_empty())
+                .unwrap_or(true)
+            {
+                self.warn(
+                    "no_trust_data",
+                    format!("no trust data for {}, using default", id.to_hex()),
+                    Some(*idx),
+                );
+            }
+            let Ok(reputation) = trust.compute_reputation(&did) else {
+                continue;
+            };
+            let actual = reputation.get(dimension);
+            if actual < min {
+                violations.push(ConstraintViolation {
+                    constraint: "min_trust",
+                    message: format!(
+                        "trust {} of {} is {:.3} < {:.3}",
+                        dimension,
+                        id.to_hex(),
+                        actual,
+                        min
+                    ),
+                    step: Some(*idx),
+                });
+            }
+        }
+    }
+
+    /// Gegenparteien der Saga (ohne den Ausführenden), je mit erstem Schritt
+    fn counterparts(&self) -> Vec<(usize, UniversalId)> {
+        let mut counterparts: Vec<(usize, UniversalId)> = Vec::new();
+        for step in &self.saga.steps {
+            let id = match &step.action {
+                SagaAction::Transfer { to, .. } | SagaAction::Delegate { to, .. } => Some(*to),
+                SagaAction::Attest { subject, .. } => Some(*subject),
+                SagaAction::Unlock { to, .. } => *to,
+                _ => None,
+            };
+            if let Some(id) = id {
+                if id != self.saga.executor && !counterparts.iter().any(|(_, c)| *c == id) {
+                    counterparts.push((step.index, id));
+                }
+            }
+        }
+        counterparts
+    }
+
+    fn warn(&mut self, code: &'static str, message: impl Into<String>, step: Option<usize>) {
+        self.warnings.push(SimulationWarning {
+            code,
+            message: message.into(),
+            step,
+        });
+    }
+}
+
+/// Höchster geforderter Trust aus Constraints und Gateway-Prüfungen
+fn required_trust(saga: &Saga, constraints: &[Constraint]) -> Option<f32> {
+    let from_constraints = constraints.iter().filter_map(|c| match c {
+        Constraint::MinTrust { value } => Some(*value),
+        Constraint::Custom { name, value } if name == "min_trust" => {
+            custom_min_trust(value).map(|(_, v)| v)
+        }
+        _ => None,
+    });
+    let from_steps = saga.steps.iter().filter_map(|s| match &s.action {
+        SagaAction::GatewayCheck { required_trust, .. } if *required_trust > 0.0 => {
+            Some(*required_trust)
+        }
+        _ => None,
+    });
+    from_constraints.chain(from_steps).reduce(f32::max)
+}
+
+/// `{"dimension": "I", "value": 0.7}` aus `Constraint::Custom { name: "min_trust" }`
+fn custom_min_trust(value: &serde_json::Value) -> Option<(TrustDimension, f32)> {
+    let symbol = value.get("dimension")?.as_str()?;
+    let dimension = TrustDimension::ALL
+        .into_iter()
+        .find(|d| d.symbol().to_string() == symbol)?;
+    let min = value.get("value")?.as_f64()? as f32;
+    Some((dimension, min))
+}
+
+/// Ziel-Realms aller Realm-Wechsel
+fn target_realms(saga: &Saga) -> Vec<(usize, RealmId)> {
+    saga.steps
+        .iter()
+        .filter_map(|s| match &s.action {
+            SagaAction::CrossRealm { to_realm, .. } => Some((s.index, *to_realm)),
+            SagaAction::GatewayCheck { target_realm, .. } => Some((s.index, *target_realm)),
+            _ => s.realm_crossing.as_ref().map(|c| (s.index, c.to)),
+        })
+        .collect()
+}
+
+fn check_owner(actor: &UniversalId, owner: &UniversalId) -> Result<(), String> {
+    if actor != owner {
+        return Err(format!(
+            "executor {} may not move funds of {}",
+            actor.to_hex(),
+            owner.to_hex()
+        ));
+    }
+    Ok(())
+}
+
+fn unauthorized(actor: &UniversalId, action: &str, asset_type: &str) -> String {
+    LedgerError::Unauthorized {
+        actor: actor.to_hex(),
+        action: action.to_string(),
+        asset_type: asset_type.to_string(),
+    }
+    .to_string()
+}
+
+// ============================================================================
+// LedgerView
+// ============================================================================
+
+/// Escrow-Lock im Overlay
+struct SimLock {
+    owner: UniversalId,
+    asset_type: String,
+    amount: u64,
+    open: bool,
+    pending: Vec<String>,
+}
+
+/// Copy-on-Write-Sicht auf den Ledger
+///
+/// Liest Salden und Locks beim ersten Zugriff aus dem Ledger und hält alle
+/// simulierten Buchungen ausschließlich im Speicher.
+struct LedgerView<'a> {
+    simulator: &'a SagaSimulator,
+    balances: HashMap<(UniversalId, String), u64>,
+    locks: HashMap<UniversalId, SimLock>,
+}
+
+impl<'a> LedgerView<'a> {
+    fn new(simulator: &'a SagaSimulator) -> Self {
+        Self {
+            simulator,
+            balances: HashMap::new(),
+            locks: HashMap::new(),
+        }
+    }
+
+    fn balance(&mut self, holder: &UniversalId, asset_type: &str) -> Result<u64, String> {
+        let key = (*holder, asset_type.to_string());
+        if let Some(balance) = self.balances.get(&key) {
+            return Ok(*balance);
+        }
+        let balance = self
+            .simulator
+            .storage
+            .ledger
+            .balance_of(holder, asset_type)
+            .map_err(|e| e.to_string())?;
+        self.balances.insert(key, balance);
+        Ok(balance)
+    }
+
+    fn debit(
+        &mut self,
+        holder: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+    ) -> Result<BalanceChange, String> {
+        if amount == 0 {
+            return Err(LedgerError::ZeroAmount.to_string());
+        }
+        let before = self.balance(holder, asset_type)?;
+        let after = before.checked_sub(amount).ok_or_else(|| {
+            LedgerError::InsufficientBalance {
+                account: LedgerAccount::Holder(*holder).key(),
+                asset_type: asset_type.to_string(),
+                available: before,
+                required: amount,
+            }
+            .to_string()
+        })?;
+        self.balances
+            .insert((*holder, asset_type.to_string()), after);
+        Ok(BalanceChange {
+            holder: *holder,
+            asset_type: asset_type.to_string(),
+            before,
+            after,
+        })
+    }
+
+    fn credit(
+        &mut self,
+        holder: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+    ) -> Result<BalanceChange, String> {
+        if amount == 0 {
+            return Err(LedgerError::ZeroAmount.to_string());
+        }
+        let before = self.balance(holder, asset_type)?;
+        let after = before.checked_add(amount).ok_or_else(|| {
+            LedgerError::Overflow(LedgerAccount::Holder(*holder).key()).to_string()
+        })?;
+        self.balances
+            .insert((*holder, asset_type.to_string()), after);
+        Ok(BalanceChange {
+            holder: *holder,
+            asset_type: asset_type.to_string(),
+            before,
+            after,
+        })
+    }
+
+    fn transfer(
+        &mut self,
+        from: &UniversalId,
+        to: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+    ) -> Result<Vec<BalanceChange>, String> {
+        let debit = self.debit(from, asset_type, amount)?;
+        let credit = self.credit(to, asset_type, amount)?;
+        Ok(vec![debit, credit])
+    }
+
+    fn lock(
+        &mut self,
+        lock_id: UniversalId,
+        owner: &UniversalId,
+        asset_type: &str,
+        amount: u64,
+        release_conditions: &[String],
+    ) -> Result<Vec<BalanceChange>, String> {
+        let exists = self.locks.contains_key(&lock_id)
+            || self
+                .simulator
+                .storage
+                .ledger
+                .lock_info(&lock_id)
+                .map_err(|e| e.to_string())?
+                .is_some();
+        if exists {
+            return Err(LedgerError::LockExists(lock_id.to_hex()).to_string());
+        }
+        let change = self.debit(owner, asset_type, amount)?;
+        self.locks.insert(
+            lock_id,
+            SimLock {
+                owner: *owner,
+                asset_type: asset_type.to_string(),
+                amount,
+                open: true,
+                pending: release_conditions.to_vec(),
+            },
+        );
+        Ok(vec![change])
+    }
+
+    fn load_lock(&mut self, lock_id: &UniversalId) -> Result<&mut SimLock, String> {
+        if !self.locks.contains_key(lock_id) {
+            let lock = self
+                .simulator
+                .storage
+                .ledger
+                .lock_info(lock_id)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| LedgerError::LockNotFound(lock_id.to_hex()).to_string())?;
+            self.locks.insert(
+                *lock_id,
+                SimLock {
+                    owner: lock.owner,
+                    open: lock.status == LockStatus::Locked,
+                    pending: lock.pending_conditions(),
+                    asset_type: lock.asset_type,
+                    amount: lock.amount,
+                },
+            );
+        }
+        Ok(self.locks.get_mut(lock_id).expect("lock loaded above"))
+    }
+
+    fn lock_owner(&mut self, lock_id: &UniversalId) -> Result<UniversalId, String> {
+        self.load_lock(lock_id).map(|lock| lock.owner)
+    }
+
+    fn unlock(
+        &mut self,
+        lock_id: &UniversalId,
+        to: Option<UniversalId>,
+    ) -> Result<Vec<BalanceChange>, String> {
+        let lock = self.load_lock(lock_id)?;
+        if !lock.open {
+            return Err(LedgerError::LockClosed(lock_id.to_hex()).to_string());
+        }
+        let recipient = to.unwrap_or(lock.owner);
+        if recipient != lock.owner && !lock.pending.is_empty() {
+            return Err(LedgerError::ConditionsNotMet {
+                lock_id: lock_id.to_hex(),
+                missing: lock.pending.clone(),
+            }
+            .to_string());
+        }
+        lock.open = false;
+        let (asset_type, amount) = (lock.asset_type.clone(), lock.amount);
+        self.credit(&recipient, &asset_type, amount)
+            .map(|c| vec![c])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::unified::{DIDNamespace, Goal, TrustVector6D};
+    use crate::local::NATIVE_ASSET;
+    use std::collections::HashMap as Params;
+
+    fn setup(balance: u64) -> (SagaSimulator, UniversalId, UniversalId) {
+        let storage = DecentralizedStorage::open_temporary().unwrap();
+        let alice = UniversalId::new(UniversalId::TAG_DID, 1, b"alice");
+        let bob = UniversalId::new(UniversalId::TAG_DID, 1, b"bob");
+        storage.ledger.register_asset(NATIVE_ASSET, alice).unwrap();
+        storage
+            .ledger
+            .mint(&alice, &alice, NATIVE_ASSET, balance, None)
+            .unwrap();
+        (SagaSimulator::new(storage), alice, bob)
+    }
+
+    fn transfer_intent(from: UniversalId, to: UniversalId, amount: u64) -> Intent {
+        Intent::new(
+            from,
+            Goal::Transfer {
+                to,
+                amount,
+                asset_type: NATIVE_ASSET.to_string(),
+            },
+            ROOT_REALM_ID,
+            1,
+        )
+    }
+
+    #[test]
+    fn test_simulate_transfer_leaves_ledger_untouched() {
+        let (simulator, alice, bob) = setup(500);
+        let report = simulator
+            .simulate_intent(&transfer_intent(alice, bob, 200))
+            .unwrap();
+
+        assert!(report.would_succeed, "{}", report.summary());
+        assert_eq!(report.steps.len(), 2);
+        assert!(report.planned_cost.mana > 0);
+        let bob_change = report.steps[1]
+            .balance_changes
+            .iter()
+            .find(|c| c.holder == bob)
+            .unwrap();
+        assert_eq!((bob_change.before, bob_change.after), (0, 200));
+
+        let ledger = &simulator.storage.ledger;
+        assert_eq!(ledger.balance_of(&alice, NATIVE_ASSET).unwrap(), 500);
+        assert_eq!(ledger.balance_of(&bob, NATIVE_ASSET).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_simulate_insufficient_balance() {
+        let (simulator, alice, bob) = setup(50);
+        let report = simulator
+            .simulate_intent(&transfer_intent(alice, bob, 200))
+            .unwrap();
+
+        assert!(!report.would_succeed);
+        assert_eq!(report.failed_at, Some(0));
+        assert_eq!(report.steps[1].outcome, SimulationOutcome::Skipped);
+        assert!(report.summary().starts_with("fails at step 0 (lock)"));
+        assert!(report.compensations.is_empty());
+    }
+
+    #[test]
+    fn test_simulate_predicts_compensation() {
+        let (simulator, alice, bob) = setup(500);
+        let intent = transfer_intent(alice, bob, 200);
+        let mut saga = SagaComposer::default().compose(&intent).unwrap();
+        let policy = SagaStep::new(
+            1,
+            "Check policy",
+            SagaAction::ExecutePolicy {
+                policy_id: "kyc".into(),
+                params: Params::new(),
+            },
+        );
+        saga.steps.insert(1, policy);
+        saga.steps[2].index = 2;
+
+        let report = simulator.simulate(&saga, &[], None);
+        assert_eq!(report.failed_at, Some(1));
+        assert_eq!(
+            report.failure_reason.as_deref(),
+            Some("no policy runner configured")
+        );
+        assert_eq!(report.compensations.len(), 1);
+        let refund = &report.compensations[0];
+        assert_eq!(refund.outcome, SimulationOutcome::Success);
+        assert_eq!(refund.balance_changes[0].after, 500);
+    }
+
+    #[test]
+    fn test_simulate_reports_constraint_violations() {
+        let (simulator, alice, bob) = setup(500);
+        let trust = &simulator.storage.trust;
+        let carol = DID::new(DIDNamespace::Self_, b"carol");
+        let bob_did = subject_did(&bob);
+        trust
+            .put(
+                carol,
+                bob_did,
+                TrustVector6D::new(0.2, 0.9, 0.9, 0.9, 0.9, 0.9),
+            )
+            .unwrap();
+
+        let mut intent = transfer_intent(alice, bob, 100);
+        intent.constraints = vec![
+            Constraint::MinTrust { value: 0.6 },
+            Constraint::MaxCost {
+                cost: Cost::new(0, 1, 0.0),
+                amount: None,
+                asset_type: None,
+            },
+        ];
+        let report = simulator.simulate_intent(&intent).unwrap();
+
+        assert!(report.failed_at.is_none());
+        assert!(!report.would_succeed);
+        let tags: Vec<_> = report.violations.iter().map(|v| v.constraint).collect();
+        assert_eq!(tags, vec!["min_trust", "max_cost"]);
+        assert_eq!(report.required_trust, Some(0.6));
+    }
+}
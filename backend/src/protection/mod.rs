@@ -12,6 +12,7 @@
 //! │  adaptive_calibration - Dynamische Parameteranpassung (Κ19, §IX)            │
 //! │  diversity            - Überwacht System-Diversität (Κ20)                   │
 //! │  quadratic            - Quadratisches Voting (Κ21)                          │
+//! │  vote_weighting       - Token-, Reputation- und Delegated-Governance        │
 //! │  anomaly              - Erkennt abnormales Verhalten                        │
 //! └─────────────────────────────────────────────────────────────────────────────┘
 //! ```
@@ -21,6 +22,7 @@ pub mod anomaly;
 pub mod anti_calcification;
 pub mod diversity;
 pub mod quadratic;
+pub mod vote_weighting;
 
 // Re-exports
 pub use adaptive_calibration::{
@@ -31,3 +33,4 @@ pub use anomaly::AnomalyDetector;
 pub use anti_calcification::{AntiCalcification, AntiCalcificationConfig};
 pub use diversity::DiversityMonitor;
 pub use quadratic::QuadraticGovernance;
+pub use vote_weighting::{weighting_for, VoteWeighting, WeightResolution, WeightingContext};
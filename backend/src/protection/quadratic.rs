@@ -191,6 +191,11 @@ impl QuadraticGovernance {
         *self.credits.entry(did.clone()).or_default() += amount;
     }
 
+    /// Ist die DID als Voter registriert?
+    pub fn is_voter(&self, did: &DID) -> bool {
+        self.credits.contains_key(did)
+    }
+
     /// Erstelle neues Proposal
     pub fn create_proposal(
         &mut self,
@@ -198,9 +203,22 @@ impl QuadraticGovernance {
         title: String,
         description: String,
         proposer: DID,
+    ) -> Proposal {
+        let period = chrono::Duration::hours(self.config.proposal_duration_hours as i64);
+        self.create_proposal_with_period(id, title, description, proposer, period)
+    }
+
+    /// Erstelle neues Proposal mit eigener Abstimmungsdauer
+    pub fn create_proposal_with_period(
+        &mut self,
+        id: String,
+        title: String,
+        description: String,
+        proposer: DID,
+        period: chrono::Duration,
     ) -> Proposal {
         let now = Utc::now();
-        let closes_at = now + chrono::Duration::hours(self.config.proposal_duration_hours as i64);
+        let closes_at = now + period;
 
         let proposal = Proposal {
             id: id.clone(),
@@ -232,11 +250,17 @@ impl QuadraticGovernance {
             .get(proposal_id)
             .ok_or_else(|| GovernanceError::ProposalNotFound(proposal_id.to_string()))?;
 
-        // Prüfe Proposal ist aktiv
-        if proposal.status != ProposalStatus::Active {
+        // Prüfe Proposal ist aktiv und Frist nicht abgelaufen
+        if proposal.status != ProposalStatus::Active || Utc::now() >= proposal.closes_at {
             return Err(GovernanceError::ProposalClosed);
         }
 
+        if vote_count == 0 {
+            return Err(GovernanceError::InvalidVoteCount(
+                "at least one vote required".to_string(),
+            ));
+        }
+
         // Prüfe nicht bereits abgestimmt
         if self
             .votes
@@ -349,6 +373,33 @@ impl QuadraticGovernance {
         Ok(status)
     }
 
+    /// Aktive Proposals, deren Frist zum Zeitpunkt `now` abgelaufen ist
+    pub fn due_proposals(&self, now: DateTime<Utc>) -> Vec<String> {
+        let mut due: Vec<&Proposal> = self
+            .proposals
+            .values()
+            .filter(|p| p.status == ProposalStatus::Active && now >= p.closes_at)
+            .collect();
+        due.sort_by_key(|p| p.closes_at);
+        due.into_iter().map(|p| p.id.clone()).collect()
+    }
+
+    /// Stelle persistiertes Proposal wieder her (ohne Credits zu verändern)
+    pub fn restore_proposal(&mut self, proposal: Proposal) {
+        self.votes.entry(proposal.id.clone()).or_default();
+        self.proposals.insert(proposal.id.clone(), proposal);
+    }
+
+    /// Stelle persistierte Stimme wieder her und ziehe ihre Kosten ab
+    pub fn restore_vote(&mut self, proposal_id: &str, vote: Vote) {
+        let credits = self.credits.entry(vote.voter.clone()).or_default();
+        *credits = credits.saturating_sub(vote.credit_cost);
+        self.votes
+            .entry(proposal_id.to_string())
+            .or_default()
+            .insert(vote.voter.clone(), vote);
+    }
+
     /// Statistiken
     pub fn stats(&self) -> QuadraticGovernanceStats {
         let active = self
@@ -515,4 +566,45 @@ mod tests {
         assert_eq!(tally.votes_against, 100);
         assert!(!tally.passed); // Die kleinen gewinnen!
     }
+
+    #[test]
+    fn test_deadline_and_restore() {
+        let mut gov = QuadraticGovernance::default();
+        let alice = DID::new_self(b"alice");
+        gov.register_voter(alice.clone());
+
+        let proposal = gov.create_proposal_with_period(
+            "prop1".to_string(),
+            "Test".to_string(),
+            "Desc".to_string(),
+            alice.clone(),
+            chrono::Duration::zero(),
+        );
+
+        // Frist abgelaufen → keine Stimmen mehr, aber fällig
+        assert!(matches!(
+            gov.vote(&proposal.id, alice.clone(), VoteDirection::For, 1),
+            Err(GovernanceError::ProposalClosed)
+        ));
+        assert_eq!(gov.due_proposals(Utc::now()), vec!["prop1".to_string()]);
+
+        // Wiederherstellung zieht Kosten persistierter Stimmen ab
+        let mut restored = QuadraticGovernance::default();
+        restored.add_credits(&alice, 100);
+        restored.restore_proposal(proposal);
+        restored.restore_vote(
+            "prop1",
+            Vote {
+                voter: alice.clone(),
+                direction: VoteDirection::For,
+                vote_count: 4,
+                credit_cost: 16,
+                timestamp: Utc::now(),
+            },
+        );
+        assert_eq!(restored.get_credits(&alice), 84);
+        assert_eq!(restored.tally("prop1").unwrap().votes_for, 4);
+        assert_eq!(restored.finalize("prop1").unwrap(), ProposalStatus::Passed);
+        assert!(restored.due_proposals(Utc::now()).is_empty());
+    }
 }
@@ -0,0 +1,420 @@
+//! # Vote Weighting
+//!
+//! Austauschbare Stimmgewichtungen für die Governance-Typen eines Realms.
+//!
+//! ```text
+//! GovernanceType │ Stimmkraft                          │ Schutz
+//! ───────────────┼─────────────────────────────────────┼──────────────────────
+//! Quadratic      │ Credits, n Stimmen kosten n²        │ Κ21 (QuadraticGovernance)
+//! Token          │ Ledger-Saldo zur Snapshot-Höhe      │ —
+//! Reputation     │ gewählte 𝕎-Dimension × scale       │ Κ19 Power-Cap
+//! Delegated      │ 1 pro Mitglied + Delegationsketten  │ Zyklen, max_depth
+//! ```
+//!
+//! Strategien arbeiten ausschließlich auf einem [`WeightingContext`]: Salden,
+//! Trust-Vektoren und Delegationen werden vom Aufrufer geladen.
+//!
+//! Das Modell eines Realms ([`GovernanceModel`]) wird über eine Governance-Regel
+//! in `RealmRules` gewählt.
+
+use crate::domain::unified::{
+    Capability, Delegation, GovernanceModel, GovernanceType, TrustDimension, TrustVector6D,
+    UniversalId,
+};
+use crate::domain::{DIDNamespace, DID};
+use crate::protection::anti_calcification::{AntiCalcification, AntiCalcificationConfig};
+use std::collections::{HashMap, HashSet};
+
+/// Standard-Skalierung für Reputation (𝕎-Wert 1.0 = 1000 Stimmen)
+pub const REPUTATION_SCALE: u64 = 1_000;
+
+/// Gewichtungs-Strategie eines Modells (`None` für Quadratic, das Credits verwendet)
+pub fn weighting_for(model: &GovernanceModel) -> Option<Box<dyn VoteWeighting>> {
+    match model {
+        GovernanceModel::Quadratic => None,
+        GovernanceModel::Token { .. } => Some(Box::new(TokenWeighting)),
+        GovernanceModel::Reputation { dimension } => {
+            Some(Box::new(ReputationWeighting::new(*dimension)))
+        }
+        GovernanceModel::Delegated { max_depth } => {
+            Some(Box::new(DelegatedWeighting::new(*max_depth)))
+        }
+    }
+}
+
+// ============================================================================
+// VoteWeighting
+// ============================================================================
+
+/// Eingaben einer Gewichtung (vom Aufrufer geladen)
+#[derive(Debug, Default)]
+pub struct WeightingContext {
+    /// Stimmberechtigte Mitglieder
+    pub members: Vec<UniversalId>,
+    /// Salden zur Snapshot-Höhe (Token)
+    pub balances: HashMap<UniversalId, u64>,
+    /// Trust-Vektoren (Reputation)
+    pub trust: HashMap<UniversalId, TrustVector6D>,
+    /// Gültige Delegationen (Delegated)
+    pub delegations: Vec<Delegation>,
+}
+
+/// Ergebnis einer Gewichtung
+#[derive(Debug, Clone, Default)]
+pub struct WeightResolution {
+    /// Effektives Gewicht je Abstimmendem
+    pub weights: HashMap<UniversalId, u64>,
+    /// Gesamte Stimmkraft aller Mitglieder
+    pub total_power: u64,
+    /// Gini-Koeffizient der Stimmkraft
+    pub gini: f64,
+    /// Κ19-Alarm: Top-Anteil hält zu viel Stimmkraft
+    pub calcified: bool,
+    /// Über Delegation gezählte Stimmen
+    pub delegated_votes: u64,
+    /// Längste aufgelöste Delegationskette
+    pub max_delegation_depth: usize,
+    /// Verhinderte zirkuläre Delegationen
+    pub cycles_prevented: usize,
+    /// Durch den Κ19-Power-Cap gekappte Mitglieder
+    pub capped: usize,
+}
+
+impl WeightResolution {
+    /// Gewichte der Abstimmenden aus der Stimmkraft aller Mitglieder
+    pub fn direct(power: &HashMap<UniversalId, u64>, voters: &HashSet<UniversalId>) -> Self {
+        Self {
+            weights: voters
+                .iter()
+                .map(|voter| (*voter, power.get(voter).copied().unwrap_or(0)))
+                .collect(),
+            total_power: power.values().sum(),
+            gini: gini(power),
+            calcified: is_calcified(power),
+            ..Default::default()
+        }
+    }
+
+    /// Summe der abgegebenen Gewichte
+    pub fn participating_power(&self) -> u64 {
+        self.weights.values().sum()
+    }
+}
+
+/// Austauschbare Stimmgewichtung eines Governance-Typs
+pub trait VoteWeighting: Send + Sync {
+    /// Governance-Typ dieser Gewichtung
+    fn governance_type(&self) -> GovernanceType;
+
+    /// Stimmkraft je Mitglied (vor Delegation)
+    fn power(&self, ctx: &WeightingContext) -> HashMap<UniversalId, u64>;
+
+    /// Effektive Gewichte der Abstimmenden
+    fn resolve(&self, ctx: &WeightingContext, voters: &HashSet<UniversalId>) -> WeightResolution {
+        WeightResolution::direct(&self.power(ctx), voters)
+    }
+}
+
+/// Gini-Koeffizient einer Stimmkraft-Verteilung (via [`AntiCalcification`])
+pub fn gini(power: &HashMap<UniversalId, u64>) -> f64 {
+    monitor(power).gini_coefficient()
+}
+
+/// Κ19: Hält der Top-Anteil mehr Stimmkraft als erlaubt?
+pub fn is_calcified(power: &HashMap<UniversalId, u64>) -> bool {
+    monitor(power).check_system_calcification().is_err()
+}
+
+fn monitor(power: &HashMap<UniversalId, u64>) -> AntiCalcification {
+    let mut monitor = AntiCalcification::default();
+    for (id, value) in power {
+        monitor.set_power(member_did(id), *value as f64);
+    }
+    monitor
+}
+
+fn member_did(id: &UniversalId) -> DID {
+    DID {
+        id: *id,
+        namespace: DIDNamespace::Self_,
+        public_key: [0u8; 32],
+        created_at: Default::default(),
+    }
+}
+
+// ============================================================================
+// Strategien
+// ============================================================================
+
+/// 1 Token = 1 Stimme
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenWeighting;
+
+impl VoteWeighting for TokenWeighting {
+    fn governance_type(&self) -> GovernanceType {
+        GovernanceType::Token
+    }
+
+    fn power(&self, ctx: &WeightingContext) -> HashMap<UniversalId, u64> {
+        ctx.members
+            .iter()
+            .map(|m| (*m, ctx.balances.get(m).copied().unwrap_or(0)))
+            .collect()
+    }
+}
+
+/// Stimmkraft aus einer Trust-Dimension, gekappt nach Κ19
+#[derive(Debug, Clone)]
+pub struct ReputationWeighting {
+    pub dimension: TrustDimension,
+    pub scale: u64,
+    pub anti_calcification: AntiCalcificationConfig,
+}
+
+impl ReputationWeighting {
+    pub fn new(dimension: TrustDimension) -> Self {
+        Self {
+            dimension,
+            scale: REPUTATION_SCALE,
+            anti_calcification: AntiCalcificationConfig::default(),
+        }
+    }
+
+    /// Dimension je Mitglied ∈ [0, 1]
+    fn raw_power(&self, ctx: &WeightingContext) -> HashMap<UniversalId, f64> {
+        ctx.members
+            .iter()
+            .map(|m| {
+                let value = ctx
+                    .trust
+                    .get(m)
+                    .map(|t| t.get(self.dimension))
+                    .unwrap_or(0.0);
+                (*m, value as f64)
+            })
+            .collect()
+    }
+
+    /// Gekappte, skalierte Stimmkraft und Anzahl gekappter Mitglieder
+    ///
+    /// Κ19: power(s) ≤ √(Σ power) / |S|^(1/4), angewendet vor der Skalierung
+    fn capped_power(&self, ctx: &WeightingContext) -> (HashMap<UniversalId, u64>, usize) {
+        let raw = self.raw_power(ctx);
+        let mut monitor = AntiCalcification::new(self.anti_calcification.clone());
+        for (id, value) in &raw {
+            monitor.set_power(member_did(id), *value);
+        }
+        let cap = monitor.calculate_power_cap();
+        let capped = raw.values().filter(|p| **p > cap).count();
+        let power = raw
+            .into_iter()
+            .map(|(id, p)| (id, (p.min(cap) * self.scale as f64).round() as u64))
+            .collect();
+        (power, capped)
+    }
+}
+
+impl VoteWeighting for ReputationWeighting {
+    fn governance_type(&self) -> GovernanceType {
+        GovernanceType::Reputation
+    }
+
+    fn power(&self, ctx: &WeightingContext) -> HashMap<UniversalId, u64> {
+        self.capped_power(ctx).0
+    }
+
+    fn resolve(&self, ctx: &WeightingContext, voters: &HashSet<UniversalId>) -> WeightResolution {
+        let (power, capped) = self.capped_power(ctx);
+        WeightResolution {
+            capped,
+            ..WeightResolution::direct(&power, voters)
+        }
+    }
+}
+
+/// Liquid Democracy: nicht abstimmende Mitglieder zählen beim ersten
+/// abstimmenden Delegierten ihrer Kette
+///
+/// Eine Delegation überträgt Stimmkraft, wenn sie `Capability::Delegate`
+/// (oder `All`) enthält. Die Kette endet nach `min(max_depth, Delegate.max_depth)`
+/// Schritten oder beim ersten Zyklus.
+#[derive(Debug, Clone, Copy)]
+pub struct DelegatedWeighting {
+    pub max_depth: u8,
+}
+
+impl DelegatedWeighting {
+    pub fn new(max_depth: u8) -> Self {
+        Self { max_depth }
+    }
+
+    /// Aktuellste stimmübertragende Delegation je Delegator
+    fn edges(&self, ctx: &WeightingContext) -> HashMap<UniversalId, (UniversalId, u8)> {
+        let mut edges: HashMap<UniversalId, &Delegation> = HashMap::new();
+        for delegation in &ctx.delegations {
+            if delegation.revoked || vote_depth(delegation).is_none() {
+                continue;
+            }
+            let newer = edges
+                .get(&delegation.delegator)
+                .is_none_or(|current| delegation.created_at > current.created_at);
+            if newer {
+                edges.insert(delegation.delegator, delegation);
+            }
+        }
+        edges
+            .into_iter()
+            .filter_map(|(from, d)| vote_depth(d).map(|depth| (from, (d.delegate, depth))))
+            .collect()
+    }
+}
+
+/// Erlaubte Kettentiefe einer Delegation (`None`: überträgt keine Stimmen)
+fn vote_depth(delegation: &Delegation) -> Option<u8> {
+    delegation
+        .capabilities
+        .iter()
+        .filter_map(|cap| match cap {
+            Capability::Delegate { max_depth } => Some(*max_depth),
+            Capability::All => Some(u8::MAX),
+            _ => None,
+        })
+        .max()
+}
+
+impl VoteWeighting for DelegatedWeighting {
+    fn governance_type(&self) -> GovernanceType {
+        GovernanceType::Delegated
+    }
+
+    fn power(&self, ctx: &WeightingContext) -> HashMap<UniversalId, u64> {
+        ctx.members.iter().map(|m| (*m, 1)).collect()
+    }
+
+    fn resolve(&self, ctx: &WeightingContext, voters: &HashSet<UniversalId>) -> WeightResolution {
+        let members: HashSet<UniversalId> = ctx.members.iter().copied().collect();
+        let edges = self.edges(ctx);
+        let mut weights: HashMap<UniversalId, u64> = voters
+            .iter()
+            .filter(|v| members.contains(v))
+            .map(|v| (*v, 1))
+            .collect();
+        let mut resolution = WeightResolution::default();
+
+        for member in &ctx.members {
+            if voters.contains(member) {
+                continue;
+            }
+            let mut visited = HashSet::from([*member]);
+            let mut current = *member;
+            let mut limit = self.max_depth;
+            let mut depth = 0u8;
+            while let Some((next, allowed)) = edges.get(&current) {
+                limit = limit.min(*allowed);
+                if depth >= limit || !members.contains(next) {
+                    break;
+                }
+                if !visited.insert(*next) {
+                    resolution.cycles_prevented += 1;
+                    break;
+                }
+                depth += 1;
+                current = *next;
+                if let Some(weight) = weights.get_mut(&current) {
+                    *weight += 1;
+                    resolution.delegated_votes += 1;
+                    resolution.max_delegation_depth =
+                        resolution.max_delegation_depth.max(depth as usize);
+                    break;
+                }
+            }
+        }
+
+        resolution.total_power = members.len() as u64;
+        resolution.gini = gini(&weights);
+        resolution.calcified = is_calcified(&weights);
+        resolution.weights = weights;
+        resolution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(name: &str) -> UniversalId {
+        UniversalId::new(UniversalId::TAG_DID, 1, name.as_bytes())
+    }
+
+    fn delegate(from: UniversalId, to: UniversalId, max_depth: u8) -> Delegation {
+        Delegation::new(from, to, 1.0, vec![Capability::Delegate { max_depth }])
+    }
+
+    #[test]
+    fn test_token_and_reputation_power() {
+        let (a, b, c) = (id("a"), id("b"), id("c"));
+        let members = [a, b, c];
+        let balances = HashMap::from([(a, 900), (b, 100)]);
+        let ctx = WeightingContext {
+            members: members.to_vec(),
+            balances,
+            ..Default::default()
+        };
+        let resolution = TokenWeighting.resolve(&ctx, &HashSet::from([a, c]));
+        assert_eq!(resolution.weights[&a], 900);
+        assert_eq!(resolution.weights[&c], 0);
+        assert_eq!(resolution.total_power, 1_000);
+        assert!(resolution.gini > 0.5);
+
+        // Κ19: die dominante Reputation wird gekappt
+        let mut whale = TrustVector6D::NEWCOMER;
+        whale.i = 1.0;
+        let mut small = TrustVector6D::NEWCOMER;
+        small.i = 0.1;
+        let trust = HashMap::from([(a, whale), (b, small), (c, small)]);
+        let ctx = WeightingContext {
+            members: members.to_vec(),
+            trust,
+            ..Default::default()
+        };
+        let weighting = ReputationWeighting::new(TrustDimension::Integrity);
+        let resolution = weighting.resolve(&ctx, &HashSet::from([a, b]));
+        assert_eq!(resolution.capped, 1);
+        assert!(resolution.weights[&a] < 1_000);
+        assert_eq!(resolution.weights[&b], 100);
+    }
+
+    #[test]
+    fn test_delegation_chains_cycles_and_depth() {
+        let (a, b, c, d, e) = (id("a"), id("b"), id("c"), id("d"), id("e"));
+        let members = [a, b, c, d, e];
+        // a → b → c (c stimmt ab), d ⇄ e (Zyklus)
+        let delegations = vec![
+            delegate(a, b, 3),
+            delegate(b, c, 3),
+            delegate(d, e, 3),
+            delegate(e, d, 3),
+        ];
+        let ctx = WeightingContext {
+            members: members.to_vec(),
+            delegations,
+            ..Default::default()
+        };
+
+        let resolution = DelegatedWeighting::new(3).resolve(&ctx, &HashSet::from([c]));
+        assert_eq!(resolution.weights[&c], 3);
+        assert_eq!(resolution.delegated_votes, 2);
+        assert_eq!(resolution.max_delegation_depth, 2);
+        assert_eq!(resolution.cycles_prevented, 2);
+        assert_eq!(resolution.total_power, 5);
+
+        // Tiefe 1: a erreicht c nicht mehr
+        let resolution = DelegatedWeighting::new(1).resolve(&ctx, &HashSet::from([c]));
+        assert_eq!(resolution.weights[&c], 2);
+
+        // Direkte Stimme überschreibt die Delegation
+        let resolution = DelegatedWeighting::new(3).resolve(&ctx, &HashSet::from([b, c]));
+        assert_eq!(resolution.weights[&b], 2);
+        assert_eq!(resolution.weights[&c], 1);
+    }
+}
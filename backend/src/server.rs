@@ -10,8 +10,13 @@
 //! │  ├── unified_state: SharedUnifiedState (Core + Execution + ...)    │
 //! │  ├── coordinator: StateCoordinator (Health + Invariants)           │
 //! │  ├── storage: DecentralizedStorage (Persistence)                   │
+//! │  ├── saga_executor: SagaExecutor (Κ22-Κ24)                         │
+//! │  ├── governance: GovernanceService (Κ21)                           │
+//! │  ├── ecl: EclService (ECL-Handler pro Realm)                       │
+//! │  ├── sessions: SessionManager (Session-Tokens nach Passkey-Login)   │
 //! │  ├── p2p_handle: Option<P2PHandle> (P2P-Netzwerk)                  │
-//! │  └── config: Settings                                              │
+//! │  ├── config: Settings (beim Start)                                 │
+//! │  └── live_config: LiveConfig (Hot Reload, wirksame Werte)          │
 //! └─────────────────────────────────────────────────────────────────────┘
 //! ```
 //!
@@ -23,14 +28,22 @@
 //! - Trust-Gate für Peer-Filterung
 //! - Event-Integration mit UnifiedState
 
-use crate::api::{create_router, create_static_router, StaticConfig};
-use crate::config::Settings;
-use crate::core::{create_unified_state, SharedUnifiedState, StateCoordinator};
-use crate::local::DecentralizedStorage;
+use crate::api::v1::auth::SessionManager;
+use crate::api::{
+    create_admin_router, create_router, create_static_router, RateLimiter, StaticConfig,
+};
+use crate::config::{ConfigReload, LiveConfig, ReloadOutcome, Settings};
+use crate::core::{create_unified_state, InFlightMap, SharedUnifiedState, StateCoordinator};
+use crate::eclvm::ManaManager;
+use crate::local::{DecentralizedStorage, IdempotencyRecord};
+use crate::peer::ecl_service::{EclService, EclServiceConfig};
 use crate::peer::gateway::GatewayGuard;
+use crate::peer::governance::{GovernanceConfig, GovernanceService};
+use crate::peer::saga_executor::{SagaExecutor, SagaExecutorConfig};
 use anyhow::Result;
 use axum::Router;
 use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::net::TcpListener;
@@ -51,6 +64,8 @@ pub struct P2PHandle {
     pub peer_id: String,
     /// Node-Name
     pub node_name: String,
+    /// Trust-Gate des Swarms (Config-Reload von `p2p.min_incoming_trust`)
+    pub trust_gate: Arc<crate::peer::p2p::TrustGate>,
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -75,15 +90,36 @@ pub struct AppState {
     /// Dezentraler Storage (Fjall)
     pub storage: DecentralizedStorage,
 
-    /// Anwendungskonfiguration
+    /// Anwendungskonfiguration beim Start
     pub config: Arc<Settings>,
 
+    /// Wirksame Konfiguration inkl. Hot Reload (`LIVE_PATHS`)
+    pub live_config: Arc<LiveConfig>,
+
     /// Startzeitpunkt für Uptime
     pub started_at: Option<Instant>,
 
     /// Optional: GatewayGuard für Crossing-Validierung (Phase 2)
     pub gateway: Option<Arc<GatewayGuard>>,
 
+    /// Saga-Executor (persistiert in `storage.sagas`)
+    pub saga_executor: SagaExecutor,
+
+    /// Realm-Governance (persistiert in `storage.governance`)
+    pub governance: GovernanceService,
+
+    /// ECL-Handler pro Realm (persistiert in `storage.ecl`)
+    pub ecl: EclService,
+
+    /// Session-Tokens nach Passkey-Login (persistiert in `storage.sessions`)
+    pub sessions: SessionManager,
+
+    /// Rate Limiting pro DID/IP (Quota nach Trust-Tier, Mana pro Route)
+    pub rate_limiter: Arc<RateLimiter>,
+
+    /// Laufende Requests je Idempotency-Key (Wiederholungen warten auf das Ergebnis)
+    pub idempotency: Arc<InFlightMap<Option<Arc<IdempotencyRecord>>>>,
+
     /// Optional: P2P-Handle für Netzwerk-Kommunikation
     pub p2p_handle: Option<P2PHandle>,
 }
@@ -97,17 +133,57 @@ impl AppState {
         // Coordinator (enthält einen StateIntegrator für Observer-Pattern)
         let coordinator = Arc::new(StateCoordinator::new(unified_state.clone()));
 
+        // Saga-Executor loggt SagaProgress in den Unified State
+        let saga_executor = SagaExecutor::new(storage.clone(), SagaExecutorConfig::default())
+            .with_unified_state(unified_state.clone());
+
+        // Governance führt angenommene Proposals im Realm-State aus
+        let governance = GovernanceService::new(
+            storage.clone(),
+            unified_state.clone(),
+            GovernanceConfig::default(),
+        );
+
+        // ECL-Handler der Realms (/api/v1/ecl/*)
+        let ecl = EclService::new(storage.clone(), unified_state.clone(), EclServiceConfig::default())
+            .with_mana(Arc::new(ManaManager::new(config.mana.to_mana_config())));
+
+        // Session-Tokens (Signaturschlüssel liegt im Storage)
+        let sessions = SessionManager::new(storage.sessions.clone(), config.auth.clone())
+            .expect("Session-Signaturschlüssel konnte nicht geladen werden");
+
+        // Rate Limiting meldet Buckets und Abweisungen an den APIState
+        let rate_limiter = Arc::new(
+            RateLimiter::new(config.rate_limit.clone())
+                .with_mana_config(config.mana.to_mana_config())
+                .with_observer(Arc::new(coordinator.integrator().clone())),
+        );
+
         Self {
             unified_state,
             coordinator,
             storage,
+            live_config: Arc::new(LiveConfig::new(config.clone())),
             config: Arc::new(config),
             started_at: Some(Instant::now()),
             gateway: None,
+            saga_executor,
+            governance,
+            ecl,
+            sessions,
+            rate_limiter,
+            idempotency: Arc::new(InFlightMap::default()),
             p2p_handle: None,
         }
     }
 
+    /// Erstelle AppState mit GatewayGuard (auch für Saga-Crossings)
+    pub fn with_gateway(mut self, gateway: Arc<GatewayGuard>) -> Self {
+        self.saga_executor.set_gateway(gateway.clone());
+        self.gateway = Some(gateway);
+        self
+    }
+
     /// Erstelle AppState mit P2P-Handle
     pub fn with_p2p(mut self, p2p_handle: P2PHandle) -> Self {
         self.p2p_handle = Some(p2p_handle);
@@ -143,12 +219,72 @@ impl AppState {
     pub fn uptime_secs(&self) -> u64 {
         self.started_at.map(|s| s.elapsed().as_secs()).unwrap_or(0)
     }
+
+    /// Config-Dateien aus `reload.config_dir` neu laden und live übernehmen
+    pub fn reload_config(&self) -> ConfigReload {
+        let reload = match Settings::load_from(Path::new(&self.config.reload.config_dir)) {
+            Ok(candidate) => self.apply_config(candidate),
+            Err(e) => self.live_config.reject(e.into()),
+        };
+        match reload.outcome {
+            ReloadOutcome::Rejected => {
+                tracing::warn!(error = ?reload.error, "⚠️  Config reload rejected")
+            }
+            outcome => tracing::info!(
+                ?outcome,
+                generation = reload.generation,
+                changes = reload.changes.len(),
+                "🔄 Config reloaded"
+            ),
+        }
+        reload
+    }
+
+    /// Validiert `candidate` und gibt die live änderbaren Werte an die Subsysteme weiter
+    pub fn apply_config(&self, candidate: Settings) -> ConfigReload {
+        let reload = self.live_config.apply(candidate);
+        if reload.outcome == ReloadOutcome::Applied {
+            let effective = self.live_config.current();
+            let mana = effective.mana.to_mana_config();
+            self.rate_limiter
+                .reconfigure(effective.rate_limit.clone(), mana.clone());
+            self.ecl.set_mana_config(mana.clone());
+            if let Some(gateway) = &self.gateway {
+                gateway.set_mana_config(mana);
+            }
+            #[cfg(feature = "p2p")]
+            if let Some(p2p) = &self.p2p_handle {
+                p2p.trust_gate
+                    .set_min_incoming_trust(effective.p2p.min_incoming_trust);
+            }
+        }
+        reload
+    }
+
+    /// Config-Dateien periodisch prüfen und bei Änderung neu laden
+    pub fn spawn_config_watcher(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let settings = &self.config.reload;
+        if !settings.enabled {
+            return None;
+        }
+        let files = Settings::config_files(Path::new(&settings.config_dir));
+        let state = self.clone();
+        Some(crate::config::reload::watch_files(
+            files,
+            std::time::Duration::from_secs(settings.interval_secs.max(1)),
+            move || {
+                state.reload_config();
+            },
+        ))
+    }
 }
 
 /// Application server
 pub struct Server {
     listener: TcpListener,
     router: Router,
+    /// Admin-Listener (localhost) mit Router, falls aktiviert
+    admin: Option<(TcpListener, Router)>,
     /// P2P-Task-Handle (falls P2P aktiv)
     #[cfg(feature = "p2p")]
     p2p_task: Option<tokio::task::JoinHandle<()>>,
@@ -171,6 +307,9 @@ impl Server {
             "🏗️  Building server..."
         );
 
+        // Konfiguration prüfen (gleiche Regeln wie beim Hot Reload)
+        crate::config::reload::validate(&settings)?;
+
         // Dezentraler Storage (Fjall)
         let data_dir = &settings.storage.data_dir;
         let storage = DecentralizedStorage::open(data_dir)?;
@@ -180,6 +319,23 @@ impl Server {
         let mut state = AppState::new(storage, settings.clone());
         tracing::info!("✅ Unified state management initialized");
 
+        // Unterbrochene Sagas fortsetzen
+        let resumed = state.saga_executor.recover()?;
+        tracing::info!(resumed, "✅ Saga executor ready");
+
+        // Abgelaufene Idempotency-Keys entfernen
+        let pruned = state.storage.idempotency.prune(chrono::Utc::now().timestamp())?;
+        tracing::info!(pruned, "✅ Idempotency keys pruned");
+
+        // Abgelaufene Challenges periodisch entfernen (anonym ausstellbar)
+        state
+            .storage
+            .challenges
+            .spawn_pruner(std::time::Duration::from_secs(60));
+
+        // Fällige Proposals periodisch finalisieren
+        state.governance.spawn_finalizer(std::time::Duration::from_secs(30));
+
         // P2P initialisieren (falls aktiviert)
         #[cfg(feature = "p2p")]
         let p2p_task = if settings.features.p2p_enabled {
@@ -199,6 +355,36 @@ impl Server {
             None
         };
 
+        // Config-Dateien überwachen (nach P2P, damit das Trust-Gate erreichbar ist)
+        if state.spawn_config_watcher().is_some() {
+            tracing::info!(
+                dir = %settings.reload.config_dir,
+                interval_secs = settings.reload.interval_secs,
+                "✅ Config watcher ready"
+            );
+        }
+
+        // Admin-Listener nur auf Loopback (Debug, Event-Injektion)
+        let admin = if settings.admin.enabled {
+            if !settings.admin.is_loopback() {
+                anyhow::bail!(
+                    "admin.host must be a loopback address, got '{}'",
+                    settings.admin.host
+                );
+            }
+            let addr = format!("{}:{}", settings.admin.host, settings.admin.port);
+            let listener = TcpListener::bind(&addr).await?;
+            tracing::info!(
+                addr = %addr,
+                operators = settings.admin.operators.len(),
+                "🔐 Admin listener ready"
+            );
+            Some((listener, create_admin_router(state.clone())))
+        } else {
+            tracing::info!("ℹ️  Admin listener disabled");
+            None
+        };
+
         // API Router
         let api_router = create_router(state);
 
@@ -233,7 +419,7 @@ impl Server {
 
         tracing::info!(addr = %addr, "🚀 Server ready");
 
-        Ok(Self { listener, router, p2p_task })
+        Ok(Self { listener, router, admin, p2p_task })
     }
 
     /// Initialize P2P network
@@ -300,6 +486,9 @@ impl Server {
         // Command-Sender für Handle
         let command_tx = manager.command_sender();
 
+        // Trust-Gate für Config-Reload
+        let trust_gate = manager.trust_gate();
+
         // Peer-ID als String für Handle
         let peer_id_string = peer_id.to_string();
 
@@ -308,6 +497,7 @@ impl Server {
             command_tx,
             peer_id: peer_id_string.clone(),
             node_name: settings.p2p.node_name.clone(),
+            trust_gate,
         };
 
         // P2P-Task spawnen
@@ -392,15 +582,40 @@ impl Server {
         self.listener.local_addr().map(|a| a.port()).unwrap_or(0)
     }
 
+    /// Get the bound admin port (if the admin listener is enabled)
+    pub fn admin_port(&self) -> Option<u16> {
+        self.admin
+            .as_ref()
+            .and_then(|(listener, _)| listener.local_addr().ok())
+            .map(|a| a.port())
+    }
+
     /// Run until shutdown signal
     pub async fn run(mut self) -> Result<(), std::io::Error> {
-        // HTTP-Server Task
-        let http_server = axum::serve(
+        // HTTP-Server Task (öffentlicher und ggf. Admin-Listener)
+        let public_server = axum::serve(
             self.listener,
             self.router
                 .into_make_service_with_connect_info::<SocketAddr>(),
         )
         .with_graceful_shutdown(shutdown_signal());
+        let admin = self.admin.take();
+        let http_server = async move {
+            let admin_server = async move {
+                match admin {
+                    Some((listener, router)) => {
+                        axum::serve(
+                            listener,
+                            router.into_make_service_with_connect_info::<SocketAddr>(),
+                        )
+                        .with_graceful_shutdown(shutdown_signal())
+                        .await
+                    }
+                    None => Ok(()),
+                }
+            };
+            tokio::try_join!(async move { public_server.await }, admin_server).map(|_| ())
+        };
 
         #[cfg(feature = "p2p")]
         {
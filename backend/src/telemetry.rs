@@ -1,29 +0,0 @@
-//! Telemetry - Structured Logging
-
-use tracing::subscriber::set_global_default;
-use tracing::Subscriber;
-use tracing_subscriber::fmt::MakeWriter;
-use tracing_subscriber::layer::SubscriberExt;
-use tracing_subscriber::{EnvFilter, Registry};
-
-pub fn get_subscriber<Sink>(
-    _name: String,
-    env_filter: String,
-    sink: Sink,
-) -> impl Subscriber + Send + Sync
-where
-    Sink: for<'a> MakeWriter<'a> + Send + Sync + 'static,
-{
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(env_filter));
-
-    let fmt = tracing_subscriber::fmt::layer()
-        .with_writer(sink)
-        .with_target(true)
-        .pretty();
-
-    Registry::default().with(filter).with(fmt)
-}
-
-pub fn init_subscriber(subscriber: impl Subscriber + Send + Sync) {
-    set_global_default(subscriber).expect("Failed to set subscriber");
-}
@@ -0,0 +1,206 @@
+//! Telemetry - Structured Logging und Tracing
+//!
+//! - fmt-Ausgabe als `pretty` oder `json` ([`TelemetrySettings::log_format`])
+//! - Optional (Feature `otel`): OTLP/HTTP-Export aller Spans an einen Collector
+//! - W3C Trace Context (`traceparent`) zur Weitergabe über HTTP und P2P-Sync
+//!
+//! Ohne Feature `otel` sind die Propagations-Funktionen No-Ops, Spans werden
+//! nur lokal für das Logging genutzt.
+
+#[cfg(feature = "otel")]
+mod otel;
+
+use axum::http::HeaderMap;
+use tracing::subscriber::set_global_default;
+use tracing::{Span, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry};
+
+use crate::config::{LogFormat, TelemetrySettings};
+
+/// W3C Trace Context Header
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// Hält den Tracer-Provider am Leben; beim Drop werden ausstehende Spans exportiert
+#[must_use = "dropping the guard shuts down the trace export"]
+#[derive(Default)]
+pub struct TelemetryGuard {
+    #[cfg(feature = "otel")]
+    provider: Option<opentelemetry_sdk::trace::TracerProvider>,
+}
+
+impl TelemetryGuard {
+    /// Ob Spans an einen OTLP-Collector exportiert werden
+    pub fn exporting(&self) -> bool {
+        #[cfg(feature = "otel")]
+        {
+            self.provider.is_some()
+        }
+        #[cfg(not(feature = "otel"))]
+        {
+            false
+        }
+    }
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "otel")]
+        if let Some(provider) = self.provider.take() {
+            let _ = provider.shutdown();
+        }
+    }
+}
+
+/// Baut den Subscriber aus fmt-Layer (pretty/json) und optionalem OTLP-Export
+///
+/// `name` wird als `service.name` der exportierten Traces verwendet.
+pub fn get_subscriber<Sink>(
+    name: String,
+    env_filter: String,
+    settings: &TelemetrySettings,
+    sink: Sink,
+) -> anyhow::Result<(impl Subscriber + Send + Sync, TelemetryGuard)>
+where
+    Sink: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(env_filter));
+
+    let fmt = match settings.log_format {
+        LogFormat::Pretty => tracing_subscriber::fmt::layer()
+            .with_writer(sink)
+            .with_target(true)
+            .pretty()
+            .boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .with_writer(sink)
+            .with_target(true)
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .boxed(),
+    };
+
+    let subscriber = Registry::default().with(filter).with(fmt);
+
+    #[cfg(feature = "otel")]
+    {
+        let provider = settings
+            .otlp_endpoint
+            .as_deref()
+            .map(|endpoint| otel::provider(&name, endpoint, settings.sample_ratio))
+            .transpose()?;
+        let layer = provider
+            .as_ref()
+            .map(|provider| otel::layer(provider, name.clone()));
+        Ok((subscriber.with(layer), TelemetryGuard { provider }))
+    }
+    #[cfg(not(feature = "otel"))]
+    {
+        let _ = name;
+        Ok((subscriber, TelemetryGuard::default()))
+    }
+}
+
+pub fn init_subscriber(subscriber: impl Subscriber + Send + Sync) {
+    set_global_default(subscriber).expect("Failed to set subscriber");
+}
+
+// ============================================================================
+// W3C Trace Context
+// ============================================================================
+
+/// `traceparent` des aktuellen Spans
+///
+/// `None` ohne Feature `otel`, ohne aktiven Export oder außerhalb eines Spans.
+pub fn current_traceparent() -> Option<String> {
+    #[cfg(feature = "otel")]
+    {
+        otel::traceparent(&Span::current())
+    }
+    #[cfg(not(feature = "otel"))]
+    {
+        None
+    }
+}
+
+/// Setzt den Remote-Parent eines Spans aus einem `traceparent`
+///
+/// Muss vor dem ersten Betreten des Spans aufgerufen werden. Ungültige
+/// Werte werden ignoriert, der Span bleibt dann Root eines neuen Traces.
+pub fn set_parent(span: &Span, traceparent: Option<&str>) {
+    #[cfg(feature = "otel")]
+    if let Some(traceparent) = traceparent {
+        otel::set_parent(span, traceparent);
+    }
+    #[cfg(not(feature = "otel"))]
+    let _ = (span, traceparent);
+}
+
+/// Wie [`set_parent`], mit dem `traceparent`-Header eines HTTP-Requests
+pub fn set_parent_from_headers(span: &Span, headers: &HeaderMap) {
+    let traceparent = headers
+        .get(TRACEPARENT_HEADER)
+        .and_then(|value| value.to_str().ok());
+    set_parent(span, traceparent);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use std::io::Write;
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_json_log_format() {
+        let buffer = Buffer::default();
+        let settings = TelemetrySettings {
+            log_format: LogFormat::Json,
+            ..TelemetrySettings::default()
+        };
+        let (subscriber, _guard) = get_subscriber(
+            "erynoa-test".into(),
+            "info".into(),
+            &settings,
+            buffer.clone(),
+        )
+        .unwrap();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("saga.step", step.index = 3);
+            let _entered = span.enter();
+            tracing::info!(gas_used = 42, "policy executed");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().clone()).unwrap();
+        let line: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(line["fields"]["message"], "policy executed");
+        assert_eq!(line["fields"]["gas_used"], 42);
+        assert_eq!(line["span"]["name"], "saga.step");
+        assert_eq!(current_traceparent(), None);
+    }
+}
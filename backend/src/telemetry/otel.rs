@@ -0,0 +1,152 @@
+//! OpenTelemetry-Anbindung (Feature `otel`)
+//!
+//! Spans werden per `tracing-opentelemetry` in OTel-Spans übersetzt und im
+//! Batch über OTLP/HTTP (Protobuf) exportiert. Trace-Contexts werden im
+//! W3C-Format (`traceparent`) propagiert.
+
+use std::collections::HashMap;
+
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{TraceError, TracerProvider as _};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+use tracing::{Span, Subscriber};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use super::TRACEPARENT_HEADER;
+
+/// Tracer-Provider mit Batch-Export an `endpoint` (OTLP/HTTP)
+pub(super) fn provider(
+    service_name: &str,
+    endpoint: &str,
+    sample_ratio: f64,
+) -> Result<TracerProvider, TraceError> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()?;
+
+    Ok(TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            sample_ratio,
+        ))))
+        .with_resource(Resource::new([KeyValue::new(
+            "service.name",
+            service_name.to_string(),
+        )]))
+        .build())
+}
+
+/// tracing-Layer, der Spans an den Provider weiterreicht
+pub(super) fn layer<S>(provider: &TracerProvider, name: String) -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    tracing_opentelemetry::layer().with_tracer(provider.tracer(name))
+}
+
+pub(super) fn traceparent(span: &Span) -> Option<String> {
+    let mut carrier = HashMap::new();
+    TraceContextPropagator::new().inject_context(&span.context(), &mut carrier);
+    carrier.remove(TRACEPARENT_HEADER)
+}
+
+pub(super) fn set_parent(span: &Span, traceparent: &str) {
+    let carrier = HashMap::from([(TRACEPARENT_HEADER.to_string(), traceparent.to_string())]);
+    span.set_parent(TraceContextPropagator::new().extract(&carrier));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Bytes, extract::State, http::StatusCode, routing::post, Router};
+    use parking_lot::Mutex;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+    /// In-Process-Collector: sammelt die Bodies aller OTLP-Exporte
+    async fn collector() -> (String, Arc<Mutex<Vec<Bytes>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let app = Router::new()
+            .route(
+                "/v1/traces",
+                post(
+                    |State(received): State<Arc<Mutex<Vec<Bytes>>>>, body: Bytes| async move {
+                        received.lock().push(body);
+                        StatusCode::OK
+                    },
+                ),
+            )
+            .with_state(received.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (endpoint, received)
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_spans_are_exported_with_remote_parent() {
+        let (endpoint, received) = collector().await;
+        let provider = provider("erynoa-test", &endpoint, 1.0).unwrap();
+        let subscriber =
+            tracing_subscriber::Registry::default().with(layer(&provider, "erynoa-test".into()));
+
+        let remote = format!("00-{}-00f067aa0ba902b7-01", TRACE_ID);
+        let propagated = tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("eclvm.run_policy", gas.used = 42u64);
+            set_parent(&span, &remote);
+            let _entered = span.enter();
+            traceparent(&Span::current())
+        });
+
+        // Kind-Span gehört zum Remote-Trace, hat aber eine eigene Span-ID
+        let propagated = propagated.expect("traceparent of exported span");
+        assert!(propagated.starts_with(&format!("00-{}-", TRACE_ID)));
+        assert!(!propagated.contains("00f067aa0ba902b7"));
+
+        let flush = provider.clone();
+        tokio::task::spawn_blocking(move || flush.force_flush())
+            .await
+            .unwrap();
+        for _ in 0..50 {
+            if !received.lock().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let bodies = received.lock().concat();
+        assert!(contains(&bodies, b"eclvm.run_policy"));
+        assert!(contains(&bodies, b"gas.used"));
+        assert!(contains(&bodies, &hex::decode(TRACE_ID).unwrap()));
+        assert!(contains(&bodies, b"erynoa-test"));
+    }
+
+    #[test]
+    fn test_invalid_traceparent_starts_new_trace() {
+        let provider = TracerProvider::builder().build();
+        let subscriber =
+            tracing_subscriber::Registry::default().with(layer(&provider, "erynoa-test".into()));
+
+        let propagated = tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("p2p.sync_request");
+            set_parent(&span, "not-a-traceparent");
+            traceparent(&span)
+        });
+        assert!(propagated.is_some());
+        assert!(!propagated.unwrap().contains(TRACE_ID));
+    }
+}
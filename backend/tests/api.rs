@@ -8,6 +8,10 @@ use serde_json::Value;
 
 pub struct TestApp {
     pub address: String,
+    /// Admin-Listener (localhost)
+    pub admin_address: String,
+    /// Passkey des in `admin.operators` eingetragenen Node-Betreibers
+    pub operator: ed25519_dalek::SigningKey,
     pub client: reqwest::Client,
     /// Temporäres Datenverzeichnis (wird beim Drop automatisch gelöscht)
     _temp_dir: tempfile::TempDir,
@@ -15,19 +19,46 @@ pub struct TestApp {
 
 impl TestApp {
     pub async fn spawn() -> Self {
+        Self::spawn_with(|_| {}).await
+    }
+
+    /// Wie [`TestApp::spawn`], mit angepassten Settings
+    pub async fn spawn_with(configure: impl FnOnce(&mut Settings)) -> Self {
+        Self::spawn_seeded(configure, |_| {}).await
+    }
+
+    /// Wie [`TestApp::spawn_with`]; `seed` befüllt den Storage vor dem Start
+    pub async fn spawn_seeded(
+        configure: impl FnOnce(&mut Settings),
+        seed: impl FnOnce(&erynoa_api::local::DecentralizedStorage),
+    ) -> Self {
         // Erstelle temporäres Verzeichnis für diesen Test
         let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
         let data_path = temp_dir.path().to_string_lossy().to_string();
 
+        let operator = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+
         let mut settings = Settings::load().expect("Failed to load config");
         settings.application.port = 0;
         settings.storage.data_dir = data_path;
+        settings.admin.port = 0;
+        settings.admin.operators = vec![did_of(&operator)];
+        configure(&mut settings);
+
+        {
+            let storage = erynoa_api::local::DecentralizedStorage::open(&settings.storage.data_dir)
+                .expect("Failed to open storage");
+            seed(&storage);
+            storage.flush().expect("Failed to flush storage");
+        }
 
         let server = Server::build(settings)
             .await
             .expect("Failed to build server");
         let port = server.port();
         let address = format!("http://127.0.0.1:{port}");
+        let admin_port = server.admin_port().expect("admin listener disabled");
+        let admin_address = format!("http://127.0.0.1:{admin_port}");
 
         tokio::spawn(server.run());
 
@@ -36,6 +67,8 @@ impl TestApp {
 
         Self {
             address,
+            admin_address,
+            operator,
             client: reqwest::Client::new(),
             _temp_dir: temp_dir,
         }
@@ -66,6 +99,151 @@ impl TestApp {
             .await
             .expect("Request failed")
     }
+
+    pub async fn get_authed(&self, path: &str, token: &str) -> reqwest::Response {
+        self.client
+            .get(format!("{}{}", self.address, path))
+            .bearer_auth(token)
+            .send()
+            .await
+            .expect("Request failed")
+    }
+
+    pub async fn post_authed(&self, path: &str, token: &str, body: Value) -> reqwest::Response {
+        self.client
+            .post(format!("{}{}", self.address, path))
+            .bearer_auth(token)
+            .json(&body)
+            .send()
+            .await
+            .expect("Request failed")
+    }
+
+    /// Connect-RPC-Aufruf im JSON-Protokoll (`/api/v1/connect/<service>/<method>`)
+    pub async fn connect(
+        &self,
+        method: &str,
+        token: Option<&str>,
+        body: Value,
+    ) -> reqwest::Response {
+        let mut req = self
+            .client
+            .post(format!(
+                "{}/api/v1/connect/erynoa.v1.{}",
+                self.address, method
+            ))
+            .header("Content-Type", "application/json")
+            .body(body.to_string());
+        if let Some(token) = token {
+            req = req.bearer_auth(token);
+        }
+        req.send().await.expect("Request failed")
+    }
+
+    /// Registriert einen Ed25519-Passkey und gibt Schlüssel und Credential-ID zurück
+    pub async fn register_passkey(&self) -> (ed25519_dalek::SigningKey, String) {
+        let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let credential_id = self.register_key(&key).await;
+        (key, credential_id)
+    }
+
+    /// Registriert `key` als Passkey und gibt die Credential-ID zurück
+    pub async fn register_key(&self, key: &ed25519_dalek::SigningKey) -> String {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+        let public_key = key.verifying_key().to_bytes();
+        let credential_id = URL_SAFE_NO_PAD.encode(&public_key[..16]);
+
+        let res = self
+            .post(
+                "/api/v1/auth/passkey/register",
+                Some(serde_json::json!({
+                    "credential_id": credential_id,
+                    "public_key": URL_SAFE_NO_PAD.encode(public_key),
+                    "algorithm": -8,
+                    "did": did_of(key),
+                    "namespace": "self"
+                })),
+            )
+            .await;
+        assert!(res.status().is_success(), "passkey registration failed");
+        credential_id
+    }
+
+    /// Holt eine Challenge und signiert eine Assertion (rp_id `localhost`, UP|UV)
+    pub async fn sign_assertion(
+        &self,
+        key: &ed25519_dalek::SigningKey,
+        credential_id: &str,
+        sign_count: u32,
+    ) -> Value {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+        use ed25519_dalek::Signer;
+        use sha2::{Digest, Sha256};
+
+        let challenge: Value = self
+            .get("/api/v1/auth/challenge")
+            .await
+            .json()
+            .await
+            .unwrap();
+        let client_data = serde_json::to_vec(&serde_json::json!({
+            "type": "webauthn.get",
+            "challenge": challenge["challenge"],
+            "origin": "http://localhost:5173"
+        }))
+        .unwrap();
+        let mut auth_data = Sha256::digest(b"localhost").to_vec();
+        auth_data.push(0x05);
+        auth_data.extend_from_slice(&sign_count.to_be_bytes());
+
+        let mut signed = auth_data.clone();
+        signed.extend_from_slice(&Sha256::digest(&client_data));
+
+        serde_json::json!({
+            "credential_id": credential_id,
+            "signature": URL_SAFE_NO_PAD.encode(key.sign(&signed).to_bytes()),
+            "authenticator_data": URL_SAFE_NO_PAD.encode(&auth_data),
+            "client_data_json": URL_SAFE_NO_PAD.encode(&client_data)
+        })
+    }
+
+    /// Registriert einen Passkey, meldet sich an und gibt die Antwort von
+    /// /auth/passkey/verify (DID + Session-Tokens) zurück
+    pub async fn login(&self, extra: Value) -> Value {
+        let key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        self.login_as(&key, extra).await
+    }
+
+    /// Meldet den Node-Betreiber mit ausdrücklich angefragtem `execute:admin` an
+    pub async fn login_operator(&self) -> Value {
+        let capabilities = serde_json::json!({
+            "capabilities": ["read:*", "write:*", "execute:ecl", "execute:admin"]
+        });
+        self.login_as(&self.operator, capabilities).await
+    }
+
+    /// Wie [`TestApp::login`], aber mit vorgegebenem Schlüssel
+    pub async fn login_as(&self, key: &ed25519_dalek::SigningKey, extra: Value) -> Value {
+        let credential_id = self.register_key(key).await;
+        let mut request = self.sign_assertion(key, &credential_id, 1).await;
+        if let (Some(request), Some(extra)) = (request.as_object_mut(), extra.as_object()) {
+            request.extend(extra.clone());
+        }
+        self.post("/api/v1/auth/passkey/verify", Some(request))
+            .await
+            .json()
+            .await
+            .unwrap()
+    }
+}
+
+/// DID eines Ed25519-Passkeys (`did:erynoa:self:<hex(pk)>`)
+pub fn did_of(key: &ed25519_dalek::SigningKey) -> String {
+    format!(
+        "did:erynoa:self:{}",
+        hex::encode(key.verifying_key().to_bytes())
+    )
 }
 
 #[cfg(test)]
@@ -87,6 +265,22 @@ mod tests {
         assert!(body["version"].is_string());
     }
 
+    #[tokio::test]
+    async fn state_metrics_are_openmetrics() {
+        let app = TestApp::spawn().await;
+        let res = app.get("/api/v1/state/metrics").await;
+
+        assert!(res.status().is_success());
+        let content_type = res.headers()["content-type"].to_str().unwrap().to_string();
+        assert!(content_type.starts_with("application/openmetrics-text"));
+
+        let body = res.text().await.unwrap();
+        assert!(body.ends_with("# EOF\n"));
+        assert!(body.contains("# TYPE erynoa_health_score gauge\n"));
+        assert!(body.contains("# TYPE erynoa_storage_read_latency_seconds histogram\n"));
+        assert!(body.contains("erynoa_storage_read_latency_seconds_count{store=\"identities\"}"));
+    }
+
     #[tokio::test]
     async fn readiness_check_works() {
         let app = TestApp::spawn().await;
@@ -450,4 +644,1563 @@ mod tests {
             assert!(verify_body.get("success").is_some() || verify_body.get("error").is_some());
         }
     }
+
+    #[tokio::test]
+    async fn ecl_endpoints_reject_unknown_realm_and_bad_callers() {
+        let app = TestApp::spawn().await;
+
+        // Ohne Session-Token
+        let res = app
+            .post(
+                "/api/v1/ecl/api/post:action",
+                Some(serde_json::json!({ "realm_id": "does-not-exist" })),
+            )
+            .await;
+        assert_eq!(res.status(), 401);
+
+        let session = app.login(serde_json::json!({})).await;
+        let token = session["access_token"].as_str().unwrap();
+        let res = app
+            .post_authed(
+                "/api/v1/ecl/api/post:action",
+                token,
+                serde_json::json!({ "realm_id": "does-not-exist" }),
+            )
+            .await;
+        assert_eq!(res.status(), 404);
+        let body: Value = res.json().await.unwrap();
+        assert_eq!(body["error"], "unknown_realm");
+
+        // Realm-gebundene Session darf nicht in fremden Realms ausführen
+        let scoped = app.login(serde_json::json!({ "realm_id": "guild" })).await;
+        let res = app
+            .post_authed(
+                "/api/v1/ecl/run",
+                scoped["access_token"].as_str().unwrap(),
+                serde_json::json!({
+                    "realm_id": "does-not-exist",
+                    "source": "policy \"p\" { require true }"
+                }),
+            )
+            .await;
+        assert_eq!(res.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn passkey_assertions_are_single_use_and_counter_checked() {
+        let app = TestApp::spawn().await;
+        let (key, credential_id) = app.register_passkey().await;
+
+        let assertion = app.sign_assertion(&key, &credential_id, 3).await;
+        let res = app
+            .post("/api/v1/auth/passkey/verify", Some(assertion.clone()))
+            .await;
+        assert_eq!(res.status(), 200);
+
+        // Replay derselben Assertion: Challenge bereits eingelöst
+        let res = app
+            .post("/api/v1/auth/passkey/verify", Some(assertion))
+            .await;
+        assert_eq!(res.status(), 401);
+        let body: Value = res.json().await.unwrap();
+        assert!(body["error"].as_str().unwrap().contains("challenge"));
+
+        // Frische Challenge, aber Zähler nicht erhöht → geklonter Authenticator
+        let stale = app.sign_assertion(&key, &credential_id, 3).await;
+        let res = app.post("/api/v1/auth/passkey/verify", Some(stale)).await;
+        assert_eq!(res.status(), 401);
+
+        let fresh = app.sign_assertion(&key, &credential_id, 4).await;
+        let res = app.post("/api/v1/auth/passkey/verify", Some(fresh)).await;
+        assert_eq!(res.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn es256_passkey_registers_and_logs_in() {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+        use p256::ecdsa::{signature::Signer, DerSignature, SigningKey};
+        use sha2::{Digest, Sha256};
+
+        let app = TestApp::spawn().await;
+        let key = SigningKey::random(&mut rand::rngs::OsRng);
+        let point = key.verifying_key().to_encoded_point(false);
+
+        // COSE_Key {1: 2, 3: -7, -1: 1, -2: x, -3: y}
+        let mut cose_key = vec![0xa5, 0x01, 0x02, 0x03, 0x26, 0x20, 0x01, 0x21, 0x58, 0x20];
+        cose_key.extend_from_slice(point.x().unwrap());
+        cose_key.extend_from_slice(&[0x22, 0x58, 0x20]);
+        cose_key.extend_from_slice(point.y().unwrap());
+
+        let credential_id = URL_SAFE_NO_PAD.encode(&point.x().unwrap()[..16]);
+        // DID-Schlüsselmaterial: SHA-256 des komprimierten Punkts
+        let compressed = key.verifying_key().to_encoded_point(true);
+        let did = format!(
+            "did:erynoa:self:{}",
+            hex::encode(Sha256::digest(compressed.as_bytes()))
+        );
+        let res = app
+            .post(
+                "/api/v1/auth/passkey/register",
+                Some(serde_json::json!({
+                    "credential_id": credential_id,
+                    "public_key": URL_SAFE_NO_PAD.encode(&cose_key),
+                    "algorithm": -7,
+                    "did": did,
+                    "namespace": "self"
+                })),
+            )
+            .await;
+        assert_eq!(res.status(), 200);
+
+        let challenge: Value = app
+            .get("/api/v1/auth/challenge")
+            .await
+            .json()
+            .await
+            .unwrap();
+        let client_data = serde_json::to_vec(&serde_json::json!({
+            "type": "webauthn.get",
+            "challenge": challenge["challenge"],
+            "origin": "http://localhost:5173"
+        }))
+        .unwrap();
+        let mut auth_data = Sha256::digest(b"localhost").to_vec();
+        auth_data.push(0x01);
+        auth_data.extend_from_slice(&0u32.to_be_bytes());
+        let mut signed = auth_data.clone();
+        signed.extend_from_slice(&Sha256::digest(&client_data));
+        let signature: DerSignature = key.sign(&signed);
+
+        let res = app
+            .post(
+                "/api/v1/auth/passkey/verify",
+                Some(serde_json::json!({
+                    "credential_id": credential_id,
+                    "signature": URL_SAFE_NO_PAD.encode(signature.as_bytes()),
+                    "authenticator_data": URL_SAFE_NO_PAD.encode(&auth_data),
+                    "client_data_json": URL_SAFE_NO_PAD.encode(&client_data)
+                })),
+            )
+            .await;
+        assert_eq!(res.status(), 200);
+        let body: Value = res.json().await.unwrap();
+        assert_eq!(body["did"], did);
+        assert!(body["access_token"].is_string());
+    }
+
+    #[tokio::test]
+    async fn session_tokens_authenticate_refresh_and_revoke() {
+        let app = TestApp::spawn().await;
+
+        let session = app
+            .login(serde_json::json!({ "capabilities": ["read:*"] }))
+            .await;
+        assert_eq!(session["success"], true);
+        assert_eq!(session["token_type"], "Bearer");
+        let token = session["access_token"].as_str().unwrap();
+
+        let me: Value = app
+            .get_authed("/api/v1/me", token)
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(me["did"], session["did"]);
+        assert_eq!(me["capabilities"], serde_json::json!(["read:*"]));
+
+        // Capability fehlt → 403
+        let res = app
+            .post_authed(
+                "/api/v1/trust/update",
+                token,
+                serde_json::json!({
+                    "entity_id": session["did"],
+                    "delta": 0.1,
+                    "reason": "PositiveInteraction"
+                }),
+            )
+            .await;
+        assert_eq!(res.status(), 403);
+
+        let res = app
+            .post(
+                "/api/v1/auth/session/refresh",
+                Some(serde_json::json!({ "refresh_token": session["refresh_token"] })),
+            )
+            .await;
+        assert!(res.status().is_success());
+        let refreshed: Value = res.json().await.unwrap();
+        let new_token = refreshed["access_token"].as_str().unwrap();
+
+        let res = app
+            .post_authed(
+                "/api/v1/auth/session/revoke",
+                new_token,
+                serde_json::json!({}),
+            )
+            .await;
+        assert!(res.status().is_success());
+        assert_eq!(app.get_authed("/api/v1/me", new_token).await.status(), 401);
+        assert_eq!(app.get_authed("/api/v1/me", token).await.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn privileged_routes_require_operator_role_and_are_audited() {
+        let app = TestApp::spawn_with(|settings| settings.rate_limit.enabled = false).await;
+        let degraded = serde_json::json!({ "mode": "Degraded" });
+
+        // Ohne Session → 401, Member → 403
+        let res = app.post("/api/v1/state/mode", Some(degraded.clone())).await;
+        assert_eq!(res.status(), 401);
+        let member = app.login(serde_json::json!({})).await;
+        let member_token = member["access_token"].as_str().unwrap();
+        let res = app
+            .post_authed("/api/v1/state/mode", member_token, degraded.clone())
+            .await;
+        assert_eq!(res.status(), 403);
+        assert_eq!(app.get("/api/v1/state/mode").await.status(), 200);
+
+        let operator = app.login_operator().await;
+        let token = operator["access_token"].as_str().unwrap();
+
+        // Debug-Routen existieren nur auf dem Admin-Listener
+        let res = app
+            .post_authed("/api/v1/debug/checkpoint", token, serde_json::json!({}))
+            .await;
+        assert_eq!(res.status(), 404);
+        let res = app
+            .client
+            .post(format!("{}/api/v1/debug/checkpoint", app.admin_address))
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap();
+        assert!(res.status().is_success());
+
+        let res = app.post_authed("/api/v1/state/mode", token, degraded).await;
+        assert!(res.status().is_success());
+
+        // Audit Log: nur für Betreiber lesbar
+        let res = app.get_authed("/api/v1/audit", member_token).await;
+        assert_eq!(res.status(), 403);
+        let audit: Value = app
+            .get_authed("/api/v1/audit", token)
+            .await
+            .json()
+            .await
+            .unwrap();
+        let entries = audit["entries"].as_array().unwrap();
+        let statuses: Vec<u64> = entries
+            .iter()
+            .map(|e| e["status"].as_u64().unwrap())
+            .collect();
+        // Anonyme Aufrufe (401) werden nicht protokolliert
+        assert_eq!(statuses, vec![403, 404, 200, 200]);
+        assert_eq!(audit["total"], 4);
+        assert_eq!(entries[0]["actor"], member["did"]);
+        assert_eq!(entries[0]["role"], "member");
+        assert_eq!(entries[1]["actor"], operator["did"]);
+        assert_eq!(entries[2]["listener"], "admin");
+        assert_eq!(entries[3]["role"], "node_operator");
+        assert_eq!(entries[3]["path"], "/api/v1/state/mode");
+        assert_eq!(entries[3]["request"]["mode"], "Degraded");
+        assert_eq!(entries[3]["before"]["system_mode"], "Normal");
+        assert_eq!(entries[3]["after"]["system_mode"], "Degraded");
+        assert_eq!(entries[3]["prev_hash"], entries[2]["hash"]);
+
+        let own: Value = app
+            .get_authed(
+                &format!("/api/v1/audit?actor={}", member["did"].as_str().unwrap()),
+                token,
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(own["entries"].as_array().unwrap().len(), 1);
+
+        let verification: Value = app
+            .get_authed("/api/v1/audit/verify", token)
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(verification["valid"], true);
+        assert_eq!(verification["entries"], 4);
+        assert_eq!(verification["head_hash"], audit["head_hash"]);
+
+        // Ohne ausdrückliches `execute:admin` bleibt der Betreiber Member
+        let credential_id = app.register_key(&app.operator).await;
+        let assertion = app.sign_assertion(&app.operator, &credential_id, 2).await;
+        let plain: Value = app
+            .post("/api/v1/auth/passkey/verify", Some(assertion))
+            .await
+            .json()
+            .await
+            .unwrap();
+        let res = app
+            .get_authed("/api/v1/audit", plain["access_token"].as_str().unwrap())
+            .await;
+        assert_eq!(res.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn trust_updates_require_node_operator() {
+        let app = TestApp::spawn_with(|settings| settings.rate_limit.enabled = false).await;
+        let update = |from_realm: &Value| {
+            serde_json::json!({
+                "entity_id": did_of(&ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng)),
+                "delta": 0.5,
+                "reason": "PositiveInteraction",
+                "from_realm": from_realm,
+            })
+        };
+
+        // Admin eines selbst angelegten Realms ändert keinen globalen Trust
+        let admin = app
+            .login(serde_json::json!({ "capabilities": ["read:*", "write:*", "execute:admin"] }))
+            .await;
+        let admin_token = admin["access_token"].as_str().unwrap();
+        let res = app
+            .connect(
+                "EnvironmentService/CreateEnvironment",
+                Some(admin_token),
+                serde_json::json!({ "name": "own-guild" }),
+            )
+            .await;
+        assert_eq!(res.status(), 200);
+        let realm = res.json::<Value>().await.unwrap()["id"]["id"].clone();
+        let res = app
+            .post_authed("/api/v1/trust/update", admin_token, update(&realm))
+            .await;
+        assert_eq!(res.status(), 403);
+
+        let operator = app.login_operator().await;
+        let res = app
+            .post_authed(
+                "/api/v1/trust/update",
+                operator["access_token"].as_str().unwrap(),
+                update(&realm),
+            )
+            .await;
+        assert_eq!(res.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn governance_finalizes_only_on_explicit_request() {
+        let app = TestApp::spawn_with(|settings| settings.rate_limit.enabled = false).await;
+        let admin = app
+            .login(serde_json::json!({ "capabilities": ["read:*", "write:*"] }))
+            .await;
+        let token = admin["access_token"].as_str().unwrap();
+        let res = app
+            .connect(
+                "EnvironmentService/CreateEnvironment",
+                Some(token),
+                serde_json::json!({ "name": "council" }),
+            )
+            .await;
+        assert_eq!(res.status(), 200);
+        let realm = res.json::<Value>().await.unwrap()["id"]["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let res = app
+            .post_authed(
+                "/api/v1/governance/proposals",
+                token,
+                serde_json::json!({
+                    "realm_id": realm,
+                    "title": "Raise min trust",
+                    "action": { "type": "set_min_trust", "min_trust": 0.4 },
+                    "voting_period_secs": 1,
+                }),
+            )
+            .await;
+        assert_eq!(res.status(), 201);
+        let id = res.json::<Value>().await.unwrap()["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let res = app
+            .post_authed(
+                &format!("/api/v1/governance/proposals/{id}/vote"),
+                token,
+                serde_json::json!({ "direction": "for" }),
+            )
+            .await;
+        assert_eq!(res.status(), 200);
+        tokio::time::sleep(std::time::Duration::from_millis(1_200)).await;
+
+        // Lesende Requests führen keine Proposals aus
+        let proposal: Value = app
+            .get(&format!("/api/v1/governance/proposals/{id}"))
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(proposal["phase"], "active");
+        let list: Value = app
+            .get("/api/v1/governance/proposals")
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(list["proposals"][0]["phase"], "active");
+
+        let finalize = format!("/api/v1/governance/proposals/{id}/finalize");
+        assert_eq!(app.post(&finalize, None).await.status(), 401);
+        let res = app
+            .post_authed(&finalize, token, serde_json::json!({}))
+            .await;
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.json::<Value>().await.unwrap()["phase"], "executed");
+    }
+
+    /// Nächste JSON-Nachricht einer WebSocket-Verbindung (max. 5 s)
+    async fn next_ws_json<S>(ws: &mut S) -> Value
+    where
+        S: axum_connect::futures::Stream<
+                Item = Result<
+                    tokio_tungstenite::tungstenite::Message,
+                    tokio_tungstenite::tungstenite::Error,
+                >,
+            > + Unpin,
+    {
+        use axum_connect::futures::StreamExt;
+        loop {
+            let message = tokio::time::timeout(std::time::Duration::from_secs(5), ws.next())
+                .await
+                .expect("no websocket message within 5s")
+                .expect("websocket closed")
+                .expect("websocket error");
+            if let tokio_tungstenite::tungstenite::Message::Text(text) = message {
+                return serde_json::from_str(text.as_str()).unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn websocket_subscriptions_filter_resume_and_authorize() {
+        use axum_connect::futures::SinkExt;
+        use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+        let app = TestApp::spawn().await;
+        let ws_url = format!(
+            "{}/api/v1/subscribe",
+            app.address.replace("http://", "ws://")
+        );
+
+        // Ohne Session kein Upgrade
+        let err = connect_async(ws_url.as_str()).await.unwrap_err();
+        assert!(matches!(
+            err,
+            tokio_tungstenite::tungstenite::Error::Http(ref res) if res.status() == 401
+        ));
+
+        let member = app.login(serde_json::json!({})).await;
+        let url = format!(
+            "{ws_url}?access_token={}",
+            member["access_token"].as_str().unwrap()
+        );
+        let (mut ws, _) = connect_async(url.as_str()).await.unwrap();
+
+        ws.send(Message::text(r#"{"type":"ping"}"#)).await.unwrap();
+        assert_eq!(next_ws_json(&mut ws).await["type"], "pong");
+
+        ws.send(Message::text(
+            r#"{"type":"subscribe","id":"policies","topic":"events","filter":{"event_types":["PolicyEvaluated"]}}"#,
+        ))
+        .await
+        .unwrap();
+        let subscribed = next_ws_json(&mut ws).await;
+        assert_eq!(subscribed["type"], "subscribed");
+        assert_eq!(subscribed["id"], "policies");
+
+        // Fremdes Realm → forbidden, doppelte ID → duplicate_id
+        ws.send(Message::text(
+            r#"{"type":"subscribe","id":"other","topic":"events","filter":{"realm_id":"realm-x"}}"#,
+        ))
+        .await
+        .unwrap();
+        let error = next_ws_json(&mut ws).await;
+        assert_eq!(error["code"], "forbidden");
+        assert_eq!(error["id"], "other");
+        ws.send(Message::text(
+            r#"{"type":"subscribe","id":"policies","topic":"deltas"}"#,
+        ))
+        .await
+        .unwrap();
+        assert_eq!(next_ws_json(&mut ws).await["code"], "duplicate_id");
+
+        // Betreiber spielt ein Event ein (Admin-Listener)
+        let operator = app.login_operator().await;
+        let operator_token = operator["access_token"].as_str().unwrap();
+        let inject = |gas: u64| {
+            app.client
+                .post(format!("{}/api/v1/state/event", app.admin_address))
+                .bearer_auth(operator_token)
+                .json(&serde_json::json!({ "event": { "PolicyEvaluated": {
+                    "policy_id": "membership-v1",
+                    "realm_id": null,
+                    "passed": true,
+                    "policy_type": "Membership",
+                    "gas_used": gas,
+                    "mana_used": 1,
+                    "duration_us": 10,
+                }}}))
+                .send()
+        };
+        assert!(inject(42).await.unwrap().status().is_success());
+
+        let event = next_ws_json(&mut ws).await;
+        assert_eq!(event["type"], "event");
+        assert_eq!(event["id"], "policies");
+        assert_eq!(event["event"]["event"]["PolicyEvaluated"]["gas_used"], 42);
+        let seen = event["event"]["sequence"].as_u64().unwrap();
+        ws.close(None).await.unwrap();
+
+        // Während der Trennung verpasstes Event wird per since_sequence nachgeliefert
+        assert!(inject(43).await.unwrap().status().is_success());
+        let (mut ws, _) = connect_async(url.as_str()).await.unwrap();
+        ws.send(Message::text(
+            serde_json::json!({
+                "type": "subscribe",
+                "id": "resume",
+                "topic": "events",
+                "since_sequence": seen,
+                "filter": { "event_types": ["PolicyEvaluated"] },
+            })
+            .to_string(),
+        ))
+        .await
+        .unwrap();
+        assert_eq!(next_ws_json(&mut ws).await["next_sequence"], seen + 1);
+        let replayed = next_ws_json(&mut ws).await;
+        assert_eq!(replayed["id"], "resume");
+        assert_eq!(
+            replayed["event"]["event"]["PolicyEvaluated"]["gas_used"],
+            43
+        );
+        assert!(replayed["event"]["sequence"].as_u64().unwrap() > seen);
+
+        ws.send(Message::text(r#"{"type":"unsubscribe","id":"resume"}"#))
+            .await
+            .unwrap();
+        assert_eq!(next_ws_json(&mut ws).await["type"], "unsubscribed");
+    }
+
+    #[tokio::test]
+    async fn openapi_spec_matches_routes_and_typed_client() {
+        use erynoa_client::{AuditListQuery, Client, EventsQuery, TrustUpdateBody};
+
+        // Der Routen-Durchlauf würde das Mana-Budget ohne Session aufbrauchen
+        let app = TestApp::spawn_with(|settings| settings.rate_limit.enabled = false).await;
+        let client = Client::new(&app.address).unwrap();
+
+        let spec = client.openapi().await.unwrap();
+        assert_eq!(spec["openapi"], "3.1.0");
+        let paths = spec["paths"].as_object().unwrap();
+        assert!(paths.contains_key("/api/v1/trust/{did}"));
+        assert!(paths.contains_key("/api/v1/saga/execute"));
+
+        // Jede dokumentierte Operation ist geroutet (Admin-Listener hat alle Routen).
+        // Der Fallback antwortet mit 404 ohne Body, Handler mit JSON.
+        for (path, item) in paths {
+            let concrete = path
+                .split('/')
+                .map(|s| if s.starts_with('{') { "x" } else { s })
+                .collect::<Vec<_>>()
+                .join("/");
+            for method in item.as_object().unwrap().keys() {
+                let method: reqwest::Method = method.to_uppercase().parse().unwrap();
+                let res = app
+                    .client
+                    .request(method.clone(), format!("{}{concrete}", app.admin_address))
+                    .send()
+                    .await
+                    .unwrap();
+                let status = res.status();
+                assert_ne!(status, 405, "{method} {path}");
+                if status == 404 {
+                    let body = res.bytes().await.unwrap();
+                    assert!(!body.is_empty(), "{method} {path} nicht geroutet");
+                }
+            }
+        }
+
+        // Typisierte Aufrufe
+        assert_eq!(client.health().await.unwrap().status, "healthy");
+        assert_eq!(client.info().await.unwrap().auth_method, "DID-Auth");
+        let err = client.me().await.unwrap_err();
+        assert_eq!(err.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+        assert_eq!(err.code(), Some("UNAUTHORIZED"));
+        let err = client.realm("missing").await.unwrap_err();
+        assert_eq!(err.status(), Some(reqwest::StatusCode::NOT_FOUND));
+
+        let login = app.login_operator().await;
+        let operator = client.with_token(login["access_token"].as_str().unwrap());
+        let me = operator.me().await.unwrap();
+        assert_eq!(me.did, did_of(&app.operator));
+
+        let did = did_of(&ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng));
+        let update = operator
+            .update_trust(&TrustUpdateBody {
+                entity_id: did.clone(),
+                delta: 0.1,
+                reason: erynoa_api::core::TrustReason::PositiveInteraction,
+                from_realm: None,
+            })
+            .await
+            .unwrap();
+        let events = operator
+            .events(&EventsQuery {
+                component: Some("trust".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(events
+            .items
+            .iter()
+            .any(|e| e["sequence"] == update.sequence));
+        assert_eq!(operator.trust(&did).await.unwrap().did, did);
+
+        let audit = operator.audit(&AuditListQuery::default()).await.unwrap();
+        assert!(audit.total >= 1);
+    }
+
+    #[tokio::test]
+    async fn idempotency_keys_replay_and_deduplicate_writes() {
+        let app = TestApp::spawn_with(|settings| settings.rate_limit.enabled = false).await;
+        let login = app.login_operator().await;
+        let token = login["access_token"].as_str().unwrap().to_string();
+        let post = |path: &str, key: &str, body: Value| {
+            app.client
+                .post(format!("{}{path}", app.address))
+                .bearer_auth(&token)
+                .header("Idempotency-Key", key)
+                .json(&body)
+                .send()
+        };
+        let realm = serde_json::json!({ "realm_id": "idem-guild" });
+
+        // Wiederholung liefert die gespeicherte Antwort, kein zweites Event
+        let first = post("/api/v1/realms", "create-1", realm.clone())
+            .await
+            .unwrap();
+        assert_eq!(first.status(), 201);
+        assert!(first.headers().get("idempotent-replayed").is_none());
+        let first: Value = first.json().await.unwrap();
+        let retry = post("/api/v1/realms", "create-1", realm.clone())
+            .await
+            .unwrap();
+        assert_eq!(retry.status(), 201);
+        assert_eq!(retry.headers()["idempotent-replayed"], "true");
+        assert_eq!(retry.json::<Value>().await.unwrap(), first);
+
+        // Gleicher Key, anderer Body → 422; ohne Key wird normal ausgeführt
+        let other = serde_json::json!({ "realm_id": "idem-other" });
+        let res = post("/api/v1/realms", "create-1", other).await.unwrap();
+        assert_eq!(res.status(), 422);
+        assert_eq!(
+            res.json::<Value>().await.unwrap()["error"],
+            "idempotency_key_reused"
+        );
+        let res = app
+            .post_authed("/api/v1/realms", &token, realm.clone())
+            .await;
+        let unkeyed: Value = res.json().await.unwrap();
+        assert!(unkeyed["sequence"].as_u64() > first["sequence"].as_u64());
+
+        // Keys gelten pro Aufrufer
+        let member = app.login(serde_json::json!({})).await;
+        let res = app
+            .client
+            .post(format!("{}/api/v1/realms", app.address))
+            .bearer_auth(member["access_token"].as_str().unwrap())
+            .header("Idempotency-Key", "create-1")
+            .json(&serde_json::json!({ "realm_id": "idem-member" }))
+            .send()
+            .await
+            .unwrap();
+        assert_ne!(res.status(), 422);
+
+        // Gleichzeitige Wiederholungen erzeugen genau ein TrustUpdate
+        let entity = did_of(&ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng));
+        let update = serde_json::json!({
+            "entity_id": entity,
+            "delta": 0.05,
+            "reason": "PositiveInteraction"
+        });
+        let (a, b, c, d) = tokio::join!(
+            post("/api/v1/trust/update", "trust-1", update.clone()),
+            post("/api/v1/trust/update", "trust-1", update.clone()),
+            post("/api/v1/trust/update", "trust-1", update.clone()),
+            post("/api/v1/trust/update", "trust-1", update.clone()),
+        );
+        let mut sequences = Vec::new();
+        for res in [a, b, c, d] {
+            let res = res.unwrap();
+            assert_eq!(res.status(), 200);
+            sequences.push(res.json::<Value>().await.unwrap()["sequence"].clone());
+        }
+        assert!(sequences.iter().all(|s| s == &sequences[0]));
+        let events: Value = app
+            .get_authed("/api/v1/events?component=trust&limit=1000", &token)
+            .await
+            .json()
+            .await
+            .unwrap();
+        let logged = events["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|e| e["event"]["TrustUpdate"]["entity_id"] == entity.as_str())
+            .count();
+        assert_eq!(logged, 1);
+
+        // Token-Antworten unter /auth/ werden nie gespeichert
+        let refresh = |refresh_token: Value| {
+            app.client
+                .post(format!("{}/api/v1/auth/session/refresh", app.address))
+                .header("Idempotency-Key", "refresh-1")
+                .json(&serde_json::json!({ "refresh_token": refresh_token }))
+                .send()
+        };
+        let res = refresh(login["refresh_token"].clone()).await.unwrap();
+        assert_eq!(res.status(), 200);
+        let refreshed: Value = res.json().await.unwrap();
+        let res = refresh(refreshed["refresh_token"].clone()).await.unwrap();
+        assert_eq!(res.status(), 200);
+        assert!(res.headers().get("idempotent-replayed").is_none());
+        let again: Value = res.json().await.unwrap();
+        assert_ne!(again["refresh_token"], refreshed["refresh_token"]);
+
+        // Connect-RPCs (Server-Streams) laufen am Speicher vorbei
+        for _ in 0..2 {
+            let res = app
+                .client
+                .post(format!(
+                    "{}/api/v1/connect/erynoa.v1.UserService/GetCurrent",
+                    app.address
+                ))
+                .bearer_auth(&token)
+                .header("Idempotency-Key", "connect-1")
+                .header("Content-Type", "application/json")
+                .body("{}")
+                .send()
+                .await
+                .unwrap();
+            assert!(res.headers().get("idempotent-replayed").is_none());
+        }
+
+        // Ohne Session wird nichts gespeichert (kein geteilter Scope)
+        for _ in 0..2 {
+            let res = app
+                .client
+                .post(format!("{}/api/v1/realms", app.address))
+                .header("Idempotency-Key", "anon-1")
+                .json(&realm)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 401);
+            assert!(res.headers().get("idempotent-replayed").is_none());
+        }
+
+        // Ungültiger Key
+        let res = post("/api/v1/realms", "mit leerzeichen", realm)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn listings_paginate_filter_and_support_etags() {
+        use erynoa_api::domain::{DIDNamespace, TrustVector6D, DID};
+
+        let alice = DID::new(DIDNamespace::Self_, b"alice-listing-key");
+        let peers: Vec<DID> = (0..3)
+            .map(|i| DID::new(DIDNamespace::Guild, format!("peer-{i}").as_bytes()))
+            .collect();
+        let (seed_alice, seed_peers) = (alice.clone(), peers.clone());
+        let app = TestApp::spawn_seeded(
+            |settings| settings.rate_limit.enabled = false,
+            move |storage| {
+                storage
+                    .identities
+                    .import_identity(seed_alice.clone(), "aa")
+                    .unwrap();
+                for peer in &seed_peers {
+                    storage
+                        .identities
+                        .import_identity(peer.clone(), "bb")
+                        .unwrap();
+                    storage
+                        .trust
+                        .put(
+                            seed_alice.clone(),
+                            peer.clone(),
+                            TrustVector6D::new(0.5, 0.5, 0.5, 0.5, 0.5, 0.5),
+                        )
+                        .unwrap();
+                }
+                storage
+                    .identities
+                    .create_identity(DIDNamespace::Self_)
+                    .unwrap();
+                for (i, tags) in [vec!["doc"], vec!["doc", "draft"], vec![]]
+                    .iter()
+                    .enumerate()
+                {
+                    storage
+                        .content
+                        .put(
+                            vec![b'x'; i + 1],
+                            if i == 2 { "image/png" } else { "text/plain" },
+                            Some(seed_alice.clone()),
+                            tags.iter().map(|t| t.to_string()).collect(),
+                        )
+                        .unwrap();
+                }
+            },
+        )
+        .await;
+
+        // Cursor-Walk über alle Identitäten, aufsteigend nach DID
+        let mut dids = Vec::new();
+        let mut path = "/api/v1/identity?limit=2".to_string();
+        loop {
+            let page: Value = app.get(&path).await.json().await.unwrap();
+            assert_eq!(page["total_estimate"], 5);
+            assert!(page["items"].as_array().unwrap().len() <= 2);
+            for item in page["items"].as_array().unwrap() {
+                assert!(item.get("private_key").is_none());
+                dids.push(item["did"].as_str().unwrap().to_string());
+            }
+            match page["next_cursor"].as_str() {
+                Some(cursor) => path = format!("/api/v1/identity?limit=2&cursor={cursor}"),
+                None => break,
+            }
+        }
+        let mut sorted = dids.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(dids, sorted);
+        assert_eq!(dids.len(), 5);
+
+        let guild: Value = app
+            .get("/api/v1/identity?namespace=guild")
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(guild["total_estimate"], 3);
+        let local: Value = app
+            .get("/api/v1/identity?local=true")
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(local["items"][0]["local"], true);
+        assert_eq!(local["total_estimate"], 1);
+
+        // ETag / If-None-Match
+        let res = app.get("/api/v1/identity?sort=created_at&order=desc").await;
+        assert_eq!(res.status(), 200);
+        let etag = res.headers()["etag"].to_str().unwrap().to_string();
+        let res = app
+            .client
+            .get(format!(
+                "{}/api/v1/identity?sort=created_at&order=desc",
+                app.address
+            ))
+            .header("If-None-Match", &etag)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 304);
+        assert_eq!(res.headers()["etag"].to_str().unwrap(), etag);
+
+        // Content über Indizes und MIME-Präfix
+        let alice_str = alice.to_string();
+        let by_creator: Value = app
+            .get(&format!("/api/v1/content?creator={alice_str}&sort=size"))
+            .await
+            .json()
+            .await
+            .unwrap();
+        let sizes: Vec<u64> = by_creator["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["size"].as_u64().unwrap())
+            .collect();
+        assert_eq!(sizes, vec![1, 2, 3]);
+        let drafts: Value = app
+            .get("/api/v1/content?tag=draft")
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(drafts["total_estimate"], 1);
+        let images: Value = app
+            .get("/api/v1/content?content_type=image/")
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(images["items"][0]["content_type"], "image/png");
+
+        // Trust-Kanten in beide Richtungen
+        let outgoing: Value = app
+            .get(&format!(
+                "/api/v1/trust/{alice_str}/edges?sort=peer&limit=2"
+            ))
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(outgoing["total_estimate"], 3);
+        assert_eq!(outgoing["items"].as_array().unwrap().len(), 2);
+        assert!(outgoing["next_cursor"].is_string());
+        let incoming: Value = app
+            .get(&format!(
+                "/api/v1/trust/{}/edges?direction=incoming",
+                peers[0]
+            ))
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(incoming["total_estimate"], 1);
+
+        // Realms und Events aus dem State
+        let login = app.login_operator().await;
+        let token = login["access_token"].as_str().unwrap().to_string();
+        for realm_id in ["list-a", "list-b", "list-c"] {
+            let res = app
+                .post_authed(
+                    "/api/v1/realms",
+                    &token,
+                    serde_json::json!({ "realm_id": realm_id }),
+                )
+                .await;
+            assert_eq!(res.status(), 201);
+        }
+        // Cursor-Walk über die RealmLifecycle-Events, neueste zuerst
+        let page = |cursor: Option<String>| {
+            let path = match cursor {
+                Some(cursor) => {
+                    format!("/api/v1/events?event_type=RealmLifecycle&limit=1&cursor={cursor}")
+                }
+                None => "/api/v1/events?event_type=RealmLifecycle&limit=1".to_string(),
+            };
+            let app = &app;
+            async move { app.get(&path).await.json::<Value>().await.unwrap() }
+        };
+        let newest = page(None).await;
+        assert_eq!(newest["total_estimate"], 3);
+        assert_eq!(
+            newest["items"][0]["event"]["RealmLifecycle"]["realm_id"],
+            "list-c"
+        );
+        let cursor = newest["next_cursor"].as_str().unwrap().to_string();
+        let next = page(Some(cursor.clone())).await;
+        assert_eq!(
+            next["items"][0]["event"]["RealmLifecycle"]["realm_id"],
+            "list-b"
+        );
+        assert!(next["items"][0]["sequence"].as_u64() < newest["items"][0]["sequence"].as_u64());
+
+        let realms: Value = app
+            .get("/api/v1/realms?sort=members")
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(
+            realms["total_estimate"].as_u64().unwrap() as usize,
+            realms["items"].as_array().unwrap().len()
+        );
+
+        // Ungültige Parameter
+        let res = app.get("/api/v1/realms?sort=name").await;
+        assert_eq!(res.status(), 400);
+        assert_eq!(res.json::<Value>().await.unwrap()["error"], "invalid_sort");
+        let res = app
+            .get(&format!("/api/v1/identity?sort=created_at&cursor={cursor}"))
+            .await;
+        assert_eq!(res.status(), 400);
+        assert_eq!(
+            res.json::<Value>().await.unwrap()["error"],
+            "invalid_cursor"
+        );
+    }
+
+    #[tokio::test]
+    async fn anonymous_clients_are_rate_limited_per_ip() {
+        let app = TestApp::spawn().await;
+
+        // Ohne Session: Tier Newcomer, 120 Requests pro Minute
+        for _ in 0..120 {
+            assert_eq!(app.get("/api/v1/info").await.status(), 200);
+        }
+        let res = app.get("/api/v1/info").await;
+        assert_eq!(res.status(), 429);
+        let retry_after: u64 = res.headers()["retry-after"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((1..=60).contains(&retry_after));
+
+        // Health-Checks sind nicht limitiert
+        assert_eq!(app.get("/api/v1/health").await.status(), 200);
+    }
+
+    // ============================================================================
+    // Connect-RPC Tests (v1/connect)
+    // ============================================================================
+
+    #[tokio::test]
+    async fn connect_health_check_and_auth_required() {
+        let app = TestApp::spawn().await;
+
+        let res = app
+            .connect("HealthService/Check", None, serde_json::json!({}))
+            .await;
+        assert_eq!(res.status(), 200);
+        let body: Value = res.json().await.unwrap();
+        assert_eq!(body["status"], "SERVING_STATUS_SERVING");
+
+        let res = app
+            .connect("UserService/GetCurrent", None, serde_json::json!({}))
+            .await;
+        assert_eq!(res.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn connect_users_storage_and_environments() {
+        let app = TestApp::spawn().await;
+        let session = app
+            .login(serde_json::json!({
+                "capabilities": ["read:*", "write:storage", "write:realms"]
+            }))
+            .await;
+        let token = Some(session["access_token"].as_str().unwrap());
+
+        let me: Value = app
+            .connect("UserService/GetCurrent", token, serde_json::json!({}))
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(me["user"]["id"], session["did"]);
+
+        // Bucket anlegen, Datei hochladen, Inhalt listen
+        let res = app
+            .connect(
+                "StorageService/CreateBucket",
+                token,
+                serde_json::json!({ "name": "docs" }),
+            )
+            .await;
+        assert_eq!(res.status(), 200);
+        let uploaded: Value = app
+            .connect(
+                "StorageService/Upload",
+                token,
+                serde_json::json!({
+                    "file": "aGVsbG8=",
+                    "filename": "hello.txt",
+                    "contentType": "text/plain",
+                    "bucket": "docs"
+                }),
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(uploaded["bucket"], "docs");
+        let listed: Value = app
+            .connect(
+                "StorageService/List",
+                token,
+                serde_json::json!({ "bucket": "docs" }),
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(listed["objects"][0]["key"], uploaded["key"]);
+
+        // Environment anlegen: Ersteller ist Mitglied, Bootstrap aktiv
+        let created: Value = app
+            .connect(
+                "EnvironmentService/CreateEnvironment",
+                token,
+                serde_json::json!({ "name": "lab" }),
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(created["success"], true);
+        assert_eq!(created["bootstrap"]["state"], "BOOTSTRAP_STATE_ACTIVE");
+        let environment = created["id"].clone();
+
+        let info: Value = app
+            .connect(
+                "EnvironmentService/GetEnvironmentInfo",
+                token,
+                serde_json::json!({ "environment": environment }),
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(info["name"], "lab");
+        assert_eq!(info["memberCount"], 1);
+
+        let left: Value = app
+            .connect(
+                "EnvironmentService/LeaveEnvironment",
+                token,
+                serde_json::json!({ "environment": environment }),
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(left["success"], true);
+        let joined: Value = app
+            .connect(
+                "EnvironmentService/JoinEnvironment",
+                token,
+                serde_json::json!({ "environment": environment }),
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(joined["status"], "JOIN_STATUS_APPROVED");
+    }
+
+    // ============================================================================
+    // Config Hot Reload (Admin-Listener)
+    // ============================================================================
+
+    #[tokio::test]
+    async fn config_reload_applies_live_subset_and_reports_diff() {
+        use erynoa_client::{ChangeStatus, Client, ReloadOutcome};
+
+        let config_dir = tempfile::tempdir().unwrap();
+        let base = std::fs::read_to_string("config/base.toml").unwrap();
+        let write_base = |content: &str| {
+            std::fs::write(config_dir.path().join("base.toml"), content).unwrap();
+        };
+        write_base(&base);
+        let dir = config_dir.path().to_string_lossy().to_string();
+        let app = TestApp::spawn_with(move |settings| {
+            settings.rate_limit.enabled = false;
+            settings.reload.enabled = false;
+            settings.reload.config_dir = dir;
+        })
+        .await;
+        let login = app.login_operator().await;
+        let token = login["access_token"].as_str().unwrap();
+        let admin = Client::new(&app.admin_address).unwrap().with_token(token);
+
+        // Nur Admin-Listener; vor dem ersten Reload kein Diff
+        let res = app.get_authed("/api/v1/config", token).await;
+        assert_eq!(res.status(), 404);
+        let err = admin.config_diff().await.unwrap_err();
+        assert_eq!(err.code(), Some("no_reload"));
+        let effective = admin.config().await.unwrap();
+        assert_eq!(effective.generation, 0);
+        assert!(effective.live_paths.contains(&"rate_limit".to_string()));
+
+        assert_eq!(effective.fixed_paths, vec!["world_formula".to_string()]);
+
+        // Weltformel wird von keinem Subsystem übernommen: Reload abgelehnt
+        write_base(
+            &base
+                .replace("challenge_ttl_secs = 300", "challenge_ttl_secs = 60")
+                .replace("# newcomer_trust = 0.1", "newcomer_trust = 0.2"),
+        );
+        let reload = admin.reload_config().await.unwrap();
+        assert_eq!(reload.outcome, ReloadOutcome::Rejected);
+        assert_eq!(
+            reload.error.as_deref(),
+            Some("world_formula.newcomer_trust cannot be changed while the node is running")
+        );
+        assert_eq!(admin.config().await.unwrap().generation, 0);
+
+        // Live-Werte greifen sofort, Port und Storage erst nach Neustart
+        write_base(
+            &base
+                .replace("challenge_ttl_secs = 300", "challenge_ttl_secs = 60")
+                .replace("requests_per_minute = 120", "requests_per_minute = 500"),
+        );
+        let reload = admin.reload_config().await.unwrap();
+        assert_eq!(reload.outcome, ReloadOutcome::Applied);
+        assert_eq!(reload.generation, 1);
+        let status = |path: &str| {
+            reload
+                .changes
+                .iter()
+                .find(|c| c.path == path)
+                .map(|c| c.status)
+        };
+        assert_eq!(
+            status("auth.challenge_ttl_secs"),
+            Some(ChangeStatus::Applied)
+        );
+        assert_eq!(status("rate_limit.enabled"), Some(ChangeStatus::Applied));
+        assert_eq!(
+            status("application.port"),
+            Some(ChangeStatus::RestartRequired)
+        );
+        assert_eq!(
+            status("storage.data_dir"),
+            Some(ChangeStatus::RestartRequired)
+        );
+
+        let effective = admin.config().await.unwrap();
+        assert_eq!(effective.generation, 1);
+        assert_eq!(effective.settings.rate_limit.requests_per_minute, 500);
+        assert_eq!(effective.settings.application.port, 0);
+        let challenge: Value = app
+            .get("/api/v1/auth/challenge")
+            .await
+            .json()
+            .await
+            .unwrap();
+        let ttl = challenge["expires_at"].as_i64().unwrap() - chrono::Utc::now().timestamp();
+        assert!((58..=60).contains(&ttl), "ttl {ttl}");
+
+        // Ungültige Werte: nichts übernommen, Fehler im Diff
+        write_base(&base.replace("# positive_delta = 0.045", "positive_delta = 5.0"));
+        let reload = admin.reload_config().await.unwrap();
+        assert_eq!(reload.outcome, ReloadOutcome::Rejected);
+        assert!(reload
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("invalid world_formula"));
+        assert!(reload
+            .changes
+            .iter()
+            .all(|c| c.status == ChangeStatus::Rejected));
+        let effective = admin.config().await.unwrap();
+        assert_eq!(effective.generation, 1);
+        assert_eq!(effective.settings.auth.challenge_ttl_secs, 60);
+
+        write_base("[application\nport = ");
+        let reload = admin.reload_config().await.unwrap();
+        assert_eq!(reload.outcome, ReloadOutcome::Rejected);
+        assert!(reload
+            .error
+            .unwrap()
+            .starts_with("config could not be loaded"));
+        let diff = admin.config_diff().await.unwrap();
+        assert_eq!(diff.outcome, ReloadOutcome::Rejected);
+        assert_eq!(diff.generation, 1);
+    }
+
+    // ============================================================================
+    // TUI Debugger (feature "debug")
+    // ============================================================================
+
+    #[cfg(feature = "debug")]
+    #[tokio::test]
+    async fn debugger_attaches_to_running_node() {
+        use erynoa_api::debug::{Action, EventEntry, NodeClient, Update};
+
+        let app = TestApp::spawn().await;
+        let session = app.login_operator().await;
+        let token = session["access_token"].as_str().unwrap();
+        let client = NodeClient::new(&app.admin_address, Some(token.to_string())).unwrap();
+
+        let snapshot = client.snapshot().await.unwrap();
+        assert_eq!(snapshot["system_mode"], "Normal");
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(client.clone().follow_stream(tx));
+        let connected = tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap();
+        assert!(matches!(
+            connected,
+            Some(Update::Stream { connected: true })
+        ));
+
+        let message = client.execute(&Action::Checkpoint).await.unwrap();
+        assert!(message.starts_with("checkpoint ckpt_"));
+
+        let event = serde_json::json!({ "event": { "PolicyEvaluated": {
+            "policy_id": "membership-v1",
+            "realm_id": null,
+            "passed": false,
+            "policy_type": "Membership",
+            "gas_used": 42,
+            "mana_used": 7,
+            "duration_us": 120,
+        }}});
+        assert!(app
+            .client
+            .post(format!("{}/api/v1/state/event", app.admin_address))
+            .bearer_auth(token)
+            .json(&event)
+            .send()
+            .await
+            .unwrap()
+            .status()
+            .is_success());
+        let events: Vec<EventEntry> = client
+            .events(None)
+            .await
+            .unwrap()
+            .iter()
+            .filter_map(EventEntry::from_value)
+            .collect();
+        assert!(events
+            .iter()
+            .any(|e| e.kind == "PolicyEvaluated" && e.payload["gas_used"] == 42));
+
+        client.execute(&Action::SetMode("Degraded")).await.unwrap();
+        assert_eq!(client.snapshot().await.unwrap()["system_mode"], "Degraded");
+        let message = client.execute(&Action::ResetMode).await.unwrap();
+        assert_eq!(message, "circuit breaker reset, mode: Normal");
+    }
+
+    #[tokio::test]
+    async fn saga_execute_requires_session_and_ownership() {
+        use axum_connect::futures::SinkExt;
+        use erynoa_api::domain::unified::{Goal, Intent};
+        use erynoa_api::domain::{UniversalId, ROOT_REALM_ID};
+        use erynoa_api::local::NATIVE_ASSET;
+        use erynoa_api::peer::SagaComposer;
+        use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+        let victim = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let attacker = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let id_of = |key: &ed25519_dalek::SigningKey| {
+            UniversalId::from_hex(&hex::encode(key.verifying_key().to_bytes())).unwrap()
+        };
+        let (victim_id, attacker_id) = (id_of(&victim), id_of(&attacker));
+        let transfer = |from: UniversalId, to: UniversalId| {
+            let intent = Intent::new(
+                from,
+                Goal::Transfer {
+                    to,
+                    amount: 10,
+                    asset_type: NATIVE_ASSET.into(),
+                },
+                ROOT_REALM_ID,
+                1,
+            );
+            SagaComposer::default().compose(&intent).unwrap()
+        };
+        let app = TestApp::spawn_seeded(
+            |settings| settings.rate_limit.enabled = false,
+            move |storage| {
+                storage
+                    .ledger
+                    .register_asset(NATIVE_ASSET, victim_id)
+                    .unwrap();
+                storage
+                    .ledger
+                    .mint(&victim_id, &victim_id, NATIVE_ASSET, 100, None)
+                    .unwrap();
+            },
+        )
+        .await;
+
+        // Ohne Session kein Einreichen
+        let theft = serde_json::json!({ "saga": transfer(victim_id, attacker_id), "wait": true });
+        let res = app.post("/api/v1/saga/execute", Some(theft.clone())).await;
+        assert_eq!(res.status(), 401);
+
+        // Fremder Executor wird abgelehnt
+        let session = app.login_as(&attacker, serde_json::json!({})).await;
+        let attacker_token = session["access_token"].as_str().unwrap().to_string();
+        let res = app
+            .post_authed("/api/v1/saga/execute", &attacker_token, theft)
+            .await;
+        assert_eq!(res.status(), 403);
+
+        // Eigene Saga läuft durch
+        let session = app.login_as(&victim, serde_json::json!({})).await;
+        let victim_token = session["access_token"].as_str().unwrap().to_string();
+        let saga = transfer(victim_id, attacker_id);
+        let saga_id = saga.id.to_hex();
+        let record: Value = app
+            .post_authed(
+                "/api/v1/saga/execute",
+                &victim_token,
+                serde_json::json!({ "saga": saga, "wait": true }),
+            )
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(record["saga"]["status"]["status"], "completed");
+
+        // Steuern nur durch den Executor
+        let res = app
+            .post_authed(
+                &format!("/api/v1/saga/{saga_id}/cancel"),
+                &attacker_token,
+                serde_json::json!({}),
+            )
+            .await;
+        assert_eq!(res.status(), 403);
+        let res = app
+            .post_authed(
+                "/api/v1/saga/signal",
+                &attacker_token,
+                serde_json::json!({ "condition": "released" }),
+            )
+            .await;
+        assert_eq!(res.status(), 403);
+
+        // Connect-RPC prüft dieselben Rechte
+        let request = serde_json::json!({ "sagaId": saga_id });
+        for method in ["ExecuteSaga", "CancelSaga", "RollbackSaga"] {
+            let method = format!("SagaService/{method}");
+            let res = app.connect(&method, None, request.clone()).await;
+            assert_eq!(res.status(), 401, "{method}");
+            let res = app
+                .connect(&method, Some(&attacker_token), request.clone())
+                .await;
+            assert_eq!(res.status(), 403, "{method}");
+        }
+        let res = app
+            .connect(
+                "SagaService/CancelSaga",
+                Some(&victim_token),
+                request.clone(),
+            )
+            .await;
+        assert_ne!(res.status(), 403);
+
+        // Connect-Stream der Live-Updates ebenfalls
+        let stream = |token: &str| {
+            let message = serde_json::to_vec(&request).unwrap();
+            let mut envelope = vec![0u8];
+            envelope.extend_from_slice(&(message.len() as u32).to_be_bytes());
+            envelope.extend_from_slice(&message);
+            app.client
+                .post(format!(
+                    "{}/api/v1/connect/erynoa.v1.SagaService/StreamSagaUpdates",
+                    app.address
+                ))
+                .bearer_auth(token)
+                .header("Content-Type", "application/connect+json")
+                .body(envelope)
+                .send()
+        };
+        let denied = stream(&attacker_token).await.unwrap().text().await.unwrap();
+        assert!(denied.contains("permission_denied"), "{denied}");
+        let allowed = stream(&victim_token).await.unwrap().text().await.unwrap();
+        assert!(allowed.contains(&saga_id), "{allowed}");
+
+        // Fortschritt per WebSocket ebenfalls nur für den Executor
+        let subscribe = serde_json::json!({
+            "type": "subscribe",
+            "id": "saga",
+            "topic": "saga",
+            "filter": { "saga_id": saga_id },
+        })
+        .to_string();
+        let ws_url = format!(
+            "{}/api/v1/subscribe",
+            app.address.replace("http://", "ws://")
+        );
+        for (token, expected) in [(&attacker_token, "error"), (&victim_token, "subscribed")] {
+            let url = format!("{ws_url}?access_token={token}");
+            let (mut ws, _) = connect_async(url.as_str()).await.unwrap();
+            ws.send(Message::text(subscribe.clone())).await.unwrap();
+            let reply = next_ws_json(&mut ws).await;
+            assert_eq!(reply["type"], expected, "{reply}");
+            if expected == "error" {
+                assert_eq!(reply["code"], "forbidden");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn intent_query_runs_as_session_did() {
+        let app = TestApp::spawn().await;
+        let query = serde_json::json!({ "goal": { "type": "query", "predicate": "events" } });
+
+        let res = app.post("/api/v1/intent/query", Some(query.clone())).await;
+        assert_eq!(res.status(), 401);
+
+        let session = app.login(serde_json::json!({})).await;
+        let token = session["access_token"].as_str().unwrap();
+        let other = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let mut foreign = query.clone();
+        foreign["source_did"] = did_of(&other).into();
+        let res = app
+            .post_authed("/api/v1/intent/query", token, foreign)
+            .await;
+        assert_eq!(res.status(), 403);
+        let body: Value = res.json().await.unwrap();
+        assert_eq!(body["error"], "source_did_mismatch");
+
+        let body: Value = app
+            .post_authed("/api/v1/intent/query", token, query)
+            .await
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["executed"], true, "{body}");
+    }
+
+    #[tokio::test]
+    async fn passkey_registration_binds_did_and_credential_id() {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+        let app = TestApp::spawn().await;
+        let victim = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let attacker = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        let credential_id = app.register_key(&victim).await;
+        let register = |key: &ed25519_dalek::SigningKey, did: String| {
+            serde_json::json!({
+                "credential_id": credential_id,
+                "public_key": URL_SAFE_NO_PAD.encode(key.verifying_key().to_bytes()),
+                "algorithm": -8,
+                "did": did,
+                "namespace": "self"
+            })
+        };
+
+        // Fremde DID für den eigenen Schlüssel
+        let res = app
+            .post(
+                "/api/v1/auth/passkey/register",
+                Some(register(&attacker, did_of(&victim))),
+            )
+            .await;
+        assert_eq!(res.status(), 400);
+
+        // Bestehende Credential-ID für eine andere DID
+        let res = app
+            .post(
+                "/api/v1/auth/passkey/register",
+                Some(register(&attacker, did_of(&attacker))),
+            )
+            .await;
+        assert_eq!(res.status(), 409);
+
+        // Erneute Registrierung ist idempotent, Login bleibt möglich
+        assert_eq!(app.register_key(&victim).await, credential_id);
+        let session = app.login_as(&victim, serde_json::json!({})).await;
+        assert_eq!(session["did"], did_of(&victim));
+    }
 }
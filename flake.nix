@@ -29,7 +29,8 @@
           filter = path: type:
             (craneLib.filterCargoSources path type)
             || (builtins.match ".*config.*" path != null)
-            || (builtins.match ".*migrations.*" path != null);
+            || (builtins.match ".*migrations.*" path != null)
+            || (builtins.match ".*\\.proto$" path != null);
         };
 
         commonArgs = {
@@ -41,6 +42,8 @@
             pkgs.libiconv
           ];
           SQLX_OFFLINE = "true";
+          # build.rs lädt protoc sonst von GitHub (im Nix-Sandbox-Build ohne Netz)
+          PROTOC = "${pkgs.protobuf}/bin/protoc";
         };
 
         cargoArtifacts = craneLib.buildDepsOnly commonArgs;
@@ -106,8 +109,11 @@
             nodePackages.pnpm  # ⚡ PERFORMANCE: Schneller als npm, hardlinkt Dependencies
             # Protobuf tools
             buf
+            protobuf
           ];
 
+          PROTOC = "${pkgs.protobuf}/bin/protoc";
+
           # ⚡ PERFORMANCE: sccache als Compiler-Wrapper
           RUSTC_WRAPPER = "${pkgs.sccache}/bin/sccache";
           SCCACHE_CACHE_SIZE = "10G";